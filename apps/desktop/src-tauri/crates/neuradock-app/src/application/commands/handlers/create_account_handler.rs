@@ -5,7 +5,9 @@ use std::sync::Arc;
 
 use crate::application::commands::account_commands::*;
 use crate::application::commands::command_handler::CommandHandler;
+use crate::application::services::{AuditLogService, AuditSummary};
 use neuradock_domain::account::{Account, AccountRepository, Credentials};
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
 use neuradock_domain::events::account_events::AccountCreated;
 use neuradock_domain::events::EventBus;
 use neuradock_domain::session::SessionTokenExtractor;
@@ -15,6 +17,7 @@ use neuradock_domain::shared::{DomainError, ProviderId};
 pub struct CreateAccountCommandHandler {
     account_repo: Arc<dyn AccountRepository>,
     event_bus: Arc<dyn EventBus>,
+    audit_log: Option<Arc<AuditLogService>>,
 }
 
 impl CreateAccountCommandHandler {
@@ -22,8 +25,14 @@ impl CreateAccountCommandHandler {
         Self {
             account_repo,
             event_bus,
+            audit_log: None,
         }
     }
+
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
 }
 
 #[async_trait]
@@ -70,6 +79,17 @@ impl CommandHandler<CreateAccountCommand> for CreateAccountCommandHandler {
             Account::DEFAULT_SESSION_EXPIRATION_DAYS
         );
 
+        if let Some(audit_log) = &self.audit_log {
+            let mut entry = AuditEntry::new(
+                AuditActor::User,
+                AuditAction::AccountCreated,
+                account_id.as_str(),
+            )
+            .with_target_name(name.as_str());
+            entry.after = Some(AuditSummary::account(&account).build());
+            audit_log.record(entry).await;
+        }
+
         // 6. Publish domain event
         let event = AccountCreated {
             account_id: account_id.clone(),
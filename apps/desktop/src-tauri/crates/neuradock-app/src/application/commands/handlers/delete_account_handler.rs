@@ -5,7 +5,9 @@ use std::sync::Arc;
 
 use crate::application::commands::account_commands::*;
 use crate::application::commands::command_handler::CommandHandler;
+use crate::application::services::{AuditLogService, AuditSummary};
 use neuradock_domain::account::AccountRepository;
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
 use neuradock_domain::events::account_events::AccountDeleted;
 use neuradock_domain::events::EventBus;
 use neuradock_domain::shared::{AccountId, DomainError};
@@ -14,6 +16,7 @@ use neuradock_domain::shared::{AccountId, DomainError};
 pub struct DeleteAccountCommandHandler {
     account_repo: Arc<dyn AccountRepository>,
     event_bus: Arc<dyn EventBus>,
+    audit_log: Option<Arc<AuditLogService>>,
 }
 
 impl DeleteAccountCommandHandler {
@@ -21,8 +24,14 @@ impl DeleteAccountCommandHandler {
         Self {
             account_repo,
             event_bus,
+            audit_log: None,
         }
     }
+
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
 }
 
 #[async_trait]
@@ -52,6 +61,17 @@ impl CommandHandler<DeleteAccountCommand> for DeleteAccountCommandHandler {
 
         info!("Account deleted successfully: {}", name);
 
+        if let Some(audit_log) = &self.audit_log {
+            let mut entry = AuditEntry::new(
+                AuditActor::User,
+                AuditAction::AccountDeleted,
+                account_id.as_str(),
+            )
+            .with_target_name(name.as_str());
+            entry.before = Some(AuditSummary::account(&account).build());
+            audit_log.record(entry).await;
+        }
+
         // 3. Publish domain event
         let event = AccountDeleted {
             account_id,
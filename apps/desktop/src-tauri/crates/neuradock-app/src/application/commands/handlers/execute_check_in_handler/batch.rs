@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use log::{error, info};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::application::commands::check_in_commands::*;
@@ -7,13 +8,15 @@ use crate::application::commands::command_handler::CommandHandler;
 use crate::application::dtos::BalanceDto;
 use crate::application::services::{
     BalanceHistoryService, CheckInExecutor, NotificationService, ProviderModelsService,
+    ProxyRoutingService,
 };
 use crate::application::ResultExt;
 use neuradock_domain::account::AccountRepository;
 use neuradock_domain::check_in::ProviderRepository;
-use neuradock_domain::proxy_config::ProxyConfigRepository;
+use neuradock_domain::events::EventBus;
 use neuradock_domain::shared::{AccountId, DomainError};
 use neuradock_domain::waf_cookies::WafCookiesRepository;
+use neuradock_infrastructure::browser::BrowserPool;
 
 use super::shared;
 
@@ -21,19 +24,21 @@ use super::shared;
 pub struct BatchExecuteCheckInCommandHandler {
     account_repo: Arc<dyn AccountRepository>,
     provider_repo: Arc<dyn ProviderRepository>,
-    proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
     notification_service: Option<Arc<NotificationService>>,
     provider_models_service: Arc<ProviderModelsService>,
     balance_history_service: Arc<BalanceHistoryService>,
     waf_cookies_repo: Arc<dyn WafCookiesRepository>,
     headless_browser: bool,
+    browser_pool: Option<Arc<BrowserPool>>,
+    event_bus: Option<Arc<dyn EventBus>>,
 }
 
 impl BatchExecuteCheckInCommandHandler {
     pub fn new(
         account_repo: Arc<dyn AccountRepository>,
         provider_repo: Arc<dyn ProviderRepository>,
-        proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
         provider_models_service: Arc<ProviderModelsService>,
         balance_history_service: Arc<BalanceHistoryService>,
         waf_cookies_repo: Arc<dyn WafCookiesRepository>,
@@ -42,12 +47,14 @@ impl BatchExecuteCheckInCommandHandler {
         Self {
             account_repo,
             provider_repo,
-            proxy_config_repo,
+            proxy_routing,
             notification_service: None,
             provider_models_service,
             balance_history_service,
             waf_cookies_repo,
             headless_browser,
+            browser_pool: None,
+            event_bus: None,
         }
     }
 
@@ -55,6 +62,16 @@ impl BatchExecuteCheckInCommandHandler {
         self.notification_service = Some(service);
         self
     }
+
+    pub fn with_browser_pool(mut self, pool: Arc<BrowserPool>) -> Self {
+        self.browser_pool = Some(pool);
+        self
+    }
+
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
 }
 
 #[async_trait]
@@ -72,17 +89,8 @@ impl CommandHandler<BatchExecuteCheckInCommand> for BatchExecuteCheckInCommandHa
         let mut failed = 0;
         let mut results = Vec::new();
 
-        // Get proxy configuration
-        let proxy_config = self.proxy_config_repo.get().await?;
-        let proxy_url = proxy_config.proxy_url();
-
-        let executor = CheckInExecutor::with_proxy(
-            self.account_repo.clone(),
-            self.headless_browser,
-            proxy_url,
-        )
-        .to_infra_err()?
-        .with_waf_cookies_repo(self.waf_cookies_repo.clone());
+        // Accounts may route through different proxies; share one executor per proxy
+        let mut executors: HashMap<Option<String>, CheckInExecutor> = HashMap::new();
 
         for account_id in cmd.account_ids {
             // Load account to get provider_id
@@ -153,6 +161,39 @@ impl CommandHandler<BatchExecuteCheckInCommand> for BatchExecuteCheckInCommandHa
                 }
             };
 
+            let proxy_url = match self
+                .proxy_routing
+                .resolve_for_account(&account_id, &provider_id, provider.domain())
+                .await
+            {
+                Ok(proxy_url) => proxy_url,
+                Err(e) => {
+                    error!("Failed to resolve proxy for account {}: {}", account_id, e);
+                    failed += 1;
+                    results.push(CheckInCommandResult {
+                        account_id: account_id.clone(),
+                        account_name,
+                        provider_id: provider_id.clone(),
+                        success: false,
+                        message: format!("Failed to resolve proxy: {}", e),
+                        balance: None,
+                    });
+                    continue;
+                }
+            };
+            if !executors.contains_key(&proxy_url) {
+                let executor = CheckInExecutor::with_proxy(
+                    self.account_repo.clone(),
+                    self.headless_browser,
+                    proxy_url.clone(),
+                )
+                .to_infra_err()?
+                .with_waf_cookies_repo(self.waf_cookies_repo.clone())
+                .with_browser_pool(self.browser_pool.clone());
+                executors.insert(proxy_url.clone(), executor);
+            }
+            let executor = &executors[&proxy_url];
+
             match executor.execute_check_in(&account_id, &provider).await {
                 Ok(result) => {
                     // Update account balance cache and save to balance_history if we have new balance data
@@ -205,12 +246,22 @@ impl CommandHandler<BatchExecuteCheckInCommand> for BatchExecuteCheckInCommandHa
                         result.success,
                         &account_id,
                         &result.account_name,
+                        provider.id().as_str(),
                         provider.name(),
                         &result.message,
                         balance_tuple,
                     )
                     .await;
 
+                    shared::publish_check_in_events(
+                        &self.event_bus,
+                        &account_id,
+                        result.success,
+                        &result.message,
+                        balance_tuple,
+                    )
+                    .await;
+
                     if result.success {
                         succeeded += 1;
                     } else {
@@ -1,3 +1,4 @@
+use chrono::Utc;
 use log::{error, info};
 use std::sync::Arc;
 
@@ -8,6 +9,8 @@ use crate::application::services::{
 use neuradock_domain::{
     account::{Account, AccountRepository},
     check_in::Provider,
+    events::account_events::{BalanceUpdated, CheckInBalance, CheckInCompleted},
+    events::EventBus,
     shared::{AccountId, DomainError},
 };
 use neuradock_infrastructure::http::UserInfo;
@@ -89,11 +92,13 @@ pub async fn auto_fetch_provider_models(
 }
 
 /// Send check-in notification (success or failure)
+#[allow(clippy::too_many_arguments)]
 pub async fn send_check_in_notification(
     notification_service: &Option<Arc<NotificationService>>,
     success: bool,
     account_id: &str,
     account_name: &str,
+    provider_id: &str,
     provider_name: &str,
     message: &str,
     balance: Option<(f64, f64, f64)>, // (current_balance, total_consumed, total_quota)
@@ -102,7 +107,13 @@ pub async fn send_check_in_notification(
         if success {
             // Send success notification
             if let Err(e) = notification_service
-                .send_check_in_success(account_id, account_name, provider_name, balance)
+                .send_check_in_success(
+                    account_id,
+                    account_name,
+                    provider_id,
+                    provider_name,
+                    balance,
+                )
                 .await
             {
                 error!("Failed to send check-in success notification: {}", e);
@@ -133,3 +144,52 @@ pub async fn send_check_in_notification(
         );
     }
 }
+
+/// Publish the check-in outcome and any new balance
+/// Failures are only logged, the check-in itself already happened
+pub async fn publish_check_in_events(
+    event_bus: &Option<Arc<dyn EventBus>>,
+    account_id: &str,
+    success: bool,
+    message: &str,
+    balance: Option<(f64, f64, f64)>, // (current_balance, total_consumed, total_quota)
+) {
+    let Some(event_bus) = event_bus else {
+        return;
+    };
+    let account_id = AccountId::from_string(account_id);
+    let occurred_at = Utc::now();
+
+    let completed = CheckInCompleted {
+        account_id: account_id.clone(),
+        success,
+        message: message.to_string(),
+        balance: balance.map(
+            |(current_balance, total_consumed, total_quota)| CheckInBalance {
+                current_balance,
+                total_consumed,
+                total_quota,
+            },
+        ),
+        occurred_at,
+    };
+    if let Err(e) = event_bus.publish(Box::new(completed)).await {
+        error!(
+            "Failed to publish CheckInCompleted for {}: {}",
+            account_id, e
+        );
+    }
+
+    if let (true, Some((current_balance, total_consumed, total_quota))) = (success, balance) {
+        let updated = BalanceUpdated {
+            account_id: account_id.clone(),
+            current_balance,
+            total_consumed,
+            total_quota,
+            occurred_at,
+        };
+        if let Err(e) = event_bus.publish(Box::new(updated)).await {
+            error!("Failed to publish BalanceUpdated for {}: {}", account_id, e);
+        }
+    }
+}
@@ -6,13 +6,15 @@ use crate::application::commands::check_in_commands::*;
 use crate::application::commands::command_handler::CommandHandler;
 use crate::application::services::{
     BalanceHistoryService, CheckInExecutor, NotificationService, ProviderModelsService,
+    ProxyRoutingService,
 };
 use crate::application::ResultExt;
 use neuradock_domain::account::AccountRepository;
 use neuradock_domain::check_in::ProviderRepository;
-use neuradock_domain::proxy_config::ProxyConfigRepository;
+use neuradock_domain::events::EventBus;
 use neuradock_domain::shared::{AccountId, DomainError};
 use neuradock_domain::waf_cookies::WafCookiesRepository;
+use neuradock_infrastructure::browser::BrowserPool;
 
 use super::shared;
 
@@ -20,19 +22,21 @@ use super::shared;
 pub struct ExecuteCheckInCommandHandler {
     account_repo: Arc<dyn AccountRepository>,
     provider_repo: Arc<dyn ProviderRepository>,
-    proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
     notification_service: Option<Arc<NotificationService>>,
     provider_models_service: Arc<ProviderModelsService>,
     balance_history_service: Arc<BalanceHistoryService>,
     waf_cookies_repo: Arc<dyn WafCookiesRepository>,
     headless_browser: bool,
+    browser_pool: Option<Arc<BrowserPool>>,
+    event_bus: Option<Arc<dyn EventBus>>,
 }
 
 impl ExecuteCheckInCommandHandler {
     pub fn new(
         account_repo: Arc<dyn AccountRepository>,
         provider_repo: Arc<dyn ProviderRepository>,
-        proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
         provider_models_service: Arc<ProviderModelsService>,
         balance_history_service: Arc<BalanceHistoryService>,
         waf_cookies_repo: Arc<dyn WafCookiesRepository>,
@@ -41,12 +45,14 @@ impl ExecuteCheckInCommandHandler {
         Self {
             account_repo,
             provider_repo,
-            proxy_config_repo,
+            proxy_routing,
             notification_service: None,
             provider_models_service,
             balance_history_service,
             waf_cookies_repo,
             headless_browser,
+            browser_pool: None,
+            event_bus: None,
         }
     }
 
@@ -54,6 +60,16 @@ impl ExecuteCheckInCommandHandler {
         self.notification_service = Some(service);
         self
     }
+
+    pub fn with_browser_pool(mut self, pool: Arc<BrowserPool>) -> Self {
+        self.browser_pool = Some(pool);
+        self
+    }
+
+    pub fn with_event_bus(mut self, event_bus: Arc<dyn EventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
 }
 
 #[async_trait]
@@ -87,9 +103,11 @@ impl CommandHandler<ExecuteCheckInCommand> for ExecuteCheckInCommandHandler {
 
         let account_name = account.name().to_string();
 
-        // Get proxy configuration
-        let proxy_config = self.proxy_config_repo.get().await?;
-        let proxy_url = proxy_config.proxy_url();
+        // Resolve the proxy for this account (override, pool, bypass or global)
+        let proxy_url = self
+            .proxy_routing
+            .resolve_for_account(&cmd.account_id, &provider_id, provider.domain())
+            .await?;
 
         // Create executor with proxy support
         let executor = CheckInExecutor::with_proxy(
@@ -98,7 +116,8 @@ impl CommandHandler<ExecuteCheckInCommand> for ExecuteCheckInCommandHandler {
             proxy_url,
         )
         .to_infra_err()?
-        .with_waf_cookies_repo(self.waf_cookies_repo.clone());
+        .with_waf_cookies_repo(self.waf_cookies_repo.clone())
+        .with_browser_pool(self.browser_pool.clone());
 
         // Execute check-in
         let result = executor
@@ -157,12 +176,22 @@ impl CommandHandler<ExecuteCheckInCommand> for ExecuteCheckInCommandHandler {
             result.success,
             &cmd.account_id,
             &account_name,
+            provider.id().as_str(),
             provider.name(),
             &result.message,
             balance_tuple,
         )
         .await;
 
+        shared::publish_check_in_events(
+            &self.event_bus,
+            &cmd.account_id,
+            result.success,
+            &result.message,
+            balance_tuple,
+        )
+        .await;
+
         Ok(CheckInCommandResult {
             account_id: cmd.account_id,
             account_name,
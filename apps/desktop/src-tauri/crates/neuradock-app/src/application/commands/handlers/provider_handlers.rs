@@ -4,17 +4,28 @@ use std::sync::Arc;
 
 use crate::application::commands::command_handler::CommandHandler;
 use crate::application::commands::provider_commands::*;
+use crate::application::services::{AuditLogService, AuditSummary};
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
 use neuradock_domain::check_in::{Provider, ProviderConfig, ProviderRepository};
 use neuradock_domain::shared::DomainError;
 
 /// Create provider command handler
 pub struct CreateProviderCommandHandler {
     provider_repo: Arc<dyn ProviderRepository>,
+    audit_log: Option<Arc<AuditLogService>>,
 }
 
 impl CreateProviderCommandHandler {
     pub fn new(provider_repo: Arc<dyn ProviderRepository>) -> Self {
-        Self { provider_repo }
+        Self {
+            provider_repo,
+            audit_log: None,
+        }
+    }
+
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
     }
 }
 
@@ -72,6 +83,17 @@ impl CommandHandler<CreateProviderCommand> for CreateProviderCommandHandler {
             cmd.name, provider_id
         );
 
+        if let Some(audit_log) = &self.audit_log {
+            let mut entry = AuditEntry::new(
+                AuditActor::User,
+                AuditAction::ProviderCreated,
+                provider_id.as_str(),
+            )
+            .with_target_name(provider.name());
+            entry.after = Some(AuditSummary::provider(&provider).build());
+            audit_log.record(entry).await;
+        }
+
         Ok(CreateProviderResult { provider_id })
     }
 }
@@ -79,11 +101,20 @@ impl CommandHandler<CreateProviderCommand> for CreateProviderCommandHandler {
 /// Update provider command handler
 pub struct UpdateProviderCommandHandler {
     provider_repo: Arc<dyn ProviderRepository>,
+    audit_log: Option<Arc<AuditLogService>>,
 }
 
 impl UpdateProviderCommandHandler {
     pub fn new(provider_repo: Arc<dyn ProviderRepository>) -> Self {
-        Self { provider_repo }
+        Self {
+            provider_repo,
+            audit_log: None,
+        }
+    }
+
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
     }
 }
 
@@ -174,6 +205,18 @@ impl CommandHandler<UpdateProviderCommand> for UpdateProviderCommandHandler {
 
         info!("Provider updated successfully: {}", cmd.provider_id);
 
+        if let Some(audit_log) = &self.audit_log {
+            let mut entry = AuditEntry::new(
+                AuditActor::User,
+                AuditAction::ProviderUpdated,
+                provider_id.as_str(),
+            )
+            .with_target_name(updated_provider.name());
+            entry.before = Some(AuditSummary::provider(&existing).build());
+            entry.after = Some(AuditSummary::provider(&updated_provider).build());
+            audit_log.record(entry).await;
+        }
+
         Ok(UpdateProviderResult { success: true })
     }
 }
@@ -182,6 +225,7 @@ impl CommandHandler<UpdateProviderCommand> for UpdateProviderCommandHandler {
 pub struct DeleteProviderCommandHandler {
     provider_repo: Arc<dyn ProviderRepository>,
     account_repo: Arc<dyn neuradock_domain::account::AccountRepository>,
+    audit_log: Option<Arc<AuditLogService>>,
 }
 
 impl DeleteProviderCommandHandler {
@@ -192,8 +236,14 @@ impl DeleteProviderCommandHandler {
         Self {
             provider_repo,
             account_repo,
+            audit_log: None,
         }
     }
+
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
 }
 
 #[async_trait]
@@ -235,6 +285,17 @@ impl CommandHandler<DeleteProviderCommand> for DeleteProviderCommandHandler {
 
         info!("Provider deleted successfully: {}", cmd.provider_id);
 
+        if let Some(audit_log) = &self.audit_log {
+            let mut entry = AuditEntry::new(
+                AuditActor::User,
+                AuditAction::ProviderDeleted,
+                provider_id.as_str(),
+            )
+            .with_target_name(existing.name());
+            entry.before = Some(AuditSummary::provider(&existing).build());
+            audit_log.record(entry).await;
+        }
+
         Ok(DeleteProviderResult { success: true })
     }
 }
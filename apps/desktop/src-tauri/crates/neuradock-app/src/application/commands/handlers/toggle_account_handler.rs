@@ -5,7 +5,9 @@ use std::sync::Arc;
 
 use crate::application::commands::account_commands::*;
 use crate::application::commands::command_handler::CommandHandler;
+use crate::application::services::{AuditLogService, AuditSummary};
 use neuradock_domain::account::AccountRepository;
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
 use neuradock_domain::events::account_events::AccountToggled;
 use neuradock_domain::events::EventBus;
 use neuradock_domain::shared::{AccountId, DomainError};
@@ -14,6 +16,7 @@ use neuradock_domain::shared::{AccountId, DomainError};
 pub struct ToggleAccountCommandHandler {
     account_repo: Arc<dyn AccountRepository>,
     event_bus: Arc<dyn EventBus>,
+    audit_log: Option<Arc<AuditLogService>>,
 }
 
 impl ToggleAccountCommandHandler {
@@ -21,8 +24,14 @@ impl ToggleAccountCommandHandler {
         Self {
             account_repo,
             event_bus,
+            audit_log: None,
         }
     }
+
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
 }
 
 #[async_trait]
@@ -44,6 +53,8 @@ impl CommandHandler<ToggleAccountCommand> for ToggleAccountCommandHandler {
             .await?
             .ok_or_else(|| DomainError::AccountNotFound(cmd.account_id.clone()))?;
 
+        let was_enabled = account.is_enabled();
+
         // 2. Toggle account
         account.toggle(cmd.enabled);
 
@@ -56,6 +67,18 @@ impl CommandHandler<ToggleAccountCommand> for ToggleAccountCommandHandler {
             if cmd.enabled { "enabled" } else { "disabled" }
         );
 
+        if let Some(audit_log) = &self.audit_log {
+            let mut entry = AuditEntry::new(
+                AuditActor::User,
+                AuditAction::AccountToggled,
+                account_id.as_str(),
+            )
+            .with_target_name(account.name());
+            entry.before = Some(AuditSummary::new().field("enabled", was_enabled).build());
+            entry.after = Some(AuditSummary::new().field("enabled", cmd.enabled).build());
+            audit_log.record(entry).await;
+        }
+
         // 4. Publish domain event
         let event = AccountToggled {
             account_id,
@@ -5,7 +5,9 @@ use std::sync::Arc;
 
 use crate::application::commands::account_commands::*;
 use crate::application::commands::command_handler::CommandHandler;
+use crate::application::services::{AuditLogService, AuditSummary};
 use neuradock_domain::account::{Account, AccountRepository, Credentials};
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
 use neuradock_domain::events::account_events::AccountUpdated;
 use neuradock_domain::events::EventBus;
 use neuradock_domain::session::SessionTokenExtractor;
@@ -15,6 +17,7 @@ use neuradock_domain::shared::{AccountId, DomainError, ProviderId};
 pub struct UpdateAccountCommandHandler {
     account_repo: Arc<dyn AccountRepository>,
     event_bus: Arc<dyn EventBus>,
+    audit_log: Option<Arc<AuditLogService>>,
 }
 
 impl UpdateAccountCommandHandler {
@@ -22,8 +25,14 @@ impl UpdateAccountCommandHandler {
         Self {
             account_repo,
             event_bus,
+            audit_log: None,
         }
     }
+
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
 }
 
 #[async_trait]
@@ -45,6 +54,15 @@ impl CommandHandler<UpdateAccountCommand> for UpdateAccountCommandHandler {
             .await?
             .ok_or_else(|| DomainError::AccountNotFound(cmd.account_id.clone()))?;
 
+        let before = self
+            .audit_log
+            .as_ref()
+            .map(|_| AuditSummary::account(&account).build());
+        let credentials_before = self
+            .audit_log
+            .as_ref()
+            .map(|_| AuditSummary::account_credentials(&account).build());
+
         let mut name_updated = None;
         let mut provider_updated = false;
         let mut credentials_updated = false;
@@ -104,6 +122,30 @@ impl CommandHandler<UpdateAccountCommand> for UpdateAccountCommandHandler {
 
         info!("Account updated successfully: {}", account.name());
 
+        if let Some(audit_log) = &self.audit_log {
+            let mut entry = AuditEntry::new(
+                AuditActor::User,
+                AuditAction::AccountUpdated,
+                account_id.as_str(),
+            )
+            .with_target_name(account.name());
+            entry.before = before;
+            entry.after = Some(AuditSummary::account(&account).build());
+            audit_log.record(entry).await;
+
+            if credentials_updated {
+                let mut entry = AuditEntry::new(
+                    AuditActor::User,
+                    AuditAction::CredentialsReplaced,
+                    account_id.as_str(),
+                )
+                .with_target_name(account.name());
+                entry.before = credentials_before;
+                entry.after = Some(AuditSummary::account_credentials(&account).build());
+                audit_log.record(entry).await;
+            }
+        }
+
         // 8. Publish domain event
         let event = AccountUpdated {
             account_id,
@@ -242,3 +242,28 @@ pub struct ExportAccountsInput {
     pub account_ids: Vec<String>,
     pub include_credentials: bool,
 }
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CookieImportSource {
+    /// Chromium / Chrome / Edge profile `Cookies` database
+    Chromium,
+    /// Firefox profile `cookies.sqlite` database
+    Firefox,
+    /// HAR archive exported from browser devtools
+    Har,
+    /// Netscape `cookies.txt` file
+    Netscape,
+}
+
+/// Input for importing an account from a browser cookie store or export file
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CookieSourceImportInput {
+    pub provider: String,
+    pub source: CookieImportSource,
+    pub path: String,
+    /// Defaults to "<provider name> <api_user>"
+    pub name: Option<String>,
+    /// Detected from the source or `/api/user/self` when omitted
+    pub api_user: Option<String>,
+}
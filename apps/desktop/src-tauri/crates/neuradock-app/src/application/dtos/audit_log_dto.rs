@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use neuradock_domain::audit::{AuditAction, AuditEntry, AuditLogPage, AuditLogQuery};
+use neuradock_domain::shared::DomainError;
+
+/// Page and filter of the audit log, pages start at 1
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AuditLogQueryInput {
+    pub page: u32,
+    pub page_size: u32,
+    /// e.g. "account_updated" or "global_config_written"
+    pub action: Option<String>,
+    /// Account id or CLI tool name
+    pub target: Option<String>,
+}
+
+impl AuditLogQueryInput {
+    pub fn into_query(self) -> Result<AuditLogQuery, DomainError> {
+        let mut query = AuditLogQuery::new(self.page, self.page_size)?;
+        query.action = self
+            .action
+            .filter(|action| !action.is_empty())
+            .map(|action| {
+                AuditAction::parse(&action).ok_or_else(|| {
+                    DomainError::Validation(format!("Unknown audit action: {}", action))
+                })
+            })
+            .transpose()?;
+        query.target = self.target.filter(|target| !target.is_empty());
+        Ok(query)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AuditEntryDto {
+    pub id: i64,
+    pub occurred_at: String,
+    /// "user" or "system"
+    pub actor: String,
+    pub action: String,
+    pub target: String,
+    pub target_name: Option<String>,
+    /// JSON summaries with secrets masked
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl From<&AuditEntry> for AuditEntryDto {
+    fn from(entry: &AuditEntry) -> Self {
+        Self {
+            id: entry.id.unwrap_or_default(),
+            occurred_at: entry.occurred_at.to_rfc3339(),
+            actor: entry.actor.as_str().to_string(),
+            action: entry.action.as_str().to_string(),
+            target: entry.target.clone(),
+            target_name: entry.target_name.clone(),
+            before: entry.before.clone(),
+            after: entry.after.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AuditLogPageDto {
+    pub entries: Vec<AuditEntryDto>,
+    pub total: u64,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+impl AuditLogPageDto {
+    pub fn new(page: &AuditLogPage, query: &AuditLogQuery) -> Self {
+        Self {
+            entries: page.entries.iter().map(AuditEntryDto::from).collect(),
+            total: page.total,
+            page: query.page,
+            page_size: query.page_size,
+        }
+    }
+}
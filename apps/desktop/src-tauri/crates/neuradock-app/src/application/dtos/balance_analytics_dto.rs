@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use neuradock_domain::balance_history::{AnomalySeverity, BalanceAnomalyKind, DailyConsumption};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct DailyConsumptionDto {
+    pub date: String, // YYYY-MM-DD
+    pub consumed: f64,
+    pub quota_added: f64,
+    pub current_balance: f64,
+}
+
+impl From<&DailyConsumption> for DailyConsumptionDto {
+    fn from(point: &DailyConsumption) -> Self {
+        Self {
+            date: point.date.format("%Y-%m-%d").to_string(),
+            consumed: point.consumed,
+            quota_added: point.quota_added,
+            current_balance: point.current_balance,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceAnomalyKindDto {
+    ConsumptionSpike,
+    BalanceDrop,
+}
+
+impl From<BalanceAnomalyKind> for BalanceAnomalyKindDto {
+    fn from(kind: BalanceAnomalyKind) -> Self {
+        match kind {
+            BalanceAnomalyKind::ConsumptionSpike => Self::ConsumptionSpike,
+            BalanceAnomalyKind::BalanceDrop => Self::BalanceDrop,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalySeverityDto {
+    Warning,
+    Critical,
+}
+
+impl From<AnomalySeverity> for AnomalySeverityDto {
+    fn from(severity: AnomalySeverity) -> Self {
+        match severity {
+            AnomalySeverity::Warning => Self::Warning,
+            AnomalySeverity::Critical => Self::Critical,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BalanceAnomalyDto {
+    pub account_id: String,
+    pub account_name: String,
+    pub provider_id: String,
+    pub provider_name: String,
+    pub date: String, // YYYY-MM-DD
+    pub kind: BalanceAnomalyKindDto,
+    pub severity: AnomalySeverityDto,
+    pub observed: f64,
+    pub baseline: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AccountBalanceAnalyticsDto {
+    pub account_id: String,
+    pub account_name: String,
+    pub provider_id: String,
+    pub provider_name: String,
+    pub currency: String,
+    pub current_balance: f64,
+    pub burn_rate_per_day: f64,
+    pub income_per_day: f64,
+    pub net_burn_per_day: f64,
+    pub days_until_empty: Option<f64>,
+    pub projected_empty_date: Option<String>, // YYYY-MM-DD
+    pub daily: Vec<DailyConsumptionDto>,
+    pub anomalies: Vec<BalanceAnomalyDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProviderBalanceForecastDto {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub account_count: i32,
+    pub current_balance: f64,
+    pub burn_rate_per_day: f64,
+    pub income_per_day: f64,
+    pub net_burn_per_day: f64,
+    pub days_until_empty: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BalanceAnalyticsDto {
+    pub window_days: u32,
+    pub display_currency: String,
+    pub accounts: Vec<AccountBalanceAnalyticsDto>,
+    pub providers: Vec<ProviderBalanceForecastDto>,
+    pub anomalies: Vec<BalanceAnomalyDto>,
+}
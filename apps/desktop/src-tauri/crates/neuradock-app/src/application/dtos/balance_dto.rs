@@ -24,6 +24,8 @@ impl From<Balance> for BalanceDto {
 pub struct ProviderBalanceDto {
     pub provider_id: String,
     pub provider_name: String,
+    /// Unit the provider reports in; amounts below are already normalized
+    pub unit: String,
     pub current_balance: f64,
     pub total_consumed: f64,
     pub total_quota: f64,
@@ -32,6 +34,7 @@ pub struct ProviderBalanceDto {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct BalanceStatisticsDto {
+    pub display_currency: String,
     pub providers: Vec<ProviderBalanceDto>,
     pub total_current_balance: f64,
     pub total_consumed: f64,
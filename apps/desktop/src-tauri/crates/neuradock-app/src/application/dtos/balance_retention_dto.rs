@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use neuradock_domain::balance_history::BalanceHistoryRetentionPolicy;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BalanceHistoryRetentionDto {
+    pub enabled: bool,
+    pub raw_retention_days: u32,
+    pub vacuum_after_cleanup: bool,
+    pub last_run_at: Option<String>,
+}
+
+impl From<&BalanceHistoryRetentionPolicy> for BalanceHistoryRetentionDto {
+    fn from(policy: &BalanceHistoryRetentionPolicy) -> Self {
+        Self {
+            enabled: policy.is_enabled(),
+            raw_retention_days: policy.raw_retention_days(),
+            vacuum_after_cleanup: policy.vacuum_after_cleanup(),
+            last_run_at: policy.last_run_at().map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateBalanceHistoryRetentionInput {
+    pub enabled: bool,
+    pub raw_retention_days: u32,
+    pub vacuum_after_cleanup: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BalanceHistoryMaintenanceResultDto {
+    pub cutoff_date: String, // YYYY-MM-DD
+    pub raw_rows_removed: u32,
+    pub daily_rows_written: u32,
+    pub vacuumed: bool,
+    pub ran_at: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceHistoryExportFormat {
+    Csv,
+    Json,
+}
+
+/// Input for exporting balance history
+/// Empty `account_ids` exports every account
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ExportBalanceHistoryInput {
+    pub account_ids: Vec<String>,
+    pub start_date: Option<String>, // YYYY-MM-DD, defaults to earliest
+    pub end_date: Option<String>,   // YYYY-MM-DD, defaults to today
+    pub format: BalanceHistoryExportFormat,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BalanceHistoryExportRowDto {
+    pub account_id: String,
+    pub account_name: String,
+    pub provider_id: String,
+    pub provider_name: String,
+    pub currency: String, // amounts are normalized to this currency
+    pub date: String,     // YYYY-MM-DD
+    pub recorded_at: String,
+    pub current_balance: f64,
+    pub total_consumed: f64,
+    pub total_quota: f64,
+    pub min_balance: f64,
+    pub max_balance: f64,
+    pub sample_count: u32,
+    pub downsampled: bool,
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use neuradock_infrastructure::browser::BrowserPoolMetrics;
+
+/// Usage of the shared WAF bypass browser pool
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BrowserPoolMetricsDto {
+    /// Browsers currently running
+    pub size: u32,
+    pub max_size: u32,
+    /// Browser leases currently held
+    pub in_use: u32,
+    /// Isolated browser contexts currently open
+    pub active_contexts: u32,
+    pub launched_total: u64,
+    pub reused_total: u64,
+    pub recycled_total: u64,
+    pub launch_failures: u64,
+    pub contexts_total: u64,
+}
+
+impl From<BrowserPoolMetrics> for BrowserPoolMetricsDto {
+    fn from(metrics: BrowserPoolMetrics) -> Self {
+        Self {
+            size: metrics.size as u32,
+            max_size: metrics.max_size as u32,
+            in_use: metrics.in_use as u32,
+            active_contexts: metrics.active_contexts as u32,
+            launched_total: metrics.launched_total,
+            reused_total: metrics.reused_total,
+            recycled_total: metrics.recycled_total,
+            launch_failures: metrics.launch_failures,
+            contexts_total: metrics.contexts_total,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+
+use neuradock_domain::claude_profile::{ClaudeProfile, ClaudeProfileDrift};
+
+/// Claude settings profile DTO (the token and extra env values stay in the backend)
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ClaudeProfileDto {
+    pub id: i64,
+    pub name: String,
+    pub masked_token: String,
+    pub base_url: String,
+    pub haiku_model: Option<String>,
+    pub sonnet_model: Option<String>,
+    pub opus_model: Option<String>,
+    pub extra_env_keys: Vec<String>,
+    pub is_active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl ClaudeProfileDto {
+    pub fn from_domain(profile: &ClaudeProfile, is_active: bool) -> Self {
+        let models = profile.models();
+        Self {
+            id: profile.id().map(|id| id.value()).unwrap_or_default(),
+            name: profile.name().to_string(),
+            masked_token: profile.masked_token(),
+            base_url: profile.base_url().to_string(),
+            haiku_model: models.haiku.clone(),
+            sonnet_model: models.sonnet.clone(),
+            opus_model: models.opus.clone(),
+            extra_env_keys: profile.extra_env().keys().cloned().collect(),
+            is_active,
+            created_at: profile.created_at().to_rfc3339(),
+            updated_at: profile.updated_at().to_rfc3339(),
+        }
+    }
+}
+
+/// Input for creating or updating a Claude settings profile
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SaveClaudeProfileInput {
+    pub name: String,
+    /// Required on create, `None` keeps the stored token on update
+    pub auth_token: Option<String>,
+    pub base_url: String,
+    pub haiku_model: Option<String>,
+    pub sonnet_model: Option<String>,
+    pub opus_model: Option<String>,
+    /// `None` keeps the stored extra env on update
+    pub extra_env: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaudeProfileDriftDto {
+    NoActiveProfile,
+    InSync,
+    ProfileUpdated,
+    Modified,
+    SettingsMissing,
+}
+
+/// Active profile and whether settings.json still matches it
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ClaudeProfileStatusDto {
+    pub active_profile: Option<ClaudeProfileDto>,
+    pub applied_at: Option<String>,
+    pub drift: ClaudeProfileDriftDto,
+    /// Env keys edited or removed by hand since the profile was applied
+    pub drifted_keys: Vec<String>,
+}
+
+impl ClaudeProfileStatusDto {
+    pub fn inactive() -> Self {
+        Self {
+            active_profile: None,
+            applied_at: None,
+            drift: ClaudeProfileDriftDto::NoActiveProfile,
+            drifted_keys: Vec::new(),
+        }
+    }
+
+    pub fn new(profile: &ClaudeProfile, applied_at: String, drift: ClaudeProfileDrift) -> Self {
+        let (drift, drifted_keys) = match drift {
+            ClaudeProfileDrift::InSync => (ClaudeProfileDriftDto::InSync, Vec::new()),
+            ClaudeProfileDrift::ProfileUpdated => {
+                (ClaudeProfileDriftDto::ProfileUpdated, Vec::new())
+            }
+            ClaudeProfileDrift::Modified { keys } => (ClaudeProfileDriftDto::Modified, keys),
+            ClaudeProfileDrift::SettingsMissing => {
+                (ClaudeProfileDriftDto::SettingsMissing, Vec::new())
+            }
+        };
+
+        Self {
+            active_profile: Some(ClaudeProfileDto::from_domain(profile, true)),
+            applied_at: Some(applied_at),
+            drift,
+            drifted_keys,
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
+use neuradock_domain::codex::{
+    CodexAutoSwitchEvent, CodexAutoSwitchPolicy, CodexTokenRefreshPolicy,
+    CodexUsageReminderSettings,
+};
+
 /// DTO for one Codex rate-limit window displayed in the UI
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
@@ -146,3 +151,200 @@ pub struct CodexPaymentLinkDto {
     pub country: String,
     pub currency: String,
 }
+
+/// Background Codex token refresh settings
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexTokenRefreshPolicyDto {
+    pub enabled: bool,
+    pub refresh_before_minutes: u32,
+    pub token_url: String,
+    pub last_run_at: Option<String>,
+}
+
+impl From<&CodexTokenRefreshPolicy> for CodexTokenRefreshPolicyDto {
+    fn from(policy: &CodexTokenRefreshPolicy) -> Self {
+        Self {
+            enabled: policy.is_enabled(),
+            refresh_before_minutes: policy.refresh_before_minutes(),
+            token_url: policy.token_url().to_string(),
+            last_run_at: policy.last_run_at().map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+/// `tokenUrl` of `None` or empty restores the default OpenAI endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCodexTokenRefreshPolicyInput {
+    pub enabled: bool,
+    pub refresh_before_minutes: u32,
+    pub token_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum CodexTokenRefreshOutcome {
+    Refreshed,
+    /// The refresh token was rejected and the account was marked expired
+    Expired,
+    /// Temporary failure, retried on the next run
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexTokenRefreshResultDto {
+    pub account_id: String,
+    pub email: String,
+    pub outcome: CodexTokenRefreshOutcome,
+    pub message: Option<String>,
+    pub token_expires_at: Option<String>,
+    pub auth_file_updated: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexTokenRefreshReportDto {
+    pub ran_at: String,
+    pub results: Vec<CodexTokenRefreshResultDto>,
+}
+
+/// Quota-aware Codex account switching settings and state
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexAutoSwitchPolicyDto {
+    pub enabled: bool,
+    pub threshold_percent: u32,
+    pub poll_interval_minutes: u32,
+    pub switch_back: bool,
+    /// Account to switch back to, set while switched away
+    pub home_account_id: Option<String>,
+    pub switch_back_at: Option<String>,
+    pub last_checked_at: Option<String>,
+}
+
+impl From<&CodexAutoSwitchPolicy> for CodexAutoSwitchPolicyDto {
+    fn from(policy: &CodexAutoSwitchPolicy) -> Self {
+        Self {
+            enabled: policy.is_enabled(),
+            threshold_percent: policy.threshold_percent(),
+            poll_interval_minutes: policy.poll_interval_minutes(),
+            switch_back: policy.switch_back(),
+            home_account_id: policy.home_account_id().map(|id| id.as_str().to_string()),
+            switch_back_at: policy.switch_back_at().map(|at| at.to_rfc3339()),
+            last_checked_at: policy.last_checked_at().map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCodexAutoSwitchPolicyInput {
+    pub enabled: bool,
+    pub threshold_percent: u32,
+    pub poll_interval_minutes: u32,
+    pub switch_back: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexAutoSwitchEventDto {
+    pub from_account_id: Option<String>,
+    pub from_email: String,
+    pub to_account_id: String,
+    pub to_email: String,
+    pub reason: String, // threshold_reached | switch_back
+    pub from_used_percent: Option<f64>,
+    pub switched_at: String,
+}
+
+impl From<&CodexAutoSwitchEvent> for CodexAutoSwitchEventDto {
+    fn from(event: &CodexAutoSwitchEvent) -> Self {
+        Self {
+            from_account_id: event
+                .from_account_id
+                .as_ref()
+                .map(|id| id.as_str().to_string()),
+            from_email: event.from_email.clone(),
+            to_account_id: event.to_account_id.as_str().to_string(),
+            to_email: event.to_email.clone(),
+            reason: event.reason.as_str().to_string(),
+            from_used_percent: event.from_used_percent,
+            switched_at: event.switched_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Result of one auto-switch poll
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexAutoSwitchCheckDto {
+    pub checked_at: String,
+    pub active_account_id: Option<String>,
+    pub active_email: Option<String>,
+    pub used_percent: Option<f64>,
+    pub switched: Option<CodexAutoSwitchEventDto>,
+    /// Why nothing was switched, when that was not the expected outcome
+    pub message: Option<String>,
+}
+
+/// One recorded usage value of a rate-limit window
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexUsagePointDto {
+    pub recorded_at: String,
+    pub used_percent: f64,
+    pub resets_at: Option<String>,
+}
+
+/// Usage of one rate-limit window over time, ready to plot
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexUsageSeriesDto {
+    pub window: String, // primary | secondary
+    pub window_minutes: Option<i64>,
+    pub points: Vec<CodexUsagePointDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexUsageHistoryDto {
+    pub account_id: String,
+    pub since: String,
+    pub until: String,
+    pub series: Vec<CodexUsageSeriesDto>,
+}
+
+/// Latest known state of one rate-limit window and the time left until it resets
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexUsageResetDto {
+    pub account_id: String,
+    pub email: String,
+    pub window: String, // primary | secondary
+    pub used_percent: f64,
+    pub window_minutes: Option<i64>,
+    pub resets_at: Option<String>,
+    /// 0 once the reset time has passed
+    pub seconds_until_reset: Option<i64>,
+    pub recorded_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexUsageReminderSettingsDto {
+    /// Notify when a window reaches 80% and 100%
+    pub threshold_reminders: bool,
+    /// Notify when a window that reached 80% resets
+    pub reset_reminders: bool,
+}
+
+impl From<&CodexUsageReminderSettings> for CodexUsageReminderSettingsDto {
+    fn from(settings: &CodexUsageReminderSettings) -> Self {
+        Self {
+            threshold_reminders: settings.threshold_reminders(),
+            reset_reminders: settings.reset_reminders(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ExchangeRateDto {
+    pub currency: String, // ISO 4217 code, e.g. "CNY"
+    pub units_per_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProviderBalanceUnitDto {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub unit: String, // "usd" | "cny" | "quota_units" | "tokens"
+    pub conversion_factor: f64,
+    /// Multiplier from the provider's reported amount to the display currency
+    pub normalization_factor: f64,
+}
+
+/// Currency settings DTO for frontend
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CurrencySettingsDto {
+    pub display_currency: String,
+    pub display_symbol: String,
+    pub exchange_rates: Vec<ExchangeRateDto>,
+    /// Every provider, including ones still on the default (USD, factor 1)
+    pub provider_units: Vec<ProviderBalanceUnitDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProviderBalanceUnitInput {
+    pub provider_id: String,
+    pub unit: String,
+    pub conversion_factor: f64,
+}
+
+/// Input for updating currency settings (replaces rates and provider units)
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateCurrencySettingsInput {
+    pub display_currency: String,
+    pub exchange_rates: Vec<ExchangeRateDto>,
+    pub provider_units: Vec<ProviderBalanceUnitInput>,
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use super::ProxyConnectivityResultDto;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AppDiagnosticsDto {
+    pub version: String,
+    /// `debug` or `release`
+    pub profile: String,
+    pub os: String,
+    pub arch: String,
+}
+
+/// Migration state of the database
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct DatabaseDiagnosticsDto {
+    pub latest_applied_version: Option<i64>,
+    pub applied_count: u32,
+    /// Recorded but unsuccessful migrations, `<version> <description>`
+    pub failed: Vec<String>,
+    /// Migrations of this build not applied yet, `<version> <description>`
+    pub pending: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct EntityCountsDto {
+    pub accounts: u32,
+    pub enabled_accounts: u32,
+    pub providers: u32,
+    pub codex_accounts: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ScheduledTaskDiagnosticsDto {
+    pub account_id: String,
+    pub account_name: String,
+    pub alive: bool,
+    pub last_execution: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SchedulerDiagnosticsDto {
+    pub health_check_running: bool,
+    pub tasks: Vec<ScheduledTaskDiagnosticsDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BrowserDiagnosticsDto {
+    pub available: bool,
+    pub path: Option<String>,
+}
+
+/// Age of the cached WAF cookies of a provider, cookie values are never included
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct WafCookieCacheDiagnosticsDto {
+    pub provider_id: String,
+    pub cookie_names: Vec<String>,
+    pub age_seconds: i64,
+    /// Negative once expired
+    pub expires_in_seconds: i64,
+    pub valid: bool,
+}
+
+/// Self-diagnostics gathered for bug reports, secrets masked
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct DiagnosticsReportDto {
+    pub generated_at: String,
+    pub app: AppDiagnosticsDto,
+    pub database: Option<DatabaseDiagnosticsDto>,
+    pub counts: Option<EntityCountsDto>,
+    pub scheduler: SchedulerDiagnosticsDto,
+    pub browser: BrowserDiagnosticsDto,
+    pub proxy: Vec<ProxyConnectivityResultDto>,
+    pub waf_cookies: Vec<WafCookieCacheDiagnosticsDto>,
+    /// Warning and error lines included in `logs/recent-errors.log`
+    pub recent_error_count: u32,
+    /// Sections that could not be collected and why
+    pub collection_errors: Vec<String>,
+}
+
+/// A diagnostics zip written to disk
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct DiagnosticsExportDto {
+    pub path: String,
+    pub size_bytes: u64,
+    pub report: DiagnosticsReportDto,
+}
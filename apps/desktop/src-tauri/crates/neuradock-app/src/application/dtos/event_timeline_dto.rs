@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use neuradock_domain::events::StoredEvent;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct StoredEventDto {
+    pub sequence: i64,
+    /// e.g. "account_created" or "check_in_completed"
+    pub event_name: String,
+    pub occurred_at: String,
+    pub recorded_at: String,
+    /// Event as JSON
+    pub payload: String,
+}
+
+impl From<&StoredEvent> for StoredEventDto {
+    fn from(event: &StoredEvent) -> Self {
+        Self {
+            sequence: event.sequence,
+            event_name: event.event_name.clone(),
+            occurred_at: event.occurred_at.to_rfc3339(),
+            recorded_at: event.recorded_at.to_rfc3339(),
+            payload: event.payload.clone(),
+        }
+    }
+}
+
+/// One page of an account's events, newest first
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AccountEventTimelineDto {
+    pub account_id: String,
+    pub events: Vec<StoredEventDto>,
+    /// Pass as `before_sequence` to load the next page, `None` on the last page
+    pub next_before_sequence: Option<i64>,
+}
@@ -1,3 +1,4 @@
+use neuradock_domain::independent_key::KeyValidation;
 use neuradock_domain::shared::DomainError;
 use serde::{Deserialize, Serialize};
 use specta::Type;
@@ -14,10 +15,42 @@ pub struct IndependentKeyDto {
     pub organization_id: Option<String>,
     pub description: Option<String>,
     pub is_active: bool,
+    pub last_validation: Option<IndependentKeyValidationDto>,
     pub created_at: String,
     pub updated_at: String,
 }
 
+/// Result of the last check of a key against its models endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct IndependentKeyValidationDto {
+    pub status: String, // "valid", "invalid", "error"
+    pub status_code: Option<u16>,
+    pub message: Option<String>,
+    pub latency_ms: u64,
+    pub models: Vec<String>,
+    // Only set for relays with a billing API, in USD
+    pub credit_total: Option<f64>,
+    pub credit_used: Option<f64>,
+    pub credit_remaining: Option<f64>,
+    pub validated_at: String,
+}
+
+impl From<&KeyValidation> for IndependentKeyValidationDto {
+    fn from(validation: &KeyValidation) -> Self {
+        Self {
+            status: validation.status.as_str().to_string(),
+            status_code: validation.status_code,
+            message: validation.message.clone(),
+            latency_ms: validation.latency_ms,
+            models: validation.models.clone(),
+            credit_total: validation.credit.map(|credit| credit.total),
+            credit_used: validation.credit.map(|credit| credit.used),
+            credit_remaining: validation.credit.map(|credit| credit.remaining()),
+            validated_at: validation.validated_at.to_rfc3339(),
+        }
+    }
+}
+
 impl IndependentKeyDto {
     pub fn try_from_domain(
         key: &neuradock_domain::independent_key::IndependentApiKey,
@@ -40,6 +73,7 @@ impl IndependentKeyDto {
             organization_id: key.organization_id().map(|s| s.to_string()),
             description: key.description().map(|s| s.to_string()),
             is_active: key.is_active(),
+            last_validation: key.last_validation().map(IndependentKeyValidationDto::from),
             created_at: key.created_at().to_rfc3339(),
             updated_at: key.updated_at().to_rfc3339(),
         })
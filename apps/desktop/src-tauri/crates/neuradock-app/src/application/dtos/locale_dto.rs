@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Locale backend messages such as notifications are translated to
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct LocaleSettingsDto {
+    /// Locale in effect, e.g. `zh-CN`
+    pub locale: String,
+    /// Whether the locale was saved, the frontend pushes its language when it wasn't
+    pub persisted: bool,
+    pub available_locales: Vec<String>,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Settings of the opt-in Prometheus `/metrics` endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct MetricsExporterSettingsDto {
+    pub enabled: bool,
+    /// Port on 127.0.0.1
+    pub port: u16,
+}
+
+/// Current state of the metrics exporter
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct MetricsExporterStatusDto {
+    pub enabled: bool,
+    pub port: u16,
+    pub running: bool,
+    /// Scrape URL while running, e.g. `http://127.0.0.1:9464/metrics`
+    pub endpoint: Option<String>,
+    /// Why the exporter is enabled but not running, usually a port already in use
+    pub last_error: Option<String>,
+}
@@ -15,6 +15,14 @@ pub use ai_chat_dto::*;
 mod balance_dto;
 pub use balance_dto::*;
 
+// Balance Analytics DTOs
+mod balance_analytics_dto;
+pub use balance_analytics_dto::*;
+
+// Balance History Retention DTOs
+mod balance_retention_dto;
+pub use balance_retention_dto::*;
+
 // Check-in DTOs
 mod check_in_dto;
 pub use check_in_dto::*;
@@ -35,10 +43,54 @@ pub use notification_dto::*;
 mod token_dto;
 pub use token_dto::*;
 
+// Claude Profile DTOs
+mod claude_profile_dto;
+pub use claude_profile_dto::*;
+
 // Independent Key DTOs
 mod independent_key_dto;
 pub use independent_key_dto::*;
 
+// Currency DTOs
+mod currency_dto;
+pub use currency_dto::*;
+
 // Proxy Config DTOs
 mod proxy_config_dto;
 pub use proxy_config_dto::*;
+
+// Browser pool DTOs
+mod browser_pool_dto;
+pub use browser_pool_dto::*;
+
+// Audit log DTOs
+mod audit_log_dto;
+pub use audit_log_dto::*;
+
+// Event timeline DTOs
+mod event_timeline_dto;
+pub use event_timeline_dto::*;
+
+// Metrics exporter DTOs
+mod metrics_exporter_dto;
+pub use metrics_exporter_dto::*;
+
+// Diagnostics DTOs
+mod diagnostics_dto;
+pub use diagnostics_dto::*;
+
+// Network settings DTOs
+mod network_settings_dto;
+pub use network_settings_dto::*;
+
+// Locale DTOs
+mod locale_dto;
+pub use locale_dto::*;
+
+// Model catalog DTOs
+mod model_catalog_dto;
+pub use model_catalog_dto::*;
+
+// Model routing DTOs
+mod model_routing_dto;
+pub use model_routing_dto::*;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// One change of a provider's model list, between two consecutive fetches that differed
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ModelCatalogChangeDto {
+    pub provider_id: String,
+    pub provider_name: String,
+    /// When the changed list was fetched
+    pub fetched_at: String,
+    /// When the list it is compared with was fetched, `None` for the first known list
+    pub previous_fetched_at: Option<String>,
+    /// Every model of the first known list is reported as added
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub model_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ModelCatalogSettingsDto {
+    /// Notify when a provider adds or drops a model configured in Claude Code or Codex
+    pub notify_configured_changes: bool,
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// A cached token able to serve the searched model
+///
+/// `account_id`, `token_id` and `base_url` are the arguments `configure_claude_global`
+/// and `configure_codex_global` expect.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ModelRouteDto {
+    pub account_id: String,
+    pub account_name: String,
+    pub provider_id: String,
+    pub provider_name: String,
+    pub base_url: String,
+    pub token_id: i64,
+    pub token_name: String,
+    pub masked_key: String,
+    /// Models matching the search, an exact match first
+    pub models: Vec<String>,
+    /// Whether the token's allow list decided the match instead of the provider's model list
+    pub model_limited: bool,
+    pub remain_quota: i64,
+    /// `remain_quota` in the display currency
+    pub remain_amount: f64,
+    pub unlimited_quota: bool,
+    /// In the display currency
+    pub current_balance: Option<f64>,
+    /// Share of successful recent check-ins on the provider, `None` without any
+    pub provider_health: Option<f64>,
+    /// Ranking score between 0 and 1, higher is better
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ModelRouteSearchDto {
+    pub query: String,
+    pub display_currency: String,
+    /// Best route first
+    pub routes: Vec<ModelRouteDto>,
+    /// Providers with unrestricted tokens but no cached model list, they may serve the model too
+    pub providers_without_models: Vec<String>,
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Timeouts (milliseconds) and retry policy
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct NetworkPolicyDto {
+    pub waf_wait_ms: u64,
+    pub browser_launch_ms: u64,
+    pub http_request_ms: u64,
+    pub db_query_ms: u64,
+    /// Retries after the first attempt of an HTTP request
+    pub max_retries: u32,
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub backoff_multiplier: f64,
+    /// Browser runs per WAF cookie fetch, first attempt included
+    pub waf_max_attempts: u32,
+}
+
+/// Provider values replacing the defaults, `None` keeps the default
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProviderNetworkOverrideDto {
+    pub provider_id: String,
+    pub waf_wait_ms: Option<u64>,
+    pub http_request_ms: Option<u64>,
+    pub max_retries: Option<u32>,
+    pub waf_max_attempts: Option<u32>,
+}
+
+/// Effective policy of a provider with an override
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProviderNetworkPolicyDto {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub override_values: ProviderNetworkOverrideDto,
+    pub effective: NetworkPolicyDto,
+}
+
+/// Allowed range of a setting, shown next to the input
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct NetworkSettingRangeDto {
+    pub name: String,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// Network settings DTO for frontend
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct NetworkSettingsDto {
+    pub defaults: NetworkPolicyDto,
+    pub provider_overrides: Vec<ProviderNetworkPolicyDto>,
+    pub ranges: Vec<NetworkSettingRangeDto>,
+}
+
+/// Input for updating network settings (replaces defaults and overrides)
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateNetworkSettingsInput {
+    pub defaults: NetworkPolicyDto,
+    pub provider_overrides: Vec<ProviderNetworkOverrideDto>,
+}
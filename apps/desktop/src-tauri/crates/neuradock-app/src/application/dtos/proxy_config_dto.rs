@@ -2,7 +2,11 @@ use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::str::FromStr;
 
-use neuradock_domain::proxy_config::{ProxyConfig, ProxyType};
+use neuradock_domain::proxy_config::{
+    PoolStrategy, ProxyConfig, ProxyEndpoint, ProxyOverride, ProxyPool, ProxyRoute, ProxyScope,
+    ProxyType,
+};
+use neuradock_domain::shared::DomainError;
 
 /// Proxy configuration DTO for frontend
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -11,6 +15,9 @@ pub struct ProxyConfigDto {
     pub proxy_type: String, // "http" or "socks5"
     pub host: String,
     pub port: u16,
+    pub username: Option<String>,
+    pub has_password: bool,
+    pub bypass_domains: Vec<String>,
 }
 
 impl From<&ProxyConfig> for ProxyConfigDto {
@@ -20,20 +27,13 @@ impl From<&ProxyConfig> for ProxyConfigDto {
             proxy_type: config.proxy_type().as_str().to_string(),
             host: config.host().to_string(),
             port: config.port(),
+            username: config.username().map(str::to_string),
+            has_password: config.password().is_some(),
+            bypass_domains: config.bypass_domains().to_vec(),
         }
     }
 }
 
-impl ProxyConfigDto {
-    pub fn proxy_url(&self) -> Option<String> {
-        if !self.enabled || self.host.trim().is_empty() || self.port == 0 {
-            return None;
-        }
-
-        Some(format!("{}://{}:{}", self.proxy_type, self.host.trim(), self.port))
-    }
-}
-
 /// Input for updating proxy configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct UpdateProxyConfigInput {
@@ -41,6 +41,12 @@ pub struct UpdateProxyConfigInput {
     pub proxy_type: String, // "http" or "socks5"
     pub host: String,
     pub port: u16,
+    /// `None` keeps the current credentials
+    pub username: Option<String>,
+    /// `None` keeps the stored password, empty clears it
+    pub password: Option<String>,
+    /// `None` keeps the current bypass list
+    pub bypass_domains: Option<Vec<String>>,
 }
 
 impl UpdateProxyConfigInput {
@@ -48,3 +54,174 @@ impl UpdateProxyConfigInput {
         ProxyType::from_str(&self.proxy_type).map_err(|e| e.to_string())
     }
 }
+
+/// Proxy endpoint DTO (password never leaves the backend)
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProxyEndpointDto {
+    pub proxy_type: String,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub has_password: bool,
+}
+
+impl From<&ProxyEndpoint> for ProxyEndpointDto {
+    fn from(endpoint: &ProxyEndpoint) -> Self {
+        Self {
+            proxy_type: endpoint.proxy_type.as_str().to_string(),
+            host: endpoint.host.clone(),
+            port: endpoint.port,
+            username: endpoint.username.clone(),
+            has_password: endpoint.password.is_some(),
+        }
+    }
+}
+
+/// Proxy endpoint input
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProxyEndpointInput {
+    pub proxy_type: String,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    /// `None` keeps the stored password of the same endpoint
+    pub password: Option<String>,
+}
+
+impl ProxyEndpointInput {
+    /// Build the endpoint, reusing the password of a matching stored endpoint
+    pub fn to_endpoint(&self, existing: &[ProxyEndpoint]) -> Result<ProxyEndpoint, DomainError> {
+        let password = self.password.clone().or_else(|| {
+            existing
+                .iter()
+                .find(|e| {
+                    e.host == self.host.trim()
+                        && e.port == self.port
+                        && e.username.as_deref() == self.username.as_deref().map(str::trim)
+                })
+                .and_then(|e| e.password.clone())
+        });
+
+        ProxyEndpoint::new(
+            ProxyType::from_str(&self.proxy_type)?,
+            self.host.clone(),
+            self.port,
+            self.username.clone(),
+            password,
+        )
+    }
+}
+
+/// Proxy pool DTO
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProxyPoolDto {
+    pub id: String,
+    pub name: String,
+    pub strategy: String, // "round_robin" or "sticky"
+    pub endpoints: Vec<ProxyEndpointDto>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&ProxyPool> for ProxyPoolDto {
+    fn from(pool: &ProxyPool) -> Self {
+        Self {
+            id: pool.id().to_string(),
+            name: pool.name().to_string(),
+            strategy: pool.strategy().as_str().to_string(),
+            endpoints: pool
+                .endpoints()
+                .iter()
+                .map(ProxyEndpointDto::from)
+                .collect(),
+            created_at: pool.created_at().to_rfc3339(),
+            updated_at: pool.updated_at().to_rfc3339(),
+        }
+    }
+}
+
+/// Input for creating (no id) or updating a proxy pool
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SaveProxyPoolInput {
+    pub id: Option<String>,
+    pub name: String,
+    pub strategy: String,
+    pub endpoints: Vec<ProxyEndpointInput>,
+}
+
+impl SaveProxyPoolInput {
+    pub fn to_strategy(&self) -> Result<PoolStrategy, DomainError> {
+        PoolStrategy::from_str(&self.strategy)
+    }
+}
+
+/// Per-account or per-provider proxy override DTO
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProxyOverrideDto {
+    pub scope: String,      // "account" or "provider"
+    pub scope_id: String,   // account id, provider id, or "codex"
+    pub route_kind: String, // "global", "direct", "pool" or "endpoint"
+    pub pool_id: Option<String>,
+    pub endpoint: Option<ProxyEndpointDto>,
+}
+
+impl From<&ProxyOverride> for ProxyOverrideDto {
+    fn from(proxy_override: &ProxyOverride) -> Self {
+        let (route_kind, pool_id, endpoint) = match &proxy_override.route {
+            ProxyRoute::Global => ("global", None, None),
+            ProxyRoute::Direct => ("direct", None, None),
+            ProxyRoute::Pool { pool_id } => ("pool", Some(pool_id.clone()), None),
+            ProxyRoute::Endpoint { endpoint } => {
+                ("endpoint", None, Some(ProxyEndpointDto::from(endpoint)))
+            }
+        };
+
+        Self {
+            scope: proxy_override.scope.as_str().to_string(),
+            scope_id: proxy_override.scope_id.clone(),
+            route_kind: route_kind.to_string(),
+            pool_id,
+            endpoint,
+        }
+    }
+}
+
+/// Input for setting a proxy override
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SetProxyOverrideInput {
+    pub scope: String,
+    pub scope_id: String,
+    pub route_kind: String,
+    pub pool_id: Option<String>,
+    pub endpoint: Option<ProxyEndpointInput>,
+}
+
+impl SetProxyOverrideInput {
+    pub fn to_scope(&self) -> Result<ProxyScope, DomainError> {
+        ProxyScope::from_str(&self.scope)
+    }
+}
+
+/// Input for a proxy connectivity test
+///
+/// With `pool_id` every endpoint of the pool is probed; otherwise the route
+/// resolved for `account_id`/`provider_id` (or the global proxy) is probed.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TestProxyConnectivityInput {
+    pub account_id: Option<String>,
+    pub provider_id: Option<String>,
+    pub pool_id: Option<String>,
+    pub target_url: Option<String>,
+}
+
+/// Result of probing one proxy
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProxyConnectivityResultDto {
+    /// Proxy URL with the password masked, `None` for a direct connection
+    pub proxy_url: Option<String>,
+    pub target_url: String,
+    pub success: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
-use neuradock_domain::token::{ApiToken, TokenStatus};
+use crate::application::services::token::ConfigBackup;
+use neuradock_domain::shared::DomainError;
+use neuradock_domain::token::{ApiToken, ApiTokenDraft, TokenStatus, TokenWatchSettings};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct TokenDto {
@@ -28,6 +30,96 @@ pub struct TokenDto {
     pub fetched_at: String,
 }
 
+/// Token settings sent to the provider when creating or updating a token
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ProviderTokenInput {
+    pub name: String,
+    pub remain_quota: i64,
+    pub unlimited_quota: bool,
+    /// Unix seconds, `None` never expires
+    pub expired_time: Option<i64>,
+    /// Empty leaves the token unrestricted
+    #[serde(default)]
+    pub model_limits: Vec<String>,
+}
+
+impl ProviderTokenInput {
+    pub fn into_draft(self) -> Result<ApiTokenDraft, DomainError> {
+        let expired_time = self
+            .expired_time
+            .map(|seconds| {
+                chrono::DateTime::from_timestamp(seconds, 0).ok_or_else(|| {
+                    DomainError::Validation(format!("Invalid token expiry: {}", seconds))
+                })
+            })
+            .transpose()?;
+
+        Ok(ApiTokenDraft {
+            name: self.name,
+            remain_quota: self.remain_quota,
+            unlimited_quota: self.unlimited_quota,
+            expired_time,
+            model_limits: self.model_limits,
+            group: String::new(),
+            allow_ips: String::new(),
+        })
+    }
+}
+
+/// Thresholds of the watchdog for tokens configured into Claude Code / Codex
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TokenWatchSettingsDto {
+    pub enabled: bool,
+    /// Warn this many days before a token expires
+    pub expiry_warning_days: u32,
+    /// Warn when the remaining quota falls below this, in provider quota units
+    pub low_quota_threshold: i64,
+}
+
+impl From<&TokenWatchSettings> for TokenWatchSettingsDto {
+    fn from(settings: &TokenWatchSettings) -> Self {
+        Self {
+            enabled: settings.enabled(),
+            expiry_warning_days: settings.expiry_warning_days(),
+            low_quota_threshold: settings.low_quota_threshold(),
+        }
+    }
+}
+
+/// Last watchdog check of the token configured into a CLI tool
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TokenWatchStatusDto {
+    /// "claude" or "codex"
+    pub tool: String,
+    pub account_id: String,
+    pub account_name: String,
+    pub token_id: i64,
+    /// `None` when the token is gone from the provider
+    pub token_name: Option<String>,
+    /// "expiring_soon", "expired", "low_quota" or "key_mismatch"
+    pub alerts: Vec<String>,
+    pub checked_at: Option<String>,
+    pub last_error: Option<String>,
+}
+
+/// Whether a token can use a model, checked before configuring a CLI tool with it
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct TokenModelCheckDto {
+    pub model: Option<String>,
+    /// `false` when neither the token's allow list nor the provider's cached model list is known
+    pub known: bool,
+    /// Whether the token's allow list decides, configuring another model is refused
+    pub enforced: bool,
+    /// `true` as well when no model was given or the models are unknown
+    pub available: bool,
+    /// Closest usable models when the model isn't available
+    pub suggestions: Vec<String>,
+    /// Models Claude Code's slots would be configured with, `None` leaves a slot unchanged
+    pub claude_haiku_model: Option<String>,
+    pub claude_sonnet_model: Option<String>,
+    pub claude_opus_model: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct ProviderNodeDto {
     pub id: String,
@@ -35,6 +127,34 @@ pub struct ProviderNodeDto {
     pub base_url: String,
 }
 
+/// Backup of CLI config files taken before NeuraDock modified them
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ConfigBackupDto {
+    pub id: String,
+    pub created_at: String,
+    pub operation: String,
+    /// Files captured, missing ones are deleted on restore
+    pub files: Vec<String>,
+    /// Set once restored, undo skips it then
+    pub restored_at: Option<String>,
+}
+
+impl From<&ConfigBackup> for ConfigBackupDto {
+    fn from(backup: &ConfigBackup) -> Self {
+        Self {
+            id: backup.id.clone(),
+            created_at: backup.created_at.to_rfc3339(),
+            operation: backup.operation.clone(),
+            files: backup
+                .files
+                .iter()
+                .map(|file| file.path.display().to_string())
+                .collect(),
+            restored_at: backup.restored_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
 impl TokenDto {
     pub fn from_domain(token: &ApiToken, account_name: String, provider_name: String) -> Self {
         let status_text = match token.status() {
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDate, Utc};
+use log::info;
+
+use crate::application::dtos::{
+    AccountBalanceAnalyticsDto, BalanceAnalyticsDto, BalanceAnomalyDto, DailyConsumptionDto,
+    ProviderBalanceForecastDto,
+};
+use neuradock_domain::account::{Account, AccountRepository};
+use neuradock_domain::balance_history::{
+    burn_rate, daily_consumption, detect_anomalies, AnomalyThresholds, BalanceHistoryDailySummary,
+    BalanceHistoryRepository, BurnRate,
+};
+use neuradock_domain::check_in::ProviderRepository;
+use neuradock_domain::currency::{CurrencySettings, CurrencySettingsRepository};
+use neuradock_domain::shared::{AccountId, DomainError};
+
+const DEFAULT_WINDOW_DAYS: u32 = 30;
+const MAX_WINDOW_DAYS: u32 = 365;
+/// Burn rate (and therefore the forecast) only looks at the most recent week
+const BURN_RATE_DAYS: u32 = 7;
+
+/// Consumption analytics built on top of `balance_history` snapshots
+pub struct BalanceAnalyticsQueryService {
+    account_repo: Arc<dyn AccountRepository>,
+    provider_repo: Arc<dyn ProviderRepository>,
+    balance_history_repo: Arc<dyn BalanceHistoryRepository>,
+    currency_repo: Arc<dyn CurrencySettingsRepository>,
+    thresholds: AnomalyThresholds,
+}
+
+impl BalanceAnalyticsQueryService {
+    pub fn new(
+        account_repo: Arc<dyn AccountRepository>,
+        provider_repo: Arc<dyn ProviderRepository>,
+        balance_history_repo: Arc<dyn BalanceHistoryRepository>,
+        currency_repo: Arc<dyn CurrencySettingsRepository>,
+    ) -> Self {
+        Self {
+            account_repo,
+            provider_repo,
+            balance_history_repo,
+            currency_repo,
+            thresholds: AnomalyThresholds::default(),
+        }
+    }
+
+    /// Burn rate, forecast and anomalies for a single account
+    pub async fn get_account_analytics(
+        &self,
+        account_id: &str,
+        window_days: Option<u32>,
+    ) -> Result<AccountBalanceAnalyticsDto, DomainError> {
+        let window_days = validate_window(window_days)?;
+        let account = self
+            .account_repo
+            .find_by_id(&AccountId::from_string(account_id))
+            .await?
+            .ok_or_else(|| DomainError::AccountNotFound(account_id.to_string()))?;
+
+        let provider_name = self
+            .provider_repo
+            .find_by_id(account.provider_id())
+            .await?
+            .map(|p| p.name().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let currency = self.currency_repo.get().await?;
+
+        let (analytics, _) = self
+            .analyze_account(&account, provider_name, &currency, window_days)
+            .await?;
+        Ok(analytics)
+    }
+
+    /// Analytics for every enabled account, with per-provider forecasts and
+    /// all anomalies sorted newest first. Amounts are in the display currency
+    /// so providers reporting in different units can be compared.
+    pub async fn get_balance_analytics(
+        &self,
+        window_days: Option<u32>,
+    ) -> Result<BalanceAnalyticsDto, DomainError> {
+        let window_days = validate_window(window_days)?;
+        let accounts = self.account_repo.find_enabled().await?;
+        let currency = self.currency_repo.get().await?;
+        let provider_names = self
+            .provider_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|p| (p.id().as_str().to_string(), p.name().to_string()))
+            .collect::<HashMap<_, _>>();
+
+        let mut account_stats = Vec::with_capacity(accounts.len());
+        let mut provider_rates: HashMap<String, (ProviderBalanceForecastDto, BurnRate)> =
+            HashMap::new();
+
+        for account in &accounts {
+            let provider_id = account.provider_id().as_str().to_string();
+            let provider_name = provider_names
+                .get(&provider_id)
+                .cloned()
+                .unwrap_or_else(|| "Unknown".to_string());
+
+            let (analytics, rate) = self
+                .analyze_account(account, provider_name.clone(), &currency, window_days)
+                .await?;
+
+            let (forecast, total_rate) = provider_rates.entry(provider_id.clone()).or_insert((
+                ProviderBalanceForecastDto {
+                    provider_id,
+                    provider_name,
+                    account_count: 0,
+                    current_balance: 0.0,
+                    burn_rate_per_day: 0.0,
+                    income_per_day: 0.0,
+                    net_burn_per_day: 0.0,
+                    days_until_empty: None,
+                },
+                BurnRate::default(),
+            ));
+            forecast.account_count += 1;
+            forecast.current_balance += analytics.current_balance;
+            total_rate.consumed_per_day += rate.consumed_per_day;
+            total_rate.income_per_day += rate.income_per_day;
+            total_rate.days_observed = total_rate.days_observed.max(rate.days_observed);
+
+            account_stats.push(analytics);
+        }
+
+        let mut providers = provider_rates
+            .into_values()
+            .map(|(mut forecast, rate)| {
+                forecast.burn_rate_per_day = rate.consumed_per_day;
+                forecast.income_per_day = rate.income_per_day;
+                forecast.net_burn_per_day = rate.net_per_day();
+                forecast.days_until_empty = rate.days_until_empty(forecast.current_balance);
+                forecast
+            })
+            .collect::<Vec<_>>();
+        providers.sort_by(|a, b| a.provider_name.cmp(&b.provider_name));
+
+        let mut anomalies = account_stats
+            .iter()
+            .flat_map(|a| a.anomalies.iter().cloned())
+            .collect::<Vec<_>>();
+        anomalies.sort_by(|a, b| b.date.cmp(&a.date).then(b.severity.cmp(&a.severity)));
+
+        info!(
+            "[balance_analytics] window={}d accounts={} providers={} anomalies={}",
+            window_days,
+            account_stats.len(),
+            providers.len(),
+            anomalies.len()
+        );
+
+        Ok(BalanceAnalyticsDto {
+            window_days,
+            display_currency: currency.display_currency().to_string(),
+            accounts: account_stats,
+            providers,
+            anomalies,
+        })
+    }
+
+    async fn analyze_account(
+        &self,
+        account: &Account,
+        provider_name: String,
+        currency: &CurrencySettings,
+        window_days: u32,
+    ) -> Result<(AccountBalanceAnalyticsDto, BurnRate), DomainError> {
+        let today = Utc::now().date_naive();
+        // One extra day so the first day in the window has a predecessor to diff against
+        let start_date = today - Duration::days(window_days as i64);
+
+        let summaries = self
+            .balance_history_repo
+            .list_daily_summaries_in_range(account.id(), start_date, today)
+            .await?;
+        let factor = currency.normalization_factor(account.provider_id().as_str());
+        let summaries = summaries
+            .iter()
+            .map(|s| {
+                BalanceHistoryDailySummary::restore(
+                    s.check_in_date(),
+                    s.daily_total_quota() * factor,
+                    s.daily_balance() * factor,
+                    s.daily_consumed() * factor,
+                )
+            })
+            .collect::<Vec<_>>();
+        let points = daily_consumption(&summaries);
+        let rate = burn_rate(&points, today, BURN_RATE_DAYS.min(window_days));
+
+        let current_balance = summaries
+            .last()
+            .map(|s| s.daily_balance())
+            .or_else(|| account.current_balance().map(|b| b * factor))
+            .unwrap_or(0.0);
+        let days_until_empty = rate.days_until_empty(current_balance);
+
+        let account_id = account.id().as_str().to_string();
+        let provider_id = account.provider_id().as_str().to_string();
+        let anomalies = detect_anomalies(&points, &self.thresholds)
+            .into_iter()
+            .map(|anomaly| BalanceAnomalyDto {
+                account_id: account_id.clone(),
+                account_name: account.name().to_string(),
+                provider_id: provider_id.clone(),
+                provider_name: provider_name.clone(),
+                date: anomaly.date.format("%Y-%m-%d").to_string(),
+                kind: anomaly.kind.into(),
+                severity: anomaly.severity.into(),
+                observed: anomaly.observed,
+                baseline: anomaly.baseline,
+            })
+            .collect();
+
+        let analytics = AccountBalanceAnalyticsDto {
+            account_id,
+            account_name: account.name().to_string(),
+            provider_id,
+            provider_name,
+            currency: currency.display_currency().to_string(),
+            current_balance,
+            burn_rate_per_day: rate.consumed_per_day,
+            income_per_day: rate.income_per_day,
+            net_burn_per_day: rate.net_per_day(),
+            days_until_empty,
+            projected_empty_date: days_until_empty.and_then(|days| projected_date(today, days)),
+            daily: points.iter().map(DailyConsumptionDto::from).collect(),
+            anomalies,
+        };
+
+        Ok((analytics, rate))
+    }
+}
+
+fn validate_window(window_days: Option<u32>) -> Result<u32, DomainError> {
+    let window_days = window_days.unwrap_or(DEFAULT_WINDOW_DAYS);
+    if window_days == 0 || window_days > MAX_WINDOW_DAYS {
+        return Err(DomainError::Validation(format!(
+            "Window must be between 1 and {} days",
+            MAX_WINDOW_DAYS
+        )));
+    }
+    Ok(window_days)
+}
+
+fn projected_date(today: NaiveDate, days: f64) -> Option<String> {
+    if !days.is_finite() || days > (MAX_WINDOW_DAYS * 10) as f64 {
+        return None;
+    }
+    today
+        .checked_add_signed(Duration::days(days.floor() as i64))
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_window_defaults_and_bounds() {
+        assert_eq!(validate_window(None).unwrap(), DEFAULT_WINDOW_DAYS);
+        assert_eq!(validate_window(Some(7)).unwrap(), 7);
+        assert!(validate_window(Some(0)).is_err());
+        assert!(validate_window(Some(MAX_WINDOW_DAYS + 1)).is_err());
+    }
+
+    #[test]
+    fn test_projected_date() {
+        let today = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        assert_eq!(projected_date(today, 9.7), Some("2026-01-10".to_string()));
+        assert_eq!(projected_date(today, f64::INFINITY), None);
+    }
+}
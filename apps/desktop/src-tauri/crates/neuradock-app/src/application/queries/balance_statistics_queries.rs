@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use neuradock_domain::account::AccountRepository;
 use neuradock_domain::check_in::ProviderRepository;
+use neuradock_domain::currency::CurrencySettingsRepository;
 use neuradock_domain::shared::DomainError;
 
 use crate::application::dtos::{BalanceStatisticsDto, ProviderBalanceDto};
@@ -12,6 +13,7 @@ pub struct BalanceStatisticsQueryService {
     account_repo: Arc<dyn AccountRepository>,
     provider_repo: Arc<dyn ProviderRepository>,
     balance_history_service: Arc<BalanceHistoryService>,
+    currency_repo: Arc<dyn CurrencySettingsRepository>,
 }
 
 impl BalanceStatisticsQueryService {
@@ -19,16 +21,20 @@ impl BalanceStatisticsQueryService {
         account_repo: Arc<dyn AccountRepository>,
         provider_repo: Arc<dyn ProviderRepository>,
         balance_history_service: Arc<BalanceHistoryService>,
+        currency_repo: Arc<dyn CurrencySettingsRepository>,
     ) -> Self {
         Self {
             account_repo,
             provider_repo,
             balance_history_service,
+            currency_repo,
         }
     }
 
+    /// Balance totals per provider and overall, normalized to the display currency
     pub async fn get_balance_statistics(&self) -> Result<BalanceStatisticsDto, DomainError> {
         let accounts = self.account_repo.find_enabled().await?;
+        let currency = self.currency_repo.get().await?;
         let providers = self.provider_repo.find_all().await?;
         let providers_by_id = providers
             .iter()
@@ -76,6 +82,9 @@ impl BalanceStatisticsQueryService {
             };
 
             let provider_id = account.provider_id().as_str();
+            let factor = currency.normalization_factor(provider_id);
+            let (current_balance, consumed, income) =
+                (current_balance * factor, consumed * factor, income * factor);
             let provider_name = providers_by_id
                 .get(provider_id)
                 .cloned()
@@ -87,6 +96,7 @@ impl BalanceStatisticsQueryService {
                     .or_insert(ProviderBalanceDto {
                         provider_id: provider_id.to_string(),
                         provider_name,
+                        unit: currency.unit_for(provider_id).unit.as_str().to_string(),
                         current_balance: 0.0,
                         total_consumed: 0.0,
                         total_quota: 0.0,
@@ -104,6 +114,7 @@ impl BalanceStatisticsQueryService {
         }
 
         Ok(BalanceStatisticsDto {
+            display_currency: currency.display_currency().to_string(),
             providers: provider_stats.into_values().collect(),
             total_current_balance,
             total_consumed,
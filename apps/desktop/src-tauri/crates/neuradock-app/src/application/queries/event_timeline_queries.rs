@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::application::dtos::{AccountEventTimelineDto, StoredEventDto};
+use neuradock_domain::events::EventStore;
+use neuradock_domain::shared::DomainError;
+
+const DEFAULT_PAGE_SIZE: u32 = 50;
+const MAX_PAGE_SIZE: u32 = 200;
+
+/// Event timeline query service
+/// Reads an account's history from the domain event store
+pub struct EventTimelineQueryService {
+    event_store: Arc<dyn EventStore>,
+}
+
+impl EventTimelineQueryService {
+    pub fn new(event_store: Arc<dyn EventStore>) -> Self {
+        Self { event_store }
+    }
+
+    /// Events of one account, newest first, paged by sequence
+    pub async fn account_timeline(
+        &self,
+        account_id: &str,
+        before_sequence: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<AccountEventTimelineDto, DomainError> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+        let events = self
+            .event_store
+            .aggregate_timeline(account_id, before_sequence, limit)
+            .await?;
+
+        let next_before_sequence = if events.len() == limit as usize {
+            events.last().map(|event| event.sequence)
+        } else {
+            None
+        };
+
+        Ok(AccountEventTimelineDto {
+            account_id: account_id.to_string(),
+            events: events.iter().map(StoredEventDto::from).collect(),
+            next_before_sequence,
+        })
+    }
+}
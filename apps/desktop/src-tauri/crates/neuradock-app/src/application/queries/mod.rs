@@ -1,7 +1,13 @@
 mod account_queries;
+mod balance_analytics_queries;
 mod balance_statistics_queries;
 mod check_in_streak_queries;
+mod event_timeline_queries;
+mod model_routing_queries;
 
 pub use account_queries::AccountQueryService;
+pub use balance_analytics_queries::BalanceAnalyticsQueryService;
 pub use balance_statistics_queries::BalanceStatisticsQueryService;
 pub use check_in_streak_queries::CheckInStreakQueries;
+pub use event_timeline_queries::EventTimelineQueryService;
+pub use model_routing_queries::ModelRoutingQueryService;
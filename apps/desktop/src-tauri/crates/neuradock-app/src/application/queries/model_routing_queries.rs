@@ -0,0 +1,323 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use crate::application::dtos::{ModelRouteDto, ModelRouteSearchDto};
+use neuradock_domain::account::{Account, AccountRepository};
+use neuradock_domain::check_in::ProviderRepository;
+use neuradock_domain::currency::{CurrencySettings, CurrencySettingsRepository};
+use neuradock_domain::events::account_events::CheckInCompleted;
+use neuradock_domain::events::{EventStore, ReplayableEvent};
+use neuradock_domain::provider_models::ProviderModelsRepository;
+use neuradock_domain::shared::DomainError;
+use neuradock_domain::token::{ApiToken, TokenRepository};
+
+const DEFAULT_LIMIT: u32 = 20;
+const MAX_LIMIT: u32 = 100;
+/// Recent check-ins per account that make up a provider's health
+const HEALTH_CHECK_INS_PER_ACCOUNT: usize = 5;
+/// Timeline events read per account to find those check-ins among other events
+const HEALTH_TIMELINE_EVENTS: u32 = 50;
+
+/// Token quota units per unit of the balance the provider reports
+const QUOTA_PER_UNIT: f64 = 500_000.0;
+/// Remaining quota scoring half of the quota weight, in USD
+const REMAINING_FOR_HALF_SCORE: f64 = 10.0;
+/// Account balance scoring half of the balance weight, in USD
+const BALANCE_FOR_HALF_SCORE: f64 = 10.0;
+const QUOTA_WEIGHT: f64 = 0.5;
+const HEALTH_WEIGHT: f64 = 0.3;
+const BALANCE_WEIGHT: f64 = 0.2;
+/// Health assumed for a provider without recorded check-ins
+const UNKNOWN_HEALTH: f64 = 0.5;
+
+/// Model routing query service
+/// Finds the cached tokens of enabled accounts that can serve a model, best first
+pub struct ModelRoutingQueryService {
+    account_repo: Arc<dyn AccountRepository>,
+    provider_repo: Arc<dyn ProviderRepository>,
+    token_repo: Arc<dyn TokenRepository>,
+    provider_models_repo: Arc<dyn ProviderModelsRepository>,
+    event_store: Arc<dyn EventStore>,
+    currency_repo: Arc<dyn CurrencySettingsRepository>,
+}
+
+impl ModelRoutingQueryService {
+    pub fn new(
+        account_repo: Arc<dyn AccountRepository>,
+        provider_repo: Arc<dyn ProviderRepository>,
+        token_repo: Arc<dyn TokenRepository>,
+        provider_models_repo: Arc<dyn ProviderModelsRepository>,
+        event_store: Arc<dyn EventStore>,
+        currency_repo: Arc<dyn CurrencySettingsRepository>,
+    ) -> Self {
+        Self {
+            account_repo,
+            provider_repo,
+            token_repo,
+            provider_models_repo,
+            event_store,
+            currency_repo,
+        }
+    }
+
+    /// Routes for models whose name contains `query`, case-insensitive
+    ///
+    /// Only active tokens with quota left are considered. Routes with an exact match come
+    /// first, then routes are ordered by score: remaining quota, provider health and
+    /// account balance.
+    pub async fn search(
+        &self,
+        query: &str,
+        limit: Option<u32>,
+    ) -> Result<ModelRouteSearchDto, DomainError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(DomainError::Validation(
+                "Model search query cannot be empty".to_string(),
+            ));
+        }
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT) as usize;
+        let currency = self.currency_repo.get().await?;
+
+        let mut accounts_by_provider: HashMap<String, Vec<Account>> = HashMap::new();
+        for account in self.account_repo.find_enabled().await? {
+            accounts_by_provider
+                .entry(account.provider_id().as_str().to_string())
+                .or_default()
+                .push(account);
+        }
+
+        let mut routes = Vec::new();
+        let mut providers_without_models = BTreeSet::new();
+        for provider in self.provider_repo.find_all().await? {
+            let Some(accounts) = accounts_by_provider.get(provider.id().as_str()) else {
+                continue;
+            };
+            let catalog = self
+                .provider_models_repo
+                .find_by_provider(provider.id().as_str())
+                .await?
+                .map(|models| models.models)
+                .unwrap_or_default();
+
+            let mut provider_routes = Vec::new();
+            for account in accounts {
+                for token in self.token_repo.find_by_account(account.id()).await? {
+                    if !has_capacity(&token) {
+                        continue;
+                    }
+                    let model_limited = token.has_model_allow_list();
+                    let models = matching_models(&token.servable_models(&catalog), query);
+                    if models.is_empty() {
+                        if catalog.is_empty() && !model_limited {
+                            providers_without_models.insert(provider.name().to_string());
+                        }
+                        continue;
+                    }
+                    provider_routes.push((account, token, models, model_limited));
+                }
+            }
+            if provider_routes.is_empty() {
+                continue;
+            }
+
+            let health = self.provider_health(accounts).await?;
+            let provider_id = provider.id().as_str();
+            for (account, token, models, model_limited) in provider_routes {
+                let remain_amount =
+                    currency.normalize(provider_id, token.remain_quota() as f64 / QUOTA_PER_UNIT);
+                let current_balance = account
+                    .current_balance()
+                    .map(|balance| currency.normalize(provider_id, balance));
+                let score = route_score(
+                    &token,
+                    in_usd(&currency, remain_amount),
+                    health,
+                    current_balance.map(|balance| in_usd(&currency, balance)),
+                );
+                routes.push(ModelRouteDto {
+                    account_id: account.id().as_str().to_string(),
+                    account_name: account.name().to_string(),
+                    provider_id: provider.id().as_str().to_string(),
+                    provider_name: provider.name().to_string(),
+                    base_url: provider.domain().to_string(),
+                    token_id: token.id().value(),
+                    token_name: token.name().to_string(),
+                    masked_key: token.masked_key(),
+                    models,
+                    model_limited,
+                    remain_quota: token.remain_quota(),
+                    remain_amount,
+                    unlimited_quota: token.unlimited_quota(),
+                    current_balance,
+                    provider_health: health,
+                    score,
+                });
+            }
+        }
+
+        routes.sort_by(|a, b| {
+            let exact = |route: &ModelRouteDto| route.models[0].eq_ignore_ascii_case(query);
+            exact(b)
+                .cmp(&exact(a))
+                .then(b.score.total_cmp(&a.score))
+                .then_with(|| a.account_name.cmp(&b.account_name))
+        });
+        routes.truncate(limit);
+
+        Ok(ModelRouteSearchDto {
+            query: query.to_string(),
+            display_currency: currency.display_currency().to_string(),
+            routes,
+            providers_without_models: providers_without_models.into_iter().collect(),
+        })
+    }
+
+    /// Share of successful recent check-ins across the provider's accounts
+    async fn provider_health(&self, accounts: &[Account]) -> Result<Option<f64>, DomainError> {
+        let mut outcomes = Vec::new();
+        for account in accounts {
+            let events = self
+                .event_store
+                .aggregate_timeline(account.id().as_str(), None, HEALTH_TIMELINE_EVENTS)
+                .await?;
+            outcomes.extend(
+                events
+                    .iter()
+                    .filter(|event| event.event_name == CheckInCompleted::EVENT_NAME)
+                    .filter_map(|event| {
+                        serde_json::from_str::<CheckInCompleted>(&event.payload).ok()
+                    })
+                    .take(HEALTH_CHECK_INS_PER_ACCOUNT)
+                    .map(|event| event.success),
+            );
+        }
+
+        if outcomes.is_empty() {
+            return Ok(None);
+        }
+        let succeeded = outcomes.iter().filter(|success| **success).count();
+        Ok(Some(succeeded as f64 / outcomes.len() as f64))
+    }
+}
+
+fn has_capacity(token: &ApiToken) -> bool {
+    token.is_active() && (token.unlimited_quota() || token.remain_quota() > 0)
+}
+
+/// Models containing `query`, case-insensitive, an exact match first
+fn matching_models(models: &[String], query: &str) -> Vec<String> {
+    let query = query.to_lowercase();
+    let mut matches = models
+        .iter()
+        .filter(|model| model.to_lowercase().contains(&query))
+        .cloned()
+        .collect::<Vec<_>>();
+    matches.sort_by(|a, b| {
+        let exact = |model: &String| model.to_lowercase() == query;
+        exact(b).cmp(&exact(a)).then_with(|| a.cmp(b))
+    });
+    matches.dedup();
+    matches
+}
+
+/// Display currency amount converted back to USD, so scores don't depend on the display
+/// currency
+fn in_usd(currency: &CurrencySettings, amount: f64) -> f64 {
+    let rate = currency
+        .exchange_rates()
+        .get(currency.display_currency())
+        .copied()
+        .unwrap_or(1.0);
+    amount / rate
+}
+
+/// Weighted score between 0 and 1, each part saturates instead of growing without bound
+///
+/// `remaining` and `balance` are in USD.
+fn route_score(token: &ApiToken, remaining: f64, health: Option<f64>, balance: Option<f64>) -> f64 {
+    let quota = if token.unlimited_quota() {
+        1.0
+    } else {
+        saturate(remaining, REMAINING_FOR_HALF_SCORE)
+    };
+    let health = health.unwrap_or(UNKNOWN_HEALTH);
+    let balance = saturate(balance.unwrap_or(0.0), BALANCE_FOR_HALF_SCORE);
+
+    QUOTA_WEIGHT * quota + HEALTH_WEIGHT * health + BALANCE_WEIGHT * balance
+}
+
+/// Maps 0.. to 0..1, reaching 0.5 at `half`
+fn saturate(value: f64, half: f64) -> f64 {
+    let value = value.max(0.0);
+    value / (value + half)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use neuradock_domain::shared::AccountId;
+    use neuradock_domain::token::{ApiTokenConfig, TokenId, TokenStatus};
+
+    fn token(remain_quota: i64, unlimited_quota: bool) -> ApiToken {
+        ApiToken::new(
+            TokenId::new(1),
+            AccountId::from_string("acc-1"),
+            ApiTokenConfig {
+                name: "test".to_string(),
+                key: "sk-test".to_string(),
+                status: TokenStatus::Enabled,
+                used_quota: 0,
+                remain_quota,
+                unlimited_quota,
+                expired_time: None,
+                model_limits_enabled: false,
+                model_limits: None,
+                group: String::new(),
+                allow_ips: String::new(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_matching_models_puts_exact_match_first() {
+        let models = vec![
+            "claude-sonnet-4-20250514".to_string(),
+            "gpt-4o".to_string(),
+            "Claude-Sonnet-4".to_string(),
+        ];
+
+        assert_eq!(
+            matching_models(&models, "claude-sonnet-4"),
+            vec!["Claude-Sonnet-4", "claude-sonnet-4-20250514"]
+        );
+        assert!(matching_models(&models, "opus").is_empty());
+    }
+
+    #[test]
+    fn test_route_score_ranking() {
+        let unlimited = route_score(&token(0, true), 0.0, Some(1.0), Some(50.0));
+        let rich = route_score(&token(50_000_000, false), 100.0, Some(1.0), Some(50.0));
+        let poor = route_score(&token(100_000, false), 0.2, Some(1.0), Some(50.0));
+        let failing = route_score(&token(50_000_000, false), 100.0, Some(0.0), Some(50.0));
+        let unknown = route_score(&token(50_000_000, false), 100.0, None, Some(50.0));
+
+        assert!(unlimited > rich);
+        assert!(rich > poor);
+        assert!(rich > unknown && unknown > failing);
+        assert!((0.0..=1.0).contains(&unlimited));
+        assert!(!has_capacity(&token(0, false)));
+        assert!(has_capacity(&token(0, true)));
+    }
+
+    #[test]
+    fn test_in_usd_undoes_display_currency() {
+        let mut currency = CurrencySettings::default();
+        let rates = currency.exchange_rates().clone();
+        currency.update("CNY", rates, Default::default()).unwrap();
+
+        let balance = currency.normalize("anyrouter", 10.0);
+        assert!((balance - 72.0).abs() < 1e-9);
+        assert!((in_usd(&currency, balance) - 10.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,191 @@
+use log::{info, warn};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use neuradock_domain::check_in::{Provider, ProviderRepository};
+use neuradock_domain::shared::{DomainError, ProviderId};
+use neuradock_domain::waf_cookies::WafCookiesRepository;
+use neuradock_infrastructure::browser::BrowserPool;
+use neuradock_infrastructure::cookie_import::{
+    host_from_domain, parse_har, parse_netscape_cookies, read_chromium_cookies,
+    read_firefox_cookies, CookieImport,
+};
+use neuradock_infrastructure::http::HttpClient;
+
+use crate::application::dtos::{CookieImportSource, CookieSourceImportInput, ImportAccountInput};
+use crate::application::services::waf_cookie_manager::WafCookieManager;
+use crate::application::services::ProxyRoutingService;
+
+/// Turns browser cookie stores and exported cookie files into account imports
+///
+/// The resulting `ImportAccountInput`s go through the regular batch
+/// create-or-update path, so imported accounts behave like hand-written JSON.
+pub struct AccountCookieImportService {
+    provider_repo: Arc<dyn ProviderRepository>,
+    waf_cookies_repo: Arc<dyn WafCookiesRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
+    headless_browser: bool,
+    browser_pool: Option<Arc<BrowserPool>>,
+}
+
+impl AccountCookieImportService {
+    pub fn new(
+        provider_repo: Arc<dyn ProviderRepository>,
+        waf_cookies_repo: Arc<dyn WafCookiesRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
+        headless_browser: bool,
+    ) -> Self {
+        Self {
+            provider_repo,
+            waf_cookies_repo,
+            proxy_routing,
+            headless_browser,
+            browser_pool: None,
+        }
+    }
+
+    pub fn with_browser_pool(mut self, pool: Arc<BrowserPool>) -> Self {
+        self.browser_pool = Some(pool);
+        self
+    }
+
+    /// Read cookies from the source and resolve the account's `api_user`
+    pub async fn prepare_import(
+        &self,
+        input: &CookieSourceImportInput,
+    ) -> Result<ImportAccountInput, DomainError> {
+        let provider = self
+            .provider_repo
+            .find_by_id(&ProviderId::from_string(&input.provider))
+            .await?
+            .ok_or_else(|| DomainError::ProviderNotFound(input.provider.clone()))?;
+
+        let import = self.read_source(input, &provider).await?;
+        if import.cookies.is_empty() {
+            let mut message = format!(
+                "No cookies for {} found in {}",
+                host_from_domain(provider.domain()),
+                input.path
+            );
+            if import.skipped_encrypted > 0 {
+                message.push_str(&format!(
+                    " ({} encrypted cookie(s) skipped, only unencrypted profiles are supported)",
+                    import.skipped_encrypted
+                ));
+            }
+            return Err(DomainError::Validation(message));
+        }
+
+        let api_user = match input
+            .api_user
+            .as_deref()
+            .map(str::trim)
+            .filter(|api_user| !api_user.is_empty())
+        {
+            Some(api_user) => api_user.to_string(),
+            None => match import.api_user {
+                Some(api_user) => api_user,
+                None => self.detect_api_user(&provider, &import.cookies).await?,
+            },
+        };
+
+        let name = input
+            .name
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} {}", provider.name(), api_user));
+
+        info!(
+            "Prepared cookie import for {} from {:?} ({} cookies)",
+            name,
+            input.source,
+            import.cookies.len()
+        );
+
+        Ok(ImportAccountInput {
+            name,
+            provider: provider.id().as_str().to_string(),
+            cookies: import.cookies,
+            api_user,
+        })
+    }
+
+    async fn read_source(
+        &self,
+        input: &CookieSourceImportInput,
+        provider: &Provider,
+    ) -> Result<CookieImport, DomainError> {
+        let path = Path::new(input.path.trim());
+        let result = match input.source {
+            CookieImportSource::Chromium => read_chromium_cookies(path, provider.domain()).await,
+            CookieImportSource::Firefox => read_firefox_cookies(path, provider.domain()).await,
+            CookieImportSource::Har | CookieImportSource::Netscape => {
+                let content = tokio::fs::read_to_string(path).await.map_err(|e| {
+                    DomainError::Validation(format!("Failed to read {}: {}", path.display(), e))
+                })?;
+                if input.source == CookieImportSource::Har {
+                    parse_har(
+                        &content,
+                        provider.domain(),
+                        provider.api_user_key(),
+                        provider.user_info_path(),
+                    )
+                } else {
+                    parse_netscape_cookies(&content, provider.domain())
+                }
+            }
+        };
+
+        result.map_err(|e| DomainError::Validation(format!("{:#}", e)))
+    }
+
+    /// Ask the provider's user info endpoint which user the cookies belong to
+    async fn detect_api_user(
+        &self,
+        provider: &Provider,
+        cookies: &HashMap<String, String>,
+    ) -> Result<String, DomainError> {
+        let proxy_url = self
+            .proxy_routing
+            .resolve_for_provider(provider.id().as_str(), provider.domain())
+            .await?;
+        let http_client = HttpClient::with_proxy(proxy_url.clone())
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+        let mut waf_manager = WafCookieManager::new(self.headless_browser, proxy_url)
+            .with_cookies_repo(self.waf_cookies_repo.clone());
+        if let Some(pool) = self.browser_pool.clone() {
+            waf_manager = waf_manager.with_browser_pool(pool);
+        }
+        let label = format!("{} import", provider.name());
+        let url = provider.user_info_url();
+
+        let request_cookies = waf_manager
+            .prepare_cookies(&label, provider, cookies)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+        let result = match http_client.detect_api_user(&url, &request_cookies).await {
+            Err(e) if waf_manager.is_waf_challenge_error(&e) => {
+                warn!(
+                    "[{}] WAF challenge while detecting api_user, retrying",
+                    label
+                );
+                let request_cookies = waf_manager
+                    .refresh_waf_cookies(&label, provider, cookies)
+                    .await
+                    .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+                http_client.detect_api_user(&url, &request_cookies).await
+            }
+            other => other,
+        };
+
+        result.map_err(|e| {
+            DomainError::Validation(format!(
+                "Failed to detect api_user, please enter it manually: {}",
+                e
+            ))
+        })
+    }
+}
@@ -0,0 +1,328 @@
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use crate::application::dtos::{
+    AuditLogPageDto, AuditLogQueryInput, ProxyConfigDto, ProxyEndpointDto, ProxyOverrideDto,
+    ProxyPoolDto,
+};
+use neuradock_domain::account::Account;
+use neuradock_domain::audit::{AuditEntry, AuditLogRepository};
+use neuradock_domain::check_in::Provider;
+use neuradock_domain::shared::DomainError;
+use neuradock_domain::token::{ApiToken, TokenStatus};
+use neuradock_infrastructure::codex_auth::CodexAuthJson;
+use neuradock_infrastructure::logging::log_utils::mask_sensitive;
+
+/// JSON summary of a changed object for the audit log
+///
+/// Secrets only ever enter through `secret` and `cookies`, which mask them.
+#[derive(Debug, Default)]
+pub struct AuditSummary(Map<String, Value>);
+
+impl AuditSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.0.insert(key.to_string(), value.into());
+        self
+    }
+
+    pub fn secret(self, key: &str, value: &str) -> Self {
+        self.field(key, mask_sensitive(value))
+    }
+
+    /// Cookie names with masked values
+    pub fn cookies(self, key: &str, cookies: &HashMap<String, String>) -> Self {
+        let masked: BTreeMap<&str, String> = cookies
+            .iter()
+            .map(|(name, value)| (name.as_str(), mask_sensitive(value)))
+            .collect();
+        self.field(key, serde_json::json!(masked))
+    }
+
+    /// Summary of the account's settings and credentials
+    pub fn account(account: &Account) -> Self {
+        Self::new()
+            .field("name", account.name())
+            .field("provider_id", account.provider_id().as_str())
+            .field("enabled", account.is_enabled())
+            .field("auto_checkin_enabled", account.auto_checkin_enabled())
+            .field(
+                "auto_checkin_time",
+                format!(
+                    "{:02}:{:02}",
+                    account.auto_checkin_hour(),
+                    account.auto_checkin_minute()
+                ),
+            )
+            .field("check_in_interval_hours", account.check_in_interval_hours())
+            .secret("api_user", account.credentials().api_user())
+            .cookies("cookies", account.credentials().cookies())
+    }
+
+    /// Masked credentials of the account, for changes that only touch them
+    pub fn account_credentials(account: &Account) -> Self {
+        Self::new()
+            .secret("api_user", account.credentials().api_user())
+            .cookies("cookies", account.credentials().cookies())
+    }
+
+    /// Summary of a Codex auth.json, tokens are never included
+    pub fn codex_auth(auth: &CodexAuthJson) -> Self {
+        let summary = Self::new()
+            .field("auth_mode", auth.auth_mode.as_str())
+            .field("email", auth.email());
+        match &auth.openai_api_key {
+            Some(key) => summary.secret("api_key", key),
+            None => summary,
+        }
+    }
+
+    /// Summary of a token's settings, the key only masked
+    pub fn token(token: &ApiToken) -> Self {
+        let (allowed, denied) = token
+            .model_limits()
+            .map(|limits| (limits.allowed.clone(), limits.denied.clone()))
+            .unwrap_or_default();
+        Self::new()
+            .field("token_id", token.id().value())
+            .field("name", token.name())
+            .secret("key", token.key())
+            .field("enabled", token.status() == TokenStatus::Enabled)
+            .field("remain_quota", token.remain_quota())
+            .field("unlimited_quota", token.unlimited_quota())
+            .field(
+                "expired_time",
+                token.expired_time().map(|time| time.to_rfc3339()),
+            )
+            .field("model_limits_enabled", token.model_limits_enabled())
+            .field("allowed_models", allowed)
+            .field("denied_models", denied)
+    }
+
+    pub fn provider(provider: &Provider) -> Self {
+        Self::new()
+            .field("name", provider.name())
+            .field("domain", provider.domain())
+            .field("login_path", provider.login_path())
+            .field("sign_in_path", provider.sign_in_path())
+            .field("user_info_path", provider.user_info_path())
+            .field("token_api_path", provider.token_api_path())
+            .field("models_path", provider.models_path())
+            .field("api_user_key", provider.api_user_key())
+            .field("needs_waf_bypass", provider.needs_waf_bypass())
+            .field("supports_check_in", provider.supports_check_in())
+            .field("check_in_bugged", provider.check_in_bugged())
+    }
+
+    /// Global proxy settings, the DTO only says whether a password is set
+    pub fn proxy_config(config: &ProxyConfigDto) -> Self {
+        Self::new()
+            .field("enabled", config.enabled)
+            .field("proxy_type", config.proxy_type.as_str())
+            .field("host", config.host.as_str())
+            .field("port", config.port)
+            .field("username", config.username.clone())
+            .field("has_password", config.has_password)
+            .field("bypass_domains", config.bypass_domains.clone())
+    }
+
+    pub fn proxy_pool(pool: &ProxyPoolDto) -> Self {
+        Self::new()
+            .field("name", pool.name.as_str())
+            .field("strategy", pool.strategy.as_str())
+            .field(
+                "endpoints",
+                pool.endpoints
+                    .iter()
+                    .map(endpoint_label)
+                    .collect::<Vec<_>>(),
+            )
+    }
+
+    pub fn proxy_override(proxy_override: &ProxyOverrideDto) -> Self {
+        Self::new()
+            .field("route_kind", proxy_override.route_kind.as_str())
+            .field("pool_id", proxy_override.pool_id.clone())
+            .field(
+                "endpoint",
+                proxy_override.endpoint.as_ref().map(endpoint_label),
+            )
+    }
+
+    pub fn build(self) -> String {
+        Value::Object(self.0).to_string()
+    }
+}
+
+/// `type://user@host:port`, the password never appears
+fn endpoint_label(endpoint: &ProxyEndpointDto) -> String {
+    let user = endpoint
+        .username
+        .as_ref()
+        .map(|username| format!("{}@", username))
+        .unwrap_or_default();
+    format!(
+        "{}://{}{}:{}",
+        endpoint.proxy_type, user, endpoint.host, endpoint.port
+    )
+}
+
+/// Writes and pages the append-only audit log
+///
+/// Recording never fails the audited operation, a failed write is only logged.
+pub struct AuditLogService {
+    repo: Arc<dyn AuditLogRepository>,
+    /// Writes started by `record_detached` that may still be running
+    detached: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl AuditLogService {
+    pub fn new(repo: Arc<dyn AuditLogRepository>) -> Self {
+        Self {
+            repo,
+            detached: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub async fn record(&self, entry: AuditEntry) {
+        if let Err(e) = self.repo.append(&entry).await {
+            warn!(
+                "[audit] Failed to record {} of {}: {}",
+                entry.action.as_str(),
+                entry.target,
+                e
+            );
+        }
+    }
+
+    /// Record from synchronous code, e.g. the CLI config services
+    pub fn record_detached(self: &Arc<Self>, entry: AuditEntry) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            warn!(
+                "[audit] No runtime to record {} of {}",
+                entry.action.as_str(),
+                entry.target
+            );
+            return;
+        };
+        let service = Arc::clone(self);
+        let handle = runtime.spawn(async move { service.record(entry).await });
+
+        let mut detached = self
+            .detached
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        detached.retain(|handle| !handle.is_finished());
+        detached.push(handle);
+    }
+
+    /// Wait for detached writes, so a short-lived process like the CLI doesn't drop them
+    pub async fn flush(&self) {
+        let pending = std::mem::take(
+            &mut *self
+                .detached
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        );
+        for handle in pending {
+            let _ = handle.await;
+        }
+    }
+
+    pub async fn query(&self, input: AuditLogQueryInput) -> Result<AuditLogPageDto, DomainError> {
+        let query = input.into_query()?;
+        let page = self.repo.query(&query).await?;
+        Ok(AuditLogPageDto::new(&page, &query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_masks_secrets() {
+        let cookies = HashMap::from([("session".to_string(), "abcdefghijklmnop".to_string())]);
+        let summary = AuditSummary::new()
+            .field("base_url", "https://api.example.com")
+            .secret("api_key", "sk-1234567890abcdef")
+            .cookies("cookies", &cookies)
+            .build();
+
+        assert!(summary.contains("https://api.example.com"));
+        assert!(!summary.contains("sk-1234567890abcdef"));
+        assert!(!summary.contains("abcdefghijklmnop"));
+        assert!(summary.contains("\"session\""));
+    }
+
+    #[test]
+    fn test_token_and_proxy_summaries_hide_secrets() {
+        use neuradock_domain::shared::AccountId;
+        use neuradock_domain::token::{ApiTokenConfig, TokenId};
+
+        let token = ApiToken::new(
+            TokenId::new(7),
+            AccountId::from_string("acc-1"),
+            ApiTokenConfig {
+                name: "ci".to_string(),
+                key: "sk-1234567890abcdef".to_string(),
+                status: TokenStatus::Enabled,
+                used_quota: 0,
+                remain_quota: 500_000,
+                unlimited_quota: false,
+                expired_time: None,
+                model_limits_enabled: false,
+                model_limits: None,
+                group: String::new(),
+                allow_ips: String::new(),
+            },
+        );
+        let summary = AuditSummary::token(&token).build();
+        assert!(summary.contains("\"token_id\":7"));
+        assert!(!summary.contains("sk-1234567890abcdef"));
+
+        let pool = ProxyPoolDto {
+            id: "pool-1".to_string(),
+            name: "office".to_string(),
+            strategy: "round_robin".to_string(),
+            endpoints: vec![ProxyEndpointDto {
+                proxy_type: "socks5".to_string(),
+                host: "10.0.0.1".to_string(),
+                port: 1080,
+                username: Some("bob".to_string()),
+                has_password: true,
+            }],
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        let summary = AuditSummary::proxy_pool(&pool).build();
+        assert!(summary.contains("socks5://bob@10.0.0.1:1080"));
+    }
+
+    #[test]
+    fn test_account_credentials_summary_masks_credentials() {
+        use neuradock_domain::account::Credentials;
+        use neuradock_domain::shared::ProviderId;
+
+        let cookies = HashMap::from([("session".to_string(), "abcdefghijklmnop".to_string())]);
+        let account = Account::new(
+            "main".to_string(),
+            ProviderId::from_string("anyrouter"),
+            Credentials::new(cookies, "1234567".to_string()),
+        )
+        .unwrap();
+
+        let summary = AuditSummary::account_credentials(&account).build();
+        assert!(summary.contains("\"session\""));
+        assert!(summary.contains("\"api_user\""));
+        assert!(!summary.contains("abcdefghijklmnop"));
+        assert!(!summary.contains("1234567"));
+        assert!(!summary.contains("\"name\""));
+    }
+}
@@ -0,0 +1,314 @@
+use chrono::{Duration, NaiveDate, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::application::dtos::{
+    BalanceHistoryExportFormat, BalanceHistoryExportRowDto, BalanceHistoryMaintenanceResultDto,
+    BalanceHistoryRetentionDto, ExportBalanceHistoryInput, UpdateBalanceHistoryRetentionInput,
+};
+use neuradock_domain::account::AccountRepository;
+use neuradock_domain::balance_history::{
+    BalanceHistoryRepository, BalanceHistoryRetentionRepository,
+};
+use neuradock_domain::check_in::ProviderRepository;
+use neuradock_domain::currency::CurrencySettingsRepository;
+use neuradock_domain::shared::{AccountId, DomainError};
+
+/// How often the background task checks whether maintenance is due
+const CHECK_INTERVAL_SECS: u64 = 3600;
+/// Minimum time between two automatic maintenance runs
+const RUN_INTERVAL_HOURS: i64 = 24;
+
+const CSV_HEADER: &str = "account_id,account_name,provider_id,provider_name,currency,date,recorded_at,current_balance,total_consumed,total_quota,min_balance,max_balance,sample_count,downsampled";
+
+/// Retention, downsampling and export for `balance_history`
+pub struct BalanceHistoryMaintenanceService {
+    balance_history_repo: Arc<dyn BalanceHistoryRepository>,
+    retention_repo: Arc<dyn BalanceHistoryRetentionRepository>,
+    account_repo: Arc<dyn AccountRepository>,
+    provider_repo: Arc<dyn ProviderRepository>,
+    currency_repo: Arc<dyn CurrencySettingsRepository>,
+    background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl BalanceHistoryMaintenanceService {
+    pub fn new(
+        balance_history_repo: Arc<dyn BalanceHistoryRepository>,
+        retention_repo: Arc<dyn BalanceHistoryRetentionRepository>,
+        account_repo: Arc<dyn AccountRepository>,
+        provider_repo: Arc<dyn ProviderRepository>,
+        currency_repo: Arc<dyn CurrencySettingsRepository>,
+    ) -> Self {
+        Self {
+            balance_history_repo,
+            retention_repo,
+            account_repo,
+            provider_repo,
+            currency_repo,
+            background_handle: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub async fn get_policy(&self) -> Result<BalanceHistoryRetentionDto, DomainError> {
+        let policy = self.retention_repo.get().await?;
+        Ok(BalanceHistoryRetentionDto::from(&policy))
+    }
+
+    pub async fn update_policy(
+        &self,
+        input: UpdateBalanceHistoryRetentionInput,
+    ) -> Result<BalanceHistoryRetentionDto, DomainError> {
+        let mut policy = self.retention_repo.get().await?;
+        policy.update(
+            input.enabled,
+            input.raw_retention_days,
+            input.vacuum_after_cleanup,
+        )?;
+        self.retention_repo.save(&policy).await?;
+
+        Ok(BalanceHistoryRetentionDto::from(&policy))
+    }
+
+    /// Downsample raw snapshots older than the retention window.
+    ///
+    /// Without `force` the run is skipped (returns `None`) when the policy is
+    /// disabled or the last run was less than a day ago.
+    pub async fn run_maintenance(
+        &self,
+        force: bool,
+    ) -> Result<Option<BalanceHistoryMaintenanceResultDto>, DomainError> {
+        let policy = self.retention_repo.get().await?;
+        let now = Utc::now();
+
+        if !force && !policy.is_due(now, Duration::hours(RUN_INTERVAL_HOURS)) {
+            return Ok(None);
+        }
+
+        let cutoff = policy.cutoff_date(now);
+        let report = self.balance_history_repo.downsample_before(cutoff).await?;
+
+        let vacuumed = policy.vacuum_after_cleanup() && report.raw_rows_removed > 0;
+        if vacuumed {
+            self.balance_history_repo.compact().await?;
+        }
+
+        self.retention_repo.mark_run(now).await?;
+
+        info!(
+            "[balance_retention] cutoff={} removed={} daily_rows={} vacuumed={}",
+            cutoff, report.raw_rows_removed, report.daily_rows_written, vacuumed
+        );
+
+        Ok(Some(BalanceHistoryMaintenanceResultDto {
+            cutoff_date: cutoff.format("%Y-%m-%d").to_string(),
+            raw_rows_removed: report.raw_rows_removed.min(u32::MAX as u64) as u32,
+            daily_rows_written: report.daily_rows_written.min(u32::MAX as u64) as u32,
+            vacuumed,
+            ran_at: now.to_rfc3339(),
+        }))
+    }
+
+    /// Start the periodic maintenance task (checks hourly, runs at most daily)
+    pub async fn start_background_task(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+
+        let handle = tokio::spawn(async move {
+            let mut check_interval =
+                tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+
+            loop {
+                check_interval.tick().await;
+
+                if let Err(e) = service.run_maintenance(false).await {
+                    error!("[balance_retention] Maintenance run failed: {}", e);
+                }
+            }
+        });
+
+        let mut background = self.background_handle.lock().await;
+        if let Some(previous) = background.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Export balance history (raw snapshots and daily aggregates) as CSV or JSON
+    ///
+    /// Amounts are normalized to the display currency.
+    pub async fn export(&self, input: ExportBalanceHistoryInput) -> Result<String, DomainError> {
+        let start_date = match input.start_date.as_deref() {
+            Some(value) => parse_date(value)?,
+            None => NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid date"),
+        };
+        let end_date = match input.end_date.as_deref() {
+            Some(value) => parse_date(value)?,
+            None => Utc::now().date_naive(),
+        };
+        if start_date > end_date {
+            return Err(DomainError::Validation(
+                "start_date must not be after end_date".to_string(),
+            ));
+        }
+
+        let account_ids = input
+            .account_ids
+            .iter()
+            .map(|id| AccountId::from_string(id))
+            .collect::<Vec<_>>();
+
+        let rows = self
+            .balance_history_repo
+            .list_for_export(&account_ids, start_date, end_date)
+            .await?;
+
+        let currency = self.currency_repo.get().await?;
+        let provider_names = self
+            .provider_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|p| (p.id().as_str().to_string(), p.name().to_string()))
+            .collect::<HashMap<_, _>>();
+        let accounts = self
+            .account_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|a| {
+                let provider_id = a.provider_id().as_str().to_string();
+                let provider_name = provider_names
+                    .get(&provider_id)
+                    .cloned()
+                    .unwrap_or_else(|| "Unknown".to_string());
+                (
+                    a.id().as_str().to_string(),
+                    (a.name().to_string(), provider_id, provider_name),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                let account_id = row.account_id.as_str().to_string();
+                let (account_name, provider_id, provider_name) =
+                    accounts.get(&account_id).cloned().unwrap_or_default();
+                let factor = currency.normalization_factor(&provider_id);
+
+                BalanceHistoryExportRowDto {
+                    account_id,
+                    account_name,
+                    provider_id,
+                    provider_name,
+                    currency: currency.display_currency().to_string(),
+                    date: row.date.format("%Y-%m-%d").to_string(),
+                    recorded_at: row.recorded_at.to_rfc3339(),
+                    current_balance: row.current_balance * factor,
+                    total_consumed: row.total_consumed * factor,
+                    total_quota: row.total_quota * factor,
+                    min_balance: row.min_balance * factor,
+                    max_balance: row.max_balance * factor,
+                    sample_count: row.sample_count,
+                    downsampled: row.downsampled,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        info!(
+            "[balance_retention] Exporting {} balance history rows as {:?}",
+            rows.len(),
+            input.format
+        );
+
+        match input.format {
+            BalanceHistoryExportFormat::Csv => Ok(to_csv(&rows)),
+            BalanceHistoryExportFormat::Json => serde_json::to_string_pretty(&rows)
+                .map_err(|e| DomainError::Infrastructure(format!("Failed to serialize: {}", e))),
+        }
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, DomainError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|e| DomainError::Validation(format!("Invalid date '{}': {}", value, e)))
+}
+
+fn to_csv(rows: &[BalanceHistoryExportRowDto]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+
+    for row in rows {
+        let fields = [
+            csv_field(&row.account_id),
+            csv_field(&row.account_name),
+            csv_field(&row.provider_id),
+            csv_field(&row.provider_name),
+            csv_field(&row.currency),
+            row.date.clone(),
+            row.recorded_at.clone(),
+            row.current_balance.to_string(),
+            row.total_consumed.to_string(),
+            row.total_quota.to_string(),
+            row.min_balance.to_string(),
+            row.max_balance.to_string(),
+            row.sample_count.to_string(),
+            row.downsampled.to_string(),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// Quote a CSV field (RFC 4180) when it contains separators, quotes or newlines
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    #[test]
+    fn test_to_csv_header_and_rows() {
+        let rows = vec![BalanceHistoryExportRowDto {
+            account_id: "acc-1".to_string(),
+            account_name: "Main, backup".to_string(),
+            provider_id: "anyrouter".to_string(),
+            provider_name: "AnyRouter".to_string(),
+            currency: "USD".to_string(),
+            date: "2026-01-01".to_string(),
+            recorded_at: "2026-01-01T08:00:00+00:00".to_string(),
+            current_balance: 80.0,
+            total_consumed: 20.0,
+            total_quota: 100.0,
+            min_balance: 80.0,
+            max_balance: 100.0,
+            sample_count: 2,
+            downsampled: true,
+        }];
+
+        let csv = to_csv(&rows);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "acc-1,\"Main, backup\",anyrouter,AnyRouter,USD,2026-01-01,2026-01-01T08:00:00+00:00,80,20,100,80,100,2,true"
+        );
+    }
+}
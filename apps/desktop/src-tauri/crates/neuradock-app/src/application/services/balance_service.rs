@@ -2,18 +2,19 @@ use std::sync::Arc;
 
 use neuradock_domain::account::AccountRepository;
 use neuradock_domain::check_in::ProviderRepository;
-use neuradock_domain::proxy_config::ProxyConfigRepository;
 use neuradock_domain::shared::{AccountId, DomainError};
+use neuradock_infrastructure::browser::BrowserPool;
 
 use crate::application::dtos::BalanceDto;
-use crate::application::services::{BalanceHistoryService, CheckInExecutor};
+use crate::application::services::{BalanceHistoryService, CheckInExecutor, ProxyRoutingService};
 
 pub struct BalanceService {
     account_repo: Arc<dyn AccountRepository>,
     provider_repo: Arc<dyn ProviderRepository>,
     balance_history_service: Arc<BalanceHistoryService>,
-    proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
     headless_browser: bool,
+    browser_pool: Option<Arc<BrowserPool>>,
 }
 
 impl BalanceService {
@@ -21,18 +22,24 @@ impl BalanceService {
         account_repo: Arc<dyn AccountRepository>,
         provider_repo: Arc<dyn ProviderRepository>,
         balance_history_service: Arc<BalanceHistoryService>,
-        proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
         headless_browser: bool,
     ) -> Self {
         Self {
             account_repo,
             provider_repo,
             balance_history_service,
-            proxy_config_repo,
+            proxy_routing,
             headless_browser,
+            browser_pool: None,
         }
     }
 
+    pub fn with_browser_pool(mut self, pool: Arc<BrowserPool>) -> Self {
+        self.browser_pool = Some(pool);
+        self
+    }
+
     pub async fn fetch_account_balance(
         &self,
         account_id: &str,
@@ -69,13 +76,17 @@ impl BalanceService {
                 DomainError::ProviderNotFound(account.provider_id().as_str().to_string())
             })?;
 
-        let proxy_url = self.proxy_config_repo.get().await?.proxy_url();
+        let proxy_url = self
+            .proxy_routing
+            .resolve_for_account(account_id, provider.id().as_str(), provider.domain())
+            .await?;
         let executor = CheckInExecutor::with_proxy(
             self.account_repo.clone(),
             self.headless_browser,
             proxy_url,
         )
-        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?
+        .with_browser_pool(self.browser_pool.clone());
         let user_info = executor
             .fetch_balance_only(account_id, &provider)
             .await
@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use neuradock_infrastructure::monitoring::{metrics, LATENCY_BUCKETS};
+
+use super::types::AccountCheckInResult;
+
+/// Record the outcome and latency of one check-in attempt
+pub fn record_check_in(
+    provider_id: &str,
+    elapsed: Duration,
+    outcome: &anyhow::Result<AccountCheckInResult>,
+) {
+    let registry = metrics();
+    let provider = [("provider", provider_id)];
+
+    registry.inc_counter(
+        "neuradock_check_in_attempts_total",
+        "Check-in attempts by provider",
+        &provider,
+    );
+    registry.observe(
+        "neuradock_check_in_duration_seconds",
+        "Check-in latency by provider, WAF refresh and balance fetch included",
+        LATENCY_BUCKETS,
+        &provider,
+        elapsed.as_secs_f64(),
+    );
+
+    let failure_class = match outcome {
+        Ok(result) if result.success => None,
+        Ok(result) => Some(classify_failure(&result.message).unwrap_or("other")),
+        Err(e) => Some(classify_failure(&format!("{:#}", e)).unwrap_or("error")),
+    };
+
+    match failure_class {
+        None => registry.inc_counter(
+            "neuradock_check_in_successes_total",
+            "Successful check-ins by provider",
+            &provider,
+        ),
+        Some(class) => registry.inc_counter(
+            "neuradock_check_in_failures_total",
+            "Failed check-ins by provider and failure class",
+            &[("provider", provider_id), ("class", class)],
+        ),
+    }
+}
+
+/// Map a failure message to a low-cardinality class for the `class` label
+fn classify_failure(message: &str) -> Option<&'static str> {
+    let message = message.to_lowercase();
+    let contains_any = |needles: &[&str]| needles.iter().any(|needle| message.contains(needle));
+
+    if contains_any(&["waf_challenge", "waf cookie", "no waf cookies"]) {
+        Some("waf")
+    } else if contains_any(&["already", "已签到", "已经签到", "今天已"]) {
+        Some("already_checked_in")
+    } else if contains_any(&[
+        "disabled",
+        "too frequent",
+        "not configured",
+        "does not support",
+    ]) {
+        Some("ineligible")
+    } else if contains_any(&[
+        "401",
+        "403",
+        "unauthorized",
+        "forbidden",
+        "expired",
+        "未登录",
+        "invalid token",
+        "api_user",
+    ]) {
+        Some("auth")
+    } else if contains_any(&[
+        "timed out",
+        "timeout",
+        "connect",
+        "dns",
+        "network",
+        "error sending request",
+    ]) {
+        Some("network")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_failure() {
+        assert_eq!(
+            classify_failure("WAF_CHALLENGE: Received HTML instead of JSON"),
+            Some("waf")
+        );
+        assert_eq!(
+            classify_failure("今天已经签到过了"),
+            Some("already_checked_in")
+        );
+        assert_eq!(
+            classify_failure("Check-in too frequent. Please wait 2 hour(s)"),
+            Some("ineligible")
+        );
+        assert_eq!(classify_failure("HTTP 401 Unauthorized"), Some("auth"));
+        assert_eq!(
+            classify_failure("error sending request for url"),
+            Some("network")
+        );
+        assert_eq!(classify_failure("Check-in failed"), None);
+    }
+}
@@ -1,10 +1,12 @@
 use anyhow::{Context, Result};
 use log::info;
 use std::sync::Arc;
+use std::time::Instant;
 use tracing::instrument;
 
 use neuradock_domain::waf_cookies::WafCookiesRepository;
 use neuradock_domain::{account::AccountRepository, check_in::Provider, shared::AccountId};
+use neuradock_infrastructure::browser::BrowserPool;
 use neuradock_infrastructure::http::{CheckInResult, HttpClient, SetCookieResult, UserInfo};
 
 use crate::application::services::user_info_service::UserInfoService;
@@ -12,6 +14,7 @@ use crate::application::services::waf_cookie_manager::WafCookieManager;
 
 mod balance;
 mod execution;
+mod metrics;
 mod types;
 mod validation;
 mod waf_handler;
@@ -51,6 +54,14 @@ impl CheckInExecutor {
         self
     }
 
+    /// Pass the pool, when there is one, on to the WAF cookie manager
+    pub fn with_browser_pool(mut self, pool: Option<Arc<BrowserPool>>) -> Self {
+        if let Some(pool) = pool {
+            self.waf_manager = self.waf_manager.with_browser_pool(pool);
+        }
+        self
+    }
+
     /// Create UserInfoService from current executor state
     fn create_user_info_service(&self) -> UserInfoService<'_> {
         UserInfoService::new(&self.http_client, &self.waf_manager)
@@ -62,6 +73,17 @@ impl CheckInExecutor {
         &self,
         account_id: &str,
         provider: &Provider,
+    ) -> Result<AccountCheckInResult> {
+        let started = Instant::now();
+        let outcome = self.run_check_in(account_id, provider).await;
+        metrics::record_check_in(provider.id().as_str(), started.elapsed(), &outcome);
+        outcome
+    }
+
+    async fn run_check_in(
+        &self,
+        account_id: &str,
+        provider: &Provider,
     ) -> Result<AccountCheckInResult> {
         let account_id_obj = AccountId::from_string(account_id);
 
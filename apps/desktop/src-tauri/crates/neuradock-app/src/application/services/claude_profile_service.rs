@@ -0,0 +1,202 @@
+use chrono::Utc;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use neuradock_domain::claude_profile::{
+    ActiveClaudeProfile, ClaudeModelMapping, ClaudeProfile, ClaudeProfileConfig,
+    ClaudeProfileDrift, ClaudeProfileId, ClaudeProfileRepository,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::application::dtos::{ClaudeProfileDto, ClaudeProfileStatusDto, SaveClaudeProfileInput};
+use crate::application::services::ClaudeConfigService;
+
+/// Named Claude Code settings profiles, switched by rewriting settings.json
+pub struct ClaudeProfileService {
+    repo: Arc<dyn ClaudeProfileRepository>,
+    claude_config: Arc<ClaudeConfigService>,
+}
+
+impl ClaudeProfileService {
+    pub fn new(
+        repo: Arc<dyn ClaudeProfileRepository>,
+        claude_config: Arc<ClaudeConfigService>,
+    ) -> Self {
+        Self {
+            repo,
+            claude_config,
+        }
+    }
+
+    pub async fn list(&self) -> Result<Vec<ClaudeProfileDto>, DomainError> {
+        let active_id = self.repo.get_active().await?.map(|a| a.profile_id);
+        Ok(self
+            .repo
+            .find_all()
+            .await?
+            .iter()
+            .map(|profile| {
+                ClaudeProfileDto::from_domain(profile, profile.id() == active_id.as_ref())
+            })
+            .collect())
+    }
+
+    pub async fn create(
+        &self,
+        input: SaveClaudeProfileInput,
+    ) -> Result<ClaudeProfileDto, DomainError> {
+        self.ensure_name_available(&input.name, None).await?;
+        let auth_token = input
+            .auth_token
+            .clone()
+            .ok_or_else(|| DomainError::Validation("Profile token is required".to_string()))?;
+
+        let profile = ClaudeProfile::create(Self::to_config(input, auth_token, None))?;
+        let id = self.repo.create(&profile).await?;
+        Ok(ClaudeProfileDto::from_domain(&profile.with_id(id), false))
+    }
+
+    pub async fn update(
+        &self,
+        id: i64,
+        input: SaveClaudeProfileInput,
+    ) -> Result<ClaudeProfileDto, DomainError> {
+        let id = ClaudeProfileId::new(id);
+        let mut profile = self.get(&id).await?;
+        self.ensure_name_available(&input.name, Some(&id)).await?;
+
+        let auth_token = input
+            .auth_token
+            .clone()
+            .filter(|token| !token.trim().is_empty())
+            .unwrap_or_else(|| profile.auth_token().to_string());
+        let extra_env = profile.extra_env().clone();
+        profile.update(Self::to_config(input, auth_token, Some(extra_env)))?;
+        self.repo.update(&profile).await?;
+
+        let is_active = self.active_profile_id().await? == Some(id);
+        Ok(ClaudeProfileDto::from_domain(&profile, is_active))
+    }
+
+    /// Delete a profile, settings.json is left as is
+    pub async fn delete(&self, id: i64) -> Result<(), DomainError> {
+        self.repo.delete(&ClaudeProfileId::new(id)).await
+    }
+
+    /// Write a profile to settings.json and record it as active
+    pub async fn switch(&self, id: i64) -> Result<String, DomainError> {
+        let id = ClaudeProfileId::new(id);
+        let profile = self.get(&id).await?;
+        let previous_keys: Vec<String> = self
+            .repo
+            .get_active()
+            .await?
+            .map(|active| active.applied_env.into_keys().collect())
+            .unwrap_or_default();
+
+        let (message, applied_env) = self
+            .claude_config
+            .apply_profile(&profile, &previous_keys)
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        self.repo
+            .set_active(&ActiveClaudeProfile {
+                profile_id: id,
+                applied_at: Utc::now(),
+                applied_env,
+            })
+            .await?;
+        Ok(message)
+    }
+
+    /// Remove the active profile's keys from settings.json
+    pub async fn deactivate(&self) -> Result<String, DomainError> {
+        let Some(active) = self.repo.get_active().await? else {
+            return Ok("No active Claude profile".to_string());
+        };
+
+        let keys: Vec<String> = active.applied_env.into_keys().collect();
+        let message = self
+            .claude_config
+            .remove_profile_env(&keys)
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+        self.repo.clear_active().await?;
+        Ok(message)
+    }
+
+    /// Active profile and drift between it and settings.json
+    pub async fn status(&self) -> Result<ClaudeProfileStatusDto, DomainError> {
+        let Some(active) = self.repo.get_active().await? else {
+            return Ok(ClaudeProfileStatusDto::inactive());
+        };
+        let Some(profile) = self.repo.find_by_id(&active.profile_id).await? else {
+            return Ok(ClaudeProfileStatusDto::inactive());
+        };
+
+        let current_env = self
+            .claude_config
+            .current_env_digests()
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+        let drift = active.drift(&profile, current_env.as_ref());
+        if drift != ClaudeProfileDrift::InSync {
+            log::info!("Claude profile {} drifted: {:?}", profile.name(), drift);
+        }
+
+        Ok(ClaudeProfileStatusDto::new(
+            &profile,
+            active.applied_at.to_rfc3339(),
+            drift,
+        ))
+    }
+
+    async fn get(&self, id: &ClaudeProfileId) -> Result<ClaudeProfile, DomainError> {
+        self.repo.find_by_id(id).await?.ok_or_else(|| {
+            DomainError::NotFound(format!("Claude profile not found: {}", id.value()))
+        })
+    }
+
+    async fn active_profile_id(&self) -> Result<Option<ClaudeProfileId>, DomainError> {
+        Ok(self
+            .repo
+            .get_active()
+            .await?
+            .map(|active| active.profile_id))
+    }
+
+    async fn ensure_name_available(
+        &self,
+        name: &str,
+        current: Option<&ClaudeProfileId>,
+    ) -> Result<(), DomainError> {
+        match self.repo.find_by_name(name.trim()).await? {
+            Some(existing) if existing.id() != current => Err(DomainError::Validation(format!(
+                "A Claude profile named {} already exists",
+                name.trim()
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn to_config(
+        input: SaveClaudeProfileInput,
+        auth_token: String,
+        current_extra_env: Option<BTreeMap<String, String>>,
+    ) -> ClaudeProfileConfig {
+        let extra_env = match input.extra_env {
+            Some(extra_env) => extra_env.into_iter().collect(),
+            None => current_extra_env.unwrap_or_default(),
+        };
+
+        ClaudeProfileConfig {
+            name: input.name,
+            auth_token,
+            base_url: input.base_url,
+            models: ClaudeModelMapping {
+                haiku: input.haiku_model,
+                sonnet: input.sonnet_model,
+                opus: input.opus_model,
+            },
+            extra_env,
+        }
+    }
+}
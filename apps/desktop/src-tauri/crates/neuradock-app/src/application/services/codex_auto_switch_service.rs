@@ -0,0 +1,554 @@
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::application::dtos::{
+    CodexAutoSwitchCheckDto, CodexAutoSwitchEventDto, CodexAutoSwitchPolicyDto,
+    UpdateCodexAutoSwitchPolicyInput,
+};
+use crate::application::services::codex_token_refresh_service::is_auth_for_account;
+use crate::application::services::{
+    AuditLogService, AuditSummary, CodexUsageHistoryService, NotificationService,
+    ProxyRoutingService,
+};
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
+use neuradock_domain::codex::{
+    effective_used_percent, CodexAccount, CodexAccountRepository, CodexAccountStatus,
+    CodexAutoSwitchEvent, CodexAutoSwitchPolicy, CodexAutoSwitchReason, CodexAutoSwitchRepository,
+    CodexRateLimitWindow,
+};
+use neuradock_domain::shared::DomainError;
+use neuradock_infrastructure::codex_auth::{CodexAuthFile, CodexAuthJson};
+use neuradock_infrastructure::http::openai::oauth::access_token_expiry;
+use neuradock_infrastructure::http::openai::quota::{fetch_codex_usage, CodexUsageWindow};
+
+/// How often the background task checks whether a poll is due
+const CHECK_INTERVAL_SECS: u64 = 60;
+const CODEX_USAGE_URL: &str = "https://chatgpt.com/backend-api/wham/usage";
+const CODEX_AUTH_AUDIT_TARGET: &str = "codex_auth";
+
+/// Switches `~/.codex/auth.json` to the stored account with the most headroom
+/// when the active one reaches the usage threshold, and back once it resets
+pub struct CodexAutoSwitchService {
+    codex_account_repo: Arc<dyn CodexAccountRepository>,
+    auto_switch_repo: Arc<dyn CodexAutoSwitchRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
+    notification_service: Arc<NotificationService>,
+    usage_history: Arc<CodexUsageHistoryService>,
+    audit_log: Option<Arc<AuditLogService>>,
+    /// Background and manual checks must not switch concurrently
+    check_lock: Mutex<()>,
+    background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl CodexAutoSwitchService {
+    pub fn new(
+        codex_account_repo: Arc<dyn CodexAccountRepository>,
+        auto_switch_repo: Arc<dyn CodexAutoSwitchRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
+        notification_service: Arc<NotificationService>,
+        usage_history: Arc<CodexUsageHistoryService>,
+    ) -> Self {
+        Self {
+            codex_account_repo,
+            auto_switch_repo,
+            proxy_routing,
+            notification_service,
+            usage_history,
+            audit_log: None,
+            check_lock: Mutex::new(()),
+            background_handle: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    pub async fn get_policy(&self) -> Result<CodexAutoSwitchPolicyDto, DomainError> {
+        let policy = self.auto_switch_repo.get_policy().await?;
+        Ok(CodexAutoSwitchPolicyDto::from(&policy))
+    }
+
+    pub async fn update_policy(
+        &self,
+        input: UpdateCodexAutoSwitchPolicyInput,
+    ) -> Result<CodexAutoSwitchPolicyDto, DomainError> {
+        let _guard = self.check_lock.lock().await;
+        let mut policy = self.auto_switch_repo.get_policy().await?;
+        policy.update(
+            input.enabled,
+            input.threshold_percent,
+            input.poll_interval_minutes,
+            input.switch_back,
+        )?;
+        self.auto_switch_repo.save_policy(&policy).await?;
+
+        Ok(CodexAutoSwitchPolicyDto::from(&policy))
+    }
+
+    pub async fn recent_switches(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<CodexAutoSwitchEventDto>, DomainError> {
+        let events = self.auto_switch_repo.recent_switches(limit).await?;
+        Ok(events.iter().map(CodexAutoSwitchEventDto::from).collect())
+    }
+
+    /// Poll the active account and switch if needed
+    ///
+    /// Without `force` the check is skipped (returns `None`) when the policy is
+    /// disabled or the poll interval has not passed yet.
+    pub async fn run_check(
+        &self,
+        force: bool,
+    ) -> Result<Option<CodexAutoSwitchCheckDto>, DomainError> {
+        let _guard = self.check_lock.lock().await;
+        let mut policy = self.auto_switch_repo.get_policy().await?;
+        let now = Utc::now();
+
+        if !force && (!policy.is_enabled() || !policy.is_due(now)) {
+            return Ok(None);
+        }
+
+        policy.mark_checked(now);
+        let result = self.check(&mut policy, now).await;
+        self.auto_switch_repo.save_policy(&policy).await?;
+
+        result.map(Some)
+    }
+
+    /// Start the periodic check task (polls at the policy's interval)
+    pub async fn start_background_task(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+
+        let handle = tokio::spawn(async move {
+            let mut check_interval =
+                tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+
+            loop {
+                check_interval.tick().await;
+
+                if let Err(e) = service.run_check(false).await {
+                    error!("[codex_auto_switch] Check failed: {}", e);
+                }
+            }
+        });
+
+        let mut background = self.background_handle.lock().await;
+        if let Some(previous) = background.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    async fn check(
+        &self,
+        policy: &mut CodexAutoSwitchPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<CodexAutoSwitchCheckDto, DomainError> {
+        let mut result = CodexAutoSwitchCheckDto {
+            checked_at: now.to_rfc3339(),
+            active_account_id: None,
+            active_email: None,
+            used_percent: None,
+            switched: None,
+            message: None,
+        };
+
+        let auth = CodexAuthFile::read()
+            .map_err(|e| DomainError::Infrastructure(format!("Failed to read auth.json: {}", e)))?;
+        let Some(auth) = auth.filter(|auth| auth.tokens.is_some()) else {
+            result.message = Some("Codex CLI is not signed in with ChatGPT".to_string());
+            return Ok(result);
+        };
+
+        let accounts = self.codex_account_repo.find_all().await?;
+        let Some(mut current) = find_active_account(&auth, &accounts).cloned() else {
+            // Signed in with an account NeuraDock does not manage, leave it alone
+            policy.clear_switch();
+            result.message = Some("auth.json does not belong to a stored account".to_string());
+            return Ok(result);
+        };
+        result.active_account_id = Some(current.id().as_str().to_string());
+        result.active_email = Some(current.email().to_string());
+
+        if policy.is_switch_back_due(now) {
+            if let Some(event) = self
+                .try_switch_back(policy, &current, &accounts, now)
+                .await?
+            {
+                result.active_account_id = Some(event.to_account_id.as_str().to_string());
+                result.active_email = Some(event.to_email.clone());
+                result.switched = Some(CodexAutoSwitchEventDto::from(&event));
+                return Ok(result);
+            }
+        }
+
+        if let Err(e) = self.refresh_quota(&mut current).await {
+            // Never switch on stale data
+            warn!(
+                "[codex_auto_switch] Usage check for {} failed: {}",
+                current.email(),
+                e
+            );
+            result.message = Some(format!("Usage check failed: {}", e));
+            return Ok(result);
+        }
+        let used_percent = effective_used_percent(&current, now);
+        result.used_percent = used_percent;
+
+        if !policy.is_exhausted(&current, now) {
+            return Ok(result);
+        }
+
+        let mut candidates = Vec::new();
+        for mut candidate in accounts {
+            if candidate.id() == current.id()
+                || *candidate.status() != CodexAccountStatus::Active
+                || !candidate.has_valid_tokens()
+            {
+                continue;
+            }
+            match self.refresh_quota(&mut candidate).await {
+                Ok(()) => candidates.push(candidate),
+                Err(e) => warn!(
+                    "[codex_auto_switch] Usage check for candidate {} failed: {}",
+                    candidate.email(),
+                    e
+                ),
+            }
+        }
+
+        let Some(target) = policy.pick_target(&candidates, current.id(), now) else {
+            warn!(
+                "[codex_auto_switch] {} is at {:.0}% but no stored account has headroom",
+                current.email(),
+                used_percent.unwrap_or_default()
+            );
+            result.message = Some("No stored account has quota headroom".to_string());
+            return Ok(result);
+        };
+
+        let event = self
+            .switch_to(
+                &current,
+                target,
+                CodexAutoSwitchReason::ThresholdReached,
+                used_percent,
+                now,
+            )
+            .await?;
+        policy.record_switch_away(current.id(), policy.exhausted_until(&current, now));
+
+        result.active_account_id = Some(target.id().as_str().to_string());
+        result.active_email = Some(target.email().to_string());
+        result.switched = Some(CodexAutoSwitchEventDto::from(&event));
+        Ok(result)
+    }
+
+    /// Switch back to the home account once its window has reset
+    async fn try_switch_back(
+        &self,
+        policy: &mut CodexAutoSwitchPolicy,
+        current: &CodexAccount,
+        accounts: &[CodexAccount],
+        now: DateTime<Utc>,
+    ) -> Result<Option<CodexAutoSwitchEvent>, DomainError> {
+        let home = policy
+            .home_account_id()
+            .and_then(|id| accounts.iter().find(|account| account.id() == id))
+            .cloned();
+        let Some(mut home) = home else {
+            policy.clear_switch();
+            return Ok(None);
+        };
+        if home.id() == current.id() {
+            // Switched back by hand
+            policy.clear_switch();
+            return Ok(None);
+        }
+        if *home.status() != CodexAccountStatus::Active || !home.has_valid_tokens() {
+            info!(
+                "[codex_auto_switch] Home account {} is no longer usable, staying on {}",
+                home.email(),
+                current.email()
+            );
+            policy.clear_switch();
+            return Ok(None);
+        }
+
+        if let Err(e) = self.refresh_quota(&mut home).await {
+            warn!(
+                "[codex_auto_switch] Usage check for home {} failed: {}",
+                home.email(),
+                e
+            );
+            return Ok(None);
+        }
+        if policy.is_exhausted(&home, now) {
+            policy.postpone_switch_back(policy.exhausted_until(&home, now));
+            return Ok(None);
+        }
+
+        let used_percent = effective_used_percent(current, now);
+        let event = self
+            .switch_to(
+                current,
+                &home,
+                CodexAutoSwitchReason::SwitchBack,
+                used_percent,
+                now,
+            )
+            .await?;
+        policy.clear_switch();
+        Ok(Some(event))
+    }
+
+    async fn refresh_quota(&self, account: &mut CodexAccount) -> Result<(), DomainError> {
+        let access_token = account
+            .access_token()
+            .ok_or_else(|| DomainError::Validation("Account has no access token".to_string()))?;
+        let proxy_url = self
+            .proxy_routing
+            .resolve_for_codex(Some(account.id().as_str()), CODEX_USAGE_URL)
+            .await?;
+
+        let quota = fetch_codex_usage(access_token, account.account_id(), proxy_url.as_deref())
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+        account.apply_quota(
+            quota.plan_type,
+            quota.has_credits,
+            quota.is_unlimited,
+            quota.credit_balance,
+            quota.primary_window.as_ref().map(map_window),
+            quota.secondary_window.as_ref().map(map_window),
+        );
+        self.codex_account_repo.save(account).await?;
+
+        if let Err(e) = self.usage_history.record_account(account).await {
+            warn!(
+                "[codex_auto_switch] Failed to record usage of {}: {}",
+                account.email(),
+                e
+            );
+        }
+        Ok(())
+    }
+
+    async fn switch_to(
+        &self,
+        from: &CodexAccount,
+        to: &CodexAccount,
+        reason: CodexAutoSwitchReason,
+        from_used_percent: Option<f64>,
+        now: DateTime<Utc>,
+    ) -> Result<CodexAutoSwitchEvent, DomainError> {
+        // The CLI may have rotated the outgoing account's tokens, keep them before overwriting
+        let current_auth = CodexAuthFile::read()
+            .map_err(|e| DomainError::Infrastructure(format!("Failed to read auth.json: {}", e)))?;
+        if let Some(current_auth) = current_auth {
+            let mut from = from.clone();
+            if adopt_rotated_tokens(&current_auth, &mut from) {
+                info!(
+                    "[codex_auto_switch] Saved tokens rotated by Codex CLI for {}",
+                    from.email()
+                );
+                self.codex_account_repo.save(&from).await?;
+            }
+        }
+
+        let auth = CodexAuthJson::chatgpt(
+            to.id_token().unwrap_or_default().to_string(),
+            to.access_token().unwrap_or_default().to_string(),
+            to.refresh_token().unwrap_or_default().to_string(),
+            to.account_id().unwrap_or_default().to_string(),
+        );
+        CodexAuthFile::write(&auth).map_err(|e| {
+            DomainError::Infrastructure(format!("Failed to write auth.json: {}", e))
+        })?;
+
+        let event = CodexAutoSwitchEvent {
+            from_account_id: Some(from.id().clone()),
+            from_email: from.email().to_string(),
+            to_account_id: to.id().clone(),
+            to_email: to.email().to_string(),
+            reason,
+            from_used_percent,
+            switched_at: now,
+        };
+        info!(
+            "[codex_auto_switch] Switched Codex auth {} -> {} ({}, usage {:?}%)",
+            event.from_email,
+            event.to_email,
+            reason.as_str(),
+            from_used_percent
+        );
+        self.auto_switch_repo.record_switch(&event).await?;
+
+        if let Some(audit_log) = &self.audit_log {
+            let mut entry = AuditEntry::new(
+                AuditActor::System,
+                AuditAction::CodexAuthSwitched,
+                CODEX_AUTH_AUDIT_TARGET,
+            )
+            .with_target_name(to.email());
+            entry.before = Some(AuditSummary::new().field("email", from.email()).build());
+            entry.after = Some(
+                AuditSummary::codex_auth(&auth)
+                    .field("reason", reason.as_str())
+                    .build(),
+            );
+            audit_log.record(entry).await;
+        }
+
+        if let Err(e) = self
+            .notification_service
+            .send_codex_account_switch(
+                &event.from_email,
+                &event.to_email,
+                reason,
+                from_used_percent,
+            )
+            .await
+        {
+            warn!(
+                "[codex_auto_switch] Failed to send switch notification: {}",
+                e
+            );
+        }
+
+        Ok(event)
+    }
+}
+
+fn map_window(window: &CodexUsageWindow) -> CodexRateLimitWindow {
+    CodexRateLimitWindow::new(window.used_percent, window.window_minutes, window.resets_at)
+}
+
+/// Stored account signed in to auth.json, by ChatGPT account ID, else by email
+fn find_active_account<'a>(
+    auth: &CodexAuthJson,
+    accounts: &'a [CodexAccount],
+) -> Option<&'a CodexAccount> {
+    let account_id = auth
+        .tokens
+        .as_ref()
+        .map(|tokens| tokens.account_id.as_str())
+        .filter(|id| !id.is_empty());
+    if let Some(account_id) = account_id {
+        if let Some(account) = accounts
+            .iter()
+            .find(|account| account.account_id() == Some(account_id))
+        {
+            return Some(account);
+        }
+    }
+
+    let email = auth.email()?;
+    accounts
+        .iter()
+        .find(|account| account.email().eq_ignore_ascii_case(&email))
+}
+
+/// Copy tokens from auth.json onto `account` if it is signed in there with other tokens
+///
+/// Returns whether the account changed.
+fn adopt_rotated_tokens(auth: &CodexAuthJson, account: &mut CodexAccount) -> bool {
+    let old_refresh_token = account.refresh_token().unwrap_or_default().to_string();
+    if !is_auth_for_account(auth, account, &old_refresh_token) {
+        return false;
+    }
+    let Some(tokens) = auth.tokens.as_ref() else {
+        return false;
+    };
+    if tokens.access_token.is_empty() || tokens.refresh_token.is_empty() {
+        return false;
+    }
+    if account.access_token() == Some(tokens.access_token.as_str())
+        && account.refresh_token() == Some(tokens.refresh_token.as_str())
+    {
+        return false;
+    }
+
+    account.apply_refreshed_tokens(
+        tokens.access_token.clone(),
+        Some(tokens.refresh_token.clone()),
+        Some(tokens.id_token.clone()).filter(|token| !token.is_empty()),
+        Some(tokens.account_id.clone()).filter(|id| !id.is_empty()),
+        access_token_expiry(&tokens.access_token),
+    );
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use neuradock_domain::codex::CodexAccountSource;
+
+    fn account(email: &str, account_id: &str) -> CodexAccount {
+        let mut account =
+            CodexAccount::new(email.to_string(), None, CodexAccountSource::Import).unwrap();
+        account.apply_tokens(
+            "access".to_string(),
+            "refresh".to_string(),
+            String::new(),
+            Some(account_id.to_string()),
+            None,
+        );
+        account
+    }
+
+    #[test]
+    fn test_find_active_account_by_chatgpt_account_id() {
+        let accounts = vec![
+            account("a@example.com", "acct-a"),
+            account("b@example.com", "acct-b"),
+        ];
+        let auth = CodexAuthJson::chatgpt(
+            String::new(),
+            "access".to_string(),
+            "refresh".to_string(),
+            "acct-b".to_string(),
+        );
+        assert_eq!(
+            find_active_account(&auth, &accounts).map(|a| a.email()),
+            Some("b@example.com")
+        );
+
+        let unknown = CodexAuthJson::chatgpt(
+            String::new(),
+            "access".to_string(),
+            "refresh".to_string(),
+            "acct-x".to_string(),
+        );
+        assert!(find_active_account(&unknown, &accounts).is_none());
+    }
+
+    #[test]
+    fn test_adopt_rotated_tokens_of_outgoing_account() {
+        let mut outgoing = account("a@example.com", "acct-a");
+        let rotated = CodexAuthJson::chatgpt(
+            "id-rotated".to_string(),
+            "access-rotated".to_string(),
+            "refresh-rotated".to_string(),
+            "acct-a".to_string(),
+        );
+        assert!(adopt_rotated_tokens(&rotated, &mut outgoing));
+        assert_eq!(outgoing.access_token(), Some("access-rotated"));
+        assert_eq!(outgoing.refresh_token(), Some("refresh-rotated"));
+        assert_eq!(outgoing.id_token(), Some("id-rotated"));
+
+        // Nothing left to adopt
+        assert!(!adopt_rotated_tokens(&rotated, &mut outgoing));
+
+        // auth.json of another account leaves the outgoing one alone
+        let mut other = account("b@example.com", "acct-b");
+        assert!(!adopt_rotated_tokens(&rotated, &mut other));
+        assert_eq!(other.access_token(), Some("access"));
+        assert_eq!(other.refresh_token(), Some("refresh"));
+    }
+}
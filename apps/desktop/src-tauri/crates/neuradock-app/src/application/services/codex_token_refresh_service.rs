@@ -0,0 +1,382 @@
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::application::dtos::{
+    CodexTokenRefreshOutcome, CodexTokenRefreshPolicyDto, CodexTokenRefreshReportDto,
+    CodexTokenRefreshResultDto, UpdateCodexTokenRefreshPolicyInput,
+};
+use crate::application::services::ProxyRoutingService;
+use neuradock_domain::codex::{
+    CodexAccount, CodexAccountId, CodexAccountRepository, CodexTokenRefreshPolicy,
+    CodexTokenRefreshPolicyRepository,
+};
+use neuradock_domain::shared::DomainError;
+use neuradock_infrastructure::codex_auth::{CodexAuthFile, CodexAuthJson};
+use neuradock_infrastructure::http::openai::oauth::refresh_codex_tokens;
+
+/// How often the background task looks for tokens about to expire
+const CHECK_INTERVAL_SECS: u64 = 600;
+
+/// Refreshes Codex OAuth tokens before they expire
+///
+/// Refreshed tokens are saved on the account and, when the account is the one
+/// in `~/.codex/auth.json`, written there too so the Codex CLI keeps working.
+pub struct CodexTokenRefreshService {
+    codex_account_repo: Arc<dyn CodexAccountRepository>,
+    policy_repo: Arc<dyn CodexTokenRefreshPolicyRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
+    /// Refresh tokens rotate, two concurrent refreshes would invalidate each other
+    /// Also held by policy updates, a run saves the policy it loaded
+    refresh_lock: Mutex<()>,
+    background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl CodexTokenRefreshService {
+    pub fn new(
+        codex_account_repo: Arc<dyn CodexAccountRepository>,
+        policy_repo: Arc<dyn CodexTokenRefreshPolicyRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
+    ) -> Self {
+        Self {
+            codex_account_repo,
+            policy_repo,
+            proxy_routing,
+            refresh_lock: Mutex::new(()),
+            background_handle: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub async fn get_policy(&self) -> Result<CodexTokenRefreshPolicyDto, DomainError> {
+        let policy = self.policy_repo.get().await?;
+        Ok(CodexTokenRefreshPolicyDto::from(&policy))
+    }
+
+    pub async fn update_policy(
+        &self,
+        input: UpdateCodexTokenRefreshPolicyInput,
+    ) -> Result<CodexTokenRefreshPolicyDto, DomainError> {
+        let _guard = self.refresh_lock.lock().await;
+        let mut policy = self.policy_repo.get().await?;
+        policy.update(
+            input.enabled,
+            input.refresh_before_minutes,
+            input.token_url.as_deref(),
+        )?;
+        self.policy_repo.save(&policy).await?;
+
+        Ok(CodexTokenRefreshPolicyDto::from(&policy))
+    }
+
+    /// Refresh every active account whose token expires within the lead time
+    ///
+    /// Without `force` the run is skipped (returns `None`) when the policy is disabled.
+    pub async fn run_due(
+        &self,
+        force: bool,
+    ) -> Result<Option<CodexTokenRefreshReportDto>, DomainError> {
+        let _guard = self.refresh_lock.lock().await;
+        let mut policy = self.policy_repo.get().await?;
+        if !force && !policy.is_enabled() {
+            return Ok(None);
+        }
+
+        let now = Utc::now();
+        let due = self
+            .codex_account_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|account| account.needs_token_refresh(now, policy.refresh_before()))
+            .collect::<Vec<_>>();
+
+        // One account failing must not keep the others from refreshing
+        let mut results = Vec::with_capacity(due.len());
+        for account in due {
+            let failed = failed_result(&account);
+            let result = match self.refresh(account, &policy).await {
+                Ok(result) => result,
+                Err(e) => {
+                    warn!("[codex_refresh] Refresh for {} failed: {}", failed.email, e);
+                    CodexTokenRefreshResultDto {
+                        message: Some(e.to_string()),
+                        ..failed
+                    }
+                }
+            };
+            results.push(result);
+        }
+
+        policy.mark_run(now);
+        self.policy_repo.save(&policy).await?;
+
+        if !results.is_empty() {
+            info!(
+                "[codex_refresh] refreshed={} expired={} failed={}",
+                count(&results, CodexTokenRefreshOutcome::Refreshed),
+                count(&results, CodexTokenRefreshOutcome::Expired),
+                count(&results, CodexTokenRefreshOutcome::Failed),
+            );
+        }
+
+        Ok(Some(CodexTokenRefreshReportDto {
+            ran_at: now.to_rfc3339(),
+            results,
+        }))
+    }
+
+    /// Refresh one account now, whatever its expiry
+    pub async fn refresh_account(
+        &self,
+        account_id: &str,
+    ) -> Result<CodexTokenRefreshResultDto, DomainError> {
+        let policy = self.policy_repo.get().await?;
+
+        let _guard = self.refresh_lock.lock().await;
+        let account = self
+            .codex_account_repo
+            .find_by_id(&CodexAccountId::from_string(account_id))
+            .await?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Codex account not found: {}", account_id))
+            })?;
+        if account.refresh_token().is_none() {
+            return Err(DomainError::Validation(
+                "Account has no refresh token".to_string(),
+            ));
+        }
+
+        self.refresh(account, &policy).await
+    }
+
+    /// Start the periodic refresh task
+    pub async fn start_background_task(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+
+        let handle = tokio::spawn(async move {
+            let mut check_interval =
+                tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+
+            loop {
+                check_interval.tick().await;
+
+                if let Err(e) = service.run_due(false).await {
+                    error!("[codex_refresh] Refresh run failed: {}", e);
+                }
+            }
+        });
+
+        let mut background = self.background_handle.lock().await;
+        if let Some(previous) = background.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Callers hold `refresh_lock`
+    async fn refresh(
+        &self,
+        mut account: CodexAccount,
+        policy: &CodexTokenRefreshPolicy,
+    ) -> Result<CodexTokenRefreshResultDto, DomainError> {
+        let old_refresh_token = account.refresh_token().unwrap_or_default().to_string();
+        let proxy_url = self
+            .proxy_routing
+            .resolve_for_codex(Some(account.id().as_str()), policy.token_url())
+            .await?;
+
+        let mut result = CodexTokenRefreshResultDto {
+            account_id: account.id().as_str().to_string(),
+            email: account.email().to_string(),
+            outcome: CodexTokenRefreshOutcome::Refreshed,
+            message: None,
+            token_expires_at: None,
+            auth_file_updated: false,
+        };
+
+        match refresh_codex_tokens(policy.token_url(), &old_refresh_token, proxy_url.as_deref())
+            .await
+        {
+            Ok(tokens) => {
+                account.apply_refreshed_tokens(
+                    tokens.access_token,
+                    tokens.refresh_token,
+                    tokens.id_token,
+                    tokens.account_id,
+                    tokens.expires_at,
+                );
+                self.codex_account_repo.save(&account).await?;
+                result.token_expires_at = account.token_expires_at().map(|at| at.to_rfc3339());
+
+                match sync_auth_file(&account, &old_refresh_token) {
+                    Ok(updated) => result.auth_file_updated = updated,
+                    Err(e) => {
+                        warn!(
+                            "[codex_refresh] Failed to update auth.json for {}: {}",
+                            account.email(),
+                            e
+                        );
+                        result.message = Some(format!("Failed to update auth.json: {}", e));
+                    }
+                }
+                info!(
+                    "[codex_refresh] Refreshed tokens for {} (auth.json updated: {})",
+                    account.email(),
+                    result.auth_file_updated
+                );
+            }
+            Err(e) if e.is_definitive() => {
+                account.mark_expired();
+                self.codex_account_repo.save(&account).await?;
+                warn!(
+                    "[codex_refresh] Refresh token of {} rejected, marked expired: {}",
+                    account.email(),
+                    e
+                );
+                result.outcome = CodexTokenRefreshOutcome::Expired;
+                result.message = Some(e.to_string());
+            }
+            Err(e) => {
+                warn!(
+                    "[codex_refresh] Refresh for {} failed, will retry: {}",
+                    account.email(),
+                    e
+                );
+                result.outcome = CodexTokenRefreshOutcome::Failed;
+                result.message = Some(e.to_string());
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Result for an account whose refresh did not get through
+fn failed_result(account: &CodexAccount) -> CodexTokenRefreshResultDto {
+    CodexTokenRefreshResultDto {
+        account_id: account.id().as_str().to_string(),
+        email: account.email().to_string(),
+        outcome: CodexTokenRefreshOutcome::Failed,
+        message: None,
+        token_expires_at: None,
+        auth_file_updated: false,
+    }
+}
+
+fn count(results: &[CodexTokenRefreshResultDto], outcome: CodexTokenRefreshOutcome) -> usize {
+    results.iter().filter(|r| r.outcome == outcome).count()
+}
+
+/// Rewrite auth.json with the new tokens if it belongs to `account`
+fn sync_auth_file(account: &CodexAccount, old_refresh_token: &str) -> anyhow::Result<bool> {
+    let Some(auth) = CodexAuthFile::read()? else {
+        return Ok(false);
+    };
+    if !is_auth_for_account(&auth, account, old_refresh_token) {
+        return Ok(false);
+    }
+
+    let auth = CodexAuthJson::chatgpt(
+        account.id_token().unwrap_or_default().to_string(),
+        account.access_token().unwrap_or_default().to_string(),
+        account.refresh_token().unwrap_or_default().to_string(),
+        account.account_id().unwrap_or_default().to_string(),
+    );
+    CodexAuthFile::write(&auth)?;
+    Ok(true)
+}
+
+/// Same refresh token, else same ChatGPT account ID, else same email
+pub(super) fn is_auth_for_account(
+    auth: &CodexAuthJson,
+    account: &CodexAccount,
+    old_refresh_token: &str,
+) -> bool {
+    let Some(tokens) = auth.tokens.as_ref() else {
+        return false;
+    };
+    if !old_refresh_token.is_empty() && tokens.refresh_token == old_refresh_token {
+        return true;
+    }
+    if !tokens.account_id.is_empty() {
+        return account.account_id() == Some(tokens.account_id.as_str());
+    }
+    auth.email()
+        .is_some_and(|email| email.eq_ignore_ascii_case(account.email()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use neuradock_domain::codex::CodexAccountSource;
+
+    fn account(account_id: Option<&str>) -> CodexAccount {
+        let mut account = CodexAccount::new(
+            "user@example.com".to_string(),
+            None,
+            CodexAccountSource::Import,
+        )
+        .unwrap();
+        account.apply_tokens(
+            "access".to_string(),
+            "refresh".to_string(),
+            String::new(),
+            account_id.map(str::to_string),
+            None,
+        );
+        account
+    }
+
+    fn auth(refresh_token: &str, account_id: &str) -> CodexAuthJson {
+        CodexAuthJson::chatgpt(
+            String::new(),
+            "access".to_string(),
+            refresh_token.to_string(),
+            account_id.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_auth_matches_by_refresh_token_or_account_id() {
+        let account = account(Some("acct-1"));
+        assert!(is_auth_for_account(&auth("old", "other"), &account, "old"));
+        assert!(is_auth_for_account(
+            &auth("stale", "acct-1"),
+            &account,
+            "old"
+        ));
+        assert!(!is_auth_for_account(
+            &auth("stale", "acct-2"),
+            &account,
+            "old"
+        ));
+        assert!(!is_auth_for_account(
+            &CodexAuthJson::api_key("sk-test".to_string()),
+            &account,
+            "old"
+        ));
+    }
+
+    #[test]
+    fn test_needs_refresh_only_when_active_and_close_to_expiry() {
+        let now = Utc::now();
+        let lead = chrono::Duration::hours(1);
+        let mut account = account(None);
+        assert!(!account.needs_token_refresh(now, lead));
+
+        account.apply_refreshed_tokens(
+            "access".to_string(),
+            None,
+            None,
+            None,
+            Some(now + chrono::Duration::minutes(30)),
+        );
+        assert!(account.needs_token_refresh(now, lead));
+        assert!(!account.needs_token_refresh(now, chrono::Duration::minutes(10)));
+        assert_eq!(account.refresh_token(), Some("refresh"));
+
+        account.mark_expired();
+        assert!(!account.needs_token_refresh(now, lead));
+    }
+}
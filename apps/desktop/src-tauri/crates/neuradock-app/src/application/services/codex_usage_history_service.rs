@@ -0,0 +1,293 @@
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::application::dtos::{
+    CodexUsageHistoryDto, CodexUsagePointDto, CodexUsageReminderSettingsDto, CodexUsageResetDto,
+    CodexUsageSeriesDto,
+};
+use crate::application::services::NotificationService;
+use neuradock_domain::codex::{
+    CodexAccount, CodexAccountId, CodexAccountRepository, CodexUsageHistoryRepository,
+    CodexUsageReminderKind, CodexUsageSnapshot, CodexUsageWindowKind,
+};
+use neuradock_domain::shared::DomainError;
+
+/// How often the background task looks for windows that have reset
+const CHECK_INTERVAL_SECS: u64 = 60;
+/// Snapshots older than this are pruned
+const RETENTION_DAYS: i64 = 90;
+const DEFAULT_HISTORY_DAYS: u32 = 7;
+/// Prune once every this many checks (hourly)
+const PRUNE_EVERY_CHECKS: u32 = 60;
+
+/// Keeps a time series of Codex rate-limit window usage and reminds when a
+/// window reaches 80%/100% or resets
+pub struct CodexUsageHistoryService {
+    codex_account_repo: Arc<dyn CodexAccountRepository>,
+    history_repo: Arc<dyn CodexUsageHistoryRepository>,
+    notification_service: Arc<NotificationService>,
+    background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl CodexUsageHistoryService {
+    pub fn new(
+        codex_account_repo: Arc<dyn CodexAccountRepository>,
+        history_repo: Arc<dyn CodexUsageHistoryRepository>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            codex_account_repo,
+            history_repo,
+            notification_service,
+            background_handle: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Record the account's freshly fetched windows and send threshold reminders
+    pub async fn record_account(&self, account: &CodexAccount) -> Result<(), DomainError> {
+        let recorded_at = account.quota_checked_at().unwrap_or_else(Utc::now);
+        let snapshots = CodexUsageSnapshot::from_account(account, recorded_at);
+        if snapshots.is_empty() {
+            return Ok(());
+        }
+        self.history_repo.record(&snapshots).await?;
+
+        let settings = self.history_repo.get_settings().await?;
+        if !settings.threshold_reminders() {
+            return Ok(());
+        }
+        for snapshot in &snapshots {
+            if let Some(kind) = snapshot.threshold_reminder() {
+                self.remind(account.email(), snapshot, kind).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Send reset reminders for windows that reset since the last poll
+    ///
+    /// Returns the number of reminders sent.
+    pub async fn check_resets(&self) -> Result<u32, DomainError> {
+        let settings = self.history_repo.get_settings().await?;
+        if !settings.reset_reminders() {
+            return Ok(0);
+        }
+
+        let now = Utc::now();
+        let due: Vec<CodexUsageSnapshot> = self
+            .history_repo
+            .latest()
+            .await?
+            .into_iter()
+            .filter(|snapshot| snapshot.is_reset_reminder_due(now))
+            .collect();
+        if due.is_empty() {
+            return Ok(0);
+        }
+
+        let emails = self.account_emails().await?;
+        let mut sent = 0;
+        for snapshot in &due {
+            let Some(email) = emails.get(&snapshot.account_id) else {
+                continue;
+            };
+            if self
+                .remind(email, snapshot, CodexUsageReminderKind::Reset)
+                .await?
+            {
+                sent += 1;
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Usage of one account's windows over the last `days` days (default 7)
+    pub async fn usage_history(
+        &self,
+        account_id: &str,
+        days: Option<u32>,
+    ) -> Result<CodexUsageHistoryDto, DomainError> {
+        let days = days.unwrap_or(DEFAULT_HISTORY_DAYS);
+        if days == 0 || days as i64 > RETENTION_DAYS {
+            return Err(DomainError::Validation(format!(
+                "History range must be between 1 and {} days",
+                RETENTION_DAYS
+            )));
+        }
+
+        let account_id = CodexAccountId::from_string(account_id);
+        let until = Utc::now();
+        let since = until - Duration::days(days as i64);
+        let snapshots = self.history_repo.list(&account_id, since, until).await?;
+
+        let series = [
+            CodexUsageWindowKind::Primary,
+            CodexUsageWindowKind::Secondary,
+        ]
+        .into_iter()
+        .filter_map(|window| {
+            let points: Vec<&CodexUsageSnapshot> = snapshots
+                .iter()
+                .filter(|snapshot| snapshot.window == window)
+                .collect();
+            let last = points.last()?;
+            Some(CodexUsageSeriesDto {
+                window: window.as_str().to_string(),
+                window_minutes: last.window_minutes,
+                points: points
+                    .iter()
+                    .map(|snapshot| CodexUsagePointDto {
+                        recorded_at: snapshot.recorded_at.to_rfc3339(),
+                        used_percent: snapshot.used_percent,
+                        resets_at: snapshot.resets_at.map(|at| at.to_rfc3339()),
+                    })
+                    .collect(),
+            })
+        })
+        .collect();
+
+        Ok(CodexUsageHistoryDto {
+            account_id: account_id.as_str().to_string(),
+            since: since.to_rfc3339(),
+            until: until.to_rfc3339(),
+            series,
+        })
+    }
+
+    /// Latest usage of every window, soonest reset first
+    pub async fn reset_overview(&self) -> Result<Vec<CodexUsageResetDto>, DomainError> {
+        let now = Utc::now();
+        let emails = self.account_emails().await?;
+        let mut overview: Vec<CodexUsageResetDto> = self
+            .history_repo
+            .latest()
+            .await?
+            .into_iter()
+            .filter_map(|snapshot| {
+                let email = emails.get(&snapshot.account_id)?.clone();
+                Some(CodexUsageResetDto {
+                    account_id: snapshot.account_id.as_str().to_string(),
+                    email,
+                    window: snapshot.window.as_str().to_string(),
+                    used_percent: snapshot.used_percent,
+                    window_minutes: snapshot.window_minutes,
+                    resets_at: snapshot.resets_at.map(|at| at.to_rfc3339()),
+                    seconds_until_reset: snapshot.seconds_until_reset(now),
+                    recorded_at: snapshot.recorded_at.to_rfc3339(),
+                })
+            })
+            .collect();
+
+        overview.sort_by_key(|entry| {
+            (
+                entry.seconds_until_reset.unwrap_or(i64::MAX),
+                entry.email.clone(),
+            )
+        });
+        Ok(overview)
+    }
+
+    pub async fn get_settings(&self) -> Result<CodexUsageReminderSettingsDto, DomainError> {
+        let settings = self.history_repo.get_settings().await?;
+        Ok(CodexUsageReminderSettingsDto::from(&settings))
+    }
+
+    pub async fn update_settings(
+        &self,
+        input: CodexUsageReminderSettingsDto,
+    ) -> Result<CodexUsageReminderSettingsDto, DomainError> {
+        let mut settings = self.history_repo.get_settings().await?;
+        settings.update(input.threshold_reminders, input.reset_reminders);
+        self.history_repo.save_settings(&settings).await?;
+
+        Ok(CodexUsageReminderSettingsDto::from(&settings))
+    }
+
+    /// Start the periodic reset check, pruning old snapshots hourly
+    pub async fn start_background_task(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+
+        let handle = tokio::spawn(async move {
+            let mut check_interval =
+                tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+            let mut checks: u32 = 0;
+
+            loop {
+                check_interval.tick().await;
+
+                if let Err(e) = service.check_resets().await {
+                    error!("[codex_usage] Reset check failed: {}", e);
+                }
+
+                if checks.is_multiple_of(PRUNE_EVERY_CHECKS) {
+                    let cutoff = Utc::now() - Duration::days(RETENTION_DAYS);
+                    match service.history_repo.delete_before(cutoff).await {
+                        Ok(0) => {}
+                        Ok(deleted) => info!("[codex_usage] Pruned {} old snapshots", deleted),
+                        Err(e) => error!("[codex_usage] Prune failed: {}", e),
+                    }
+                }
+                checks = checks.wrapping_add(1);
+            }
+        });
+
+        let mut background = self.background_handle.lock().await;
+        if let Some(previous) = background.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Send a reminder once per window cycle, returns whether it was sent
+    async fn remind(
+        &self,
+        email: &str,
+        snapshot: &CodexUsageSnapshot,
+        kind: CodexUsageReminderKind,
+    ) -> Result<bool, DomainError> {
+        let claimed = self
+            .history_repo
+            .claim_reminder(
+                &snapshot.account_id,
+                snapshot.window,
+                kind,
+                snapshot.cycle(),
+            )
+            .await?;
+        if !claimed {
+            return Ok(false);
+        }
+
+        if let Err(e) = self
+            .notification_service
+            .send_codex_usage_reminder(
+                email,
+                snapshot.window,
+                kind,
+                snapshot.used_percent,
+                snapshot.resets_at,
+            )
+            .await
+        {
+            warn!(
+                "[codex_usage] Failed to send {} reminder: {}",
+                kind.as_str(),
+                e
+            );
+        }
+        Ok(true)
+    }
+
+    async fn account_emails(&self) -> Result<HashMap<CodexAccountId, String>, DomainError> {
+        Ok(self
+            .codex_account_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|account| (account.id().clone(), account.email().to_string()))
+            .collect())
+    }
+}
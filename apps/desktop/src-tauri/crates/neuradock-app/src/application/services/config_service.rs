@@ -1,10 +1,12 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicU8, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Arc, RwLock};
 use tauri::{AppHandle, Manager};
-use tracing::info;
+use tracing::{info, warn};
+
+use super::i18n;
 
 /// Log level configuration
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -42,16 +44,46 @@ impl LogLevel {
     }
 }
 
+/// Default port of the Prometheus metrics exporter
+pub const DEFAULT_METRICS_PORT: u16 = 9464;
+
+/// Opt-in localhost `/metrics` endpoint settings
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MetricsExporterSettings {
+    pub enabled: bool,
+    pub port: u16,
+}
+
+impl Default for MetricsExporterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_METRICS_PORT,
+        }
+    }
+}
+
 /// Persistent configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct AppConfig {
     log_level: LogLevel,
+    #[serde(default)]
+    metrics_exporter: MetricsExporterSettings,
+    /// Backend translation locale, `None` until the frontend reports its language
+    #[serde(default)]
+    locale: Option<String>,
+    /// Notify when a provider adds or drops a model configured in Claude Code or Codex
+    #[serde(default)]
+    notify_model_catalog_changes: bool,
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
             log_level: LogLevel::Info,
+            metrics_exporter: MetricsExporterSettings::default(),
+            locale: None,
+            notify_model_catalog_changes: false,
         }
     }
 }
@@ -59,6 +91,9 @@ impl Default for AppConfig {
 /// Application configuration service
 pub struct ConfigService {
     log_level: Arc<AtomicU8>,
+    metrics_exporter: RwLock<MetricsExporterSettings>,
+    locale: RwLock<Option<&'static str>>,
+    notify_model_catalog_changes: AtomicBool,
     config_path: PathBuf,
 }
 
@@ -87,8 +122,20 @@ impl ConfigService {
         info!("📁 Config loaded from: {:?}", config_path);
         info!("🔧 Initial log level: {}", config.log_level.as_str());
 
+        let locale = config.locale.as_deref().and_then(|tag| {
+            let locale = i18n::set_locale(tag);
+            if locale.is_none() {
+                warn!("Ignoring unsupported saved locale: {}", tag);
+            }
+            locale
+        });
+        info!("🌐 Notification locale: {}", i18n::current_locale());
+
         Ok(Self {
             log_level: Arc::new(AtomicU8::new(config.log_level as u8)),
+            metrics_exporter: RwLock::new(config.metrics_exporter),
+            locale: RwLock::new(locale),
+            notify_model_catalog_changes: AtomicBool::new(config.notify_model_catalog_changes),
             config_path,
         })
     }
@@ -104,17 +151,87 @@ impl ConfigService {
         info!("🔧 Changing log level to: {}", level.as_str());
         self.log_level.store(level as u8, Ordering::Relaxed);
 
-        // Persist to disk
-        let config = AppConfig { log_level: level };
-
-        let content = serde_json::to_string_pretty(&config)?;
-        std::fs::write(&self.config_path, content)?;
+        self.save()?;
 
         info!("💾 Log level saved to: {:?}", self.config_path);
         info!("⚠️  Log level will take effect on next app restart");
 
         Ok(())
     }
+
+    /// Get metrics exporter settings
+    pub fn get_metrics_exporter_settings(&self) -> MetricsExporterSettings {
+        *self
+            .metrics_exporter
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Set metrics exporter settings and persist to disk
+    pub fn set_metrics_exporter_settings(&self, settings: MetricsExporterSettings) -> Result<()> {
+        *self
+            .metrics_exporter
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = settings;
+        self.save()?;
+
+        info!(
+            "💾 Metrics exporter settings saved (enabled: {}, port: {})",
+            settings.enabled, settings.port
+        );
+        Ok(())
+    }
+
+    /// Saved locale, `None` while notifications still follow `NEURADOCK_LOCALE` or the default
+    pub fn get_locale(&self) -> Option<&'static str> {
+        *self
+            .locale
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Set the locale backend messages are translated to and persist it, applied immediately
+    pub fn set_locale(&self, locale: &'static str) -> Result<()> {
+        let locale = i18n::set_locale(locale)
+            .ok_or_else(|| anyhow::anyhow!("Unsupported locale: {}", locale))?;
+        *self
+            .locale
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(locale);
+        self.save()?;
+
+        info!("💾 Locale saved: {}", locale);
+        Ok(())
+    }
+
+    /// Whether model catalog changes affecting Claude Code or Codex are notified
+    pub fn notify_model_catalog_changes(&self) -> bool {
+        self.notify_model_catalog_changes.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable model catalog change notifications and persist the choice
+    pub fn set_notify_model_catalog_changes(&self, enabled: bool) -> Result<()> {
+        self.notify_model_catalog_changes
+            .store(enabled, Ordering::Relaxed);
+        self.save()?;
+
+        info!("💾 Model catalog change notifications: {}", enabled);
+        Ok(())
+    }
+
+    /// Persist the whole configuration so saving one setting keeps the others
+    fn save(&self) -> Result<()> {
+        let config = AppConfig {
+            log_level: self.get_log_level(),
+            metrics_exporter: self.get_metrics_exporter_settings(),
+            locale: self.get_locale().map(str::to_string),
+            notify_model_catalog_changes: self.notify_model_catalog_changes(),
+        };
+
+        let content = serde_json::to_string_pretty(&config)?;
+        std::fs::write(&self.config_path, content)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -135,4 +252,14 @@ mod tests {
         assert_eq!(LogLevel::Info.as_str(), "info");
         assert_eq!(LogLevel::Trace.as_str(), "trace");
     }
+
+    #[test]
+    fn test_app_config_without_metrics_exporter_uses_defaults() {
+        let config: AppConfig = serde_json::from_str(r#"{"log_level":"debug"}"#).unwrap();
+        assert_eq!(config.log_level, LogLevel::Debug);
+        assert_eq!(config.metrics_exporter, MetricsExporterSettings::default());
+        assert!(!config.metrics_exporter.enabled);
+        assert_eq!(config.locale, None);
+        assert!(!config.notify_model_catalog_changes);
+    }
 }
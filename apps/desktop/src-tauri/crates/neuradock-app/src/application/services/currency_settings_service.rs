@@ -0,0 +1,115 @@
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use neuradock_domain::check_in::ProviderRepository;
+use neuradock_domain::currency::{
+    BalanceUnit, CurrencySettings, CurrencySettingsRepository, ProviderBalanceUnit,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::application::dtos::{
+    CurrencySettingsDto, ExchangeRateDto, ProviderBalanceUnitDto, UpdateCurrencySettingsInput,
+};
+
+pub struct CurrencySettingsService {
+    repo: Arc<dyn CurrencySettingsRepository>,
+    provider_repo: Arc<dyn ProviderRepository>,
+}
+
+impl CurrencySettingsService {
+    pub fn new(
+        repo: Arc<dyn CurrencySettingsRepository>,
+        provider_repo: Arc<dyn ProviderRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            provider_repo,
+        }
+    }
+
+    pub async fn get(&self) -> Result<CurrencySettingsDto, DomainError> {
+        let settings = self.repo.get().await?;
+        self.to_dto(&settings).await
+    }
+
+    pub async fn update(
+        &self,
+        input: UpdateCurrencySettingsInput,
+    ) -> Result<CurrencySettingsDto, DomainError> {
+        let provider_ids = self
+            .provider_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|p| p.id().as_str().to_string())
+            .collect::<Vec<_>>();
+
+        let mut provider_units = HashMap::new();
+        for unit in input.provider_units {
+            if !provider_ids.contains(&unit.provider_id) {
+                return Err(DomainError::Validation(format!(
+                    "Provider not found: {}",
+                    unit.provider_id
+                )));
+            }
+            provider_units.insert(
+                unit.provider_id,
+                ProviderBalanceUnit {
+                    unit: BalanceUnit::from_str(&unit.unit)?,
+                    conversion_factor: unit.conversion_factor,
+                },
+            );
+        }
+
+        let exchange_rates = input
+            .exchange_rates
+            .into_iter()
+            .map(|rate| (rate.currency, rate.units_per_usd))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut settings = self.repo.get().await?;
+        settings.update(&input.display_currency, exchange_rates, provider_units)?;
+        self.repo.save(&settings).await?;
+
+        self.to_dto(&settings).await
+    }
+
+    async fn to_dto(
+        &self,
+        settings: &CurrencySettings,
+    ) -> Result<CurrencySettingsDto, DomainError> {
+        let mut provider_units = self
+            .provider_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|provider| {
+                let provider_id = provider.id().as_str();
+                let unit = settings.unit_for(provider_id);
+                ProviderBalanceUnitDto {
+                    provider_id: provider_id.to_string(),
+                    provider_name: provider.name().to_string(),
+                    unit: unit.unit.as_str().to_string(),
+                    conversion_factor: unit.conversion_factor,
+                    normalization_factor: settings.normalization_factor(provider_id),
+                }
+            })
+            .collect::<Vec<_>>();
+        provider_units.sort_by(|a, b| a.provider_name.cmp(&b.provider_name));
+
+        Ok(CurrencySettingsDto {
+            display_currency: settings.display_currency().to_string(),
+            display_symbol: settings.display_symbol(),
+            exchange_rates: settings
+                .exchange_rates()
+                .iter()
+                .map(|(currency, rate)| ExchangeRateDto {
+                    currency: currency.clone(),
+                    units_per_usd: *rate,
+                })
+                .collect(),
+            provider_units,
+        })
+    }
+}
@@ -0,0 +1,272 @@
+use chrono::{Local, Utc};
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::application::dtos::{
+    AppDiagnosticsDto, BrowserDiagnosticsDto, DatabaseDiagnosticsDto, DiagnosticsExportDto,
+    DiagnosticsReportDto, EntityCountsDto, ProxyConnectivityResultDto, ScheduledTaskDiagnosticsDto,
+    SchedulerDiagnosticsDto, TestProxyConnectivityInput, WafCookieCacheDiagnosticsDto,
+};
+use crate::application::services::{AutoCheckInScheduler, ProxyRoutingService};
+use neuradock_domain::account::AccountRepository;
+use neuradock_domain::check_in::ProviderRepository;
+use neuradock_domain::codex::CodexAccountRepository;
+use neuradock_domain::shared::DomainError;
+use neuradock_domain::waf_cookies::WafCookiesRepository;
+use neuradock_infrastructure::diagnostics::{recent_log_errors, redact_secrets, ZipArchive};
+use neuradock_infrastructure::http::waf_bypass::check_available_browser;
+use neuradock_infrastructure::logging;
+use neuradock_infrastructure::persistence::Database;
+
+/// Warning and error lines copied from the logs into the bundle
+const MAX_LOG_LINES: usize = 500;
+
+/// Gathers a self-diagnostics report and bundles it with recent log errors into a zip
+///
+/// Nothing secret is collected in the first place (no cookie values, keys or tokens),
+/// and log lines and error messages go through `redact_secrets`.
+pub struct DiagnosticsService {
+    pool: Arc<SqlitePool>,
+    account_repo: Arc<dyn AccountRepository>,
+    provider_repo: Arc<dyn ProviderRepository>,
+    codex_account_repo: Arc<dyn CodexAccountRepository>,
+    waf_cookies_repo: Arc<dyn WafCookiesRepository>,
+    scheduler: Arc<AutoCheckInScheduler>,
+    proxy_routing: Arc<ProxyRoutingService>,
+    /// Where bundles go when no destination is given
+    output_dir: PathBuf,
+}
+
+impl DiagnosticsService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        pool: Arc<SqlitePool>,
+        account_repo: Arc<dyn AccountRepository>,
+        provider_repo: Arc<dyn ProviderRepository>,
+        codex_account_repo: Arc<dyn CodexAccountRepository>,
+        waf_cookies_repo: Arc<dyn WafCookiesRepository>,
+        scheduler: Arc<AutoCheckInScheduler>,
+        proxy_routing: Arc<ProxyRoutingService>,
+        output_dir: PathBuf,
+    ) -> Self {
+        Self {
+            pool,
+            account_repo,
+            provider_repo,
+            codex_account_repo,
+            waf_cookies_repo,
+            scheduler,
+            proxy_routing,
+            output_dir,
+        }
+    }
+
+    /// Collect the report and write it as a zip to `destination`, or the diagnostics directory
+    pub async fn export(
+        &self,
+        destination: Option<PathBuf>,
+    ) -> Result<DiagnosticsExportDto, DomainError> {
+        let mut collection_errors = Vec::new();
+
+        let log_lines = match logging::get_log_dir() {
+            Some(log_dir) => recent_log_errors(&log_dir, MAX_LOG_LINES).unwrap_or_else(|e| {
+                collection_errors.push(format!("logs: {}", e));
+                Vec::new()
+            }),
+            None => {
+                collection_errors.push("logs: log directory not initialized".to_string());
+                Vec::new()
+            }
+        };
+
+        let mut report = self.collect(collection_errors).await;
+        report.recent_error_count = log_lines.len() as u32;
+
+        let report_json = serde_json::to_string_pretty(&report)
+            .map_err(|e| DomainError::Serialization(e.to_string()))?;
+        let mut recent_errors = log_lines.join("\n");
+        if !recent_errors.is_empty() {
+            recent_errors.push('\n');
+        }
+
+        let mut archive = ZipArchive::new();
+        archive
+            .add_file("report.json", report_json.as_bytes())
+            .and_then(|_| archive.add_file("logs/recent-errors.log", recent_errors.as_bytes()))
+            .map_err(|e| DomainError::Infrastructure(format!("Failed to build zip: {}", e)))?;
+        let bytes = archive.finish();
+
+        let path = match destination {
+            Some(path) => path,
+            None => self.output_dir.join(format!(
+                "neuradock-diagnostics-{}.zip",
+                Local::now().format("%Y%m%d-%H%M%S")
+            )),
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                DomainError::Infrastructure(format!("Failed to create {}: {}", parent.display(), e))
+            })?;
+        }
+        std::fs::write(&path, &bytes).map_err(|e| {
+            DomainError::Infrastructure(format!("Failed to write {}: {}", path.display(), e))
+        })?;
+
+        info!(
+            "Diagnostics bundle written to {} ({} bytes)",
+            path.display(),
+            bytes.len()
+        );
+
+        Ok(DiagnosticsExportDto {
+            path: path.display().to_string(),
+            size_bytes: bytes.len() as u64,
+            report,
+        })
+    }
+
+    /// Each section is collected independently, a failure is noted instead of aborting
+    async fn collect(&self, mut collection_errors: Vec<String>) -> DiagnosticsReportDto {
+        let mut note = |section: &str, error: &dyn std::fmt::Display| {
+            let message = redact_secrets(&format!("{}: {}", section, error));
+            warn!("Diagnostics: {}", message);
+            collection_errors.push(message);
+        };
+
+        let database = match Database::schema_status(&self.pool).await {
+            Ok(status) => Some(DatabaseDiagnosticsDto {
+                latest_applied_version: status.latest_applied(),
+                applied_count: status.applied.len() as u32,
+                failed: status
+                    .applied
+                    .iter()
+                    .filter(|migration| !migration.success)
+                    .map(|migration| format!("{} {}", migration.version, migration.description))
+                    .collect(),
+                pending: status
+                    .pending
+                    .iter()
+                    .map(|(version, description)| format!("{} {}", version, description))
+                    .collect(),
+            }),
+            Err(e) => {
+                note("database", &e);
+                None
+            }
+        };
+
+        let counts = match self.entity_counts().await {
+            Ok(counts) => Some(counts),
+            Err(e) => {
+                note("counts", &e);
+                None
+            }
+        };
+
+        let proxy = match self
+            .proxy_routing
+            .test_connectivity(TestProxyConnectivityInput {
+                account_id: None,
+                provider_id: None,
+                pool_id: None,
+                target_url: None,
+            })
+            .await
+        {
+            Ok(results) => results
+                .into_iter()
+                .map(|result| ProxyConnectivityResultDto {
+                    error: result.error.map(|error| redact_secrets(&error)),
+                    ..result
+                })
+                .collect(),
+            Err(e) => {
+                note("proxy", &e);
+                Vec::new()
+            }
+        };
+
+        let waf_cookies = match self.waf_cookies_repo.find_all().await {
+            Ok(entries) => {
+                let now = Utc::now();
+                entries
+                    .into_iter()
+                    .map(|entry| {
+                        let mut cookie_names: Vec<String> = entry.cookies.keys().cloned().collect();
+                        cookie_names.sort();
+                        WafCookieCacheDiagnosticsDto {
+                            valid: entry.is_valid(),
+                            provider_id: entry.provider_id,
+                            cookie_names,
+                            age_seconds: (now - entry.fetched_at).num_seconds(),
+                            expires_in_seconds: (entry.expires_at - now).num_seconds(),
+                        }
+                    })
+                    .collect()
+            }
+            Err(e) => {
+                note("waf_cookies", &e);
+                Vec::new()
+            }
+        };
+
+        let liveness = self.scheduler.liveness().await;
+        let browser_path = check_available_browser();
+
+        DiagnosticsReportDto {
+            generated_at: Utc::now().to_rfc3339(),
+            app: AppDiagnosticsDto {
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                profile: if cfg!(debug_assertions) {
+                    "debug"
+                } else {
+                    "release"
+                }
+                .to_string(),
+                os: std::env::consts::OS.to_string(),
+                arch: std::env::consts::ARCH.to_string(),
+            },
+            database,
+            counts,
+            scheduler: SchedulerDiagnosticsDto {
+                health_check_running: liveness.health_check_running,
+                tasks: liveness
+                    .tasks
+                    .into_iter()
+                    .map(|task| ScheduledTaskDiagnosticsDto {
+                        account_id: task.account_id,
+                        account_name: task.account_name,
+                        alive: task.alive,
+                        last_execution: task.last_execution.map(|at| at.to_rfc3339()),
+                    })
+                    .collect(),
+            },
+            browser: BrowserDiagnosticsDto {
+                available: browser_path.is_some(),
+                path: browser_path,
+            },
+            proxy,
+            waf_cookies,
+            recent_error_count: 0,
+            collection_errors,
+        }
+    }
+
+    async fn entity_counts(&self) -> Result<EntityCountsDto, DomainError> {
+        let accounts = self.account_repo.find_all().await?;
+        let providers = self.provider_repo.find_all().await?;
+        let codex_accounts = self.codex_account_repo.count().await?;
+
+        Ok(EntityCountsDto {
+            accounts: accounts.len() as u32,
+            enabled_accounts: accounts
+                .iter()
+                .filter(|account| account.is_enabled())
+                .count() as u32,
+            providers: providers.len() as u32,
+            codex_accounts: codex_accounts as u32,
+        })
+    }
+}
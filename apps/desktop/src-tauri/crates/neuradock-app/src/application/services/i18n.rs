@@ -1,44 +1,277 @@
 use once_cell::sync::Lazy;
 use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::RwLock;
 use tracing::error;
 
-static TRANSLATIONS_ZH: Lazy<Value> = Lazy::new(|| {
-    let json_str = include_str!("i18n/locales/zh-CN.json");
+/// Locales with a translation file, tags match the frontend's i18next languages
+pub const SUPPORTED_LOCALES: [&str; 4] = ["zh-CN", "en-US", "zh-TW", "ja-JP"];
+
+/// Locale used until one is configured
+pub const DEFAULT_LOCALE: &str = "zh-CN";
+
+/// Last locale tried before falling back to the raw key
+const FALLBACK_LOCALE: &str = "en-US";
+
+fn parse_locale(locale: &str, json_str: &str) -> Value {
     serde_json::from_str(json_str).unwrap_or_else(|e| {
-        error!("Failed to parse zh-CN.json: {}", e);
+        error!("Failed to parse {}.json: {}", locale, e);
         Value::Object(serde_json::Map::new())
     })
+}
+
+static TRANSLATIONS: Lazy<HashMap<&'static str, Value>> = Lazy::new(|| {
+    HashMap::from([
+        (
+            "zh-CN",
+            parse_locale("zh-CN", include_str!("i18n/locales/zh-CN.json")),
+        ),
+        (
+            "en-US",
+            parse_locale("en-US", include_str!("i18n/locales/en-US.json")),
+        ),
+        (
+            "zh-TW",
+            parse_locale("zh-TW", include_str!("i18n/locales/zh-TW.json")),
+        ),
+        (
+            "ja-JP",
+            parse_locale("ja-JP", include_str!("i18n/locales/ja-JP.json")),
+        ),
+    ])
 });
 
-static TRANSLATIONS_EN: Lazy<Value> = Lazy::new(|| {
-    let json_str = include_str!("i18n/locales/en-US.json");
-    serde_json::from_str(json_str).unwrap_or_else(|e| {
-        error!("Failed to parse en-US.json: {}", e);
-        Value::Object(serde_json::Map::new())
-    })
+/// `NEURADOCK_LOCALE` still selects the locale until the saved setting is applied
+static CURRENT_LOCALE: Lazy<RwLock<&'static str>> = Lazy::new(|| {
+    let locale = std::env::var("NEURADOCK_LOCALE")
+        .ok()
+        .and_then(|tag| normalize_locale(&tag))
+        .unwrap_or(DEFAULT_LOCALE);
+    RwLock::new(locale)
 });
 
+/// Map a locale tag to a supported locale
+///
+/// Accepts `_` or `-` separators and any region, e.g. "zh_CN", "zh-Hant", "ja" or "en-GB".
+pub fn normalize_locale(tag: &str) -> Option<&'static str> {
+    let tag = tag.trim().replace('_', "-").to_ascii_lowercase();
+    let mut subtags = tag.split('-');
+    match subtags.next()? {
+        "zh" => {
+            let traditional = subtags.any(|s| matches!(s, "tw" | "hk" | "mo" | "hant"));
+            Some(if traditional { "zh-TW" } else { "zh-CN" })
+        }
+        "en" => Some("en-US"),
+        "ja" => Some("ja-JP"),
+        _ => None,
+    }
+}
+
+/// Locale notifications are currently rendered in
+pub fn current_locale() -> &'static str {
+    *CURRENT_LOCALE
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Switch the locale for every following translation
+///
+/// Returns the supported locale `tag` maps to, or `None` (locale unchanged) when unsupported.
+pub fn set_locale(tag: &str) -> Option<&'static str> {
+    let locale = normalize_locale(tag)?;
+    *CURRENT_LOCALE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = locale;
+    Some(locale)
+}
+
 /// Get translation by key path (e.g., "notification.checkIn.success.title")
-/// Selects language from `NEURADOCK_LOCALE` env var (prefix match: "zh" => Chinese, otherwise English).
 pub fn t(key: &str) -> String {
-    let locale = std::env::var("NEURADOCK_LOCALE").unwrap_or_else(|_| "zh_CN".to_string());
-    let translations = if locale.to_lowercase().starts_with("zh") {
-        &*TRANSLATIONS_ZH
+    t_args(key, &[])
+}
+
+/// Get translation by key path and fill in `{{name}}` placeholders
+///
+/// A `count` argument selects the plural form first: `key_one` or `key_other` as in
+/// i18next, then `key` itself. Missing keys fall back to zh-CN for zh-TW, then to en-US,
+/// then to the key.
+pub fn t_args(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let count = args
+        .iter()
+        .find(|(name, _)| *name == "count")
+        .and_then(|(_, value)| value.to_string().parse::<f64>().ok());
+    let template = translate(current_locale(), key, count);
+    interpolate(&template, args)
+}
+
+fn translate(locale: &str, key: &str, count: Option<f64>) -> String {
+    for candidate in fallback_chain(locale) {
+        if let Some(count) = count {
+            for category in [plural_category(candidate, count), "other"] {
+                if let Some(value) = lookup(candidate, &format!("{}_{}", key, category)) {
+                    return value.to_string();
+                }
+            }
+        }
+        if let Some(value) = lookup(candidate, key) {
+            return value.to_string();
+        }
+    }
+    key.to_string()
+}
+
+fn fallback_chain(locale: &str) -> Vec<&str> {
+    let mut chain = vec![locale];
+    if locale == "zh-TW" {
+        chain.push("zh-CN");
+    }
+    if locale != FALLBACK_LOCALE {
+        chain.push(FALLBACK_LOCALE);
+    }
+    chain
+}
+
+/// CLDR plural category, Chinese and Japanese don't inflect for number
+fn plural_category(locale: &str, count: f64) -> &'static str {
+    if locale.starts_with("en") && count == 1.0 {
+        "one"
     } else {
-        &*TRANSLATIONS_EN
-    };
+        "other"
+    }
+}
 
+fn lookup(locale: &str, key: &str) -> Option<&'static str> {
     // Navigate the nested JSON structure using the key path
-    let parts: Vec<&str> = key.split('.').collect();
-    let mut current = translations;
+    let mut current = TRANSLATIONS.get(locale)?;
+    for part in key.split('.') {
+        current = current.get(part)?;
+    }
+    current.as_str()
+}
+
+fn interpolate(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{{{}}}}}", name), &value.to_string())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    #[test]
+    fn test_normalize_locale() {
+        assert_eq!(normalize_locale("zh_CN"), Some("zh-CN"));
+        assert_eq!(normalize_locale("zh"), Some("zh-CN"));
+        assert_eq!(normalize_locale("zh-Hant-HK"), Some("zh-TW"));
+        assert_eq!(normalize_locale("zh_TW"), Some("zh-TW"));
+        assert_eq!(normalize_locale("en-GB"), Some("en-US"));
+        assert_eq!(normalize_locale("ja"), Some("ja-JP"));
+        assert_eq!(normalize_locale("fr-FR"), None);
+        assert_eq!(normalize_locale(""), None);
+    }
+
+    #[test]
+    fn test_plural_and_interpolation() {
+        let render = |locale, count: i64| {
+            let template = translate(
+                locale,
+                "notification.tokenWatch.expiresIn",
+                Some(count as f64),
+            );
+            interpolate(&template, &[("count", &count)])
+        };
 
-    for part in parts {
-        match current.get(part) {
-            Some(value) => current = value,
-            None => return key.to_string(), // Fallback to key if not found
+        assert_eq!(render("en-US", 1), "Expires in 1 day");
+        assert_eq!(render("en-US", 3), "Expires in 3 days");
+        assert_eq!(render("zh-CN", 1), "1 天后过期");
+        assert_eq!(render("ja-JP", 3), "あと 3 日で期限切れ");
+
+        let template = translate("en-US", "notification.checkIn.success.simple", None);
+        assert_eq!(
+            interpolate(&template, &[("account", &"Alice")]),
+            "✅ Alice checked in successfully!"
+        );
+    }
+
+    #[test]
+    fn test_missing_key_falls_back() {
+        assert_eq!(fallback_chain("zh-TW"), vec!["zh-TW", "zh-CN", "en-US"]);
+        assert_eq!(fallback_chain("en-US"), vec!["en-US"]);
+        assert_eq!(
+            translate("ja-JP", "notification.unknown", None),
+            "notification.unknown"
+        );
+    }
+
+    /// Keys passed as literals to `t(..)` or `t_args(..)` anywhere in the crate's sources
+    fn keys_used_in_code() -> BTreeSet<String> {
+        fn visit(dir: &Path, keys: &mut BTreeSet<String>) {
+            for entry in std::fs::read_dir(dir).expect("read source dir") {
+                let path = entry.expect("read dir entry").path();
+                if path.is_dir() {
+                    visit(&path, keys);
+                } else if path.extension().is_some_and(|ext| ext == "rs") {
+                    let source = std::fs::read_to_string(&path).expect("read source file");
+                    collect_keys(&source, keys);
+                }
+            }
+        }
+
+        let mut keys = BTreeSet::new();
+        visit(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut keys,
+        );
+        keys
+    }
+
+    fn collect_keys(source: &str, keys: &mut BTreeSet<String>) {
+        for call in ["t(", "t_args("] {
+            for (start, _) in source.match_indices(call) {
+                let is_call = source[..start]
+                    .chars()
+                    .next_back()
+                    .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '.'));
+                let Some(rest) = source[start + call.len()..].trim_start().strip_prefix('"') else {
+                    continue;
+                };
+                let Some(key) = rest.split('"').next() else {
+                    continue;
+                };
+                // Only dotted key paths, skips string literals that merely contain "t("
+                let is_key = key.contains('.')
+                    && key
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_');
+                if is_call && is_key {
+                    keys.insert(key.to_string());
+                }
+            }
         }
     }
 
-    // Return the string value or the key as fallback
-    current.as_str().unwrap_or(key).to_string()
+    #[test]
+    fn test_keys_used_in_code_exist_in_every_locale() {
+        let keys = keys_used_in_code();
+        assert!(
+            keys.contains("notification.checkIn.success.title"),
+            "key scan found nothing, update collect_keys"
+        );
+
+        let mut missing = Vec::new();
+        for locale in SUPPORTED_LOCALES {
+            for key in &keys {
+                let plural = format!("{}_other", key);
+                if lookup(locale, key).is_none() && lookup(locale, &plural).is_none() {
+                    missing.push(format!("{}: {}", locale, key));
+                }
+            }
+        }
+        assert!(missing.is_empty(), "missing translations: {:?}", missing);
+    }
 }
@@ -0,0 +1,160 @@
+use chrono::Utc;
+use log::warn;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::dtos::IndependentKeyDto;
+use crate::application::services::ProxyRoutingService;
+use neuradock_domain::independent_key::{
+    IndependentApiKey, IndependentKeyId, IndependentKeyRepository, KeyCredit, KeyProviderType,
+    KeyValidation, KeyValidationStatus,
+};
+use neuradock_domain::proxy_config::ProxyTarget;
+use neuradock_domain::shared::DomainError;
+use neuradock_infrastructure::http::key_probe::{
+    api_url, fetch_relay_credit, probe_api_key, KeyAuthStyle,
+};
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Checks independent API keys against their provider's models endpoint
+pub struct IndependentKeyValidationService {
+    independent_key_repo: Arc<dyn IndependentKeyRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
+}
+
+impl IndependentKeyValidationService {
+    pub fn new(
+        independent_key_repo: Arc<dyn IndependentKeyRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
+    ) -> Self {
+        Self {
+            independent_key_repo,
+            proxy_routing,
+        }
+    }
+
+    /// Validate a key, record the outcome and return the updated key
+    pub async fn validate(&self, key_id: i64) -> Result<IndependentKeyDto, DomainError> {
+        let id = IndependentKeyId::new(key_id);
+        let mut key = self
+            .independent_key_repo
+            .find_by_id(&id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Key with ID {} not found", key_id)))?;
+
+        let validation = self.probe(&key).await?;
+        self.independent_key_repo
+            .record_validation(&id, &validation)
+            .await?;
+        key.record_validation(validation);
+
+        IndependentKeyDto::try_from_domain(&key)
+    }
+
+    async fn probe(&self, key: &IndependentApiKey) -> Result<KeyValidation, DomainError> {
+        if key.base_url().trim().is_empty() {
+            return Err(DomainError::Validation(
+                "Key has no base URL to validate against".to_string(),
+            ));
+        }
+
+        let models_url = api_url(key.base_url(), "/models");
+        let host = url::Url::parse(&models_url)
+            .map_err(|e| DomainError::Validation(format!("Invalid base URL: {}", e)))?
+            .host_str()
+            .map(str::to_string);
+        let proxy_url = self
+            .proxy_routing
+            .resolve(ProxyTarget {
+                account_id: None,
+                provider_id: None,
+                host: host.as_deref(),
+            })
+            .await?;
+
+        let auth = match key.provider_type() {
+            KeyProviderType::Anthropic => KeyAuthStyle::Anthropic,
+            KeyProviderType::OpenAI | KeyProviderType::Custom => KeyAuthStyle::Bearer,
+        };
+        let result = probe_api_key(
+            key.base_url(),
+            key.api_key(),
+            auth,
+            key.organization_id(),
+            proxy_url.as_deref(),
+            PROBE_TIMEOUT,
+        )
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        let status = validation_status(result.status_code, result.error.is_some());
+
+        let credit = if status == KeyValidationStatus::Valid && key.is_openai_compatible_relay() {
+            match fetch_relay_credit(
+                key.base_url(),
+                key.api_key(),
+                proxy_url.as_deref(),
+                PROBE_TIMEOUT,
+            )
+            .await
+            {
+                Ok(credit) => credit.map(|credit| KeyCredit {
+                    total: credit.total_usd,
+                    used: credit.used_usd,
+                }),
+                Err(e) => {
+                    warn!("Failed to fetch credit for key {}: {}", key.name(), e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        Ok(KeyValidation {
+            status,
+            status_code: result.status_code,
+            message: result.error,
+            latency_ms: result.latency_ms,
+            models: result.models,
+            credit,
+            validated_at: Utc::now(),
+        })
+    }
+}
+
+/// A success response that is not a models list means the base URL is wrong
+fn validation_status(status_code: Option<u16>, has_error: bool) -> KeyValidationStatus {
+    match status_code {
+        Some(code) if (200..300).contains(&code) && has_error => KeyValidationStatus::Error,
+        Some(code) => KeyValidationStatus::from_status_code(code),
+        None => KeyValidationStatus::Error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation_status() {
+        assert_eq!(
+            validation_status(Some(200), false),
+            KeyValidationStatus::Valid
+        );
+        assert_eq!(
+            validation_status(Some(200), true),
+            KeyValidationStatus::Error
+        );
+        assert_eq!(
+            validation_status(Some(401), true),
+            KeyValidationStatus::Invalid
+        );
+        assert_eq!(
+            validation_status(Some(429), true),
+            KeyValidationStatus::Error
+        );
+        assert_eq!(validation_status(None, true), KeyValidationStatus::Error);
+    }
+}
@@ -0,0 +1,277 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::application::dtos::{MetricsExporterSettingsDto, MetricsExporterStatusDto};
+use crate::application::services::{AutoCheckInScheduler, ConfigService, MetricsExporterSettings};
+use neuradock_domain::account::AccountRepository;
+use neuradock_domain::codex::CodexAccountRepository;
+use neuradock_domain::shared::DomainError;
+use neuradock_infrastructure::monitoring::{metrics, Labels, MetricsExporter, MetricsSource};
+
+fn labels(pairs: &[(&str, &str)]) -> Labels {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Refreshes the gauges that are read from storage on every scrape
+///
+/// Counters and histograms are recorded where things happen (check-ins, WAF refreshes),
+/// balances, Codex windows and scheduler liveness are snapshots taken here.
+struct ScrapeCollector {
+    account_repo: Arc<dyn AccountRepository>,
+    codex_account_repo: Arc<dyn CodexAccountRepository>,
+    scheduler: Arc<AutoCheckInScheduler>,
+}
+
+impl ScrapeCollector {
+    async fn collect_accounts(&self) {
+        let accounts = match self.account_repo.find_all().await {
+            Ok(accounts) => accounts,
+            Err(e) => {
+                warn!("Metrics: failed to load accounts: {}", e);
+                return;
+            }
+        };
+
+        let mut balance = Vec::new();
+        let mut consumed = Vec::new();
+        let mut quota = Vec::new();
+        for account in &accounts {
+            let account_labels = labels(&[
+                ("account_id", account.id().as_str()),
+                ("account", account.name()),
+                ("provider", account.provider_id().as_str()),
+            ]);
+            if let Some(value) = account.current_balance() {
+                balance.push((account_labels.clone(), value));
+            }
+            if let Some(value) = account.total_consumed() {
+                consumed.push((account_labels.clone(), value));
+            }
+            if let Some(value) = account.total_quota() {
+                quota.push((account_labels, value));
+            }
+        }
+
+        let registry = metrics();
+        registry.replace_gauges(
+            "neuradock_account_balance",
+            "Current balance per account as of the last check-in or balance refresh",
+            balance,
+        );
+        registry.replace_gauges(
+            "neuradock_account_consumed",
+            "Total consumption per account as reported by the provider",
+            consumed,
+        );
+        registry.replace_gauges(
+            "neuradock_account_quota",
+            "Total quota per account as reported by the provider",
+            quota,
+        );
+    }
+
+    async fn collect_codex_windows(&self) {
+        let accounts = match self.codex_account_repo.find_all().await {
+            Ok(accounts) => accounts,
+            Err(e) => {
+                warn!("Metrics: failed to load Codex accounts: {}", e);
+                return;
+            }
+        };
+
+        let mut used = Vec::new();
+        for account in &accounts {
+            let windows = [
+                ("primary", account.primary_window()),
+                ("secondary", account.secondary_window()),
+            ];
+            for (window, usage) in windows {
+                if let Some(usage) = usage {
+                    used.push((
+                        labels(&[("email", account.email()), ("window", window)]),
+                        usage.used_percent(),
+                    ));
+                }
+            }
+        }
+
+        metrics().replace_gauges(
+            "neuradock_codex_window_used_percent",
+            "Codex rate limit window usage in percent per account",
+            used,
+        );
+    }
+
+    async fn collect_scheduler(&self) {
+        let liveness = self.scheduler.liveness().await;
+
+        let mut alive = Vec::new();
+        let mut last_execution = Vec::new();
+        for task in &liveness.tasks {
+            let task_labels = labels(&[
+                ("account_id", &task.account_id),
+                ("account", &task.account_name),
+            ]);
+            alive.push((task_labels.clone(), if task.alive { 1.0 } else { 0.0 }));
+            if let Some(at) = task.last_execution {
+                last_execution.push((task_labels, at.timestamp() as f64));
+            }
+        }
+
+        let registry = metrics();
+        registry.replace_gauges(
+            "neuradock_scheduler_task_alive",
+            "Whether the scheduled check-in task of an account is running",
+            alive,
+        );
+        registry.replace_gauges(
+            "neuradock_scheduler_task_last_execution_timestamp_seconds",
+            "Unix time of the last scheduled check-in per account",
+            last_execution,
+        );
+        registry.set_gauge(
+            "neuradock_scheduler_health_check_running",
+            "Whether the scheduler health check loop is running",
+            &[],
+            if liveness.health_check_running {
+                1.0
+            } else {
+                0.0
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl MetricsSource for ScrapeCollector {
+    async fn render(&self) -> String {
+        self.collect_accounts().await;
+        self.collect_codex_windows().await;
+        self.collect_scheduler().await;
+        metrics().render()
+    }
+}
+
+#[derive(Default)]
+struct ExporterState {
+    exporter: Option<MetricsExporter>,
+    last_error: Option<String>,
+}
+
+/// Runs the opt-in Prometheus exporter according to the persisted settings
+pub struct MetricsExporterService {
+    config: Arc<ConfigService>,
+    source: Arc<dyn MetricsSource>,
+    state: Mutex<ExporterState>,
+}
+
+impl MetricsExporterService {
+    pub fn new(
+        config: Arc<ConfigService>,
+        account_repo: Arc<dyn AccountRepository>,
+        codex_account_repo: Arc<dyn CodexAccountRepository>,
+        scheduler: Arc<AutoCheckInScheduler>,
+    ) -> Self {
+        Self {
+            config,
+            source: Arc::new(ScrapeCollector {
+                account_repo,
+                codex_account_repo,
+                scheduler,
+            }),
+            state: Mutex::new(ExporterState::default()),
+        }
+    }
+
+    /// Start the exporter if it was enabled in a previous session
+    pub async fn start(&self) {
+        let settings = self.config.get_metrics_exporter_settings();
+        self.apply(settings).await;
+    }
+
+    pub async fn status(&self) -> MetricsExporterStatusDto {
+        let settings = self.config.get_metrics_exporter_settings();
+        let state = self.state.lock().await;
+        let address = state.exporter.as_ref().map(MetricsExporter::local_addr);
+
+        MetricsExporterStatusDto {
+            enabled: settings.enabled,
+            port: settings.port,
+            running: address.is_some(),
+            endpoint: address.map(|address| format!("http://{}/metrics", address)),
+            last_error: state.last_error.clone(),
+        }
+    }
+
+    /// Persist new settings and start, restart or stop the exporter right away
+    pub async fn update_settings(
+        &self,
+        input: MetricsExporterSettingsDto,
+    ) -> Result<MetricsExporterStatusDto, DomainError> {
+        if input.port == 0 {
+            return Err(DomainError::Validation(
+                "Metrics exporter port must be between 1 and 65535".to_string(),
+            ));
+        }
+
+        let settings = MetricsExporterSettings {
+            enabled: input.enabled,
+            port: input.port,
+        };
+        self.config
+            .set_metrics_exporter_settings(settings)
+            .map_err(|e| {
+                DomainError::Infrastructure(format!(
+                    "Failed to save metrics exporter settings: {}",
+                    e
+                ))
+            })?;
+
+        self.apply(settings).await;
+        Ok(self.status().await)
+    }
+
+    async fn apply(&self, settings: MetricsExporterSettings) {
+        let mut state = self.state.lock().await;
+
+        let unchanged = state
+            .exporter
+            .as_ref()
+            .is_some_and(|exporter| exporter.local_addr().port() == settings.port);
+        if settings.enabled && unchanged {
+            return;
+        }
+
+        // Stop the running exporter before binding the new port
+        if let Some(exporter) = state.exporter.take() {
+            exporter.stop();
+        }
+        state.last_error = None;
+
+        if !settings.enabled {
+            return;
+        }
+
+        match MetricsExporter::start(settings.port, self.source.clone()).await {
+            Ok(exporter) => {
+                info!(
+                    "✅ Metrics exporter started on http://{}/metrics",
+                    exporter.local_addr()
+                );
+                state.exporter = Some(exporter);
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to start metrics exporter on port {}: {}",
+                    settings.port, e
+                );
+                state.last_error = Some(e.to_string());
+            }
+        }
+    }
+}
@@ -1,26 +1,57 @@
+mod account_cookie_import_service;
+mod audit_log_service;
+mod balance_history_maintenance_service;
 mod balance_history_service;
 mod balance_service;
 mod check_in_executor;
+mod claude_profile_service;
+mod codex_auto_switch_service;
+mod codex_token_refresh_service;
+mod codex_usage_history_service;
 mod config_service;
+mod currency_settings_service;
+mod diagnostics_service;
 mod i18n;
+mod independent_key_validation_service;
+mod metrics_exporter_service;
+mod model_catalog_service;
+mod network_settings_service;
 mod notification_service;
 mod orphan_account_repair_service;
 mod provider_models_query_service;
 mod provider_models_service;
 mod proxy_config_service;
+mod proxy_routing_service;
 mod scheduler;
 pub mod token;
+mod token_watch_service;
 mod user_info_service;
 mod waf_cookie_manager;
 
+pub use account_cookie_import_service::AccountCookieImportService;
+pub use audit_log_service::{AuditLogService, AuditSummary};
+pub use balance_history_maintenance_service::BalanceHistoryMaintenanceService;
 pub use balance_history_service::BalanceHistoryService;
 pub use balance_service::BalanceService;
 pub use check_in_executor::CheckInExecutor;
-pub use config_service::{ConfigService, LogLevel};
+pub use claude_profile_service::ClaudeProfileService;
+pub use codex_auto_switch_service::CodexAutoSwitchService;
+pub use codex_token_refresh_service::CodexTokenRefreshService;
+pub use codex_usage_history_service::CodexUsageHistoryService;
+pub use config_service::{ConfigService, LogLevel, MetricsExporterSettings};
+pub use currency_settings_service::CurrencySettingsService;
+pub use diagnostics_service::DiagnosticsService;
+pub use i18n::{current_locale, normalize_locale, SUPPORTED_LOCALES};
+pub use independent_key_validation_service::IndependentKeyValidationService;
+pub use metrics_exporter_service::MetricsExporterService;
+pub use model_catalog_service::ModelCatalogService;
+pub use network_settings_service::NetworkSettingsService;
 pub use notification_service::NotificationService;
 pub use orphan_account_repair_service::OrphanAccountRepairService;
 pub use provider_models_query_service::ProviderModelsQueryService;
 pub use provider_models_service::ProviderModelsService;
 pub use proxy_config_service::ProxyConfigService;
+pub use proxy_routing_service::ProxyRoutingService;
 pub use scheduler::AutoCheckInScheduler;
-pub use token::{ClaudeConfigService, CodexConfigService, TokenService};
+pub use token::{ClaudeConfigService, CliToolConfigService, CodexConfigService, TokenService};
+pub use token_watch_service::TokenWatchService;
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use neuradock_domain::check_in::{Provider, ProviderRepository};
+use neuradock_domain::provider_models::{ModelCatalogDiff, ProviderModelsRepository};
+use neuradock_domain::shared::{DomainError, ProviderId};
+
+use crate::application::dtos::ModelCatalogChangeDto;
+use crate::application::services::token::ConfiguredModels;
+use crate::application::services::{
+    ClaudeConfigService, CodexConfigService, ConfigService, NotificationService,
+};
+
+/// Tracks how provider model lists change between fetches
+///
+/// Every fetched list goes through [`ModelCatalogService::record`]; the repository keeps
+/// a snapshot whenever the list differs from the previous one.
+pub struct ModelCatalogService {
+    provider_models_repo: Arc<dyn ProviderModelsRepository>,
+    provider_repo: Arc<dyn ProviderRepository>,
+    claude_config: Arc<ClaudeConfigService>,
+    codex_config: Arc<CodexConfigService>,
+    config: Arc<ConfigService>,
+    notification_service: Arc<NotificationService>,
+}
+
+impl ModelCatalogService {
+    pub fn new(
+        provider_models_repo: Arc<dyn ProviderModelsRepository>,
+        provider_repo: Arc<dyn ProviderRepository>,
+        claude_config: Arc<ClaudeConfigService>,
+        codex_config: Arc<CodexConfigService>,
+        config: Arc<ConfigService>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            provider_models_repo,
+            provider_repo,
+            claude_config,
+            codex_config,
+            config,
+            notification_service,
+        }
+    }
+
+    /// Save a fetched model list and report changes to configured models
+    pub async fn record(&self, provider: &Provider, models: &[String]) -> Result<(), DomainError> {
+        let diff = self
+            .provider_models_repo
+            .save(provider.id().as_str(), models)
+            .await?;
+        let Some(diff) = diff.filter(|diff| !diff.is_empty()) else {
+            return Ok(());
+        };
+
+        info!(
+            "Model list of {} changed: {} added, {} removed",
+            provider.name(),
+            diff.added.len(),
+            diff.removed.len()
+        );
+        if self.config.notify_model_catalog_changes() {
+            self.notify_configured_changes(provider, &diff).await;
+        }
+        Ok(())
+    }
+
+    /// Model list changes, newest first
+    ///
+    /// Limited to one provider when `provider_id` is given.
+    pub async fn changes(
+        &self,
+        provider_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<ModelCatalogChangeDto>, DomainError> {
+        let providers = match provider_id {
+            Some(id) => vec![self
+                .provider_repo
+                .find_by_id(&ProviderId::from_string(id))
+                .await?
+                .ok_or_else(|| DomainError::ProviderNotFound(id.to_string()))?],
+            None => self.provider_repo.find_all().await?,
+        };
+
+        let mut changes = Vec::new();
+        for provider in &providers {
+            // One extra snapshot so the oldest change in range has a predecessor
+            let snapshots = self
+                .provider_models_repo
+                .find_snapshots(provider.id().as_str(), limit as i64 + 1)
+                .await?;
+            for (index, snapshot) in snapshots.iter().enumerate() {
+                let previous = snapshots.get(index + 1);
+                if previous.is_none() && snapshots.len() > limit {
+                    break;
+                }
+                let diff = ModelCatalogDiff::between(
+                    previous.map(|p| p.models.as_slice()).unwrap_or_default(),
+                    &snapshot.models,
+                );
+                changes.push(ModelCatalogChangeDto {
+                    provider_id: provider.id().as_str().to_string(),
+                    provider_name: provider.name().to_string(),
+                    fetched_at: snapshot.fetched_at.to_rfc3339(),
+                    previous_fetched_at: previous.map(|p| p.fetched_at.to_rfc3339()),
+                    added: diff.added,
+                    removed: diff.removed,
+                    model_count: snapshot.models.len(),
+                });
+            }
+        }
+
+        // RFC 3339 timestamps in UTC sort chronologically as strings
+        changes.sort_by(|a, b| b.fetched_at.cmp(&a.fetched_at));
+        changes.truncate(limit);
+        Ok(changes)
+    }
+
+    async fn notify_configured_changes(&self, provider: &Provider, diff: &ModelCatalogDiff) {
+        let tools = [
+            ("Claude Code", self.claude_config.configured_models()),
+            ("Codex", self.codex_config.configured_models()),
+        ];
+        for (tool, configured) in tools {
+            let configured = match configured {
+                Ok(Some(configured)) => configured,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("Failed to read {} config for model changes: {}", tool, e);
+                    continue;
+                }
+            };
+            let Some(changes) = configured_changes(&configured, provider, diff) else {
+                continue;
+            };
+
+            if let Err(e) = self
+                .notification_service
+                .send_model_catalog_change(provider.name(), tool, &changes.added, &changes.removed)
+                .await
+            {
+                warn!("Failed to send model catalog notification: {}", e);
+            }
+        }
+    }
+}
+
+/// Changes to the models a tool uses, when the tool is configured with this provider
+fn configured_changes(
+    configured: &ConfiguredModels,
+    provider: &Provider,
+    diff: &ModelCatalogDiff,
+) -> Option<ModelCatalogDiff> {
+    if !configured.uses_host_of(provider.domain()) {
+        return None;
+    }
+    Some(diff.restricted_to(&configured.models)).filter(|changes| !changes.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use neuradock_domain::check_in::ProviderConfig;
+
+    fn provider(domain: &str) -> Provider {
+        Provider::new(ProviderConfig {
+            name: "Example".to_string(),
+            domain: domain.to_string(),
+            login_path: "/login".to_string(),
+            sign_in_path: None,
+            user_info_path: "/api/user/self".to_string(),
+            token_api_path: None,
+            models_path: Some("/api/user/models".to_string()),
+            api_user_key: "new-api-user".to_string(),
+            bypass_method: None,
+            supports_check_in: true,
+            check_in_bugged: false,
+        })
+    }
+
+    fn models(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_configured_changes_only_for_the_configured_provider() {
+        let diff = ModelCatalogDiff::between(
+            &models(&["claude-sonnet-4", "gpt-4o"]),
+            &models(&["gpt-4o", "o3"]),
+        );
+        let configured = ConfiguredModels {
+            base_url: Some("https://api.example.com".to_string()),
+            models: models(&["claude-sonnet-4", "claude-haiku-4"]),
+        };
+
+        let changes = configured_changes(&configured, &provider("https://example.com"), &diff)
+            .expect("configured model was removed");
+        assert_eq!(changes.removed, models(&["claude-sonnet-4"]));
+        assert!(changes.added.is_empty());
+
+        assert!(configured_changes(&configured, &provider("https://other.com"), &diff).is_none());
+
+        let unaffected = ConfiguredModels {
+            models: models(&["claude-haiku-4"]),
+            ..configured
+        };
+        assert!(configured_changes(&unaffected, &provider("https://example.com"), &diff).is_none());
+    }
+}
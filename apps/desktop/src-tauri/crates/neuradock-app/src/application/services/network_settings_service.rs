@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use neuradock_domain::check_in::ProviderRepository;
+use neuradock_domain::network_settings::{
+    NetworkPolicySettings, NetworkSettings, NetworkSettingsRepository, ProviderNetworkOverride,
+};
+use neuradock_domain::shared::DomainError;
+use neuradock_infrastructure::config::network_policy::{self, NetworkPolicies};
+use neuradock_infrastructure::config::NetworkPolicy;
+use neuradock_infrastructure::http::RetryConfig;
+
+use crate::application::dtos::{
+    NetworkPolicyDto, NetworkSettingRangeDto, NetworkSettingsDto, ProviderNetworkOverrideDto,
+    ProviderNetworkPolicyDto, UpdateNetworkSettingsInput,
+};
+
+/// How often the persisted settings are re-installed, picks up edits made with the CLI
+const RELOAD_INTERVAL_SECS: u64 = 60;
+
+/// Persisted timeouts and retry policy, installed process-wide so changes apply live
+///
+/// HTTP and token clients, WAF cookie fetches, browser launches and the scheduler's
+/// check-ins read the installed policy per request, nothing needs to be rebuilt.
+pub struct NetworkSettingsService {
+    repo: Arc<dyn NetworkSettingsRepository>,
+    provider_repo: Arc<dyn ProviderRepository>,
+    background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl NetworkSettingsService {
+    pub fn new(
+        repo: Arc<dyn NetworkSettingsRepository>,
+        provider_repo: Arc<dyn ProviderRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            provider_repo,
+            background_handle: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub async fn get(&self) -> Result<NetworkSettingsDto, DomainError> {
+        let settings = self.repo.get().await?;
+        self.to_dto(&settings).await
+    }
+
+    /// Validate, persist and install new settings
+    pub async fn update(
+        &self,
+        input: UpdateNetworkSettingsInput,
+    ) -> Result<NetworkSettingsDto, DomainError> {
+        let provider_ids = self
+            .provider_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|p| p.id().as_str().to_string())
+            .collect::<Vec<_>>();
+
+        let mut provider_overrides = HashMap::new();
+        for value in input.provider_overrides {
+            if !provider_ids.contains(&value.provider_id) {
+                return Err(DomainError::Validation(format!(
+                    "Provider not found: {}",
+                    value.provider_id
+                )));
+            }
+            provider_overrides.insert(
+                value.provider_id,
+                ProviderNetworkOverride {
+                    waf_wait_ms: value.waf_wait_ms,
+                    http_request_ms: value.http_request_ms,
+                    max_retries: value.max_retries,
+                    waf_max_attempts: value.waf_max_attempts,
+                },
+            );
+        }
+
+        let mut settings = self.repo.get().await?;
+        settings.update(policy_from_dto(&input.defaults), provider_overrides)?;
+        self.save_and_install(settings).await
+    }
+
+    /// Restore the built-in defaults and drop every provider override
+    pub async fn reset(&self) -> Result<NetworkSettingsDto, DomainError> {
+        self.save_and_install(NetworkSettings::default()).await
+    }
+
+    /// Install the persisted settings, called at startup and by the background task
+    pub async fn apply(&self) -> Result<(), DomainError> {
+        let settings = self.repo.get().await?;
+        self.install(&settings).await
+    }
+
+    /// Start re-installing the persisted settings periodically
+    pub async fn start_background_task(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+
+        let handle = tokio::spawn(async move {
+            let mut reload_interval =
+                tokio::time::interval(Duration::from_secs(RELOAD_INTERVAL_SECS));
+            // The first tick completes immediately, settings were just applied
+            reload_interval.tick().await;
+
+            loop {
+                reload_interval.tick().await;
+
+                if let Err(e) = service.apply().await {
+                    warn!("Failed to reload network settings: {}", e);
+                }
+            }
+        });
+
+        let mut background = self.background_handle.lock().await;
+        if let Some(previous) = background.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    async fn save_and_install(
+        &self,
+        settings: NetworkSettings,
+    ) -> Result<NetworkSettingsDto, DomainError> {
+        self.repo.save(&settings).await?;
+        self.install(&settings).await?;
+        info!("✅ Network settings updated and applied");
+        self.to_dto(&settings).await
+    }
+
+    /// Map provider overrides to their hosts and swap the runtime policies
+    async fn install(&self, settings: &NetworkSettings) -> Result<(), DomainError> {
+        let providers = self.provider_repo.find_all().await?;
+
+        let mut hosts = HashMap::new();
+        for provider in &providers {
+            let provider_id = provider.id().as_str();
+            if !settings.provider_overrides().contains_key(provider_id) {
+                continue;
+            }
+            match url::Url::parse(provider.domain())
+                .ok()
+                .and_then(|url| url.host_str().map(str::to_string))
+            {
+                Some(host) => {
+                    hosts.insert(host, to_runtime(&settings.for_provider(provider_id)));
+                }
+                None => warn!(
+                    "Network override for provider {} ignored, invalid domain: {}",
+                    provider.name(),
+                    provider.domain()
+                ),
+            }
+        }
+
+        network_policy::install(NetworkPolicies {
+            default: to_runtime(settings.defaults()),
+            hosts,
+        });
+        Ok(())
+    }
+
+    async fn to_dto(&self, settings: &NetworkSettings) -> Result<NetworkSettingsDto, DomainError> {
+        let mut provider_overrides = self
+            .provider_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .filter_map(|provider| {
+                let provider_id = provider.id().as_str();
+                let value = settings.provider_overrides().get(provider_id)?;
+                Some(ProviderNetworkPolicyDto {
+                    provider_id: provider_id.to_string(),
+                    provider_name: provider.name().to_string(),
+                    override_values: ProviderNetworkOverrideDto {
+                        provider_id: provider_id.to_string(),
+                        waf_wait_ms: value.waf_wait_ms,
+                        http_request_ms: value.http_request_ms,
+                        max_retries: value.max_retries,
+                        waf_max_attempts: value.waf_max_attempts,
+                    },
+                    effective: policy_to_dto(&settings.for_provider(provider_id)),
+                })
+            })
+            .collect::<Vec<_>>();
+        provider_overrides.sort_by(|a, b| a.provider_name.cmp(&b.provider_name));
+
+        Ok(NetworkSettingsDto {
+            defaults: policy_to_dto(settings.defaults()),
+            provider_overrides,
+            ranges: NetworkPolicySettings::ranges()
+                .into_iter()
+                .map(|(name, min, max)| NetworkSettingRangeDto {
+                    name: name.to_string(),
+                    min,
+                    max,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Runtime policy for validated settings, timeouts without a setting keep their defaults
+fn to_runtime(settings: &NetworkPolicySettings) -> NetworkPolicy {
+    let mut policy = NetworkPolicy::default();
+    policy.timeouts.waf_wait = Duration::from_millis(settings.waf_wait_ms);
+    policy.timeouts.browser_launch = Duration::from_millis(settings.browser_launch_ms);
+    policy.timeouts.http_request = Duration::from_millis(settings.http_request_ms);
+    policy.timeouts.db_query = Duration::from_millis(settings.db_query_ms);
+    policy.retry = RetryConfig {
+        max_retries: settings.max_retries,
+        initial_backoff_ms: settings.initial_backoff_ms,
+        max_backoff_ms: settings.max_backoff_ms,
+        backoff_multiplier: settings.backoff_multiplier,
+    };
+    policy.waf_max_attempts = settings.waf_max_attempts;
+    policy
+}
+
+fn policy_from_dto(dto: &NetworkPolicyDto) -> NetworkPolicySettings {
+    NetworkPolicySettings {
+        waf_wait_ms: dto.waf_wait_ms,
+        browser_launch_ms: dto.browser_launch_ms,
+        http_request_ms: dto.http_request_ms,
+        db_query_ms: dto.db_query_ms,
+        max_retries: dto.max_retries,
+        initial_backoff_ms: dto.initial_backoff_ms,
+        max_backoff_ms: dto.max_backoff_ms,
+        backoff_multiplier: dto.backoff_multiplier,
+        waf_max_attempts: dto.waf_max_attempts,
+    }
+}
+
+fn policy_to_dto(settings: &NetworkPolicySettings) -> NetworkPolicyDto {
+    NetworkPolicyDto {
+        waf_wait_ms: settings.waf_wait_ms,
+        browser_launch_ms: settings.browser_launch_ms,
+        http_request_ms: settings.http_request_ms,
+        db_query_ms: settings.db_query_ms,
+        max_retries: settings.max_retries,
+        initial_backoff_ms: settings.initial_backoff_ms,
+        max_backoff_ms: settings.max_backoff_ms,
+        backoff_multiplier: settings.backoff_multiplier,
+        waf_max_attempts: settings.waf_max_attempts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings_match_runtime_defaults() {
+        let runtime = to_runtime(&NetworkPolicySettings::default());
+        let defaults = NetworkPolicy::default();
+
+        assert_eq!(runtime.timeouts.waf_wait, defaults.timeouts.waf_wait);
+        assert_eq!(
+            runtime.timeouts.browser_launch,
+            defaults.timeouts.browser_launch
+        );
+        assert_eq!(
+            runtime.timeouts.http_request,
+            defaults.timeouts.http_request
+        );
+        assert_eq!(runtime.timeouts.db_query, defaults.timeouts.db_query);
+        assert_eq!(runtime.retry.max_retries, defaults.retry.max_retries);
+        assert_eq!(runtime.waf_max_attempts, defaults.waf_max_attempts);
+    }
+}
@@ -1,12 +1,17 @@
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use log::{error, info};
 use std::sync::Arc;
 
-use crate::application::services::i18n::t;
+use crate::application::services::i18n::{t, t_args};
 use neuradock_domain::balance_history::{BalanceHistoryRecord, BalanceHistoryRepository};
+use neuradock_domain::codex::{
+    CodexAutoSwitchReason, CodexUsageReminderKind, CodexUsageWindowKind,
+};
+use neuradock_domain::currency::{CurrencySettings, CurrencySettingsRepository};
 use neuradock_domain::notification::{NotificationChannelRepository, NotificationMessage};
 use neuradock_domain::shared::AccountId;
+use neuradock_domain::token::{ApiToken, TokenWatchAlert, TokenWatchTool};
 use neuradock_infrastructure::notification::create_sender;
 
 /// Notification application service
@@ -14,6 +19,7 @@ use neuradock_infrastructure::notification::create_sender;
 pub struct NotificationService {
     channel_repo: Arc<dyn NotificationChannelRepository>,
     balance_history_repo: Arc<dyn BalanceHistoryRepository>,
+    currency_repo: Option<Arc<dyn CurrencySettingsRepository>>,
 }
 
 impl NotificationService {
@@ -24,6 +30,26 @@ impl NotificationService {
         Self {
             channel_repo,
             balance_history_repo,
+            currency_repo: None,
+        }
+    }
+
+    /// Format balances in the configured display currency
+    pub fn with_currency_settings(mut self, repo: Arc<dyn CurrencySettingsRepository>) -> Self {
+        self.currency_repo = Some(repo);
+        self
+    }
+
+    async fn currency_settings(&self) -> CurrencySettings {
+        let Some(repo) = &self.currency_repo else {
+            return CurrencySettings::default();
+        };
+        match repo.get().await {
+            Ok(settings) => settings,
+            Err(e) => {
+                error!("Failed to load currency settings, using USD: {}", e);
+                CurrencySettings::default()
+            }
         }
     }
 
@@ -158,14 +184,24 @@ impl NotificationService {
     }
 
     /// Send check-in success notification with yesterday/today comparison
+    ///
+    /// Amounts are normalized to the display currency of the provider.
     pub async fn send_check_in_success(
         &self,
         account_id: &str,
         account_name: &str,
+        provider_id: &str,
         provider_name: &str,
         balance: Option<(f64, f64, f64)>, // (current_balance, total_consumed, total_quota)
     ) -> Result<()> {
-        let yesterday_balance = self.get_yesterday_balance(account_id).await;
+        let currency = self.currency_settings().await;
+        let factor = currency.normalization_factor(provider_id);
+        let symbol = currency.display_symbol();
+        let normalize = |(current, consumed, quota): (f64, f64, f64)| {
+            (current * factor, consumed * factor, quota * factor)
+        };
+        let balance = balance.map(normalize);
+        let yesterday_balance = self.get_yesterday_balance(account_id).await.map(normalize);
         let now = chrono::Local::now();
         let time_str = now.format("%Y-%m-%d %H:%M:%S").to_string();
 
@@ -201,7 +237,7 @@ impl NotificationService {
                 };
 
                 format!(
-                    "{}: {}\n{}: {}\n{}: {}\n\n{}:\n   {}: ${:.2}\n   {}: ${:.2}\n   {}: ${:.2}\n\n{}:\n   {}: ${:.2} {}\n   {}: ${:.2} {}\n   {}: ${:.2} {}\n\n{}:\n   {}: {:+.2} {}\n   {}: {:+.2} {}\n   {}: {:+.2} {}",
+                    "{}: {}\n{}: {}\n{}: {}\n\n{}:\n   {}: {}{:.2}\n   {}: {}{:.2}\n   {}: {}{:.2}\n\n{}:\n   {}: {}{:.2} {}\n   {}: {}{:.2} {}\n   {}: {}{:.2} {}\n\n{}:\n   {}: {:+.2} {}\n   {}: {:+.2} {}\n   {}: {:+.2} {}",
                     t("notification.label.account"),
                     account_name,
                     t("notification.label.provider"),
@@ -210,36 +246,42 @@ impl NotificationService {
                     time_str,
                     t("notification.label.yesterday"),
                     t("notification.label.currentBalance"),
+                    symbol,
                     yesterday_current,
                     t("notification.label.totalConsumed"),
+                    symbol,
                     yesterday_consumed,
                     t("notification.label.totalQuota"),
+                    symbol,
                     yesterday_income,
                     t("notification.label.today"),
                     t("notification.label.currentBalance"),
+                    symbol,
                     today_current,
                     current_emoji,
                     t("notification.label.totalConsumed"),
+                    symbol,
                     today_consumed,
                     consumed_emoji,
                     t("notification.label.totalQuota"),
+                    symbol,
                     today_income,
                     income_emoji,
                     t("notification.label.changes"),
                     t("notification.label.currentBalance"),
                     current_change,
-                    "$",
+                    symbol,
                     t("notification.label.totalConsumed"),
                     consumed_change,
-                    "$",
+                    symbol,
                     t("notification.label.totalQuota"),
                     income_change,
-                    "$"
+                    symbol
                 )
             } else {
                 // No yesterday data, just show today
                 format!(
-                    "{}: {}\n{}: {}\n{}: {}\n\n{}:\n   {}: ${:.2}\n   {}: ${:.2}\n   {}: ${:.2}",
+                    "{}: {}\n{}: {}\n{}: {}\n\n{}:\n   {}: {}{:.2}\n   {}: {}{:.2}\n   {}: {}{:.2}",
                     t("notification.label.account"),
                     account_name,
                     t("notification.label.provider"),
@@ -248,10 +290,13 @@ impl NotificationService {
                     time_str,
                     t("notification.label.today"),
                     t("notification.label.currentBalance"),
+                    symbol,
                     today_current,
                     t("notification.label.totalConsumed"),
+                    symbol,
                     today_consumed,
                     t("notification.label.totalQuota"),
+                    symbol,
                     today_income
                 )
             }
@@ -264,7 +309,10 @@ impl NotificationService {
                 provider_name,
                 t("notification.label.time"),
                 time_str,
-                t("notification.checkIn.success.simple")
+                t_args(
+                    "notification.checkIn.success.simple",
+                    &[("account", &account_name)]
+                )
             )
         };
 
@@ -299,4 +347,201 @@ impl NotificationService {
 
         self.send_to_all(&message).await
     }
+
+    /// Send a notification that ~/.codex/auth.json was switched to another account
+    pub async fn send_codex_account_switch(
+        &self,
+        from_email: &str,
+        to_email: &str,
+        reason: CodexAutoSwitchReason,
+        from_used_percent: Option<f64>,
+    ) -> Result<()> {
+        let time_str = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let reason = match reason {
+            CodexAutoSwitchReason::ThresholdReached => t("notification.codexSwitch.thresholdReached"),
+            CodexAutoSwitchReason::SwitchBack => t("notification.codexSwitch.switchBack"),
+        };
+
+        let mut content = format!(
+            "{}: {}\n{}: {}\n{}: {}",
+            t("notification.label.from"),
+            from_email,
+            t("notification.label.to"),
+            to_email,
+            t("notification.label.reason"),
+            reason
+        );
+        if let Some(used) = from_used_percent {
+            content.push_str(&format!("\n{}: {:.0}%", t("notification.label.usage"), used));
+        }
+        content.push_str(&format!("\n{}: {}", t("notification.label.time"), time_str));
+
+        let message = NotificationMessage::new(t("notification.codexSwitch.title"), content);
+
+        self.send_to_all(&message).await
+    }
+
+    /// Send a reminder that a Codex rate-limit window is running low or has reset
+    pub async fn send_codex_usage_reminder(
+        &self,
+        email: &str,
+        window: CodexUsageWindowKind,
+        kind: CodexUsageReminderKind,
+        used_percent: f64,
+        resets_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let time_str = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let window = match window {
+            CodexUsageWindowKind::Primary => t("notification.codexUsage.primary"),
+            CodexUsageWindowKind::Secondary => t("notification.codexUsage.secondary"),
+        };
+        let title = match kind {
+            CodexUsageReminderKind::Warning => t("notification.codexUsage.warningTitle"),
+            CodexUsageReminderKind::Exhausted => t("notification.codexUsage.exhaustedTitle"),
+            CodexUsageReminderKind::Reset => t("notification.codexUsage.resetTitle"),
+        };
+
+        let mut content = format!(
+            "{}: {}\n{}: {}\n{}: {:.0}%",
+            t("notification.label.account"),
+            email,
+            t("notification.label.window"),
+            window,
+            t("notification.label.usage"),
+            used_percent
+        );
+        if kind != CodexUsageReminderKind::Reset {
+            if let Some(resets_at) = resets_at {
+                content.push_str(&format!(
+                    "\n{}: {}",
+                    t("notification.label.resetsAt"),
+                    resets_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                ));
+            }
+        }
+        content.push_str(&format!("\n{}: {}", t("notification.label.time"), time_str));
+
+        let message = NotificationMessage::new(title, content);
+
+        self.send_to_all(&message).await
+    }
+
+    /// Alert about the provider token configured into a CLI tool
+    pub async fn send_token_watch_alert(
+        &self,
+        tool: TokenWatchTool,
+        account_name: &str,
+        alert: TokenWatchAlert,
+        token: Option<&ApiToken>,
+    ) -> Result<()> {
+        let time_str = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let tool = match tool {
+            TokenWatchTool::Claude => "Claude Code",
+            TokenWatchTool::Codex => "Codex",
+        };
+        let title = match alert {
+            TokenWatchAlert::ExpiringSoon => t("notification.tokenWatch.expiringSoonTitle"),
+            TokenWatchAlert::Expired => t("notification.tokenWatch.expiredTitle"),
+            TokenWatchAlert::LowQuota => t("notification.tokenWatch.lowQuotaTitle"),
+            TokenWatchAlert::KeyMismatch => t("notification.tokenWatch.keyMismatchTitle"),
+        };
+
+        let mut content = format!(
+            "{}: {}\n{}: {}",
+            t("notification.label.account"),
+            account_name,
+            t("notification.label.tool"),
+            tool
+        );
+        match token {
+            Some(token) => {
+                content.push_str(&format!(
+                    "\n{}: {} ({})",
+                    t("notification.label.token"),
+                    token.name(),
+                    token.masked_key()
+                ));
+                if let Some(expired_time) = token.expired_time() {
+                    content.push_str(&format!(
+                        "\n{}: {}",
+                        t("notification.label.expiresAt"),
+                        expired_time
+                            .with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M")
+                    ));
+                    if alert == TokenWatchAlert::ExpiringSoon {
+                        // Whole days, rounded up so a token expiring tonight reads "1 day"
+                        let days_left = ((expired_time - Utc::now()).num_hours().max(0) + 23) / 24;
+                        content.push_str(&format!(
+                            " ({})",
+                            t_args(
+                                "notification.tokenWatch.expiresIn",
+                                &[("count", &days_left)]
+                            )
+                        ));
+                    }
+                }
+                if !token.unlimited_quota() {
+                    // 500,000 quota units are $1 on new-api
+                    content.push_str(&format!(
+                        "\n{}: ${:.2}",
+                        t("notification.label.remainQuota"),
+                        token.remain_quota() as f64 / 500_000.0
+                    ));
+                }
+            }
+            None => {
+                content.push_str(&format!("\n{}", t("notification.tokenWatch.keyMismatch")));
+            }
+        }
+        content.push_str(&format!("\n{}: {}", t("notification.label.time"), time_str));
+
+        let message = NotificationMessage::new(title, content);
+
+        self.send_to_all(&message).await
+    }
+
+    /// Alert that a provider added or dropped models a CLI tool is configured with
+    pub async fn send_model_catalog_change(
+        &self,
+        provider_name: &str,
+        tool: &str,
+        added: &[String],
+        removed: &[String],
+    ) -> Result<()> {
+        let time_str = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let mut content = format!(
+            "{}: {}\n{}: {}",
+            t("notification.label.provider"),
+            provider_name,
+            t("notification.label.tool"),
+            tool
+        );
+        if !removed.is_empty() {
+            content.push_str(&format!(
+                "\n➖ {}",
+                t_args(
+                    "notification.modelCatalog.removed",
+                    &[("count", &removed.len()), ("models", &removed.join(", "))]
+                )
+            ));
+        }
+        if !added.is_empty() {
+            content.push_str(&format!(
+                "\n➕ {}",
+                t_args(
+                    "notification.modelCatalog.added",
+                    &[("count", &added.len()), ("models", &added.join(", "))]
+                )
+            ));
+        }
+        content.push_str(&format!("\n{}: {}", t("notification.label.time"), time_str));
+
+        let message = NotificationMessage::new(t("notification.modelCatalog.title"), content);
+
+        self.send_to_all(&message).await
+    }
 }
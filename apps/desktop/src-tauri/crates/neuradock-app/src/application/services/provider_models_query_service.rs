@@ -2,19 +2,23 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use neuradock_domain::account::AccountRepository;
-use neuradock_domain::check_in::ProviderRepository;
+use neuradock_domain::check_in::{Provider, ProviderRepository};
 use neuradock_domain::provider_models::ProviderModelsRepository;
-use neuradock_domain::proxy_config::ProxyConfigRepository;
 use neuradock_domain::shared::{AccountId, DomainError, ProviderId};
 use neuradock_domain::waf_cookies::WafCookiesRepository;
+use neuradock_infrastructure::browser::BrowserPool;
 use neuradock_infrastructure::http::{token::TokenClient, WafBypassService};
 
+use crate::application::services::{ModelCatalogService, ProxyRoutingService};
+
 pub struct ProviderModelsQueryService {
     account_repo: Arc<dyn AccountRepository>,
     provider_repo: Arc<dyn ProviderRepository>,
     provider_models_repo: Arc<dyn ProviderModelsRepository>,
     waf_cookies_repo: Arc<dyn WafCookiesRepository>,
-    proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
+    browser_pool: Option<Arc<BrowserPool>>,
+    model_catalog: Option<Arc<ModelCatalogService>>,
 }
 
 impl ProviderModelsQueryService {
@@ -23,14 +27,39 @@ impl ProviderModelsQueryService {
         provider_repo: Arc<dyn ProviderRepository>,
         provider_models_repo: Arc<dyn ProviderModelsRepository>,
         waf_cookies_repo: Arc<dyn WafCookiesRepository>,
-        proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
     ) -> Self {
         Self {
             account_repo,
             provider_repo,
             provider_models_repo,
             waf_cookies_repo,
-            proxy_config_repo,
+            proxy_routing,
+            browser_pool: None,
+            model_catalog: None,
+        }
+    }
+
+    /// Pool used by the WAF bypass of model list requests
+    pub fn with_browser_pool(mut self, pool: Arc<BrowserPool>) -> Self {
+        self.browser_pool = Some(pool);
+        self
+    }
+
+    /// Record model list changes and notify about configured models
+    pub fn with_model_catalog(mut self, model_catalog: Arc<ModelCatalogService>) -> Self {
+        self.model_catalog = Some(model_catalog);
+        self
+    }
+
+    async fn save_models(&self, provider: &Provider, models: &[String]) -> Result<(), DomainError> {
+        match &self.model_catalog {
+            Some(catalog) => catalog.record(provider, models).await,
+            None => self
+                .provider_models_repo
+                .save(provider.id().as_str(), models)
+                .await
+                .map(|_| ()),
         }
     }
 
@@ -102,11 +131,9 @@ impl ProviderModelsQueryService {
         let api_user = account.credentials().api_user();
 
         let proxy_url = self
-            .proxy_config_repo
-            .get()
-            .await
-            .ok()
-            .and_then(|c| c.proxy_url());
+            .proxy_routing
+            .resolve_for_account(&account_id, &provider_id, provider.domain())
+            .await?;
         let client = TokenClient::with_proxy(proxy_url)
             .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
 
@@ -135,9 +162,7 @@ impl ProviderModelsQueryService {
             }
         };
 
-        self.provider_models_repo
-            .save(&provider_id, &models)
-            .await?;
+        self.save_models(&provider, &models).await?;
         Ok(models)
     }
 
@@ -172,12 +197,13 @@ impl ProviderModelsQueryService {
         };
 
         let proxy_url = self
-            .proxy_config_repo
-            .get()
-            .await
-            .ok()
-            .and_then(|c| c.proxy_url());
-        let waf_service = WafBypassService::with_proxy(true, proxy_url.clone());
+            .proxy_routing
+            .resolve_for_account(&account_id, &provider_id, provider.domain())
+            .await?;
+        let mut waf_service = WafBypassService::with_proxy(true, proxy_url.clone());
+        if let Some(pool) = self.browser_pool.clone() {
+            waf_service = waf_service.with_browser_pool(pool);
+        }
         let client = TokenClient::with_proxy(proxy_url)
             .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
 
@@ -222,9 +248,7 @@ impl ProviderModelsQueryService {
         )
         .await?;
 
-        self.provider_models_repo
-            .save(&provider_id, &models)
-            .await?;
+        self.save_models(&provider, &models).await?;
         Ok(models)
     }
 }
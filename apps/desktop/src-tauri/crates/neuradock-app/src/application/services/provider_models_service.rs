@@ -1,32 +1,41 @@
 use log::{error, info};
 use neuradock_domain::check_in::Provider;
 use neuradock_domain::provider_models::ProviderModelsRepository;
-use neuradock_domain::proxy_config::ProxyConfigRepository;
 use neuradock_domain::waf_cookies::WafCookiesRepository;
 use neuradock_infrastructure::http::token::TokenClient;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::application::services::{ModelCatalogService, ProxyRoutingService};
+
 /// Service for fetching and saving provider models
 pub struct ProviderModelsService {
     provider_models_repo: Arc<dyn ProviderModelsRepository>,
     waf_cookies_repo: Arc<dyn WafCookiesRepository>,
-    proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
+    model_catalog: Option<Arc<ModelCatalogService>>,
 }
 
 impl ProviderModelsService {
     pub fn new(
         provider_models_repo: Arc<dyn ProviderModelsRepository>,
         waf_cookies_repo: Arc<dyn WafCookiesRepository>,
-        proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
     ) -> Self {
         Self {
             provider_models_repo,
             waf_cookies_repo,
-            proxy_config_repo,
+            proxy_routing,
+            model_catalog: None,
         }
     }
 
+    /// Record model list changes and notify about configured models
+    pub fn with_model_catalog(mut self, model_catalog: Arc<ModelCatalogService>) -> Self {
+        self.model_catalog = Some(model_catalog);
+        self
+    }
+
     /// Fetch and save provider models after successful check-in
     pub async fn fetch_and_save_provider_models(
         &self,
@@ -80,11 +89,11 @@ impl ProviderModelsService {
             .join("; ");
 
         let proxy_url = self
-            .proxy_config_repo
-            .get()
+            .proxy_routing
+            .resolve_for_provider(provider_id, provider.domain())
             .await
             .ok()
-            .and_then(|cfg| cfg.proxy_url());
+            .flatten();
 
         // Create token client and fetch models
         let client = match TokenClient::with_proxy(proxy_url) {
@@ -120,7 +129,15 @@ impl ProviderModelsService {
                 );
 
                 // Save to database
-                if let Err(e) = self.provider_models_repo.save(provider_id, &models).await {
+                let saved = match &self.model_catalog {
+                    Some(catalog) => catalog.record(provider, &models).await,
+                    None => self
+                        .provider_models_repo
+                        .save(provider_id, &models)
+                        .await
+                        .map(|_| ()),
+                };
+                if let Err(e) = saved {
                     error!("Failed to save provider models: {}", e);
                 } else {
                     info!("Provider models saved to database");
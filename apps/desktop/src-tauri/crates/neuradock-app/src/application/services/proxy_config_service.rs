@@ -28,6 +28,18 @@ impl ProxyConfigService {
         let proxy_type = input.to_proxy_type().map_err(DomainError::Validation)?;
 
         config.update(input.enabled, proxy_type, input.host, input.port)?;
+        if let Some(username) = input.username {
+            let password = match input.password {
+                Some(password) => Some(password),
+                None => config.password().map(str::to_string),
+            };
+            config.set_auth(Some(username), password);
+        } else if input.password.is_some_and(|p| p.is_empty()) {
+            config.set_auth(None, None);
+        }
+        if let Some(bypass_domains) = input.bypass_domains {
+            config.set_bypass_domains(bypass_domains)?;
+        }
         self.repo.save(&config).await?;
 
         Ok(ProxyConfigDto::from(&config))
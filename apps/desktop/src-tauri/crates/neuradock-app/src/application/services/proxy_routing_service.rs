@@ -0,0 +1,369 @@
+use log::{debug, info};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use neuradock_domain::proxy_config::{
+    ProxyConfigRepository, ProxyEndpoint, ProxyOverride, ProxyPool, ProxyRoute,
+    ProxyRoutingRepository, ProxyScope, ProxyTarget,
+};
+use neuradock_domain::shared::DomainError;
+use neuradock_infrastructure::http::probe_proxy;
+use neuradock_infrastructure::logging::log_utils::mask_url_credentials;
+
+use crate::application::dtos::{
+    ProxyConnectivityResultDto, ProxyOverrideDto, ProxyPoolDto, SaveProxyPoolInput,
+    SetProxyOverrideInput, TestProxyConnectivityInput,
+};
+
+/// Provider scope id used for Codex (OpenAI) traffic
+pub const CODEX_PROXY_SCOPE: &str = "codex";
+
+const DEFAULT_PROBE_URL: &str = "https://www.gstatic.com/generate_204";
+const PROBE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Extract the host from a URL, accepting bare domains as well
+fn host_of(url: &str) -> Option<String> {
+    let with_scheme = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{}", url)
+    };
+    url::Url::parse(&with_scheme)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+}
+
+/// Resolves which proxy each outgoing request uses
+///
+/// Combines the global proxy settings with proxy pools and per-account /
+/// per-provider overrides, and keeps the round-robin position of each pool.
+pub struct ProxyRoutingService {
+    proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+    routing_repo: Arc<dyn ProxyRoutingRepository>,
+    round_robin: Mutex<HashMap<String, usize>>,
+}
+
+impl ProxyRoutingService {
+    pub fn new(
+        proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+        routing_repo: Arc<dyn ProxyRoutingRepository>,
+    ) -> Self {
+        Self {
+            proxy_config_repo,
+            routing_repo,
+            round_robin: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn next_counter(&self, pool_id: &str) -> usize {
+        let mut counters = self
+            .round_robin
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let counter = counters.entry(pool_id.to_string()).or_insert(0);
+        let current = *counter;
+        *counter = counter.wrapping_add(1);
+        current
+    }
+
+    /// Resolve the proxy URL for a request, `None` meaning a direct connection
+    pub async fn resolve(&self, target: ProxyTarget<'_>) -> Result<Option<String>, DomainError> {
+        let global = self.proxy_config_repo.get().await?;
+        let routing = self.routing_repo.load().await?;
+        let proxy_url = routing.resolve(&global, &target, |pool_id| self.next_counter(pool_id));
+
+        debug!(
+            "Proxy route for account={:?} provider={:?} host={:?}: {}",
+            target.account_id,
+            target.provider_id,
+            target.host,
+            proxy_url
+                .as_deref()
+                .map(mask_url_credentials)
+                .unwrap_or_else(|| "direct".to_string())
+        );
+
+        Ok(proxy_url)
+    }
+
+    /// Proxy for requests made on behalf of an account
+    pub async fn resolve_for_account(
+        &self,
+        account_id: &str,
+        provider_id: &str,
+        provider_domain: &str,
+    ) -> Result<Option<String>, DomainError> {
+        let host = host_of(provider_domain);
+        self.resolve(ProxyTarget {
+            account_id: Some(account_id),
+            provider_id: Some(provider_id),
+            host: host.as_deref(),
+        })
+        .await
+    }
+
+    /// Proxy for provider-level requests not tied to one account
+    pub async fn resolve_for_provider(
+        &self,
+        provider_id: &str,
+        provider_domain: &str,
+    ) -> Result<Option<String>, DomainError> {
+        let host = host_of(provider_domain);
+        self.resolve(ProxyTarget {
+            account_id: None,
+            provider_id: Some(provider_id),
+            host: host.as_deref(),
+        })
+        .await
+    }
+
+    /// Proxy for Codex (OpenAI) requests, optionally for one Codex account
+    pub async fn resolve_for_codex(
+        &self,
+        codex_account_id: Option<&str>,
+        url: &str,
+    ) -> Result<Option<String>, DomainError> {
+        let host = host_of(url);
+        self.resolve(ProxyTarget {
+            account_id: codex_account_id,
+            provider_id: Some(CODEX_PROXY_SCOPE),
+            host: host.as_deref(),
+        })
+        .await
+    }
+
+    pub async fn list_pools(&self) -> Result<Vec<ProxyPoolDto>, DomainError> {
+        let pools = self.routing_repo.list_pools().await?;
+        Ok(pools.iter().map(ProxyPoolDto::from).collect())
+    }
+
+    pub async fn save_pool(&self, input: SaveProxyPoolInput) -> Result<ProxyPoolDto, DomainError> {
+        let strategy = input.to_strategy()?;
+
+        let existing =
+            match input.id.as_deref() {
+                Some(id) => Some(self.routing_repo.find_pool(id).await?.ok_or_else(|| {
+                    DomainError::NotFound(format!("Proxy pool not found: {}", id))
+                })?),
+                None => None,
+            };
+        let existing_endpoints = existing
+            .as_ref()
+            .map(|pool| pool.endpoints().to_vec())
+            .unwrap_or_default();
+
+        let endpoints = input
+            .endpoints
+            .iter()
+            .map(|endpoint| endpoint.to_endpoint(&existing_endpoints))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let pool = match existing {
+            Some(mut pool) => {
+                pool.update(input.name, strategy, endpoints)?;
+                pool
+            }
+            None => ProxyPool::new(
+                uuid::Uuid::new_v4().to_string(),
+                input.name,
+                strategy,
+                endpoints,
+            )?,
+        };
+
+        self.routing_repo.save_pool(&pool).await?;
+        info!(
+            "Saved proxy pool {} ({} endpoints)",
+            pool.name(),
+            pool.endpoints().len()
+        );
+
+        Ok(ProxyPoolDto::from(&pool))
+    }
+
+    /// Delete a pool that no override references
+    pub async fn delete_pool(&self, id: &str) -> Result<(), DomainError> {
+        let in_use = self
+            .routing_repo
+            .list_overrides()
+            .await?
+            .into_iter()
+            .filter(|o| matches!(&o.route, ProxyRoute::Pool { pool_id } if pool_id == id))
+            .map(|o| format!("{} {}", o.scope.as_str(), o.scope_id))
+            .collect::<Vec<_>>();
+
+        if !in_use.is_empty() {
+            return Err(DomainError::Validation(format!(
+                "Proxy pool is still used by: {}",
+                in_use.join(", ")
+            )));
+        }
+
+        self.routing_repo.delete_pool(id).await
+    }
+
+    pub async fn list_overrides(&self) -> Result<Vec<ProxyOverrideDto>, DomainError> {
+        let overrides = self.routing_repo.list_overrides().await?;
+        Ok(overrides.iter().map(ProxyOverrideDto::from).collect())
+    }
+
+    pub async fn set_override(
+        &self,
+        input: SetProxyOverrideInput,
+    ) -> Result<ProxyOverrideDto, DomainError> {
+        let scope = input.to_scope()?;
+        let scope_id = input.scope_id.trim().to_string();
+        if scope_id.is_empty() {
+            return Err(DomainError::Validation(
+                "Proxy override scope id cannot be empty".to_string(),
+            ));
+        }
+
+        let route = match input.route_kind.as_str() {
+            "global" => ProxyRoute::Global,
+            "direct" => ProxyRoute::Direct,
+            "pool" => {
+                let pool_id = input.pool_id.ok_or_else(|| {
+                    DomainError::Validation("pool_id is required for a pool route".to_string())
+                })?;
+                if self.routing_repo.find_pool(&pool_id).await?.is_none() {
+                    return Err(DomainError::NotFound(format!(
+                        "Proxy pool not found: {}",
+                        pool_id
+                    )));
+                }
+                ProxyRoute::Pool { pool_id }
+            }
+            "endpoint" => {
+                let endpoint = input.endpoint.ok_or_else(|| {
+                    DomainError::Validation(
+                        "endpoint is required for an endpoint route".to_string(),
+                    )
+                })?;
+                let existing = self.existing_override_endpoint(scope, &scope_id).await?;
+                ProxyRoute::Endpoint {
+                    endpoint: endpoint.to_endpoint(&existing)?,
+                }
+            }
+            other => {
+                return Err(DomainError::Validation(format!(
+                    "Invalid proxy route kind: {}",
+                    other
+                )))
+            }
+        };
+
+        let proxy_override = ProxyOverride {
+            scope,
+            scope_id,
+            route,
+        };
+        self.routing_repo.save_override(&proxy_override).await?;
+
+        Ok(ProxyOverrideDto::from(&proxy_override))
+    }
+
+    async fn existing_override_endpoint(
+        &self,
+        scope: ProxyScope,
+        scope_id: &str,
+    ) -> Result<Vec<ProxyEndpoint>, DomainError> {
+        Ok(self
+            .routing_repo
+            .list_overrides()
+            .await?
+            .into_iter()
+            .filter(|o| o.scope == scope && o.scope_id == scope_id)
+            .filter_map(|o| match o.route {
+                ProxyRoute::Endpoint { endpoint } => Some(endpoint),
+                _ => None,
+            })
+            .collect())
+    }
+
+    pub async fn delete_override(&self, scope: &str, scope_id: &str) -> Result<(), DomainError> {
+        self.routing_repo
+            .delete_override(ProxyScope::from_str(scope)?, scope_id)
+            .await
+    }
+
+    /// Probe the resolved route, or every endpoint of a pool
+    pub async fn test_connectivity(
+        &self,
+        input: TestProxyConnectivityInput,
+    ) -> Result<Vec<ProxyConnectivityResultDto>, DomainError> {
+        let target_url = input
+            .target_url
+            .filter(|url| !url.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_PROBE_URL.to_string());
+
+        let proxy_urls = match input.pool_id.as_deref() {
+            Some(pool_id) => {
+                let pool = self.routing_repo.find_pool(pool_id).await?.ok_or_else(|| {
+                    DomainError::NotFound(format!("Proxy pool not found: {}", pool_id))
+                })?;
+                pool.endpoints()
+                    .iter()
+                    .map(|endpoint| Some(endpoint.to_url()))
+                    .collect()
+            }
+            None => {
+                let host = host_of(&target_url);
+                vec![
+                    self.resolve(ProxyTarget {
+                        account_id: input.account_id.as_deref(),
+                        provider_id: input.provider_id.as_deref(),
+                        host: host.as_deref(),
+                    })
+                    .await?,
+                ]
+            }
+        };
+
+        let mut results = Vec::with_capacity(proxy_urls.len());
+        for proxy_url in proxy_urls {
+            let probe = probe_proxy(proxy_url.as_deref(), &target_url, PROBE_TIMEOUT)
+                .await
+                .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+            let masked = proxy_url.as_deref().map(mask_url_credentials);
+
+            info!(
+                "Proxy connectivity test via {}: success={}, latency={}ms",
+                masked.as_deref().unwrap_or("direct"),
+                probe.success,
+                probe.latency_ms
+            );
+
+            results.push(ProxyConnectivityResultDto {
+                proxy_url: masked,
+                target_url: target_url.clone(),
+                success: probe.success,
+                status_code: probe.status_code,
+                latency_ms: probe.latency_ms,
+                error: probe.error,
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_of() {
+        assert_eq!(
+            host_of("https://anyrouter.top/"),
+            Some("anyrouter.top".to_string())
+        );
+        assert_eq!(
+            host_of("api.example.com:8443"),
+            Some("api.example.com".to_string())
+        );
+        assert_eq!(host_of(""), None);
+    }
+}
@@ -2,6 +2,8 @@ use std::sync::Arc;
 use tokio::time::Duration;
 use tracing::{error, info, warn};
 
+use super::types::{ScheduledTaskLiveness, SchedulerLiveness};
+
 impl super::AutoCheckInScheduler {
     /// Start health check background task to monitor scheduled tasks
     pub(super) async fn start_health_check_task(&self) {
@@ -60,4 +62,36 @@ impl super::AutoCheckInScheduler {
 
         info!("✅ Health check task started (checking every 5 minutes)");
     }
+
+    /// Report which scheduled tasks are still running, without waiting for the next health check
+    pub async fn liveness(&self) -> SchedulerLiveness {
+        let health_check_running = self
+            .health_check_handle
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished());
+
+        let tasks_lock = self.tasks.lock().await;
+        let metadata_lock = self.task_metadata.lock().await;
+        let tasks = tasks_lock
+            .iter()
+            .map(|(account_id, handle)| {
+                let meta = metadata_lock.get(account_id);
+                ScheduledTaskLiveness {
+                    account_id: account_id.as_str().to_string(),
+                    account_name: meta
+                        .map(|meta| meta.account_name.clone())
+                        .unwrap_or_default(),
+                    alive: !handle.is_finished(),
+                    last_execution: meta.and_then(|meta| meta.last_execution),
+                }
+            })
+            .collect();
+
+        SchedulerLiveness {
+            health_check_running,
+            tasks,
+        }
+    }
 }
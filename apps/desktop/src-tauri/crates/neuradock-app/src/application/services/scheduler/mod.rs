@@ -4,6 +4,7 @@ mod task_spawner;
 mod types;
 
 use neuradock_domain::shared::AccountId;
+use neuradock_infrastructure::browser::BrowserPool;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -20,6 +21,8 @@ pub struct AutoCheckInScheduler {
     task_metadata: Arc<Mutex<HashMap<AccountId, TaskMetadata>>>,
     /// Health check task handle
     health_check_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+    /// Shared browsers for WAF bypass during scheduled check-ins
+    browser_pool: Option<Arc<BrowserPool>>,
 }
 
 impl AutoCheckInScheduler {
@@ -28,9 +31,15 @@ impl AutoCheckInScheduler {
             tasks: Arc::new(Mutex::new(HashMap::new())),
             task_metadata: Arc::new(Mutex::new(HashMap::new())),
             health_check_handle: Arc::new(Mutex::new(None)),
+            browser_pool: None,
         })
     }
 
+    pub fn with_browser_pool(mut self, pool: Arc<BrowserPool>) -> Self {
+        self.browser_pool = Some(pool);
+        self
+    }
+
     pub async fn start(&self) -> Result<(), Box<dyn std::error::Error>> {
         info!("✅ Auto check-in scheduler started (using tokio timer)");
 
@@ -28,6 +28,7 @@ impl super::AutoCheckInScheduler {
 
         // Clone task metadata for updating within the task
         let task_metadata = Arc::clone(&self.task_metadata);
+        let browser_pool = self.browser_pool.clone();
 
         // Initialize metadata
         {
@@ -109,6 +110,7 @@ impl super::AutoCheckInScheduler {
                 use crate::application::services::CheckInExecutor;
                 match CheckInExecutor::new(account_repo.clone(), true) {
                     Ok(executor) => {
+                        let executor = executor.with_browser_pool(browser_pool.clone());
                         match executor
                             .execute_check_in(account_id.as_str(), &provider)
                             .await
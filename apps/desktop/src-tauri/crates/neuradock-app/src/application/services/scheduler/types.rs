@@ -10,6 +10,22 @@ pub(super) struct TaskMetadata {
     pub last_execution: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Liveness of one scheduled check-in task
+#[derive(Debug, Clone)]
+pub struct ScheduledTaskLiveness {
+    pub account_id: String,
+    pub account_name: String,
+    pub alive: bool,
+    pub last_execution: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Snapshot of the scheduler's background tasks
+#[derive(Debug, Clone)]
+pub struct SchedulerLiveness {
+    pub health_check_running: bool,
+    pub tasks: Vec<ScheduledTaskLiveness>,
+}
+
 /// Configuration for spawning a check-in task
 pub(super) struct CheckInTaskConfig {
     pub account_id: AccountId,
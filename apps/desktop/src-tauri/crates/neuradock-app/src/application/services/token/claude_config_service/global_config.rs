@@ -2,22 +2,24 @@ use anyhow::{Context, Result};
 use serde_json::{json, Value};
 use std::fs;
 
-use super::helpers::{ensure_sk_prefix, get_claude_config_path, MANAGED_ENV_KEYS};
+use super::helpers::{get_claude_config_path, MANAGED_ENV_KEYS};
+use crate::application::services::token::config_helpers::ensure_sk_prefix;
+use crate::application::services::token::ClaudeModelSlots;
 use neuradock_domain::token::ApiToken;
 
 pub(super) fn configure_global_impl(
     token: &ApiToken,
     base_url: &str,
-    model: Option<&str>,
+    slots: &ClaudeModelSlots,
 ) -> Result<String> {
     let api_key = ensure_sk_prefix(token.key());
-    configure_global_with_key_impl(&api_key, base_url, model)
+    configure_global_with_key_impl(&api_key, base_url, slots)
 }
 
 pub(super) fn configure_global_with_key_impl(
     api_key: &str,
     base_url: &str,
-    model: Option<&str>,
+    slots: &ClaudeModelSlots,
 ) -> Result<String> {
     let config_path = get_claude_config_path()?;
 
@@ -56,11 +58,7 @@ pub(super) fn configure_global_with_key_impl(
             env_obj.insert("API_TIMEOUT_MS".to_string(), json!("3000000"));
             env_obj.insert("CLAUDE_CODE_ATTRIBUTION_HEADER".to_string(), json!("0"));
 
-            if let Some(m) = model {
-                env_obj.insert("ANTHROPIC_DEFAULT_HAIKU_MODEL".to_string(), json!(m));
-                env_obj.insert("ANTHROPIC_DEFAULT_SONNET_MODEL".to_string(), json!(m));
-                env_obj.insert("ANTHROPIC_DEFAULT_OPUS_MODEL".to_string(), json!(m));
-            }
+            slots.write_to(env_obj);
         } else {
             // env exists but is not an object, replace it
             let mut env_map = serde_json::Map::new();
@@ -74,11 +72,7 @@ pub(super) fn configure_global_with_key_impl(
             env_map.insert("API_TIMEOUT_MS".to_string(), json!("3000000"));
             env_map.insert("CLAUDE_CODE_ATTRIBUTION_HEADER".to_string(), json!("0"));
 
-            if let Some(m) = model {
-                env_map.insert("ANTHROPIC_DEFAULT_HAIKU_MODEL".to_string(), json!(m));
-                env_map.insert("ANTHROPIC_DEFAULT_SONNET_MODEL".to_string(), json!(m));
-                env_map.insert("ANTHROPIC_DEFAULT_OPUS_MODEL".to_string(), json!(m));
-            }
+            slots.write_to(&mut env_map);
 
             *env_value = Value::Object(env_map);
         }
@@ -95,11 +89,7 @@ pub(super) fn configure_global_with_key_impl(
         env_map.insert("API_TIMEOUT_MS".to_string(), json!("3000000"));
         env_map.insert("CLAUDE_CODE_ATTRIBUTION_HEADER".to_string(), json!("0"));
 
-        if let Some(m) = model {
-            env_map.insert("ANTHROPIC_DEFAULT_HAIKU_MODEL".to_string(), json!(m));
-            env_map.insert("ANTHROPIC_DEFAULT_SONNET_MODEL".to_string(), json!(m));
-            env_map.insert("ANTHROPIC_DEFAULT_OPUS_MODEL".to_string(), json!(m));
-        }
+        slots.write_to(&mut env_map);
 
         config_obj.insert("env".to_string(), Value::Object(env_map));
     }
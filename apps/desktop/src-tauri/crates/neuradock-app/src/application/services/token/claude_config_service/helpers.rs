@@ -1,5 +1,7 @@
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::application::services::token::config_backup::ConfigBackupStore;
 
 // Keys that we manage in the env section
 pub(super) const MANAGED_ENV_KEYS: &[&str] = &[
@@ -14,16 +16,37 @@ pub(super) const MANAGED_ENV_KEYS: &[&str] = &[
     "ANTHROPIC_DEFAULT_OPUS_MODEL",
 ];
 
-pub(super) fn get_claude_config_path() -> Result<PathBuf> {
+// Env keys selecting the models Claude Code uses
+pub(super) const MODEL_ENV_KEYS: &[&str] = &[
+    "ANTHROPIC_MODEL",
+    "ANTHROPIC_DEFAULT_HAIKU_MODEL",
+    "ANTHROPIC_DEFAULT_SONNET_MODEL",
+    "ANTHROPIC_DEFAULT_OPUS_MODEL",
+    "ANTHROPIC_SMALL_FAST_MODEL",
+];
+
+// Values written alongside every token/base URL pair
+pub(super) const DEFAULT_ENV: &[(&str, &str)] = &[
+    ("CLAUDE_CODE_DISABLE_NONESSENTIAL_TRAFFIC", "1"),
+    ("DISABLE_TELEMETRY", "1"),
+    ("API_TIMEOUT_MS", "3000000"),
+    ("CLAUDE_CODE_ATTRIBUTION_HEADER", "0"),
+];
+
+pub(super) fn get_claude_dir() -> Result<PathBuf> {
     let home = dirs::home_dir().context("Cannot find home directory")?;
-    Ok(home.join(".claude").join("settings.json"))
+    Ok(home.join(".claude"))
+}
+
+pub(super) fn get_claude_config_path() -> Result<PathBuf> {
+    Ok(claude_settings_path(&get_claude_dir()?))
+}
+
+pub(super) fn claude_settings_path(claude_dir: &Path) -> PathBuf {
+    claude_dir.join("settings.json")
 }
 
-/// Ensure API key has sk- prefix
-pub(super) fn ensure_sk_prefix(key: &str) -> String {
-    if key.starts_with("sk-") {
-        key.to_string()
-    } else {
-        format!("sk-{}", key)
-    }
+/// Backups of settings.json live next to it in ~/.claude/neuradock-backups
+pub(super) fn claude_backup_store(claude_dir: &Path) -> ConfigBackupStore {
+    ConfigBackupStore::new(claude_dir.join("neuradock-backups"))
 }
@@ -1,27 +1,78 @@
 mod global_config;
 mod helpers;
+mod profile;
 mod temp_commands;
 
 use anyhow::Result;
+use std::sync::Arc;
 
+use super::config_backup::ConfigBackup;
+use super::shell_env::ShellDialect;
+use super::{ClaudeModelSlots, ConfiguredModels, TokenModels};
+use crate::application::services::{AuditLogService, AuditSummary};
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
+use neuradock_domain::claude_profile::ClaudeProfile;
 use neuradock_domain::token::ApiToken;
 
-pub struct ClaudeConfigService;
+use profile::EnvDigests;
+
+const AUDIT_TARGET: &str = "claude";
+
+pub struct ClaudeConfigService {
+    audit_log: Option<Arc<AuditLogService>>,
+}
 
 impl ClaudeConfigService {
     pub fn new() -> Self {
-        Self
+        Self { audit_log: None }
+    }
+
+    /// Record writes to settings.json in the audit log
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
     }
 
     /// Configure Claude Code globally by writing to ~/.claude/settings.json
     /// This properly merges with existing configuration
+    ///
+    /// `catalog` is the provider's cached model list. A model outside the token's allow list
+    /// fails with `ModelNotAvailable`, one missing from the catalog only adds a warning. When
+    /// the token's models are known, the haiku/sonnet/opus slots get the closest of them.
     pub fn configure_global(
         &self,
         token: &ApiToken,
         base_url: &str,
         model: Option<&str>,
+        catalog: &[String],
     ) -> Result<String> {
-        global_config::configure_global_impl(token, base_url, model)
+        let available = TokenModels::resolve(token, catalog);
+        let warning = match (&available, model) {
+            (Some(available), Some(model)) => available.vet(model)?,
+            _ => None,
+        };
+        let slots = ClaudeModelSlots::for_model(available.as_ref(), model);
+
+        let before = self.key_summary();
+        let mut message = global_config::configure_global_impl(token, base_url, &slots)?;
+        self.audit(
+            AuditAction::GlobalConfigWritten,
+            before,
+            Some(
+                AuditSummary::new()
+                    .field("token", token.name())
+                    .field("base_url", base_url)
+                    .field("model", model)
+                    .field("haiku_model", slots.haiku.as_deref())
+                    .field("sonnet_model", slots.sonnet.as_deref())
+                    .field("opus_model", slots.opus.as_deref())
+                    .secret("auth_token", token.key()),
+            ),
+        );
+        if let Some(warning) = warning {
+            message.push_str(&format!("\nWarning: {}", warning));
+        }
+        Ok(message)
     }
 
     /// Configure Claude Code globally with API key string (for independent keys)
@@ -31,23 +82,126 @@ impl ClaudeConfigService {
         base_url: &str,
         model: Option<&str>,
     ) -> Result<String> {
-        global_config::configure_global_with_key_impl(api_key, base_url, model)
+        let before = self.key_summary();
+        let message = global_config::configure_global_with_key_impl(
+            api_key,
+            base_url,
+            &ClaudeModelSlots::same(model),
+        )?;
+        self.audit(
+            AuditAction::GlobalConfigWritten,
+            before,
+            Some(
+                AuditSummary::new()
+                    .field("base_url", base_url)
+                    .field("model", model)
+                    .secret("auth_token", api_key),
+            ),
+        );
+        Ok(message)
     }
 
     /// Clear Claude Code global configuration
     /// Only removes the env keys that we manage, preserves other settings
     pub fn clear_global(&self) -> Result<String> {
-        global_config::clear_global_impl()
+        let before = self.key_summary();
+        let message = global_config::clear_global_impl()?;
+        self.audit(AuditAction::GlobalConfigCleared, before, None);
+        Ok(message)
+    }
+
+    /// Write a settings profile, replacing the keys written by the previous one
+    /// Returns the message and the digests of the written env values
+    pub fn apply_profile(
+        &self,
+        profile: &ClaudeProfile,
+        previous_keys: &[String],
+    ) -> Result<(String, EnvDigests)> {
+        let before = self.key_summary();
+        let applied =
+            profile::apply_profile_impl(&helpers::get_claude_dir()?, profile, previous_keys)?;
+        self.audit(
+            AuditAction::GlobalConfigWritten,
+            before,
+            Some(
+                AuditSummary::new()
+                    .field("profile", profile.name())
+                    .field("base_url", profile.base_url())
+                    .secret("auth_token", profile.auth_token()),
+            ),
+        );
+        Ok(applied)
+    }
+
+    /// Remove the env keys written by a profile
+    pub fn remove_profile_env(&self, keys: &[String]) -> Result<String> {
+        let before = self.key_summary();
+        let message = profile::remove_profile_env_impl(&helpers::get_claude_dir()?, keys)?;
+        self.audit(
+            AuditAction::GlobalConfigCleared,
+            before,
+            Some(AuditSummary::new().field("removed_keys", keys)),
+        );
+        Ok(message)
+    }
+
+    /// Digests of the env in settings.json, `None` when the file does not exist
+    pub fn current_env_digests(&self) -> Result<Option<EnvDigests>> {
+        profile::current_env_digests_impl(&helpers::get_claude_dir()?)
+    }
+
+    /// Auth token currently configured in settings.json
+    pub fn configured_auth_token(&self) -> Result<Option<String>> {
+        profile::configured_auth_token_impl(&helpers::get_claude_dir()?)
+    }
+
+    /// Base URL and models configured in settings.json, `None` when it does not exist
+    pub fn configured_models(&self) -> Result<Option<ConfiguredModels>> {
+        profile::configured_models_impl(&helpers::get_claude_dir()?)
+    }
+
+    /// List settings.json backups taken before profile switches, newest first
+    pub fn list_backups(&self) -> Result<Vec<ConfigBackup>> {
+        profile::list_backups_impl(&helpers::get_claude_dir()?)
+    }
+
+    /// Restore a settings.json backup, or undo the latest change when `backup_id` is `None`
+    pub fn restore_backup(&self, backup_id: Option<&str>) -> Result<String> {
+        let before = self.key_summary();
+        let message = profile::restore_backup_impl(&helpers::get_claude_dir()?, backup_id)?;
+        let after = self
+            .key_summary()
+            .unwrap_or_default()
+            .field("backup_id", backup_id.unwrap_or("latest"));
+        self.audit(AuditAction::BackupRestored, before, Some(after));
+        Ok(message)
     }
 
     /// Generate temporary export commands for current shell session
+    ///
+    /// `model` is checked against `catalog` and mapped to the model slots as in
+    /// `configure_global`, a warning is appended as a shell comment.
     pub fn generate_temp_commands(
         &self,
         token: &ApiToken,
         base_url: &str,
         model: Option<&str>,
+        catalog: &[String],
+        shell: ShellDialect,
     ) -> Result<String> {
-        temp_commands::generate_temp_commands_impl(token, base_url, model)
+        let available = TokenModels::resolve(token, catalog);
+        let warning = match (&available, model) {
+            (Some(available), Some(model)) => available.vet(model)?,
+            _ => None,
+        };
+        let slots = ClaudeModelSlots::for_model(available.as_ref(), model);
+
+        let mut commands =
+            temp_commands::generate_temp_commands_impl(token, base_url, &slots, shell)?;
+        if let Some(warning) = warning {
+            commands.push_str(&format!("\n# Warning: {}", warning));
+        }
+        Ok(commands)
     }
 
     /// Generate temporary export commands with API key string (for independent keys)
@@ -56,8 +210,39 @@ impl ClaudeConfigService {
         api_key: &str,
         base_url: &str,
         model: Option<&str>,
+        shell: ShellDialect,
     ) -> Result<String> {
-        temp_commands::generate_temp_commands_with_key_impl(api_key, base_url, model)
+        temp_commands::generate_temp_commands_with_key_impl(
+            api_key,
+            base_url,
+            &ClaudeModelSlots::same(model),
+            shell,
+        )
+    }
+}
+
+impl ClaudeConfigService {
+    /// Masked auth token currently in settings.json
+    fn key_summary(&self) -> Option<AuditSummary> {
+        self.audit_log.as_ref()?;
+        let token = self.configured_auth_token().ok().flatten()?;
+        Some(AuditSummary::new().secret("auth_token", &token))
+    }
+
+    fn audit(
+        &self,
+        action: AuditAction,
+        before: Option<AuditSummary>,
+        after: Option<AuditSummary>,
+    ) {
+        let Some(audit_log) = &self.audit_log else {
+            return;
+        };
+        let mut entry =
+            AuditEntry::new(AuditActor::User, action, AUDIT_TARGET).with_target_name("Claude Code");
+        entry.before = before.map(AuditSummary::build);
+        entry.after = after.map(AuditSummary::build);
+        audit_log.record_detached(entry);
     }
 }
 
@@ -0,0 +1,341 @@
+use anyhow::{Context, Result};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use super::helpers::{
+    claude_backup_store, claude_settings_path, DEFAULT_ENV, MANAGED_ENV_KEYS, MODEL_ENV_KEYS,
+};
+use crate::application::services::token::config_backup::ConfigBackup;
+use crate::application::services::token::ConfiguredModels;
+use neuradock_domain::claude_profile::ClaudeProfile;
+
+/// Env keys and digests of the values written to settings.json
+pub type EnvDigests = BTreeMap<String, String>;
+
+fn digest(value: &str) -> String {
+    format!("{:x}", Sha256::digest(value.as_bytes()))
+}
+
+/// Env written to settings.json for a profile, extra env may override defaults
+fn profile_env(profile: &ClaudeProfile) -> BTreeMap<String, String> {
+    let mut env: BTreeMap<String, String> = DEFAULT_ENV
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    env.insert(
+        "ANTHROPIC_AUTH_TOKEN".to_string(),
+        profile.auth_token().to_string(),
+    );
+    env.insert(
+        "ANTHROPIC_BASE_URL".to_string(),
+        profile.base_url().to_string(),
+    );
+
+    let models = profile.models();
+    for (key, model) in [
+        ("ANTHROPIC_DEFAULT_HAIKU_MODEL", &models.haiku),
+        ("ANTHROPIC_DEFAULT_SONNET_MODEL", &models.sonnet),
+        ("ANTHROPIC_DEFAULT_OPUS_MODEL", &models.opus),
+    ] {
+        if let Some(model) = model {
+            env.insert(key.to_string(), model.clone());
+        }
+    }
+
+    env.extend(profile.extra_env().clone());
+    env
+}
+
+fn read_settings(path: &Path) -> Result<Option<Map<String, Value>>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(path).context("Failed to read existing settings.json")?;
+    if content.trim().is_empty() {
+        return Ok(Some(Map::new()));
+    }
+    match serde_json::from_str(&content).context("Failed to parse existing settings.json")? {
+        Value::Object(settings) => Ok(Some(settings)),
+        _ => anyhow::bail!("settings.json must be a JSON object"),
+    }
+}
+
+/// Remove `keys` from the env section, dropping it when it ends up empty
+fn remove_env_keys<'a>(settings: &mut Map<String, Value>, keys: impl Iterator<Item = &'a str>) {
+    if let Some(env) = settings.get_mut("env").and_then(Value::as_object_mut) {
+        for key in keys {
+            env.remove(key);
+        }
+        if env.is_empty() {
+            settings.remove("env");
+        }
+    }
+}
+
+/// Write a profile's env into settings.json
+///
+/// Managed keys and the keys written by the previous profile are replaced,
+/// the rest of the file is kept. Returns the digests of the written values.
+pub(super) fn apply_profile_impl(
+    claude_dir: &Path,
+    profile: &ClaudeProfile,
+    previous_keys: &[String],
+) -> Result<(String, EnvDigests)> {
+    let settings_path = claude_settings_path(claude_dir);
+    fs::create_dir_all(claude_dir)?;
+
+    let mut settings = read_settings(&settings_path)?.unwrap_or_default();
+    remove_env_keys(
+        &mut settings,
+        MANAGED_ENV_KEYS
+            .iter()
+            .copied()
+            .chain(previous_keys.iter().map(String::as_str)),
+    );
+
+    let env = profile_env(profile);
+    let env_section = settings.entry("env").or_insert_with(|| json!({}));
+    if !env_section.is_object() {
+        *env_section = json!({});
+    }
+    if let Some(env_section) = env_section.as_object_mut() {
+        for (key, value) in &env {
+            env_section.insert(key.clone(), json!(value));
+        }
+    }
+
+    let backup = claude_backup_store(claude_dir).backup(
+        &format!("switch to profile {}", profile.name()),
+        &[&settings_path],
+    )?;
+    fs::write(
+        &settings_path,
+        serde_json::to_string_pretty(&Value::Object(settings))?,
+    )?;
+
+    log::info!(
+        "Claude profile {} applied at: {}",
+        profile.name(),
+        settings_path.display()
+    );
+
+    let digests = env
+        .iter()
+        .map(|(key, value)| (key.clone(), digest(value)))
+        .collect();
+    Ok((
+        format!(
+            "Switched Claude Code to profile \"{}\" at: {} (backup {})",
+            profile.name(),
+            settings_path.display(),
+            backup.id
+        ),
+        digests,
+    ))
+}
+
+/// Remove the keys written by a profile from settings.json
+pub(super) fn remove_profile_env_impl(claude_dir: &Path, keys: &[String]) -> Result<String> {
+    let settings_path = claude_settings_path(claude_dir);
+    let Some(mut settings) = read_settings(&settings_path)? else {
+        return Ok("No Claude Code configuration file found".to_string());
+    };
+
+    let backup = claude_backup_store(claude_dir).backup("deactivate profile", &[&settings_path])?;
+    remove_env_keys(&mut settings, keys.iter().map(String::as_str));
+    fs::write(
+        &settings_path,
+        serde_json::to_string_pretty(&Value::Object(settings))?,
+    )?;
+
+    Ok(format!(
+        "Removed Claude Code profile settings (backup {})",
+        backup.id
+    ))
+}
+
+/// Digests of the env currently in settings.json, `None` when the file is missing
+pub(super) fn current_env_digests_impl(claude_dir: &Path) -> Result<Option<EnvDigests>> {
+    let Some(settings) = read_settings(&claude_settings_path(claude_dir))? else {
+        return Ok(None);
+    };
+
+    let digests = settings
+        .get("env")
+        .and_then(Value::as_object)
+        .map(|env| {
+            env.iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    (key.clone(), digest(&value))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(Some(digests))
+}
+
+/// `ANTHROPIC_AUTH_TOKEN` currently in settings.json
+pub(super) fn configured_auth_token_impl(claude_dir: &Path) -> Result<Option<String>> {
+    Ok(
+        read_settings(&claude_settings_path(claude_dir))?.and_then(|settings| {
+            settings
+                .get("env")
+                .and_then(|env| env.get("ANTHROPIC_AUTH_TOKEN"))
+                .and_then(Value::as_str)
+                .filter(|token| !token.is_empty())
+                .map(str::to_string)
+        }),
+    )
+}
+
+/// Base URL and models in settings.json, `None` when the file is missing
+pub(super) fn configured_models_impl(claude_dir: &Path) -> Result<Option<ConfiguredModels>> {
+    let Some(settings) = read_settings(&claude_settings_path(claude_dir))? else {
+        return Ok(None);
+    };
+    let env = settings.get("env").and_then(Value::as_object);
+    let env_value = |key: &str| env.and_then(|env| env.get(key)).and_then(Value::as_str);
+
+    let mut configured = ConfiguredModels {
+        base_url: env_value("ANTHROPIC_BASE_URL")
+            .filter(|url| !url.is_empty())
+            .map(str::to_string),
+        models: Vec::new(),
+    };
+    if let Some(model) = settings.get("model").and_then(Value::as_str) {
+        configured.push_model(model);
+    }
+    for key in MODEL_ENV_KEYS {
+        if let Some(model) = env_value(key) {
+            configured.push_model(model);
+        }
+    }
+    Ok(Some(configured))
+}
+
+pub(super) fn list_backups_impl(claude_dir: &Path) -> Result<Vec<ConfigBackup>> {
+    claude_backup_store(claude_dir).list()
+}
+
+pub(super) fn restore_backup_impl(claude_dir: &Path, backup_id: Option<&str>) -> Result<String> {
+    let backup = claude_backup_store(claude_dir).restore(backup_id)?;
+
+    Ok(format!(
+        "Restored Claude Code settings from backup {} (before \"{}\")",
+        backup.id, backup.operation
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use neuradock_domain::claude_profile::{ClaudeModelMapping, ClaudeProfileConfig};
+
+    fn profile(name: &str, extra: &[(&str, &str)]) -> ClaudeProfile {
+        ClaudeProfile::create(ClaudeProfileConfig {
+            name: name.to_string(),
+            auth_token: format!("sk-{}", name),
+            base_url: "https://relay.example.com".to_string(),
+            models: ClaudeModelMapping {
+                haiku: Some("glm-4.5-air".to_string()),
+                sonnet: None,
+                opus: None,
+            },
+            extra_env: extra
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        })
+        .unwrap()
+    }
+
+    fn read_env(dir: &Path) -> Map<String, Value> {
+        read_settings(&claude_settings_path(dir)).unwrap().unwrap()["env"]
+            .as_object()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_configured_auth_token() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(configured_auth_token_impl(dir.path()).unwrap(), None);
+
+        apply_profile_impl(dir.path(), &profile("work", &[]), &[]).unwrap();
+        assert_eq!(
+            configured_auth_token_impl(dir.path()).unwrap().as_deref(),
+            Some("sk-work")
+        );
+    }
+
+    #[test]
+    fn test_switch_profiles_keeps_user_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("settings.json"),
+            r#"{"permissions":{"allow":["Bash(ls)"]},"env":{"MY_VAR":"1","ANTHROPIC_DEFAULT_OPUS_MODEL":"old"}}"#,
+        )
+        .unwrap();
+
+        let (_, first) = apply_profile_impl(
+            dir.path(),
+            &profile("work", &[("HTTPS_PROXY", "http://p:1")]),
+            &[],
+        )
+        .unwrap();
+        let env = read_env(dir.path());
+        assert_eq!(env["ANTHROPIC_AUTH_TOKEN"], "sk-work");
+        assert_eq!(env["ANTHROPIC_DEFAULT_HAIKU_MODEL"], "glm-4.5-air");
+        assert_eq!(env["HTTPS_PROXY"], "http://p:1");
+        assert_eq!(env["MY_VAR"], "1");
+        // Managed keys not set by the profile are cleared
+        assert!(env.get("ANTHROPIC_DEFAULT_OPUS_MODEL").is_none());
+
+        let previous: Vec<String> = first.keys().cloned().collect();
+        let (_, second) = apply_profile_impl(dir.path(), &profile("home", &[]), &previous).unwrap();
+        let env = read_env(dir.path());
+        assert_eq!(env["ANTHROPIC_AUTH_TOKEN"], "sk-home");
+        assert!(env.get("HTTPS_PROXY").is_none());
+        assert_eq!(env["MY_VAR"], "1");
+
+        let settings = read_settings(&claude_settings_path(dir.path()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(settings["permissions"]["allow"][0], "Bash(ls)");
+        assert_eq!(list_backups_impl(dir.path()).unwrap().len(), 2);
+
+        let current = current_env_digests_impl(dir.path()).unwrap().unwrap();
+        assert!(second
+            .iter()
+            .all(|(key, digest)| current.get(key) == Some(digest)));
+        assert_ne!(
+            first["ANTHROPIC_AUTH_TOKEN"],
+            second["ANTHROPIC_AUTH_TOKEN"]
+        );
+    }
+
+    #[test]
+    fn test_remove_profile_env_and_undo() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(current_env_digests_impl(dir.path()).unwrap().is_none());
+
+        let (_, applied) = apply_profile_impl(dir.path(), &profile("work", &[]), &[]).unwrap();
+        let keys: Vec<String> = applied.keys().cloned().collect();
+        remove_profile_env_impl(dir.path(), &keys).unwrap();
+        let settings = read_settings(&claude_settings_path(dir.path()))
+            .unwrap()
+            .unwrap();
+        assert!(settings.get("env").is_none());
+
+        restore_backup_impl(dir.path(), None).unwrap();
+        assert_eq!(read_env(dir.path())["ANTHROPIC_AUTH_TOKEN"], "sk-work");
+    }
+}
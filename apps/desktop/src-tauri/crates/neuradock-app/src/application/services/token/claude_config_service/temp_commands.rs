@@ -1,37 +1,85 @@
 use anyhow::Result;
 
-use super::helpers::ensure_sk_prefix;
+use super::helpers::DEFAULT_ENV;
+use crate::application::services::token::config_helpers::ensure_sk_prefix;
+use crate::application::services::token::shell_env::{render_env, ShellDialect};
+use crate::application::services::token::ClaudeModelSlots;
 use neuradock_domain::token::ApiToken;
 
 pub(super) fn generate_temp_commands_impl(
     token: &ApiToken,
     base_url: &str,
-    model: Option<&str>,
+    slots: &ClaudeModelSlots,
+    shell: ShellDialect,
 ) -> Result<String> {
-    let api_key = ensure_sk_prefix(token.key());
-    generate_temp_commands_with_key_impl(&api_key, base_url, model)
+    generate_temp_commands_with_key_impl(token.key(), base_url, slots, shell)
 }
 
 pub(super) fn generate_temp_commands_with_key_impl(
     api_key: &str,
     base_url: &str,
-    model: Option<&str>,
+    slots: &ClaudeModelSlots,
+    shell: ShellDialect,
 ) -> Result<String> {
-    let api_key = ensure_sk_prefix(api_key);
-    let mut commands = vec![
-        format!("export ANTHROPIC_AUTH_TOKEN=\"{}\"", api_key),
-        format!("export ANTHROPIC_BASE_URL=\"{}\"", base_url),
-        "export CLAUDE_CODE_DISABLE_NONESSENTIAL_TRAFFIC=\"1\"".to_string(),
-        "export DISABLE_TELEMETRY=\"1\"".to_string(),
-        "export API_TIMEOUT_MS=\"3000000\"".to_string(),
-        "export CLAUDE_CODE_ATTRIBUTION_HEADER=\"0\"".to_string(),
+    let mut vars = vec![
+        ("ANTHROPIC_AUTH_TOKEN", ensure_sk_prefix(api_key)),
+        ("ANTHROPIC_BASE_URL", base_url.to_string()),
     ];
+    vars.extend(
+        DEFAULT_ENV
+            .iter()
+            .map(|(key, value)| (*key, value.to_string())),
+    );
 
-    if let Some(m) = model {
-        commands.push(format!("export ANTHROPIC_DEFAULT_HAIKU_MODEL=\"{}\"", m));
-        commands.push(format!("export ANTHROPIC_DEFAULT_SONNET_MODEL=\"{}\"", m));
-        commands.push(format!("export ANTHROPIC_DEFAULT_OPUS_MODEL=\"{}\"", m));
+    vars.extend(slots.env_vars());
+
+    Ok(render_env(shell, &["Claude Code"], &vars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_commands_per_shell() {
+        let posix = generate_temp_commands_with_key_impl(
+            "abc",
+            "https://relay.example.com",
+            &ClaudeModelSlots::same(Some("glm-4.6")),
+            ShellDialect::Posix,
+        )
+        .unwrap();
+        assert!(posix.contains("export ANTHROPIC_AUTH_TOKEN='sk-abc'"));
+        assert!(posix.contains("export ANTHROPIC_DEFAULT_OPUS_MODEL='glm-4.6'"));
+        assert!(posix.contains("export API_TIMEOUT_MS='3000000'"));
+
+        let powershell = generate_temp_commands_with_key_impl(
+            "sk-abc",
+            "https://relay.example.com",
+            &ClaudeModelSlots::same(None),
+            ShellDialect::PowerShell,
+        )
+        .unwrap();
+        assert!(powershell.contains("$env:ANTHROPIC_BASE_URL = 'https://relay.example.com'"));
+        assert!(!powershell.contains("ANTHROPIC_DEFAULT_OPUS_MODEL"));
     }
 
-    Ok(commands.join("\n"))
+    #[test]
+    fn test_temp_commands_write_mapped_slots() {
+        let slots = ClaudeModelSlots {
+            haiku: Some("claude-haiku-4-5".to_string()),
+            sonnet: Some("claude-sonnet-4-5".to_string()),
+            opus: None,
+        };
+        let posix = generate_temp_commands_with_key_impl(
+            "sk-abc",
+            "https://relay.example.com",
+            &slots,
+            ShellDialect::Posix,
+        )
+        .unwrap();
+        assert!(posix.contains("export ANTHROPIC_DEFAULT_HAIKU_MODEL='claude-haiku-4-5'"));
+        assert!(posix.contains("export ANTHROPIC_DEFAULT_SONNET_MODEL='claude-sonnet-4-5'"));
+        assert!(!posix.contains("ANTHROPIC_DEFAULT_OPUS_MODEL"));
+    }
 }
@@ -0,0 +1,226 @@
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::helpers::{backup_store, read_optional, ToolTarget};
+use super::CliTool;
+use crate::application::services::token::config_helpers::ensure_v1_base_url;
+
+const BLOCK_START: &str = "# >>> Managed by NeuraDock >>>";
+const BLOCK_END: &str = "# <<< Managed by NeuraDock <<<";
+/// Prefix for user settings that would conflict with the managed block
+const REPLACED_PREFIX: &str = "# (replaced by NeuraDock) ";
+
+fn config_path(home_dir: &Path) -> PathBuf {
+    home_dir.join(".aider.conf.yml")
+}
+
+/// Key of a top-level `key: value` line
+fn top_level_key(line: &str) -> Option<&str> {
+    if line.starts_with([' ', '\t', '#', '-']) {
+        return None;
+    }
+    line.split_once(':').map(|(key, _)| key.trim())
+}
+
+/// Lines outside the managed block, and whether the block was found
+fn strip_block(content: &str) -> (Vec<&str>, bool) {
+    let mut found = false;
+    let mut inside = false;
+    let lines = content
+        .lines()
+        .filter(|line| {
+            let trimmed = line.trim();
+            if trimmed == BLOCK_START {
+                found = true;
+                inside = true;
+                return false;
+            }
+            if trimmed == BLOCK_END {
+                inside = false;
+                return false;
+            }
+            !inside
+        })
+        .collect();
+    (lines, found)
+}
+
+/// JSON strings are valid YAML double-quoted scalars
+fn yaml_string(value: &str) -> String {
+    serde_json::Value::String(value.to_string()).to_string()
+}
+
+fn join_lines(mut lines: Vec<String>) -> String {
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    if lines.is_empty() {
+        String::new()
+    } else {
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Write the OpenAI compatible endpoint to ~/.aider.conf.yml
+///
+/// aider's config is usually a commented copy of the sample file, so it is
+/// edited line by line: the managed settings go in a marked block and user
+/// settings for the same keys are commented out until the block is cleared.
+pub(super) fn configure_impl(home_dir: &Path, target: &ToolTarget) -> Result<String> {
+    let config_path = config_path(home_dir);
+
+    let mut settings = vec![
+        ("openai-api-key", target.api_key.to_string()),
+        ("openai-api-base", ensure_v1_base_url(target.base_url)),
+    ];
+    if let Some(model) = target.model {
+        // litellm routes `openai/<model>` to the OpenAI compatible base URL
+        let model = if model.starts_with("openai/") {
+            model.to_string()
+        } else {
+            format!("openai/{}", model)
+        };
+        settings.push(("model", model));
+    }
+
+    let existing = read_optional(&config_path)?.unwrap_or_default();
+    let (lines, _) = strip_block(&existing);
+    let mut lines: Vec<String> = lines
+        .into_iter()
+        .map(|line| match top_level_key(line) {
+            Some(key) if settings.iter().any(|(managed, _)| *managed == key) => {
+                format!("{}{}", REPLACED_PREFIX, line)
+            }
+            _ => line.to_string(),
+        })
+        .collect();
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    if !lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines.push(BLOCK_START.to_string());
+    lines.extend(
+        settings
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key, yaml_string(value))),
+    );
+    lines.push(BLOCK_END.to_string());
+
+    let backup = backup_store(CliTool::Aider, home_dir)
+        .backup(&format!("configure {}", target.slug), &[&config_path])?;
+
+    fs::write(&config_path, join_lines(lines))?;
+    log::info!("aider config updated at: {}", config_path.display());
+
+    Ok(format!(
+        "Successfully configured aider globally ({}):\n  - .aider.conf.yml: {}\n  - backup: {}",
+        target.display_name,
+        config_path.display(),
+        backup.id
+    ))
+}
+
+/// Remove the managed block and re-enable the user settings it replaced
+pub(super) fn clear_impl(home_dir: &Path) -> Result<String> {
+    let config_path = config_path(home_dir);
+
+    let Some(content) = read_optional(&config_path)? else {
+        return Ok("No aider configuration managed by NeuraDock found".to_string());
+    };
+    let (lines, found) = strip_block(&content);
+    if !found {
+        return Ok("No aider configuration managed by NeuraDock found".to_string());
+    }
+
+    let lines: Vec<String> = lines
+        .into_iter()
+        .map(|line| {
+            line.strip_prefix(REPLACED_PREFIX)
+                .unwrap_or(line)
+                .to_string()
+        })
+        .collect();
+    let content = join_lines(lines);
+
+    let backup = backup_store(CliTool::Aider, home_dir).backup("clear", &[&config_path])?;
+    if content.is_empty() {
+        fs::remove_file(&config_path)?;
+    } else {
+        fs::write(&config_path, content)?;
+    }
+
+    log::info!("Successfully cleared aider configuration");
+
+    Ok(format!(
+        "Successfully cleared aider configuration (backup {}):\n  - .aider.conf.yml: removed API key ({})",
+        backup.id,
+        config_path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER_CONFIG: &str =
+        "## Specify the model to use\nmodel: gpt-4o\n\n#dark-mode: false\nauto-commits: false\n";
+
+    #[test]
+    fn test_configure_comments_out_conflicts_and_clear_restores_them() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(config_path(dir.path()), USER_CONFIG).unwrap();
+
+        let target = ToolTarget {
+            slug: "relay",
+            display_name: "Relay",
+            api_key: "sk-a\"b",
+            base_url: "https://relay.example.com",
+            model: Some("deepseek-chat"),
+        };
+        configure_impl(dir.path(), &target).unwrap();
+        configure_impl(dir.path(), &target).unwrap();
+
+        let config = fs::read_to_string(config_path(dir.path())).unwrap();
+        assert_eq!(
+            config,
+            "## Specify the model to use\n# (replaced by NeuraDock) model: gpt-4o\n\n\
+             #dark-mode: false\nauto-commits: false\n\n\
+             # >>> Managed by NeuraDock >>>\n\
+             openai-api-key: \"sk-a\\\"b\"\n\
+             openai-api-base: \"https://relay.example.com/v1\"\n\
+             model: \"openai/deepseek-chat\"\n\
+             # <<< Managed by NeuraDock <<<\n"
+        );
+
+        clear_impl(dir.path()).unwrap();
+        assert_eq!(
+            fs::read_to_string(config_path(dir.path())).unwrap(),
+            USER_CONFIG
+        );
+        assert!(clear_impl(dir.path()).unwrap().starts_with("No aider"));
+    }
+
+    #[test]
+    fn test_clear_removes_file_written_by_neuradock() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = ToolTarget {
+            slug: "openai_compatible",
+            display_name: "OpenAI Compatible API",
+            api_key: "sk-abc",
+            base_url: "https://api.example.com/v1",
+            model: None,
+        };
+        configure_impl(dir.path(), &target).unwrap();
+        assert!(!fs::read_to_string(config_path(dir.path()))
+            .unwrap()
+            .contains("model:"));
+
+        clear_impl(dir.path()).unwrap();
+        assert!(!config_path(dir.path()).exists());
+        assert!(dir.path().join(".aider/neuradock-backups").is_dir());
+    }
+}
@@ -0,0 +1,264 @@
+use anyhow::{Context, Result};
+use serde_yaml::{Mapping, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::helpers::{backup_store, read_optional, ToolTarget};
+use super::CliTool;
+use crate::application::services::token::config_helpers::ensure_v1_base_url;
+
+/// Suffix of the model titles NeuraDock writes, used to find them again
+const MANAGED_SUFFIX: &str = " (NeuraDock)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigFormat {
+    Yaml,
+    /// Legacy config.json, still read when there is no config.yaml
+    Json,
+}
+
+impl ConfigFormat {
+    fn file_name(self) -> &'static str {
+        match self {
+            ConfigFormat::Yaml => "config.yaml",
+            ConfigFormat::Json => "config.json",
+        }
+    }
+
+    /// Models are named by `name` in config.yaml and by `title` in config.json
+    fn title_key(self) -> &'static str {
+        match self {
+            ConfigFormat::Yaml => "name",
+            ConfigFormat::Json => "title",
+        }
+    }
+
+    fn parse(self, content: &str) -> Result<Mapping> {
+        if content.trim().is_empty() {
+            return Ok(Mapping::new());
+        }
+        let context = || format!("Failed to parse existing {}", self.file_name());
+        let value: Value = match self {
+            ConfigFormat::Yaml => serde_yaml::from_str(content).with_context(context)?,
+            ConfigFormat::Json => serde_json::from_str(content).with_context(context)?,
+        };
+        match value {
+            Value::Mapping(config) => Ok(config),
+            _ => anyhow::bail!("{} must be a mapping", self.file_name()),
+        }
+    }
+
+    fn render(self, config: &Mapping) -> Result<String> {
+        Ok(match self {
+            ConfigFormat::Yaml => serde_yaml::to_string(config)?,
+            ConfigFormat::Json => serde_json::to_string_pretty(config)?,
+        })
+    }
+}
+
+fn config_file(continue_dir: &Path) -> (PathBuf, ConfigFormat) {
+    let yaml_path = continue_dir.join(ConfigFormat::Yaml.file_name());
+    let json_path = continue_dir.join(ConfigFormat::Json.file_name());
+    if !yaml_path.exists() && json_path.exists() {
+        (json_path, ConfigFormat::Json)
+    } else {
+        (yaml_path, ConfigFormat::Yaml)
+    }
+}
+
+fn is_managed(model: &Value, format: ConfigFormat) -> bool {
+    model
+        .get(format.title_key())
+        .and_then(Value::as_str)
+        .is_some_and(|title| title.ends_with(MANAGED_SUFFIX))
+}
+
+fn models_mut(config: &mut Mapping, format: ConfigFormat) -> Result<&mut Vec<Value>> {
+    match config
+        .entry("models".into())
+        .or_insert_with(|| Value::Sequence(Vec::new()))
+    {
+        Value::Sequence(models) => Ok(models),
+        _ => anyhow::bail!("`models` in {} must be a list", format.file_name()),
+    }
+}
+
+/// Put an OpenAI compatible model first in Continue's model list
+///
+/// The first model is Continue's default chat model. User models are kept,
+/// but comments in config.yaml are not (the backup has the original file).
+pub(super) fn configure_impl(continue_dir: &Path, target: &ToolTarget) -> Result<String> {
+    let (config_path, format) = config_file(continue_dir);
+    let model = target.require_model(CliTool::Continue)?;
+
+    fs::create_dir_all(continue_dir)?;
+
+    let mut config = format.parse(&read_optional(&config_path)?.unwrap_or_default())?;
+    if format == ConfigFormat::Yaml {
+        // Required top-level fields of a local config.yaml
+        for (key, default) in [
+            ("name", "Local Assistant"),
+            ("version", "1.0.0"),
+            ("schema", "v1"),
+        ] {
+            config.entry(key.into()).or_insert_with(|| default.into());
+        }
+    }
+
+    let mut entry = Mapping::new();
+    entry.insert(
+        format.title_key().into(),
+        format!("{} - {}{}", model, target.display_name, MANAGED_SUFFIX).into(),
+    );
+    entry.insert("provider".into(), "openai".into());
+    entry.insert("model".into(), model.into());
+    entry.insert("apiBase".into(), ensure_v1_base_url(target.base_url).into());
+    entry.insert("apiKey".into(), target.api_key.into());
+    if format == ConfigFormat::Yaml {
+        entry.insert(
+            "roles".into(),
+            Value::Sequence(vec!["chat".into(), "edit".into(), "apply".into()]),
+        );
+    }
+
+    let models = models_mut(&mut config, format)?;
+    models.retain(|model| !is_managed(model, format));
+    models.insert(0, Value::Mapping(entry));
+    let content = format.render(&config)?;
+
+    let backup = backup_store(CliTool::Continue, continue_dir)
+        .backup(&format!("configure {}", target.slug), &[&config_path])?;
+
+    fs::write(&config_path, content)?;
+    log::info!("Continue config updated at: {}", config_path.display());
+
+    Ok(format!(
+        "Successfully configured Continue globally ({}):\n  - {}: {}\n  - backup: {}",
+        target.display_name,
+        format.file_name(),
+        config_path.display(),
+        backup.id
+    ))
+}
+
+/// Remove the NeuraDock models, keeping the rest of the config
+pub(super) fn clear_impl(continue_dir: &Path) -> Result<String> {
+    let (config_path, format) = config_file(continue_dir);
+
+    let Some(content) = read_optional(&config_path)? else {
+        return Ok("No Continue configuration managed by NeuraDock found".to_string());
+    };
+    let mut config = format.parse(&content)?;
+
+    let removed = match config.get_mut("models") {
+        Some(Value::Sequence(models)) => {
+            let before = models.len();
+            models.retain(|model| !is_managed(model, format));
+            before - models.len()
+        }
+        _ => 0,
+    };
+    if removed == 0 {
+        return Ok("No Continue configuration managed by NeuraDock found".to_string());
+    }
+
+    let backup = backup_store(CliTool::Continue, continue_dir).backup("clear", &[&config_path])?;
+    fs::write(&config_path, format.render(&config)?)?;
+
+    log::info!("Successfully cleared Continue configuration");
+
+    Ok(format!(
+        "Successfully cleared Continue configuration (backup {}):\n  - {}: removed {} model(s) ({})",
+        backup.id,
+        format.file_name(),
+        removed,
+        config_path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(model: &'static str) -> ToolTarget<'static> {
+        ToolTarget {
+            slug: "relay",
+            display_name: "Relay",
+            api_key: "sk-abc",
+            base_url: "https://relay.example.com",
+            model: Some(model),
+        }
+    }
+
+    fn read_yaml(path: &Path) -> Value {
+        serde_yaml::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_configure_and_clear_yaml_keep_user_models() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.yaml");
+        fs::write(
+            &config_path,
+            "name: My Config\nversion: 0.0.1\nschema: v1\nmodels:\n  - name: Local\n    provider: ollama\n    model: qwen3\n",
+        )
+        .unwrap();
+
+        configure_impl(dir.path(), &target("gpt-4o")).unwrap();
+        configure_impl(dir.path(), &target("deepseek-chat")).unwrap();
+
+        let config = read_yaml(&config_path);
+        assert_eq!(config["name"], "My Config");
+        let models = config["models"].as_sequence().unwrap();
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0]["name"], "deepseek-chat - Relay (NeuraDock)");
+        assert_eq!(models[0]["apiBase"], "https://relay.example.com/v1");
+        assert_eq!(models[0]["roles"][0], "chat");
+        assert_eq!(models[1]["name"], "Local");
+
+        clear_impl(dir.path()).unwrap();
+        let config = read_yaml(&config_path);
+        let models = config["models"].as_sequence().unwrap();
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0]["name"], "Local");
+        assert!(clear_impl(dir.path()).unwrap().starts_with("No Continue"));
+    }
+
+    #[test]
+    fn test_new_config_is_yaml_with_required_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(configure_impl(
+            dir.path(),
+            &ToolTarget {
+                model: None,
+                ..target("gpt-4o")
+            }
+        )
+        .is_err());
+
+        configure_impl(dir.path(), &target("gpt-4o")).unwrap();
+        let content = fs::read_to_string(dir.path().join("config.yaml")).unwrap();
+        assert!(content.starts_with("name: Local Assistant\nversion: 1.0.0\nschema: v1\n"));
+        assert!(!dir.path().join("config.json").exists());
+    }
+
+    #[test]
+    fn test_legacy_json_config_is_updated_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        fs::write(
+            &config_path,
+            r#"{"models":[],"allowAnonymousTelemetry":false}"#,
+        )
+        .unwrap();
+
+        configure_impl(dir.path(), &target("gpt-4o")).unwrap();
+
+        assert!(!dir.path().join("config.yaml").exists());
+        let config: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(config["models"][0]["title"], "gpt-4o - Relay (NeuraDock)");
+        assert!(config["models"][0].get("roles").is_none());
+        assert_eq!(config["allowAnonymousTelemetry"], false);
+    }
+}
@@ -0,0 +1,216 @@
+use anyhow::Result;
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::helpers::{backup_store, object_entry, parse_json_object, read_optional, ToolTarget};
+use super::CliTool;
+
+/// Comment placed above the variables NeuraDock writes to .env
+const MANAGED_MARKER: &str = "# Managed by NeuraDock";
+const MANAGED_ENV_KEYS: &[&str] = &["GEMINI_API_KEY", "GOOGLE_GEMINI_BASE_URL", "GEMINI_MODEL"];
+
+fn env_path(gemini_dir: &Path) -> PathBuf {
+    gemini_dir.join(".env")
+}
+
+fn settings_path(gemini_dir: &Path) -> PathBuf {
+    gemini_dir.join("settings.json")
+}
+
+fn env_line_key(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    let line = line.strip_prefix("export ").unwrap_or(line);
+    line.split_once('=').map(|(key, _)| key.trim())
+}
+
+/// Lines of `.env` without the managed variables, and whether any were found
+fn strip_managed_env(content: &str) -> (Vec<String>, bool) {
+    let mut removed = false;
+    let mut lines: Vec<String> = content
+        .lines()
+        .filter(|line| {
+            if line.trim() == MANAGED_MARKER {
+                return false;
+            }
+            let managed = env_line_key(line).is_some_and(|key| MANAGED_ENV_KEYS.contains(&key));
+            removed |= managed;
+            !managed
+        })
+        .map(str::to_string)
+        .collect();
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    (lines, removed)
+}
+
+/// Single quotes keep the value literal for the dotenv parser Gemini CLI uses
+fn env_assignment(key: &str, value: &str) -> Result<String> {
+    if value.contains('\'') || value.contains('\n') {
+        anyhow::bail!("{} contains characters that cannot be written to .env", key);
+    }
+    Ok(format!("{}='{}'", key, value))
+}
+
+/// Write the key, endpoint and model to ~/.gemini/.env and select API key auth
+///
+/// Gemini CLI loads `~/.gemini/.env` when the project has no `.env` of its own.
+/// Other variables in the file and other settings are kept.
+pub(super) fn configure_impl(gemini_dir: &Path, target: &ToolTarget) -> Result<String> {
+    let env_path = env_path(gemini_dir);
+    let settings_path = settings_path(gemini_dir);
+
+    fs::create_dir_all(gemini_dir)?;
+
+    // Build both files before touching anything so a parse error leaves them intact
+    let (mut lines, _) = strip_managed_env(&read_optional(&env_path)?.unwrap_or_default());
+    if !lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines.push(MANAGED_MARKER.to_string());
+    lines.push(env_assignment("GEMINI_API_KEY", target.api_key)?);
+    lines.push(env_assignment(
+        "GOOGLE_GEMINI_BASE_URL",
+        target.base_url.trim_end_matches('/'),
+    )?);
+    if let Some(model) = target.model {
+        lines.push(env_assignment("GEMINI_MODEL", model)?);
+    }
+
+    let mut settings =
+        parse_json_object(read_optional(&settings_path)?.as_deref(), "settings.json")?;
+    let security = object_entry(&mut settings, "security", "settings.json")?;
+    object_entry(security, "auth", "settings.json")?
+        .insert("selectedType".to_string(), json!("gemini-api-key"));
+    // Releases before the nested settings layout read the top-level key
+    if settings.contains_key("selectedAuthType") {
+        settings.insert("selectedAuthType".to_string(), json!("gemini-api-key"));
+    }
+    let settings_json = serde_json::to_string_pretty(&settings)?;
+
+    let backup = backup_store(CliTool::GeminiCli, gemini_dir).backup(
+        &format!("configure {}", target.slug),
+        &[&env_path, &settings_path],
+    )?;
+
+    fs::write(&env_path, lines.join("\n") + "\n")?;
+    log::info!("Gemini CLI .env updated at: {}", env_path.display());
+    fs::write(&settings_path, settings_json)?;
+    log::info!(
+        "Gemini CLI settings.json updated at: {}",
+        settings_path.display()
+    );
+
+    Ok(format!(
+        "Successfully configured Gemini CLI globally ({}):\n  - .env: {}\n  - settings.json: {}\n  - backup: {}",
+        target.display_name,
+        env_path.display(),
+        settings_path.display(),
+        backup.id
+    ))
+}
+
+/// Remove the managed variables from .env, keeping settings.json as is
+pub(super) fn clear_impl(gemini_dir: &Path) -> Result<String> {
+    let env_path = env_path(gemini_dir);
+
+    let Some(content) = read_optional(&env_path)? else {
+        return Ok("No Gemini CLI configuration managed by NeuraDock found".to_string());
+    };
+    let (lines, removed) = strip_managed_env(&content);
+    if !removed {
+        return Ok("No Gemini CLI configuration managed by NeuraDock found".to_string());
+    }
+
+    let backup = backup_store(CliTool::GeminiCli, gemini_dir).backup("clear", &[&env_path])?;
+    if lines.is_empty() {
+        fs::remove_file(&env_path)?;
+    } else {
+        fs::write(&env_path, lines.join("\n") + "\n")?;
+    }
+
+    log::info!("Successfully cleared Gemini CLI configuration");
+
+    Ok(format!(
+        "Successfully cleared Gemini CLI configuration (backup {}):\n  - .env: removed API key ({})",
+        backup.id,
+        env_path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn target(model: Option<&'static str>) -> ToolTarget<'static> {
+        ToolTarget {
+            slug: "relay",
+            display_name: "Relay",
+            api_key: "sk-abc",
+            base_url: "https://relay.example.com/",
+            model,
+        }
+    }
+
+    #[test]
+    fn test_configure_and_clear_keep_user_env_and_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let gemini_dir = dir.path();
+        fs::write(
+            env_path(gemini_dir),
+            "HTTPS_PROXY=http://127.0.0.1:7890\nexport GEMINI_API_KEY=old\n",
+        )
+        .unwrap();
+        fs::write(settings_path(gemini_dir), r#"{"ui":{"theme":"Dracula"}}"#).unwrap();
+
+        configure_impl(gemini_dir, &target(Some("gemini-2.5-pro"))).unwrap();
+
+        let env = fs::read_to_string(env_path(gemini_dir)).unwrap();
+        assert_eq!(
+            env,
+            "HTTPS_PROXY=http://127.0.0.1:7890\n\n# Managed by NeuraDock\n\
+             GEMINI_API_KEY='sk-abc'\n\
+             GOOGLE_GEMINI_BASE_URL='https://relay.example.com'\n\
+             GEMINI_MODEL='gemini-2.5-pro'\n"
+        );
+        let settings: Value =
+            serde_json::from_str(&fs::read_to_string(settings_path(gemini_dir)).unwrap()).unwrap();
+        assert_eq!(settings["ui"]["theme"], "Dracula");
+        assert_eq!(
+            settings["security"]["auth"]["selectedType"],
+            "gemini-api-key"
+        );
+
+        // Configuring again replaces the block instead of appending a second one
+        configure_impl(gemini_dir, &target(None)).unwrap();
+        let env = fs::read_to_string(env_path(gemini_dir)).unwrap();
+        assert_eq!(env.matches("GEMINI_API_KEY").count(), 1);
+        assert!(!env.contains("GEMINI_MODEL"));
+
+        clear_impl(gemini_dir).unwrap();
+        assert_eq!(
+            fs::read_to_string(env_path(gemini_dir)).unwrap(),
+            "HTTPS_PROXY=http://127.0.0.1:7890\n"
+        );
+        assert!(clear_impl(gemini_dir).unwrap().starts_with("No Gemini CLI"));
+    }
+
+    #[test]
+    fn test_clear_removes_env_file_written_by_neuradock() {
+        let dir = tempfile::tempdir().unwrap();
+        configure_impl(dir.path(), &target(None)).unwrap();
+
+        clear_impl(dir.path()).unwrap();
+        assert!(!env_path(dir.path()).exists());
+        // Undo the clear
+        backup_store(CliTool::GeminiCli, dir.path())
+            .restore(None)
+            .unwrap();
+        assert!(fs::read_to_string(env_path(dir.path()))
+            .unwrap()
+            .contains("GEMINI_API_KEY='sk-abc'"));
+    }
+}
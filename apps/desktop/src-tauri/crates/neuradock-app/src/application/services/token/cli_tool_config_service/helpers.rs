@@ -0,0 +1,91 @@
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::CliTool;
+use crate::application::services::token::config_backup::ConfigBackupStore;
+
+/// Provider and credentials written into a tool's config
+pub(super) struct ToolTarget<'a> {
+    /// Identifier safe to use in config keys, e.g. `anyrouter`
+    pub slug: &'a str,
+    pub display_name: &'a str,
+    pub api_key: &'a str,
+    pub base_url: &'a str,
+    pub model: Option<&'a str>,
+}
+
+impl ToolTarget<'_> {
+    pub fn require_model(&self, tool: CliTool) -> Result<&str> {
+        self.model
+            .with_context(|| format!("A model is required to configure {}", tool.display_name()))
+    }
+}
+
+/// Directory holding the tool's config files
+///
+/// aider keeps `.aider.conf.yml` directly in the home directory.
+pub(super) fn get_tool_dir(tool: CliTool) -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Cannot find home directory")?;
+    Ok(match tool {
+        CliTool::GeminiCli => home.join(".gemini"),
+        // opencode uses the XDG config dir on every platform
+        CliTool::Opencode => std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home.join(".config"))
+            .join("opencode"),
+        CliTool::Aider => home,
+        CliTool::Continue => home.join(".continue"),
+    })
+}
+
+/// Backups live next to the config in `neuradock-backups`
+/// (`~/.aider/neuradock-backups` for aider, which also uses `~/.aider` for its caches)
+pub(super) fn backup_store(tool: CliTool, tool_dir: &Path) -> ConfigBackupStore {
+    let root = match tool {
+        CliTool::Aider => tool_dir.join(".aider"),
+        _ => tool_dir.to_path_buf(),
+    };
+    ConfigBackupStore::new(root.join("neuradock-backups"))
+}
+
+pub(super) fn read_optional(path: &Path) -> Result<Option<String>> {
+    if path.exists() {
+        fs::read_to_string(path)
+            .map(Some)
+            .with_context(|| format!("Failed to read {}", path.display()))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Parse a JSON config file that must hold an object, empty content counts as `{}`
+pub(super) fn parse_json_object(
+    content: Option<&str>,
+    file_name: &str,
+) -> Result<Map<String, Value>> {
+    match content {
+        Some(content) if !content.trim().is_empty() => {
+            match serde_json::from_str::<Value>(content)
+                .with_context(|| format!("Failed to parse existing {}", file_name))?
+            {
+                Value::Object(map) => Ok(map),
+                _ => anyhow::bail!("{} must be a JSON object", file_name),
+            }
+        }
+        _ => Ok(Map::new()),
+    }
+}
+
+/// Get an object member, creating it when missing
+pub(super) fn object_entry<'a>(
+    map: &'a mut Map<String, Value>,
+    key: &str,
+    file_name: &str,
+) -> Result<&'a mut Map<String, Value>> {
+    map.entry(key.to_string())
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .with_context(|| format!("`{}` in {} must be an object", key, file_name))
+}
@@ -0,0 +1,216 @@
+mod aider_config;
+mod continue_config;
+mod gemini_config;
+mod helpers;
+mod opencode_config;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use std::sync::Arc;
+
+use super::config_backup::ConfigBackup;
+use super::config_helpers::{ensure_sk_prefix, sanitize_provider_slug};
+use crate::application::services::{AuditLogService, AuditSummary};
+use helpers::ToolTarget;
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
+use neuradock_domain::token::ApiToken;
+
+const GENERIC_PROVIDER_SLUG: &str = "openai_compatible";
+const GENERIC_PROVIDER_NAME: &str = "OpenAI Compatible API";
+
+/// Coding tools configured besides Claude Code and Codex
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum CliTool {
+    /// Gemini CLI (~/.gemini/.env and settings.json)
+    GeminiCli,
+    /// opencode (~/.config/opencode/opencode.json)
+    Opencode,
+    /// aider (~/.aider.conf.yml)
+    Aider,
+    /// Continue (~/.continue/config.yaml, or the legacy config.json)
+    Continue,
+}
+
+impl CliTool {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CliTool::GeminiCli => "gemini_cli",
+            CliTool::Opencode => "opencode",
+            CliTool::Aider => "aider",
+            CliTool::Continue => "continue",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CliTool::GeminiCli => "Gemini CLI",
+            CliTool::Opencode => "opencode",
+            CliTool::Aider => "aider",
+            CliTool::Continue => "Continue",
+        }
+    }
+}
+
+pub struct CliToolConfigService {
+    audit_log: Option<Arc<AuditLogService>>,
+}
+
+impl CliToolConfigService {
+    pub fn new() -> Self {
+        Self { audit_log: None }
+    }
+
+    /// Record writes to the tools' config files in the audit log
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Configure a tool globally with a provider token
+    /// Only the NeuraDock-managed entries change, other settings are kept
+    pub fn configure_global(
+        &self,
+        tool: CliTool,
+        token: &ApiToken,
+        provider_id: &str,
+        provider_name: &str,
+        base_url: &str,
+        model: Option<&str>,
+    ) -> Result<String> {
+        let slug = sanitize_provider_slug(provider_id);
+        let api_key = ensure_sk_prefix(token.key());
+        let display_name = if provider_name.is_empty() {
+            provider_id
+        } else {
+            provider_name
+        };
+
+        let message = configure_impl(
+            tool,
+            &helpers::get_tool_dir(tool)?,
+            &ToolTarget {
+                slug: &slug,
+                display_name,
+                api_key: &api_key,
+                base_url,
+                model,
+            },
+        )?;
+        self.audit(
+            tool,
+            AuditAction::GlobalConfigWritten,
+            Some(
+                AuditSummary::new()
+                    .field("token", token.name())
+                    .field("provider_id", provider_id)
+                    .field("base_url", base_url)
+                    .field("model", model)
+                    .secret("api_key", &api_key),
+            ),
+        );
+        Ok(message)
+    }
+
+    /// Configure a tool globally with API key string (for independent keys)
+    /// The key is written as entered, independent keys may be vendor keys without `sk-`
+    pub fn configure_global_with_key(
+        &self,
+        tool: CliTool,
+        api_key: &str,
+        base_url: &str,
+        model: Option<&str>,
+    ) -> Result<String> {
+        let message = configure_impl(
+            tool,
+            &helpers::get_tool_dir(tool)?,
+            &ToolTarget {
+                slug: GENERIC_PROVIDER_SLUG,
+                display_name: GENERIC_PROVIDER_NAME,
+                api_key,
+                base_url,
+                model,
+            },
+        )?;
+        self.audit(
+            tool,
+            AuditAction::GlobalConfigWritten,
+            Some(
+                AuditSummary::new()
+                    .field("base_url", base_url)
+                    .field("model", model)
+                    .secret("api_key", api_key),
+            ),
+        );
+        Ok(message)
+    }
+
+    /// Clear a tool's global configuration
+    /// Removes the NeuraDock-managed entries, keeping other settings
+    pub fn clear_global(&self, tool: CliTool) -> Result<String> {
+        let tool_dir = helpers::get_tool_dir(tool)?;
+        let message = match tool {
+            CliTool::GeminiCli => gemini_config::clear_impl(&tool_dir),
+            CliTool::Opencode => opencode_config::clear_impl(&tool_dir),
+            CliTool::Aider => aider_config::clear_impl(&tool_dir),
+            CliTool::Continue => continue_config::clear_impl(&tool_dir),
+        }?;
+        self.audit(tool, AuditAction::GlobalConfigCleared, None);
+        Ok(message)
+    }
+
+    /// List a tool's config backups, newest first
+    pub fn list_backups(&self, tool: CliTool) -> Result<Vec<ConfigBackup>> {
+        helpers::backup_store(tool, &helpers::get_tool_dir(tool)?).list()
+    }
+
+    /// Restore a config backup, or undo the latest change when `backup_id` is `None`
+    pub fn restore_backup(&self, tool: CliTool, backup_id: Option<&str>) -> Result<String> {
+        let backup =
+            helpers::backup_store(tool, &helpers::get_tool_dir(tool)?).restore(backup_id)?;
+
+        self.audit(
+            tool,
+            AuditAction::BackupRestored,
+            Some(
+                AuditSummary::new()
+                    .field("backup_id", backup.id.as_str())
+                    .field("operation", backup.operation.as_str()),
+            ),
+        );
+
+        Ok(format!(
+            "Restored {} configuration from backup {} (before \"{}\")",
+            tool.display_name(),
+            backup.id,
+            backup.operation
+        ))
+    }
+
+    fn audit(&self, tool: CliTool, action: AuditAction, after: Option<AuditSummary>) {
+        let Some(audit_log) = &self.audit_log else {
+            return;
+        };
+        let mut entry = AuditEntry::new(AuditActor::User, action, tool.as_str())
+            .with_target_name(tool.display_name());
+        entry.after = after.map(AuditSummary::build);
+        audit_log.record_detached(entry);
+    }
+}
+
+fn configure_impl(tool: CliTool, tool_dir: &Path, target: &ToolTarget) -> Result<String> {
+    match tool {
+        CliTool::GeminiCli => gemini_config::configure_impl(tool_dir, target),
+        CliTool::Opencode => opencode_config::configure_impl(tool_dir, target),
+        CliTool::Aider => aider_config::configure_impl(tool_dir, target),
+        CliTool::Continue => continue_config::configure_impl(tool_dir, target),
+    }
+}
+
+impl Default for CliToolConfigService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,179 @@
+use anyhow::Result;
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::helpers::{backup_store, object_entry, parse_json_object, read_optional, ToolTarget};
+use super::CliTool;
+use crate::application::services::token::config_helpers::ensure_v1_base_url;
+
+/// Prefix of the provider IDs NeuraDock writes, used to find them again
+const MANAGED_PREFIX: &str = "neuradock-";
+const CONFIG_SCHEMA: &str = "https://opencode.ai/config.json";
+
+fn config_path(opencode_dir: &Path) -> PathBuf {
+    opencode_dir.join("opencode.json")
+}
+
+fn is_managed_model(value: Option<&Value>) -> bool {
+    value
+        .and_then(Value::as_str)
+        .is_some_and(|model| model.starts_with(MANAGED_PREFIX))
+}
+
+/// Add `provider.neuradock-<slug>` as an OpenAI compatible provider and select its model
+///
+/// Other providers, MCP servers, agents and so on are kept as is.
+pub(super) fn configure_impl(opencode_dir: &Path, target: &ToolTarget) -> Result<String> {
+    let config_path = config_path(opencode_dir);
+    let model = target.require_model(CliTool::Opencode)?;
+    let provider_id = format!("{}{}", MANAGED_PREFIX, target.slug);
+
+    fs::create_dir_all(opencode_dir)?;
+
+    let mut config = parse_json_object(read_optional(&config_path)?.as_deref(), "opencode.json")?;
+    config
+        .entry("$schema".to_string())
+        .or_insert_with(|| json!(CONFIG_SCHEMA));
+
+    let mut models = Map::new();
+    models.insert(model.to_string(), json!({ "name": model }));
+    object_entry(&mut config, "provider", "opencode.json")?.insert(
+        provider_id.clone(),
+        json!({
+            "npm": "@ai-sdk/openai-compatible",
+            "name": format!("{} (NeuraDock)", target.display_name),
+            "options": {
+                "baseURL": ensure_v1_base_url(target.base_url),
+                "apiKey": target.api_key,
+            },
+            "models": models,
+        }),
+    );
+    config.insert(
+        "model".to_string(),
+        json!(format!("{}/{}", provider_id, model)),
+    );
+    let config_json = serde_json::to_string_pretty(&config)?;
+
+    let backup = backup_store(CliTool::Opencode, opencode_dir)
+        .backup(&format!("configure {}", target.slug), &[&config_path])?;
+
+    fs::write(&config_path, config_json)?;
+    log::info!("opencode config updated at: {}", config_path.display());
+
+    Ok(format!(
+        "Successfully configured opencode globally (provider \"{}\"):\n  - opencode.json: {}\n  - backup: {}",
+        provider_id,
+        config_path.display(),
+        backup.id
+    ))
+}
+
+/// Remove NeuraDock providers and the model selections pointing at them
+pub(super) fn clear_impl(opencode_dir: &Path) -> Result<String> {
+    let config_path = config_path(opencode_dir);
+
+    let Some(content) = read_optional(&config_path)? else {
+        return Ok("No opencode configuration managed by NeuraDock found".to_string());
+    };
+    let mut config = parse_json_object(Some(&content), "opencode.json")?;
+
+    let mut removed: Vec<String> = Vec::new();
+    if let Some(providers) = config.get_mut("provider").and_then(Value::as_object_mut) {
+        providers.retain(|id, _| {
+            let managed = id.starts_with(MANAGED_PREFIX);
+            if managed {
+                removed.push(id.clone());
+            }
+            !managed
+        });
+        if providers.is_empty() {
+            config.remove("provider");
+        }
+    }
+    for key in ["model", "small_model"] {
+        if is_managed_model(config.get(key)) {
+            config.remove(key);
+        }
+    }
+
+    if removed.is_empty() {
+        return Ok("No opencode configuration managed by NeuraDock found".to_string());
+    }
+
+    let backup = backup_store(CliTool::Opencode, opencode_dir).backup("clear", &[&config_path])?;
+    fs::write(&config_path, serde_json::to_string_pretty(&config)?)?;
+
+    log::info!("Successfully cleared opencode configuration");
+
+    Ok(format!(
+        "Successfully cleared opencode configuration (backup {}):\n  - opencode.json: removed {} ({})",
+        backup.id,
+        removed.join(", "),
+        config_path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_config(dir: &Path) -> Value {
+        serde_json::from_str(&fs::read_to_string(config_path(dir)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_configure_and_clear_keep_user_providers() {
+        let dir = tempfile::tempdir().unwrap();
+        let user_config =
+            r#"{"provider":{"ollama":{"npm":"@ai-sdk/openai-compatible"}},"theme":"tokyonight"}"#;
+        fs::write(config_path(dir.path()), user_config).unwrap();
+
+        let target = ToolTarget {
+            slug: "anyrouter",
+            display_name: "AnyRouter",
+            api_key: "sk-abc",
+            base_url: "https://anyrouter.top",
+            model: Some("gpt-5"),
+        };
+        configure_impl(dir.path(), &target).unwrap();
+
+        let config = read_config(dir.path());
+        assert_eq!(config["model"], "neuradock-anyrouter/gpt-5");
+        let provider = &config["provider"]["neuradock-anyrouter"];
+        assert_eq!(provider["options"]["baseURL"], "https://anyrouter.top/v1");
+        assert_eq!(provider["options"]["apiKey"], "sk-abc");
+        assert_eq!(provider["models"]["gpt-5"]["name"], "gpt-5");
+        assert!(config["provider"]["ollama"].is_object());
+        assert_eq!(config["theme"], "tokyonight");
+
+        clear_impl(dir.path()).unwrap();
+        let config = read_config(dir.path());
+        assert!(config.get("model").is_none());
+        assert!(config["provider"].get("neuradock-anyrouter").is_none());
+        assert!(config["provider"]["ollama"].is_object());
+        assert!(clear_impl(dir.path()).unwrap().starts_with("No opencode"));
+    }
+
+    #[test]
+    fn test_configure_requires_model_and_valid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut target = ToolTarget {
+            slug: "openai_compatible",
+            display_name: "OpenAI Compatible API",
+            api_key: "sk-abc",
+            base_url: "https://api.example.com/v1",
+            model: None,
+        };
+        assert!(configure_impl(dir.path(), &target).is_err());
+
+        target.model = Some("gpt-4o");
+        fs::write(config_path(dir.path()), "{ // comment\n}").unwrap();
+        assert!(configure_impl(dir.path(), &target).is_err());
+        assert_eq!(
+            fs::read_to_string(config_path(dir.path())).unwrap(),
+            "{ // comment\n}"
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use anyhow::{Context, Result};
+use toml_edit::{value, DocumentMut, Item, Table};
+
+use crate::application::services::token::ConfiguredModels;
+
+/// Comment placed above every section NeuraDock writes, used to find them again
+const MANAGED_MARKER: &str = "# Managed by NeuraDock";
+
+pub(super) struct ProviderSection<'a> {
+    pub slug: &'a str,
+    pub display_name: &'a str,
+    pub base_url: &'a str,
+    pub model: &'a str,
+}
+
+fn parse(existing: &str) -> Result<DocumentMut> {
+    existing
+        .parse::<DocumentMut>()
+        .context("Failed to parse existing config.toml, fix or restore it before configuring")
+}
+
+fn is_managed(table: &Table) -> bool {
+    table
+        .decor()
+        .prefix()
+        .and_then(|prefix| prefix.as_str())
+        .is_some_and(|prefix| prefix.contains(MANAGED_MARKER))
+}
+
+fn managed_table() -> Table {
+    let mut table = Table::new();
+    table
+        .decor_mut()
+        .set_prefix(format!("\n{}\n", MANAGED_MARKER));
+    table
+}
+
+/// Get a top-level table such as `[model_providers]`, creating it implicitly
+fn parent_table<'a>(doc: &'a mut DocumentMut, key: &str) -> Result<&'a mut Table> {
+    doc.entry(key)
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        })
+        .as_table_mut()
+        .with_context(|| format!("`{}` in config.toml must be a table", key))
+}
+
+/// Write `model_providers.<slug>` and `profiles.<slug>` and select the profile
+///
+/// Everything else in the document (MCP servers, sandbox settings, other
+/// providers, comments) is kept as is.
+pub(super) fn merge_provider_config(existing: &str, section: &ProviderSection) -> Result<String> {
+    let mut doc = parse(existing)?;
+
+    let mut provider = managed_table();
+    provider["name"] = value(section.display_name);
+    provider["base_url"] = value(section.base_url);
+    provider["wire_api"] = value("responses");
+    parent_table(&mut doc, "model_providers")?.insert(section.slug, Item::Table(provider));
+
+    let mut profile = managed_table();
+    profile["model"] = value(section.model);
+    profile["model_provider"] = value(section.slug);
+    profile["preferred_auth_method"] = value("apikey");
+    parent_table(&mut doc, "profiles")?.insert(section.slug, Item::Table(profile));
+
+    // The active profile overrides top-level model settings
+    doc["profile"] = value(section.slug);
+
+    Ok(doc.to_string())
+}
+
+/// Remove every NeuraDock-managed provider and profile section
+///
+/// Returns the new content and the removed slugs.
+pub(super) fn remove_managed_sections(existing: &str) -> Result<(String, Vec<String>)> {
+    let mut doc = parse(existing)?;
+    let mut removed = Vec::new();
+
+    for parent in ["model_providers", "profiles"] {
+        let Some(table) = doc.get_mut(parent).and_then(Item::as_table_mut) else {
+            continue;
+        };
+        let managed: Vec<String> = table
+            .iter()
+            .filter(|(_, item)| item.as_table().is_some_and(is_managed))
+            .map(|(key, _)| key.to_string())
+            .collect();
+        for slug in managed {
+            table.remove(&slug);
+            if !removed.contains(&slug) {
+                removed.push(slug);
+            }
+        }
+        if table.is_empty() {
+            doc.remove(parent);
+        }
+    }
+
+    let active_removed = doc
+        .get("profile")
+        .and_then(Item::as_str)
+        .is_some_and(|profile| removed.iter().any(|slug| slug == profile));
+    if active_removed {
+        doc.remove("profile");
+    }
+
+    Ok((doc.to_string(), removed))
+}
+
+/// Model and provider base URL in effect, the active profile overriding top-level settings
+pub(super) fn configured_models(existing: &str) -> Result<ConfiguredModels> {
+    let doc = parse(existing)?;
+    let profile = doc
+        .get("profile")
+        .and_then(Item::as_str)
+        .and_then(|name| doc.get("profiles")?.get(name));
+    let setting = |key: &str| {
+        profile
+            .and_then(|profile| profile.get(key))
+            .and_then(Item::as_str)
+            .or_else(|| doc.get(key).and_then(Item::as_str))
+    };
+
+    let mut configured = ConfiguredModels {
+        base_url: setting("model_provider")
+            .and_then(|slug| doc.get("model_providers")?.get(slug)?.get("base_url"))
+            .and_then(Item::as_str)
+            .map(str::to_string),
+        models: Vec::new(),
+    };
+    if let Some(model) = setting("model") {
+        configured.push_model(model);
+    }
+    Ok(configured)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER_CONFIG: &str = r#"# my settings
+model = "o3"
+sandbox_mode = "workspace-write"
+
+[mcp_servers.docs]
+command = "npx"
+args = ["-y", "docs-mcp"]
+
+[model_providers.azure]
+name = "Azure"
+base_url = "https://example.openai.azure.com/openai"
+"#;
+
+    fn section() -> ProviderSection<'static> {
+        ProviderSection {
+            slug: "anyrouter",
+            display_name: "AnyRouter",
+            base_url: "https://anyrouter.top/v1",
+            model: "gpt-5",
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_user_sections() {
+        let merged = merge_provider_config(USER_CONFIG, &section()).unwrap();
+        let doc = merged.parse::<DocumentMut>().unwrap();
+
+        assert!(merged.starts_with("# my settings\n"));
+        assert_eq!(doc["model"].as_str(), Some("o3"));
+        assert_eq!(doc["sandbox_mode"].as_str(), Some("workspace-write"));
+        assert_eq!(doc["mcp_servers"]["docs"]["command"].as_str(), Some("npx"));
+        assert_eq!(
+            doc["model_providers"]["azure"]["name"].as_str(),
+            Some("Azure")
+        );
+
+        assert_eq!(doc["profile"].as_str(), Some("anyrouter"));
+        assert_eq!(
+            doc["model_providers"]["anyrouter"]["base_url"].as_str(),
+            Some("https://anyrouter.top/v1")
+        );
+        assert_eq!(
+            doc["profiles"]["anyrouter"]["model"].as_str(),
+            Some("gpt-5")
+        );
+        assert_eq!(
+            doc["profiles"]["anyrouter"]["model_provider"].as_str(),
+            Some("anyrouter")
+        );
+    }
+
+    #[test]
+    fn test_merge_is_idempotent_and_updates_in_place() {
+        let first = merge_provider_config("", &section()).unwrap();
+        let mut updated = section();
+        updated.model = "gpt-5-codex";
+        let second = merge_provider_config(&first, &updated).unwrap();
+
+        assert_eq!(second.matches(MANAGED_MARKER).count(), 2);
+        assert!(second.contains("model = \"gpt-5-codex\""));
+        assert!(!second.contains("model = \"gpt-5\"\n"));
+    }
+
+    #[test]
+    fn test_remove_managed_sections_only() {
+        let merged = merge_provider_config(USER_CONFIG, &section()).unwrap();
+        let (cleared, removed) = remove_managed_sections(&merged).unwrap();
+        let doc = cleared.parse::<DocumentMut>().unwrap();
+
+        assert_eq!(removed, vec!["anyrouter".to_string()]);
+        assert!(doc.get("profile").is_none());
+        assert!(doc.get("profiles").is_none());
+        assert!(doc["model_providers"].get("anyrouter").is_none());
+        assert_eq!(
+            doc["model_providers"]["azure"]["name"].as_str(),
+            Some("Azure")
+        );
+        assert_eq!(doc["mcp_servers"]["docs"]["command"].as_str(), Some("npx"));
+    }
+
+    #[test]
+    fn test_configured_models_prefers_active_profile() {
+        let merged = merge_provider_config(USER_CONFIG, &section()).unwrap();
+        let configured = configured_models(&merged).unwrap();
+        assert_eq!(configured.models, vec!["gpt-5".to_string()]);
+        assert_eq!(
+            configured.base_url.as_deref(),
+            Some("https://anyrouter.top/v1")
+        );
+
+        let top_level = configured_models(USER_CONFIG).unwrap();
+        assert_eq!(top_level.models, vec!["o3".to_string()]);
+    }
+
+    #[test]
+    fn test_invalid_toml_is_rejected() {
+        assert!(merge_provider_config("model = ", &section()).is_err());
+        assert!(remove_managed_sections("[broken").is_err());
+    }
+}
@@ -1,116 +1,315 @@
-use anyhow::Result;
-use serde_json::json;
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
 use std::fs;
+use std::path::Path;
 
-use super::helpers::{
-    ensure_sk_prefix, generate_generic_config, generate_provider_config, get_codex_auth_path,
-    get_codex_config_path, get_codex_dir, sanitize_provider_slug,
+use super::config_toml::{
+    configured_models, merge_provider_config, remove_managed_sections, ProviderSection,
 };
-use neuradock_domain::token::ApiToken;
+use super::helpers::{codex_auth_path, codex_backup_store, codex_config_path};
+use crate::application::services::token::config_backup::ConfigBackup;
+use crate::application::services::token::config_helpers::{
+    ensure_sk_prefix, ensure_v1_base_url, sanitize_provider_slug,
+};
+use crate::application::services::token::ConfiguredModels;
+
+const GENERIC_PROVIDER_SLUG: &str = "openai_compatible";
+const GENERIC_PROVIDER_NAME: &str = "OpenAI Compatible API";
+
+fn read_optional(path: &Path) -> Result<Option<String>> {
+    if path.exists() {
+        fs::read_to_string(path)
+            .map(Some)
+            .with_context(|| format!("Failed to read {}", path.display()))
+    } else {
+        Ok(None)
+    }
+}
+
+fn parse_auth_json(content: &str) -> Result<serde_json::Map<String, Value>> {
+    match serde_json::from_str::<Value>(content).context("Failed to parse existing auth.json")? {
+        Value::Object(map) => Ok(map),
+        _ => anyhow::bail!("auth.json must be a JSON object"),
+    }
+}
+
+/// Merge the managed provider into config.toml and the key into auth.json
+fn write_provider(
+    codex_dir: &Path,
+    operation: &str,
+    section: &ProviderSection,
+    api_key: &str,
+) -> Result<String> {
+    let config_path = codex_config_path(codex_dir);
+    let auth_path = codex_auth_path(codex_dir);
+
+    // Ensure directory exists
+    fs::create_dir_all(codex_dir)?;
+
+    // Build both files before touching anything so a parse error leaves them intact
+    let existing_config = read_optional(&config_path)?.unwrap_or_default();
+    let config_content = merge_provider_config(&existing_config, section)?;
+
+    let mut auth = match read_optional(&auth_path)? {
+        Some(content) if !content.trim().is_empty() => parse_auth_json(&content)?,
+        _ => serde_json::Map::new(),
+    };
+    auth.insert(
+        "OPENAI_API_KEY".to_string(),
+        json!(ensure_sk_prefix(api_key)),
+    );
+    let auth_json = serde_json::to_string_pretty(&auth)?;
+
+    let backup = codex_backup_store(codex_dir).backup(operation, &[&config_path, &auth_path])?;
+
+    fs::write(&config_path, &config_content)?;
+    log::info!("Codex config.toml updated at: {}", config_path.display());
+    fs::write(&auth_path, auth_json)?;
+    log::info!("Codex auth.json updated at: {}", auth_path.display());
+
+    Ok(format!(
+        "Successfully configured Codex globally (profile \"{}\"):\n  - config.toml: {}\n  - auth.json: {}\n  - backup: {}",
+        section.slug,
+        config_path.display(),
+        auth_path.display(),
+        backup.id
+    ))
+}
 
 pub(super) fn configure_global_impl(
-    token: &ApiToken,
+    codex_dir: &Path,
+    api_key: &str,
     provider_id: &str,
     provider_name: &str,
     base_url: &str,
     model: Option<&str>,
 ) -> Result<String> {
-    let codex_dir = get_codex_dir()?;
-    let config_path = get_codex_config_path()?;
-    let auth_path = get_codex_auth_path()?;
-
-    // Ensure directory exists
-    fs::create_dir_all(&codex_dir)?;
-
-    // Generate config.toml based on provider metadata
     let provider_slug = sanitize_provider_slug(provider_id);
     let display_name = if provider_name.is_empty() {
         provider_id
     } else {
         provider_name
     };
-    let config_content = generate_provider_config(&provider_slug, display_name, base_url, model);
-
-    // Write config.toml
-    fs::write(&config_path, &config_content)?;
-    log::info!("Codex config.toml written to: {}", config_path.display());
-
-    // Create auth.json with API key (ensure sk- prefix)
-    let api_key = ensure_sk_prefix(token.key());
-    let auth_content = json!({
-        "OPENAI_API_KEY": api_key
-    });
+    let base_url_v1 = ensure_v1_base_url(base_url);
 
-    let auth_json = serde_json::to_string_pretty(&auth_content)?;
-    fs::write(&auth_path, auth_json)?;
-    log::info!("Codex auth.json written to: {}", auth_path.display());
-
-    Ok(format!(
-        "Successfully configured Codex globally:\n  - config.toml: {}\n  - auth.json: {}",
-        config_path.display(),
-        auth_path.display()
-    ))
+    write_provider(
+        codex_dir,
+        &format!("configure {}", provider_slug),
+        &ProviderSection {
+            slug: &provider_slug,
+            display_name,
+            base_url: &base_url_v1,
+            model: model.unwrap_or("gpt-5"),
+        },
+        api_key,
+    )
 }
 
 pub(super) fn configure_global_with_key_impl(
+    codex_dir: &Path,
     api_key: &str,
     base_url: &str,
     model: Option<&str>,
 ) -> Result<String> {
-    let codex_dir = get_codex_dir()?;
-    let config_path = get_codex_config_path()?;
-    let auth_path = get_codex_auth_path()?;
+    let base_url_v1 = ensure_v1_base_url(base_url);
 
-    // Ensure directory exists
-    fs::create_dir_all(&codex_dir)?;
+    write_provider(
+        codex_dir,
+        &format!("configure {}", GENERIC_PROVIDER_SLUG),
+        &ProviderSection {
+            slug: GENERIC_PROVIDER_SLUG,
+            display_name: GENERIC_PROVIDER_NAME,
+            base_url: &base_url_v1,
+            model: model.unwrap_or("gpt-4o"),
+        },
+        api_key,
+    )
+}
 
-    // Generate generic OpenAI-compatible config
-    let config_content = generate_generic_config(base_url, model);
+/// Remove NeuraDock-managed sections and the API key, keeping everything else
+pub(super) fn clear_global_impl(codex_dir: &Path) -> Result<String> {
+    let config_path = codex_config_path(codex_dir);
+    let auth_path = codex_auth_path(codex_dir);
 
-    // Write config.toml
-    fs::write(&config_path, &config_content)?;
-    log::info!("Codex config.toml written to: {}", config_path.display());
+    let config_update = match read_optional(&config_path)? {
+        Some(content) => {
+            let (cleared, removed) = remove_managed_sections(&content)?;
+            (!removed.is_empty()).then_some((cleared, removed))
+        }
+        None => None,
+    };
+    let auth_update = match read_optional(&auth_path)? {
+        Some(content) if !content.trim().is_empty() => {
+            let mut auth = parse_auth_json(&content)?;
+            auth.remove("OPENAI_API_KEY").map(|_| auth)
+        }
+        _ => None,
+    };
 
-    // Create auth.json with API key (ensure sk- prefix)
-    let api_key = ensure_sk_prefix(api_key);
-    let auth_content = json!({
-        "OPENAI_API_KEY": api_key
-    });
+    if config_update.is_none() && auth_update.is_none() {
+        return Ok("No Codex configuration managed by NeuraDock found".to_string());
+    }
 
-    let auth_json = serde_json::to_string_pretty(&auth_content)?;
-    fs::write(&auth_path, auth_json)?;
-    log::info!("Codex auth.json written to: {}", auth_path.display());
+    let backup = codex_backup_store(codex_dir).backup("clear", &[&config_path, &auth_path])?;
+    let mut changes = vec![];
+
+    if let Some((content, removed)) = config_update {
+        fs::write(&config_path, content)?;
+        changes.push(format!(
+            "config.toml: removed {} ({})",
+            removed.join(", "),
+            config_path.display()
+        ));
+    }
+
+    if let Some(auth) = auth_update {
+        if auth.is_empty() {
+            fs::remove_file(&auth_path)?;
+        } else {
+            fs::write(&auth_path, serde_json::to_string_pretty(&auth)?)?;
+        }
+        changes.push(format!(
+            "auth.json: removed API key ({})",
+            auth_path.display()
+        ));
+    }
+
+    log::info!("Successfully cleared Codex configuration");
 
     Ok(format!(
-        "Successfully configured Codex globally:\n  - config.toml: {}\n  - auth.json: {}",
-        config_path.display(),
-        auth_path.display()
+        "Successfully cleared Codex configuration (backup {}):\n  - {}",
+        backup.id,
+        changes.join("\n  - ")
     ))
 }
 
-pub(super) fn clear_global_impl() -> Result<String> {
-    let config_path = get_codex_config_path()?;
-    let auth_path = get_codex_auth_path()?;
-    let mut removed = vec![];
+/// `OPENAI_API_KEY` currently in auth.json
+pub(super) fn configured_api_key_impl(codex_dir: &Path) -> Result<Option<String>> {
+    let auth = match read_optional(&codex_auth_path(codex_dir))? {
+        Some(content) if !content.trim().is_empty() => parse_auth_json(&content)?,
+        _ => return Ok(None),
+    };
+    Ok(auth
+        .get("OPENAI_API_KEY")
+        .and_then(Value::as_str)
+        .filter(|key| !key.is_empty())
+        .map(str::to_string))
+}
 
-    if config_path.exists() {
-        fs::remove_file(&config_path)?;
-        removed.push(format!("config.toml: {}", config_path.display()));
-    }
+/// Model and base URL in config.toml, `None` when the file is missing
+pub(super) fn configured_models_impl(codex_dir: &Path) -> Result<Option<ConfiguredModels>> {
+    read_optional(&codex_config_path(codex_dir))?
+        .map(|content| configured_models(&content))
+        .transpose()
+}
 
-    if auth_path.exists() {
-        fs::remove_file(&auth_path)?;
-        removed.push(format!("auth.json: {}", auth_path.display()));
+pub(super) fn list_backups_impl(codex_dir: &Path) -> Result<Vec<ConfigBackup>> {
+    codex_backup_store(codex_dir).list()
+}
+
+pub(super) fn restore_backup_impl(codex_dir: &Path, backup_id: Option<&str>) -> Result<String> {
+    let backup = codex_backup_store(codex_dir).restore(backup_id)?;
+
+    Ok(format!(
+        "Restored Codex configuration from backup {} (before \"{}\")",
+        backup.id, backup.operation
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configure_clear_and_undo_keep_user_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let codex_dir = dir.path();
+        let user_config = "[mcp_servers.docs]\ncommand = \"npx\"\n";
+        let user_auth = r#"{"auth_mode":"chatgpt","tokens":{"access_token":"at"}}"#;
+        fs::write(codex_dir.join("config.toml"), user_config).unwrap();
+        fs::write(codex_dir.join("auth.json"), user_auth).unwrap();
+
+        configure_global_impl(
+            codex_dir,
+            "abc",
+            "anyrouter",
+            "AnyRouter",
+            "https://anyrouter.top",
+            None,
+        )
+        .unwrap();
+
+        let config = fs::read_to_string(codex_dir.join("config.toml")).unwrap();
+        assert!(config.contains("[mcp_servers.docs]"));
+        assert!(config.contains("[model_providers.anyrouter]"));
+        let auth: Value =
+            serde_json::from_str(&fs::read_to_string(codex_dir.join("auth.json")).unwrap())
+                .unwrap();
+        assert_eq!(auth["OPENAI_API_KEY"], "sk-abc");
+        assert_eq!(auth["tokens"]["access_token"], "at");
+
+        clear_global_impl(codex_dir).unwrap();
+        let config = fs::read_to_string(codex_dir.join("config.toml")).unwrap();
+        assert!(config.contains("[mcp_servers.docs]"));
+        assert!(!config.contains("anyrouter"));
+        let auth: Value =
+            serde_json::from_str(&fs::read_to_string(codex_dir.join("auth.json")).unwrap())
+                .unwrap();
+        assert!(auth.get("OPENAI_API_KEY").is_none());
+
+        // Undo the clear, then undo the configure
+        assert_eq!(list_backups_impl(codex_dir).unwrap().len(), 2);
+        restore_backup_impl(codex_dir, None).unwrap();
+        assert!(fs::read_to_string(codex_dir.join("config.toml"))
+            .unwrap()
+            .contains("[model_providers.anyrouter]"));
+        restore_backup_impl(codex_dir, None).unwrap();
+        assert_eq!(
+            fs::read_to_string(codex_dir.join("config.toml")).unwrap(),
+            user_config
+        );
+        assert_eq!(
+            fs::read_to_string(codex_dir.join("auth.json")).unwrap(),
+            user_auth
+        );
     }
 
-    if removed.is_empty() {
-        return Ok("No Codex configuration files found".to_string());
+    #[test]
+    fn test_configured_api_key() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(configured_api_key_impl(dir.path()).unwrap(), None);
+
+        // ChatGPT logins store a null key
+        fs::write(
+            dir.path().join("auth.json"),
+            r#"{"OPENAI_API_KEY":null,"tokens":{}}"#,
+        )
+        .unwrap();
+        assert_eq!(configured_api_key_impl(dir.path()).unwrap(), None);
+
+        configure_global_with_key_impl(dir.path(), "abc", "https://api.example.com", None).unwrap();
+        assert_eq!(
+            configured_api_key_impl(dir.path()).unwrap().as_deref(),
+            Some("sk-abc")
+        );
     }
 
-    log::info!("Successfully cleared Codex configuration");
+    #[test]
+    fn test_configure_refuses_invalid_existing_config() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("config.toml"), "model = ").unwrap();
 
-    Ok(format!(
-        "Successfully cleared Codex configuration:\n  - {}",
-        removed.join("\n  - ")
-    ))
+        assert!(configure_global_with_key_impl(
+            dir.path(),
+            "sk-abc",
+            "https://api.example.com",
+            None
+        )
+        .is_err());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("config.toml")).unwrap(),
+            "model = "
+        );
+        assert!(list_backups_impl(dir.path()).unwrap().is_empty());
+    }
 }
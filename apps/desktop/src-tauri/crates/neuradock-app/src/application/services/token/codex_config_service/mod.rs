@@ -1,19 +1,40 @@
+mod config_toml;
 mod global_config;
 mod helpers;
 mod temp_commands;
 
 use anyhow::Result;
+use std::sync::Arc;
 
+use super::config_backup::ConfigBackup;
+use super::shell_env::ShellDialect;
+use super::{ConfiguredModels, TokenModels};
+use crate::application::services::{AuditLogService, AuditSummary};
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
 use neuradock_domain::token::ApiToken;
 
-pub struct CodexConfigService;
+const AUDIT_TARGET: &str = "codex";
+
+pub struct CodexConfigService {
+    audit_log: Option<Arc<AuditLogService>>,
+}
 
 impl CodexConfigService {
     pub fn new() -> Self {
-        Self
+        Self { audit_log: None }
+    }
+
+    /// Record writes to ~/.codex in the audit log
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLogService>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
     }
 
-    /// Configure Codex globally by writing to ~/.codex/config.toml and ~/.codex/auth.json
+    /// Configure Codex globally by merging into ~/.codex/config.toml and ~/.codex/auth.json
+    /// Only the `model_providers.<slug>` / `profiles.<slug>` sections and the API key change
+    ///
+    /// `catalog` is the provider's cached model list. A model outside the token's allow list
+    /// fails with `ModelNotAvailable`, one missing from the catalog only adds a warning.
     pub fn configure_global(
         &self,
         token: &ApiToken,
@@ -21,8 +42,38 @@ impl CodexConfigService {
         provider_name: &str,
         base_url: &str,
         model: Option<&str>,
+        catalog: &[String],
     ) -> Result<String> {
-        global_config::configure_global_impl(token, provider_id, provider_name, base_url, model)
+        let warning = match (TokenModels::resolve(token, catalog), model) {
+            (Some(available), Some(model)) => available.vet(model)?,
+            _ => None,
+        };
+
+        let before = self.key_summary();
+        let mut message = global_config::configure_global_impl(
+            &helpers::get_codex_dir()?,
+            token.key(),
+            provider_id,
+            provider_name,
+            base_url,
+            model,
+        )?;
+        self.audit(
+            AuditAction::GlobalConfigWritten,
+            before,
+            Some(
+                AuditSummary::new()
+                    .field("token", token.name())
+                    .field("provider_id", provider_id)
+                    .field("base_url", base_url)
+                    .field("model", model)
+                    .secret("api_key", token.key()),
+            ),
+        );
+        if let Some(warning) = warning {
+            message.push_str(&format!("\nWarning: {}", warning));
+        }
+        Ok(message)
     }
 
     /// Configure Codex globally with API key string (for independent keys)
@@ -32,18 +83,67 @@ impl CodexConfigService {
         base_url: &str,
         model: Option<&str>,
     ) -> Result<String> {
-        global_config::configure_global_with_key_impl(api_key, base_url, model)
+        let before = self.key_summary();
+        let message = global_config::configure_global_with_key_impl(
+            &helpers::get_codex_dir()?,
+            api_key,
+            base_url,
+            model,
+        )?;
+        self.audit(
+            AuditAction::GlobalConfigWritten,
+            before,
+            Some(
+                AuditSummary::new()
+                    .field("base_url", base_url)
+                    .field("model", model)
+                    .secret("api_key", api_key),
+            ),
+        );
+        Ok(message)
     }
 
     /// Clear Codex global configuration
-    /// Removes both config.toml and auth.json files
+    /// Removes the NeuraDock-managed sections and API key, keeping other settings
     pub fn clear_global(&self) -> Result<String> {
-        global_config::clear_global_impl()
+        let before = self.key_summary();
+        let message = global_config::clear_global_impl(&helpers::get_codex_dir()?)?;
+        self.audit(AuditAction::GlobalConfigCleared, before, None);
+        Ok(message)
+    }
+
+    /// API key currently configured in auth.json
+    pub fn configured_api_key(&self) -> Result<Option<String>> {
+        global_config::configured_api_key_impl(&helpers::get_codex_dir()?)
+    }
+
+    /// Model and base URL configured in config.toml, `None` when it does not exist
+    pub fn configured_models(&self) -> Result<Option<ConfiguredModels>> {
+        global_config::configured_models_impl(&helpers::get_codex_dir()?)
+    }
+
+    /// List config backups, newest first
+    pub fn list_backups(&self) -> Result<Vec<ConfigBackup>> {
+        global_config::list_backups_impl(&helpers::get_codex_dir()?)
+    }
+
+    /// Restore a config backup, or undo the latest change when `backup_id` is `None`
+    pub fn restore_backup(&self, backup_id: Option<&str>) -> Result<String> {
+        let before = self.key_summary();
+        let message = global_config::restore_backup_impl(&helpers::get_codex_dir()?, backup_id)?;
+        let after = self
+            .key_summary()
+            .unwrap_or_default()
+            .field("backup_id", backup_id.unwrap_or("latest"));
+        self.audit(AuditAction::BackupRestored, before, Some(after));
+        Ok(message)
     }
 
     /// Generate temporary export commands for current shell session
-    /// Note: This feature is temporarily unavailable
-    #[allow(dead_code)]
+    ///
+    /// `model` is checked against `catalog` as in `configure_global`, a warning is
+    /// appended as a shell comment.
+    #[allow(clippy::too_many_arguments)]
     pub fn generate_temp_commands(
         &self,
         token: &ApiToken,
@@ -51,25 +151,62 @@ impl CodexConfigService {
         provider_name: &str,
         base_url: &str,
         model: Option<&str>,
+        catalog: &[String],
+        shell: ShellDialect,
     ) -> Result<String> {
-        temp_commands::generate_temp_commands_impl(
+        let warning = match (TokenModels::resolve(token, catalog), model) {
+            (Some(available), Some(model)) => available.vet(model)?,
+            _ => None,
+        };
+
+        let mut commands = temp_commands::generate_temp_commands_impl(
             token,
             provider_id,
             provider_name,
             base_url,
             model,
-        )
+            shell,
+        )?;
+        if let Some(warning) = warning {
+            commands.push_str(&format!("\n# Warning: {}", warning));
+        }
+        Ok(commands)
     }
 
     /// Generate temporary export commands with API key string (for independent keys)
-    #[allow(dead_code)]
     pub fn generate_temp_commands_with_key(
         &self,
         api_key: &str,
         base_url: &str,
         model: Option<&str>,
+        shell: ShellDialect,
     ) -> Result<String> {
-        temp_commands::generate_temp_commands_with_key_impl(api_key, base_url, model)
+        temp_commands::generate_temp_commands_with_key_impl(api_key, base_url, model, shell)
+    }
+}
+
+impl CodexConfigService {
+    /// Masked API key currently in auth.json
+    fn key_summary(&self) -> Option<AuditSummary> {
+        self.audit_log.as_ref()?;
+        let key = self.configured_api_key().ok().flatten()?;
+        Some(AuditSummary::new().secret("api_key", &key))
+    }
+
+    fn audit(
+        &self,
+        action: AuditAction,
+        before: Option<AuditSummary>,
+        after: Option<AuditSummary>,
+    ) {
+        let Some(audit_log) = &self.audit_log else {
+            return;
+        };
+        let mut entry =
+            AuditEntry::new(AuditActor::User, action, AUDIT_TARGET).with_target_name("Codex");
+        entry.before = before.map(AuditSummary::build);
+        entry.after = after.map(AuditSummary::build);
+        audit_log.record_detached(entry);
     }
 }
 
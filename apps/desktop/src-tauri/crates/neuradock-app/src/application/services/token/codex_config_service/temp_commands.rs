@@ -1,30 +1,96 @@
 use anyhow::Result;
 
+use crate::application::services::token::config_helpers::{ensure_sk_prefix, ensure_v1_base_url};
+use crate::application::services::token::shell_env::{render_env, ShellDialect};
 use neuradock_domain::token::ApiToken;
 
+/// Codex has no model env var, the model is passed on the command line instead
+fn model_note(model: Option<&str>) -> Option<String> {
+    model.map(|m| format!("Start Codex with: codex -m {}", m))
+}
+
 /// Generate temporary export commands for current shell session
-/// Note: This feature is temporarily unavailable
-#[allow(dead_code)]
+///
+/// Codex reads the key from `OPENAI_API_KEY` and the endpoint of its built-in
+/// provider from `OPENAI_BASE_URL`, so no config.toml change is needed.
 pub(super) fn generate_temp_commands_impl(
-    _token: &ApiToken,
-    _provider_id: &str,
-    _provider_name: &str,
-    _base_url: &str,
-    _model: Option<&str>,
+    token: &ApiToken,
+    provider_id: &str,
+    provider_name: &str,
+    base_url: &str,
+    model: Option<&str>,
+    shell: ShellDialect,
 ) -> Result<String> {
-    Err(anyhow::anyhow!(
-        "Temporary configuration is currently unavailable. Please use global configuration instead."
-    ))
+    let display_name = if provider_name.is_empty() {
+        provider_id
+    } else {
+        provider_name
+    };
+    render(
+        &format!("Codex via {}", display_name),
+        token.key(),
+        base_url,
+        model,
+        shell,
+    )
 }
 
 /// Generate temporary export commands with API key string (for independent keys)
-#[allow(dead_code)]
 pub(super) fn generate_temp_commands_with_key_impl(
-    _api_key: &str,
-    _base_url: &str,
-    _model: Option<&str>,
+    api_key: &str,
+    base_url: &str,
+    model: Option<&str>,
+    shell: ShellDialect,
+) -> Result<String> {
+    render("Codex", api_key, base_url, model, shell)
+}
+
+fn render(
+    title: &str,
+    api_key: &str,
+    base_url: &str,
+    model: Option<&str>,
+    shell: ShellDialect,
 ) -> Result<String> {
-    Err(anyhow::anyhow!(
-        "Temporary configuration is currently unavailable. Please use global configuration instead."
-    ))
+    let vars = [
+        ("OPENAI_API_KEY", ensure_sk_prefix(api_key)),
+        ("OPENAI_BASE_URL", ensure_v1_base_url(base_url)),
+    ];
+    let model_note = model_note(model);
+    let mut notes = vec![title];
+    notes.extend(model_note.as_deref());
+
+    Ok(render_env(shell, &notes, &vars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_commands_per_shell() {
+        let fish = generate_temp_commands_with_key_impl(
+            "abc",
+            "https://relay.example.com",
+            Some("gpt-5"),
+            ShellDialect::Fish,
+        )
+        .unwrap();
+        assert_eq!(
+            fish,
+            "# Codex\n# Start Codex with: codex -m gpt-5\n\
+             set -gx OPENAI_API_KEY 'sk-abc'\n\
+             set -gx OPENAI_BASE_URL 'https://relay.example.com/v1'"
+        );
+
+        let envrc = generate_temp_commands_with_key_impl(
+            "sk-abc",
+            "https://relay.example.com/v1",
+            None,
+            ShellDialect::Direnv,
+        )
+        .unwrap();
+        assert!(envrc.starts_with("# .envrc"));
+        assert!(envrc.ends_with("export OPENAI_BASE_URL='https://relay.example.com/v1'"));
+    }
 }
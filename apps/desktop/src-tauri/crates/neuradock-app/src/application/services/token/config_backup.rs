@@ -0,0 +1,289 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MANIFEST_FILE: &str = "manifest.json";
+const DEFAULT_MAX_BACKUPS: usize = 20;
+/// Operation of the backup taken right before a restore overwrites the files
+pub const RESTORE_OPERATION: &str = "restore";
+
+/// One file captured by a backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackedUpFile {
+    pub path: PathBuf,
+    /// `false` when the file did not exist yet, restoring then deletes it
+    pub existed: bool,
+    /// Name of the copy inside the backup directory
+    stored_as: Option<String>,
+}
+
+/// Snapshot of the config files touched by one operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigBackup {
+    pub id: String,
+    pub created_at: DateTime<Utc>,
+    pub operation: String,
+    pub files: Vec<BackedUpFile>,
+    /// When the backup was last restored, undo skips restored backups
+    #[serde(default)]
+    pub restored_at: Option<DateTime<Utc>>,
+}
+
+impl ConfigBackup {
+    fn sort_key(&self) -> (DateTime<Utc>, &str) {
+        (self.created_at, self.id.as_str())
+    }
+}
+
+/// Timestamped backups of CLI config files written by NeuraDock
+///
+/// Each backup is a directory named after its timestamp holding copies of
+/// the files and a manifest. Restoring never deletes backups: the current files
+/// are backed up first, so a restore can itself be restored.
+pub(crate) struct ConfigBackupStore {
+    root: PathBuf,
+    max_backups: usize,
+}
+
+impl ConfigBackupStore {
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            max_backups: DEFAULT_MAX_BACKUPS,
+        }
+    }
+
+    /// Copy the current content of `files` before they are modified
+    pub fn backup(&self, operation: &str, files: &[&Path]) -> Result<ConfigBackup> {
+        let created_at = Utc::now();
+        let base_id = created_at.format("%Y%m%dT%H%M%S%3fZ").to_string();
+        let mut id = base_id.clone();
+        let mut suffix = 1;
+        while self.root.join(&id).exists() {
+            id = format!("{}-{}", base_id, suffix);
+            suffix += 1;
+        }
+
+        let dir = self.root.join(&id);
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create backup directory {}", dir.display()))?;
+
+        let mut backed_up = Vec::with_capacity(files.len());
+        for (index, path) in files.iter().enumerate() {
+            let stored_as = if path.is_file() {
+                let file_name = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "file".to_string());
+                let stored_as = format!("{}-{}", index, file_name);
+                fs::copy(path, dir.join(&stored_as))
+                    .with_context(|| format!("Failed to back up {}", path.display()))?;
+                Some(stored_as)
+            } else {
+                None
+            };
+
+            backed_up.push(BackedUpFile {
+                path: path.to_path_buf(),
+                existed: stored_as.is_some(),
+                stored_as,
+            });
+        }
+
+        let backup = ConfigBackup {
+            id,
+            created_at,
+            operation: operation.to_string(),
+            files: backed_up,
+            restored_at: None,
+        };
+        self.write_manifest(&backup)?;
+        log::info!("Config backup {} created for {}", backup.id, operation);
+
+        self.prune()?;
+        Ok(backup)
+    }
+
+    /// All backups, newest first
+    pub fn list(&self) -> Result<Vec<ConfigBackup>> {
+        if !self.root.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut backups = Vec::new();
+        for entry in fs::read_dir(&self.root)? {
+            let manifest_path = entry?.path().join(MANIFEST_FILE);
+            let Ok(content) = fs::read_to_string(&manifest_path) else {
+                continue;
+            };
+            match serde_json::from_str::<ConfigBackup>(&content) {
+                Ok(backup) => backups.push(backup),
+                Err(e) => log::warn!(
+                    "Ignoring unreadable backup manifest {}: {}",
+                    manifest_path.display(),
+                    e
+                ),
+            }
+        }
+
+        // IDs of pruned backups can be reused within the same millisecond, so
+        // order by the full timestamp and only use the ID to break ties
+        backups.sort_by(|a, b| b.sort_key().cmp(&a.sort_key()));
+        Ok(backups)
+    }
+
+    /// Restore a backup, or undo the latest change when `id` is `None`
+    ///
+    /// Undo restores the newest backup that wasn't restored yet and isn't a restore's
+    /// own backup, so repeated undos keep walking back. The current files are backed up
+    /// first as a "restore" backup.
+    pub fn restore(&self, id: Option<&str>) -> Result<ConfigBackup> {
+        let backups = self.list()?;
+        let mut backup = match id {
+            Some(id) => backups
+                .iter()
+                .find(|backup| backup.id == id)
+                .with_context(|| format!("Backup not found: {}", id))?,
+            None => backups
+                .iter()
+                .find(|backup| {
+                    backup.restored_at.is_none() && backup.operation != RESTORE_OPERATION
+                })
+                .context("No backups to restore")?,
+        }
+        .clone();
+
+        // Read the copies first, the backup below may prune the one being restored
+        let dir = self.root.join(&backup.id);
+        let mut contents = Vec::with_capacity(backup.files.len());
+        for file in &backup.files {
+            let content = match &file.stored_as {
+                Some(stored_as) => Some(fs::read(dir.join(stored_as)).with_context(|| {
+                    format!("Failed to read backup of {}", file.path.display())
+                })?),
+                None => None,
+            };
+            contents.push(content);
+        }
+
+        let paths = backup
+            .files
+            .iter()
+            .map(|file| file.path.as_path())
+            .collect::<Vec<_>>();
+        self.backup(RESTORE_OPERATION, &paths)?;
+
+        for (file, content) in backup.files.iter().zip(contents) {
+            match content {
+                Some(content) => {
+                    if let Some(parent) = file.path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(&file.path, content)
+                        .with_context(|| format!("Failed to restore {}", file.path.display()))?;
+                }
+                None if file.path.exists() => {
+                    fs::remove_file(&file.path)
+                        .with_context(|| format!("Failed to remove {}", file.path.display()))?;
+                }
+                None => {}
+            }
+        }
+
+        backup.restored_at = Some(Utc::now());
+        if dir.is_dir() {
+            self.write_manifest(&backup)?;
+        }
+
+        log::info!(
+            "Restored config backup {} ({})",
+            backup.id,
+            backup.operation
+        );
+        Ok(backup)
+    }
+
+    fn write_manifest(&self, backup: &ConfigBackup) -> Result<()> {
+        fs::write(
+            self.root.join(&backup.id).join(MANIFEST_FILE),
+            serde_json::to_string_pretty(backup)?,
+        )?;
+        Ok(())
+    }
+
+    fn prune(&self) -> Result<()> {
+        for old in self.list()?.iter().skip(self.max_backups) {
+            fs::remove_dir_all(self.root.join(&old.id))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backup_and_undo() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ConfigBackupStore::new(dir.path().join("backups"));
+        let config = dir.path().join("config.toml");
+        let auth = dir.path().join("auth.json");
+
+        fs::write(&config, "original").unwrap();
+        store.backup("configure", &[&config, &auth]).unwrap();
+        fs::write(&config, "changed").unwrap();
+        fs::write(&auth, "{}").unwrap();
+
+        let restored = store.restore(None).unwrap();
+        assert_eq!(restored.operation, "configure");
+        assert_eq!(fs::read_to_string(&config).unwrap(), "original");
+        // auth.json did not exist before, so undo removes it
+        assert!(!auth.exists());
+
+        // The files overwritten by the restore were backed up, nothing was deleted
+        let listed = store.list().unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].operation, RESTORE_OPERATION);
+        assert!(listed[1].restored_at.is_some());
+        assert!(store.restore(None).is_err());
+
+        // Restoring the restore's backup brings back the hand edits
+        store.restore(Some(&listed[0].id)).unwrap();
+        assert_eq!(fs::read_to_string(&config).unwrap(), "changed");
+        assert_eq!(fs::read_to_string(&auth).unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_restore_keeps_backups_and_prunes() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = ConfigBackupStore::new(dir.path().join("backups"));
+        store.max_backups = 3;
+        let config = dir.path().join("config.toml");
+
+        let mut ids = Vec::new();
+        for version in 0..5 {
+            fs::write(&config, format!("v{}", version)).unwrap();
+            ids.push(store.backup("configure", &[&config]).unwrap().id);
+        }
+
+        let listed = store.list().unwrap();
+        assert_eq!(listed.len(), 3);
+        assert_eq!(listed[0].id, ids[4]);
+
+        // The oldest kept backup is pruned by the restore's own backup but still restored
+        store.restore(Some(&ids[2])).unwrap();
+        assert_eq!(fs::read_to_string(&config).unwrap(), "v2");
+        let remaining: Vec<_> = store.list().unwrap().into_iter().map(|b| b.id).collect();
+        assert_eq!(remaining.len(), 3);
+        assert_eq!(remaining[1..], [ids[4].clone(), ids[3].clone()]);
+
+        // Undo skips the restore's backup and walks back from the newest change
+        store.restore(None).unwrap();
+        assert_eq!(fs::read_to_string(&config).unwrap(), "v4");
+        // Its own backup pruned the next change
+        assert!(store.restore(None).is_err());
+    }
+}
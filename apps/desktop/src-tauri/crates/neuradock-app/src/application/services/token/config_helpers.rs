@@ -0,0 +1,54 @@
+//! Key and URL normalization shared by the CLI config services
+
+/// Ensure API key has sk- prefix
+pub(super) fn ensure_sk_prefix(key: &str) -> String {
+    if key.starts_with("sk-") {
+        key.to_string()
+    } else {
+        format!("sk-{}", key)
+    }
+}
+
+/// Identifier safe to use in config keys, e.g. `any_router` for `Any-Router`
+pub(super) fn sanitize_provider_slug(provider_id: &str) -> String {
+    provider_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// OpenAI-compatible base URL ending in `/v1`
+pub(super) fn ensure_v1_base_url(base_url: &str) -> String {
+    let trimmed = base_url.trim_end_matches('/');
+    if trimmed.ends_with("/v1") {
+        trimmed.to_string()
+    } else {
+        format!("{}/v1", trimmed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalizes_keys_slugs_and_base_urls() {
+        assert_eq!(ensure_sk_prefix("abc"), "sk-abc");
+        assert_eq!(ensure_sk_prefix("sk-abc"), "sk-abc");
+        assert_eq!(sanitize_provider_slug("Any-Router.io"), "any_router_io");
+        assert_eq!(
+            ensure_v1_base_url("https://api.example.com/"),
+            "https://api.example.com/v1"
+        );
+        assert_eq!(
+            ensure_v1_base_url("https://api.example.com/v1/"),
+            "https://api.example.com/v1"
+        );
+    }
+}
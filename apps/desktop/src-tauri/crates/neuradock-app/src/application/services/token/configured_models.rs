@@ -0,0 +1,56 @@
+/// Endpoint and model names a CLI tool is currently configured with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfiguredModels {
+    /// `None` when the tool talks to its vendor's own API
+    pub base_url: Option<String>,
+    /// Distinct model names in the order they appear in the config
+    pub models: Vec<String>,
+}
+
+impl ConfiguredModels {
+    pub(super) fn push_model(&mut self, model: &str) {
+        let model = model.trim();
+        if !model.is_empty() && !self.models.iter().any(|m| m == model) {
+            self.models.push(model.to_string());
+        }
+    }
+
+    /// Whether the base URL points at `domain` or one of its subdomains (or the reverse)
+    pub fn uses_host_of(&self, domain: &str) -> bool {
+        let host = |url: &str| {
+            url::Url::parse(url)
+                .ok()
+                .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+        };
+        let (Some(configured), Some(provider)) =
+            (self.base_url.as_deref().and_then(host), host(domain))
+        else {
+            return false;
+        };
+        let is_subdomain = |child: &str, parent: &str| {
+            child
+                .strip_suffix(parent)
+                .is_some_and(|prefix| prefix.ends_with('.'))
+        };
+        configured == provider
+            || is_subdomain(&configured, &provider)
+            || is_subdomain(&provider, &configured)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uses_host_of() {
+        let configured = ConfiguredModels {
+            base_url: Some("https://api.example.com/v1".to_string()),
+            models: Vec::new(),
+        };
+        assert!(configured.uses_host_of("https://api.example.com"));
+        assert!(configured.uses_host_of("https://example.com/"));
+        assert!(!configured.uses_host_of("https://notexample.com"));
+        assert!(!ConfiguredModels::default().uses_host_of("https://example.com"));
+    }
+}
@@ -1,7 +1,18 @@
 mod claude_config_service;
+mod cli_tool_config_service;
 mod codex_config_service;
+mod config_backup;
+mod config_helpers;
+mod configured_models;
+mod model_selection;
+mod shell_env;
 mod token_service;
 
 pub use claude_config_service::ClaudeConfigService;
+pub use cli_tool_config_service::{CliTool, CliToolConfigService};
 pub use codex_config_service::CodexConfigService;
+pub use config_backup::ConfigBackup;
+pub use configured_models::ConfiguredModels;
+pub use model_selection::{ClaudeModelSlots, ModelNotAvailable, TokenModels};
+pub use shell_env::ShellDialect;
 pub use token_service::TokenService;
@@ -0,0 +1,275 @@
+use serde_json::{json, Map, Value};
+use std::cmp::Reverse;
+use std::fmt;
+
+use neuradock_domain::token::ApiToken;
+
+/// Claude Code model slots with the env key each is written to
+const CLAUDE_SLOTS: [(&str, &str); 3] = [
+    ("haiku", "ANTHROPIC_DEFAULT_HAIKU_MODEL"),
+    ("sonnet", "ANTHROPIC_DEFAULT_SONNET_MODEL"),
+    ("opus", "ANTHROPIC_DEFAULT_OPUS_MODEL"),
+];
+
+/// Alternatives listed when a model is rejected
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Models a token can be configured with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenModels {
+    models: Vec<String>,
+    enforced: bool,
+}
+
+impl TokenModels {
+    /// Models from the token's allow list, else from its provider's cached model list
+    ///
+    /// `None` when neither names a model, nothing can be checked then.
+    pub fn resolve(token: &ApiToken, catalog: &[String]) -> Option<Self> {
+        let models = token.servable_models(catalog);
+        if models.is_empty() && !token.has_model_allow_list() {
+            return None;
+        }
+        Some(Self {
+            models,
+            enforced: token.has_model_allow_list(),
+        })
+    }
+
+    /// Whether the list comes from the token's allow list, which the provider enforces
+    ///
+    /// A cached model list may be stale, a model missing from it is only suspicious.
+    pub fn is_enforced(&self) -> bool {
+        self.enforced
+    }
+
+    /// Fails with the closest alternatives when `model` isn't in the list
+    pub fn check(&self, model: &str) -> Result<(), ModelNotAvailable> {
+        if self.models.iter().any(|m| m == model) {
+            return Ok(());
+        }
+        Err(ModelNotAvailable {
+            model: model.to_string(),
+            enforced: self.enforced,
+            suggestions: self.suggestions(model, MAX_SUGGESTIONS),
+        })
+    }
+
+    /// Check a model about to be configured
+    ///
+    /// Fails when the allow list rejects it, returns a warning when only the cached model
+    /// list lacks it.
+    pub fn vet(&self, model: &str) -> Result<Option<ModelNotAvailable>, ModelNotAvailable> {
+        match self.check(model) {
+            Ok(()) => Ok(None),
+            Err(e) if e.enforced => Err(e),
+            Err(e) => Ok(Some(e)),
+        }
+    }
+
+    /// Up to `limit` models most similar to `model`
+    pub fn suggestions(&self, model: &str, limit: usize) -> Vec<String> {
+        let mut ranked = self.models.iter().collect::<Vec<_>>();
+        ranked.sort_by_key(|candidate| (Reverse(similarity(candidate, model)), *candidate));
+        ranked.into_iter().take(limit).cloned().collect()
+    }
+
+    /// Haiku, sonnet and opus slots mapped to the closest models of each family
+    ///
+    /// The chosen model fills its own family's slot and any slot without a model of that
+    /// family. Without a chosen model, each family's last model by name is used, usually its
+    /// newest.
+    pub fn claude_slots(&self, model: Option<&str>) -> ClaudeModelSlots {
+        let slots = CLAUDE_SLOTS.map(|(family, _)| {
+            if let Some(model) = model.filter(|m| is_family(m, family)) {
+                return Some(model.to_string());
+            }
+            self.models
+                .iter()
+                .filter(|candidate| is_family(candidate, family))
+                .max_by_key(|candidate| (model.map(|m| similarity(candidate, m)), *candidate))
+                .cloned()
+                .or_else(|| model.map(str::to_string))
+        });
+        let [haiku, sonnet, opus] = slots;
+        ClaudeModelSlots {
+            haiku,
+            sonnet,
+            opus,
+        }
+    }
+}
+
+/// Models written to Claude Code's haiku, sonnet and opus slots, `None` leaves a slot as is
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClaudeModelSlots {
+    pub haiku: Option<String>,
+    pub sonnet: Option<String>,
+    pub opus: Option<String>,
+}
+
+impl ClaudeModelSlots {
+    /// Every slot set to `model`, used when the available models are unknown
+    pub fn same(model: Option<&str>) -> Self {
+        let model = model.map(str::to_string);
+        Self {
+            haiku: model.clone(),
+            sonnet: model.clone(),
+            opus: model,
+        }
+    }
+
+    /// Slots to configure with `model`
+    ///
+    /// Mapped to the closest available models when a model is chosen or the token has an
+    /// allow list, otherwise every slot gets `model`.
+    pub fn for_model(available: Option<&TokenModels>, model: Option<&str>) -> Self {
+        match available {
+            Some(available) if model.is_some() || available.is_enforced() => {
+                available.claude_slots(model)
+            }
+            _ => Self::same(model),
+        }
+    }
+
+    pub(super) fn write_to(&self, env: &mut Map<String, Value>) {
+        for (key, model) in self.env_vars() {
+            env.insert(key.to_string(), json!(model));
+        }
+    }
+
+    /// Env key and model of each slot that is set
+    pub(super) fn env_vars(&self) -> Vec<(&'static str, String)> {
+        CLAUDE_SLOTS
+            .iter()
+            .zip([&self.haiku, &self.sonnet, &self.opus])
+            .filter_map(|((_, key), model)| Some((*key, model.clone()?)))
+            .collect()
+    }
+}
+
+/// A model the token can't use, with alternatives it can
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelNotAvailable {
+    pub model: String,
+    /// Rejected by the token's allow list rather than missing from the cached model list
+    pub enforced: bool,
+    pub suggestions: Vec<String>,
+}
+
+impl fmt::Display for ModelNotAvailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.enforced {
+            write!(
+                f,
+                "Model '{}' is not allowed by the token's model limits",
+                self.model
+            )?;
+        } else {
+            write!(
+                f,
+                "Model '{}' is not in the provider's model list",
+                self.model
+            )?;
+        }
+        if !self.suggestions.is_empty() {
+            write!(f, ", available: {}", self.suggestions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ModelNotAvailable {}
+
+fn words(model: &str) -> Vec<String> {
+    model
+        .to_ascii_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_family(model: &str, family: &str) -> bool {
+    words(model).iter().any(|word| word == family)
+}
+
+/// Shared name parts, then shared prefix length, compared case-insensitively
+fn similarity(a: &str, b: &str) -> (usize, usize) {
+    let b_words = words(b);
+    let shared = words(a)
+        .iter()
+        .filter(|word| b_words.contains(word))
+        .count();
+    let prefix = a
+        .to_ascii_lowercase()
+        .chars()
+        .zip(b.to_ascii_lowercase().chars())
+        .take_while(|(x, y)| x == y)
+        .count();
+    (shared, prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn available(models: &[&str], enforced: bool) -> TokenModels {
+        TokenModels {
+            models: models.iter().map(|m| m.to_string()).collect(),
+            enforced,
+        }
+    }
+
+    #[test]
+    fn test_check_suggests_closest_models() {
+        let models = available(
+            &[
+                "gpt-4o",
+                "claude-sonnet-4-5-20250929",
+                "claude-3-5-haiku-20241022",
+            ],
+            true,
+        );
+
+        assert!(models.check("gpt-4o").is_ok());
+        let err = models.check("claude-sonnet-4-5").unwrap_err();
+        assert!(err.enforced);
+        assert_eq!(err.suggestions[0], "claude-sonnet-4-5-20250929");
+        assert_eq!(err.suggestions.len(), 3);
+        assert!(err.to_string().contains("not allowed"));
+    }
+
+    #[test]
+    fn test_claude_slots_map_to_closest_family_models() {
+        let models = available(
+            &[
+                "claude-3-5-haiku-20241022",
+                "claude-haiku-4-5-20251001",
+                "claude-sonnet-4-20250514",
+                "claude-sonnet-4-5-20250929",
+                "gpt-4o",
+            ],
+            true,
+        );
+
+        let slots = models.claude_slots(Some("claude-sonnet-4-5-20250929"));
+        assert_eq!(slots.haiku.as_deref(), Some("claude-haiku-4-5-20251001"));
+        assert_eq!(slots.sonnet.as_deref(), Some("claude-sonnet-4-5-20250929"));
+        // No opus model available, the chosen model fills the slot
+        assert_eq!(slots.opus.as_deref(), Some("claude-sonnet-4-5-20250929"));
+
+        let slots = models.claude_slots(None);
+        assert_eq!(slots.haiku.as_deref(), Some("claude-haiku-4-5-20251001"));
+        assert_eq!(slots.sonnet.as_deref(), Some("claude-sonnet-4-5-20250929"));
+        assert_eq!(slots.opus, None);
+
+        let mut env = Map::new();
+        slots.write_to(&mut env);
+        assert_eq!(env.len(), 2);
+        assert_eq!(
+            env["ANTHROPIC_DEFAULT_SONNET_MODEL"],
+            "claude-sonnet-4-5-20250929"
+        );
+    }
+}
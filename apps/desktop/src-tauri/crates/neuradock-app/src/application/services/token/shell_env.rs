@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Shell syntax used for temporary (current session) configuration
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ShellDialect {
+    /// bash / zsh / sh
+    #[default]
+    Posix,
+    Fish,
+    PowerShell,
+    /// `.envrc` file loaded by direnv
+    Direnv,
+}
+
+/// POSIX single quotes: nothing is special inside, `'` closes and reopens
+fn quote_posix(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// fish single quotes: only `\` and `'` need escaping
+fn quote_fish(value: &str) -> String {
+    format!("'{}'", value.replace('\\', r"\\").replace('\'', r"\'"))
+}
+
+/// PowerShell verbatim strings: `'` is doubled
+fn quote_powershell(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Render `vars` as statements setting environment variables in `dialect`
+///
+/// `notes` become comments above the statements.
+pub(crate) fn render_env(dialect: ShellDialect, notes: &[&str], vars: &[(&str, String)]) -> String {
+    let mut lines: Vec<String> = Vec::with_capacity(notes.len() + vars.len() + 1);
+    if dialect == ShellDialect::Direnv {
+        lines.push("# .envrc - run `direnv allow` after saving".to_string());
+    }
+    lines.extend(notes.iter().map(|note| format!("# {}", note)));
+
+    lines.extend(vars.iter().map(|(key, value)| match dialect {
+        ShellDialect::Posix | ShellDialect::Direnv => {
+            format!("export {}={}", key, quote_posix(value))
+        }
+        ShellDialect::Fish => format!("set -gx {} {}", key, quote_fish(value)),
+        ShellDialect::PowerShell => format!("$env:{} = {}", key, quote_powershell(value)),
+    }));
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRICKY: &str = r#"sk-a'b"c$HOME\n`x`"#;
+
+    fn render(dialect: ShellDialect) -> String {
+        render_env(dialect, &[], &[("OPENAI_API_KEY", TRICKY.to_string())])
+    }
+
+    #[test]
+    fn test_posix_quoting() {
+        assert_eq!(
+            render(ShellDialect::Posix),
+            r#"export OPENAI_API_KEY='sk-a'\''b"c$HOME\n`x`'"#
+        );
+    }
+
+    #[test]
+    fn test_fish_quoting() {
+        assert_eq!(
+            render(ShellDialect::Fish),
+            r#"set -gx OPENAI_API_KEY 'sk-a\'b"c$HOME\\n`x`'"#
+        );
+    }
+
+    #[test]
+    fn test_powershell_quoting() {
+        assert_eq!(
+            render(ShellDialect::PowerShell),
+            r#"$env:OPENAI_API_KEY = 'sk-a''b"c$HOME\n`x`'"#
+        );
+    }
+
+    #[test]
+    fn test_direnv_file() {
+        let envrc = render_env(
+            ShellDialect::Direnv,
+            &["Claude Code"],
+            &[("A", "1".to_string()), ("B", "it's".to_string())],
+        );
+        assert_eq!(
+            envrc,
+            "# .envrc - run `direnv allow` after saving\n# Claude Code\nexport A='1'\nexport B='it'\\''s'"
+        );
+    }
+
+    #[test]
+    fn test_posix_output_round_trips_through_sh() {
+        let Ok(output) = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(format!(
+                "{}\nprintf '%s' \"$OPENAI_API_KEY\"",
+                render(ShellDialect::Posix)
+            ))
+            .output()
+        else {
+            // No POSIX shell available (e.g. Windows CI)
+            return;
+        };
+        assert_eq!(String::from_utf8_lossy(&output.stdout), TRICKY);
+    }
+}
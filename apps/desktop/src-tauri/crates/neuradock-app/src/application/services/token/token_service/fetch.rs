@@ -113,7 +113,7 @@ impl super::TokenService {
             Some(api_user)
         };
 
-        let proxy_url = self.load_proxy_url().await;
+        let proxy_url = self.load_proxy_url(account.id().as_str(), &provider).await;
         let http_client = self.build_token_client(proxy_url.clone())?;
         let waf_service = self.build_waf_service(proxy_url);
 
@@ -192,13 +192,14 @@ impl super::TokenService {
             .map(|data| self.convert_to_domain(data, account_id.clone()))
             .collect::<Result<Vec<_>>>()?;
 
-        // 6. Delete old tokens and save new ones (to handle deleted tokens on server side)
+        // 6. Delete old tokens and save new ones (to handle deleted tokens on server side,
+        // including the last one)
+        log::info!(
+            "Deleting old tokens for account {} before saving new ones",
+            account_id
+        );
+        self.token_repo.delete_by_account(account_id).await?;
         if !tokens.is_empty() {
-            log::info!(
-                "Deleting old tokens for account {} before saving new ones",
-                account_id
-            );
-            self.token_repo.delete_by_account(account_id).await?;
             self.token_repo.save_batch(tokens.clone()).await?;
             log::info!("Cached {} tokens for account {}", tokens.len(), account_id);
         }
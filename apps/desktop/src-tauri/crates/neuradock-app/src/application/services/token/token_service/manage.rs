@@ -0,0 +1,282 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use std::collections::HashMap;
+
+use neuradock_domain::account::Account;
+use neuradock_domain::check_in::Provider;
+use neuradock_domain::shared::AccountId;
+use neuradock_domain::token::{ApiToken, ApiTokenDraft, TokenId, TokenStatus};
+use neuradock_infrastructure::http::token::{TokenClient, TokenPayload, TokenSession};
+use neuradock_infrastructure::http::WafBypassService;
+
+/// A change to one token on the provider's console
+enum TokenMutation {
+    Create(TokenPayload),
+    Update(TokenPayload),
+    SetStatus { id: i64, status: TokenStatus },
+    Delete(i64),
+}
+
+/// Everything needed to talk to an account's token console
+struct TokenConsole {
+    account: Account,
+    provider: Provider,
+    base_url: String,
+    token_api_path: String,
+    cookies: HashMap<String, String>,
+    client: TokenClient,
+    waf_service: WafBypassService,
+}
+
+impl super::TokenService {
+    /// Create a token on the provider and return the refreshed token list
+    pub async fn create_provider_token(
+        &self,
+        account_id: &AccountId,
+        draft: ApiTokenDraft,
+    ) -> Result<Vec<ApiToken>> {
+        let payload = Self::build_payload(None, draft)?;
+        self.mutate(account_id, TokenMutation::Create(payload))
+            .await
+    }
+
+    /// Replace a token's name, quota, expiry and model limits
+    pub async fn update_provider_token(
+        &self,
+        account_id: &AccountId,
+        token_id: i64,
+        draft: ApiTokenDraft,
+    ) -> Result<Vec<ApiToken>> {
+        let cached = self.cached_token(account_id, token_id).await?;
+        let payload = Self::update_payload(&cached, draft)?;
+        self.mutate(account_id, TokenMutation::Update(payload))
+            .await
+    }
+
+    /// Enable or disable a token, e.g. to cut off a leaked key right away
+    pub async fn set_provider_token_enabled(
+        &self,
+        account_id: &AccountId,
+        token_id: i64,
+        enabled: bool,
+    ) -> Result<Vec<ApiToken>> {
+        self.cached_token(account_id, token_id).await?;
+        let status = if enabled {
+            TokenStatus::Enabled
+        } else {
+            TokenStatus::Disabled
+        };
+        self.mutate(
+            account_id,
+            TokenMutation::SetStatus {
+                id: token_id,
+                status,
+            },
+        )
+        .await
+    }
+
+    pub async fn delete_provider_token(
+        &self,
+        account_id: &AccountId,
+        token_id: i64,
+    ) -> Result<Vec<ApiToken>> {
+        self.cached_token(account_id, token_id).await?;
+        self.mutate(account_id, TokenMutation::Delete(token_id))
+            .await
+    }
+
+    fn build_payload(id: Option<i64>, draft: ApiTokenDraft) -> Result<TokenPayload> {
+        let draft = draft.normalized(Utc::now())?;
+        Ok(TokenPayload {
+            id,
+            model_limits_enabled: draft.model_limits_enabled(),
+            model_limits: draft.model_limits.join(","),
+            expired_time: draft
+                .expired_time
+                .map(|expired_time| expired_time.timestamp())
+                .unwrap_or(-1),
+            name: draft.name,
+            remain_quota: draft.remain_quota,
+            unlimited_quota: draft.unlimited_quota,
+            group: draft.group,
+            allow_ips: draft.allow_ips,
+        })
+    }
+
+    /// new-api replaces the whole token on update, so send back the settings the draft doesn't edit
+    fn update_payload(cached: &ApiToken, draft: ApiTokenDraft) -> Result<TokenPayload> {
+        let draft = ApiTokenDraft {
+            group: cached.group().to_string(),
+            allow_ips: cached.allow_ips().to_string(),
+            ..draft
+        };
+        Self::build_payload(Some(cached.id().value()), draft)
+    }
+
+    /// Token ids are only unique per provider, so refuse ids not cached for this account
+    async fn cached_token(&self, account_id: &AccountId, token_id: i64) -> Result<ApiToken> {
+        self.token_repo
+            .find_by_account(account_id)
+            .await?
+            .into_iter()
+            .find(|token| token.id() == &TokenId::new(token_id))
+            .with_context(|| {
+                format!(
+                    "Token {} not found for this account, refresh the token list first",
+                    token_id
+                )
+            })
+    }
+
+    /// Apply the mutation, retrying once with fresh WAF cookies, then refresh the cache
+    async fn mutate(
+        &self,
+        account_id: &AccountId,
+        mutation: TokenMutation,
+    ) -> Result<Vec<ApiToken>> {
+        let mut console = self.open_console(account_id).await?;
+
+        match self.send_mutation(&console, &mutation).await {
+            Ok(()) => {}
+            Err(e) if e.to_string().contains("WAF_CHALLENGE") => {
+                log::warn!("WAF challenge on token console, getting fresh WAF cookies...");
+                let provider_id = console.provider.id().as_str().to_string();
+                if let Some(ref waf_cookies_repo) = self.waf_cookies_repo {
+                    if let Err(e) = waf_cookies_repo.delete(&provider_id).await {
+                        log::warn!("Failed to delete cached WAF cookies: {}", e);
+                    }
+                }
+
+                let waf_cookies = self
+                    .get_fresh_waf_cookies(
+                        &console.waf_service,
+                        &console.provider,
+                        &console.account,
+                    )
+                    .await?;
+                console.cookies.extend(waf_cookies);
+                self.send_mutation(&console, &mutation).await?;
+            }
+            Err(e) => return Err(e),
+        }
+
+        self.fetch_and_cache_tokens(account_id, true).await
+    }
+
+    async fn send_mutation(&self, console: &TokenConsole, mutation: &TokenMutation) -> Result<()> {
+        let cookie_string = self.build_cookie_string(&console.cookies);
+        let api_user_header = console.provider.api_user_key();
+        let api_user = console.account.credentials().api_user();
+        let session = TokenSession {
+            base_url: &console.base_url,
+            token_api_path: &console.token_api_path,
+            cookie_string: &cookie_string,
+            api_user_header: (!api_user_header.is_empty()).then_some(api_user_header),
+            api_user: (!api_user.is_empty()).then_some(api_user),
+        };
+
+        match mutation {
+            TokenMutation::Create(payload) => console.client.create_token(&session, payload).await,
+            TokenMutation::Update(payload) => console.client.update_token(&session, payload).await,
+            TokenMutation::SetStatus { id, status } => {
+                console
+                    .client
+                    .set_token_status(&session, *id, status.to_i32())
+                    .await
+            }
+            TokenMutation::Delete(id) => console.client.delete_token(&session, *id).await,
+        }
+    }
+
+    async fn open_console(&self, account_id: &AccountId) -> Result<TokenConsole> {
+        let account = self
+            .account_repo
+            .find_by_id(account_id)
+            .await?
+            .context("Account not found")?;
+        if !account.is_session_valid() {
+            anyhow::bail!("Account session expired, please re-login");
+        }
+
+        let provider = self.load_provider(account.provider_id()).await?;
+        let token_api_path = provider
+            .token_api_path()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Provider {} is missing token API endpoint configuration",
+                    provider.name()
+                )
+            })?
+            .to_string();
+        let base_url = provider.domain().trim_end_matches('/').to_string();
+
+        let mut cookies = account.credentials().cookies().clone();
+        if let Some(ref waf_cookies_repo) = self.waf_cookies_repo {
+            match waf_cookies_repo.get_valid(provider.id().as_str()).await {
+                Ok(Some(cached_waf)) => cookies.extend(cached_waf.cookies),
+                Ok(None) => {}
+                Err(e) => log::warn!("Failed to check cached WAF cookies: {}", e),
+            }
+        }
+
+        let proxy_url = self.load_proxy_url(account.id().as_str(), &provider).await;
+        let client = self.build_token_client(proxy_url.clone())?;
+        let waf_service = self.build_waf_service(proxy_url);
+
+        Ok(TokenConsole {
+            account,
+            provider,
+            base_url,
+            token_api_path,
+            cookies,
+            client,
+            waf_service,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::TokenService;
+    use super::*;
+    use neuradock_domain::token::ApiTokenConfig;
+
+    #[test]
+    fn update_payload_keeps_group_and_allowed_ips() {
+        let cached = ApiToken::new(
+            TokenId::new(7),
+            AccountId::new(),
+            ApiTokenConfig {
+                name: "ci-runner".to_string(),
+                key: "sk-test".to_string(),
+                status: TokenStatus::Enabled,
+                used_quota: 0,
+                remain_quota: 100_000,
+                unlimited_quota: false,
+                expired_time: None,
+                model_limits_enabled: false,
+                model_limits: None,
+                group: "vip".to_string(),
+                allow_ips: "10.0.0.1\n10.0.0.2".to_string(),
+            },
+        );
+        let draft = ApiTokenDraft {
+            name: "ci-runner-2".to_string(),
+            remain_quota: 500_000,
+            unlimited_quota: false,
+            expired_time: None,
+            model_limits: vec!["gpt-4o".to_string()],
+            group: String::new(),
+            allow_ips: String::new(),
+        };
+
+        let payload = TokenService::update_payload(&cached, draft).unwrap();
+        assert_eq!(payload.id, Some(7));
+        assert_eq!(payload.name, "ci-runner-2");
+        assert_eq!(payload.remain_quota, 500_000);
+        assert_eq!(payload.model_limits, "gpt-4o");
+        assert_eq!(payload.group, "vip");
+        assert_eq!(payload.allow_ips, "10.0.0.1\n10.0.0.2");
+    }
+}
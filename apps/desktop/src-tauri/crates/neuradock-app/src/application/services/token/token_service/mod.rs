@@ -1,5 +1,6 @@
 mod cache;
 mod fetch;
+mod manage;
 mod token_mapper;
 mod waf_handler;
 
@@ -9,19 +10,22 @@ use std::sync::Arc;
 
 use neuradock_domain::account::AccountRepository;
 use neuradock_domain::check_in::{Provider, ProviderRepository};
-use neuradock_domain::proxy_config::ProxyConfigRepository;
 use neuradock_domain::shared::ProviderId;
 use neuradock_domain::token::TokenRepository;
 use neuradock_domain::waf_cookies::WafCookiesRepository;
+use neuradock_infrastructure::browser::BrowserPool;
 use neuradock_infrastructure::http::token::TokenClient;
 use neuradock_infrastructure::http::WafBypassService;
 
+use crate::application::services::ProxyRoutingService;
+
 pub struct TokenService {
     pub(super) token_repo: Arc<dyn TokenRepository>,
     pub(super) account_repo: Arc<dyn AccountRepository>,
     pub(super) provider_repo: Arc<dyn ProviderRepository>,
-    pub(super) proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+    pub(super) proxy_routing: Arc<ProxyRoutingService>,
     pub(super) waf_cookies_repo: Option<Arc<dyn WafCookiesRepository>>,
+    pub(super) browser_pool: Option<Arc<BrowserPool>>,
 }
 
 impl TokenService {
@@ -29,14 +33,15 @@ impl TokenService {
         token_repo: Arc<dyn TokenRepository>,
         account_repo: Arc<dyn AccountRepository>,
         provider_repo: Arc<dyn ProviderRepository>,
-        proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+        proxy_routing: Arc<ProxyRoutingService>,
     ) -> Result<Self> {
         Ok(Self {
             token_repo,
             account_repo,
             provider_repo,
-            proxy_config_repo,
+            proxy_routing,
             waf_cookies_repo: None,
+            browser_pool: None,
         })
     }
 
@@ -46,6 +51,12 @@ impl TokenService {
         self
     }
 
+    /// Pool used by the WAF bypass of token list and token console requests
+    pub fn with_browser_pool(mut self, pool: Arc<BrowserPool>) -> Self {
+        self.browser_pool = Some(pool);
+        self
+    }
+
     /// Load provider by ID
     pub(super) async fn load_provider(&self, provider_id: &ProviderId) -> Result<Provider> {
         self.provider_repo
@@ -63,12 +74,16 @@ impl TokenService {
             .join("; ")
     }
 
-    pub(super) async fn load_proxy_url(&self) -> Option<String> {
-        self.proxy_config_repo
-            .get()
+    pub(super) async fn load_proxy_url(
+        &self,
+        account_id: &str,
+        provider: &Provider,
+    ) -> Option<String> {
+        self.proxy_routing
+            .resolve_for_account(account_id, provider.id().as_str(), provider.domain())
             .await
             .ok()
-            .and_then(|cfg| cfg.proxy_url())
+            .flatten()
     }
 
     pub(super) fn build_token_client(&self, proxy_url: Option<String>) -> Result<TokenClient> {
@@ -76,6 +91,10 @@ impl TokenService {
     }
 
     pub(super) fn build_waf_service(&self, proxy_url: Option<String>) -> WafBypassService {
-        WafBypassService::with_proxy(true, proxy_url)
+        let service = WafBypassService::with_proxy(true, proxy_url);
+        match &self.browser_pool {
+            Some(pool) => service.with_browser_pool(pool.clone()),
+            None => service,
+        }
     }
 }
@@ -40,6 +40,8 @@ impl super::TokenService {
                 expired_time,
                 model_limits_enabled: data.model_limits_enabled,
                 model_limits,
+                group: data.group,
+                allow_ips: data.allow_ips.unwrap_or_default(),
             },
         ))
     }
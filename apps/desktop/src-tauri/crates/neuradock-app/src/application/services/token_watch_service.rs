@@ -0,0 +1,301 @@
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::application::dtos::{TokenWatchSettingsDto, TokenWatchStatusDto};
+use crate::application::services::{
+    ClaudeConfigService, CodexConfigService, NotificationService, TokenService,
+};
+use neuradock_domain::account::AccountRepository;
+use neuradock_domain::shared::{AccountId, DomainError};
+use neuradock_domain::token::{
+    ApiToken, TokenRepository, TokenWatchRepository, TokenWatchTarget, TokenWatchTool,
+};
+
+/// Tokens are re-fetched this often, matching the token cache lifetime
+const CHECK_INTERVAL_SECS: u64 = 60 * 60;
+const TOOLS: [TokenWatchTool; 2] = [TokenWatchTool::Claude, TokenWatchTool::Codex];
+
+/// Identifies a key without storing it, with or without the `sk-` prefix
+fn key_digest(key: &str) -> String {
+    let key = key.trim();
+    let key = key.strip_prefix("sk-").unwrap_or(key);
+    format!("{:x}", Sha256::digest(key.as_bytes()))
+}
+
+/// Watches the provider tokens configured into Claude Code and Codex
+///
+/// Re-fetches the owning account's tokens and alerts before the token
+/// expires, when its quota runs low, or when the configured key no longer
+/// matches an enabled token.
+pub struct TokenWatchService {
+    token_service: Arc<TokenService>,
+    token_repo: Arc<dyn TokenRepository>,
+    account_repo: Arc<dyn AccountRepository>,
+    watch_repo: Arc<dyn TokenWatchRepository>,
+    claude_config: Arc<ClaudeConfigService>,
+    codex_config: Arc<CodexConfigService>,
+    notification_service: Arc<NotificationService>,
+    background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl TokenWatchService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        token_service: Arc<TokenService>,
+        token_repo: Arc<dyn TokenRepository>,
+        account_repo: Arc<dyn AccountRepository>,
+        watch_repo: Arc<dyn TokenWatchRepository>,
+        claude_config: Arc<ClaudeConfigService>,
+        codex_config: Arc<CodexConfigService>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            token_service,
+            token_repo,
+            account_repo,
+            watch_repo,
+            claude_config,
+            codex_config,
+            notification_service,
+            background_handle: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Remember which token was just written into the tool's config
+    pub async fn track(&self, tool: TokenWatchTool, token: &ApiToken) -> Result<(), DomainError> {
+        let target = TokenWatchTarget::new(
+            tool,
+            token.account_id().clone(),
+            token.id().clone(),
+            key_digest(token.key()),
+        );
+        self.watch_repo.save_target(&target).await
+    }
+
+    /// Stop watching the tool, e.g. after its config was cleared
+    pub async fn untrack(&self, tool: TokenWatchTool) -> Result<(), DomainError> {
+        self.watch_repo.delete_target(tool).await
+    }
+
+    /// Check the token configured into every tool and send new alerts
+    pub async fn check_all(&self) -> Result<Vec<TokenWatchStatusDto>, DomainError> {
+        for tool in TOOLS {
+            if let Err(e) = self.check_tool(tool).await {
+                warn!("[token_watch] Check of {} failed: {}", tool.as_str(), e);
+            }
+        }
+        self.status().await
+    }
+
+    /// Targets and the alerts of their last check
+    pub async fn status(&self) -> Result<Vec<TokenWatchStatusDto>, DomainError> {
+        let accounts: HashMap<AccountId, String> = self
+            .account_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|account| (account.id().clone(), account.name().to_string()))
+            .collect();
+
+        let mut statuses = Vec::new();
+        for target in self.watch_repo.list_targets().await? {
+            let token_name = self
+                .token_repo
+                .find_by_account(&target.account_id)
+                .await?
+                .into_iter()
+                .find(|token| token.id() == &target.token_id)
+                .map(|token| token.name().to_string());
+
+            statuses.push(TokenWatchStatusDto {
+                tool: target.tool.as_str().to_string(),
+                account_id: target.account_id.as_str().to_string(),
+                account_name: accounts
+                    .get(&target.account_id)
+                    .cloned()
+                    .unwrap_or_default(),
+                token_id: target.token_id.value(),
+                token_name,
+                alerts: target
+                    .alerts
+                    .iter()
+                    .map(|alert| alert.as_str().to_string())
+                    .collect(),
+                checked_at: target.checked_at.map(|at| at.to_rfc3339()),
+                last_error: target.last_error,
+            });
+        }
+        Ok(statuses)
+    }
+
+    pub async fn get_settings(&self) -> Result<TokenWatchSettingsDto, DomainError> {
+        let settings = self.watch_repo.get_settings().await?;
+        Ok(TokenWatchSettingsDto::from(&settings))
+    }
+
+    pub async fn update_settings(
+        &self,
+        input: TokenWatchSettingsDto,
+    ) -> Result<TokenWatchSettingsDto, DomainError> {
+        let mut settings = self.watch_repo.get_settings().await?;
+        settings.update(
+            input.enabled,
+            input.expiry_warning_days,
+            input.low_quota_threshold,
+        )?;
+        self.watch_repo.save_settings(&settings).await?;
+
+        Ok(TokenWatchSettingsDto::from(&settings))
+    }
+
+    /// Start the hourly check, skipped while the watchdog is disabled
+    pub async fn start_background_task(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+
+        let handle = tokio::spawn(async move {
+            let mut check_interval =
+                tokio::time::interval(std::time::Duration::from_secs(CHECK_INTERVAL_SECS));
+
+            loop {
+                check_interval.tick().await;
+
+                match service.watch_repo.get_settings().await {
+                    Ok(settings) if !settings.enabled() => continue,
+                    Ok(_) => {}
+                    Err(e) => {
+                        error!("[token_watch] Failed to load settings: {}", e);
+                        continue;
+                    }
+                }
+                if let Err(e) = service.check_all().await {
+                    error!("[token_watch] Check failed: {}", e);
+                }
+            }
+        });
+
+        let mut background = self.background_handle.lock().await;
+        if let Some(previous) = background.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    fn configured_key(&self, tool: TokenWatchTool) -> Result<Option<String>, DomainError> {
+        let key = match tool {
+            TokenWatchTool::Claude => self.claude_config.configured_auth_token(),
+            TokenWatchTool::Codex => self.codex_config.configured_api_key(),
+        };
+        key.map_err(|e| DomainError::Infrastructure(e.to_string()))
+    }
+
+    async fn check_tool(&self, tool: TokenWatchTool) -> Result<(), DomainError> {
+        let Some(key) = self.configured_key(tool)? else {
+            self.watch_repo.delete_target(tool).await?;
+            return Ok(());
+        };
+        let digest = key_digest(&key);
+
+        let target = match self.watch_repo.find_target(tool).await? {
+            Some(target) if target.key_digest == digest => Some(target),
+            // Configured outside the token page or changed since, look the key up
+            _ => self.discover(tool, &digest).await?,
+        };
+        let Some(mut target) = target else {
+            // Not a provider token we know, e.g. an independent key
+            self.watch_repo.delete_target(tool).await?;
+            return Ok(());
+        };
+
+        let now = Utc::now();
+        let tokens = match self
+            .token_service
+            .fetch_and_cache_tokens(&target.account_id, true)
+            .await
+        {
+            Ok(tokens) => tokens,
+            Err(e) => {
+                target.record_error(e.to_string(), now);
+                return self.watch_repo.save_target(&target).await;
+            }
+        };
+
+        let token = tokens
+            .iter()
+            .find(|token| key_digest(token.key()) == digest);
+        if let Some(token) = token {
+            target.token_id = token.id().clone();
+        }
+        let settings = self.watch_repo.get_settings().await?;
+        let raised = target.record_check(settings.alerts_for(token, now), now);
+        self.watch_repo.save_target(&target).await?;
+
+        if raised.is_empty() {
+            return Ok(());
+        }
+        let account_name = self
+            .account_repo
+            .find_by_id(&target.account_id)
+            .await?
+            .map(|account| account.name().to_string())
+            .unwrap_or_else(|| target.account_id.as_str().to_string());
+        for alert in raised {
+            info!(
+                "[token_watch] {} token of {}: {}",
+                tool.as_str(),
+                account_name,
+                alert.as_str()
+            );
+            if let Err(e) = self
+                .notification_service
+                .send_token_watch_alert(tool, &account_name, alert, token)
+                .await
+            {
+                warn!(
+                    "[token_watch] Failed to send {} alert: {}",
+                    alert.as_str(),
+                    e
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Find the cached token with the configured key
+    async fn discover(
+        &self,
+        tool: TokenWatchTool,
+        digest: &str,
+    ) -> Result<Option<TokenWatchTarget>, DomainError> {
+        for account in self.account_repo.find_all().await? {
+            let tokens = self.token_repo.find_by_account(account.id()).await?;
+            if let Some(token) = tokens
+                .iter()
+                .find(|token| key_digest(token.key()) == digest)
+            {
+                return Ok(Some(TokenWatchTarget::new(
+                    tool,
+                    account.id().clone(),
+                    token.id().clone(),
+                    digest.to_string(),
+                )));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_digest_ignores_sk_prefix() {
+        assert_eq!(key_digest("sk-abc"), key_digest("abc"));
+        assert_eq!(key_digest(" sk-abc\n"), key_digest("abc"));
+        assert_ne!(key_digest("sk-abc"), key_digest("sk-abd"));
+    }
+}
@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 use neuradock_domain::check_in::Provider;
 use neuradock_domain::waf_cookies::WafCookiesRepository;
+use neuradock_infrastructure::browser::BrowserPool;
 use neuradock_infrastructure::http::WafBypassService;
 
 /// Service for managing WAF cookies with caching support
@@ -22,6 +23,12 @@ impl WafCookieManager {
         }
     }
 
+    /// Solve WAF challenges on pages from the shared pool instead of a new browser
+    pub fn with_browser_pool(mut self, pool: Arc<BrowserPool>) -> Self {
+        self.waf_service = self.waf_service.with_browser_pool(pool);
+        self
+    }
+
     /// Set WAF cookies repository for caching
     pub fn with_cookies_repo(mut self, repo: Arc<dyn WafCookiesRepository>) -> Self {
         self.waf_cookies_repo = Some(repo);
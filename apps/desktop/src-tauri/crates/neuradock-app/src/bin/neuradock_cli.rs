@@ -0,0 +1,9 @@
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Err(e) = neuradock_app_lib::presentation::cli::run(&args).await {
+        eprintln!("{:#}", e);
+        std::process::exit(1);
+    }
+}
@@ -1,9 +1,6 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-mod application;
-mod presentation;
-
-// Use external crates
+use neuradock_app_lib::presentation;
 
 use presentation::commands::ai_chat::EmbeddedAiChatState;
 use presentation::ipc;
@@ -6,44 +6,79 @@ use tracing::{info, warn};
 
 use crate::application::commands::handlers::*;
 use crate::application::event_handlers::SchedulerReloadEventHandler;
-use crate::application::queries::BalanceStatisticsQueryService;
-use crate::application::queries::{AccountQueryService, CheckInStreakQueries};
+use crate::application::queries::{
+    AccountQueryService, CheckInStreakQueries, EventTimelineQueryService, ModelRoutingQueryService,
+};
+use crate::application::queries::{BalanceAnalyticsQueryService, BalanceStatisticsQueryService};
 use crate::application::services::{
-    AutoCheckInScheduler, BalanceHistoryService, BalanceService, ClaudeConfigService,
-    CodexConfigService, ConfigService, NotificationService, OrphanAccountRepairService,
-    ProviderModelsQueryService, ProviderModelsService, ProxyConfigService, TokenService,
+    AccountCookieImportService, AuditLogService, AutoCheckInScheduler,
+    BalanceHistoryMaintenanceService, BalanceHistoryService, BalanceService, ClaudeConfigService,
+    ClaudeProfileService, CliToolConfigService, CodexAutoSwitchService, CodexConfigService,
+    CodexTokenRefreshService, CodexUsageHistoryService, ConfigService, CurrencySettingsService,
+    DiagnosticsService, IndependentKeyValidationService, MetricsExporterService,
+    ModelCatalogService, NetworkSettingsService, NotificationService, OrphanAccountRepairService,
+    ProviderModelsQueryService, ProviderModelsService, ProxyConfigService, ProxyRoutingService,
+    TokenService, TokenWatchService,
 };
 use crate::presentation::state::{AppState, CommandHandlers, Queries, Repositories, Services};
 use neuradock_domain::account::AccountRepository;
 use neuradock_domain::ai_chat::AiChatServiceRepository;
-use neuradock_domain::balance_history::BalanceHistoryRepository;
+use neuradock_domain::audit::AuditLogRepository;
+use neuradock_domain::balance_history::{
+    BalanceHistoryRepository, BalanceHistoryRetentionRepository,
+};
 use neuradock_domain::check_in::{Provider, ProviderRepository};
-use neuradock_domain::codex::CodexAccountRepository;
+use neuradock_domain::claude_profile::ClaudeProfileRepository;
+use neuradock_domain::codex::{
+    CodexAccountRepository, CodexAutoSwitchRepository, CodexTokenRefreshPolicyRepository,
+    CodexUsageHistoryRepository,
+};
+use neuradock_domain::currency::CurrencySettingsRepository;
 use neuradock_domain::custom_node::CustomProviderNodeRepository;
 use neuradock_domain::events::account_events::*;
+use neuradock_domain::events::EventStore;
 use neuradock_domain::independent_key::IndependentKeyRepository;
+use neuradock_domain::network_settings::NetworkSettingsRepository;
 use neuradock_domain::notification::NotificationChannelRepository;
 use neuradock_domain::provider_models::ProviderModelsRepository;
-use neuradock_domain::proxy_config::ProxyConfigRepository;
+use neuradock_domain::proxy_config::{ProxyConfigRepository, ProxyRoutingRepository};
 use neuradock_domain::session::SessionRepository;
-use neuradock_domain::token::TokenRepository;
+use neuradock_domain::token::{TokenRepository, TokenWatchRepository};
 use neuradock_domain::waf_cookies::WafCookiesRepository;
 use neuradock_infrastructure::bootstrap::seed_builtin_ai_chats;
 use neuradock_infrastructure::bootstrap::seed_builtin_providers;
-use neuradock_infrastructure::events::InMemoryEventBus;
+use neuradock_infrastructure::browser::{BrowserPool, BrowserPoolConfig};
+use neuradock_infrastructure::events::PersistentEventBus;
 use neuradock_infrastructure::notification::SqliteNotificationChannelRepository;
 use neuradock_infrastructure::persistence::{
     repositories::{
-        SqliteAccountRepository, SqliteAiChatServiceRepository, SqliteBalanceHistoryRepository,
-        SqliteCodexAccountRepository, SqliteCustomProviderNodeRepository,
-        SqliteIndependentKeyRepository, SqliteProviderModelsRepository, SqliteProviderRepository,
-        SqliteProxyConfigRepository, SqliteSessionRepository, SqliteTokenRepository,
-        SqliteWafCookiesRepository,
+        SqliteAccountRepository, SqliteAiChatServiceRepository, SqliteAuditLogRepository,
+        SqliteBalanceHistoryRepository, SqliteBalanceHistoryRetentionRepository,
+        SqliteClaudeProfileRepository, SqliteCodexAccountRepository,
+        SqliteCodexAutoSwitchRepository, SqliteCodexTokenRefreshPolicyRepository,
+        SqliteCodexUsageHistoryRepository, SqliteCurrencySettingsRepository,
+        SqliteCustomProviderNodeRepository, SqliteEventStore, SqliteIndependentKeyRepository,
+        SqliteNetworkSettingsRepository, SqliteProviderModelsRepository, SqliteProviderRepository,
+        SqliteProxyConfigRepository, SqliteProxyRoutingRepository, SqliteSessionRepository,
+        SqliteTokenRepository, SqliteTokenWatchRepository, SqliteWafCookiesRepository,
     },
     Database,
 };
 use neuradock_infrastructure::security::{EncryptionService, KeyManager};
 
+// TODO: In production, get password from secure input
+// For now, use a default password (should be configurable)
+pub const DEFAULT_ENCRYPTION_PASSWORD: &str = "neuradock_default_password_2024";
+
+/// Database file name inside the app data directory
+pub fn database_filename() -> &'static str {
+    if cfg!(debug_assertions) {
+        "neuradock-dev.db"
+    } else {
+        "neuradock.db"
+    }
+}
+
 pub async fn build_app_state(
     app_handle: tauri::AppHandle,
 ) -> Result<AppState, Box<dyn std::error::Error>> {
@@ -69,13 +104,7 @@ pub async fn build_app_state(
         started_at.elapsed().as_millis()
     );
 
-    let db_filename = if cfg!(debug_assertions) {
-        "neuradock-dev.db"
-    } else {
-        "neuradock.db"
-    };
-
-    let db_path = app_data_dir.join(db_filename);
+    let db_path = app_data_dir.join(database_filename());
     let db_path_str = db_path.to_str().ok_or("Invalid database path")?;
 
     info!("Database path: {}", db_path_str);
@@ -88,11 +117,8 @@ pub async fn build_app_state(
         .initialize()
         .map_err(|e| format!("Failed to initialize encryption salt: {}", e))?;
 
-    // TODO: In production, get password from secure input
-    // For now, use a default password (should be configurable)
-    let encryption_password = "neuradock_default_password_2024";
     let encryption_service = Arc::new(
-        EncryptionService::from_password(encryption_password, &salt)
+        EncryptionService::from_password(DEFAULT_ENCRYPTION_PASSWORD, &salt)
             .map_err(|e| format!("Failed to create encryption service: {}", e))?,
     );
     info!(
@@ -139,14 +165,45 @@ pub async fn build_app_state(
         as Arc<dyn ProviderModelsRepository>;
     let waf_cookies_repo =
         Arc::new(SqliteWafCookiesRepository::new(pool.clone())) as Arc<dyn WafCookiesRepository>;
-    let proxy_config_repo =
-        Arc::new(SqliteProxyConfigRepository::new(pool.clone())) as Arc<dyn ProxyConfigRepository>;
+    let proxy_config_repo = Arc::new(SqliteProxyConfigRepository::new(
+        pool.clone(),
+        encryption_service.clone(),
+    )) as Arc<dyn ProxyConfigRepository>;
+    let proxy_routing_repo = Arc::new(SqliteProxyRoutingRepository::new(
+        pool.clone(),
+        encryption_service.clone(),
+    )) as Arc<dyn ProxyRoutingRepository>;
+    let proxy_routing_service = Arc::new(ProxyRoutingService::new(
+        proxy_config_repo.clone(),
+        proxy_routing_repo,
+    ));
     let balance_history_repo = Arc::new(SqliteBalanceHistoryRepository::new(pool.clone()))
         as Arc<dyn BalanceHistoryRepository>;
+    let balance_history_retention_repo =
+        Arc::new(SqliteBalanceHistoryRetentionRepository::new(pool.clone()))
+            as Arc<dyn BalanceHistoryRetentionRepository>;
+    let currency_settings_repo = Arc::new(SqliteCurrencySettingsRepository::new(pool.clone()))
+        as Arc<dyn CurrencySettingsRepository>;
     let ai_chat_service_repo = Arc::new(SqliteAiChatServiceRepository::new(pool.clone()))
         as Arc<dyn AiChatServiceRepository>;
-    let codex_account_repo =
-        Arc::new(SqliteCodexAccountRepository::new(pool.clone())) as Arc<dyn CodexAccountRepository>;
+    let codex_account_repo = Arc::new(SqliteCodexAccountRepository::new(pool.clone()))
+        as Arc<dyn CodexAccountRepository>;
+    let codex_token_refresh_policy_repo =
+        Arc::new(SqliteCodexTokenRefreshPolicyRepository::new(pool.clone()))
+            as Arc<dyn CodexTokenRefreshPolicyRepository>;
+    let codex_auto_switch_repo = Arc::new(SqliteCodexAutoSwitchRepository::new(pool.clone()))
+        as Arc<dyn CodexAutoSwitchRepository>;
+    let codex_usage_history_repo = Arc::new(SqliteCodexUsageHistoryRepository::new(pool.clone()))
+        as Arc<dyn CodexUsageHistoryRepository>;
+    let token_watch_repo =
+        Arc::new(SqliteTokenWatchRepository::new(pool.clone())) as Arc<dyn TokenWatchRepository>;
+    let claude_profile_repo = Arc::new(SqliteClaudeProfileRepository::new(
+        pool.clone(),
+        encryption_service.clone(),
+    )) as Arc<dyn ClaudeProfileRepository>;
+    let audit_log_repo =
+        Arc::new(SqliteAuditLogRepository::new(pool.clone())) as Arc<dyn AuditLogRepository>;
+    let audit_log = Arc::new(AuditLogService::new(audit_log_repo));
 
     info!("🌱 Seeding built-in providers...");
     let started_at = Instant::now();
@@ -193,20 +250,52 @@ pub async fn build_app_state(
         started_at.elapsed().as_millis()
     );
 
-    let notification_service = Arc::new(NotificationService::new(
-        notification_channel_repo.clone(),
-        balance_history_repo.clone(),
+    // Shared by every WAF bypass so batch runs reuse a few headless browsers
+    let browser_pool = Arc::new(BrowserPool::for_system_browser(
+        BrowserPoolConfig::default(),
+        true,
     ));
+    browser_pool.start_cleanup_task().await;
+
+    let notification_service = Arc::new(
+        NotificationService::new(
+            notification_channel_repo.clone(),
+            balance_history_repo.clone(),
+        )
+        .with_currency_settings(currency_settings_repo.clone()),
+    );
     let token_service = build_token_service(
         token_repo.clone(),
         account_repo.clone(),
         provider_repo.clone(),
-        proxy_config_repo.clone(),
+        proxy_routing_service.clone(),
         waf_cookies_repo.clone(),
+        browser_pool.clone(),
     )?;
-    let claude_config_service = Arc::new(ClaudeConfigService::new());
-    let codex_config_service = Arc::new(CodexConfigService::new());
+    let claude_config_service =
+        Arc::new(ClaudeConfigService::new().with_audit_log(audit_log.clone()));
+    let claude_profile_service = Arc::new(ClaudeProfileService::new(
+        claude_profile_repo,
+        claude_config_service.clone(),
+    ));
+    let codex_config_service =
+        Arc::new(CodexConfigService::new().with_audit_log(audit_log.clone()));
     let config_service = build_config_service(&app_handle)?;
+    let currency_settings_service = Arc::new(CurrencySettingsService::new(
+        currency_settings_repo.clone(),
+        provider_repo.clone(),
+    ));
+
+    // Install persisted timeouts and retry policy before anything goes on the network
+    let network_settings_service = Arc::new(NetworkSettingsService::new(
+        Arc::new(SqliteNetworkSettingsRepository::new(pool.clone()))
+            as Arc<dyn NetworkSettingsRepository>,
+        provider_repo.clone(),
+    ));
+    if let Err(e) = network_settings_service.apply().await {
+        warn!("Failed to apply network settings, using defaults: {}", e);
+    }
+    network_settings_service.start_background_task().await;
 
     let account_queries = Arc::new(AccountQueryService::new(account_repo.clone()));
     let streak_queries = Arc::new(CheckInStreakQueries::new(
@@ -214,37 +303,120 @@ pub async fn build_app_state(
         provider_repo.clone(),
         balance_history_repo.clone(),
     ));
+    let balance_analytics_queries = Arc::new(BalanceAnalyticsQueryService::new(
+        account_repo.clone(),
+        provider_repo.clone(),
+        balance_history_repo.clone(),
+        currency_settings_repo.clone(),
+    ));
 
     // Initialize check-in related services
-    let provider_models_service = Arc::new(ProviderModelsService::new(
+    let model_catalog = Arc::new(ModelCatalogService::new(
         provider_models_repo.clone(),
-        waf_cookies_repo.clone(),
-        proxy_config_repo.clone(),
+        provider_repo.clone(),
+        claude_config_service.clone(),
+        codex_config_service.clone(),
+        config_service.clone(),
+        notification_service.clone(),
     ));
-    let provider_models_query = Arc::new(ProviderModelsQueryService::new(
+    let provider_models_service = Arc::new(
+        ProviderModelsService::new(
+            provider_models_repo.clone(),
+            waf_cookies_repo.clone(),
+            proxy_routing_service.clone(),
+        )
+        .with_model_catalog(model_catalog.clone()),
+    );
+    let provider_models_query = Arc::new(
+        ProviderModelsQueryService::new(
+            account_repo.clone(),
+            provider_repo.clone(),
+            provider_models_repo.clone(),
+            waf_cookies_repo.clone(),
+            proxy_routing_service.clone(),
+        )
+        .with_browser_pool(browser_pool.clone())
+        .with_model_catalog(model_catalog.clone()),
+    );
+    let balance_history_maintenance = Arc::new(BalanceHistoryMaintenanceService::new(
+        balance_history_repo.clone(),
+        balance_history_retention_repo,
         account_repo.clone(),
         provider_repo.clone(),
-        provider_models_repo.clone(),
-        waf_cookies_repo.clone(),
-        proxy_config_repo.clone(),
+        currency_settings_repo.clone(),
     ));
-    let balance_history_service = Arc::new(BalanceHistoryService::new(balance_history_repo));
-    let balance_service = Arc::new(BalanceService::new(
+    balance_history_maintenance.start_background_task().await;
+    let codex_token_refresh = Arc::new(CodexTokenRefreshService::new(
+        codex_account_repo.clone(),
+        codex_token_refresh_policy_repo,
+        proxy_routing_service.clone(),
+    ));
+    codex_token_refresh.start_background_task().await;
+    let codex_usage_history = Arc::new(CodexUsageHistoryService::new(
+        codex_account_repo.clone(),
+        codex_usage_history_repo,
+        notification_service.clone(),
+    ));
+    codex_usage_history.start_background_task().await;
+    let codex_auto_switch = Arc::new(
+        CodexAutoSwitchService::new(
+            codex_account_repo.clone(),
+            codex_auto_switch_repo,
+            proxy_routing_service.clone(),
+            notification_service.clone(),
+            codex_usage_history.clone(),
+        )
+        .with_audit_log(audit_log.clone()),
+    );
+    codex_auto_switch.start_background_task().await;
+    let token_watch = Arc::new(TokenWatchService::new(
+        token_service.clone(),
+        token_repo.clone(),
         account_repo.clone(),
-        provider_repo.clone(),
-        balance_history_service.clone(),
-        proxy_config_repo.clone(),
-        true,
+        token_watch_repo,
+        claude_config_service.clone(),
+        codex_config_service.clone(),
+        notification_service.clone(),
+    ));
+    token_watch.start_background_task().await;
+    let balance_history_service = Arc::new(BalanceHistoryService::new(balance_history_repo));
+    let balance_service = Arc::new(
+        BalanceService::new(
+            account_repo.clone(),
+            provider_repo.clone(),
+            balance_history_service.clone(),
+            proxy_routing_service.clone(),
+            true,
+        )
+        .with_browser_pool(browser_pool.clone()),
+    );
+    let account_cookie_import = Arc::new(
+        AccountCookieImportService::new(
+            provider_repo.clone(),
+            waf_cookies_repo.clone(),
+            proxy_routing_service.clone(),
+            true,
+        )
+        .with_browser_pool(browser_pool.clone()),
+    );
+    let independent_key_validation = Arc::new(IndependentKeyValidationService::new(
+        independent_key_repo.clone(),
+        proxy_routing_service.clone(),
     ));
     let balance_statistics_queries = Arc::new(BalanceStatisticsQueryService::new(
         account_repo.clone(),
         provider_repo.clone(),
         balance_history_service.clone(),
+        currency_settings_repo.clone(),
     ));
 
     info!("📊 Initializing scheduler...");
     let started_at = Instant::now();
-    let scheduler = Arc::new(AutoCheckInScheduler::new().await?);
+    let scheduler = Arc::new(
+        AutoCheckInScheduler::new()
+            .await?
+            .with_browser_pool(browser_pool.clone()),
+    );
     info!(
         "✓ Scheduler initialized ({}ms)",
         started_at.elapsed().as_millis()
@@ -258,9 +430,31 @@ pub async fn build_app_state(
         started_at.elapsed().as_millis()
     );
 
+    // Opt-in Prometheus endpoint, only listens when enabled in settings
+    let metrics_exporter = Arc::new(MetricsExporterService::new(
+        config_service.clone(),
+        account_repo.clone(),
+        codex_account_repo.clone(),
+        scheduler.clone(),
+    ));
+    metrics_exporter.start().await;
+
+    let diagnostics = Arc::new(DiagnosticsService::new(
+        pool.clone(),
+        account_repo.clone(),
+        provider_repo.clone(),
+        codex_account_repo.clone(),
+        waf_cookies_repo.clone(),
+        scheduler.clone(),
+        proxy_routing_service.clone(),
+        app_data_dir.join("diagnostics"),
+    ));
+
     // Initialize event bus and register event handlers
+    // Events are persisted first, subscribers resume from their checkpoint after a crash
     info!("🔧 Initializing event bus...");
-    let event_bus = Arc::new(InMemoryEventBus::new());
+    let event_store = Arc::new(SqliteEventStore::new(pool.clone())) as Arc<dyn EventStore>;
+    let event_bus = Arc::new(PersistentEventBus::new(event_store.clone()));
 
     // Register SchedulerReloadEventHandler for account events
     let scheduler_reload_handler = SchedulerReloadEventHandler::new(
@@ -272,26 +466,43 @@ pub async fn build_app_state(
 
     use neuradock_domain::events::TypedEventHandlerWrapper;
 
-    let _ = event_bus
-        .subscribe::<AccountCreated>(Arc::new(
-            TypedEventHandlerWrapper::<AccountCreated, _>::new(scheduler_reload_handler.clone()),
-        ))
-        .await;
-    let _ = event_bus
-        .subscribe::<AccountUpdated>(Arc::new(
-            TypedEventHandlerWrapper::<AccountUpdated, _>::new(scheduler_reload_handler.clone()),
-        ))
-        .await;
-    let _ = event_bus
-        .subscribe::<AccountDeleted>(Arc::new(
-            TypedEventHandlerWrapper::<AccountDeleted, _>::new(scheduler_reload_handler.clone()),
-        ))
-        .await;
-    let _ = event_bus
-        .subscribe::<AccountToggled>(Arc::new(
-            TypedEventHandlerWrapper::<AccountToggled, _>::new(scheduler_reload_handler),
-        ))
-        .await;
+    const SCHEDULER_RELOAD: &str = "scheduler_reload";
+    event_bus
+        .subscribe::<AccountCreated>(
+            SCHEDULER_RELOAD,
+            Arc::new(TypedEventHandlerWrapper::<AccountCreated, _>::new(
+                scheduler_reload_handler.clone(),
+            )),
+        )
+        .await?;
+    event_bus
+        .subscribe::<AccountUpdated>(
+            SCHEDULER_RELOAD,
+            Arc::new(TypedEventHandlerWrapper::<AccountUpdated, _>::new(
+                scheduler_reload_handler.clone(),
+            )),
+        )
+        .await?;
+    event_bus
+        .subscribe::<AccountDeleted>(
+            SCHEDULER_RELOAD,
+            Arc::new(TypedEventHandlerWrapper::<AccountDeleted, _>::new(
+                scheduler_reload_handler.clone(),
+            )),
+        )
+        .await?;
+    event_bus
+        .subscribe::<AccountToggled>(
+            SCHEDULER_RELOAD,
+            Arc::new(TypedEventHandlerWrapper::<AccountToggled, _>::new(
+                scheduler_reload_handler,
+            )),
+        )
+        .await?;
+
+    if let Err(e) = event_bus.replay_pending().await {
+        warn!("Failed to replay pending domain events: {}", e);
+    }
 
     info!("✓ Event bus initialized and handlers registered");
 
@@ -323,45 +534,49 @@ pub async fn build_app_state(
 
     info!("🔧 Initializing command handlers...");
     let command_handlers = CommandHandlers {
-        create_account: Arc::new(CreateAccountCommandHandler::new(
-            account_repo.clone(),
-            event_bus.clone(),
-        )),
-        update_account: Arc::new(UpdateAccountCommandHandler::new(
-            account_repo.clone(),
-            event_bus.clone(),
-        )),
-        delete_account: Arc::new(DeleteAccountCommandHandler::new(
-            account_repo.clone(),
-            event_bus.clone(),
-        )),
-        toggle_account: Arc::new(ToggleAccountCommandHandler::new(
-            account_repo.clone(),
-            event_bus.clone(),
-        )),
+        create_account: Arc::new(
+            CreateAccountCommandHandler::new(account_repo.clone(), event_bus.clone())
+                .with_audit_log(audit_log.clone()),
+        ),
+        update_account: Arc::new(
+            UpdateAccountCommandHandler::new(account_repo.clone(), event_bus.clone())
+                .with_audit_log(audit_log.clone()),
+        ),
+        delete_account: Arc::new(
+            DeleteAccountCommandHandler::new(account_repo.clone(), event_bus.clone())
+                .with_audit_log(audit_log.clone()),
+        ),
+        toggle_account: Arc::new(
+            ToggleAccountCommandHandler::new(account_repo.clone(), event_bus.clone())
+                .with_audit_log(audit_log.clone()),
+        ),
         execute_check_in: Arc::new(
             ExecuteCheckInCommandHandler::new(
                 account_repo.clone(),
                 provider_repo.clone(),
-                proxy_config_repo.clone(),
+                proxy_routing_service.clone(),
                 provider_models_service.clone(),
                 balance_history_service.clone(),
                 waf_cookies_repo.clone(),
                 true, // headless_browser
             )
-            .with_notification_service(notification_service.clone()),
+            .with_notification_service(notification_service.clone())
+            .with_browser_pool(browser_pool.clone())
+            .with_event_bus(event_bus.clone()),
         ),
         batch_execute_check_in: Arc::new(
             BatchExecuteCheckInCommandHandler::new(
                 account_repo.clone(),
                 provider_repo.clone(),
-                proxy_config_repo.clone(),
+                proxy_routing_service.clone(),
                 provider_models_service.clone(),
                 balance_history_service.clone(),
                 waf_cookies_repo.clone(),
                 true, // headless_browser
             )
-            .with_notification_service(notification_service.clone()),
+            .with_notification_service(notification_service.clone())
+            .with_browser_pool(browser_pool.clone())
+            .with_event_bus(event_bus.clone()),
         ),
         create_notification_channel: Arc::new(CreateNotificationChannelHandler::new(
             notification_channel_repo.clone(),
@@ -375,15 +590,30 @@ pub async fn build_app_state(
         test_notification_channel: Arc::new(TestNotificationChannelHandler::new(
             notification_channel_repo.clone(),
         )),
-        create_provider: Arc::new(CreateProviderCommandHandler::new(provider_repo.clone())),
-        update_provider: Arc::new(UpdateProviderCommandHandler::new(provider_repo.clone())),
-        delete_provider: Arc::new(DeleteProviderCommandHandler::new(
-            provider_repo.clone(),
-            account_repo.clone(),
-        )),
+        create_provider: Arc::new(
+            CreateProviderCommandHandler::new(provider_repo.clone())
+                .with_audit_log(audit_log.clone()),
+        ),
+        update_provider: Arc::new(
+            UpdateProviderCommandHandler::new(provider_repo.clone())
+                .with_audit_log(audit_log.clone()),
+        ),
+        delete_provider: Arc::new(
+            DeleteProviderCommandHandler::new(provider_repo.clone(), account_repo.clone())
+                .with_audit_log(audit_log.clone()),
+        ),
     };
     info!("✓ Command handlers initialized");
 
+    let model_routing_queries = Arc::new(ModelRoutingQueryService::new(
+        account_repo.clone(),
+        provider_repo.clone(),
+        token_repo,
+        provider_models_repo,
+        event_store.clone(),
+        currency_settings_repo.clone(),
+    ));
+
     info!(
         "✅ AppState ready ({}ms)",
         startup_started_at.elapsed().as_millis()
@@ -403,16 +633,38 @@ pub async fn build_app_state(
         services: Services {
             token: token_service,
             claude_config: claude_config_service,
+            claude_profile: claude_profile_service,
             codex_config: codex_config_service,
+            codex_token_refresh,
+            codex_auto_switch,
+            codex_usage_history,
+            token_watch,
+            cli_tool_config: Arc::new(
+                CliToolConfigService::new().with_audit_log(audit_log.clone()),
+            ),
             config: config_service,
             balance: balance_service,
+            balance_history_maintenance,
             proxy_config: Arc::new(ProxyConfigService::new(proxy_config_repo.clone())),
+            proxy_routing: proxy_routing_service,
+            currency: currency_settings_service,
+            network_settings: network_settings_service,
             provider_models_query,
+            model_catalog,
+            account_cookie_import,
+            independent_key_validation,
+            browser_pool,
+            audit_log,
+            metrics_exporter,
+            diagnostics,
         },
         queries: Queries {
             account: account_queries,
             streak: streak_queries,
             balance_statistics: balance_statistics_queries,
+            balance_analytics: balance_analytics_queries,
+            event_timeline: Arc::new(EventTimelineQueryService::new(event_store)),
+            model_routing: model_routing_queries,
         },
         command_handlers,
     })
@@ -438,15 +690,17 @@ fn build_token_service(
     token_repo: Arc<dyn TokenRepository>,
     account_repo: Arc<dyn AccountRepository>,
     provider_repo: Arc<dyn ProviderRepository>,
-    proxy_config_repo: Arc<dyn ProxyConfigRepository>,
+    proxy_routing: Arc<ProxyRoutingService>,
     waf_cookies_repo: Arc<dyn WafCookiesRepository>,
+    browser_pool: Arc<BrowserPool>,
 ) -> Result<Arc<TokenService>, Box<dyn std::error::Error>> {
     info!("🔧 Initializing token services...");
     let started_at = Instant::now();
     let service = Arc::new(
-        TokenService::new(token_repo, account_repo, provider_repo, proxy_config_repo)
+        TokenService::new(token_repo, account_repo, provider_repo, proxy_routing)
             .map_err(|e| format!("Failed to initialize token service: {}", e))?
-            .with_waf_cookies_repo(waf_cookies_repo),
+            .with_waf_cookies_repo(waf_cookies_repo)
+            .with_browser_pool(browser_pool),
     );
     info!(
         "✓ Token services initialized ({}ms)",
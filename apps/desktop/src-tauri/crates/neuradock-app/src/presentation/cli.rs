@@ -0,0 +1,301 @@
+//! Command line entry point for scripting NeuraDock without the window
+//!
+//! Opens the same database as the app (set `NEURADOCK_DATA_DIR` to override
+//! the app data directory).
+
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::application::dtos::{
+    ClaudeProfileDriftDto, ClaudeProfileDto, NetworkPolicyDto, NetworkSettingsDto,
+    ProviderNetworkOverrideDto, UpdateNetworkSettingsInput,
+};
+use crate::application::services::{
+    AuditLogService, ClaudeConfigService, ClaudeProfileService, NetworkSettingsService,
+};
+use crate::presentation::bootstrap::{database_filename, DEFAULT_ENCRYPTION_PASSWORD};
+use neuradock_domain::check_in::ProviderRepository;
+use neuradock_infrastructure::persistence::repositories::{
+    SqliteAuditLogRepository, SqliteClaudeProfileRepository, SqliteNetworkSettingsRepository,
+    SqliteProviderRepository,
+};
+use neuradock_infrastructure::persistence::Database;
+use neuradock_infrastructure::security::{EncryptionService, KeyManager};
+use sqlx::SqlitePool;
+
+/// Must match `identifier` in tauri.conf.json
+const APP_IDENTIFIER: &str = "com.neuradock.app";
+
+const USAGE: &str = "Usage:
+  neuradock_cli claude-profile list
+  neuradock_cli claude-profile status
+  neuradock_cli claude-profile switch <name|id>
+  neuradock_cli claude-profile off
+  neuradock_cli network show
+  neuradock_cli network set <setting> <value> [--provider <name|id>]
+  neuradock_cli network reset
+
+Settings: waf_wait_ms, browser_launch_ms, http_request_ms, db_query_ms, max_retries,
+initial_backoff_ms, max_backoff_ms, backoff_multiplier, waf_max_attempts.
+Provider overrides accept waf_wait_ms, http_request_ms, max_retries and
+waf_max_attempts, use the value `default` to remove one.";
+
+fn app_data_dir() -> Result<PathBuf> {
+    if let Some(dir) = std::env::var_os("NEURADOCK_DATA_DIR") {
+        return Ok(PathBuf::from(dir));
+    }
+    Ok(dirs::data_dir()
+        .context("Cannot find data directory")?
+        .join(APP_IDENTIFIER))
+}
+
+async fn open_database(app_data_dir: &std::path::Path) -> Result<Arc<SqlitePool>> {
+    let db_path = app_data_dir.join(database_filename());
+    if !db_path.is_file() {
+        bail!(
+            "NeuraDock database not found at {}, start the app once or set NEURADOCK_DATA_DIR",
+            db_path.display()
+        );
+    }
+
+    let database = Database::new(db_path.to_str().context("Invalid database path")?).await?;
+    database.run_migrations().await?;
+    Ok(Arc::new(database.pool().clone()))
+}
+
+async fn claude_profile_service() -> Result<(ClaudeProfileService, Arc<AuditLogService>)> {
+    let app_data_dir = app_data_dir()?;
+    let pool = open_database(&app_data_dir).await?;
+
+    let salt = KeyManager::new(app_data_dir)
+        .initialize()
+        .map_err(|e| anyhow::anyhow!("Failed to initialize encryption salt: {}", e))?;
+    let encryption = Arc::new(
+        EncryptionService::from_password(DEFAULT_ENCRYPTION_PASSWORD, &salt)
+            .map_err(|e| anyhow::anyhow!("Failed to create encryption service: {}", e))?,
+    );
+
+    let audit_log = Arc::new(AuditLogService::new(Arc::new(
+        SqliteAuditLogRepository::new(pool.clone()),
+    )));
+    let service = ClaudeProfileService::new(
+        Arc::new(SqliteClaudeProfileRepository::new(pool, encryption)),
+        Arc::new(ClaudeConfigService::new().with_audit_log(audit_log.clone())),
+    );
+    Ok((service, audit_log))
+}
+
+fn print_profile(profile: &ClaudeProfileDto) {
+    let marker = if profile.is_active { "*" } else { " " };
+    println!(
+        "{} {:>3}  {:<20} {}  sonnet={} haiku={} opus={}",
+        marker,
+        profile.id,
+        profile.name,
+        profile.base_url,
+        profile.sonnet_model.as_deref().unwrap_or("-"),
+        profile.haiku_model.as_deref().unwrap_or("-"),
+        profile.opus_model.as_deref().unwrap_or("-"),
+    );
+}
+
+async fn run_claude_profile(args: &[String]) -> Result<()> {
+    let (service, audit_log) = claude_profile_service().await?;
+    let result = run_claude_profile_command(&service, args).await;
+    audit_log.flush().await;
+    result
+}
+
+async fn run_claude_profile_command(service: &ClaudeProfileService, args: &[String]) -> Result<()> {
+    match args {
+        [command] if command == "list" => {
+            let profiles = service.list().await?;
+            if profiles.is_empty() {
+                println!("No Claude profiles, create one in the app first");
+            }
+            profiles.iter().for_each(print_profile);
+        }
+        [command] if command == "status" => {
+            let status = service.status().await?;
+            let Some(profile) = status.active_profile else {
+                println!("No active Claude profile");
+                return Ok(());
+            };
+            println!(
+                "Active profile: {} (applied {})",
+                profile.name,
+                status.applied_at.unwrap_or_default()
+            );
+            match status.drift {
+                ClaudeProfileDriftDto::InSync | ClaudeProfileDriftDto::NoActiveProfile => {
+                    println!("settings.json matches the profile")
+                }
+                ClaudeProfileDriftDto::ProfileUpdated => {
+                    println!("Profile changed since it was applied, switch again to update")
+                }
+                ClaudeProfileDriftDto::Modified => println!(
+                    "settings.json was edited by hand: {}",
+                    status.drifted_keys.join(", ")
+                ),
+                ClaudeProfileDriftDto::SettingsMissing => println!("settings.json is missing"),
+            }
+        }
+        [command, reference] if command == "switch" => {
+            let profiles = service.list().await?;
+            let profile = profiles
+                .iter()
+                .find(|p| &p.name == reference)
+                .or_else(|| profiles.iter().find(|p| p.id.to_string() == *reference))
+                .with_context(|| format!("Claude profile not found: {}", reference))?;
+            println!("{}", service.switch(profile.id).await?);
+        }
+        [command] if command == "off" => {
+            println!("{}", service.deactivate().await?);
+        }
+        _ => bail!("{}", USAGE),
+    }
+
+    Ok(())
+}
+
+fn print_policy(policy: &NetworkPolicyDto) {
+    println!(
+        "  waf_wait_ms={} browser_launch_ms={} http_request_ms={} db_query_ms={}",
+        policy.waf_wait_ms, policy.browser_launch_ms, policy.http_request_ms, policy.db_query_ms
+    );
+    println!(
+        "  max_retries={} initial_backoff_ms={} max_backoff_ms={} backoff_multiplier={} waf_max_attempts={}",
+        policy.max_retries,
+        policy.initial_backoff_ms,
+        policy.max_backoff_ms,
+        policy.backoff_multiplier,
+        policy.waf_max_attempts
+    );
+}
+
+fn print_network_settings(settings: &NetworkSettingsDto) {
+    println!("Defaults:");
+    print_policy(&settings.defaults);
+    for provider in &settings.provider_overrides {
+        println!("{} ({}):", provider.provider_name, provider.provider_id);
+        print_policy(&provider.effective);
+    }
+}
+
+fn parse_setting<T: std::str::FromStr>(name: &str, value: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid value for {}: {}", name, value))
+}
+
+fn set_default(policy: &mut NetworkPolicyDto, name: &str, value: &str) -> Result<()> {
+    match name {
+        "waf_wait_ms" => policy.waf_wait_ms = parse_setting(name, value)?,
+        "browser_launch_ms" => policy.browser_launch_ms = parse_setting(name, value)?,
+        "http_request_ms" => policy.http_request_ms = parse_setting(name, value)?,
+        "db_query_ms" => policy.db_query_ms = parse_setting(name, value)?,
+        "max_retries" => policy.max_retries = parse_setting(name, value)?,
+        "initial_backoff_ms" => policy.initial_backoff_ms = parse_setting(name, value)?,
+        "max_backoff_ms" => policy.max_backoff_ms = parse_setting(name, value)?,
+        "backoff_multiplier" => policy.backoff_multiplier = parse_setting(name, value)?,
+        "waf_max_attempts" => policy.waf_max_attempts = parse_setting(name, value)?,
+        _ => bail!("Unknown setting: {}\n\n{}", name, USAGE),
+    }
+    Ok(())
+}
+
+fn set_override(
+    value_override: &mut ProviderNetworkOverrideDto,
+    name: &str,
+    value: &str,
+) -> Result<()> {
+    fn parse_optional<T: std::str::FromStr>(name: &str, value: &str) -> Result<Option<T>> {
+        if value == "default" {
+            return Ok(None);
+        }
+        parse_setting(name, value).map(Some)
+    }
+
+    match name {
+        "waf_wait_ms" => value_override.waf_wait_ms = parse_optional(name, value)?,
+        "http_request_ms" => value_override.http_request_ms = parse_optional(name, value)?,
+        "max_retries" => value_override.max_retries = parse_optional(name, value)?,
+        "waf_max_attempts" => value_override.waf_max_attempts = parse_optional(name, value)?,
+        _ => bail!("{} cannot be overridden per provider", name),
+    }
+    Ok(())
+}
+
+async fn run_network(args: &[String]) -> Result<()> {
+    let pool = open_database(&app_data_dir()?).await?;
+    let provider_repo = Arc::new(SqliteProviderRepository::new(pool.clone()));
+    let service = NetworkSettingsService::new(
+        Arc::new(SqliteNetworkSettingsRepository::new(pool)),
+        provider_repo.clone(),
+    );
+
+    match args {
+        [command] if command == "show" => print_network_settings(&service.get().await?),
+        [command] if command == "reset" => print_network_settings(&service.reset().await?),
+        [command, name, value, rest @ ..] if command == "set" => {
+            let settings = service.get().await?;
+            let mut input = UpdateNetworkSettingsInput {
+                defaults: settings.defaults,
+                provider_overrides: settings
+                    .provider_overrides
+                    .into_iter()
+                    .map(|provider| provider.override_values)
+                    .collect(),
+            };
+
+            match rest {
+                [] => set_default(&mut input.defaults, name, value)?,
+                [flag, reference] if flag == "--provider" => {
+                    let providers = provider_repo.find_all().await?;
+                    let provider = providers
+                        .iter()
+                        .find(|p| p.name() == reference)
+                        .or_else(|| providers.iter().find(|p| p.id().as_str() == reference))
+                        .with_context(|| format!("Provider not found: {}", reference))?;
+                    let provider_id = provider.id().as_str();
+
+                    let index = match input
+                        .provider_overrides
+                        .iter()
+                        .position(|o| o.provider_id == provider_id)
+                    {
+                        Some(index) => index,
+                        None => {
+                            input.provider_overrides.push(ProviderNetworkOverrideDto {
+                                provider_id: provider_id.to_string(),
+                                waf_wait_ms: None,
+                                http_request_ms: None,
+                                max_retries: None,
+                                waf_max_attempts: None,
+                            });
+                            input.provider_overrides.len() - 1
+                        }
+                    };
+                    set_override(&mut input.provider_overrides[index], name, value)?;
+                }
+                _ => bail!("{}", USAGE),
+            }
+
+            print_network_settings(&service.update(input).await?);
+            println!("A running app applies the change within a minute");
+        }
+        _ => bail!("{}", USAGE),
+    }
+
+    Ok(())
+}
+
+/// Run the CLI with the arguments after the program name
+pub async fn run(args: &[String]) -> Result<()> {
+    match args {
+        [group, rest @ ..] if group == "claude-profile" => run_claude_profile(rest).await,
+        [group, rest @ ..] if group == "network" => run_network(rest).await,
+        _ => bail!("{}", USAGE),
+    }
+}
@@ -1,10 +1,12 @@
 // Module declarations
 pub mod account;
 pub mod ai_chat;
+pub mod audit;
 pub mod balance;
 pub mod check_in;
 pub mod codex;
 pub mod config;
+pub mod currency;
 pub mod independent_key;
 pub mod notification;
 pub mod provider;
@@ -15,10 +17,12 @@ pub mod token;
 // Re-export all commands for easy access
 pub use account::*;
 pub use ai_chat::*;
+pub use audit::*;
 pub use balance::*;
 pub use check_in::*;
 pub use codex::*;
 pub use config::*;
+pub use currency::*;
 pub use independent_key::*;
 pub use notification::*;
 pub use provider::*;
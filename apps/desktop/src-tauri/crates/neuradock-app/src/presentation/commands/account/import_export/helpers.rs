@@ -4,7 +4,10 @@ use neuradock_domain::shared::AccountId;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::application::services::{AuditLogService, AuditSummary};
 use crate::presentation::error::CommandError;
+use neuradock_domain::account::Account;
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
 
 const DEFAULT_SESSION_EXPIRATION_DAYS: i64 = 30;
 
@@ -27,13 +30,26 @@ pub(super) async fn create_and_save_default_session(
     Ok(())
 }
 
+/// Record an imported account in the audit log
+pub(super) async fn record_account_created(audit_log: &AuditLogService, account: &Account) {
+    let mut entry = AuditEntry::new(
+        AuditActor::User,
+        AuditAction::AccountCreated,
+        account.id().as_str(),
+    )
+    .with_target_name(account.name());
+    entry.after = Some(AuditSummary::account(account).build());
+    audit_log.record(entry).await;
+}
+
 /// Helper function to import a single account
 pub(super) async fn import_single_account(
     input: crate::application::dtos::ImportAccountInput,
     account_repo: &Arc<dyn neuradock_domain::account::AccountRepository>,
     session_repo: &Arc<dyn SessionRepository>,
+    audit_log: &AuditLogService,
 ) -> Result<String, CommandError> {
-    use neuradock_domain::account::{Account, Credentials};
+    use neuradock_domain::account::Credentials;
     use neuradock_domain::shared::ProviderId;
 
     let cookies = input.cookies.clone();
@@ -52,6 +68,7 @@ pub(super) async fn import_single_account(
         .map_err(CommandError::from)?;
 
     create_and_save_default_session(account_id.clone(), &cookies, session_repo).await?;
+    record_account_created(audit_log, &account).await;
 
     Ok(account_id.as_str().to_string())
 }
@@ -63,6 +80,7 @@ pub(super) async fn update_account_cookies(
     api_user: String,
     account_repo: &Arc<dyn neuradock_domain::account::AccountRepository>,
     session_repo: &Arc<dyn SessionRepository>,
+    audit_log: &AuditLogService,
 ) -> Result<(), CommandError> {
     use neuradock_domain::account::Credentials;
 
@@ -72,6 +90,7 @@ pub(super) async fn update_account_cookies(
         .map_err(CommandError::from)?
         .ok_or_else(|| CommandError::not_found("Account not found"))?;
 
+    let before = AuditSummary::account_credentials(&account).build();
     let credentials = Credentials::new(cookies.clone(), api_user);
     account
         .update_credentials(credentials)
@@ -83,5 +102,15 @@ pub(super) async fn update_account_cookies(
 
     create_and_save_default_session(account_id.clone(), &cookies, session_repo).await?;
 
+    let mut entry = AuditEntry::new(
+        AuditActor::User,
+        AuditAction::CredentialsReplaced,
+        account_id.as_str(),
+    )
+    .with_target_name(account.name());
+    entry.before = Some(before);
+    entry.after = Some(AuditSummary::account_credentials(&account).build());
+    audit_log.record(entry).await;
+
     Ok(())
 }
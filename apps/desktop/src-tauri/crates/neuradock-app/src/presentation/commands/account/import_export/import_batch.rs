@@ -23,7 +23,14 @@ pub async fn import_accounts_batch(
 
     for input in inputs {
         let account_name = input.name.clone();
-        match import_single_account(input, &repositories.account, &repositories.session).await {
+        match import_single_account(
+            input,
+            &repositories.account,
+            &repositories.session,
+            &services.audit_log,
+        )
+        .await
+        {
             Ok(account_id) => {
                 succeeded += 1;
                 if let Err(err) = services
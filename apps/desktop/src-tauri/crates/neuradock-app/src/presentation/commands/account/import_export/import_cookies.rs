@@ -0,0 +1,56 @@
+use crate::application::dtos::{BatchUpdateResult, CookieSourceImportInput, UpdateItemResult};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::{Repositories, Services};
+use tauri::State;
+use tracing::warn;
+
+use super::update_batch::apply_batch_update;
+
+/// Import accounts from browser cookie databases, HAR or cookies.txt files
+/// Matching accounts (name+provider) are updated, others created if `create_if_not_exists`
+#[tauri::command]
+#[specta::specta]
+pub async fn import_accounts_from_cookie_sources(
+    inputs: Vec<CookieSourceImportInput>,
+    create_if_not_exists: bool,
+    repositories: State<'_, Repositories>,
+    services: State<'_, Services>,
+) -> Result<BatchUpdateResult, CommandError> {
+    let mut prepared = Vec::with_capacity(inputs.len());
+    let mut source_failures = Vec::new();
+
+    for input in inputs {
+        match services.account_cookie_import.prepare_import(&input).await {
+            Ok(import) => prepared.push(import),
+            Err(e) => {
+                warn!(
+                    target: "neuradock::import",
+                    source = ?input.source,
+                    "Failed to read cookie source {}: {}",
+                    input.path,
+                    e
+                );
+                source_failures.push(UpdateItemResult {
+                    success: false,
+                    account_id: None,
+                    account_name: input.name.unwrap_or(input.path),
+                    action: "failed".to_string(),
+                    error: Some(e.to_string()),
+                });
+            }
+        }
+    }
+
+    let mut result = apply_batch_update(
+        prepared,
+        create_if_not_exists,
+        &repositories,
+        &services.audit_log,
+    )
+    .await?;
+    result.failed += source_failures.len() as i32;
+    result.total += source_failures.len() as i32;
+    result.results.extend(source_failures);
+
+    Ok(result)
+}
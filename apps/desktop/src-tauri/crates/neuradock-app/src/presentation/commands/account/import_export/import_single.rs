@@ -5,7 +5,7 @@ use neuradock_domain::shared::ProviderId;
 use tauri::State;
 use tracing::warn;
 
-use super::helpers::create_and_save_default_session;
+use super::helpers::{create_and_save_default_session, record_account_created};
 
 /// Import a single account from JSON
 #[tauri::command]
@@ -36,6 +36,7 @@ pub async fn import_account_from_json(
         .map_err(CommandError::from)?;
 
     create_and_save_default_session(account_id.clone(), &cookies, &repositories.session).await?;
+    record_account_created(&services.audit_log, &account).await;
 
     let account_id_str = account_id.as_str().to_string();
     if let Err(err) = services
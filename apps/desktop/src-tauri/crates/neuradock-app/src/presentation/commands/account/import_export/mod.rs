@@ -1,10 +1,12 @@
 mod export;
 mod helpers;
 mod import_batch;
+mod import_cookies;
 mod import_single;
 mod update_batch;
 
 pub use export::export_accounts_to_json;
 pub use import_batch::import_accounts_batch;
+pub use import_cookies::import_accounts_from_cookie_sources;
 pub use import_single::import_account_from_json;
 pub use update_batch::update_accounts_batch;
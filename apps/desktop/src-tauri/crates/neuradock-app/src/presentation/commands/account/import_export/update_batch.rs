@@ -1,6 +1,7 @@
 use crate::application::dtos::{BatchUpdateResult, ImportAccountInput, UpdateItemResult};
+use crate::application::services::AuditLogService;
 use crate::presentation::error::CommandError;
-use crate::presentation::state::Repositories;
+use crate::presentation::state::{Repositories, Services};
 use tauri::State;
 
 use super::helpers::{import_single_account, update_account_cookies};
@@ -13,10 +14,27 @@ pub async fn update_accounts_batch(
     json_data: String,
     create_if_not_exists: bool,
     repositories: State<'_, Repositories>,
+    services: State<'_, Services>,
 ) -> Result<BatchUpdateResult, CommandError> {
     let inputs: Vec<ImportAccountInput> =
         serde_json::from_str(&json_data).map_err(CommandError::from)?;
 
+    apply_batch_update(
+        inputs,
+        create_if_not_exists,
+        &repositories,
+        &services.audit_log,
+    )
+    .await
+}
+
+/// Match inputs by name+provider, update existing accounts and optionally create missing ones
+pub(super) async fn apply_batch_update(
+    inputs: Vec<ImportAccountInput>,
+    create_if_not_exists: bool,
+    repositories: &Repositories,
+    audit_log: &AuditLogService,
+) -> Result<BatchUpdateResult, CommandError> {
     // Load all existing accounts for matching
     let existing_accounts = repositories
         .account
@@ -48,6 +66,7 @@ pub async fn update_accounts_batch(
                     input.api_user,
                     &repositories.account,
                     &repositories.session,
+                    audit_log,
                 )
                 .await
                 {
@@ -76,8 +95,13 @@ pub async fn update_accounts_batch(
             None => {
                 if create_if_not_exists {
                     // Create new account
-                    match import_single_account(input, &repositories.account, &repositories.session)
-                        .await
+                    match import_single_account(
+                        input,
+                        &repositories.account,
+                        &repositories.session,
+                        audit_log,
+                    )
+                    .await
                     {
                         Ok(account_id) => {
                             created += 1;
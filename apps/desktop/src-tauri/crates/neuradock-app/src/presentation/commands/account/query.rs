@@ -55,6 +55,22 @@ pub async fn get_account_detail(
         .into_dto())
 }
 
+/// Get an account's event history, newest first
+#[tauri::command]
+#[specta::specta]
+pub async fn get_account_event_timeline(
+    account_id: String,
+    before_sequence: Option<i64>,
+    limit: Option<u32>,
+    queries: State<'_, Queries>,
+) -> Result<dtos::AccountEventTimelineDto, CommandError> {
+    queries
+        .event_timeline
+        .account_timeline(&account_id, before_sequence, limit)
+        .await
+        .map_err(CommandError::from)
+}
+
 async fn provider_map(
     repositories: &Repositories,
 ) -> Result<HashMap<String, Provider>, neuradock_domain::shared::DomainError> {
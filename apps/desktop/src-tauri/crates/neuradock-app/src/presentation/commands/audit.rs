@@ -0,0 +1,19 @@
+use tauri::State;
+
+use crate::application::dtos::{AuditLogPageDto, AuditLogQueryInput};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+
+/// Page through the audit log, newest entries first
+#[tauri::command]
+#[specta::specta]
+pub async fn get_audit_log(
+    query: AuditLogQueryInput,
+    services: State<'_, Services>,
+) -> Result<AuditLogPageDto, CommandError> {
+    services
+        .audit_log
+        .query(query)
+        .await
+        .map_err(CommandError::from)
+}
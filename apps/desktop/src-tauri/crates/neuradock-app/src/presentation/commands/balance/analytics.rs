@@ -0,0 +1,34 @@
+use crate::application::dtos::{AccountBalanceAnalyticsDto, BalanceAnalyticsDto};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Queries;
+use tauri::State;
+
+/// Get burn rate, days-until-empty forecast and anomalies for all enabled accounts
+/// `window_days` defaults to 30 (max 365)
+#[tauri::command]
+#[specta::specta]
+pub async fn get_balance_analytics(
+    window_days: Option<u32>,
+    state: State<'_, Queries>,
+) -> Result<BalanceAnalyticsDto, CommandError> {
+    state
+        .balance_analytics
+        .get_balance_analytics(window_days)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Get balance analytics for a single account
+#[tauri::command]
+#[specta::specta]
+pub async fn get_account_balance_analytics(
+    account_id: String,
+    window_days: Option<u32>,
+    state: State<'_, Queries>,
+) -> Result<AccountBalanceAnalyticsDto, CommandError> {
+    state
+        .balance_analytics
+        .get_account_analytics(&account_id, window_days)
+        .await
+        .map_err(CommandError::from)
+}
@@ -0,0 +1,61 @@
+use crate::application::dtos::{
+    BalanceHistoryMaintenanceResultDto, BalanceHistoryRetentionDto, ExportBalanceHistoryInput,
+    UpdateBalanceHistoryRetentionInput,
+};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+use tauri::State;
+
+/// Get the balance history retention policy
+#[tauri::command]
+#[specta::specta]
+pub async fn get_balance_history_retention(
+    state: State<'_, Services>,
+) -> Result<BalanceHistoryRetentionDto, CommandError> {
+    state
+        .balance_history_maintenance
+        .get_policy()
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Update the balance history retention policy
+#[tauri::command]
+#[specta::specta]
+pub async fn update_balance_history_retention(
+    input: UpdateBalanceHistoryRetentionInput,
+    state: State<'_, Services>,
+) -> Result<BalanceHistoryRetentionDto, CommandError> {
+    state
+        .balance_history_maintenance
+        .update_policy(input)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Downsample old balance history now, regardless of the last run
+#[tauri::command]
+#[specta::specta]
+pub async fn run_balance_history_maintenance(
+    state: State<'_, Services>,
+) -> Result<Option<BalanceHistoryMaintenanceResultDto>, CommandError> {
+    state
+        .balance_history_maintenance
+        .run_maintenance(true)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Export balance history as CSV or JSON text
+#[tauri::command]
+#[specta::specta]
+pub async fn export_balance_history(
+    input: ExportBalanceHistoryInput,
+    state: State<'_, Services>,
+) -> Result<String, CommandError> {
+    state
+        .balance_history_maintenance
+        .export(input)
+        .await
+        .map_err(CommandError::from)
+}
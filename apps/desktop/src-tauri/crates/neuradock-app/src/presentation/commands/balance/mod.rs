@@ -1,8 +1,15 @@
+mod analytics;
 mod batch;
 mod fetch;
+mod history;
 mod statistics;
 
 // Re-export all commands for backward compatibility
+pub use analytics::{get_account_balance_analytics, get_balance_analytics};
 pub use batch::fetch_accounts_balances;
 pub use fetch::fetch_account_balance;
+pub use history::{
+    export_balance_history, get_balance_history_retention, run_balance_history_maintenance,
+    update_balance_history_retention,
+};
 pub use statistics::get_balance_statistics;
@@ -9,7 +9,9 @@ use tracing::{info, warn};
 
 use crate::application::dtos::{CodexAccountDto, CodexAuthInfoDto, CodexInboxCodeDto, CodexQuotaDto, CodexRateLimitWindowDto};
 use crate::presentation::error::CommandError;
-use crate::presentation::state::Repositories;
+use crate::application::services::{AuditSummary, CodexUsageHistoryService, ProxyRoutingService};
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
+use crate::presentation::state::{Repositories, Services};
 use super::quota::{apply_usage_quota, quota_to_dto};
 use neuradock_domain::codex::CodexAccountId;
 use neuradock_infrastructure::http::openai::tempmail::TempmailClient;
@@ -41,6 +43,7 @@ async fn find_linked_account(
 
 async fn sync_active_auth_quota(
     repos: &Repositories,
+    usage_history: &CodexUsageHistoryService,
     email: Option<&str>,
     account_id: Option<&str>,
     quota: &CodexUsageQuota,
@@ -50,7 +53,20 @@ async fn sync_active_auth_quota(
     };
 
     apply_usage_quota(&mut account, quota);
-    repos.codex_account.save(&account).await.map_err(map_err)
+    repos.codex_account.save(&account).await.map_err(map_err)?;
+    record_usage(usage_history, &account).await;
+    Ok(())
+}
+
+/// Append the account's current windows to the usage history, failures are only logged
+async fn record_usage(usage_history: &CodexUsageHistoryService, account: &CodexAccount) {
+    if let Err(error) = usage_history.record_account(account).await {
+        warn!(
+            "Failed to record Codex usage history for {}: {}",
+            account.email(),
+            error
+        );
+    }
 }
 
 fn map_cached_window(
@@ -104,10 +120,25 @@ fn resolve_active_auth_last_refresh(
         .unwrap_or_else(|| auth.last_refresh.clone())
 }
 
+const CODEX_USAGE_URL: &str = "https://chatgpt.com/backend-api/wham/usage";
+
+/// Proxy for Codex usage requests, optionally scoped to a stored Codex account
+async fn codex_usage_proxy(
+    proxy_routing: &ProxyRoutingService,
+    codex_account_id: Option<&str>,
+) -> Result<Option<String>, CommandError> {
+    proxy_routing
+        .resolve_for_codex(codex_account_id, CODEX_USAGE_URL)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Load the active auth.json; quota is refreshed only when `services` is given
 async fn load_active_auth(
     repos: &Repositories,
-    refresh_quota: bool,
+    services: Option<&Services>,
 ) -> Result<Option<CodexAuthInfoDto>, CommandError> {
+    let refresh_quota = services.is_some();
     let auth = CodexAuthFile::read().map_err(map_err)?;
 
     Ok(match auth {
@@ -139,10 +170,16 @@ async fn load_active_auth(
             );
 
             let (quota, quota_error) = if refresh_quota && has_tokens {
-                if let Some(tokens) = a.tokens.as_ref() {
+                if let (Some(tokens), Some(services)) = (a.tokens.as_ref(), services) {
+                    let proxy_url = codex_usage_proxy(
+                        &services.proxy_routing,
+                        linked_account.as_ref().map(|account| account.id().as_str()),
+                    )
+                    .await?;
                     match fetch_codex_usage(
                         &tokens.access_token,
                         (!tokens.account_id.is_empty()).then_some(tokens.account_id.as_str()),
+                        proxy_url.as_deref(),
                     )
                     .await
                     {
@@ -150,6 +187,7 @@ async fn load_active_auth(
                             let refreshed_at = Utc::now().to_rfc3339();
                             if let Err(error) = sync_active_auth_quota(
                                 repos,
+                                &services.codex_usage_history,
                                 email.as_deref(),
                                 (!tokens.account_id.is_empty()).then_some(tokens.account_id.as_str()),
                                 &quota,
@@ -240,8 +278,10 @@ pub async fn delete_codex_account(
 pub async fn fetch_codex_quota(
     access_token: String,
     account_id: Option<String>,
+    services: State<'_, Services>,
 ) -> Result<CodexQuotaDto, CommandError> {
-    fetch_codex_usage(&access_token, account_id.as_deref())
+    let proxy_url = codex_usage_proxy(&services.proxy_routing, None).await?;
+    fetch_codex_usage(&access_token, account_id.as_deref(), proxy_url.as_deref())
         .await
         .map(|quota| quota_to_dto(&quota))
         .map_err(map_err)
@@ -252,6 +292,7 @@ pub async fn fetch_codex_quota(
 pub async fn refresh_codex_account_quota(
     id: String,
     repos: State<'_, Repositories>,
+    services: State<'_, Services>,
 ) -> Result<CodexAccountDto, CommandError> {
     let account_id_domain = CodexAccountId::from_string(&id);
     let mut account = repos
@@ -267,10 +308,13 @@ pub async fn refresh_codex_account_quota(
         .to_string();
     let acct_id = account.account_id().map(|s| s.to_string());
 
-    match fetch_codex_usage(&access_token, acct_id.as_deref()).await {
+    let proxy_url = codex_usage_proxy(&services.proxy_routing, Some(&id)).await?;
+
+    match fetch_codex_usage(&access_token, acct_id.as_deref(), proxy_url.as_deref()).await {
         Ok(quota) => {
             apply_usage_quota(&mut account, &quota);
             repos.codex_account.save(&account).await.map_err(map_err)?;
+            record_usage(&services.codex_usage_history, &account).await;
         }
         Err(e) => return Err(CommandError::infrastructure(format!("Failed to fetch quota: {}", e))),
     }
@@ -285,15 +329,16 @@ pub async fn refresh_codex_account_quota(
 pub async fn get_active_codex_auth(
     repos: State<'_, Repositories>,
 ) -> Result<Option<CodexAuthInfoDto>, CommandError> {
-    load_active_auth(&repos, false).await
+    load_active_auth(&repos, None).await
 }
 
 #[tauri::command]
 #[specta::specta]
 pub async fn refresh_active_codex_auth_quota(
     repos: State<'_, Repositories>,
+    services: State<'_, Services>,
 ) -> Result<Option<CodexAuthInfoDto>, CommandError> {
-    load_active_auth(&repos, true).await
+    load_active_auth(&repos, Some(&services)).await
 }
 
 #[tauri::command]
@@ -335,6 +380,7 @@ pub async fn get_codex_account_inbox_code(
 pub async fn switch_codex_auth(
     account_id: String,
     repos: State<'_, Repositories>,
+    services: State<'_, Services>,
 ) -> Result<(), CommandError> {
     let acct_id = CodexAccountId::from_string(&account_id);
     let account = repos
@@ -362,21 +408,82 @@ pub async fn switch_codex_auth(
         .to_string();
 
     let auth = CodexAuthJson::chatgpt(id_token, access_token, refresh_token, openai_account_id);
+    let before = active_auth_summary();
     CodexAuthFile::write(&auth).map_err(map_err)?;
 
     info!("Switched Codex auth to account: {}", account.email());
+    record_auth_change(
+        &services,
+        AuditAction::CodexAuthSwitched,
+        Some(account.email()),
+        before,
+        Some(&auth),
+    )
+    .await;
     Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn set_codex_api_key(key: String) -> Result<(), CommandError> {
+pub async fn set_codex_api_key(
+    key: String,
+    services: State<'_, Services>,
+) -> Result<(), CommandError> {
     let auth = CodexAuthJson::api_key(key);
-    CodexAuthFile::write(&auth).map_err(map_err)
+    let before = active_auth_summary();
+    CodexAuthFile::write(&auth).map_err(map_err)?;
+    record_auth_change(
+        &services,
+        AuditAction::CodexAuthSwitched,
+        None,
+        before,
+        Some(&auth),
+    )
+    .await;
+    Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub async fn logout_codex_auth() -> Result<(), CommandError> {
-    CodexAuthFile::clear().map_err(map_err)
+pub async fn logout_codex_auth(services: State<'_, Services>) -> Result<(), CommandError> {
+    let before = active_auth_summary();
+    CodexAuthFile::clear().map_err(map_err)?;
+    record_auth_change(
+        &services,
+        AuditAction::GlobalConfigCleared,
+        None,
+        before,
+        None,
+    )
+    .await;
+    Ok(())
+}
+
+const CODEX_AUTH_AUDIT_TARGET: &str = "codex_auth";
+
+/// Masked summary of the auth.json about to be replaced
+fn active_auth_summary() -> Option<String> {
+    match CodexAuthFile::read() {
+        Ok(auth) => auth.map(|auth| AuditSummary::codex_auth(&auth).build()),
+        Err(error) => {
+            warn!("Failed to read Codex auth for the audit log: {}", error);
+            None
+        }
+    }
+}
+
+async fn record_auth_change(
+    services: &Services,
+    action: AuditAction,
+    target_name: Option<&str>,
+    before: Option<String>,
+    after: Option<&CodexAuthJson>,
+) {
+    let mut entry = AuditEntry::new(AuditActor::User, action, CODEX_AUTH_AUDIT_TARGET);
+    if let Some(name) = target_name {
+        entry = entry.with_target_name(name);
+    }
+    entry.before = before;
+    entry.after = after.map(|auth| AuditSummary::codex_auth(auth).build());
+    services.audit_log.record(entry).await;
 }
@@ -0,0 +1,61 @@
+use crate::application::dtos::{
+    CodexAutoSwitchCheckDto, CodexAutoSwitchEventDto, CodexAutoSwitchPolicyDto,
+    UpdateCodexAutoSwitchPolicyInput,
+};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+use tauri::State;
+
+/// Get the quota-aware Codex auto-switch settings
+#[tauri::command]
+#[specta::specta]
+pub async fn get_codex_auto_switch_policy(
+    state: State<'_, Services>,
+) -> Result<CodexAutoSwitchPolicyDto, CommandError> {
+    state
+        .codex_auto_switch
+        .get_policy()
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Update the quota-aware Codex auto-switch settings
+#[tauri::command]
+#[specta::specta]
+pub async fn update_codex_auto_switch_policy(
+    input: UpdateCodexAutoSwitchPolicyInput,
+    state: State<'_, Services>,
+) -> Result<CodexAutoSwitchPolicyDto, CommandError> {
+    state
+        .codex_auto_switch
+        .update_policy(input)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Check the active Codex account now and switch if it is over the threshold
+#[tauri::command]
+#[specta::specta]
+pub async fn run_codex_auto_switch_check(
+    state: State<'_, Services>,
+) -> Result<Option<CodexAutoSwitchCheckDto>, CommandError> {
+    state
+        .codex_auto_switch
+        .run_check(true)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Most recent automatic Codex account switches
+#[tauri::command]
+#[specta::specta]
+pub async fn list_codex_auto_switches(
+    limit: Option<u32>,
+    state: State<'_, Services>,
+) -> Result<Vec<CodexAutoSwitchEventDto>, CommandError> {
+    state
+        .codex_auto_switch
+        .recent_switches(limit.unwrap_or(50).min(500))
+        .await
+        .map_err(CommandError::from)
+}
@@ -1,8 +1,14 @@
 pub mod accounts;
+pub mod auto_switch;
 pub mod payment;
 pub mod quota;
 pub mod register;
+pub mod token_refresh;
+pub mod usage_history;
 
 pub use accounts::*;
+pub use auto_switch::*;
 pub use payment::*;
 pub use register::*;
+pub use token_refresh::*;
+pub use usage_history::*;
@@ -4,7 +4,7 @@ use crate::application::dtos::{
     CodexPaymentIntervalDto, CodexPaymentLinkDto, CodexPaymentLinkRequestDto, CodexPaymentPlanDto,
 };
 use crate::presentation::error::CommandError;
-use crate::presentation::state::Repositories;
+use crate::presentation::state::{Repositories, Services};
 use neuradock_domain::codex::CodexAccountId;
 use neuradock_infrastructure::http::openai::payment::{
     generate_payment_link, PaymentInterval, PaymentLinkRequest, PaymentPlan,
@@ -20,6 +20,7 @@ pub async fn generate_codex_payment_link(
     account_id: String,
     request: CodexPaymentLinkRequestDto,
     repos: State<'_, Repositories>,
+    services: State<'_, Services>,
 ) -> Result<CodexPaymentLinkDto, CommandError> {
     let account_id = CodexAccountId::from_string(&account_id);
     let account = repos
@@ -38,7 +39,13 @@ pub async fn generate_codex_payment_link(
         price_interval: request.price_interval.map(map_interval),
     };
 
-    let result = generate_payment_link(&account, &payment_request, None)
+    let proxy_url = services
+        .proxy_routing
+        .resolve_for_codex(Some(account_id.as_str()), "https://chatgpt.com")
+        .await
+        .map_err(CommandError::from)?;
+
+    let result = generate_payment_link(&account, &payment_request, proxy_url.as_deref())
         .await
         .map_err(|e| CommandError::infrastructure(format!("生成支付链接失败: {}", e)))?;
 
@@ -66,11 +66,10 @@ pub async fn register_codex_accounts(
     let semaphore = Arc::new(tokio::sync::Semaphore::new(concurrency as usize));
     let repo = repos.codex_account.clone();
     let system_proxy_url = services
-        .proxy_config
-        .get()
+        .proxy_routing
+        .resolve_for_codex(None, "https://auth.openai.com")
         .await
-        .map_err(CommandError::from)?
-        .proxy_url();
+        .map_err(CommandError::from)?;
 
     let mut tasks = vec![];
 
@@ -359,7 +358,7 @@ async fn register_single_account(
     if let Some(access_token) = account.access_token().map(|token| token.to_string()) {
         log("💳 同步额度信息...", None);
         let account_id = account.account_id().map(|id| id.to_string());
-        match fetch_codex_usage(&access_token, account_id.as_deref()).await {
+        match fetch_codex_usage(&access_token, account_id.as_deref(), proxy_url).await {
             Ok(quota) => {
                 apply_usage_quota(&mut account, &quota);
                 if let Some(window) = quota.secondary_window.as_ref() {
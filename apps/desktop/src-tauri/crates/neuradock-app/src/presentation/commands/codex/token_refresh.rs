@@ -0,0 +1,61 @@
+use crate::application::dtos::{
+    CodexTokenRefreshPolicyDto, CodexTokenRefreshReportDto, CodexTokenRefreshResultDto,
+    UpdateCodexTokenRefreshPolicyInput,
+};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+use tauri::State;
+
+/// Get the background Codex token refresh settings
+#[tauri::command]
+#[specta::specta]
+pub async fn get_codex_token_refresh_policy(
+    state: State<'_, Services>,
+) -> Result<CodexTokenRefreshPolicyDto, CommandError> {
+    state
+        .codex_token_refresh
+        .get_policy()
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Update the background Codex token refresh settings
+#[tauri::command]
+#[specta::specta]
+pub async fn update_codex_token_refresh_policy(
+    input: UpdateCodexTokenRefreshPolicyInput,
+    state: State<'_, Services>,
+) -> Result<CodexTokenRefreshPolicyDto, CommandError> {
+    state
+        .codex_token_refresh
+        .update_policy(input)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Refresh every Codex account whose token is about to expire, even if disabled
+#[tauri::command]
+#[specta::specta]
+pub async fn run_codex_token_refresh(
+    state: State<'_, Services>,
+) -> Result<Option<CodexTokenRefreshReportDto>, CommandError> {
+    state
+        .codex_token_refresh
+        .run_due(true)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Refresh the tokens of one Codex account now
+#[tauri::command]
+#[specta::specta]
+pub async fn refresh_codex_account_tokens(
+    account_id: String,
+    state: State<'_, Services>,
+) -> Result<CodexTokenRefreshResultDto, CommandError> {
+    state
+        .codex_token_refresh
+        .refresh_account(&account_id)
+        .await
+        .map_err(CommandError::from)
+}
@@ -0,0 +1,59 @@
+use crate::application::dtos::{
+    CodexUsageHistoryDto, CodexUsageReminderSettingsDto, CodexUsageResetDto,
+};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+use tauri::State;
+
+/// Usage of a Codex account's rate-limit windows over the last `days` days
+#[tauri::command]
+#[specta::specta]
+pub async fn get_codex_usage_history(
+    account_id: String,
+    days: Option<u32>,
+    state: State<'_, Services>,
+) -> Result<CodexUsageHistoryDto, CommandError> {
+    state
+        .codex_usage_history
+        .usage_history(&account_id, days)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Latest usage of every Codex rate-limit window and the time left until it resets
+#[tauri::command]
+#[specta::specta]
+pub async fn get_codex_usage_resets(
+    state: State<'_, Services>,
+) -> Result<Vec<CodexUsageResetDto>, CommandError> {
+    state
+        .codex_usage_history
+        .reset_overview()
+        .await
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_codex_usage_reminder_settings(
+    state: State<'_, Services>,
+) -> Result<CodexUsageReminderSettingsDto, CommandError> {
+    state
+        .codex_usage_history
+        .get_settings()
+        .await
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_codex_usage_reminder_settings(
+    input: CodexUsageReminderSettingsDto,
+    state: State<'_, Services>,
+) -> Result<CodexUsageReminderSettingsDto, CommandError> {
+    state
+        .codex_usage_history
+        .update_settings(input)
+        .await
+        .map_err(CommandError::from)
+}
@@ -1,4 +1,10 @@
-use crate::application::services::LogLevel;
+use crate::application::dtos::{
+    LocaleSettingsDto, MetricsExporterSettingsDto, MetricsExporterStatusDto, NetworkSettingsDto,
+    UpdateNetworkSettingsInput,
+};
+use crate::application::services::{
+    current_locale, normalize_locale, ConfigService, LogLevel, SUPPORTED_LOCALES,
+};
 use crate::presentation::error::CommandError;
 use crate::presentation::state::Services;
 use tauri::State;
@@ -34,3 +40,87 @@ pub async fn set_log_level(level: String, state: State<'_, Services>) -> Result<
         .map_err(|e| CommandError::infrastructure(format!("Failed to save log level: {}", e)))?;
     Ok(())
 }
+
+/// Get the Prometheus metrics exporter settings and whether it is running
+#[tauri::command]
+#[specta::specta]
+pub async fn get_metrics_exporter_status(
+    state: State<'_, Services>,
+) -> Result<MetricsExporterStatusDto, CommandError> {
+    Ok(state.metrics_exporter.status().await)
+}
+
+/// Enable, disable or move the localhost `/metrics` endpoint, applied immediately
+#[tauri::command]
+#[specta::specta]
+pub async fn update_metrics_exporter_settings(
+    settings: MetricsExporterSettingsDto,
+    state: State<'_, Services>,
+) -> Result<MetricsExporterStatusDto, CommandError> {
+    Ok(state.metrics_exporter.update_settings(settings).await?)
+}
+
+/// Get timeouts, retry policy, per-provider overrides and allowed ranges
+#[tauri::command]
+#[specta::specta]
+pub async fn get_network_settings(
+    state: State<'_, Services>,
+) -> Result<NetworkSettingsDto, CommandError> {
+    Ok(state.network_settings.get().await?)
+}
+
+/// Replace timeouts, retry policy and per-provider overrides, applied to the next request
+#[tauri::command]
+#[specta::specta]
+pub async fn update_network_settings(
+    input: UpdateNetworkSettingsInput,
+    state: State<'_, Services>,
+) -> Result<NetworkSettingsDto, CommandError> {
+    Ok(state.network_settings.update(input).await?)
+}
+
+/// Restore the default timeouts and retry policy and drop provider overrides
+#[tauri::command]
+#[specta::specta]
+pub async fn reset_network_settings(
+    state: State<'_, Services>,
+) -> Result<NetworkSettingsDto, CommandError> {
+    Ok(state.network_settings.reset().await?)
+}
+
+fn locale_settings(config: &ConfigService) -> LocaleSettingsDto {
+    LocaleSettingsDto {
+        locale: current_locale().to_string(),
+        persisted: config.get_locale().is_some(),
+        available_locales: SUPPORTED_LOCALES.iter().map(|l| l.to_string()).collect(),
+    }
+}
+
+/// Get the locale backend notifications are translated to
+#[tauri::command]
+#[specta::specta]
+pub async fn get_locale(state: State<'_, Services>) -> Result<LocaleSettingsDto, CommandError> {
+    Ok(locale_settings(&state.config))
+}
+
+/// Set and persist the backend locale, called when the UI language changes
+#[tauri::command]
+#[specta::specta]
+pub async fn set_locale(
+    locale: String,
+    state: State<'_, Services>,
+) -> Result<LocaleSettingsDto, CommandError> {
+    let locale = normalize_locale(&locale).ok_or_else(|| {
+        CommandError::validation(format!(
+            "Unsupported locale: {}. Must be one of: {}",
+            locale,
+            SUPPORTED_LOCALES.join(", ")
+        ))
+    })?;
+
+    state
+        .config
+        .set_locale(locale)
+        .map_err(|e| CommandError::infrastructure(format!("Failed to save locale: {}", e)))?;
+    Ok(locale_settings(&state.config))
+}
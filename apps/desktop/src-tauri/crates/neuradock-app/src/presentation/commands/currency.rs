@@ -0,0 +1,28 @@
+use tauri::State;
+
+use crate::application::dtos::{CurrencySettingsDto, UpdateCurrencySettingsInput};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+
+/// Get display currency, exchange rates and per-provider balance units
+#[tauri::command]
+#[specta::specta]
+pub async fn get_currency_settings(
+    state: State<'_, Services>,
+) -> Result<CurrencySettingsDto, CommandError> {
+    state.currency.get().await.map_err(CommandError::from)
+}
+
+/// Update display currency, exchange rates and per-provider balance units
+#[tauri::command]
+#[specta::specta]
+pub async fn update_currency_settings(
+    input: UpdateCurrencySettingsInput,
+    state: State<'_, Services>,
+) -> Result<CurrencySettingsDto, CommandError> {
+    state
+        .currency
+        .update(input)
+        .await
+        .map_err(CommandError::from)
+}
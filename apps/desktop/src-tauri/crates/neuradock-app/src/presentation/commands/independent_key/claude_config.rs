@@ -1,8 +1,8 @@
 use tauri::State;
 
-use crate::application::services::token::ClaudeConfigService;
+use crate::application::services::token::{ClaudeConfigService, ShellDialect};
 use crate::presentation::error::CommandError;
-use crate::presentation::state::Repositories;
+use crate::presentation::state::{Repositories, Services};
 use neuradock_domain::independent_key::IndependentKeyId;
 
 /// Configure independent API key to Claude Code globally
@@ -12,6 +12,7 @@ pub async fn configure_independent_key_claude(
     key_id: i64,
     model: Option<String>,
     repositories: State<'_, Repositories>,
+    services: State<'_, Services>,
 ) -> Result<String, CommandError> {
     let id = IndependentKeyId::new(key_id);
 
@@ -30,9 +31,9 @@ pub async fn configure_independent_key_claude(
         ));
     }
 
-    // Call Claude config service directly with API key
-    let service = ClaudeConfigService::new();
-    service
+    // Call the shared Claude config service so the change is audited
+    services
+        .claude_config
         .configure_global_with_key(key.api_key(), key.base_url(), model.as_deref())
         .map_err(CommandError::from)
 }
@@ -43,6 +44,7 @@ pub async fn configure_independent_key_claude(
 pub async fn generate_independent_key_claude_temp(
     key_id: i64,
     model: Option<String>,
+    shell: Option<ShellDialect>,
     repositories: State<'_, Repositories>,
 ) -> Result<String, CommandError> {
     let id = IndependentKeyId::new(key_id);
@@ -58,6 +60,11 @@ pub async fn generate_independent_key_claude_temp(
     // Generate temp commands
     let service = ClaudeConfigService::new();
     service
-        .generate_temp_commands_with_key(key.api_key(), key.base_url(), model.as_deref())
+        .generate_temp_commands_with_key(
+            key.api_key(),
+            key.base_url(),
+            model.as_deref(),
+            shell.unwrap_or_default(),
+        )
         .map_err(CommandError::from)
 }
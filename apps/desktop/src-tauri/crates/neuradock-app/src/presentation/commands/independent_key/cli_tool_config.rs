@@ -0,0 +1,39 @@
+use tauri::State;
+
+use crate::application::services::token::CliTool;
+use crate::presentation::error::CommandError;
+use crate::presentation::state::{Repositories, Services};
+use neuradock_domain::independent_key::IndependentKeyId;
+
+/// Configure independent API key to Gemini CLI, opencode, aider or Continue globally
+#[tauri::command]
+#[specta::specta]
+pub async fn configure_independent_key_cli_tool(
+    key_id: i64,
+    tool: CliTool,
+    model: Option<String>,
+    services: State<'_, Services>,
+    repositories: State<'_, Repositories>,
+) -> Result<String, CommandError> {
+    let id = IndependentKeyId::new(key_id);
+
+    // Get the independent key
+    let key = repositories
+        .independent_key
+        .find_by_id(&id)
+        .await
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::not_found(format!("Key with ID {} not found", key_id)))?;
+
+    // Check if key is active
+    if !key.is_active() {
+        return Err(CommandError::validation(
+            "Cannot configure inactive API key. Please enable it first.",
+        ));
+    }
+
+    services
+        .cli_tool_config
+        .configure_global_with_key(tool, key.api_key(), key.base_url(), model.as_deref())
+        .map_err(CommandError::from)
+}
@@ -1,11 +1,15 @@
 mod claude_config;
+mod cli_tool_config;
 mod codex_config;
 mod crud;
+mod validation;
 
 // Re-export all commands for backward compatibility
 pub use claude_config::{configure_independent_key_claude, generate_independent_key_claude_temp};
+pub use cli_tool_config::configure_independent_key_cli_tool;
 pub use codex_config::{configure_independent_key_codex, generate_independent_key_codex_temp};
 pub use crud::{
     create_independent_key, delete_independent_key, get_all_independent_keys,
     get_independent_key_by_id, toggle_independent_key, update_independent_key,
 };
+pub use validation::validate_independent_key;
@@ -0,0 +1,19 @@
+use tauri::State;
+
+use crate::application::dtos::IndependentKeyDto;
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+
+/// Check a key against its provider's models endpoint and store the result
+#[tauri::command]
+#[specta::specta]
+pub async fn validate_independent_key(
+    key_id: i64,
+    services: State<'_, Services>,
+) -> Result<IndependentKeyDto, CommandError> {
+    services
+        .independent_key_validation
+        .validate(key_id)
+        .await
+        .map_err(CommandError::from)
+}
@@ -1,8 +1,16 @@
 use tauri::State;
+use tracing::warn;
 
-use crate::application::dtos::{ProxyConfigDto, UpdateProxyConfigInput};
+use crate::application::dtos::{
+    ProxyConfigDto, ProxyConnectivityResultDto, ProxyOverrideDto, ProxyPoolDto, SaveProxyPoolInput,
+    SetProxyOverrideInput, TestProxyConnectivityInput, UpdateProxyConfigInput,
+};
+use crate::application::services::AuditSummary;
 use crate::presentation::error::CommandError;
 use crate::presentation::state::Services;
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
+
+const PROXY_AUDIT_TARGET: &str = "proxy";
 
 /// Get current proxy configuration
 #[tauri::command]
@@ -18,9 +26,210 @@ pub async fn update_proxy_config(
     input: UpdateProxyConfigInput,
     state: State<'_, Services>,
 ) -> Result<ProxyConfigDto, CommandError> {
-    state
+    let before = state.proxy_config.get().await.ok();
+    let config = state
         .proxy_config
         .update(input)
         .await
+        .map_err(CommandError::from)?;
+
+    record_proxy_change(
+        &state,
+        PROXY_AUDIT_TARGET.to_string(),
+        None,
+        before.map(|config| AuditSummary::proxy_config(&config)),
+        Some(AuditSummary::proxy_config(&config)),
+    )
+    .await;
+    Ok(config)
+}
+
+/// List proxy pools
+#[tauri::command]
+#[specta::specta]
+pub async fn list_proxy_pools(
+    state: State<'_, Services>,
+) -> Result<Vec<ProxyPoolDto>, CommandError> {
+    state
+        .proxy_routing
+        .list_pools()
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Create or update a proxy pool
+#[tauri::command]
+#[specta::specta]
+pub async fn save_proxy_pool(
+    input: SaveProxyPoolInput,
+    state: State<'_, Services>,
+) -> Result<ProxyPoolDto, CommandError> {
+    let before = match &input.id {
+        Some(id) => find_pool(&state, id).await,
+        None => None,
+    };
+    let pool = state
+        .proxy_routing
+        .save_pool(input)
+        .await
+        .map_err(CommandError::from)?;
+
+    record_proxy_change(
+        &state,
+        pool_target(&pool.id),
+        Some(&pool.name),
+        before.as_ref().map(AuditSummary::proxy_pool),
+        Some(AuditSummary::proxy_pool(&pool)),
+    )
+    .await;
+    Ok(pool)
+}
+
+/// Delete a proxy pool that no override uses
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_proxy_pool(
+    pool_id: String,
+    state: State<'_, Services>,
+) -> Result<(), CommandError> {
+    let before = find_pool(&state, &pool_id).await;
+    state
+        .proxy_routing
+        .delete_pool(&pool_id)
+        .await
+        .map_err(CommandError::from)?;
+
+    record_proxy_change(
+        &state,
+        pool_target(&pool_id),
+        before.as_ref().map(|pool| pool.name.as_str()),
+        before.as_ref().map(AuditSummary::proxy_pool),
+        None,
+    )
+    .await;
+    Ok(())
+}
+
+/// List per-account and per-provider proxy overrides
+#[tauri::command]
+#[specta::specta]
+pub async fn list_proxy_overrides(
+    state: State<'_, Services>,
+) -> Result<Vec<ProxyOverrideDto>, CommandError> {
+    state
+        .proxy_routing
+        .list_overrides()
+        .await
         .map_err(CommandError::from)
 }
+
+/// Set the proxy route for an account or provider
+#[tauri::command]
+#[specta::specta]
+pub async fn set_proxy_override(
+    input: SetProxyOverrideInput,
+    state: State<'_, Services>,
+) -> Result<ProxyOverrideDto, CommandError> {
+    let before = find_override(&state, &input.scope, &input.scope_id).await;
+    let proxy_override = state
+        .proxy_routing
+        .set_override(input)
+        .await
+        .map_err(CommandError::from)?;
+
+    record_proxy_change(
+        &state,
+        override_target(&proxy_override.scope, &proxy_override.scope_id),
+        None,
+        before.as_ref().map(AuditSummary::proxy_override),
+        Some(AuditSummary::proxy_override(&proxy_override)),
+    )
+    .await;
+    Ok(proxy_override)
+}
+
+/// Remove a proxy override so the scope falls back to the global proxy
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_proxy_override(
+    scope: String,
+    scope_id: String,
+    state: State<'_, Services>,
+) -> Result<(), CommandError> {
+    let before = find_override(&state, &scope, &scope_id).await;
+    state
+        .proxy_routing
+        .delete_override(&scope, &scope_id)
+        .await
+        .map_err(CommandError::from)?;
+
+    record_proxy_change(
+        &state,
+        override_target(&scope, &scope_id),
+        None,
+        before.as_ref().map(AuditSummary::proxy_override),
+        None,
+    )
+    .await;
+    Ok(())
+}
+
+/// Test connectivity through the resolved proxy or every endpoint of a pool
+#[tauri::command]
+#[specta::specta]
+pub async fn test_proxy_connectivity(
+    input: TestProxyConnectivityInput,
+    state: State<'_, Services>,
+) -> Result<Vec<ProxyConnectivityResultDto>, CommandError> {
+    state
+        .proxy_routing
+        .test_connectivity(input)
+        .await
+        .map_err(CommandError::from)
+}
+
+fn pool_target(pool_id: &str) -> String {
+    format!("{}:pool:{}", PROXY_AUDIT_TARGET, pool_id)
+}
+
+fn override_target(scope: &str, scope_id: &str) -> String {
+    format!("{}:{}:{}", PROXY_AUDIT_TARGET, scope, scope_id)
+}
+
+async fn find_pool(state: &Services, pool_id: &str) -> Option<ProxyPoolDto> {
+    match state.proxy_routing.list_pools().await {
+        Ok(pools) => pools.into_iter().find(|pool| pool.id == pool_id),
+        Err(e) => {
+            warn!("Failed to read proxy pools for the audit log: {}", e);
+            None
+        }
+    }
+}
+
+async fn find_override(state: &Services, scope: &str, scope_id: &str) -> Option<ProxyOverrideDto> {
+    match state.proxy_routing.list_overrides().await {
+        Ok(overrides) => overrides
+            .into_iter()
+            .find(|o| o.scope == scope && o.scope_id == scope_id),
+        Err(e) => {
+            warn!("Failed to read proxy overrides for the audit log: {}", e);
+            None
+        }
+    }
+}
+
+async fn record_proxy_change(
+    state: &Services,
+    target: String,
+    target_name: Option<&str>,
+    before: Option<AuditSummary>,
+    after: Option<AuditSummary>,
+) {
+    let mut entry = AuditEntry::new(AuditActor::User, AuditAction::ProxyConfigUpdated, target);
+    if let Some(name) = target_name {
+        entry = entry.with_target_name(name);
+    }
+    entry.before = before.map(AuditSummary::build);
+    entry.after = after.map(AuditSummary::build);
+    state.audit_log.record(entry).await;
+}
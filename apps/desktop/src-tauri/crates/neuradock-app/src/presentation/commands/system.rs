@@ -1,7 +1,9 @@
+use crate::application::dtos::{BrowserPoolMetricsDto, DiagnosticsExportDto};
 use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
 use neuradock_infrastructure::logging::{log_from_frontend as log_fe, FrontendLog};
 
-use tauri::Manager;
+use tauri::{Manager, State};
 use tauri_plugin_opener::OpenerExt;
 
 /// Get application version information
@@ -56,3 +58,27 @@ pub async fn open_log_dir(app: tauri::AppHandle) -> Result<String, CommandError>
 
     Ok(log_dir.display().to_string())
 }
+
+/// Export a diagnostics zip for bug reports, secrets masked
+///
+/// Written to `destination` when given, otherwise to the app data `diagnostics` directory.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_diagnostics(
+    destination: Option<String>,
+    services: State<'_, Services>,
+) -> Result<DiagnosticsExportDto, CommandError> {
+    let destination = destination
+        .filter(|path| !path.trim().is_empty())
+        .map(std::path::PathBuf::from);
+    Ok(services.diagnostics.export(destination).await?)
+}
+
+/// Usage counters of the shared WAF bypass browser pool
+#[tauri::command]
+#[specta::specta]
+pub async fn get_browser_pool_metrics(
+    services: State<'_, Services>,
+) -> Result<BrowserPoolMetricsDto, CommandError> {
+    Ok(services.browser_pool.metrics().await.into())
+}
@@ -1,6 +1,9 @@
+use super::model_check::{config_error, provider_catalog};
+use crate::application::services::token::ShellDialect;
 use crate::presentation::error::CommandError;
-use crate::presentation::state::Services;
+use crate::presentation::state::{Repositories, Services};
 use neuradock_domain::shared::AccountId;
+use neuradock_domain::token::TokenWatchTool;
 use tauri::State;
 
 #[tauri::command]
@@ -11,6 +14,7 @@ pub async fn configure_claude_global(
     base_url: String,
     model: Option<String>,
     services: State<'_, Services>,
+    repositories: State<'_, Repositories>,
 ) -> Result<String, CommandError> {
     let account_id = AccountId::from_string(&account_id);
     let token_id = neuradock_domain::token::TokenId::new(token_id);
@@ -27,11 +31,21 @@ pub async fn configure_claude_global(
         .find(|t| t.id() == &token_id)
         .ok_or_else(|| CommandError::not_found("Token not found"))?;
 
+    let catalog = provider_catalog(&account_id, &services, &repositories).await?;
+
     // Configure to Claude Code
     let result = services
         .claude_config
-        .configure_global(token, &base_url, model.as_deref())
-        .map_err(CommandError::from)?;
+        .configure_global(token, &base_url, model.as_deref(), &catalog)
+        .map_err(config_error)?;
+
+    if let Err(e) = services
+        .token_watch
+        .track(TokenWatchTool::Claude, token)
+        .await
+    {
+        log::warn!("Failed to watch configured Claude Code token: {}", e);
+    }
 
     Ok(result)
 }
@@ -43,7 +57,9 @@ pub async fn generate_claude_temp_commands(
     account_id: String,
     base_url: String,
     model: Option<String>,
+    shell: Option<ShellDialect>,
     services: State<'_, Services>,
+    repositories: State<'_, Repositories>,
 ) -> Result<String, CommandError> {
     let account_id = AccountId::from_string(&account_id);
     let token_id = neuradock_domain::token::TokenId::new(token_id);
@@ -60,11 +76,19 @@ pub async fn generate_claude_temp_commands(
         .find(|t| t.id() == &token_id)
         .ok_or_else(|| CommandError::not_found("Token not found"))?;
 
+    let catalog = provider_catalog(&account_id, &services, &repositories).await?;
+
     // Generate temp commands
     let commands = services
         .claude_config
-        .generate_temp_commands(token, &base_url, model.as_deref())
-        .map_err(CommandError::from)?;
+        .generate_temp_commands(
+            token,
+            &base_url,
+            model.as_deref(),
+            &catalog,
+            shell.unwrap_or_default(),
+        )
+        .map_err(config_error)?;
 
     Ok(commands)
 }
@@ -72,10 +96,16 @@ pub async fn generate_claude_temp_commands(
 #[tauri::command]
 #[specta::specta]
 pub async fn clear_claude_global(services: State<'_, Services>) -> Result<String, CommandError> {
-    services
+    let result = services
         .claude_config
         .clear_global()
-        .map_err(CommandError::from)
+        .map_err(CommandError::from)?;
+
+    if let Err(e) = services.token_watch.untrack(TokenWatchTool::Claude).await {
+        log::warn!("Failed to stop watching Claude Code token: {}", e);
+    }
+
+    Ok(result)
 }
 
 /// Check if models are compatible with Claude Code
@@ -0,0 +1,130 @@
+use tauri::State;
+
+use crate::application::dtos::{
+    ClaudeProfileDto, ClaudeProfileStatusDto, ConfigBackupDto, SaveClaudeProfileInput,
+};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+
+/// List Claude settings profiles
+#[tauri::command]
+#[specta::specta]
+pub async fn list_claude_profiles(
+    services: State<'_, Services>,
+) -> Result<Vec<ClaudeProfileDto>, CommandError> {
+    services
+        .claude_profile
+        .list()
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Create a Claude settings profile
+#[tauri::command]
+#[specta::specta]
+pub async fn create_claude_profile(
+    input: SaveClaudeProfileInput,
+    services: State<'_, Services>,
+) -> Result<ClaudeProfileDto, CommandError> {
+    services
+        .claude_profile
+        .create(input)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Update a Claude settings profile, switch again to apply it
+#[tauri::command]
+#[specta::specta]
+pub async fn update_claude_profile(
+    profile_id: i64,
+    input: SaveClaudeProfileInput,
+    services: State<'_, Services>,
+) -> Result<ClaudeProfileDto, CommandError> {
+    services
+        .claude_profile
+        .update(profile_id, input)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Delete a Claude settings profile
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_claude_profile(
+    profile_id: i64,
+    services: State<'_, Services>,
+) -> Result<(), CommandError> {
+    services
+        .claude_profile
+        .delete(profile_id)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Write a profile to ~/.claude/settings.json and mark it active
+#[tauri::command]
+#[specta::specta]
+pub async fn switch_claude_profile(
+    profile_id: i64,
+    services: State<'_, Services>,
+) -> Result<String, CommandError> {
+    services
+        .claude_profile
+        .switch(profile_id)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Remove the active profile's settings from ~/.claude/settings.json
+#[tauri::command]
+#[specta::specta]
+pub async fn deactivate_claude_profile(
+    services: State<'_, Services>,
+) -> Result<String, CommandError> {
+    services
+        .claude_profile
+        .deactivate()
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Active profile and whether settings.json was edited since it was applied
+#[tauri::command]
+#[specta::specta]
+pub async fn get_claude_profile_status(
+    services: State<'_, Services>,
+) -> Result<ClaudeProfileStatusDto, CommandError> {
+    services
+        .claude_profile
+        .status()
+        .await
+        .map_err(CommandError::from)
+}
+
+/// List settings.json backups, newest first
+#[tauri::command]
+#[specta::specta]
+pub async fn list_claude_config_backups(
+    services: State<'_, Services>,
+) -> Result<Vec<ConfigBackupDto>, CommandError> {
+    let backups = services
+        .claude_config
+        .list_backups()
+        .map_err(CommandError::from)?;
+
+    Ok(backups.iter().map(ConfigBackupDto::from).collect())
+}
+
+/// Restore a settings.json backup, or undo the last change when no ID is given
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_claude_config_backup(
+    backup_id: Option<String>,
+    services: State<'_, Services>,
+) -> Result<String, CommandError> {
+    services
+        .claude_config
+        .restore_backup(backup_id.as_deref())
+        .map_err(CommandError::from)
+}
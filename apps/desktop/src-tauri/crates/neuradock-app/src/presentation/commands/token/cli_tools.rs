@@ -0,0 +1,96 @@
+use crate::application::dtos::ConfigBackupDto;
+use crate::application::services::token::CliTool;
+use crate::presentation::error::CommandError;
+use crate::presentation::state::{Repositories, Services};
+use neuradock_domain::shared::{AccountId, ProviderId};
+use tauri::State;
+
+/// Configure Gemini CLI, opencode, aider or Continue with a provider token
+#[tauri::command]
+#[specta::specta]
+#[allow(clippy::too_many_arguments)]
+pub async fn configure_cli_tool_global(
+    tool: CliTool,
+    token_id: i64,
+    account_id: String,
+    provider_id: String,
+    base_url: String,
+    model: Option<String>,
+    services: State<'_, Services>,
+    repositories: State<'_, Repositories>,
+) -> Result<String, CommandError> {
+    let account_id = AccountId::from_string(&account_id);
+    let token_id = neuradock_domain::token::TokenId::new(token_id);
+
+    // Get token from cache
+    let tokens = services
+        .token
+        .get_cached_tokens(&account_id)
+        .await
+        .map_err(CommandError::from)?;
+
+    let token = tokens
+        .iter()
+        .find(|t| t.id() == &token_id)
+        .ok_or_else(|| CommandError::not_found("Token not found"))?;
+
+    let provider_id_obj = ProviderId::from_string(&provider_id);
+    let provider = repositories
+        .provider
+        .find_by_id(&provider_id_obj)
+        .await
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::not_found(format!("Provider not found: {}", provider_id)))?;
+
+    services
+        .cli_tool_config
+        .configure_global(
+            tool,
+            token,
+            provider.id().as_str(),
+            provider.name(),
+            &base_url,
+            model.as_deref(),
+        )
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn clear_cli_tool_global(
+    tool: CliTool,
+    services: State<'_, Services>,
+) -> Result<String, CommandError> {
+    services
+        .cli_tool_config
+        .clear_global(tool)
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_cli_tool_config_backups(
+    tool: CliTool,
+    services: State<'_, Services>,
+) -> Result<Vec<ConfigBackupDto>, CommandError> {
+    let backups = services
+        .cli_tool_config
+        .list_backups(tool)
+        .map_err(CommandError::from)?;
+
+    Ok(backups.iter().map(ConfigBackupDto::from).collect())
+}
+
+/// Restore a tool's config backup, or undo the latest change when no id is given
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_cli_tool_config_backup(
+    tool: CliTool,
+    backup_id: Option<String>,
+    services: State<'_, Services>,
+) -> Result<String, CommandError> {
+    services
+        .cli_tool_config
+        .restore_backup(tool, backup_id.as_deref())
+        .map_err(CommandError::from)
+}
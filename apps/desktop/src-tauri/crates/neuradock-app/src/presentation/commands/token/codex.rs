@@ -1,6 +1,10 @@
+use super::model_check::config_error;
+use crate::application::dtos::ConfigBackupDto;
+use crate::application::services::token::ShellDialect;
 use crate::presentation::error::CommandError;
 use crate::presentation::state::{Repositories, Services};
 use neuradock_domain::shared::{AccountId, ProviderId};
+use neuradock_domain::token::TokenWatchTool;
 use tauri::State;
 
 #[tauri::command]
@@ -37,6 +41,12 @@ pub async fn configure_codex_global(
         .map_err(CommandError::from)?
         .ok_or_else(|| CommandError::not_found(format!("Provider not found: {}", provider_id)))?;
 
+    let catalog = services
+        .provider_models_query
+        .get_cached(provider.id().as_str())
+        .await
+        .map_err(CommandError::from)?;
+
     // Configure to Codex
     let result = services
         .codex_config
@@ -46,20 +56,31 @@ pub async fn configure_codex_global(
             provider.name(),
             &base_url,
             model.as_deref(),
+            &catalog,
         )
-        .map_err(CommandError::from)?;
+        .map_err(config_error)?;
+
+    if let Err(e) = services
+        .token_watch
+        .track(TokenWatchTool::Codex, token)
+        .await
+    {
+        log::warn!("Failed to watch configured Codex token: {}", e);
+    }
 
     Ok(result)
 }
 
 #[tauri::command]
 #[specta::specta]
+#[allow(clippy::too_many_arguments)]
 pub async fn generate_codex_temp_commands(
     token_id: i64,
     account_id: String,
     provider_id: String,
     base_url: String,
     model: Option<String>,
+    shell: Option<ShellDialect>,
     services: State<'_, Services>,
     repositories: State<'_, Repositories>,
 ) -> Result<String, CommandError> {
@@ -86,6 +107,12 @@ pub async fn generate_codex_temp_commands(
         .map_err(CommandError::from)?
         .ok_or_else(|| CommandError::not_found(format!("Provider not found: {}", provider_id)))?;
 
+    let catalog = services
+        .provider_models_query
+        .get_cached(provider.id().as_str())
+        .await
+        .map_err(CommandError::from)?;
+
     // Generate temp commands
     let commands = services
         .codex_config
@@ -95,8 +122,10 @@ pub async fn generate_codex_temp_commands(
             provider.name(),
             &base_url,
             model.as_deref(),
+            &catalog,
+            shell.unwrap_or_default(),
         )
-        .map_err(CommandError::from)?;
+        .map_err(config_error)?;
 
     Ok(commands)
 }
@@ -104,8 +133,40 @@ pub async fn generate_codex_temp_commands(
 #[tauri::command]
 #[specta::specta]
 pub async fn clear_codex_global(services: State<'_, Services>) -> Result<String, CommandError> {
-    services
+    let result = services
         .codex_config
         .clear_global()
+        .map_err(CommandError::from)?;
+
+    if let Err(e) = services.token_watch.untrack(TokenWatchTool::Codex).await {
+        log::warn!("Failed to stop watching Codex token: {}", e);
+    }
+
+    Ok(result)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_codex_config_backups(
+    services: State<'_, Services>,
+) -> Result<Vec<ConfigBackupDto>, CommandError> {
+    let backups = services
+        .codex_config
+        .list_backups()
+        .map_err(CommandError::from)?;
+
+    Ok(backups.iter().map(ConfigBackupDto::from).collect())
+}
+
+/// Restore a Codex config backup, or undo the latest change when no id is given
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_codex_config_backup(
+    backup_id: Option<String>,
+    services: State<'_, Services>,
+) -> Result<String, CommandError> {
+    services
+        .codex_config
+        .restore_backup(backup_id.as_deref())
         .map_err(CommandError::from)
 }
@@ -2,6 +2,7 @@ use crate::application::dtos::TokenDto;
 use crate::presentation::error::CommandError;
 use crate::presentation::state::{Repositories, Services};
 use neuradock_domain::shared::AccountId;
+use neuradock_domain::token::ApiToken;
 use tauri::State;
 
 #[tauri::command]
@@ -31,10 +32,19 @@ pub async fn fetch_account_tokens(
 
     log::info!("Fetched {} tokens for account {}", tokens.len(), account_id);
 
+    token_dtos(&repositories, &account_id, &tokens).await
+}
+
+/// Fill token DTOs with the account and provider names
+pub(super) async fn token_dtos(
+    repositories: &Repositories,
+    account_id: &AccountId,
+    tokens: &[ApiToken],
+) -> Result<Vec<TokenDto>, CommandError> {
     // Get account info to fill DTO
     let account = repositories
         .account
-        .find_by_id(&account_id)
+        .find_by_id(account_id)
         .await
         .map_err(CommandError::from)?
         .ok_or_else(|| CommandError::not_found("Account not found"))?;
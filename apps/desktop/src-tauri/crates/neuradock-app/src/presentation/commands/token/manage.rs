@@ -0,0 +1,181 @@
+use super::fetch::token_dtos;
+use crate::application::dtos::{ProviderTokenInput, TokenDto};
+use crate::application::services::AuditSummary;
+use crate::presentation::error::CommandError;
+use crate::presentation::state::{Repositories, Services};
+use neuradock_domain::audit::{AuditAction, AuditActor, AuditEntry};
+use neuradock_domain::shared::AccountId;
+use neuradock_domain::token::{ApiToken, TokenId};
+use tauri::State;
+
+/// Create a token on the account's provider, returns the refreshed token list
+#[tauri::command]
+#[specta::specta]
+pub async fn create_provider_token(
+    account_id: String,
+    input: ProviderTokenInput,
+    services: State<'_, Services>,
+    repositories: State<'_, Repositories>,
+) -> Result<Vec<TokenDto>, CommandError> {
+    let account_id = AccountId::from_string(&account_id);
+    let draft = input.into_draft().map_err(CommandError::from)?;
+    let before = cached_tokens(&services, &account_id).await;
+
+    let tokens = services
+        .token
+        .create_provider_token(&account_id, draft)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to create token: {}", e);
+            CommandError::from(e)
+        })?;
+
+    // The provider assigns the id, the created token is the one not cached before
+    let created = tokens
+        .iter()
+        .find(|token| !before.iter().any(|cached| cached.id() == token.id()));
+    record_token_change(
+        &services,
+        &account_id,
+        AuditAction::TokenCreated,
+        None,
+        created,
+    )
+    .await;
+
+    token_dtos(&repositories, &account_id, &tokens).await
+}
+
+/// Rename a token or change its quota, expiry and model limits
+#[tauri::command]
+#[specta::specta]
+pub async fn update_provider_token(
+    account_id: String,
+    token_id: i64,
+    input: ProviderTokenInput,
+    services: State<'_, Services>,
+    repositories: State<'_, Repositories>,
+) -> Result<Vec<TokenDto>, CommandError> {
+    let account_id = AccountId::from_string(&account_id);
+    let draft = input.into_draft().map_err(CommandError::from)?;
+    let before = cached_tokens(&services, &account_id).await;
+
+    let tokens = services
+        .token
+        .update_provider_token(&account_id, token_id, draft)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to update token {}: {}", token_id, e);
+            CommandError::from(e)
+        })?;
+
+    record_token_change(
+        &services,
+        &account_id,
+        AuditAction::TokenUpdated,
+        find_token(&before, token_id),
+        find_token(&tokens, token_id),
+    )
+    .await;
+
+    token_dtos(&repositories, &account_id, &tokens).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_provider_token_enabled(
+    account_id: String,
+    token_id: i64,
+    enabled: bool,
+    services: State<'_, Services>,
+    repositories: State<'_, Repositories>,
+) -> Result<Vec<TokenDto>, CommandError> {
+    let account_id = AccountId::from_string(&account_id);
+    let before = cached_tokens(&services, &account_id).await;
+
+    let tokens = services
+        .token
+        .set_provider_token_enabled(&account_id, token_id, enabled)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to change status of token {}: {}", token_id, e);
+            CommandError::from(e)
+        })?;
+
+    record_token_change(
+        &services,
+        &account_id,
+        AuditAction::TokenUpdated,
+        find_token(&before, token_id),
+        find_token(&tokens, token_id),
+    )
+    .await;
+
+    token_dtos(&repositories, &account_id, &tokens).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_provider_token(
+    account_id: String,
+    token_id: i64,
+    services: State<'_, Services>,
+    repositories: State<'_, Repositories>,
+) -> Result<Vec<TokenDto>, CommandError> {
+    let account_id = AccountId::from_string(&account_id);
+    let before = cached_tokens(&services, &account_id).await;
+
+    let tokens = services
+        .token
+        .delete_provider_token(&account_id, token_id)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to delete token {}: {}", token_id, e);
+            CommandError::from(e)
+        })?;
+
+    record_token_change(
+        &services,
+        &account_id,
+        AuditAction::TokenDeleted,
+        find_token(&before, token_id),
+        None,
+    )
+    .await;
+
+    token_dtos(&repositories, &account_id, &tokens).await
+}
+
+/// Cached tokens before a change, for the audit log
+async fn cached_tokens(services: &Services, account_id: &AccountId) -> Vec<ApiToken> {
+    match services.token.get_cached_tokens(account_id).await {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            log::warn!("Failed to read cached tokens for the audit log: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+fn find_token(tokens: &[ApiToken], token_id: i64) -> Option<&ApiToken> {
+    tokens
+        .iter()
+        .find(|token| token.id() == &TokenId::new(token_id))
+}
+
+/// Token changes are recorded under their account
+async fn record_token_change(
+    services: &Services,
+    account_id: &AccountId,
+    action: AuditAction,
+    before: Option<&ApiToken>,
+    after: Option<&ApiToken>,
+) {
+    let mut entry = AuditEntry::new(AuditActor::User, action, account_id.as_str());
+    if let Some(token) = after.or(before) {
+        entry = entry.with_target_name(token.name());
+    }
+    entry.before = before.map(|token| AuditSummary::token(token).build());
+    entry.after = after.map(|token| AuditSummary::token(token).build());
+    services.audit_log.record(entry).await;
+}
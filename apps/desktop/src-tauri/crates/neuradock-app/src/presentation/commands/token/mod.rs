@@ -2,14 +2,34 @@
 mod fetch;
 pub use fetch::*;
 
+// Provider token create/update/delete commands
+mod manage;
+pub use manage::*;
+
+// Configured token expiry and quota watchdog commands
+mod watch;
+pub use watch::*;
+
 // Claude Code configuration commands
 mod claude;
 pub use claude::*;
 
+// Claude settings profile commands
+mod claude_profiles;
+pub use claude_profiles::*;
+
+// Model checks before configuring Claude Code or Codex
+mod model_check;
+pub use model_check::check_token_model;
+
 // Codex configuration commands
 mod codex;
 pub use codex::*;
 
+// Gemini CLI / opencode / aider / Continue configuration commands
+mod cli_tools;
+pub use cli_tools::*;
+
 // Provider nodes management commands
 mod nodes;
 pub use nodes::*;
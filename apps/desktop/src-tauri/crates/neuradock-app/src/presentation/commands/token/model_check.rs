@@ -0,0 +1,83 @@
+use crate::application::dtos::TokenModelCheckDto;
+use crate::application::services::token::{ClaudeModelSlots, ModelNotAvailable, TokenModels};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::{Repositories, Services};
+use neuradock_domain::shared::AccountId;
+use neuradock_domain::token::TokenId;
+use tauri::State;
+
+/// Cached model list of the account's provider, empty when never fetched
+pub(super) async fn provider_catalog(
+    account_id: &AccountId,
+    services: &Services,
+    repositories: &Repositories,
+) -> Result<Vec<String>, CommandError> {
+    let account = repositories
+        .account
+        .find_by_id(account_id)
+        .await
+        .map_err(CommandError::from)?
+        .ok_or_else(|| CommandError::not_found(format!("Account not found: {}", account_id)))?;
+
+    services
+        .provider_models_query
+        .get_cached(account.provider_id().as_str())
+        .await
+        .map_err(CommandError::from)
+}
+
+/// A model the token can't use is a validation error, anything else failed to write
+pub(super) fn config_error(err: anyhow::Error) -> CommandError {
+    if err.downcast_ref::<ModelNotAvailable>().is_some() {
+        CommandError::validation(err.to_string())
+    } else {
+        CommandError::from(err)
+    }
+}
+
+/// Check a model against the token's model limits and its provider's cached model list
+/// Also returns the Claude Code slots `configure_claude_global` would write
+#[tauri::command]
+#[specta::specta]
+pub async fn check_token_model(
+    token_id: i64,
+    account_id: String,
+    model: Option<String>,
+    services: State<'_, Services>,
+    repositories: State<'_, Repositories>,
+) -> Result<TokenModelCheckDto, CommandError> {
+    let account_id = AccountId::from_string(&account_id);
+    let token_id = TokenId::new(token_id);
+
+    let tokens = services
+        .token
+        .get_cached_tokens(&account_id)
+        .await
+        .map_err(CommandError::from)?;
+    let token = tokens
+        .iter()
+        .find(|t| t.id() == &token_id)
+        .ok_or_else(|| CommandError::not_found("Token not found"))?;
+
+    let catalog = provider_catalog(&account_id, &services, &repositories).await?;
+    let available = TokenModels::resolve(token, &catalog);
+    let model = model
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty());
+    let rejection = match (&available, model.as_deref()) {
+        (Some(available), Some(model)) => available.check(model).err(),
+        _ => None,
+    };
+    let slots = ClaudeModelSlots::for_model(available.as_ref(), model.as_deref());
+
+    Ok(TokenModelCheckDto {
+        model,
+        known: available.is_some(),
+        enforced: available.as_ref().is_some_and(TokenModels::is_enforced),
+        available: rejection.is_none(),
+        suggestions: rejection.map(|r| r.suggestions).unwrap_or_default(),
+        claude_haiku_model: slots.haiku,
+        claude_sonnet_model: slots.sonnet,
+        claude_opus_model: slots.opus,
+    })
+}
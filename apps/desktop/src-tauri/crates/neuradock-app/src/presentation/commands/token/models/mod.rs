@@ -1,7 +1,13 @@
 mod cached_models;
 mod fetch_models;
+mod model_changes;
+mod model_routes;
 mod refresh_models;
 
 pub use cached_models::get_cached_provider_models;
 pub use fetch_models::fetch_provider_models;
+pub use model_changes::{
+    get_model_catalog_settings, get_provider_model_changes, update_model_catalog_settings,
+};
+pub use model_routes::search_model_routes;
 pub use refresh_models::refresh_provider_models_with_waf;
@@ -0,0 +1,52 @@
+use crate::application::dtos::{ModelCatalogChangeDto, ModelCatalogSettingsDto};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+use tauri::State;
+
+/// Changes returned when no limit is given
+const DEFAULT_CHANGES_LIMIT: u32 = 50;
+
+/// What changed in provider model lists, newest first
+/// Pass a provider_id to only list that provider's changes
+#[tauri::command]
+#[specta::specta]
+pub async fn get_provider_model_changes(
+    provider_id: Option<String>,
+    limit: Option<u32>,
+    services: State<'_, Services>,
+) -> Result<Vec<ModelCatalogChangeDto>, CommandError> {
+    services
+        .model_catalog
+        .changes(
+            provider_id.as_deref(),
+            limit.unwrap_or(DEFAULT_CHANGES_LIMIT) as usize,
+        )
+        .await
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_model_catalog_settings(
+    services: State<'_, Services>,
+) -> Result<ModelCatalogSettingsDto, CommandError> {
+    Ok(ModelCatalogSettingsDto {
+        notify_configured_changes: services.config.notify_model_catalog_changes(),
+    })
+}
+
+/// Enable or disable notifications when configured Claude Code / Codex models change
+#[tauri::command]
+#[specta::specta]
+pub async fn update_model_catalog_settings(
+    settings: ModelCatalogSettingsDto,
+    services: State<'_, Services>,
+) -> Result<ModelCatalogSettingsDto, CommandError> {
+    services
+        .config
+        .set_notify_model_catalog_changes(settings.notify_configured_changes)
+        .map_err(|e| {
+            CommandError::infrastructure(format!("Failed to save model catalog settings: {}", e))
+        })?;
+    Ok(settings)
+}
@@ -0,0 +1,20 @@
+use crate::application::dtos::ModelRouteSearchDto;
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Queries;
+use tauri::State;
+
+/// Find the cached tokens that can serve a model across all providers, best first
+/// `model` matches any model name containing it, case-insensitive
+#[tauri::command]
+#[specta::specta]
+pub async fn search_model_routes(
+    model: String,
+    limit: Option<u32>,
+    queries: State<'_, Queries>,
+) -> Result<ModelRouteSearchDto, CommandError> {
+    queries
+        .model_routing
+        .search(&model, limit)
+        .await
+        .map_err(CommandError::from)
+}
@@ -0,0 +1,55 @@
+use crate::application::dtos::{TokenWatchSettingsDto, TokenWatchStatusDto};
+use crate::presentation::error::CommandError;
+use crate::presentation::state::Services;
+use tauri::State;
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_token_watch_settings(
+    services: State<'_, Services>,
+) -> Result<TokenWatchSettingsDto, CommandError> {
+    services
+        .token_watch
+        .get_settings()
+        .await
+        .map_err(CommandError::from)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_token_watch_settings(
+    input: TokenWatchSettingsDto,
+    services: State<'_, Services>,
+) -> Result<TokenWatchSettingsDto, CommandError> {
+    services
+        .token_watch
+        .update_settings(input)
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Tokens configured into Claude Code / Codex and the alerts of their last check
+#[tauri::command]
+#[specta::specta]
+pub async fn get_token_watch_status(
+    services: State<'_, Services>,
+) -> Result<Vec<TokenWatchStatusDto>, CommandError> {
+    services
+        .token_watch
+        .status()
+        .await
+        .map_err(CommandError::from)
+}
+
+/// Re-fetch the configured tokens now and send new alerts
+#[tauri::command]
+#[specta::specta]
+pub async fn run_token_watch(
+    services: State<'_, Services>,
+) -> Result<Vec<TokenWatchStatusDto>, CommandError> {
+    services
+        .token_watch
+        .check_all()
+        .await
+        .map_err(CommandError::from)
+}
@@ -14,6 +14,7 @@ pub fn builder() -> Builder<tauri::Wry> {
             toggle_account,
             import_account_from_json,
             import_accounts_batch,
+            import_accounts_from_cookie_sources,
             update_accounts_batch,
             export_accounts_to_json,
             // Check-in commands
@@ -24,6 +25,12 @@ pub fn builder() -> Builder<tauri::Wry> {
             fetch_account_balance,
             fetch_accounts_balances,
             get_balance_statistics,
+            get_balance_analytics,
+            get_account_balance_analytics,
+            get_balance_history_retention,
+            update_balance_history_retention,
+            run_balance_history_maintenance,
+            export_balance_history,
             // Provider commands
             add_provider,
             check_browser_available,
@@ -34,6 +41,7 @@ pub fn builder() -> Builder<tauri::Wry> {
             // Query commands
             get_all_accounts,
             get_account_detail,
+            get_account_event_timeline,
             get_check_in_history,
             get_check_in_stats,
             get_running_jobs,
@@ -47,8 +55,24 @@ pub fn builder() -> Builder<tauri::Wry> {
             // Config commands
             get_log_level,
             set_log_level,
+            get_metrics_exporter_status,
+            update_metrics_exporter_settings,
+            get_network_settings,
+            update_network_settings,
+            reset_network_settings,
+            get_locale,
+            set_locale,
             get_proxy_config,
             update_proxy_config,
+            list_proxy_pools,
+            save_proxy_pool,
+            delete_proxy_pool,
+            list_proxy_overrides,
+            set_proxy_override,
+            delete_proxy_override,
+            test_proxy_connectivity,
+            get_currency_settings,
+            update_currency_settings,
             // Notification commands
             create_notification_channel,
             update_notification_channel,
@@ -57,6 +81,15 @@ pub fn builder() -> Builder<tauri::Wry> {
             test_notification_channel,
             // Token commands
             fetch_account_tokens,
+            create_provider_token,
+            update_provider_token,
+            set_provider_token_enabled,
+            delete_provider_token,
+            get_token_watch_settings,
+            update_token_watch_settings,
+            get_token_watch_status,
+            run_token_watch,
+            check_token_model,
             configure_claude_global,
             generate_claude_temp_commands,
             configure_codex_global,
@@ -67,9 +100,28 @@ pub fn builder() -> Builder<tauri::Wry> {
             delete_custom_node,
             clear_claude_global,
             clear_codex_global,
+            list_codex_config_backups,
+            restore_codex_config_backup,
+            list_claude_profiles,
+            create_claude_profile,
+            update_claude_profile,
+            delete_claude_profile,
+            switch_claude_profile,
+            deactivate_claude_profile,
+            get_claude_profile_status,
+            list_claude_config_backups,
+            restore_claude_config_backup,
+            configure_cli_tool_global,
+            clear_cli_tool_global,
+            list_cli_tool_config_backups,
+            restore_cli_tool_config_backup,
             fetch_provider_models,
             refresh_provider_models_with_waf,
             get_cached_provider_models,
+            get_provider_model_changes,
+            get_model_catalog_settings,
+            update_model_catalog_settings,
+            search_model_routes,
             // Independent API Key commands
             get_all_independent_keys,
             get_independent_key_by_id,
@@ -77,14 +129,19 @@ pub fn builder() -> Builder<tauri::Wry> {
             update_independent_key,
             delete_independent_key,
             toggle_independent_key,
+            validate_independent_key,
             configure_independent_key_claude,
             generate_independent_key_claude_temp,
             configure_independent_key_codex,
             generate_independent_key_codex_temp,
+            configure_independent_key_cli_tool,
             // System & Logging commands
             get_app_version,
             log_from_frontend,
             open_log_dir,
+            export_diagnostics,
+            get_browser_pool_metrics,
+            get_audit_log,
             // AI Chat Service commands
             list_ai_chat_services,
             list_enabled_ai_chat_services,
@@ -120,6 +177,21 @@ pub fn builder() -> Builder<tauri::Wry> {
             generate_codex_payment_link,
             register_codex_accounts,
             cancel_codex_registration,
+            // Codex token refresh commands
+            get_codex_token_refresh_policy,
+            update_codex_token_refresh_policy,
+            run_codex_token_refresh,
+            refresh_codex_account_tokens,
+            // Codex auto-switch commands
+            get_codex_auto_switch_policy,
+            update_codex_auto_switch_policy,
+            run_codex_auto_switch_check,
+            list_codex_auto_switches,
+            // Codex usage history commands
+            get_codex_usage_history,
+            get_codex_usage_resets,
+            get_codex_usage_reminder_settings,
+            update_codex_usage_reminder_settings,
         ])
         .events(collect_events![
             crate::presentation::events::CheckInProgress,
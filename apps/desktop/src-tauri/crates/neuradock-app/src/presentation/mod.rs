@@ -1,4 +1,5 @@
 pub mod bootstrap;
+pub mod cli;
 pub mod commands;
 pub mod error;
 pub mod events;
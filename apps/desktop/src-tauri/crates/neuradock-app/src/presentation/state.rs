@@ -2,11 +2,17 @@ use std::sync::Arc;
 
 use crate::application::commands::handlers::*;
 use crate::application::queries::{
-    AccountQueryService, BalanceStatisticsQueryService, CheckInStreakQueries,
+    AccountQueryService, BalanceAnalyticsQueryService, BalanceStatisticsQueryService,
+    CheckInStreakQueries, EventTimelineQueryService, ModelRoutingQueryService,
 };
 use crate::application::services::{
-    BalanceService, ClaudeConfigService, CodexConfigService, ConfigService,
-    ProviderModelsQueryService, ProxyConfigService, TokenService,
+    AccountCookieImportService, AuditLogService, BalanceHistoryMaintenanceService, BalanceService,
+    ClaudeConfigService, ClaudeProfileService, CliToolConfigService, CodexAutoSwitchService,
+    CodexConfigService, CodexTokenRefreshService, CodexUsageHistoryService, ConfigService,
+    CurrencySettingsService, DiagnosticsService, IndependentKeyValidationService,
+    MetricsExporterService, ModelCatalogService, NetworkSettingsService,
+    ProviderModelsQueryService, ProxyConfigService, ProxyRoutingService, TokenService,
+    TokenWatchService,
 };
 use neuradock_domain::account::AccountRepository;
 use neuradock_domain::ai_chat::AiChatServiceRepository;
@@ -16,6 +22,7 @@ use neuradock_domain::custom_node::CustomProviderNodeRepository;
 use neuradock_domain::independent_key::IndependentKeyRepository;
 use neuradock_domain::notification::NotificationChannelRepository;
 use neuradock_domain::session::SessionRepository;
+use neuradock_infrastructure::browser::BrowserPool;
 
 /// Command handlers container
 #[derive(Clone)]
@@ -51,11 +58,28 @@ pub struct Repositories {
 pub struct Services {
     pub token: Arc<TokenService>,
     pub claude_config: Arc<ClaudeConfigService>,
+    pub claude_profile: Arc<ClaudeProfileService>,
     pub codex_config: Arc<CodexConfigService>,
+    pub codex_token_refresh: Arc<CodexTokenRefreshService>,
+    pub codex_auto_switch: Arc<CodexAutoSwitchService>,
+    pub codex_usage_history: Arc<CodexUsageHistoryService>,
+    pub token_watch: Arc<TokenWatchService>,
+    pub cli_tool_config: Arc<CliToolConfigService>,
     pub config: Arc<ConfigService>,
     pub balance: Arc<BalanceService>,
+    pub balance_history_maintenance: Arc<BalanceHistoryMaintenanceService>,
     pub proxy_config: Arc<ProxyConfigService>,
+    pub proxy_routing: Arc<ProxyRoutingService>,
+    pub currency: Arc<CurrencySettingsService>,
+    pub network_settings: Arc<NetworkSettingsService>,
     pub provider_models_query: Arc<ProviderModelsQueryService>,
+    pub model_catalog: Arc<ModelCatalogService>,
+    pub account_cookie_import: Arc<AccountCookieImportService>,
+    pub independent_key_validation: Arc<IndependentKeyValidationService>,
+    pub browser_pool: Arc<BrowserPool>,
+    pub audit_log: Arc<AuditLogService>,
+    pub metrics_exporter: Arc<MetricsExporterService>,
+    pub diagnostics: Arc<DiagnosticsService>,
 }
 
 #[derive(Clone)]
@@ -63,6 +87,9 @@ pub struct Queries {
     pub account: Arc<AccountQueryService>,
     pub streak: Arc<CheckInStreakQueries>,
     pub balance_statistics: Arc<BalanceStatisticsQueryService>,
+    pub balance_analytics: Arc<BalanceAnalyticsQueryService>,
+    pub event_timeline: Arc<EventTimelineQueryService>,
+    pub model_routing: Arc<ModelRoutingQueryService>,
 }
 
 #[derive(Clone)]
@@ -0,0 +1,219 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::shared::DomainError;
+
+const MAX_PAGE_SIZE: u32 = 200;
+
+/// Who triggered the change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AuditActor {
+    /// Issued from the UI
+    User,
+    /// Background tasks such as the Codex auto-switch
+    System,
+}
+
+impl AuditActor {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::User => "user",
+            Self::System => "system",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "system" => Self::System,
+            _ => Self::User,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AuditAction {
+    AccountCreated,
+    AccountUpdated,
+    AccountDeleted,
+    AccountToggled,
+    /// Cookies or api_user of an account replaced
+    CredentialsReplaced,
+    /// Claude Code, Codex or another CLI tool's global config written
+    GlobalConfigWritten,
+    GlobalConfigCleared,
+    /// ~/.codex/auth.json pointed at another account or key
+    CodexAuthSwitched,
+    /// A CLI tool config restored from one of its backups
+    BackupRestored,
+    /// An API token created, edited or removed on the provider's console
+    TokenCreated,
+    TokenUpdated,
+    TokenDeleted,
+    ProviderCreated,
+    ProviderUpdated,
+    ProviderDeleted,
+    /// Global proxy, a proxy pool or a per-account/provider proxy override changed
+    ProxyConfigUpdated,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::AccountCreated => "account_created",
+            Self::AccountUpdated => "account_updated",
+            Self::AccountDeleted => "account_deleted",
+            Self::AccountToggled => "account_toggled",
+            Self::CredentialsReplaced => "credentials_replaced",
+            Self::GlobalConfigWritten => "global_config_written",
+            Self::GlobalConfigCleared => "global_config_cleared",
+            Self::CodexAuthSwitched => "codex_auth_switched",
+            Self::BackupRestored => "backup_restored",
+            Self::TokenCreated => "token_created",
+            Self::TokenUpdated => "token_updated",
+            Self::TokenDeleted => "token_deleted",
+            Self::ProviderCreated => "provider_created",
+            Self::ProviderUpdated => "provider_updated",
+            Self::ProviderDeleted => "provider_deleted",
+            Self::ProxyConfigUpdated => "proxy_config_updated",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "account_created" => Some(Self::AccountCreated),
+            "account_updated" => Some(Self::AccountUpdated),
+            "account_deleted" => Some(Self::AccountDeleted),
+            "account_toggled" => Some(Self::AccountToggled),
+            "credentials_replaced" => Some(Self::CredentialsReplaced),
+            "global_config_written" => Some(Self::GlobalConfigWritten),
+            "global_config_cleared" => Some(Self::GlobalConfigCleared),
+            "codex_auth_switched" => Some(Self::CodexAuthSwitched),
+            "backup_restored" => Some(Self::BackupRestored),
+            "token_created" => Some(Self::TokenCreated),
+            "token_updated" => Some(Self::TokenUpdated),
+            "token_deleted" => Some(Self::TokenDeleted),
+            "provider_created" => Some(Self::ProviderCreated),
+            "provider_updated" => Some(Self::ProviderUpdated),
+            "provider_deleted" => Some(Self::ProviderDeleted),
+            "proxy_config_updated" => Some(Self::ProxyConfigUpdated),
+            _ => None,
+        }
+    }
+}
+
+/// One state-changing operation
+///
+/// `target` names what changed: an account id (also for its tokens), a provider
+/// id, the proxy setting, or the CLI tool whose config was written. `before` and `after` are summaries with secrets already masked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    /// Sequence id, `None` until appended
+    pub id: Option<i64>,
+    pub occurred_at: DateTime<Utc>,
+    pub actor: AuditActor,
+    pub action: AuditAction,
+    pub target: String,
+    pub target_name: Option<String>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl AuditEntry {
+    pub fn new(actor: AuditActor, action: AuditAction, target: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            occurred_at: Utc::now(),
+            actor,
+            action,
+            target: target.into(),
+            target_name: None,
+            before: None,
+            after: None,
+        }
+    }
+
+    pub fn with_target_name(mut self, name: impl Into<String>) -> Self {
+        self.target_name = Some(name.into());
+        self
+    }
+
+    pub fn with_before(mut self, summary: impl Into<String>) -> Self {
+        self.before = Some(summary.into());
+        self
+    }
+
+    pub fn with_after(mut self, summary: impl Into<String>) -> Self {
+        self.after = Some(summary.into());
+        self
+    }
+}
+
+/// Filter and page of an audit log query, pages start at 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditLogQuery {
+    pub page: u32,
+    pub page_size: u32,
+    pub action: Option<AuditAction>,
+    pub target: Option<String>,
+}
+
+impl AuditLogQuery {
+    pub fn new(page: u32, page_size: u32) -> Result<Self, DomainError> {
+        if page == 0 {
+            return Err(DomainError::Validation("Pages start at 1".to_string()));
+        }
+        if page_size == 0 || page_size > MAX_PAGE_SIZE {
+            return Err(DomainError::Validation(format!(
+                "Page size must be between 1 and {}",
+                MAX_PAGE_SIZE
+            )));
+        }
+        Ok(Self {
+            page,
+            page_size,
+            action: None,
+            target: None,
+        })
+    }
+
+    pub fn offset(&self) -> u64 {
+        (self.page as u64 - 1) * self.page_size as u64
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditLogPage {
+    pub entries: Vec<AuditEntry>,
+    /// Entries matching the filter across all pages
+    pub total: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_round_trip() {
+        for action in [
+            AuditAction::AccountCreated,
+            AuditAction::CredentialsReplaced,
+            AuditAction::GlobalConfigCleared,
+            AuditAction::BackupRestored,
+            AuditAction::TokenDeleted,
+            AuditAction::ProviderUpdated,
+            AuditAction::ProxyConfigUpdated,
+        ] {
+            assert_eq!(AuditAction::parse(action.as_str()), Some(action));
+        }
+        assert_eq!(AuditAction::parse("unknown"), None);
+        assert_eq!(AuditActor::parse("system"), AuditActor::System);
+    }
+
+    #[test]
+    fn test_query_validates_page() {
+        assert!(AuditLogQuery::new(0, 20).is_err());
+        assert!(AuditLogQuery::new(1, 0).is_err());
+        assert!(AuditLogQuery::new(1, 201).is_err());
+        assert_eq!(AuditLogQuery::new(3, 20).unwrap().offset(), 40);
+    }
+}
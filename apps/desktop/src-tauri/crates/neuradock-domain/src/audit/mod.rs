@@ -0,0 +1,5 @@
+mod entry;
+mod repository;
+
+pub use entry::{AuditAction, AuditActor, AuditEntry, AuditLogPage, AuditLogQuery};
+pub use repository::AuditLogRepository;
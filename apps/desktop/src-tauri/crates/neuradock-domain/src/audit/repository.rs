@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use super::entry::{AuditEntry, AuditLogPage, AuditLogQuery};
+use crate::shared::DomainError;
+
+/// Append-only store of state-changing operations
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    /// Store the entry, returns its sequence id
+    async fn append(&self, entry: &AuditEntry) -> Result<i64, DomainError>;
+    /// Entries matching the query, newest first
+    async fn query(&self, query: &AuditLogQuery) -> Result<AuditLogPage, DomainError>;
+}
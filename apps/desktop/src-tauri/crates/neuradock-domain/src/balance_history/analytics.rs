@@ -0,0 +1,339 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::BalanceHistoryDailySummary;
+
+/// Consumption derived from two consecutive daily summaries.
+///
+/// `span_days` is the number of calendar days between the two snapshots, so a
+/// gap (app not running) is spread over the days it covers instead of being
+/// reported as a single-day spike.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyConsumption {
+    pub date: NaiveDate,
+    pub span_days: u32,
+    pub consumed: f64,
+    pub quota_added: f64,
+    pub current_balance: f64,
+    pub previous_balance: f64,
+}
+
+impl DailyConsumption {
+    /// Consumption normalized to a single day.
+    pub fn consumed_per_day(&self) -> f64 {
+        self.consumed / self.span_days.max(1) as f64
+    }
+
+    /// Balance decrease normalized to a single day (0 when the balance grew).
+    pub fn balance_drop_per_day(&self) -> f64 {
+        (self.previous_balance - self.current_balance).max(0.0) / self.span_days.max(1) as f64
+    }
+}
+
+/// Average daily consumption and income over a trailing window.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct BurnRate {
+    pub consumed_per_day: f64,
+    pub income_per_day: f64,
+    pub days_observed: u32,
+}
+
+impl BurnRate {
+    /// Consumption minus check-in income; positive means the balance is shrinking.
+    pub fn net_per_day(&self) -> f64 {
+        self.consumed_per_day - self.income_per_day
+    }
+
+    /// Days until `current_balance` reaches zero at the current net burn rate.
+    ///
+    /// Returns `None` when the balance is not shrinking.
+    pub fn days_until_empty(&self, current_balance: f64) -> Option<f64> {
+        let net = self.net_per_day();
+        if net <= f64::EPSILON {
+            return None;
+        }
+        Some((current_balance.max(0.0) / net).max(0.0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceAnomalyKind {
+    /// Daily consumption far above the account's recent baseline.
+    ConsumptionSpike,
+    /// Large share of the balance disappeared in a single day.
+    BalanceDrop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalySeverity {
+    Warning,
+    Critical,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BalanceAnomaly {
+    pub date: NaiveDate,
+    pub kind: BalanceAnomalyKind,
+    pub severity: AnomalySeverity,
+    /// Observed value (per-day consumption or balance drop)
+    pub observed: f64,
+    /// Reference value (baseline consumption or previous balance)
+    pub baseline: f64,
+}
+
+/// Thresholds used by [`detect_anomalies`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AnomalyThresholds {
+    /// Consumption must exceed `baseline * spike_multiplier` to count as a spike
+    pub spike_multiplier: f64,
+    /// Number of preceding days averaged into the baseline
+    pub baseline_days: usize,
+    /// Minimum preceding days required before spikes are reported
+    pub min_baseline_days: usize,
+    /// Ignore movements smaller than this absolute amount
+    pub min_amount: f64,
+    /// Fraction of the previous balance lost in one day that counts as a drop
+    pub balance_drop_ratio: f64,
+}
+
+impl Default for AnomalyThresholds {
+    fn default() -> Self {
+        Self {
+            spike_multiplier: 3.0,
+            baseline_days: 7,
+            min_baseline_days: 3,
+            min_amount: 1.0,
+            balance_drop_ratio: 0.5,
+        }
+    }
+}
+
+/// Convert daily summaries (sorted ascending by date) into per-day consumption.
+///
+/// The first summary only serves as the starting point and produces no entry.
+pub fn daily_consumption(summaries: &[BalanceHistoryDailySummary]) -> Vec<DailyConsumption> {
+    summaries
+        .windows(2)
+        .map(|pair| {
+            let (prev, curr) = (&pair[0], &pair[1]);
+            let span_days = (curr.check_in_date() - prev.check_in_date())
+                .num_days()
+                .max(1) as u32;
+
+            DailyConsumption {
+                date: curr.check_in_date(),
+                span_days,
+                consumed: (curr.daily_consumed() - prev.daily_consumed()).max(0.0),
+                quota_added: (curr.daily_total_quota() - prev.daily_total_quota()).max(0.0),
+                current_balance: curr.daily_balance(),
+                previous_balance: prev.daily_balance(),
+            }
+        })
+        .collect()
+}
+
+/// Average consumption and income over entries dated after `as_of - window_days`.
+pub fn burn_rate(points: &[DailyConsumption], as_of: NaiveDate, window_days: u32) -> BurnRate {
+    let window_start = as_of - chrono::Duration::days(window_days as i64);
+
+    let (consumed, income, days) = points
+        .iter()
+        .filter(|p| p.date > window_start && p.date <= as_of)
+        .fold((0.0, 0.0, 0u32), |(consumed, income, days), p| {
+            (
+                consumed + p.consumed,
+                income + p.quota_added,
+                days + p.span_days,
+            )
+        });
+
+    if days == 0 {
+        return BurnRate::default();
+    }
+
+    BurnRate {
+        consumed_per_day: consumed / days as f64,
+        income_per_day: income / days as f64,
+        days_observed: days,
+    }
+}
+
+/// Flag consumption spikes and sudden balance drops.
+///
+/// A spike is a day whose consumption exceeds the trailing average of the
+/// preceding `baseline_days` entries by `spike_multiplier`. A drop is a day
+/// that loses at least `balance_drop_ratio` of the previous balance, which is
+/// typical for a leaked key being drained.
+pub fn detect_anomalies(
+    points: &[DailyConsumption],
+    thresholds: &AnomalyThresholds,
+) -> Vec<BalanceAnomaly> {
+    let mut anomalies = Vec::new();
+
+    for (index, point) in points.iter().enumerate() {
+        let consumed = point.consumed_per_day();
+        let history_start = index.saturating_sub(thresholds.baseline_days);
+        let history = &points[history_start..index];
+
+        if history.len() >= thresholds.min_baseline_days && consumed >= thresholds.min_amount {
+            let baseline = history
+                .iter()
+                .map(DailyConsumption::consumed_per_day)
+                .sum::<f64>()
+                / history.len() as f64;
+            let threshold = baseline * thresholds.spike_multiplier;
+
+            if consumed > threshold.max(thresholds.min_amount) {
+                let severity = if consumed > threshold * 2.0 {
+                    AnomalySeverity::Critical
+                } else {
+                    AnomalySeverity::Warning
+                };
+                anomalies.push(BalanceAnomaly {
+                    date: point.date,
+                    kind: BalanceAnomalyKind::ConsumptionSpike,
+                    severity,
+                    observed: consumed,
+                    baseline,
+                });
+            }
+        }
+
+        let drop = point.balance_drop_per_day();
+        if point.previous_balance > 0.0 && drop >= thresholds.min_amount {
+            let ratio = drop / point.previous_balance;
+            if ratio >= thresholds.balance_drop_ratio {
+                let severity = if ratio >= 0.9 {
+                    AnomalySeverity::Critical
+                } else {
+                    AnomalySeverity::Warning
+                };
+                anomalies.push(BalanceAnomaly {
+                    date: point.date,
+                    kind: BalanceAnomalyKind::BalanceDrop,
+                    severity,
+                    observed: drop,
+                    baseline: point.previous_balance,
+                });
+            }
+        }
+    }
+
+    anomalies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    fn summary(day: u32, quota: f64, balance: f64, consumed: f64) -> BalanceHistoryDailySummary {
+        BalanceHistoryDailySummary::restore(date(day), quota, balance, consumed)
+    }
+
+    #[test]
+    fn test_daily_consumption_from_summaries() {
+        let summaries = vec![
+            summary(1, 100.0, 100.0, 0.0),
+            summary(2, 110.0, 100.0, 10.0),
+            summary(4, 110.0, 80.0, 30.0),
+        ];
+
+        let points = daily_consumption(&summaries);
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].consumed, 10.0);
+        assert_eq!(points[0].quota_added, 10.0);
+        assert_eq!(points[1].span_days, 2);
+        assert_eq!(points[1].consumed_per_day(), 10.0);
+    }
+
+    #[test]
+    fn test_burn_rate_and_forecast() {
+        let summaries = vec![
+            summary(1, 100.0, 100.0, 0.0),
+            summary(2, 100.0, 90.0, 10.0),
+            summary(3, 100.0, 80.0, 20.0),
+        ];
+        let points = daily_consumption(&summaries);
+
+        let rate = burn_rate(&points, date(3), 7);
+        assert_eq!(rate.days_observed, 2);
+        assert_eq!(rate.consumed_per_day, 10.0);
+        assert_eq!(rate.days_until_empty(80.0), Some(8.0));
+    }
+
+    #[test]
+    fn test_forecast_none_when_income_covers_consumption() {
+        let rate = BurnRate {
+            consumed_per_day: 5.0,
+            income_per_day: 10.0,
+            days_observed: 7,
+        };
+        assert_eq!(rate.days_until_empty(100.0), None);
+    }
+
+    #[test]
+    fn test_burn_rate_respects_window() {
+        let summaries = vec![
+            summary(1, 100.0, 100.0, 0.0),
+            summary(2, 100.0, 50.0, 50.0),
+            summary(10, 100.0, 48.0, 52.0),
+        ];
+        let points = daily_consumption(&summaries);
+
+        let rate = burn_rate(&points, date(10), 3);
+        assert_eq!(rate.days_observed, 8);
+        assert_eq!(rate.consumed_per_day, 0.25);
+    }
+
+    #[test]
+    fn test_detect_consumption_spike() {
+        let mut summaries = vec![summary(1, 1000.0, 1000.0, 0.0)];
+        for day in 2..=6 {
+            let consumed = (day - 1) as f64 * 2.0;
+            summaries.push(summary(day, 1000.0, 1000.0 - consumed, consumed));
+        }
+        summaries.push(summary(7, 1000.0, 960.0, 40.0));
+
+        let anomalies = detect_anomalies(
+            &daily_consumption(&summaries),
+            &AnomalyThresholds::default(),
+        );
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].kind, BalanceAnomalyKind::ConsumptionSpike);
+        assert_eq!(anomalies[0].severity, AnomalySeverity::Critical);
+        assert_eq!(anomalies[0].date, date(7));
+    }
+
+    #[test]
+    fn test_detect_balance_drop() {
+        let summaries = vec![summary(1, 100.0, 100.0, 0.0), summary(2, 100.0, 40.0, 60.0)];
+
+        let anomalies = detect_anomalies(
+            &daily_consumption(&summaries),
+            &AnomalyThresholds::default(),
+        );
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].kind, BalanceAnomalyKind::BalanceDrop);
+        assert_eq!(anomalies[0].severity, AnomalySeverity::Warning);
+        assert_eq!(anomalies[0].observed, 60.0);
+    }
+
+    #[test]
+    fn test_steady_usage_has_no_anomalies() {
+        let summaries = (1..=10)
+            .map(|day| summary(day, 1000.0, 1000.0 - day as f64 * 5.0, day as f64 * 5.0))
+            .collect::<Vec<_>>();
+
+        let anomalies = detect_anomalies(
+            &daily_consumption(&summaries),
+            &AnomalyThresholds::default(),
+        );
+        assert!(anomalies.is_empty());
+    }
+}
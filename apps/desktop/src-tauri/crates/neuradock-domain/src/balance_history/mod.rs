@@ -1,5 +1,9 @@
+mod analytics;
 mod repository;
+mod retention;
 mod types;
 
+pub use analytics::*;
 pub use repository::*;
+pub use retention::*;
 pub use types::*;
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 
-use super::{BalanceHistoryDailySummary, BalanceHistoryRecord};
+use super::{
+    BalanceHistoryDailySummary, BalanceHistoryExportRow, BalanceHistoryRecord,
+    BalanceHistoryRetentionPolicy, DownsampleReport,
+};
 use crate::shared::{AccountId, DomainError};
 
 #[async_trait]
@@ -48,4 +51,34 @@ pub trait BalanceHistoryRepository: Send + Sync {
 
     /// List distinct account IDs present in balance_history.
     async fn list_distinct_account_ids(&self) -> Result<Vec<AccountId>, DomainError>;
+
+    /// Collapse raw snapshots recorded before `cutoff` into daily aggregates.
+    ///
+    /// The latest snapshot of each account is always kept raw.
+    async fn downsample_before(&self, cutoff: NaiveDate) -> Result<DownsampleReport, DomainError>;
+
+    /// List raw snapshots and daily aggregates in a date range (inclusive).
+    ///
+    /// An empty `account_ids` slice means all accounts.
+    async fn list_for_export(
+        &self,
+        account_ids: &[AccountId],
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<BalanceHistoryExportRow>, DomainError>;
+
+    /// Reclaim storage after large deletions (SQLite `VACUUM`).
+    async fn compact(&self) -> Result<(), DomainError>;
+}
+
+/// Retention policy repository (singleton)
+#[async_trait]
+pub trait BalanceHistoryRetentionRepository: Send + Sync {
+    async fn get(&self) -> Result<BalanceHistoryRetentionPolicy, DomainError>;
+
+    async fn save(&self, policy: &BalanceHistoryRetentionPolicy) -> Result<(), DomainError>;
+
+    /// Record a maintenance run without touching the settings, which may have changed
+    /// while it ran
+    async fn mark_run(&self, at: DateTime<Utc>) -> Result<(), DomainError>;
 }
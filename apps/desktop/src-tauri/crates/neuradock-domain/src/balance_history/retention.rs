@@ -0,0 +1,179 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::shared::{AccountId, DomainError};
+
+pub const MIN_RAW_RETENTION_DAYS: u32 = 7;
+pub const MAX_RAW_RETENTION_DAYS: u32 = 3650;
+pub const DEFAULT_RAW_RETENTION_DAYS: u32 = 90;
+
+/// Retention policy for `balance_history` (singleton)
+///
+/// Raw snapshots newer than `raw_retention_days` are kept as-is. Older days
+/// are collapsed into one daily min/max/last aggregate per account. The most
+/// recent snapshot of every account is always kept raw so "latest balance"
+/// lookups keep working for dormant accounts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceHistoryRetentionPolicy {
+    enabled: bool,
+    raw_retention_days: u32,
+    vacuum_after_cleanup: bool,
+    last_run_at: Option<DateTime<Utc>>,
+}
+
+impl Default for BalanceHistoryRetentionPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            raw_retention_days: DEFAULT_RAW_RETENTION_DAYS,
+            vacuum_after_cleanup: true,
+            last_run_at: None,
+        }
+    }
+}
+
+impl BalanceHistoryRetentionPolicy {
+    pub fn restore(
+        enabled: bool,
+        raw_retention_days: u32,
+        vacuum_after_cleanup: bool,
+        last_run_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            enabled,
+            raw_retention_days,
+            vacuum_after_cleanup,
+            last_run_at,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        enabled: bool,
+        raw_retention_days: u32,
+        vacuum_after_cleanup: bool,
+    ) -> Result<(), DomainError> {
+        if !(MIN_RAW_RETENTION_DAYS..=MAX_RAW_RETENTION_DAYS).contains(&raw_retention_days) {
+            return Err(DomainError::Validation(format!(
+                "Raw retention must be between {} and {} days",
+                MIN_RAW_RETENTION_DAYS, MAX_RAW_RETENTION_DAYS
+            )));
+        }
+
+        self.enabled = enabled;
+        self.raw_retention_days = raw_retention_days;
+        self.vacuum_after_cleanup = vacuum_after_cleanup;
+        Ok(())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn raw_retention_days(&self) -> u32 {
+        self.raw_retention_days
+    }
+
+    pub fn vacuum_after_cleanup(&self) -> bool {
+        self.vacuum_after_cleanup
+    }
+
+    pub fn last_run_at(&self) -> Option<DateTime<Utc>> {
+        self.last_run_at
+    }
+
+    /// First day that must still be kept raw; earlier days get downsampled.
+    pub fn cutoff_date(&self, now: DateTime<Utc>) -> NaiveDate {
+        (now - Duration::days(self.raw_retention_days as i64)).date_naive()
+    }
+
+    /// Whether a maintenance run is due (at most once per `interval`).
+    pub fn is_due(&self, now: DateTime<Utc>, interval: Duration) -> bool {
+        self.enabled
+            && self
+                .last_run_at
+                .is_none_or(|last_run| now - last_run >= interval)
+    }
+
+    pub fn mark_run(&mut self, at: DateTime<Utc>) {
+        self.last_run_at = Some(at);
+    }
+}
+
+/// Result of collapsing raw snapshots into daily aggregates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownsampleReport {
+    pub raw_rows_removed: u64,
+    pub daily_rows_written: u64,
+}
+
+/// Unified view over raw snapshots and daily aggregates, used for exports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceHistoryExportRow {
+    pub account_id: AccountId,
+    pub date: NaiveDate,
+    pub recorded_at: DateTime<Utc>,
+    pub current_balance: f64,
+    pub total_consumed: f64,
+    pub total_quota: f64,
+    pub min_balance: f64,
+    pub max_balance: f64,
+    pub sample_count: u32,
+    /// `true` when the row is a daily aggregate rather than a raw snapshot
+    pub downsampled: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy() {
+        let policy = BalanceHistoryRetentionPolicy::default();
+        assert!(policy.is_enabled());
+        assert_eq!(policy.raw_retention_days(), DEFAULT_RAW_RETENTION_DAYS);
+        assert!(policy.last_run_at().is_none());
+    }
+
+    #[test]
+    fn test_update_validates_range() {
+        let mut policy = BalanceHistoryRetentionPolicy::default();
+        assert!(policy.update(true, 1, true).is_err());
+        assert!(policy
+            .update(true, MAX_RAW_RETENTION_DAYS + 1, true)
+            .is_err());
+
+        policy.update(false, 30, false).unwrap();
+        assert!(!policy.is_enabled());
+        assert_eq!(policy.raw_retention_days(), 30);
+        assert!(!policy.vacuum_after_cleanup());
+    }
+
+    #[test]
+    fn test_cutoff_date() {
+        let mut policy = BalanceHistoryRetentionPolicy::default();
+        policy.update(true, 30, true).unwrap();
+
+        let now = "2026-03-31T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            policy.cutoff_date(now),
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_is_due() {
+        let now = Utc::now();
+        let mut policy = BalanceHistoryRetentionPolicy::default();
+        assert!(policy.is_due(now, Duration::hours(24)));
+
+        policy.mark_run(now - Duration::hours(1));
+        assert!(!policy.is_due(now, Duration::hours(24)));
+
+        policy.mark_run(now - Duration::hours(25));
+        assert!(policy.is_due(now, Duration::hours(24)));
+
+        policy.update(false, 90, true).unwrap();
+        assert!(!policy.is_due(now, Duration::hours(24)));
+    }
+}
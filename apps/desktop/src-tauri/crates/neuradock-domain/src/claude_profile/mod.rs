@@ -0,0 +1,5 @@
+mod profile;
+mod repository;
+
+pub use profile::*;
+pub use repository::*;
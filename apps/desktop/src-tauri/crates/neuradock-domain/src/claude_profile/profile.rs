@@ -0,0 +1,369 @@
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+use crate::shared::DomainError;
+
+/// Env keys set from dedicated profile fields, not allowed in `extra_env`
+pub const RESERVED_ENV_KEYS: &[&str] = &[
+    "ANTHROPIC_AUTH_TOKEN",
+    "ANTHROPIC_BASE_URL",
+    "ANTHROPIC_DEFAULT_HAIKU_MODEL",
+    "ANTHROPIC_DEFAULT_SONNET_MODEL",
+    "ANTHROPIC_DEFAULT_OPUS_MODEL",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClaudeProfileId(i64);
+
+impl ClaudeProfileId {
+    pub fn new(id: i64) -> Self {
+        Self(id)
+    }
+
+    pub fn value(&self) -> i64 {
+        self.0
+    }
+}
+
+/// Models Claude Code uses for its haiku/sonnet/opus slots
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClaudeModelMapping {
+    pub haiku: Option<String>,
+    pub sonnet: Option<String>,
+    pub opus: Option<String>,
+}
+
+/// Configuration for creating or updating a ClaudeProfile
+#[derive(Debug, Clone)]
+pub struct ClaudeProfileConfig {
+    pub name: String,
+    pub auth_token: String,
+    pub base_url: String,
+    pub models: ClaudeModelMapping,
+    pub extra_env: BTreeMap<String, String>,
+}
+
+impl ClaudeProfileConfig {
+    fn validated(self) -> Result<Self, DomainError> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err(DomainError::Validation(
+                "Profile name cannot be empty".to_string(),
+            ));
+        }
+
+        let auth_token = self.auth_token.trim().to_string();
+        if auth_token.is_empty() {
+            return Err(DomainError::Validation(
+                "Profile token cannot be empty".to_string(),
+            ));
+        }
+
+        let base_url = self.base_url.trim().trim_end_matches('/').to_string();
+        if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+            return Err(DomainError::Validation(format!(
+                "Base URL must start with http:// or https://: {}",
+                base_url
+            )));
+        }
+
+        for key in self.extra_env.keys() {
+            if !is_env_key(key) {
+                return Err(DomainError::Validation(format!(
+                    "Invalid environment variable name: {}",
+                    key
+                )));
+            }
+            if RESERVED_ENV_KEYS.contains(&key.as_str()) {
+                return Err(DomainError::Validation(format!(
+                    "{} is set by the profile itself and cannot be used as extra env",
+                    key
+                )));
+            }
+        }
+
+        let model = |value: Option<String>| {
+            value
+                .map(|m| m.trim().to_string())
+                .filter(|m| !m.is_empty())
+        };
+
+        Ok(Self {
+            name,
+            auth_token,
+            base_url,
+            models: ClaudeModelMapping {
+                haiku: model(self.models.haiku),
+                sonnet: model(self.models.sonnet),
+                opus: model(self.models.opus),
+            },
+            extra_env: self.extra_env,
+        })
+    }
+}
+
+fn is_env_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Named set of Claude Code settings that can be switched in one go
+#[derive(Debug, Clone)]
+pub struct ClaudeProfile {
+    id: Option<ClaudeProfileId>,
+    name: String,
+    auth_token: String,
+    base_url: String,
+    models: ClaudeModelMapping,
+    extra_env: BTreeMap<String, String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl ClaudeProfile {
+    pub fn create(config: ClaudeProfileConfig) -> Result<Self, DomainError> {
+        let config = config.validated()?;
+        let now = Utc::now();
+
+        Ok(Self {
+            id: None,
+            name: config.name,
+            auth_token: config.auth_token,
+            base_url: config.base_url,
+            models: config.models,
+            extra_env: config.extra_env,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Restore a ClaudeProfile from persistence
+    pub fn restore(
+        id: ClaudeProfileId,
+        config: ClaudeProfileConfig,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Some(id),
+            name: config.name,
+            auth_token: config.auth_token,
+            base_url: config.base_url,
+            models: config.models,
+            extra_env: config.extra_env,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn with_id(mut self, id: ClaudeProfileId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    // Getters
+    pub fn id(&self) -> Option<&ClaudeProfileId> {
+        self.id.as_ref()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn auth_token(&self) -> &str {
+        &self.auth_token
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn models(&self) -> &ClaudeModelMapping {
+        &self.models
+    }
+
+    pub fn extra_env(&self) -> &BTreeMap<String, String> {
+        &self.extra_env
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    pub fn masked_token(&self) -> String {
+        let char_count = self.auth_token.chars().count();
+        if char_count <= 12 {
+            return "*".repeat(char_count);
+        }
+
+        let prefix: String = self.auth_token.chars().take(8).collect();
+        let suffix_chars: Vec<char> = self.auth_token.chars().rev().take(4).collect();
+        let suffix: String = suffix_chars.into_iter().rev().collect();
+
+        format!("{}...{}", prefix, suffix)
+    }
+
+    // Business logic
+    pub fn update(&mut self, config: ClaudeProfileConfig) -> Result<(), DomainError> {
+        let config = config.validated()?;
+        self.name = config.name;
+        self.auth_token = config.auth_token;
+        self.base_url = config.base_url;
+        self.models = config.models;
+        self.extra_env = config.extra_env;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// Record of the profile last written to settings.json
+///
+/// Only digests of the written values are kept so hand edits can be detected
+/// without storing the token a second time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveClaudeProfile {
+    pub profile_id: ClaudeProfileId,
+    pub applied_at: DateTime<Utc>,
+    /// Env key -> digest of the value written
+    pub applied_env: BTreeMap<String, String>,
+}
+
+/// How settings.json compares to the active profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClaudeProfileDrift {
+    InSync,
+    /// The profile was edited after it was applied, switch again to pick it up
+    ProfileUpdated,
+    /// settings.json was edited by hand, these keys differ or are missing
+    Modified {
+        keys: Vec<String>,
+    },
+    /// settings.json no longer exists
+    SettingsMissing,
+}
+
+impl ActiveClaudeProfile {
+    /// Compare against the digests of the env currently in settings.json
+    pub fn drift(
+        &self,
+        profile: &ClaudeProfile,
+        current_env: Option<&BTreeMap<String, String>>,
+    ) -> ClaudeProfileDrift {
+        let Some(current_env) = current_env else {
+            return ClaudeProfileDrift::SettingsMissing;
+        };
+
+        let keys: Vec<String> = self
+            .applied_env
+            .iter()
+            .filter(|(key, digest)| current_env.get(*key) != Some(*digest))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        if !keys.is_empty() {
+            ClaudeProfileDrift::Modified { keys }
+        } else if profile.updated_at() > self.applied_at {
+            ClaudeProfileDrift::ProfileUpdated
+        } else {
+            ClaudeProfileDrift::InSync
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn config() -> ClaudeProfileConfig {
+        ClaudeProfileConfig {
+            name: " Work ".to_string(),
+            auth_token: "sk-work-token-123456".to_string(),
+            base_url: "https://relay.example.com/".to_string(),
+            models: ClaudeModelMapping {
+                haiku: Some("glm-4.5-air".to_string()),
+                sonnet: Some(" ".to_string()),
+                opus: None,
+            },
+            extra_env: BTreeMap::from([("HTTPS_PROXY".to_string(), "http://p:1".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_create_normalizes_fields() {
+        let profile = ClaudeProfile::create(config()).unwrap();
+
+        assert_eq!(profile.name(), "Work");
+        assert_eq!(profile.base_url(), "https://relay.example.com");
+        assert_eq!(profile.models().haiku.as_deref(), Some("glm-4.5-air"));
+        assert_eq!(profile.models().sonnet, None);
+        assert_eq!(profile.masked_token(), "sk-work-...3456");
+    }
+
+    #[test]
+    fn test_create_rejects_invalid_config() {
+        let mut bad_url = config();
+        bad_url.base_url = "relay.example.com".to_string();
+        assert!(ClaudeProfile::create(bad_url).is_err());
+
+        let mut reserved = config();
+        reserved
+            .extra_env
+            .insert("ANTHROPIC_BASE_URL".to_string(), "x".to_string());
+        assert!(ClaudeProfile::create(reserved).is_err());
+
+        let mut bad_key = config();
+        bad_key
+            .extra_env
+            .insert("1BAD-KEY".to_string(), "x".to_string());
+        assert!(ClaudeProfile::create(bad_key).is_err());
+    }
+
+    #[test]
+    fn test_drift_detection() {
+        let profile = ClaudeProfile::create(config()).unwrap();
+        let applied_env = BTreeMap::from([
+            ("ANTHROPIC_AUTH_TOKEN".to_string(), "d1".to_string()),
+            ("ANTHROPIC_BASE_URL".to_string(), "d2".to_string()),
+        ]);
+        let active = ActiveClaudeProfile {
+            profile_id: ClaudeProfileId::new(1),
+            applied_at: profile.updated_at() + Duration::seconds(1),
+            applied_env: applied_env.clone(),
+        };
+
+        assert_eq!(
+            active.drift(&profile, Some(&applied_env)),
+            ClaudeProfileDrift::InSync
+        );
+        assert_eq!(
+            active.drift(&profile, None),
+            ClaudeProfileDrift::SettingsMissing
+        );
+
+        let mut edited = applied_env.clone();
+        edited.insert("ANTHROPIC_BASE_URL".to_string(), "other".to_string());
+        assert_eq!(
+            active.drift(&profile, Some(&edited)),
+            ClaudeProfileDrift::Modified {
+                keys: vec!["ANTHROPIC_BASE_URL".to_string()]
+            }
+        );
+
+        let stale = ActiveClaudeProfile {
+            applied_at: profile.updated_at() - Duration::seconds(1),
+            ..active
+        };
+        assert_eq!(
+            stale.drift(&profile, Some(&applied_env)),
+            ClaudeProfileDrift::ProfileUpdated
+        );
+    }
+}
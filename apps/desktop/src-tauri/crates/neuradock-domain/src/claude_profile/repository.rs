@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+
+use super::{ActiveClaudeProfile, ClaudeProfile, ClaudeProfileId};
+use crate::shared::DomainError;
+
+/// Claude settings profiles and the record of the active one
+#[async_trait]
+pub trait ClaudeProfileRepository: Send + Sync {
+    async fn create(&self, profile: &ClaudeProfile) -> Result<ClaudeProfileId, DomainError>;
+
+    async fn update(&self, profile: &ClaudeProfile) -> Result<(), DomainError>;
+
+    /// Delete a profile, clearing the active record if it pointed to it
+    async fn delete(&self, id: &ClaudeProfileId) -> Result<(), DomainError>;
+
+    async fn find_by_id(&self, id: &ClaudeProfileId) -> Result<Option<ClaudeProfile>, DomainError>;
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<ClaudeProfile>, DomainError>;
+
+    async fn find_all(&self) -> Result<Vec<ClaudeProfile>, DomainError>;
+
+    async fn get_active(&self) -> Result<Option<ActiveClaudeProfile>, DomainError>;
+
+    async fn set_active(&self, active: &ActiveClaudeProfile) -> Result<(), DomainError>;
+
+    async fn clear_active(&self) -> Result<(), DomainError>;
+}
@@ -243,6 +243,38 @@ impl CodexAccount {
         self.updated_at = Utc::now();
     }
 
+    /// Store tokens from a refresh grant
+    ///
+    /// The refresh and ID tokens are only replaced when the endpoint rotated them.
+    pub fn apply_refreshed_tokens(
+        &mut self,
+        access_token: String,
+        refresh_token: Option<String>,
+        id_token: Option<String>,
+        account_id: Option<String>,
+        token_expires_at: Option<DateTime<Utc>>,
+    ) {
+        self.access_token = Some(access_token);
+        if let Some(rt) = refresh_token {
+            self.refresh_token = Some(rt);
+        }
+        if let Some(it) = id_token {
+            self.id_token = Some(it);
+        }
+        if let Some(aid) = account_id {
+            self.account_id = Some(aid);
+        }
+        self.token_expires_at = token_expires_at;
+        self.last_refresh_at = Some(Utc::now());
+        self.updated_at = Utc::now();
+    }
+
+    /// The refresh token was rejected, the account has to log in again
+    pub fn mark_expired(&mut self) {
+        self.status = CodexAccountStatus::Expired;
+        self.updated_at = Utc::now();
+    }
+
     pub fn set_payment_session(
         &mut self,
         web_session_cookie: String,
@@ -288,6 +320,15 @@ impl CodexAccount {
         }
     }
 
+    /// Active account whose access token expires within `lead` of `now`
+    pub fn needs_token_refresh(&self, now: DateTime<Utc>, lead: chrono::Duration) -> bool {
+        self.status == CodexAccountStatus::Active
+            && self.refresh_token.is_some()
+            && self
+                .token_expires_at
+                .is_some_and(|exp| exp - now <= lead)
+    }
+
     pub fn token_days_remaining(&self) -> Option<i64> {
         self.token_expires_at.map(|exp| {
             let remaining = exp - Utc::now();
@@ -0,0 +1,367 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::aggregate::{CodexAccount, CodexAccountId, CodexAccountStatus, CodexRateLimitWindow};
+use crate::shared::DomainError;
+
+pub const MIN_THRESHOLD_PERCENT: u32 = 50;
+pub const MAX_THRESHOLD_PERCENT: u32 = 100;
+pub const MIN_POLL_INTERVAL_MINUTES: u32 = 1;
+pub const MAX_POLL_INTERVAL_MINUTES: u32 = 24 * 60;
+
+/// Usage of an account, windows past their reset time count as empty
+///
+/// `None` when the account has no cached quota.
+pub fn effective_used_percent(account: &CodexAccount, now: DateTime<Utc>) -> Option<f64> {
+    let windows = windows(account);
+    if windows.is_empty() {
+        return None;
+    }
+    Some(
+        windows
+            .into_iter()
+            .map(|window| window_used_percent(window, now))
+            .fold(0.0, f64::max),
+    )
+}
+
+fn windows(account: &CodexAccount) -> Vec<&CodexRateLimitWindow> {
+    [account.primary_window(), account.secondary_window()]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn window_used_percent(window: &CodexRateLimitWindow, now: DateTime<Utc>) -> f64 {
+    match window.resets_at() {
+        Some(resets_at) if resets_at <= now => 0.0,
+        _ => window.used_percent(),
+    }
+}
+
+/// Switch the Codex CLI to another stored account when the active one runs low (singleton)
+///
+/// While switched away, `home_account_id` is the account the CLI used before and
+/// `switch_back_at` the reset time of its exhausted window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexAutoSwitchPolicy {
+    enabled: bool,
+    threshold_percent: u32,
+    poll_interval_minutes: u32,
+    switch_back: bool,
+    home_account_id: Option<CodexAccountId>,
+    switch_back_at: Option<DateTime<Utc>>,
+    last_checked_at: Option<DateTime<Utc>>,
+}
+
+impl Default for CodexAutoSwitchPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold_percent: 90,
+            poll_interval_minutes: 10,
+            switch_back: true,
+            home_account_id: None,
+            switch_back_at: None,
+            last_checked_at: None,
+        }
+    }
+}
+
+impl CodexAutoSwitchPolicy {
+    pub fn restore(
+        enabled: bool,
+        threshold_percent: u32,
+        poll_interval_minutes: u32,
+        switch_back: bool,
+        home_account_id: Option<CodexAccountId>,
+        switch_back_at: Option<DateTime<Utc>>,
+        last_checked_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            enabled,
+            threshold_percent,
+            poll_interval_minutes,
+            switch_back,
+            home_account_id,
+            switch_back_at,
+            last_checked_at,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        enabled: bool,
+        threshold_percent: u32,
+        poll_interval_minutes: u32,
+        switch_back: bool,
+    ) -> Result<(), DomainError> {
+        if !(MIN_THRESHOLD_PERCENT..=MAX_THRESHOLD_PERCENT).contains(&threshold_percent) {
+            return Err(DomainError::Validation(format!(
+                "Switch threshold must be between {}% and {}%",
+                MIN_THRESHOLD_PERCENT, MAX_THRESHOLD_PERCENT
+            )));
+        }
+        if !(MIN_POLL_INTERVAL_MINUTES..=MAX_POLL_INTERVAL_MINUTES).contains(&poll_interval_minutes)
+        {
+            return Err(DomainError::Validation(format!(
+                "Poll interval must be between {} and {} minutes",
+                MIN_POLL_INTERVAL_MINUTES, MAX_POLL_INTERVAL_MINUTES
+            )));
+        }
+
+        self.enabled = enabled;
+        self.threshold_percent = threshold_percent;
+        self.poll_interval_minutes = poll_interval_minutes;
+        self.switch_back = switch_back;
+        if !switch_back {
+            self.clear_switch();
+        }
+        Ok(())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn threshold_percent(&self) -> u32 {
+        self.threshold_percent
+    }
+
+    pub fn poll_interval_minutes(&self) -> u32 {
+        self.poll_interval_minutes
+    }
+
+    pub fn switch_back(&self) -> bool {
+        self.switch_back
+    }
+
+    pub fn home_account_id(&self) -> Option<&CodexAccountId> {
+        self.home_account_id.as_ref()
+    }
+
+    pub fn switch_back_at(&self) -> Option<DateTime<Utc>> {
+        self.switch_back_at
+    }
+
+    pub fn last_checked_at(&self) -> Option<DateTime<Utc>> {
+        self.last_checked_at
+    }
+
+    /// Whether a poll is due at `now`
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        match self.last_checked_at {
+            Some(last) => now - last >= Duration::minutes(self.poll_interval_minutes as i64),
+            None => true,
+        }
+    }
+
+    pub fn mark_checked(&mut self, at: DateTime<Utc>) {
+        self.last_checked_at = Some(at);
+    }
+
+    /// Whether any rate-limit window of `account` is at or above the threshold
+    pub fn is_exhausted(&self, account: &CodexAccount, now: DateTime<Utc>) -> bool {
+        effective_used_percent(account, now)
+            .is_some_and(|used| used >= self.threshold_percent as f64)
+    }
+
+    /// Latest reset time among the windows of `account` at or above the threshold
+    pub fn exhausted_until(
+        &self,
+        account: &CodexAccount,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        windows(account)
+            .into_iter()
+            .filter(|window| window_used_percent(window, now) >= self.threshold_percent as f64)
+            .filter_map(|window| window.resets_at())
+            .max()
+    }
+
+    /// Active account with valid tokens and the most headroom below the threshold
+    pub fn pick_target<'a>(
+        &self,
+        candidates: &'a [CodexAccount],
+        current: &CodexAccountId,
+        now: DateTime<Utc>,
+    ) -> Option<&'a CodexAccount> {
+        candidates
+            .iter()
+            .filter(|account| {
+                account.id() != current
+                    && *account.status() == CodexAccountStatus::Active
+                    && account.has_valid_tokens()
+                    && !account.is_token_expired()
+            })
+            .filter_map(|account| {
+                effective_used_percent(account, now)
+                    .filter(|used| *used < self.threshold_percent as f64)
+                    .map(|used| (account, used))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(account, _)| account)
+    }
+
+    /// Remember where to return once `home` resets
+    ///
+    /// Only the first switch of a chain is recorded, later hops keep the original home.
+    pub fn record_switch_away(&mut self, home: &CodexAccountId, resets_at: Option<DateTime<Utc>>) {
+        if !self.switch_back || self.home_account_id.is_some() {
+            return;
+        }
+        self.home_account_id = Some(home.clone());
+        self.switch_back_at = resets_at;
+    }
+
+    /// Home is still exhausted, try again at its new reset time
+    pub fn postpone_switch_back(&mut self, resets_at: Option<DateTime<Utc>>) {
+        self.switch_back_at = resets_at;
+    }
+
+    /// Whether the home account should be checked for a switch back
+    pub fn is_switch_back_due(&self, now: DateTime<Utc>) -> bool {
+        self.home_account_id.is_some() && self.switch_back_at.is_none_or(|at| now >= at)
+    }
+
+    pub fn clear_switch(&mut self) {
+        self.home_account_id = None;
+        self.switch_back_at = None;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CodexAutoSwitchReason {
+    /// The active account reached the usage threshold
+    ThresholdReached,
+    /// The original account's window reset
+    SwitchBack,
+}
+
+impl CodexAutoSwitchReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ThresholdReached => "threshold_reached",
+            Self::SwitchBack => "switch_back",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "switch_back" => Self::SwitchBack,
+            _ => Self::ThresholdReached,
+        }
+    }
+}
+
+/// One automatic change of the account in `~/.codex/auth.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexAutoSwitchEvent {
+    pub from_account_id: Option<CodexAccountId>,
+    pub from_email: String,
+    pub to_account_id: CodexAccountId,
+    pub to_email: String,
+    pub reason: CodexAutoSwitchReason,
+    /// Usage of the account switched away from, in percent
+    pub from_used_percent: Option<f64>,
+    pub switched_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codex::CodexAccountSource;
+
+    fn account(email: &str, windows: &[(f64, Option<DateTime<Utc>>)]) -> CodexAccount {
+        let mut account =
+            CodexAccount::new(email.to_string(), None, CodexAccountSource::Import).unwrap();
+        account.apply_tokens(
+            "access".to_string(),
+            "refresh".to_string(),
+            "id".to_string(),
+            None,
+            None,
+        );
+        let mut windows = windows
+            .iter()
+            .map(|(used, resets_at)| CodexRateLimitWindow::new(*used, Some(300), *resets_at));
+        account.apply_quota(None, None, None, None, windows.next(), windows.next());
+        account
+    }
+
+    #[test]
+    fn test_update_validates_ranges_and_clears_home_without_switch_back() {
+        let mut policy = CodexAutoSwitchPolicy::default();
+        assert!(policy.update(true, 40, 10, true).is_err());
+        assert!(policy.update(true, 90, 0, true).is_err());
+
+        policy.update(true, 80, 5, true).unwrap();
+        policy.record_switch_away(&CodexAccountId::from_string("home"), None);
+        assert!(policy.home_account_id().is_some());
+
+        policy.update(true, 80, 5, false).unwrap();
+        assert!(policy.home_account_id().is_none());
+    }
+
+    #[test]
+    fn test_reset_windows_count_as_empty() {
+        let now = Utc::now();
+        let policy = CodexAutoSwitchPolicy::default();
+        let current = account(
+            "a@example.com",
+            &[
+                (95.0, Some(now + Duration::hours(2))),
+                (40.0, Some(now + Duration::days(3))),
+            ],
+        );
+        assert!(policy.is_exhausted(&current, now));
+        assert_eq!(
+            policy.exhausted_until(&current, now),
+            Some(now + Duration::hours(2))
+        );
+
+        let reset = account(
+            "b@example.com",
+            &[(100.0, Some(now - Duration::minutes(1)))],
+        );
+        assert_eq!(effective_used_percent(&reset, now), Some(0.0));
+        assert!(!policy.is_exhausted(&reset, now));
+    }
+
+    #[test]
+    fn test_pick_target_prefers_most_headroom() {
+        let now = Utc::now();
+        let policy = CodexAutoSwitchPolicy::default();
+        let current = account("a@example.com", &[(95.0, None)]);
+        let busy = account("b@example.com", &[(70.0, None), (20.0, None)]);
+        let idle = account("c@example.com", &[(10.0, None)]);
+        let full = account("d@example.com", &[(92.0, None)]);
+        let unknown = account("e@example.com", &[]);
+        let mut expired = account("f@example.com", &[(0.0, None)]);
+        expired.mark_expired();
+
+        let candidates = vec![current.clone(), busy, idle, full, unknown, expired];
+        let target = policy.pick_target(&candidates, current.id(), now).unwrap();
+        assert_eq!(target.email(), "c@example.com");
+
+        assert!(policy
+            .pick_target(&candidates[..1], current.id(), now)
+            .is_none());
+    }
+
+    #[test]
+    fn test_switch_back_keeps_first_home() {
+        let now = Utc::now();
+        let mut policy = CodexAutoSwitchPolicy::default();
+        let home = CodexAccountId::from_string("home");
+        policy.record_switch_away(&home, Some(now + Duration::hours(1)));
+        policy.record_switch_away(&CodexAccountId::from_string("second"), None);
+
+        assert_eq!(policy.home_account_id(), Some(&home));
+        assert!(!policy.is_switch_back_due(now));
+        assert!(policy.is_switch_back_due(now + Duration::hours(1)));
+
+        policy.clear_switch();
+        assert!(!policy.is_switch_back_due(now + Duration::hours(1)));
+    }
+}
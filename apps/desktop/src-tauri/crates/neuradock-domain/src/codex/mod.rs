@@ -1,7 +1,20 @@
 pub mod aggregate;
+pub mod auto_switch;
+pub mod refresh_policy;
 pub mod repository;
+pub mod usage_history;
 
 pub use aggregate::{
     CodexAccount, CodexAccountId, CodexAccountSource, CodexAccountStatus, CodexRateLimitWindow,
 };
-pub use repository::CodexAccountRepository;
+pub use auto_switch::{
+    effective_used_percent, CodexAutoSwitchEvent, CodexAutoSwitchPolicy, CodexAutoSwitchReason,
+};
+pub use refresh_policy::{CodexTokenRefreshPolicy, DEFAULT_CODEX_TOKEN_URL};
+pub use repository::{
+    CodexAccountRepository, CodexAutoSwitchRepository, CodexTokenRefreshPolicyRepository,
+    CodexUsageHistoryRepository,
+};
+pub use usage_history::{
+    CodexUsageReminderKind, CodexUsageReminderSettings, CodexUsageSnapshot, CodexUsageWindowKind,
+};
@@ -0,0 +1,145 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::shared::DomainError;
+
+pub const DEFAULT_CODEX_TOKEN_URL: &str = "https://auth.openai.com/oauth/token";
+pub const MIN_REFRESH_BEFORE_MINUTES: u32 = 5;
+pub const MAX_REFRESH_BEFORE_MINUTES: u32 = 7 * 24 * 60;
+pub const DEFAULT_REFRESH_BEFORE_MINUTES: u32 = 24 * 60;
+
+/// Background refresh of Codex OAuth tokens (singleton)
+///
+/// Access tokens are refreshed once they expire within `refresh_before_minutes`.
+/// The token endpoint is configurable so a local stand-in can replace
+/// auth.openai.com.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexTokenRefreshPolicy {
+    enabled: bool,
+    refresh_before_minutes: u32,
+    token_url: String,
+    last_run_at: Option<DateTime<Utc>>,
+}
+
+impl Default for CodexTokenRefreshPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            refresh_before_minutes: DEFAULT_REFRESH_BEFORE_MINUTES,
+            token_url: DEFAULT_CODEX_TOKEN_URL.to_string(),
+            last_run_at: None,
+        }
+    }
+}
+
+impl CodexTokenRefreshPolicy {
+    pub fn restore(
+        enabled: bool,
+        refresh_before_minutes: u32,
+        token_url: String,
+        last_run_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            enabled,
+            refresh_before_minutes,
+            token_url,
+            last_run_at,
+        }
+    }
+
+    /// `token_url` of `None` resets the endpoint to auth.openai.com
+    pub fn update(
+        &mut self,
+        enabled: bool,
+        refresh_before_minutes: u32,
+        token_url: Option<&str>,
+    ) -> Result<(), DomainError> {
+        if !(MIN_REFRESH_BEFORE_MINUTES..=MAX_REFRESH_BEFORE_MINUTES)
+            .contains(&refresh_before_minutes)
+        {
+            return Err(DomainError::Validation(format!(
+                "Refresh lead time must be between {} and {} minutes",
+                MIN_REFRESH_BEFORE_MINUTES, MAX_REFRESH_BEFORE_MINUTES
+            )));
+        }
+
+        let token_url = match token_url.map(str::trim).filter(|url| !url.is_empty()) {
+            Some(url) => {
+                let parsed = url::Url::parse(url).map_err(|e| {
+                    DomainError::Validation(format!("Invalid token endpoint '{}': {}", url, e))
+                })?;
+                if !matches!(parsed.scheme(), "http" | "https") {
+                    return Err(DomainError::Validation(
+                        "Token endpoint must be an http(s) URL".to_string(),
+                    ));
+                }
+                url.to_string()
+            }
+            None => DEFAULT_CODEX_TOKEN_URL.to_string(),
+        };
+
+        self.enabled = enabled;
+        self.refresh_before_minutes = refresh_before_minutes;
+        self.token_url = token_url;
+        Ok(())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn refresh_before_minutes(&self) -> u32 {
+        self.refresh_before_minutes
+    }
+
+    pub fn refresh_before(&self) -> Duration {
+        Duration::minutes(self.refresh_before_minutes as i64)
+    }
+
+    pub fn token_url(&self) -> &str {
+        &self.token_url
+    }
+
+    pub fn last_run_at(&self) -> Option<DateTime<Utc>> {
+        self.last_run_at
+    }
+
+    pub fn mark_run(&mut self, at: DateTime<Utc>) {
+        self.last_run_at = Some(at);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy() {
+        let policy = CodexTokenRefreshPolicy::default();
+        assert!(policy.is_enabled());
+        assert_eq!(policy.token_url(), DEFAULT_CODEX_TOKEN_URL);
+        assert_eq!(policy.refresh_before(), Duration::hours(24));
+    }
+
+    #[test]
+    fn test_update_validates_lead_time_and_endpoint() {
+        let mut policy = CodexTokenRefreshPolicy::default();
+        assert!(policy.update(true, 1, None).is_err());
+        assert!(policy
+            .update(true, MAX_REFRESH_BEFORE_MINUTES + 1, None)
+            .is_err());
+        assert!(policy.update(true, 60, Some("not a url")).is_err());
+        assert!(policy
+            .update(true, 60, Some("ftp://localhost/token"))
+            .is_err());
+
+        policy
+            .update(false, 60, Some(" http://127.0.0.1:8080/oauth/token "))
+            .unwrap();
+        assert!(!policy.is_enabled());
+        assert_eq!(policy.token_url(), "http://127.0.0.1:8080/oauth/token");
+
+        policy.update(true, 60, Some("")).unwrap();
+        assert_eq!(policy.token_url(), DEFAULT_CODEX_TOKEN_URL);
+    }
+}
@@ -1,6 +1,12 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use super::aggregate::{CodexAccount, CodexAccountId};
+use super::auto_switch::{CodexAutoSwitchEvent, CodexAutoSwitchPolicy};
+use super::refresh_policy::CodexTokenRefreshPolicy;
+use super::usage_history::{
+    CodexUsageReminderKind, CodexUsageReminderSettings, CodexUsageSnapshot, CodexUsageWindowKind,
+};
 use crate::shared::DomainError;
 
 #[async_trait]
@@ -12,3 +18,45 @@ pub trait CodexAccountRepository: Send + Sync {
     async fn delete(&self, id: &CodexAccountId) -> Result<(), DomainError>;
     async fn count(&self) -> Result<i64, DomainError>;
 }
+
+#[async_trait]
+pub trait CodexTokenRefreshPolicyRepository: Send + Sync {
+    async fn get(&self) -> Result<CodexTokenRefreshPolicy, DomainError>;
+    async fn save(&self, policy: &CodexTokenRefreshPolicy) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+pub trait CodexAutoSwitchRepository: Send + Sync {
+    async fn get_policy(&self) -> Result<CodexAutoSwitchPolicy, DomainError>;
+    async fn save_policy(&self, policy: &CodexAutoSwitchPolicy) -> Result<(), DomainError>;
+    async fn record_switch(&self, event: &CodexAutoSwitchEvent) -> Result<(), DomainError>;
+    /// Most recent switches first
+    async fn recent_switches(&self, limit: u32) -> Result<Vec<CodexAutoSwitchEvent>, DomainError>;
+}
+
+#[async_trait]
+pub trait CodexUsageHistoryRepository: Send + Sync {
+    async fn record(&self, snapshots: &[CodexUsageSnapshot]) -> Result<(), DomainError>;
+    /// Snapshots of one account recorded in `[since, until]`, oldest first
+    async fn list(
+        &self,
+        account_id: &CodexAccountId,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<CodexUsageSnapshot>, DomainError>;
+    /// Most recent snapshot of every account and window
+    async fn latest(&self) -> Result<Vec<CodexUsageSnapshot>, DomainError>;
+    /// Returns the number of deleted snapshots
+    async fn delete_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DomainError>;
+    /// Claim a reminder for a window cycle, `false` when it was already sent
+    async fn claim_reminder(
+        &self,
+        account_id: &CodexAccountId,
+        window: CodexUsageWindowKind,
+        kind: CodexUsageReminderKind,
+        cycle: DateTime<Utc>,
+    ) -> Result<bool, DomainError>;
+    async fn get_settings(&self) -> Result<CodexUsageReminderSettings, DomainError>;
+    async fn save_settings(&self, settings: &CodexUsageReminderSettings)
+        -> Result<(), DomainError>;
+}
@@ -0,0 +1,249 @@
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::aggregate::{CodexAccount, CodexAccountId};
+
+/// Usage at or above this percentage triggers the "almost used up" reminder
+pub const USAGE_WARNING_PERCENT: f64 = 80.0;
+pub const USAGE_EXHAUSTED_PERCENT: f64 = 100.0;
+/// Reset reminders for windows that reset longer ago than this are dropped
+const RESET_REMINDER_GRACE_HOURS: i64 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CodexUsageWindowKind {
+    Primary,
+    Secondary,
+}
+
+impl CodexUsageWindowKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Primary => "primary",
+            Self::Secondary => "secondary",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "secondary" => Self::Secondary,
+            _ => Self::Primary,
+        }
+    }
+}
+
+/// Usage of one rate-limit window of a Codex account at a point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CodexUsageSnapshot {
+    pub account_id: CodexAccountId,
+    pub window: CodexUsageWindowKind,
+    pub used_percent: f64,
+    pub window_minutes: Option<i64>,
+    pub resets_at: Option<DateTime<Utc>>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl CodexUsageSnapshot {
+    /// Snapshots of the account's cached rate-limit windows
+    pub fn from_account(account: &CodexAccount, recorded_at: DateTime<Utc>) -> Vec<Self> {
+        [
+            (CodexUsageWindowKind::Primary, account.primary_window()),
+            (CodexUsageWindowKind::Secondary, account.secondary_window()),
+        ]
+        .into_iter()
+        .filter_map(|(kind, window)| {
+            window.map(|window| Self {
+                account_id: account.id().clone(),
+                window: kind,
+                used_percent: window.used_percent(),
+                window_minutes: window.window_minutes(),
+                resets_at: window.resets_at(),
+                recorded_at,
+            })
+        })
+        .collect()
+    }
+
+    /// Identifies the window cycle the snapshot belongs to
+    ///
+    /// The reset time is rounded to the minute so small drifts between
+    /// polls stay in the same cycle. Windows without a reset time fall back to
+    /// buckets of `window_minutes` (or a day).
+    pub fn cycle(&self) -> DateTime<Utc> {
+        match self.resets_at {
+            Some(resets_at) => resets_at
+                .duration_round(Duration::minutes(1))
+                .unwrap_or(resets_at),
+            None => {
+                let bucket = Duration::minutes(self.window_minutes.unwrap_or(24 * 60).max(1));
+                self.recorded_at
+                    .duration_trunc(bucket)
+                    .unwrap_or(self.recorded_at)
+            }
+        }
+    }
+
+    pub fn seconds_until_reset(&self, now: DateTime<Utc>) -> Option<i64> {
+        self.resets_at
+            .map(|resets_at| (resets_at - now).num_seconds().max(0))
+    }
+
+    /// Threshold reminder for this snapshot, if usage crossed one
+    pub fn threshold_reminder(&self) -> Option<CodexUsageReminderKind> {
+        if self.used_percent >= USAGE_EXHAUSTED_PERCENT {
+            Some(CodexUsageReminderKind::Exhausted)
+        } else if self.used_percent >= USAGE_WARNING_PERCENT {
+            Some(CodexUsageReminderKind::Warning)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the window was running low and reset recently enough to remind about it
+    ///
+    /// Windows that never reached the warning threshold reset silently.
+    pub fn is_reset_reminder_due(&self, now: DateTime<Utc>) -> bool {
+        self.used_percent >= USAGE_WARNING_PERCENT
+            && self.resets_at.is_some_and(|resets_at| {
+                resets_at <= now && now - resets_at <= Duration::hours(RESET_REMINDER_GRACE_HOURS)
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CodexUsageReminderKind {
+    /// Usage reached 80%
+    Warning,
+    /// Usage reached 100%
+    Exhausted,
+    /// The window reset
+    Reset,
+}
+
+impl CodexUsageReminderKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Warning => "warning",
+            Self::Exhausted => "exhausted",
+            Self::Reset => "reset",
+        }
+    }
+}
+
+/// Which usage reminders are sent (singleton)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodexUsageReminderSettings {
+    threshold_reminders: bool,
+    reset_reminders: bool,
+}
+
+impl Default for CodexUsageReminderSettings {
+    fn default() -> Self {
+        Self {
+            threshold_reminders: true,
+            reset_reminders: true,
+        }
+    }
+}
+
+impl CodexUsageReminderSettings {
+    pub fn restore(threshold_reminders: bool, reset_reminders: bool) -> Self {
+        Self {
+            threshold_reminders,
+            reset_reminders,
+        }
+    }
+
+    pub fn update(&mut self, threshold_reminders: bool, reset_reminders: bool) {
+        self.threshold_reminders = threshold_reminders;
+        self.reset_reminders = reset_reminders;
+    }
+
+    pub fn threshold_reminders(&self) -> bool {
+        self.threshold_reminders
+    }
+
+    pub fn reset_reminders(&self) -> bool {
+        self.reset_reminders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codex::{CodexAccountSource, CodexRateLimitWindow};
+    use chrono::TimeZone;
+
+    fn snapshot(used_percent: f64, resets_at: Option<DateTime<Utc>>) -> CodexUsageSnapshot {
+        CodexUsageSnapshot {
+            account_id: CodexAccountId::from_string("acc"),
+            window: CodexUsageWindowKind::Primary,
+            used_percent,
+            window_minutes: Some(300),
+            resets_at,
+            recorded_at: Utc.with_ymd_and_hms(2026, 10, 1, 12, 34, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_from_account_takes_both_windows() {
+        let mut account = CodexAccount::new(
+            "a@example.com".to_string(),
+            None,
+            CodexAccountSource::Import,
+        )
+        .unwrap();
+        assert!(CodexUsageSnapshot::from_account(&account, Utc::now()).is_empty());
+
+        account.apply_quota(
+            None,
+            None,
+            None,
+            None,
+            Some(CodexRateLimitWindow::new(42.0, Some(300), None)),
+            Some(CodexRateLimitWindow::new(7.0, Some(10080), None)),
+        );
+        let snapshots = CodexUsageSnapshot::from_account(&account, Utc::now());
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[1].window, CodexUsageWindowKind::Secondary);
+        assert_eq!(snapshots[1].used_percent, 7.0);
+    }
+
+    #[test]
+    fn test_cycle_is_stable_across_polls() {
+        let resets_at = Utc.with_ymd_and_hms(2026, 10, 1, 15, 0, 0).unwrap();
+        let a = snapshot(10.0, Some(resets_at + Duration::seconds(2)));
+        let b = snapshot(20.0, Some(resets_at - Duration::seconds(3)));
+        assert_eq!(a.cycle(), b.cycle());
+
+        // Without a reset time snapshots are bucketed by the window length
+        let mut unknown = snapshot(10.0, None);
+        unknown.window_minutes = None;
+        assert_eq!(
+            unknown.cycle(),
+            Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_reminders() {
+        let now = Utc::now();
+        assert_eq!(snapshot(79.9, None).threshold_reminder(), None);
+        assert_eq!(
+            snapshot(80.0, None).threshold_reminder(),
+            Some(CodexUsageReminderKind::Warning)
+        );
+        assert_eq!(
+            snapshot(100.0, None).threshold_reminder(),
+            Some(CodexUsageReminderKind::Exhausted)
+        );
+
+        assert!(!snapshot(90.0, Some(now + Duration::minutes(1))).is_reset_reminder_due(now));
+        assert!(snapshot(90.0, Some(now - Duration::minutes(1))).is_reset_reminder_due(now));
+        assert!(!snapshot(90.0, Some(now - Duration::days(1))).is_reset_reminder_due(now));
+        assert!(!snapshot(50.0, Some(now - Duration::minutes(1))).is_reset_reminder_due(now));
+        assert_eq!(
+            snapshot(50.0, Some(now + Duration::seconds(90))).seconds_until_reset(now),
+            Some(90)
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use super::CurrencySettings;
+use crate::shared::DomainError;
+
+/// Currency settings repository trait
+#[async_trait]
+pub trait CurrencySettingsRepository: Send + Sync {
+    /// Get display currency, exchange rates and provider units
+    async fn get(&self) -> Result<CurrencySettings, DomainError>;
+
+    /// Replace the stored currency settings
+    async fn save(&self, settings: &CurrencySettings) -> Result<(), DomainError>;
+}
@@ -0,0 +1,329 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::shared::DomainError;
+
+/// Currency every exchange rate is expressed against
+pub const BASE_CURRENCY: &str = "USD";
+
+/// Unit a provider reports balances in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceUnit {
+    Usd,
+    Cny,
+    /// Raw new-api quota units
+    QuotaUnits,
+    Tokens,
+}
+
+impl BalanceUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BalanceUnit::Usd => "usd",
+            BalanceUnit::Cny => "cny",
+            BalanceUnit::QuotaUnits => "quota_units",
+            BalanceUnit::Tokens => "tokens",
+        }
+    }
+
+    /// Currency the provider's conversion factor converts into.
+    ///
+    /// Quota units and tokens have no currency of their own; their factor is
+    /// the USD price of one unit.
+    pub fn settlement_currency(&self) -> &'static str {
+        match self {
+            BalanceUnit::Cny => "CNY",
+            BalanceUnit::Usd | BalanceUnit::QuotaUnits | BalanceUnit::Tokens => BASE_CURRENCY,
+        }
+    }
+}
+
+impl fmt::Display for BalanceUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for BalanceUnit {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "usd" => Ok(BalanceUnit::Usd),
+            "cny" => Ok(BalanceUnit::Cny),
+            "quota_units" => Ok(BalanceUnit::QuotaUnits),
+            "tokens" => Ok(BalanceUnit::Tokens),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid balance unit: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// How a provider's reported amounts map onto a currency
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProviderBalanceUnit {
+    pub unit: BalanceUnit,
+    /// Multiplier from the reported amount to the unit's settlement currency
+    pub conversion_factor: f64,
+}
+
+impl Default for ProviderBalanceUnit {
+    /// Balances are already parsed into USD by the user info client
+    fn default() -> Self {
+        Self {
+            unit: BalanceUnit::Usd,
+            conversion_factor: 1.0,
+        }
+    }
+}
+
+/// Display currency, exchange rate table and per-provider balance units
+///
+/// Exchange rates are stored as units of the currency per 1 USD, so USD is
+/// always present with a rate of 1. Normalizing an amount converts it with the
+/// provider's factor, then from the settlement currency into the display
+/// currency.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencySettings {
+    display_currency: String,
+    exchange_rates: BTreeMap<String, f64>,
+    provider_units: HashMap<String, ProviderBalanceUnit>,
+}
+
+impl Default for CurrencySettings {
+    fn default() -> Self {
+        Self {
+            display_currency: BASE_CURRENCY.to_string(),
+            exchange_rates: BTreeMap::from([
+                (BASE_CURRENCY.to_string(), 1.0),
+                ("CNY".to_string(), 7.2),
+            ]),
+            provider_units: HashMap::new(),
+        }
+    }
+}
+
+impl CurrencySettings {
+    pub fn restore(
+        display_currency: String,
+        exchange_rates: BTreeMap<String, f64>,
+        provider_units: HashMap<String, ProviderBalanceUnit>,
+    ) -> Self {
+        let mut exchange_rates = exchange_rates;
+        exchange_rates.insert(BASE_CURRENCY.to_string(), 1.0);
+
+        Self {
+            display_currency,
+            exchange_rates,
+            provider_units,
+        }
+    }
+
+    /// Replace all settings after validating them as a whole
+    pub fn update(
+        &mut self,
+        display_currency: &str,
+        exchange_rates: BTreeMap<String, f64>,
+        provider_units: HashMap<String, ProviderBalanceUnit>,
+    ) -> Result<(), DomainError> {
+        let mut rates = BTreeMap::new();
+        for (code, rate) in exchange_rates {
+            let code = normalize_currency_code(&code)?;
+            if !rate.is_finite() || rate <= 0.0 {
+                return Err(DomainError::Validation(format!(
+                    "Exchange rate for {} must be a positive number",
+                    code
+                )));
+            }
+            if code == BASE_CURRENCY && (rate - 1.0).abs() > f64::EPSILON {
+                return Err(DomainError::Validation(format!(
+                    "Exchange rate for {} is fixed at 1",
+                    BASE_CURRENCY
+                )));
+            }
+            rates.insert(code, rate);
+        }
+        rates.insert(BASE_CURRENCY.to_string(), 1.0);
+
+        let display_currency = normalize_currency_code(display_currency)?;
+        if !rates.contains_key(&display_currency) {
+            return Err(DomainError::Validation(format!(
+                "No exchange rate configured for display currency {}",
+                display_currency
+            )));
+        }
+
+        for (provider_id, unit) in &provider_units {
+            if !unit.conversion_factor.is_finite() || unit.conversion_factor <= 0.0 {
+                return Err(DomainError::Validation(format!(
+                    "Conversion factor for provider {} must be a positive number",
+                    provider_id
+                )));
+            }
+            let settlement = unit.unit.settlement_currency();
+            if !rates.contains_key(settlement) {
+                return Err(DomainError::Validation(format!(
+                    "No exchange rate configured for {} (used by provider {})",
+                    settlement, provider_id
+                )));
+            }
+        }
+
+        self.display_currency = display_currency;
+        self.exchange_rates = rates;
+        self.provider_units = provider_units;
+        Ok(())
+    }
+
+    pub fn display_currency(&self) -> &str {
+        &self.display_currency
+    }
+
+    pub fn exchange_rates(&self) -> &BTreeMap<String, f64> {
+        &self.exchange_rates
+    }
+
+    pub fn provider_units(&self) -> &HashMap<String, ProviderBalanceUnit> {
+        &self.provider_units
+    }
+
+    /// Unit configured for a provider (USD with factor 1 when unset)
+    pub fn unit_for(&self, provider_id: &str) -> ProviderBalanceUnit {
+        self.provider_units
+            .get(provider_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Multiplier from a provider's reported amount to the display currency
+    pub fn normalization_factor(&self, provider_id: &str) -> f64 {
+        let unit = self.unit_for(provider_id);
+        let from_rate = self
+            .exchange_rates
+            .get(unit.unit.settlement_currency())
+            .copied()
+            .unwrap_or(1.0);
+        let to_rate = self
+            .exchange_rates
+            .get(&self.display_currency)
+            .copied()
+            .unwrap_or(1.0);
+
+        unit.conversion_factor * to_rate / from_rate
+    }
+
+    /// Convert a provider's reported amount into the display currency
+    pub fn normalize(&self, provider_id: &str, amount: f64) -> f64 {
+        amount * self.normalization_factor(provider_id)
+    }
+
+    /// Symbol used when formatting amounts in the display currency
+    pub fn display_symbol(&self) -> String {
+        match self.display_currency.as_str() {
+            "USD" => "$".to_string(),
+            "CNY" | "JPY" => "¥".to_string(),
+            "EUR" => "€".to_string(),
+            "GBP" => "£".to_string(),
+            other => format!("{} ", other),
+        }
+    }
+}
+
+fn normalize_currency_code(code: &str) -> Result<String, DomainError> {
+    let code = code.trim().to_uppercase();
+    if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(DomainError::Validation(format!(
+            "Invalid currency code: {}",
+            code
+        )));
+    }
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rates(pairs: &[(&str, f64)]) -> BTreeMap<String, f64> {
+        pairs.iter().map(|(c, r)| (c.to_string(), *r)).collect()
+    }
+
+    #[test]
+    fn test_default_is_identity() {
+        let settings = CurrencySettings::default();
+        assert_eq!(settings.display_currency(), "USD");
+        assert_eq!(settings.normalize("any-provider", 12.5), 12.5);
+        assert_eq!(settings.display_symbol(), "$");
+    }
+
+    #[test]
+    fn test_normalize_across_units() {
+        let mut settings = CurrencySettings::default();
+        let units = HashMap::from([
+            (
+                "cny-provider".to_string(),
+                ProviderBalanceUnit {
+                    unit: BalanceUnit::Cny,
+                    conversion_factor: 1.0,
+                },
+            ),
+            (
+                "quota-provider".to_string(),
+                ProviderBalanceUnit {
+                    unit: BalanceUnit::QuotaUnits,
+                    conversion_factor: 1.0 / 500_000.0,
+                },
+            ),
+        ]);
+        settings
+            .update("cny", rates(&[("CNY", 8.0)]), units)
+            .unwrap();
+
+        assert_eq!(settings.display_currency(), "CNY");
+        assert_eq!(settings.normalize("cny-provider", 10.0), 10.0);
+        assert_eq!(settings.normalize("quota-provider", 500_000.0), 8.0);
+        assert_eq!(settings.normalize("usd-provider", 2.0), 16.0);
+    }
+
+    #[test]
+    fn test_update_validation() {
+        let mut settings = CurrencySettings::default();
+
+        assert!(settings.update("EUR", rates(&[]), HashMap::new()).is_err());
+        assert!(settings
+            .update("USD", rates(&[("USD", 2.0)]), HashMap::new())
+            .is_err());
+        assert!(settings
+            .update("USD", rates(&[("CNY", 0.0)]), HashMap::new())
+            .is_err());
+        assert!(settings.update("US", rates(&[]), HashMap::new()).is_err());
+
+        let cny_without_rate = HashMap::from([(
+            "p".to_string(),
+            ProviderBalanceUnit {
+                unit: BalanceUnit::Cny,
+                conversion_factor: 1.0,
+            },
+        )]);
+        assert!(settings
+            .update("USD", rates(&[]), cny_without_rate)
+            .is_err());
+
+        // Failed updates leave settings untouched
+        assert_eq!(settings.exchange_rates().get("CNY"), Some(&7.2));
+    }
+
+    #[test]
+    fn test_balance_unit_from_str() {
+        assert_eq!(
+            "quota_units".parse::<BalanceUnit>().unwrap(),
+            BalanceUnit::QuotaUnits
+        );
+        assert!("gold".parse::<BalanceUnit>().is_err());
+    }
+}
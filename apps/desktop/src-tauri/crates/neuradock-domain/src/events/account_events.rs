@@ -2,12 +2,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::any::Any;
 
-use crate::events::DomainEvent;
-use crate::shared::{AccountId, ProviderId};
+use crate::events::{DomainEvent, ReplayableEvent};
+use crate::shared::{AccountId, DomainError, ProviderId};
 
-/// Macro to implement DomainEvent trait with type name
+/// Macro to implement DomainEvent and ReplayableEvent for account events
 macro_rules! impl_domain_event {
-    ($type:ty) => {
+    ($type:ty, $name:literal) => {
         impl DomainEvent for $type {
             fn as_any(&self) -> &(dyn Any + Send + Sync) {
                 self
@@ -16,6 +16,26 @@ macro_rules! impl_domain_event {
             fn event_type_name(&self) -> &'static str {
                 std::any::type_name::<Self>()
             }
+
+            fn event_name(&self) -> &'static str {
+                $name
+            }
+
+            fn aggregate_id(&self) -> Option<String> {
+                Some(self.account_id.as_str().to_string())
+            }
+
+            fn occurred_at(&self) -> DateTime<Utc> {
+                self.occurred_at
+            }
+
+            fn to_payload(&self) -> Result<String, DomainError> {
+                serde_json::to_string(self).map_err(|e| DomainError::Serialization(e.to_string()))
+            }
+        }
+
+        impl ReplayableEvent for $type {
+            const EVENT_NAME: &'static str = $name;
         }
     };
 }
@@ -30,7 +50,7 @@ pub struct AccountCreated {
     pub occurred_at: DateTime<Utc>,
 }
 
-impl_domain_event!(AccountCreated);
+impl_domain_event!(AccountCreated, "account_created");
 
 /// Event fired when an account is updated
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,7 +63,7 @@ pub struct AccountUpdated {
     pub occurred_at: DateTime<Utc>,
 }
 
-impl_domain_event!(AccountUpdated);
+impl_domain_event!(AccountUpdated, "account_updated");
 
 /// Event fired when an account is deleted
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,7 +73,7 @@ pub struct AccountDeleted {
     pub occurred_at: DateTime<Utc>,
 }
 
-impl_domain_event!(AccountDeleted);
+impl_domain_event!(AccountDeleted, "account_deleted");
 
 /// Event fired when an account is toggled (enabled/disabled)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,7 +83,7 @@ pub struct AccountToggled {
     pub occurred_at: DateTime<Utc>,
 }
 
-impl_domain_event!(AccountToggled);
+impl_domain_event!(AccountToggled, "account_toggled");
 
 /// Event fired when a check-in is completed
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -82,7 +102,7 @@ pub struct CheckInBalance {
     pub total_quota: f64,
 }
 
-impl_domain_event!(CheckInCompleted);
+impl_domain_event!(CheckInCompleted, "check_in_completed");
 
 /// Event fired when balance is updated
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -94,4 +114,26 @@ pub struct BalanceUpdated {
     pub occurred_at: DateTime<Utc>,
 }
 
-impl_domain_event!(BalanceUpdated);
+impl_domain_event!(BalanceUpdated, "balance_updated");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_payload_round_trip() {
+        let event = AccountToggled {
+            account_id: AccountId::from_string("acc-1"),
+            enabled: false,
+            occurred_at: Utc::now(),
+        };
+
+        assert_eq!(event.event_name(), AccountToggled::EVENT_NAME);
+        assert_eq!(event.aggregate_id().as_deref(), Some("acc-1"));
+
+        let decoded = AccountToggled::decode(&event.to_payload().unwrap()).unwrap();
+        let decoded = decoded.as_any().downcast_ref::<AccountToggled>().unwrap();
+        assert!(!decoded.enabled);
+        assert_eq!(decoded.occurred_at, event.occurred_at);
+    }
+}
@@ -1,9 +1,14 @@
+use chrono::{DateTime, Utc};
 use std::any::Any;
 
+use crate::shared::DomainError;
+
 pub mod account_events;
 pub mod event_bus;
+pub mod store;
 
 pub use event_bus::{DynamicEventHandler, EventBus, EventHandler, TypedEventHandlerWrapper};
+pub use store::{EventStore, ReplayableEvent, StoredEvent};
 
 /// Base trait for all domain events
 /// All events must be Send + Sync for thread safety
@@ -15,4 +20,24 @@ pub trait DomainEvent: Send + Sync + Any {
     /// Get the type name of this event for routing/matching
     /// This should return the same value as std::any::type_name::<Self>()
     fn event_type_name(&self) -> &'static str;
+
+    /// Stable name persisted in the event store
+    /// Unlike the type name it does not change when the event type moves
+    fn event_name(&self) -> &'static str {
+        self.event_type_name()
+    }
+
+    /// Aggregate the event belongs to, e.g. the account id
+    fn aggregate_id(&self) -> Option<String> {
+        None
+    }
+
+    fn occurred_at(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    /// JSON payload persisted in the event store
+    fn to_payload(&self) -> Result<String, DomainError> {
+        Ok("null".to_string())
+    }
 }
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+
+use super::DomainEvent;
+use crate::shared::DomainError;
+
+/// Event that can be decoded from the event store and replayed to handlers
+pub trait ReplayableEvent: DomainEvent + DeserializeOwned + Sized {
+    /// Same value as `DomainEvent::event_name`
+    const EVENT_NAME: &'static str;
+
+    fn decode(payload: &str) -> Result<Box<dyn DomainEvent>, DomainError> {
+        let event: Self = serde_json::from_str(payload)
+            .map_err(|e| DomainError::Deserialization(e.to_string()))?;
+        Ok(Box::new(event))
+    }
+}
+
+/// Published event as persisted in the event store
+#[derive(Debug, Clone)]
+pub struct StoredEvent {
+    /// Monotonic position in the stream, starting at 1
+    pub sequence: i64,
+    pub event_name: String,
+    pub aggregate_id: Option<String>,
+    pub payload: String,
+    pub occurred_at: DateTime<Utc>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Append-only store of published domain events plus subscriber checkpoints
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// Append an event and return its sequence number
+    async fn append(&self, event: &dyn DomainEvent) -> Result<i64, DomainError>;
+
+    /// Events with a sequence greater than `after_sequence`, oldest first
+    async fn read_after(
+        &self,
+        after_sequence: i64,
+        limit: u32,
+    ) -> Result<Vec<StoredEvent>, DomainError>;
+
+    /// Events of one aggregate, newest first, optionally before a sequence
+    async fn aggregate_timeline(
+        &self,
+        aggregate_id: &str,
+        before_sequence: Option<i64>,
+        limit: u32,
+    ) -> Result<Vec<StoredEvent>, DomainError>;
+
+    /// Sequence of the newest event, 0 when the store is empty
+    async fn last_sequence(&self) -> Result<i64, DomainError>;
+
+    /// Last sequence a subscriber has handled, `None` for a new subscriber
+    async fn checkpoint(&self, subscriber: &str) -> Result<Option<i64>, DomainError>;
+
+    /// Move a subscriber's checkpoint forward, it never moves back
+    async fn save_checkpoint(&self, subscriber: &str, sequence: i64) -> Result<(), DomainError>;
+}
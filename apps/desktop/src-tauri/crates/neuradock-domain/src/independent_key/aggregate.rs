@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::str::FromStr;
 
+use super::validation::KeyValidation;
 use crate::shared::DomainError;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
@@ -89,6 +90,7 @@ pub struct IndependentApiKey {
     organization_id: Option<String>,
     description: Option<String>,
     is_active: bool,
+    last_validation: Option<KeyValidation>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -109,6 +111,7 @@ impl IndependentApiKey {
             organization_id: config.organization_id,
             description: config.description,
             is_active: true,
+            last_validation: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -136,6 +139,7 @@ impl IndependentApiKey {
             organization_id: config.organization_id,
             description: config.description,
             is_active,
+            last_validation: None,
             created_at,
             updated_at,
         }
@@ -146,6 +150,11 @@ impl IndependentApiKey {
         self
     }
 
+    pub fn with_last_validation(mut self, validation: Option<KeyValidation>) -> Self {
+        self.last_validation = validation;
+        self
+    }
+
     // Getters
     pub fn id(&self) -> Option<&IndependentKeyId> {
         self.id.as_ref()
@@ -191,6 +200,10 @@ impl IndependentApiKey {
         self.is_active
     }
 
+    pub fn last_validation(&self) -> Option<&KeyValidation> {
+        self.last_validation.as_ref()
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -199,6 +212,17 @@ impl IndependentApiKey {
         self.updated_at
     }
 
+    /// OpenAI-compatible endpoint other than api.openai.com, which may offer a billing API
+    pub fn is_openai_compatible_relay(&self) -> bool {
+        let official_host = url::Url::parse(KeyProviderType::OpenAI.default_base_url())
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string));
+        let host = url::Url::parse(self.base_url.trim())
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string));
+        self.provider_type != KeyProviderType::Anthropic && host.is_some() && host != official_host
+    }
+
     pub fn masked_key(&self) -> String {
         let char_count = self.api_key.chars().count();
         if char_count <= 12 {
@@ -224,12 +248,17 @@ impl IndependentApiKey {
         if let Some(n) = name {
             self.name = n;
         }
+        let credentials_changed = api_key.is_some() || base_url.is_some();
         if let Some(k) = api_key {
             self.api_key = k;
         }
         if let Some(u) = base_url {
             self.base_url = u;
         }
+        if credentials_changed {
+            // The old result says nothing about the new key or endpoint
+            self.last_validation = None;
+        }
         if organization_id.is_some() {
             self.organization_id = organization_id;
         }
@@ -239,6 +268,11 @@ impl IndependentApiKey {
         self.updated_at = Utc::now();
     }
 
+    /// Validation results are not configuration changes, `updated_at` stays
+    pub fn record_validation(&mut self, validation: KeyValidation) {
+        self.last_validation = Some(validation);
+    }
+
     pub fn set_active(&mut self, active: bool) {
         self.is_active = active;
         self.updated_at = Utc::now();
@@ -265,6 +299,7 @@ mod tests {
         assert_eq!(key.base_url(), "https://api.openai.com/v1");
         assert_eq!(key.masked_key(), "sk-test1...3456");
         assert!(key.is_active());
+        assert!(!key.is_openai_compatible_relay());
     }
 
     #[test]
@@ -281,6 +316,7 @@ mod tests {
 
         assert_eq!(key.provider_display_name(), "MyProvider");
         assert_eq!(key.base_url(), "https://custom.api.com/v1");
+        assert!(key.is_openai_compatible_relay());
     }
 
     #[test]
@@ -308,6 +344,36 @@ mod tests {
         assert_eq!(key.description(), Some("New description"));
     }
 
+    #[test]
+    fn test_changing_credentials_clears_validation() {
+        use crate::independent_key::{KeyValidation, KeyValidationStatus};
+
+        let mut key = IndependentApiKey::create(IndependentApiKeyConfig {
+            name: "Test".to_string(),
+            provider_type: KeyProviderType::OpenAI,
+            custom_provider_name: None,
+            api_key: "sk-old".to_string(),
+            base_url: None,
+            organization_id: None,
+            description: None,
+        });
+        key.record_validation(KeyValidation {
+            status: KeyValidationStatus::Valid,
+            status_code: Some(200),
+            message: None,
+            latency_ms: 120,
+            models: vec!["gpt-4o".to_string()],
+            credit: None,
+            validated_at: Utc::now(),
+        });
+
+        key.update(Some("Renamed".to_string()), None, None, None, None);
+        assert!(key.last_validation().is_some_and(|v| v.is_valid()));
+
+        key.update(None, Some("sk-new".to_string()), None, None, None);
+        assert!(key.last_validation().is_none());
+    }
+
     #[test]
     fn test_masked_key_handles_unicode() {
         let key = IndependentApiKey::create(IndependentApiKeyConfig {
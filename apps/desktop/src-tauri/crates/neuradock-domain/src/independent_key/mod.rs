@@ -1,7 +1,9 @@
 mod aggregate;
 mod repository;
+mod validation;
 
 pub use aggregate::{
     IndependentApiKey, IndependentApiKeyConfig, IndependentKeyId, KeyProviderType,
 };
 pub use repository::IndependentKeyRepository;
+pub use validation::{KeyCredit, KeyValidation, KeyValidationStatus};
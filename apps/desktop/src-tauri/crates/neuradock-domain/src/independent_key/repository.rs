@@ -1,4 +1,4 @@
-use crate::independent_key::{IndependentApiKey, IndependentKeyId, KeyProviderType};
+use crate::independent_key::{IndependentApiKey, IndependentKeyId, KeyProviderType, KeyValidation};
 use crate::shared::DomainError;
 use async_trait::async_trait;
 
@@ -17,4 +17,10 @@ pub trait IndependentKeyRepository: Send + Sync {
         provider_type: &KeyProviderType,
     ) -> Result<Vec<IndependentApiKey>, DomainError>;
     async fn find_active(&self) -> Result<Vec<IndependentApiKey>, DomainError>;
+    /// Store the outcome of a validation without touching the key itself
+    async fn record_validation(
+        &self,
+        id: &IndependentKeyId,
+        validation: &KeyValidation,
+    ) -> Result<(), DomainError>;
 }
@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+pub enum KeyValidationStatus {
+    /// The models endpoint accepted the key
+    Valid,
+    /// The endpoint answered 401/403, the key is wrong or revoked
+    Invalid,
+    /// Network error, timeout or an unexpected response
+    Error,
+}
+
+impl KeyValidationStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Valid => "valid",
+            Self::Invalid => "invalid",
+            Self::Error => "error",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "valid" => Self::Valid,
+            "invalid" => Self::Invalid,
+            _ => Self::Error,
+        }
+    }
+
+    /// Status for an HTTP response of the models endpoint
+    pub fn from_status_code(status_code: u16) -> Self {
+        match status_code {
+            200..=299 => Self::Valid,
+            401 | 403 => Self::Invalid,
+            _ => Self::Error,
+        }
+    }
+}
+
+/// Remaining credit reported by an OpenAI-compatible relay, in USD
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Type)]
+pub struct KeyCredit {
+    pub total: f64,
+    pub used: f64,
+}
+
+impl KeyCredit {
+    pub fn remaining(&self) -> f64 {
+        (self.total - self.used).max(0.0)
+    }
+}
+
+/// Outcome of the last validation of an independent key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct KeyValidation {
+    pub status: KeyValidationStatus,
+    pub status_code: Option<u16>,
+    pub message: Option<String>,
+    pub latency_ms: u64,
+    /// Model IDs listed by the endpoint, empty unless valid
+    pub models: Vec<String>,
+    /// Only set when the endpoint offers a billing API
+    pub credit: Option<KeyCredit>,
+    pub validated_at: DateTime<Utc>,
+}
+
+impl KeyValidation {
+    pub fn is_valid(&self) -> bool {
+        self.status == KeyValidationStatus::Valid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_from_status_code() {
+        assert_eq!(
+            KeyValidationStatus::from_status_code(200),
+            KeyValidationStatus::Valid
+        );
+        assert_eq!(
+            KeyValidationStatus::from_status_code(401),
+            KeyValidationStatus::Invalid
+        );
+        assert_eq!(
+            KeyValidationStatus::from_status_code(403),
+            KeyValidationStatus::Invalid
+        );
+        assert_eq!(
+            KeyValidationStatus::from_status_code(502),
+            KeyValidationStatus::Error
+        );
+        assert_eq!(
+            KeyValidationStatus::parse(KeyValidationStatus::Invalid.as_str()),
+            KeyValidationStatus::Invalid
+        );
+    }
+
+    #[test]
+    fn test_credit_remaining_never_negative() {
+        let credit = KeyCredit {
+            total: 10.0,
+            used: 12.5,
+        };
+        assert_eq!(credit.remaining(), 0.0);
+        assert_eq!(
+            KeyCredit {
+                total: 10.0,
+                used: 2.5
+            }
+            .remaining(),
+            7.5
+        );
+    }
+}
@@ -2,14 +2,18 @@
 // No dependencies on infrastructure or presentation layers
 
 pub mod account;
+pub mod audit;
 pub mod codex;
 pub mod ai_chat;
 pub mod balance;
 pub mod balance_history;
 pub mod check_in;
+pub mod claude_profile;
+pub mod currency;
 pub mod custom_node;
 pub mod events;
 pub mod independent_key;
+pub mod network_settings;
 pub mod notification;
 pub mod provider_models;
 pub mod proxy_config;
@@ -0,0 +1,5 @@
+mod repository;
+mod settings;
+
+pub use repository::*;
+pub use settings::*;
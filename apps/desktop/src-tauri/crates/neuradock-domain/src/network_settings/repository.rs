@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use super::NetworkSettings;
+use crate::shared::DomainError;
+
+/// Network settings repository trait
+#[async_trait]
+pub trait NetworkSettingsRepository: Send + Sync {
+    /// Get default timeouts and retry policy with per-provider overrides
+    async fn get(&self) -> Result<NetworkSettings, DomainError>;
+
+    /// Replace the stored network settings
+    async fn save(&self, settings: &NetworkSettings) -> Result<(), DomainError>;
+}
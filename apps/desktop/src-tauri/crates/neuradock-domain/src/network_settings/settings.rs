@@ -0,0 +1,282 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use crate::shared::DomainError;
+
+const WAF_WAIT_MS: RangeInclusive<u64> = 1_000..=120_000;
+const BROWSER_LAUNCH_MS: RangeInclusive<u64> = 5_000..=300_000;
+const HTTP_REQUEST_MS: RangeInclusive<u64> = 1_000..=300_000;
+const DB_QUERY_MS: RangeInclusive<u64> = 1_000..=120_000;
+const MAX_RETRIES: RangeInclusive<u32> = 0..=10;
+const INITIAL_BACKOFF_MS: RangeInclusive<u64> = 100..=60_000;
+const MAX_BACKOFF_MS: RangeInclusive<u64> = 100..=300_000;
+const BACKOFF_MULTIPLIER: RangeInclusive<f64> = 1.0..=10.0;
+const WAF_MAX_ATTEMPTS: RangeInclusive<u32> = 1..=5;
+
+/// Timeouts and retry policy for outgoing requests and browser automation
+///
+/// Durations are in milliseconds. `max_retries` counts retries after the first
+/// attempt of an HTTP request, `waf_max_attempts` counts browser runs in total.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NetworkPolicySettings {
+    pub waf_wait_ms: u64,
+    pub browser_launch_ms: u64,
+    pub http_request_ms: u64,
+    pub db_query_ms: u64,
+    pub max_retries: u32,
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub backoff_multiplier: f64,
+    pub waf_max_attempts: u32,
+}
+
+impl Default for NetworkPolicySettings {
+    fn default() -> Self {
+        Self {
+            waf_wait_ms: 8_000,
+            browser_launch_ms: 30_000,
+            http_request_ms: 30_000,
+            db_query_ms: 10_000,
+            max_retries: 3,
+            initial_backoff_ms: 1_000,
+            max_backoff_ms: 10_000,
+            backoff_multiplier: 2.0,
+            waf_max_attempts: 2,
+        }
+    }
+}
+
+fn check_range<T: PartialOrd + std::fmt::Display>(
+    name: &str,
+    value: T,
+    range: &RangeInclusive<T>,
+) -> Result<(), DomainError> {
+    if range.contains(&value) {
+        return Ok(());
+    }
+    Err(DomainError::Validation(format!(
+        "{} must be between {} and {}, got {}",
+        name,
+        range.start(),
+        range.end(),
+        value
+    )))
+}
+
+impl NetworkPolicySettings {
+    /// Allowed `(name, min, max)` of every setting, for display next to the inputs
+    pub fn ranges() -> Vec<(&'static str, f64, f64)> {
+        let ms =
+            |name, range: RangeInclusive<u64>| (name, *range.start() as f64, *range.end() as f64);
+        let count =
+            |name, range: RangeInclusive<u32>| (name, *range.start() as f64, *range.end() as f64);
+        vec![
+            ms("waf_wait_ms", WAF_WAIT_MS),
+            ms("browser_launch_ms", BROWSER_LAUNCH_MS),
+            ms("http_request_ms", HTTP_REQUEST_MS),
+            ms("db_query_ms", DB_QUERY_MS),
+            count("max_retries", MAX_RETRIES),
+            ms("initial_backoff_ms", INITIAL_BACKOFF_MS),
+            ms("max_backoff_ms", MAX_BACKOFF_MS),
+            (
+                "backoff_multiplier",
+                *BACKOFF_MULTIPLIER.start(),
+                *BACKOFF_MULTIPLIER.end(),
+            ),
+            count("waf_max_attempts", WAF_MAX_ATTEMPTS),
+        ]
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        check_range("waf_wait_ms", self.waf_wait_ms, &WAF_WAIT_MS)?;
+        check_range(
+            "browser_launch_ms",
+            self.browser_launch_ms,
+            &BROWSER_LAUNCH_MS,
+        )?;
+        check_range("http_request_ms", self.http_request_ms, &HTTP_REQUEST_MS)?;
+        check_range("db_query_ms", self.db_query_ms, &DB_QUERY_MS)?;
+        check_range("max_retries", self.max_retries, &MAX_RETRIES)?;
+        check_range(
+            "initial_backoff_ms",
+            self.initial_backoff_ms,
+            &INITIAL_BACKOFF_MS,
+        )?;
+        check_range("max_backoff_ms", self.max_backoff_ms, &MAX_BACKOFF_MS)?;
+        check_range(
+            "backoff_multiplier",
+            self.backoff_multiplier,
+            &BACKOFF_MULTIPLIER,
+        )?;
+        check_range("waf_max_attempts", self.waf_max_attempts, &WAF_MAX_ATTEMPTS)?;
+
+        if self.max_backoff_ms < self.initial_backoff_ms {
+            return Err(DomainError::Validation(
+                "max_backoff_ms must not be lower than initial_backoff_ms".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Per-provider values replacing the defaults for slow or flaky sites
+///
+/// Only settings that depend on the site can be overridden; browser launch and
+/// database timeouts are local and stay global.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderNetworkOverride {
+    pub waf_wait_ms: Option<u64>,
+    pub http_request_ms: Option<u64>,
+    pub max_retries: Option<u32>,
+    pub waf_max_attempts: Option<u32>,
+}
+
+impl ProviderNetworkOverride {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The defaults with this override's values applied
+    pub fn apply_to(&self, defaults: &NetworkPolicySettings) -> NetworkPolicySettings {
+        NetworkPolicySettings {
+            waf_wait_ms: self.waf_wait_ms.unwrap_or(defaults.waf_wait_ms),
+            http_request_ms: self.http_request_ms.unwrap_or(defaults.http_request_ms),
+            max_retries: self.max_retries.unwrap_or(defaults.max_retries),
+            waf_max_attempts: self.waf_max_attempts.unwrap_or(defaults.waf_max_attempts),
+            ..*defaults
+        }
+    }
+}
+
+/// Default network policy plus per-provider overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NetworkSettings {
+    defaults: NetworkPolicySettings,
+    provider_overrides: HashMap<String, ProviderNetworkOverride>,
+}
+
+impl NetworkSettings {
+    pub fn restore(
+        defaults: NetworkPolicySettings,
+        provider_overrides: HashMap<String, ProviderNetworkOverride>,
+    ) -> Self {
+        Self {
+            defaults,
+            provider_overrides,
+        }
+    }
+
+    /// Replace all settings after validating them as a whole
+    ///
+    /// Empty overrides are dropped, each remaining one is validated as applied
+    /// to the new defaults.
+    pub fn update(
+        &mut self,
+        defaults: NetworkPolicySettings,
+        provider_overrides: HashMap<String, ProviderNetworkOverride>,
+    ) -> Result<(), DomainError> {
+        defaults.validate()?;
+
+        let provider_overrides: HashMap<_, _> = provider_overrides
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .collect();
+        for (provider_id, value) in &provider_overrides {
+            value.apply_to(&defaults).validate().map_err(|e| {
+                DomainError::Validation(format!("Override for provider {}: {}", provider_id, e))
+            })?;
+        }
+
+        self.defaults = defaults;
+        self.provider_overrides = provider_overrides;
+        Ok(())
+    }
+
+    pub fn defaults(&self) -> &NetworkPolicySettings {
+        &self.defaults
+    }
+
+    pub fn provider_overrides(&self) -> &HashMap<String, ProviderNetworkOverride> {
+        &self.provider_overrides
+    }
+
+    /// Effective policy for a provider (the defaults when it has no override)
+    pub fn for_provider(&self, provider_id: &str) -> NetworkPolicySettings {
+        self.provider_overrides
+            .get(provider_id)
+            .map(|value| value.apply_to(&self.defaults))
+            .unwrap_or(self.defaults)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_are_valid() {
+        assert!(NetworkPolicySettings::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_update_validation() {
+        let mut settings = NetworkSettings::default();
+
+        let too_short = NetworkPolicySettings {
+            http_request_ms: 10,
+            ..Default::default()
+        };
+        assert!(settings.update(too_short, HashMap::new()).is_err());
+
+        let inverted_backoff = NetworkPolicySettings {
+            initial_backoff_ms: 5_000,
+            max_backoff_ms: 1_000,
+            ..Default::default()
+        };
+        assert!(settings.update(inverted_backoff, HashMap::new()).is_err());
+
+        let bad_override = HashMap::from([(
+            "slow".to_string(),
+            ProviderNetworkOverride {
+                waf_max_attempts: Some(0),
+                ..Default::default()
+            },
+        )]);
+        assert!(settings
+            .update(NetworkPolicySettings::default(), bad_override)
+            .is_err());
+
+        // Failed updates leave settings untouched
+        assert_eq!(settings.defaults(), &NetworkPolicySettings::default());
+    }
+
+    #[test]
+    fn test_for_provider_applies_override() {
+        let mut settings = NetworkSettings::default();
+        let overrides = HashMap::from([
+            (
+                "slow".to_string(),
+                ProviderNetworkOverride {
+                    waf_wait_ms: Some(20_000),
+                    max_retries: Some(5),
+                    ..Default::default()
+                },
+            ),
+            ("empty".to_string(), ProviderNetworkOverride::default()),
+        ]);
+        settings
+            .update(NetworkPolicySettings::default(), overrides)
+            .unwrap();
+
+        let slow = settings.for_provider("slow");
+        assert_eq!(slow.waf_wait_ms, 20_000);
+        assert_eq!(slow.max_retries, 5);
+        assert_eq!(slow.http_request_ms, 30_000);
+        assert_eq!(
+            settings.for_provider("other"),
+            NetworkPolicySettings::default()
+        );
+        assert!(!settings.provider_overrides().contains_key("empty"));
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use std::collections::BTreeSet;
+
+/// A provider's model list as fetched at one point in time
+///
+/// Snapshots are only recorded when the list changes, so consecutive snapshots
+/// always differ.
+#[derive(Debug, Clone)]
+pub struct ProviderModelsSnapshot {
+    pub id: i64,
+    pub provider_id: String,
+    pub models: Vec<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// Models added to and removed from a provider's list, both sorted
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelCatalogDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl ModelCatalogDiff {
+    /// Compare two model lists, ignoring order and duplicates
+    pub fn between(previous: &[String], current: &[String]) -> Self {
+        let previous: BTreeSet<&String> = previous.iter().collect();
+        let current: BTreeSet<&String> = current.iter().collect();
+        Self {
+            added: current
+                .difference(&previous)
+                .map(|m| m.to_string())
+                .collect(),
+            removed: previous
+                .difference(&current)
+                .map(|m| m.to_string())
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Only the changes to the given models
+    pub fn restricted_to(&self, models: &[String]) -> Self {
+        let keep = |list: &[String]| {
+            list.iter()
+                .filter(|model| models.contains(model))
+                .cloned()
+                .collect()
+        };
+        Self {
+            added: keep(&self.added),
+            removed: keep(&self.removed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn models(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_between_ignores_order_and_duplicates() {
+        let diff = ModelCatalogDiff::between(
+            &models(&["gpt-4o", "claude-sonnet-4", "gpt-4o"]),
+            &models(&["o3", "gpt-4o", "deepseek-v3"]),
+        );
+        assert_eq!(diff.added, models(&["deepseek-v3", "o3"]));
+        assert_eq!(diff.removed, models(&["claude-sonnet-4"]));
+
+        let unchanged = ModelCatalogDiff::between(&models(&["a", "b"]), &models(&["b", "a", "a"]));
+        assert!(unchanged.is_empty());
+    }
+
+    #[test]
+    fn test_restricted_to_configured_models() {
+        let diff = ModelCatalogDiff::between(&models(&["a", "b"]), &models(&["b", "c"]));
+        let configured = diff.restricted_to(&models(&["a", "z"]));
+        assert!(configured.added.is_empty());
+        assert_eq!(configured.removed, models(&["a"]));
+        assert!(diff.restricted_to(&models(&["b"])).is_empty());
+    }
+}
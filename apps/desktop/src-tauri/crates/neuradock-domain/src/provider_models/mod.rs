@@ -1,3 +1,5 @@
+mod catalog;
 mod repository;
 
+pub use catalog::{ModelCatalogDiff, ProviderModelsSnapshot};
 pub use repository::{ProviderModels, ProviderModelsRepository};
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
+use super::{ModelCatalogDiff, ProviderModelsSnapshot};
 use crate::shared::DomainError;
 
 /// Provider models domain entity
@@ -14,8 +15,15 @@ pub struct ProviderModels {
 /// Repository trait for provider models
 #[async_trait]
 pub trait ProviderModelsRepository: Send + Sync {
-    /// Save or update provider models
-    async fn save(&self, provider_id: &str, models: &[String]) -> Result<(), DomainError>;
+    /// Save or update provider models, recording a snapshot when the list changed
+    ///
+    /// Returns the changes against the previous snapshot, `None` for the first list
+    /// saved for the provider.
+    async fn save(
+        &self,
+        provider_id: &str,
+        models: &[String],
+    ) -> Result<Option<ModelCatalogDiff>, DomainError>;
 
     /// Find models by provider ID
     async fn find_by_provider(
@@ -26,6 +34,13 @@ pub trait ProviderModelsRepository: Send + Sync {
     /// Check if provider models are stale (older than specified hours)
     async fn is_stale(&self, provider_id: &str, max_age_hours: i64) -> Result<bool, DomainError>;
 
-    /// Delete models for a provider
+    /// Model list snapshots of a provider, newest first
+    async fn find_snapshots(
+        &self,
+        provider_id: &str,
+        limit: i64,
+    ) -> Result<Vec<ProviderModelsSnapshot>, DomainError>;
+
+    /// Delete models and their snapshot history for a provider
     async fn delete_by_provider(&self, provider_id: &str) -> Result<(), DomainError>;
 }
@@ -1,5 +1,7 @@
 mod config;
 mod repository;
+mod routing;
 
 pub use config::*;
 pub use repository::*;
+pub use routing::*;
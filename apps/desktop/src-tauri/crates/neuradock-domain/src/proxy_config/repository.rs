@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 
-use super::ProxyConfig;
+use super::{ProxyConfig, ProxyOverride, ProxyPool, ProxyRouting, ProxyScope};
 use crate::shared::DomainError;
 
 /// Proxy configuration repository trait
@@ -12,3 +12,24 @@ pub trait ProxyConfigRepository: Send + Sync {
     /// Save the proxy configuration
     async fn save(&self, config: &ProxyConfig) -> Result<(), DomainError>;
 }
+
+/// Proxy pools and per-account/per-provider overrides
+#[async_trait]
+pub trait ProxyRoutingRepository: Send + Sync {
+    /// Load all pools and overrides
+    async fn load(&self) -> Result<ProxyRouting, DomainError>;
+
+    async fn list_pools(&self) -> Result<Vec<ProxyPool>, DomainError>;
+
+    async fn find_pool(&self, id: &str) -> Result<Option<ProxyPool>, DomainError>;
+
+    async fn save_pool(&self, pool: &ProxyPool) -> Result<(), DomainError>;
+
+    async fn delete_pool(&self, id: &str) -> Result<(), DomainError>;
+
+    async fn list_overrides(&self) -> Result<Vec<ProxyOverride>, DomainError>;
+
+    async fn save_override(&self, proxy_override: &ProxyOverride) -> Result<(), DomainError>;
+
+    async fn delete_override(&self, scope: ProxyScope, scope_id: &str) -> Result<(), DomainError>;
+}
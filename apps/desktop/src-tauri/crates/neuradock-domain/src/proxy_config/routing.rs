@@ -0,0 +1,570 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use super::config::build_proxy_url;
+use super::{ProxyConfig, ProxyType};
+use crate::shared::DomainError;
+
+/// A single upstream proxy server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyEndpoint {
+    pub proxy_type: ProxyType,
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl ProxyEndpoint {
+    pub fn new(
+        proxy_type: ProxyType,
+        host: String,
+        port: u16,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> Result<Self, DomainError> {
+        let host = host.trim().to_string();
+        if host.is_empty() {
+            return Err(DomainError::Validation(
+                "Proxy host cannot be empty".to_string(),
+            ));
+        }
+        if port == 0 {
+            return Err(DomainError::Validation(
+                "Proxy port must be greater than 0".to_string(),
+            ));
+        }
+
+        let username = username
+            .map(|u| u.trim().to_string())
+            .filter(|u| !u.is_empty());
+        let password = password.filter(|p| !p.is_empty() && username.is_some());
+
+        Ok(Self {
+            proxy_type,
+            host,
+            port,
+            username,
+            password,
+        })
+    }
+
+    pub fn to_url(&self) -> String {
+        build_proxy_url(
+            self.proxy_type,
+            &self.host,
+            self.port,
+            self.username.as_deref(),
+            self.password.as_deref(),
+        )
+    }
+}
+
+/// How a pool picks an endpoint for each request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolStrategy {
+    /// Rotate through endpoints on every request
+    RoundRobin,
+    /// Always use the same endpoint for the same account/provider
+    Sticky,
+}
+
+impl PoolStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PoolStrategy::RoundRobin => "round_robin",
+            PoolStrategy::Sticky => "sticky",
+        }
+    }
+}
+
+impl fmt::Display for PoolStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for PoolStrategy {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "round_robin" => Ok(PoolStrategy::RoundRobin),
+            "sticky" => Ok(PoolStrategy::Sticky),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid pool strategy: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Named set of proxy endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyPool {
+    id: String,
+    name: String,
+    strategy: PoolStrategy,
+    endpoints: Vec<ProxyEndpoint>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl ProxyPool {
+    pub fn new(
+        id: String,
+        name: String,
+        strategy: PoolStrategy,
+        endpoints: Vec<ProxyEndpoint>,
+    ) -> Result<Self, DomainError> {
+        let name = validate_pool_name(&name)?;
+        let now = Utc::now();
+        Ok(Self {
+            id,
+            name,
+            strategy,
+            endpoints,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn restore(
+        id: String,
+        name: String,
+        strategy: PoolStrategy,
+        endpoints: Vec<ProxyEndpoint>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            strategy,
+            endpoints,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        name: String,
+        strategy: PoolStrategy,
+        endpoints: Vec<ProxyEndpoint>,
+    ) -> Result<(), DomainError> {
+        self.name = validate_pool_name(&name)?;
+        self.strategy = strategy;
+        self.endpoints = endpoints;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn strategy(&self) -> PoolStrategy {
+        self.strategy
+    }
+
+    pub fn endpoints(&self) -> &[ProxyEndpoint] {
+        &self.endpoints
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    /// Pick an endpoint.
+    ///
+    /// `counter` is the pool's round-robin position; `sticky_key` identifies
+    /// the caller for sticky assignment.
+    pub fn select(&self, counter: usize, sticky_key: &str) -> Option<&ProxyEndpoint> {
+        if self.endpoints.is_empty() {
+            return None;
+        }
+        let index = match self.strategy {
+            PoolStrategy::RoundRobin => counter,
+            PoolStrategy::Sticky => stable_hash(sticky_key) as usize,
+        } % self.endpoints.len();
+        self.endpoints.get(index)
+    }
+}
+
+fn validate_pool_name(name: &str) -> Result<String, DomainError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DomainError::Validation(
+            "Proxy pool name cannot be empty".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// FNV-1a, stable across runs so sticky assignments survive restarts
+fn stable_hash(key: &str) -> u64 {
+    key.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// What an override applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProxyScope {
+    Account,
+    Provider,
+}
+
+impl ProxyScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProxyScope::Account => "account",
+            ProxyScope::Provider => "provider",
+        }
+    }
+}
+
+impl FromStr for ProxyScope {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "account" => Ok(ProxyScope::Account),
+            "provider" => Ok(ProxyScope::Provider),
+            _ => Err(DomainError::Validation(format!(
+                "Invalid proxy scope: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Where traffic for a scope should go
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ProxyRoute {
+    /// Follow the global proxy settings
+    Global,
+    /// Connect without any proxy
+    Direct,
+    /// Use an endpoint from a named pool
+    Pool { pool_id: String },
+    /// Use a dedicated endpoint
+    Endpoint { endpoint: ProxyEndpoint },
+}
+
+/// Per-account or per-provider proxy route
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyOverride {
+    pub scope: ProxyScope,
+    pub scope_id: String,
+    pub route: ProxyRoute,
+}
+
+/// Request being routed
+#[derive(Debug, Clone, Default)]
+pub struct ProxyTarget<'a> {
+    pub account_id: Option<&'a str>,
+    pub provider_id: Option<&'a str>,
+    /// Destination host, checked against the bypass list
+    pub host: Option<&'a str>,
+}
+
+impl ProxyTarget<'_> {
+    fn sticky_key(&self) -> String {
+        format!(
+            "{}|{}|{}",
+            self.account_id.unwrap_or_default(),
+            self.provider_id.unwrap_or_default(),
+            self.host.unwrap_or_default()
+        )
+    }
+}
+
+/// Pools and overrides layered on top of the global proxy settings
+#[derive(Debug, Clone, Default)]
+pub struct ProxyRouting {
+    pools: HashMap<String, ProxyPool>,
+    overrides: HashMap<(ProxyScope, String), ProxyRoute>,
+}
+
+impl ProxyRouting {
+    pub fn new(pools: Vec<ProxyPool>, overrides: Vec<ProxyOverride>) -> Self {
+        Self {
+            pools: pools.into_iter().map(|p| (p.id.clone(), p)).collect(),
+            overrides: overrides
+                .into_iter()
+                .map(|o| ((o.scope, o.scope_id), o.route))
+                .collect(),
+        }
+    }
+
+    pub fn pool(&self, id: &str) -> Option<&ProxyPool> {
+        self.pools.get(id)
+    }
+
+    /// Most specific route for a target: account, then provider, then global
+    pub fn route_for(&self, target: &ProxyTarget<'_>) -> &ProxyRoute {
+        let account = target
+            .account_id
+            .and_then(|id| self.overrides.get(&(ProxyScope::Account, id.to_string())));
+        let provider = target
+            .provider_id
+            .and_then(|id| self.overrides.get(&(ProxyScope::Provider, id.to_string())));
+
+        account.or(provider).unwrap_or(&ProxyRoute::Global)
+    }
+
+    /// Resolve the proxy URL for a target, or `None` for a direct connection.
+    ///
+    /// The bypass list of the global settings applies to every route. Pools
+    /// that are missing or empty fall back to the global proxy.
+    /// `next_counter` is called only for round-robin pools.
+    pub fn resolve(
+        &self,
+        global: &ProxyConfig,
+        target: &ProxyTarget<'_>,
+        mut next_counter: impl FnMut(&str) -> usize,
+    ) -> Option<String> {
+        if target.host.is_some_and(|host| global.is_bypassed(host)) {
+            return None;
+        }
+
+        match self.route_for(target) {
+            ProxyRoute::Global => global.proxy_url(),
+            ProxyRoute::Direct => None,
+            ProxyRoute::Endpoint { endpoint } => Some(endpoint.to_url()),
+            ProxyRoute::Pool { pool_id } => {
+                let Some(pool) = self.pools.get(pool_id) else {
+                    return global.proxy_url();
+                };
+                let counter = match pool.strategy {
+                    PoolStrategy::RoundRobin => next_counter(pool_id),
+                    PoolStrategy::Sticky => 0,
+                };
+                pool.select(counter, &target.sticky_key())
+                    .map(ProxyEndpoint::to_url)
+                    .or_else(|| global.proxy_url())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(host: &str) -> ProxyEndpoint {
+        ProxyEndpoint::new(ProxyType::Http, host.to_string(), 8080, None, None).unwrap()
+    }
+
+    fn pool(id: &str, strategy: PoolStrategy) -> ProxyPool {
+        ProxyPool::new(
+            id.to_string(),
+            id.to_string(),
+            strategy,
+            vec![endpoint("a"), endpoint("b"), endpoint("c")],
+        )
+        .unwrap()
+    }
+
+    fn global() -> ProxyConfig {
+        let mut config = ProxyConfig::new(ProxyType::Socks5, "global".to_string(), 1080).unwrap();
+        config
+            .set_bypass_domains(vec!["internal.example".to_string()])
+            .unwrap();
+        config
+    }
+
+    #[test]
+    fn test_override_precedence() {
+        let routing = ProxyRouting::new(
+            vec![],
+            vec![
+                ProxyOverride {
+                    scope: ProxyScope::Provider,
+                    scope_id: "p1".to_string(),
+                    route: ProxyRoute::Direct,
+                },
+                ProxyOverride {
+                    scope: ProxyScope::Account,
+                    scope_id: "a1".to_string(),
+                    route: ProxyRoute::Endpoint {
+                        endpoint: endpoint("dedicated"),
+                    },
+                },
+            ],
+        );
+        let global = global();
+        let resolve = |account, provider| {
+            routing.resolve(
+                &global,
+                &ProxyTarget {
+                    account_id: account,
+                    provider_id: provider,
+                    host: Some("api.example.com"),
+                },
+                |_| 0,
+            )
+        };
+
+        assert_eq!(
+            resolve(Some("a1"), Some("p1")),
+            Some("http://dedicated:8080".to_string())
+        );
+        assert_eq!(resolve(Some("a2"), Some("p1")), None);
+        assert_eq!(
+            resolve(Some("a2"), Some("p2")),
+            Some("socks5://global:1080".to_string())
+        );
+    }
+
+    #[test]
+    fn test_bypass_wins_over_overrides() {
+        let routing = ProxyRouting::new(
+            vec![],
+            vec![ProxyOverride {
+                scope: ProxyScope::Account,
+                scope_id: "a1".to_string(),
+                route: ProxyRoute::Endpoint {
+                    endpoint: endpoint("dedicated"),
+                },
+            }],
+        );
+        let target = ProxyTarget {
+            account_id: Some("a1"),
+            provider_id: None,
+            host: Some("api.internal.example"),
+        };
+        assert_eq!(routing.resolve(&global(), &target, |_| 0), None);
+    }
+
+    #[test]
+    fn test_pool_strategies() {
+        let routing = ProxyRouting::new(
+            vec![
+                pool("rr", PoolStrategy::RoundRobin),
+                pool("sticky", PoolStrategy::Sticky),
+            ],
+            vec![
+                ProxyOverride {
+                    scope: ProxyScope::Provider,
+                    scope_id: "rr-provider".to_string(),
+                    route: ProxyRoute::Pool {
+                        pool_id: "rr".to_string(),
+                    },
+                },
+                ProxyOverride {
+                    scope: ProxyScope::Provider,
+                    scope_id: "sticky-provider".to_string(),
+                    route: ProxyRoute::Pool {
+                        pool_id: "sticky".to_string(),
+                    },
+                },
+            ],
+        );
+        let global = global();
+
+        let mut counter = 0;
+        let rr_target = ProxyTarget {
+            account_id: Some("a1"),
+            provider_id: Some("rr-provider"),
+            host: None,
+        };
+        let picks: Vec<_> = (0..4)
+            .map(|_| {
+                routing.resolve(&global, &rr_target, |_| {
+                    counter += 1;
+                    counter - 1
+                })
+            })
+            .collect();
+        assert_eq!(
+            picks,
+            ["a", "b", "c", "a"]
+                .iter()
+                .map(|h| Some(format!("http://{}:8080", h)))
+                .collect::<Vec<_>>()
+        );
+
+        let sticky_target = ProxyTarget {
+            account_id: Some("a1"),
+            provider_id: Some("sticky-provider"),
+            host: None,
+        };
+        let first = routing.resolve(&global, &sticky_target, |_| unreachable!());
+        for _ in 0..5 {
+            assert_eq!(routing.resolve(&global, &sticky_target, |_| 0), first);
+        }
+    }
+
+    #[test]
+    fn test_missing_or_empty_pool_falls_back_to_global() {
+        let empty = ProxyPool::new(
+            "empty".to_string(),
+            "Empty".to_string(),
+            PoolStrategy::RoundRobin,
+            vec![],
+        )
+        .unwrap();
+        let routing = ProxyRouting::new(
+            vec![empty],
+            vec![
+                ProxyOverride {
+                    scope: ProxyScope::Account,
+                    scope_id: "a1".to_string(),
+                    route: ProxyRoute::Pool {
+                        pool_id: "empty".to_string(),
+                    },
+                },
+                ProxyOverride {
+                    scope: ProxyScope::Account,
+                    scope_id: "a2".to_string(),
+                    route: ProxyRoute::Pool {
+                        pool_id: "missing".to_string(),
+                    },
+                },
+            ],
+        );
+        for account in ["a1", "a2"] {
+            let target = ProxyTarget {
+                account_id: Some(account),
+                ..Default::default()
+            };
+            assert_eq!(
+                routing.resolve(&global(), &target, |_| 0),
+                Some("socks5://global:1080".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_endpoint_validation() {
+        assert!(ProxyEndpoint::new(ProxyType::Http, " ".to_string(), 80, None, None).is_err());
+        assert!(ProxyEndpoint::new(ProxyType::Http, "h".to_string(), 0, None, None).is_err());
+
+        let ep = ProxyEndpoint::new(
+            ProxyType::Socks5,
+            "h".to_string(),
+            1080,
+            Some("u".to_string()),
+            Some("p".to_string()),
+        )
+        .unwrap();
+        assert_eq!(ep.to_url(), "socks5://u:p@h:1080");
+    }
+}
@@ -45,6 +45,14 @@ pub struct ModelLimits {
     pub denied: Vec<String>,
 }
 
+impl ModelLimits {
+    /// Whether the limits let a token call `model`, an empty allow list allows any model
+    pub fn allows(&self, model: &str) -> bool {
+        !self.denied.iter().any(|m| m == model)
+            && (self.allowed.is_empty() || self.allowed.iter().any(|m| m == model))
+    }
+}
+
 /// Configuration for creating an ApiToken
 #[derive(Debug, Clone)]
 pub struct ApiTokenConfig {
@@ -57,6 +65,10 @@ pub struct ApiTokenConfig {
     pub expired_time: Option<DateTime<Utc>>,
     pub model_limits_enabled: bool,
     pub model_limits: Option<ModelLimits>,
+    /// new-api user group the token bills to, empty for the user's own group
+    pub group: String,
+    /// Newline-separated IPs allowed to use the token, empty allows any
+    pub allow_ips: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -72,6 +84,8 @@ pub struct ApiToken {
     expired_time: Option<DateTime<Utc>>,
     model_limits_enabled: bool,
     model_limits: Option<ModelLimits>,
+    group: String,
+    allow_ips: String,
     fetched_at: DateTime<Utc>,
 }
 
@@ -89,6 +103,8 @@ impl ApiToken {
             expired_time: config.expired_time,
             model_limits_enabled: config.model_limits_enabled,
             model_limits: config.model_limits,
+            group: config.group,
+            allow_ips: config.allow_ips,
             fetched_at: Utc::now(),
         }
     }
@@ -150,6 +166,44 @@ impl ApiToken {
         self.model_limits.as_ref()
     }
 
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    pub fn allow_ips(&self) -> &str {
+        &self.allow_ips
+    }
+
+    /// Whether an enabled allow list decides which models the token can call
+    pub fn has_model_allow_list(&self) -> bool {
+        self.model_limits_enabled
+            && self
+                .model_limits
+                .as_ref()
+                .is_some_and(|limits| !limits.allowed.is_empty())
+    }
+
+    /// Models this token can call, out of its provider's model list `catalog`
+    ///
+    /// An enabled allow list replaces the catalog, since limits may name models the
+    /// provider's models API doesn't list. Otherwise the catalog is used as is.
+    pub fn servable_models(&self, catalog: &[String]) -> Vec<String> {
+        let limits = match self.model_limits.as_ref() {
+            Some(limits) if self.model_limits_enabled => limits,
+            _ => return catalog.to_vec(),
+        };
+        let candidates = if limits.allowed.is_empty() {
+            catalog
+        } else {
+            &limits.allowed
+        };
+        candidates
+            .iter()
+            .filter(|model| limits.allows(model))
+            .cloned()
+            .collect()
+    }
+
     pub fn fetched_at(&self) -> DateTime<Utc> {
         self.fetched_at
     }
@@ -193,6 +247,8 @@ mod tests {
                 expired_time: None,
                 model_limits_enabled: false,
                 model_limits: None,
+                group: String::new(),
+                allow_ips: String::new(),
             },
         );
 
@@ -215,6 +271,8 @@ mod tests {
                 expired_time: Some(expired_time),
                 model_limits_enabled: false,
                 model_limits: None,
+                group: String::new(),
+                allow_ips: String::new(),
             },
         );
 
@@ -237,6 +295,8 @@ mod tests {
                 expired_time: None,
                 model_limits_enabled: false,
                 model_limits: None,
+                group: String::new(),
+                allow_ips: String::new(),
             },
         );
 
@@ -258,9 +318,54 @@ mod tests {
                 expired_time: None,
                 model_limits_enabled: false,
                 model_limits: None,
+                group: String::new(),
+                allow_ips: String::new(),
             },
         );
 
         assert_eq!(token.usage_percentage(), 0.0);
     }
+
+    #[test]
+    fn test_servable_models() {
+        let catalog = vec!["gpt-4o".to_string(), "claude-sonnet-4".to_string()];
+        let token = |enabled: bool, allowed: &[&str], denied: &[&str]| {
+            ApiToken::new(
+                TokenId::new(1),
+                AccountId::from_string("acc-1"),
+                ApiTokenConfig {
+                    name: "test".to_string(),
+                    key: "sk-test".to_string(),
+                    status: TokenStatus::Enabled,
+                    used_quota: 0,
+                    remain_quota: 100000,
+                    unlimited_quota: false,
+                    expired_time: None,
+                    model_limits_enabled: enabled,
+                    model_limits: Some(ModelLimits {
+                        allowed: allowed.iter().map(|m| m.to_string()).collect(),
+                        denied: denied.iter().map(|m| m.to_string()).collect(),
+                    }),
+                    group: String::new(),
+                    allow_ips: String::new(),
+                },
+            )
+        };
+
+        // Disabled limits are ignored
+        assert_eq!(
+            token(false, &["gpt-4o"], &[]).servable_models(&catalog),
+            catalog
+        );
+        // The allow list replaces the catalog, unlisted models included
+        assert_eq!(
+            token(true, &["claude-opus-4", "gpt-4o"], &["gpt-4o"]).servable_models(&catalog),
+            vec!["claude-opus-4".to_string()]
+        );
+        // Without an allow list only denied models are removed
+        assert_eq!(
+            token(true, &[], &["gpt-4o"]).servable_models(&catalog),
+            vec!["claude-sonnet-4".to_string()]
+        );
+    }
 }
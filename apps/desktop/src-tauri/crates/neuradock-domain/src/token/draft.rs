@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+
+use crate::shared::DomainError;
+
+/// new-api rejects longer token names
+pub const MAX_TOKEN_NAME_CHARS: usize = 30;
+
+/// Settings of a token to create on a provider, or to write back to one
+///
+/// An empty `model_limits` leaves the token unrestricted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiTokenDraft {
+    pub name: String,
+    pub remain_quota: i64,
+    pub unlimited_quota: bool,
+    /// `None` never expires
+    pub expired_time: Option<DateTime<Utc>>,
+    pub model_limits: Vec<String>,
+    /// Billing group, empty for the user's own group
+    pub group: String,
+    /// Newline-separated allowed IPs, empty allows any
+    pub allow_ips: String,
+}
+
+impl ApiTokenDraft {
+    /// Trim the name and model list, then check them against what new-api accepts
+    pub fn normalized(mut self, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        self.name = self.name.trim().to_string();
+        if self.name.is_empty() {
+            return Err(DomainError::Validation(
+                "Token name cannot be empty".to_string(),
+            ));
+        }
+        if self.name.chars().count() > MAX_TOKEN_NAME_CHARS {
+            return Err(DomainError::Validation(format!(
+                "Token name cannot be longer than {} characters",
+                MAX_TOKEN_NAME_CHARS
+            )));
+        }
+        if self.remain_quota < 0 {
+            return Err(DomainError::Validation(
+                "Token quota cannot be negative".to_string(),
+            ));
+        }
+        if self
+            .expired_time
+            .is_some_and(|expired_time| expired_time <= now)
+        {
+            return Err(DomainError::Validation(
+                "Token expiry must be in the future".to_string(),
+            ));
+        }
+
+        let mut models: Vec<String> = Vec::with_capacity(self.model_limits.len());
+        for model in &self.model_limits {
+            let model = model.trim();
+            if !model.is_empty() && !models.iter().any(|m| m == model) {
+                models.push(model.to_string());
+            }
+        }
+        self.model_limits = models;
+
+        Ok(self)
+    }
+
+    pub fn model_limits_enabled(&self) -> bool {
+        !self.model_limits.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn draft() -> ApiTokenDraft {
+        ApiTokenDraft {
+            name: "  ci-runner ".to_string(),
+            remain_quota: 500_000,
+            unlimited_quota: false,
+            expired_time: None,
+            model_limits: vec![
+                " gpt-4o".to_string(),
+                String::new(),
+                "gpt-4o".to_string(),
+                "claude-sonnet-4-5".to_string(),
+            ],
+            group: String::new(),
+            allow_ips: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_normalized_trims_name_and_models() {
+        let draft = draft().normalized(Utc::now()).unwrap();
+        assert_eq!(draft.name, "ci-runner");
+        assert_eq!(draft.model_limits, vec!["gpt-4o", "claude-sonnet-4-5"]);
+        assert!(draft.model_limits_enabled());
+    }
+
+    #[test]
+    fn test_normalized_rejects_invalid_settings() {
+        let now = Utc::now();
+
+        let mut blank = draft();
+        blank.name = "   ".to_string();
+        assert!(blank.normalized(now).is_err());
+
+        let mut long = draft();
+        long.name = "x".repeat(MAX_TOKEN_NAME_CHARS + 1);
+        assert!(long.normalized(now).is_err());
+
+        let mut negative = draft();
+        negative.remain_quota = -1;
+        assert!(negative.normalized(now).is_err());
+
+        let mut expired = draft();
+        expired.expired_time = Some(now - Duration::minutes(1));
+        assert!(expired.normalized(now).is_err());
+    }
+}
@@ -1,5 +1,9 @@
 mod aggregate;
+mod draft;
 mod repository;
+mod watch;
 
 pub use aggregate::{ApiToken, ApiTokenConfig, ModelLimits, TokenId, TokenStatus};
-pub use repository::TokenRepository;
+pub use draft::{ApiTokenDraft, MAX_TOKEN_NAME_CHARS};
+pub use repository::{TokenRepository, TokenWatchRepository};
+pub use watch::{TokenWatchAlert, TokenWatchSettings, TokenWatchTarget, TokenWatchTool};
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 
 use super::aggregate::{ApiToken, TokenId};
+use super::watch::{TokenWatchSettings, TokenWatchTarget, TokenWatchTool};
 use crate::shared::{AccountId, DomainError};
 
 #[async_trait]
@@ -11,3 +12,18 @@ pub trait TokenRepository: Send + Sync {
     async fn find_by_account(&self, account_id: &AccountId) -> Result<Vec<ApiToken>, DomainError>;
     async fn delete_by_account(&self, account_id: &AccountId) -> Result<(), DomainError>;
 }
+
+/// Tokens configured into CLI tools and the watchdog thresholds
+#[async_trait]
+pub trait TokenWatchRepository: Send + Sync {
+    async fn list_targets(&self) -> Result<Vec<TokenWatchTarget>, DomainError>;
+    async fn find_target(
+        &self,
+        tool: TokenWatchTool,
+    ) -> Result<Option<TokenWatchTarget>, DomainError>;
+    /// Insert or replace the target of `target.tool`
+    async fn save_target(&self, target: &TokenWatchTarget) -> Result<(), DomainError>;
+    async fn delete_target(&self, tool: TokenWatchTool) -> Result<(), DomainError>;
+    async fn get_settings(&self) -> Result<TokenWatchSettings, DomainError>;
+    async fn save_settings(&self, settings: &TokenWatchSettings) -> Result<(), DomainError>;
+}
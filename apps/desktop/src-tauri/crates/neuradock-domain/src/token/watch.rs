@@ -0,0 +1,322 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::aggregate::{ApiToken, TokenId, TokenStatus};
+use crate::shared::{AccountId, DomainError};
+
+/// 500,000 quota units are $1 on new-api
+const DEFAULT_LOW_QUOTA_THRESHOLD: i64 = 500_000;
+const DEFAULT_EXPIRY_WARNING_DAYS: u32 = 3;
+const MAX_EXPIRY_WARNING_DAYS: u32 = 90;
+
+/// CLI tool whose configured key is watched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TokenWatchTool {
+    /// `ANTHROPIC_AUTH_TOKEN` in ~/.claude/settings.json
+    Claude,
+    /// `OPENAI_API_KEY` in ~/.codex/auth.json
+    Codex,
+}
+
+impl TokenWatchTool {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Claude => "claude",
+            Self::Codex => "codex",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "codex" => Self::Codex,
+            _ => Self::Claude,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TokenWatchAlert {
+    /// Expires within the warning period
+    ExpiringSoon,
+    Expired,
+    /// Remaining quota below the threshold
+    LowQuota,
+    /// The configured key no longer matches an enabled token
+    KeyMismatch,
+}
+
+impl TokenWatchAlert {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ExpiringSoon => "expiring_soon",
+            Self::Expired => "expired",
+            Self::LowQuota => "low_quota",
+            Self::KeyMismatch => "key_mismatch",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "expiring_soon" => Some(Self::ExpiringSoon),
+            "expired" => Some(Self::Expired),
+            "low_quota" => Some(Self::LowQuota),
+            "key_mismatch" => Some(Self::KeyMismatch),
+            _ => None,
+        }
+    }
+}
+
+/// A provider token configured into a CLI tool
+///
+/// `key_digest` identifies the configured key without storing it again.
+/// `alerts` are the alerts raised by the last check, only new ones are sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenWatchTarget {
+    pub tool: TokenWatchTool,
+    pub account_id: AccountId,
+    pub token_id: TokenId,
+    pub key_digest: String,
+    pub alerts: Vec<TokenWatchAlert>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+impl TokenWatchTarget {
+    pub fn new(
+        tool: TokenWatchTool,
+        account_id: AccountId,
+        token_id: TokenId,
+        key_digest: String,
+    ) -> Self {
+        Self {
+            tool,
+            account_id,
+            token_id,
+            key_digest,
+            alerts: Vec::new(),
+            checked_at: None,
+            last_error: None,
+        }
+    }
+
+    /// Store the alerts of a check, returns the ones not raised by the previous check
+    pub fn record_check(
+        &mut self,
+        alerts: Vec<TokenWatchAlert>,
+        now: DateTime<Utc>,
+    ) -> Vec<TokenWatchAlert> {
+        let raised = alerts
+            .iter()
+            .copied()
+            .filter(|alert| !self.alerts.contains(alert))
+            .collect();
+        self.alerts = alerts;
+        self.checked_at = Some(now);
+        self.last_error = None;
+        raised
+    }
+
+    /// Keep the previous alerts when the tokens could not be fetched
+    pub fn record_error(&mut self, error: String, now: DateTime<Utc>) {
+        self.checked_at = Some(now);
+        self.last_error = Some(error);
+    }
+}
+
+/// Thresholds of the token watchdog (singleton)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenWatchSettings {
+    enabled: bool,
+    expiry_warning_days: u32,
+    /// In provider quota units
+    low_quota_threshold: i64,
+}
+
+impl Default for TokenWatchSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            expiry_warning_days: DEFAULT_EXPIRY_WARNING_DAYS,
+            low_quota_threshold: DEFAULT_LOW_QUOTA_THRESHOLD,
+        }
+    }
+}
+
+impl TokenWatchSettings {
+    pub fn restore(enabled: bool, expiry_warning_days: u32, low_quota_threshold: i64) -> Self {
+        Self {
+            enabled,
+            expiry_warning_days,
+            low_quota_threshold,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        enabled: bool,
+        expiry_warning_days: u32,
+        low_quota_threshold: i64,
+    ) -> Result<(), DomainError> {
+        if expiry_warning_days > MAX_EXPIRY_WARNING_DAYS {
+            return Err(DomainError::Validation(format!(
+                "Expiry warning cannot be more than {} days ahead",
+                MAX_EXPIRY_WARNING_DAYS
+            )));
+        }
+        if low_quota_threshold < 0 {
+            return Err(DomainError::Validation(
+                "Low quota threshold cannot be negative".to_string(),
+            ));
+        }
+        self.enabled = enabled;
+        self.expiry_warning_days = expiry_warning_days;
+        self.low_quota_threshold = low_quota_threshold;
+        Ok(())
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn expiry_warning_days(&self) -> u32 {
+        self.expiry_warning_days
+    }
+
+    pub fn low_quota_threshold(&self) -> i64 {
+        self.low_quota_threshold
+    }
+
+    /// Alerts for the token matching the configured key, `None` when no token matches
+    pub fn alerts_for(&self, token: Option<&ApiToken>, now: DateTime<Utc>) -> Vec<TokenWatchAlert> {
+        let Some(token) = token else {
+            return vec![TokenWatchAlert::KeyMismatch];
+        };
+        let expired = token.status() == TokenStatus::Expired
+            || token.expired_time().is_some_and(|at| at <= now);
+        if expired {
+            return vec![TokenWatchAlert::Expired];
+        }
+        if token.status() != TokenStatus::Enabled {
+            return vec![TokenWatchAlert::KeyMismatch];
+        }
+
+        let mut alerts = Vec::new();
+        let warn_from = now + Duration::days(self.expiry_warning_days as i64);
+        if token.expired_time().is_some_and(|at| at <= warn_from) {
+            alerts.push(TokenWatchAlert::ExpiringSoon);
+        }
+        if !token.unlimited_quota() && token.remain_quota() < self.low_quota_threshold {
+            alerts.push(TokenWatchAlert::LowQuota);
+        }
+        alerts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::ApiTokenConfig;
+
+    fn token(
+        status: TokenStatus,
+        remain_quota: i64,
+        expired_time: Option<DateTime<Utc>>,
+    ) -> ApiToken {
+        ApiToken::new(
+            TokenId::new(1),
+            AccountId::from_string("acc-1"),
+            ApiTokenConfig {
+                name: "cli".to_string(),
+                key: "sk-test".to_string(),
+                status,
+                used_quota: 0,
+                remain_quota,
+                unlimited_quota: false,
+                expired_time,
+                model_limits_enabled: false,
+                model_limits: None,
+                group: String::new(),
+                allow_ips: String::new(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_alerts_for_token_state() {
+        let settings = TokenWatchSettings::default();
+        let now = Utc::now();
+
+        assert!(settings
+            .alerts_for(Some(&token(TokenStatus::Enabled, 10_000_000, None)), now)
+            .is_empty());
+        assert_eq!(
+            settings.alerts_for(None, now),
+            vec![TokenWatchAlert::KeyMismatch]
+        );
+        assert_eq!(
+            settings.alerts_for(Some(&token(TokenStatus::Disabled, 10_000_000, None)), now),
+            vec![TokenWatchAlert::KeyMismatch]
+        );
+        assert_eq!(
+            settings.alerts_for(
+                Some(&token(
+                    TokenStatus::Enabled,
+                    10_000_000,
+                    Some(now - Duration::hours(1))
+                )),
+                now
+            ),
+            vec![TokenWatchAlert::Expired]
+        );
+        assert_eq!(
+            settings.alerts_for(
+                Some(&token(
+                    TokenStatus::Enabled,
+                    100,
+                    Some(now + Duration::days(1))
+                )),
+                now
+            ),
+            vec![TokenWatchAlert::ExpiringSoon, TokenWatchAlert::LowQuota]
+        );
+    }
+
+    #[test]
+    fn test_record_check_returns_only_new_alerts() {
+        let mut target = TokenWatchTarget::new(
+            TokenWatchTool::Codex,
+            AccountId::from_string("acc-1"),
+            TokenId::new(1),
+            "digest".to_string(),
+        );
+        let now = Utc::now();
+
+        assert_eq!(
+            target.record_check(vec![TokenWatchAlert::LowQuota], now),
+            vec![TokenWatchAlert::LowQuota]
+        );
+        assert!(target
+            .record_check(vec![TokenWatchAlert::LowQuota], now)
+            .is_empty());
+
+        target.record_error("timeout".to_string(), now);
+        assert_eq!(target.alerts, vec![TokenWatchAlert::LowQuota]);
+
+        // Once resolved, the alert is raised again the next time
+        target.record_check(Vec::new(), now);
+        assert_eq!(
+            target.record_check(vec![TokenWatchAlert::LowQuota], now),
+            vec![TokenWatchAlert::LowQuota]
+        );
+    }
+
+    #[test]
+    fn test_settings_update_validates() {
+        let mut settings = TokenWatchSettings::default();
+        assert!(settings.update(true, 91, 0).is_err());
+        assert!(settings.update(true, 7, -1).is_err());
+        settings.update(false, 7, 1_000_000).unwrap();
+        assert!(!settings.enabled());
+        assert_eq!(settings.expiry_warning_days(), 7);
+    }
+}
@@ -32,6 +32,9 @@ pub trait WafCookiesRepository: Send + Sync {
     /// Get valid (non-expired) WAF cookies for a provider
     async fn get_valid(&self, provider_id: &str) -> Result<Option<WafCookies>, DomainError>;
 
+    /// List cached WAF cookies of all providers, expired entries included
+    async fn find_all(&self) -> Result<Vec<WafCookies>, DomainError>;
+
     /// Delete WAF cookies for a provider
     async fn delete(&self, provider_id: &str) -> Result<(), DomainError>;
 
@@ -0,0 +1,30 @@
+-- Daily aggregates for balance_history rows older than the raw retention window
+CREATE TABLE IF NOT EXISTS balance_history_daily (
+    account_id TEXT NOT NULL,
+    record_date TEXT NOT NULL,
+    min_balance REAL NOT NULL,
+    max_balance REAL NOT NULL,
+    last_balance REAL NOT NULL,
+    last_total_consumed REAL NOT NULL,
+    last_total_quota REAL NOT NULL,
+    sample_count INTEGER NOT NULL DEFAULT 1,
+    last_recorded_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (account_id, record_date),
+    FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_balance_history_daily_date
+ON balance_history_daily(record_date);
+
+-- Retention policy for balance_history (singleton)
+CREATE TABLE IF NOT EXISTS balance_history_retention (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    enabled BOOLEAN NOT NULL DEFAULT 1,
+    raw_retention_days INTEGER NOT NULL DEFAULT 90 CHECK(raw_retention_days >= 7 AND raw_retention_days <= 3650),
+    vacuum_after_cleanup BOOLEAN NOT NULL DEFAULT 1,
+    last_run_at TEXT,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO balance_history_retention (id, enabled, raw_retention_days, vacuum_after_cleanup)
+VALUES (1, 1, 90, 1);
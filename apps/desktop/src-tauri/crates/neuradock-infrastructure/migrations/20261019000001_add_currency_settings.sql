@@ -0,0 +1,27 @@
+-- Display currency for normalized balances (singleton)
+CREATE TABLE IF NOT EXISTS currency_settings (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    display_currency TEXT NOT NULL DEFAULT 'USD',
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO currency_settings (id, display_currency)
+VALUES (1, 'USD');
+
+-- User-configurable exchange rates, expressed as units per 1 USD
+CREATE TABLE IF NOT EXISTS exchange_rates (
+    currency TEXT PRIMARY KEY,
+    units_per_usd REAL NOT NULL CHECK(units_per_usd > 0),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO exchange_rates (currency, units_per_usd) VALUES ('USD', 1.0);
+INSERT OR IGNORE INTO exchange_rates (currency, units_per_usd) VALUES ('CNY', 7.2);
+
+-- Unit each provider reports balances in, with its conversion factor
+CREATE TABLE IF NOT EXISTS provider_balance_units (
+    provider_id TEXT PRIMARY KEY,
+    unit TEXT NOT NULL CHECK(unit IN ('usd', 'cny', 'quota_units', 'tokens')),
+    conversion_factor REAL NOT NULL DEFAULT 1.0 CHECK(conversion_factor > 0),
+    FOREIGN KEY (provider_id) REFERENCES providers(id) ON DELETE CASCADE
+);
@@ -0,0 +1,27 @@
+-- Proxy authentication and direct-connection bypass list
+ALTER TABLE proxy_settings ADD COLUMN username TEXT;
+-- Password is stored encrypted
+ALTER TABLE proxy_settings ADD COLUMN password TEXT;
+ALTER TABLE proxy_settings ADD COLUMN bypass_domains TEXT NOT NULL DEFAULT '[]';
+
+-- Named proxy pools, endpoints stored as JSON with encrypted passwords
+CREATE TABLE IF NOT EXISTS proxy_pools (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    strategy TEXT NOT NULL DEFAULT 'round_robin' CHECK(strategy IN ('round_robin', 'sticky')),
+    endpoints TEXT NOT NULL DEFAULT '[]',
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+-- Per-account and per-provider proxy routes
+CREATE TABLE IF NOT EXISTS proxy_overrides (
+    scope_type TEXT NOT NULL CHECK(scope_type IN ('account', 'provider')),
+    scope_id TEXT NOT NULL,
+    route_kind TEXT NOT NULL CHECK(route_kind IN ('global', 'direct', 'pool', 'endpoint')),
+    pool_id TEXT,
+    endpoint TEXT, -- JSON with encrypted password
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (scope_type, scope_id),
+    FOREIGN KEY (pool_id) REFERENCES proxy_pools(id) ON DELETE RESTRICT
+);
@@ -0,0 +1,22 @@
+-- Named Claude Code settings profiles
+CREATE TABLE IF NOT EXISTS claude_profiles (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    auth_token TEXT NOT NULL, -- encrypted
+    base_url TEXT NOT NULL,
+    haiku_model TEXT,
+    sonnet_model TEXT,
+    opus_model TEXT,
+    extra_env TEXT NOT NULL, -- encrypted JSON object
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- Profile currently written to settings.json (single row)
+CREATE TABLE IF NOT EXISTS claude_active_profile (
+    id INTEGER PRIMARY KEY CHECK(id = 1),
+    profile_id INTEGER NOT NULL,
+    applied_env TEXT NOT NULL, -- JSON object of env key to value digest
+    applied_at TEXT NOT NULL,
+    FOREIGN KEY (profile_id) REFERENCES claude_profiles(id) ON DELETE CASCADE
+);
@@ -0,0 +1,13 @@
+-- Background refresh of Codex OAuth tokens (singleton)
+-- token_url can point at a local stand-in instead of auth.openai.com
+CREATE TABLE IF NOT EXISTS codex_token_refresh_policy (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    enabled BOOLEAN NOT NULL DEFAULT 1,
+    refresh_before_minutes INTEGER NOT NULL DEFAULT 1440 CHECK(refresh_before_minutes >= 5 AND refresh_before_minutes <= 10080),
+    token_url TEXT NOT NULL DEFAULT 'https://auth.openai.com/oauth/token',
+    last_run_at TEXT,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO codex_token_refresh_policy (id, enabled, refresh_before_minutes)
+VALUES (1, 1, 1440);
@@ -0,0 +1,31 @@
+-- Quota-aware switching of ~/.codex/auth.json between stored Codex accounts (singleton)
+-- home_account_id and switch_back_at are set while switched away from the original account
+CREATE TABLE IF NOT EXISTS codex_auto_switch_policy (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    enabled BOOLEAN NOT NULL DEFAULT 0,
+    threshold_percent INTEGER NOT NULL DEFAULT 90 CHECK(threshold_percent >= 50 AND threshold_percent <= 100),
+    poll_interval_minutes INTEGER NOT NULL DEFAULT 10 CHECK(poll_interval_minutes >= 1 AND poll_interval_minutes <= 1440),
+    switch_back BOOLEAN NOT NULL DEFAULT 1,
+    home_account_id TEXT,
+    switch_back_at TEXT,
+    last_checked_at TEXT,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO codex_auto_switch_policy (id, enabled, threshold_percent, poll_interval_minutes, switch_back)
+VALUES (1, 0, 90, 10, 1);
+
+-- Every automatic switch, kept after the accounts are deleted
+CREATE TABLE IF NOT EXISTS codex_auto_switch_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    from_account_id TEXT,
+    from_email TEXT NOT NULL,
+    to_account_id TEXT NOT NULL,
+    to_email TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    from_used_percent REAL,
+    switched_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_codex_auto_switch_log_switched_at
+ON codex_auto_switch_log(switched_at);
@@ -0,0 +1,39 @@
+-- Time series of Codex rate-limit window usage, one row per account, window and poll
+CREATE TABLE IF NOT EXISTS codex_usage_snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id TEXT NOT NULL,
+    window_kind TEXT NOT NULL CHECK(window_kind IN ('primary', 'secondary')),
+    used_percent REAL NOT NULL,
+    window_minutes INTEGER,
+    resets_at TEXT,
+    recorded_at TEXT NOT NULL,
+    FOREIGN KEY (account_id) REFERENCES codex_accounts(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_codex_usage_snapshots_account_recorded
+ON codex_usage_snapshots(account_id, recorded_at);
+
+CREATE INDEX IF NOT EXISTS idx_codex_usage_snapshots_recorded_at
+ON codex_usage_snapshots(recorded_at);
+
+-- Reminders already sent, at most one per account, window, kind and window cycle
+CREATE TABLE IF NOT EXISTS codex_usage_reminders (
+    account_id TEXT NOT NULL,
+    window_kind TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    cycle_resets_at TEXT NOT NULL,
+    sent_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (account_id, window_kind, kind, cycle_resets_at),
+    FOREIGN KEY (account_id) REFERENCES codex_accounts(id) ON DELETE CASCADE
+);
+
+-- Which usage reminders are sent (singleton)
+CREATE TABLE IF NOT EXISTS codex_usage_reminder_settings (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    threshold_reminders BOOLEAN NOT NULL DEFAULT 1,
+    reset_reminders BOOLEAN NOT NULL DEFAULT 1,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO codex_usage_reminder_settings (id, threshold_reminders, reset_reminders)
+VALUES (1, 1, 1);
@@ -0,0 +1,10 @@
+-- Result of the last validation of an independent API key against its models endpoint
+-- available_models is a JSON array of model IDs, credit_* are only set for relays with a billing API
+ALTER TABLE independent_api_keys ADD COLUMN last_validation_status TEXT;
+ALTER TABLE independent_api_keys ADD COLUMN last_validation_status_code INTEGER;
+ALTER TABLE independent_api_keys ADD COLUMN last_validation_message TEXT;
+ALTER TABLE independent_api_keys ADD COLUMN last_validation_latency_ms INTEGER;
+ALTER TABLE independent_api_keys ADD COLUMN available_models TEXT;
+ALTER TABLE independent_api_keys ADD COLUMN credit_total REAL;
+ALTER TABLE independent_api_keys ADD COLUMN credit_used REAL;
+ALTER TABLE independent_api_keys ADD COLUMN last_validated_at TEXT;
@@ -0,0 +1,25 @@
+-- Provider tokens configured into CLI tools, one per tool
+CREATE TABLE IF NOT EXISTS token_watch_targets (
+    tool TEXT PRIMARY KEY CHECK(tool IN ('claude', 'codex')),
+    account_id TEXT NOT NULL,
+    token_id INTEGER NOT NULL,
+    -- SHA-256 of the configured key without the sk- prefix
+    key_digest TEXT NOT NULL,
+    -- Comma-separated alerts raised by the last check
+    alerts TEXT NOT NULL DEFAULT '',
+    checked_at TEXT,
+    last_error TEXT,
+    FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE
+);
+
+-- Thresholds of the token expiry and quota watchdog (singleton)
+CREATE TABLE IF NOT EXISTS token_watch_settings (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    enabled BOOLEAN NOT NULL DEFAULT 1,
+    expiry_warning_days INTEGER NOT NULL DEFAULT 3,
+    low_quota_threshold INTEGER NOT NULL DEFAULT 500000,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO token_watch_settings (id, enabled, expiry_warning_days, low_quota_threshold)
+VALUES (1, 1, 3, 500000);
@@ -0,0 +1,17 @@
+-- Record of state-changing operations, only ever appended to
+CREATE TABLE IF NOT EXISTS audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    occurred_at TEXT NOT NULL,
+    actor TEXT NOT NULL CHECK(actor IN ('user', 'system')),
+    action TEXT NOT NULL,
+    -- Account id or CLI tool name, not a foreign key so entries outlive what they describe
+    target TEXT NOT NULL,
+    target_name TEXT,
+    -- Summaries with secrets masked
+    before_summary TEXT,
+    after_summary TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_action ON audit_log(action, id);
+CREATE INDEX IF NOT EXISTS idx_audit_log_target ON audit_log(target, id);
+
@@ -0,0 +1,19 @@
+-- Every published domain event, in publish order
+CREATE TABLE IF NOT EXISTS domain_events (
+    sequence INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_name TEXT NOT NULL,
+    -- Account id for account events, not a foreign key so history outlives the account
+    aggregate_id TEXT,
+    payload TEXT NOT NULL,
+    occurred_at TEXT NOT NULL,
+    recorded_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_domain_events_aggregate ON domain_events(aggregate_id, sequence);
+
+-- Last sequence each durable subscriber has handled
+CREATE TABLE IF NOT EXISTS event_subscriber_checkpoints (
+    subscriber TEXT PRIMARY KEY,
+    last_sequence INTEGER NOT NULL,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,24 @@
+-- Runtime-editable timeouts and retry policy (singleton, defaults live in code)
+CREATE TABLE IF NOT EXISTS network_settings (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    waf_wait_ms INTEGER NOT NULL,
+    browser_launch_ms INTEGER NOT NULL,
+    http_request_ms INTEGER NOT NULL,
+    db_query_ms INTEGER NOT NULL,
+    max_retries INTEGER NOT NULL,
+    initial_backoff_ms INTEGER NOT NULL,
+    max_backoff_ms INTEGER NOT NULL,
+    backoff_multiplier REAL NOT NULL,
+    waf_max_attempts INTEGER NOT NULL,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+-- Per-provider overrides for slow sites, NULL keeps the default
+CREATE TABLE IF NOT EXISTS provider_network_overrides (
+    provider_id TEXT PRIMARY KEY,
+    waf_wait_ms INTEGER,
+    http_request_ms INTEGER,
+    max_retries INTEGER,
+    waf_max_attempts INTEGER,
+    FOREIGN KEY (provider_id) REFERENCES providers(id) ON DELETE CASCADE
+);
@@ -0,0 +1,15 @@
+-- Model list history per provider
+-- A snapshot is recorded whenever a fetched list differs from the previous one
+CREATE TABLE IF NOT EXISTS provider_models_snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    provider_id TEXT NOT NULL,
+    models TEXT NOT NULL,  -- JSON array of model names
+    fetched_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_provider_models_snapshots_provider
+    ON provider_models_snapshots(provider_id, id);
+
+-- The current lists become the first snapshots
+INSERT INTO provider_models_snapshots (provider_id, models, fetched_at)
+SELECT provider_id, models, fetched_at FROM provider_models;
@@ -0,0 +1,5 @@
+-- Keep the billing group and allowed IPs of cached tokens
+-- Token updates replace the whole token on the provider, so these are sent back unchanged
+
+ALTER TABLE api_tokens ADD COLUMN token_group TEXT NOT NULL DEFAULT '';
+ALTER TABLE api_tokens ADD COLUMN allow_ips TEXT NOT NULL DEFAULT '';
@@ -1,3 +1,3 @@
 pub mod pool;
 
-pub use pool::{BrowserPool, BrowserPoolConfig, PooledBrowser};
+pub use pool::{BrowserPool, BrowserPoolConfig, BrowserPoolMetrics, PooledBrowser, PooledContext};
@@ -1,15 +1,60 @@
-use chromiumoxide::{Browser, BrowserConfig};
+use chromiumoxide::cdp::browser_protocol::browser::BrowserContextId;
+use chromiumoxide::cdp::browser_protocol::target::{
+    CreateBrowserContextParams, CreateTargetParams,
+};
+use chromiumoxide::{Browser, BrowserConfig, Page};
+use futures::StreamExt;
 use neuradock_domain::shared::DomainError;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{Mutex, Semaphore};
+use tokio::task::JoinHandle;
+
+use crate::config::TimeoutConfig;
+use crate::http::waf_bypass::find_browser;
+
+/// Launched browser, shared by the pool and the leases on it
+///
+/// Whoever drops the last reference kills the process and cleans up after it,
+/// so closing the pool never pulls a browser out from under a lease.
+struct BrowserProcess {
+    browser: Browser,
+    /// Declared after `browser` so it runs once the process has been dropped
+    cleanup: ProcessCleanup,
+}
+
+/// Stops the CDP handler and removes the temporary profile when dropped
+struct ProcessCleanup {
+    /// Drives the CDP connection, the browser stops answering once it ends
+    handler_task: JoinHandle<()>,
+    /// Temporary profile created for this instance
+    profile_dir: Option<PathBuf>,
+}
+
+impl Drop for ProcessCleanup {
+    fn drop(&mut self) {
+        self.handler_task.abort();
+        remove_profile_dir(self.profile_dir.as_ref());
+    }
+}
 
 /// Browser instance with metadata
 struct BrowserInstance {
-    browser: Arc<Browser>,
+    process: Arc<BrowserProcess>,
     created_at: Instant,
     last_used: Instant,
     usage_count: usize,
+    /// Leases currently holding this instance
+    active: Arc<AtomicUsize>,
+}
+
+impl BrowserInstance {
+    fn is_idle(&self) -> bool {
+        self.active.load(Ordering::SeqCst) == 0
+    }
 }
 
 /// Browser pool configuration
@@ -21,8 +66,8 @@ pub struct BrowserPoolConfig {
     pub max_idle_time: u64,
     /// Maximum usage count before recycling
     pub max_usage_count: usize,
-    /// Browser launch timeout (seconds)
-    pub launch_timeout: u64,
+    /// Browser launch timeout (seconds), `None` follows the configured network policy
+    pub launch_timeout: Option<u64>,
 }
 
 impl Default for BrowserPoolConfig {
@@ -31,61 +76,138 @@ impl Default for BrowserPoolConfig {
             max_size: 3,
             max_idle_time: 300, // 5 minutes
             max_usage_count: 50,
-            launch_timeout: 30,
+            launch_timeout: None,
         }
     }
 }
 
+impl BrowserPoolConfig {
+    fn is_reusable(&self, instance: &BrowserInstance, now: Instant) -> bool {
+        now.duration_since(instance.last_used).as_secs() < self.max_idle_time
+            && instance.usage_count < self.max_usage_count
+            && !instance.process.cleanup.handler_task.is_finished()
+    }
+}
+
+/// How pooled browsers are launched
+enum BrowserLauncher {
+    /// Fixed configuration, shared by every instance
+    Config(Box<BrowserConfig>),
+    /// Detect the installed Chromium-based browser, one temporary profile per instance
+    System { headless: bool },
+}
+
+/// Pool usage counters
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BrowserPoolMetrics {
+    /// Browsers currently running
+    pub size: usize,
+    pub max_size: usize,
+    /// Leases currently held
+    pub in_use: usize,
+    /// Isolated contexts currently open
+    pub active_contexts: usize,
+    pub launched_total: u64,
+    pub reused_total: u64,
+    pub recycled_total: u64,
+    pub launch_failures: u64,
+    pub contexts_total: u64,
+}
+
+#[derive(Default)]
+struct PoolCounters {
+    launched: AtomicU64,
+    reused: AtomicU64,
+    recycled: AtomicU64,
+    launch_failures: AtomicU64,
+    contexts: AtomicU64,
+    active_contexts: AtomicUsize,
+}
+
+#[derive(Default)]
+struct PoolState {
+    instances: Vec<BrowserInstance>,
+    /// Launches in flight, counted against `max_size`
+    launching: usize,
+}
+
 /// Browser pool manager
 pub struct BrowserPool {
-    pool: Arc<Mutex<Vec<BrowserInstance>>>,
+    pool: Arc<Mutex<PoolState>>,
     semaphore: Arc<Semaphore>,
     config: BrowserPoolConfig,
-    browser_config: BrowserConfig,
+    launcher: BrowserLauncher,
+    counters: Arc<PoolCounters>,
+    cleanup_handle: Mutex<Option<JoinHandle<()>>>,
 }
 
 impl BrowserPool {
     /// Create a new browser pool
     pub fn new(config: BrowserPoolConfig, browser_config: BrowserConfig) -> Self {
+        Self::with_launcher(config, BrowserLauncher::Config(Box::new(browser_config)))
+    }
+
+    /// Create a pool launching the browser found on the system
+    ///
+    /// Detection happens on the first launch, so the pool can be created on
+    /// machines without a browser and only fails when a page is requested.
+    pub fn for_system_browser(config: BrowserPoolConfig, headless: bool) -> Self {
+        Self::with_launcher(config, BrowserLauncher::System { headless })
+    }
+
+    fn with_launcher(config: BrowserPoolConfig, launcher: BrowserLauncher) -> Self {
         Self {
-            pool: Arc::new(Mutex::new(Vec::with_capacity(config.max_size))),
+            pool: Arc::new(Mutex::new(PoolState {
+                instances: Vec::with_capacity(config.max_size),
+                launching: 0,
+            })),
             semaphore: Arc::new(Semaphore::new(config.max_size)),
             config,
-            browser_config,
+            launcher,
+            counters: Arc::new(PoolCounters::default()),
+            cleanup_handle: Mutex::new(None),
         }
     }
 
     /// Acquire a browser from the pool
+    ///
+    /// Prefers an idle browser, launches a new one while below `max_size`
+    /// and otherwise shares the least busy one.
     pub async fn acquire(&self) -> Result<PooledBrowser, DomainError> {
         // Acquire semaphore permit
         let permit = self.semaphore.clone().acquire_owned().await.map_err(|e| {
             DomainError::Infrastructure(format!("Failed to acquire browser: {}", e))
         })?;
 
-        // Try to get an existing browser
-        let mut pool = self.pool.lock().await;
-
-        // Find a usable browser
         let now = Instant::now();
-        let mut reusable_idx = None;
-
-        for (idx, instance) in pool.iter().enumerate() {
-            let idle_time = now.duration_since(instance.last_used);
-
-            // Check if browser is still valid
-            if idle_time.as_secs() < self.config.max_idle_time
-                && instance.usage_count < self.config.max_usage_count
+        let mut state = self.pool.lock().await;
+        let retired = self.take_stale(&mut state, now);
+
+        let reusable = state
+            .instances
+            .iter()
+            .enumerate()
+            .filter(|(_, instance)| self.config.is_reusable(instance, now))
+            .min_by_key(|(_, instance)| instance.active.load(Ordering::SeqCst))
+            .map(|(idx, instance)| (idx, instance.is_idle()));
+
+        let shared = match reusable {
+            Some((idx, true)) => Some(idx),
+            Some((idx, false))
+                if state.instances.len() + state.launching >= self.config.max_size =>
             {
-                reusable_idx = Some(idx);
-                break;
+                Some(idx)
             }
-        }
+            _ => None,
+        };
 
-        let browser = if let Some(idx) = reusable_idx {
+        if let Some(idx) = shared {
             // Reuse existing browser - update stats WITHOUT removing from pool
-            let instance = &mut pool[idx];
+            let instance = &mut state.instances[idx];
             instance.last_used = now;
             instance.usage_count += 1;
+            instance.active.fetch_add(1, Ordering::SeqCst);
+            self.counters.reused.fetch_add(1, Ordering::Relaxed);
 
             tracing::info!(
                 "Reusing browser instance (usage: {}, age: {}s)",
@@ -93,90 +215,299 @@ impl BrowserPool {
                 now.duration_since(instance.created_at).as_secs()
             );
 
-            Arc::clone(&instance.browser)
-        } else {
-            // Create new browser
-            tracing::info!("Creating new browser instance (pool size: {})", pool.len());
+            let pooled = PooledBrowser {
+                process: Arc::clone(&instance.process),
+                active: Arc::clone(&instance.active),
+                _permit: permit,
+            };
+            drop(state);
+            Self::close_all(retired).await;
+            return Ok(pooled);
+        }
+
+        // Create new browser
+        tracing::info!(
+            "Creating new browser instance (pool size: {})",
+            state.instances.len()
+        );
+        state.launching += 1;
+        drop(state); // Release lock before launching browser
+        Self::close_all(retired).await;
+
+        let launched = self.launch().await;
+
+        let mut state = self.pool.lock().await;
+        state.launching -= 1;
+        let instance = match launched {
+            Ok(instance) => instance,
+            Err(e) => {
+                self.counters
+                    .launch_failures
+                    .fetch_add(1, Ordering::Relaxed);
+                return Err(e);
+            }
+        };
+        self.counters.launched.fetch_add(1, Ordering::Relaxed);
 
-            drop(pool); // Release lock before launching browser
+        let pooled = PooledBrowser {
+            process: Arc::clone(&instance.process),
+            active: Arc::clone(&instance.active),
+            _permit: permit,
+        };
+        state.instances.push(instance);
 
-            let (browser, _handler) = tokio::time::timeout(
-                Duration::from_secs(self.config.launch_timeout),
-                Browser::launch(self.browser_config.clone()),
-            )
+        Ok(pooled)
+    }
+
+    /// Acquire a browser and open an isolated context on it
+    ///
+    /// Cookies and storage are not shared with other contexts, so concurrent
+    /// runs for different providers (or accounts) cannot see each other's
+    /// sessions. `proxy_server` applies to this context only.
+    pub async fn acquire_context(
+        &self,
+        provider_key: &str,
+        proxy_server: Option<&str>,
+    ) -> Result<PooledContext, DomainError> {
+        let browser = self.acquire().await?;
+
+        let mut params = CreateBrowserContextParams::builder().dispose_on_detach(true);
+        if let Some(proxy_server) = proxy_server {
+            params = params.proxy_server(proxy_server);
+        }
+        let context_id = browser
+            .browser()
+            .create_browser_context(params.build())
             .await
-            .map_err(|_| DomainError::Infrastructure("Browser launch timeout".to_string()))?
-            .map_err(|e| DomainError::Infrastructure(format!("Failed to launch browser: {}", e)))?;
-
-            let browser = Arc::new(browser);
-            let mut pool = self.pool.lock().await;
-            pool.push(BrowserInstance {
-                browser: Arc::clone(&browser),
-                created_at: now,
-                last_used: now,
-                usage_count: 1,
-            });
+            .map_err(|e| {
+                DomainError::Infrastructure(format!("Failed to create browser context: {}", e))
+            })?;
 
-            browser
-        };
+        self.counters.contexts.fetch_add(1, Ordering::Relaxed);
+        self.counters.active_contexts.fetch_add(1, Ordering::SeqCst);
+        tracing::debug!("Opened browser context for {}", provider_key);
 
-        Ok(PooledBrowser {
+        Ok(PooledContext {
             browser,
-            _permit: permit,
+            context_id: Some(context_id),
+            provider_key: provider_key.to_string(),
+            counters: Arc::clone(&self.counters),
         })
     }
 
     /// Get current pool size
     pub async fn size(&self) -> usize {
-        self.pool.lock().await.len()
+        self.pool.lock().await.instances.len()
+    }
+
+    /// Snapshot of the pool usage counters
+    pub async fn metrics(&self) -> BrowserPoolMetrics {
+        let size = self.size().await;
+        BrowserPoolMetrics {
+            size,
+            max_size: self.config.max_size,
+            in_use: self.config.max_size - self.semaphore.available_permits(),
+            active_contexts: self.counters.active_contexts.load(Ordering::SeqCst),
+            launched_total: self.counters.launched.load(Ordering::Relaxed),
+            reused_total: self.counters.reused.load(Ordering::Relaxed),
+            recycled_total: self.counters.recycled.load(Ordering::Relaxed),
+            launch_failures: self.counters.launch_failures.load(Ordering::Relaxed),
+            contexts_total: self.counters.contexts.load(Ordering::Relaxed),
+        }
     }
 
     /// Clear all browsers in the pool
+    ///
+    /// Browsers still leased keep running until their last lease is dropped.
     pub async fn clear(&self) {
-        let mut pool = self.pool.lock().await;
-        pool.clear();
+        let instances = std::mem::take(&mut self.pool.lock().await.instances);
+        Self::close_all(instances).await;
         tracing::info!("Browser pool cleared");
     }
 
     /// Clean up stale browsers
     pub async fn cleanup(&self) {
-        let mut pool = self.pool.lock().await;
-        let now = Instant::now();
+        let retired = {
+            let mut state = self.pool.lock().await;
+            self.take_stale(&mut state, Instant::now())
+        };
 
-        let initial_size = pool.len();
-        pool.retain(|instance| {
-            let idle_time = now.duration_since(instance.last_used);
-            let is_valid = idle_time.as_secs() < self.config.max_idle_time
-                && instance.usage_count < self.config.max_usage_count;
-
-            if !is_valid {
-                tracing::info!(
-                    "Removing stale browser (idle: {}s, usage: {})",
-                    idle_time.as_secs(),
-                    instance.usage_count
-                );
+        let removed = retired.len();
+        Self::close_all(retired).await;
+        if removed > 0 {
+            tracing::info!("Cleaned up {} stale browser(s)", removed);
+        }
+    }
+
+    /// Close idle browsers in the background every minute
+    pub async fn start_cleanup_task(self: &Arc<Self>) {
+        let pool = Arc::downgrade(self);
+
+        let handle = tokio::spawn(async move {
+            let mut cleanup_interval = tokio::time::interval(Duration::from_secs(60));
+            loop {
+                cleanup_interval.tick().await;
+                let Some(pool) = pool.upgrade() else {
+                    return;
+                };
+                pool.cleanup().await;
             }
+        });
+
+        if let Some(previous) = self.cleanup_handle.lock().await.replace(handle) {
+            previous.abort();
+        }
+    }
 
-            is_valid
+    /// Remove idle instances that can no longer be reused
+    fn take_stale(&self, state: &mut PoolState, now: Instant) -> Vec<BrowserInstance> {
+        let (keep, retired): (Vec<_>, Vec<_>) = std::mem::take(&mut state.instances)
+            .into_iter()
+            .partition(|instance| !instance.is_idle() || self.config.is_reusable(instance, now));
+        state.instances = keep;
+
+        for instance in &retired {
+            tracing::info!(
+                "Removing stale browser (idle: {}s, usage: {})",
+                now.duration_since(instance.last_used).as_secs(),
+                instance.usage_count
+            );
+        }
+        self.counters
+            .recycled
+            .fetch_add(retired.len() as u64, Ordering::Relaxed);
+        retired
+    }
+
+    async fn launch(&self) -> Result<BrowserInstance, DomainError> {
+        let (browser_config, profile_dir) = match &self.launcher {
+            BrowserLauncher::Config(config) => (config.as_ref().clone(), None),
+            BrowserLauncher::System { headless } => {
+                let (config, profile_dir) = system_browser_config(*headless)?;
+                (config, Some(profile_dir))
+            }
+        };
+
+        let launch_timeout = self
+            .config
+            .launch_timeout
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| TimeoutConfig::global().browser_launch);
+        let launched = tokio::time::timeout(launch_timeout, Browser::launch(browser_config)).await;
+
+        let (browser, mut handler) = match launched {
+            Ok(Ok(launched)) => launched,
+            Ok(Err(e)) => {
+                remove_profile_dir(profile_dir.as_ref());
+                return Err(DomainError::Infrastructure(format!(
+                    "Failed to launch browser: {}",
+                    e
+                )));
+            }
+            Err(_) => {
+                remove_profile_dir(profile_dir.as_ref());
+                return Err(DomainError::Infrastructure(
+                    "Browser launch timeout".to_string(),
+                ));
+            }
+        };
+
+        let handler_task = tokio::spawn(async move {
+            while let Some(_event) = handler.next().await {
+                // Handle events if needed
+            }
         });
 
-        let removed = initial_size - pool.len();
-        if removed > 0 {
-            tracing::info!("Cleaned up {} stale browser(s)", removed);
+        let now = Instant::now();
+        Ok(BrowserInstance {
+            process: Arc::new(BrowserProcess {
+                browser,
+                cleanup: ProcessCleanup {
+                    handler_task,
+                    profile_dir,
+                },
+            }),
+            created_at: now,
+            last_used: now,
+            usage_count: 1,
+            active: Arc::new(AtomicUsize::new(1)),
+        })
+    }
+
+    async fn close_all(instances: Vec<BrowserInstance>) {
+        for instance in instances {
+            Self::close_instance(instance).await;
+        }
+    }
+
+    async fn close_instance(instance: BrowserInstance) {
+        match Arc::try_unwrap(instance.process) {
+            Ok(mut process) => {
+                let close_timeout = TimeoutConfig::global().browser_close;
+                match tokio::time::timeout(close_timeout, process.browser.close()).await {
+                    Ok(Ok(_)) => {
+                        let _ = tokio::time::timeout(close_timeout, process.browser.wait()).await;
+                    }
+                    Ok(Err(e)) => tracing::warn!("Failed to close pooled browser: {}", e),
+                    Err(_) => tracing::warn!("Closing pooled browser timed out"),
+                }
+            }
+            // Still leased, the last lease to drop it kills the process
+            Err(_) => tracing::debug!("Pooled browser still leased, dropping reference"),
+        }
+    }
+}
+
+fn system_browser_config(headless: bool) -> Result<(BrowserConfig, PathBuf), DomainError> {
+    let browser_path = find_browser().ok_or_else(|| {
+        DomainError::Infrastructure(
+            "No Chromium-based browser found. Please install one of: Google Chrome, Chromium, Brave, or Microsoft Edge"
+                .to_string(),
+        )
+    })?;
+
+    // Every instance needs its own profile to avoid lock conflicts
+    let profile_dir =
+        std::env::temp_dir().join(format!("chromiumoxide-pool-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&profile_dir).map_err(|e| {
+        DomainError::Infrastructure(format!("Failed to create temp directory: {}", e))
+    })?;
+
+    let mut builder = BrowserConfig::builder()
+        .window_size(1920, 1080)
+        .no_sandbox()
+        .user_data_dir(&profile_dir)
+        .chrome_executable(&browser_path);
+    if !headless {
+        builder = builder.with_head();
+    }
+
+    let config = builder.build().map_err(|e| {
+        remove_profile_dir(Some(&profile_dir));
+        DomainError::Infrastructure(format!("Failed to build browser config: {}", e))
+    })?;
+    Ok((config, profile_dir))
+}
+
+fn remove_profile_dir(profile_dir: Option<&PathBuf>) {
+    if let Some(dir) = profile_dir {
+        if let Err(e) = std::fs::remove_dir_all(dir) {
+            tracing::warn!("Failed to remove browser profile {:?}: {}", dir, e);
         }
     }
 }
 
 /// Pooled browser with automatic return to pool
 pub struct PooledBrowser {
-    browser: Arc<Browser>,
+    process: Arc<BrowserProcess>,
+    active: Arc<AtomicUsize>,
     _permit: tokio::sync::OwnedSemaphorePermit,
 }
 
 impl PooledBrowser {
     /// Get reference to the browser
     pub fn browser(&self) -> &Browser {
-        &self.browser
+        &self.process.browser
     }
 }
 
@@ -184,14 +515,139 @@ impl Drop for PooledBrowser {
     fn drop(&mut self) {
         // Browser is returned to pool when PooledBrowser is dropped
         // The permit is automatically released
+        self.active.fetch_sub(1, Ordering::SeqCst);
         tracing::debug!("Browser returned to pool");
     }
 }
 
+/// Isolated browser context on a pooled browser
+///
+/// The context and its pages are disposed by [`PooledContext::close`], or in
+/// the background when the lease is dropped without closing it.
+pub struct PooledContext {
+    browser: PooledBrowser,
+    context_id: Option<BrowserContextId>,
+    provider_key: String,
+    counters: Arc<PoolCounters>,
+}
+
+impl PooledContext {
+    /// Open a page inside this context
+    pub async fn new_page(&self, url: &str) -> Result<Page, DomainError> {
+        let mut params = CreateTargetParams::builder().url(url);
+        if let Some(context_id) = self.context_id.clone() {
+            params = params.browser_context_id(context_id);
+        }
+        let params = params
+            .build()
+            .map_err(|e| DomainError::Infrastructure(format!("Invalid page target: {}", e)))?;
+
+        self.browser
+            .browser()
+            .new_page(params)
+            .await
+            .map_err(|e| DomainError::Infrastructure(format!("Failed to create new page: {}", e)))
+    }
+
+    /// Dispose the context, closing its pages, and return the browser to the pool
+    pub async fn close(mut self) {
+        if let Some(context_id) = self.context_id.take() {
+            if let Err(e) = self
+                .browser
+                .browser()
+                .dispose_browser_context(context_id)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to dispose browser context for {}: {}",
+                    self.provider_key,
+                    e
+                );
+            }
+            self.counters.active_contexts.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+impl Drop for PooledContext {
+    fn drop(&mut self) {
+        let Some(context_id) = self.context_id.take() else {
+            return;
+        };
+        self.counters.active_contexts.fetch_sub(1, Ordering::SeqCst);
+
+        let process = Arc::clone(&self.browser.process);
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(async move {
+                let _ = process.browser.dispose_browser_context(context_id).await;
+            });
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_metrics_start_empty() {
+        let pool = BrowserPool::for_system_browser(BrowserPoolConfig::default(), true);
+
+        let metrics = pool.metrics().await;
+        assert_eq!(metrics.size, 0);
+        assert_eq!(metrics.max_size, 3);
+        assert_eq!(metrics.in_use, 0);
+        assert_eq!(metrics.launched_total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_process_cleanup_runs_when_last_reference_drops() {
+        let profile_dir =
+            std::env::temp_dir().join(format!("neuradock-pool-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&profile_dir).unwrap();
+        let handler_task = tokio::spawn(std::future::pending::<()>());
+        let handler = handler_task.abort_handle();
+
+        let cleanup = Arc::new(ProcessCleanup {
+            handler_task,
+            profile_dir: Some(profile_dir.clone()),
+        });
+        let lease = Arc::clone(&cleanup);
+
+        // The pool letting go must not affect a lease still holding the browser
+        drop(cleanup);
+        tokio::task::yield_now().await;
+        assert!(profile_dir.exists());
+        assert!(!handler.is_finished());
+
+        drop(lease);
+        tokio::task::yield_now().await;
+        assert!(!profile_dir.exists());
+        assert!(handler.is_finished());
+    }
+
+    #[tokio::test]
+    async fn test_failed_launch_releases_slot() {
+        let config = BrowserPoolConfig {
+            max_size: 1,
+            launch_timeout: Some(5),
+            ..Default::default()
+        };
+        let browser_config = BrowserConfig::builder()
+            .chrome_executable("/nonexistent/neuradock-chrome")
+            .build()
+            .unwrap();
+        let pool = BrowserPool::new(config, browser_config);
+
+        assert!(pool.acquire().await.is_err());
+        assert!(pool.acquire().await.is_err());
+
+        let metrics = pool.metrics().await;
+        assert_eq!(metrics.launch_failures, 2);
+        assert_eq!(metrics.size, 0);
+        assert_eq!(metrics.in_use, 0);
+    }
+
     #[tokio::test]
     #[ignore = "Requires a local Chrome/Chromium runtime available to chromiumoxide"]
     async fn test_browser_pool_acquire() {
@@ -199,7 +655,7 @@ mod tests {
             max_size: 2,
             max_idle_time: 60,
             max_usage_count: 10,
-            launch_timeout: 30,
+            launch_timeout: Some(30),
         };
 
         let browser_config = BrowserConfig::builder().with_head().build().unwrap();
@@ -226,7 +682,7 @@ mod tests {
             max_size: 2,
             max_idle_time: 60,
             max_usage_count: 10,
-            launch_timeout: 30,
+            launch_timeout: Some(30),
         };
 
         let browser_config = BrowserConfig::builder().with_head().build().unwrap();
@@ -246,4 +702,21 @@ mod tests {
         // Should still have only 1 browser
         assert_eq!(pool.size().await, 1);
     }
+
+    #[tokio::test]
+    #[ignore = "Requires a local Chrome/Chromium runtime available to chromiumoxide"]
+    async fn test_contexts_share_one_browser() {
+        let pool = BrowserPool::for_system_browser(BrowserPoolConfig::default(), true);
+
+        let first = pool.acquire_context("provider-a", None).await.unwrap();
+        first.close().await;
+        let second = pool.acquire_context("provider-b", None).await.unwrap();
+        let _page = second.new_page("about:blank").await.unwrap();
+        second.close().await;
+
+        let metrics = pool.metrics().await;
+        assert_eq!(metrics.size, 1);
+        assert_eq!(metrics.contexts_total, 2);
+        assert_eq!(metrics.active_contexts, 0);
+    }
 }
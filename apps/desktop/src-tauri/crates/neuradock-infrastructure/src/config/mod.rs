@@ -1,3 +1,5 @@
+pub mod network_policy;
 pub mod timeouts;
 
+pub use network_policy::NetworkPolicy;
 pub use timeouts::TimeoutConfig;
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use super::TimeoutConfig;
+use crate::http::RetryConfig;
+
+/// Browser runs per WAF cookie fetch unless configured otherwise
+pub const DEFAULT_WAF_MAX_ATTEMPTS: u32 = 2;
+
+/// Timeouts and retry policy in effect for requests to one host
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkPolicy {
+    pub timeouts: TimeoutConfig,
+    pub retry: RetryConfig,
+    /// Browser runs per WAF cookie fetch, first attempt included
+    pub waf_max_attempts: u32,
+}
+
+impl Default for NetworkPolicy {
+    fn default() -> Self {
+        Self {
+            timeouts: TimeoutConfig::default(),
+            retry: RetryConfig::default(),
+            waf_max_attempts: DEFAULT_WAF_MAX_ATTEMPTS,
+        }
+    }
+}
+
+/// Default policy plus overrides keyed by lowercase host
+#[derive(Debug, Clone, Default)]
+pub struct NetworkPolicies {
+    pub default: NetworkPolicy,
+    pub hosts: HashMap<String, NetworkPolicy>,
+}
+
+impl NetworkPolicies {
+    /// Policy for a host, an override for `example.com` also covers `api.example.com`
+    ///
+    /// The most specific matching host wins.
+    pub fn for_host(&self, host: &str) -> NetworkPolicy {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        self.hosts
+            .iter()
+            .filter(|(candidate, _)| {
+                host == **candidate
+                    || host
+                        .strip_suffix(candidate.as_str())
+                        .is_some_and(|prefix| prefix.ends_with('.'))
+            })
+            .max_by_key(|(candidate, _)| candidate.len())
+            .map(|(_, policy)| *policy)
+            .unwrap_or(self.default)
+    }
+
+    /// Policy for the host of `url`, the default when it has none
+    pub fn for_url(&self, url: &str) -> NetworkPolicy {
+        url::Url::parse(url)
+            .ok()
+            .and_then(|parsed| parsed.host_str().map(|host| self.for_host(host)))
+            .unwrap_or(self.default)
+    }
+}
+
+static POLICIES: OnceLock<RwLock<NetworkPolicies>> = OnceLock::new();
+
+fn policies() -> &'static RwLock<NetworkPolicies> {
+    POLICIES.get_or_init(|| RwLock::new(NetworkPolicies::default()))
+}
+
+/// Replace the process-wide policies
+///
+/// Clients, WAF fetches and browser launches read them when they start a request, so
+/// new values apply to the next request without recreating anything.
+pub fn install(new_policies: NetworkPolicies) {
+    *policies()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = new_policies;
+}
+
+/// The default policy currently installed
+pub fn current() -> NetworkPolicy {
+    policies()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .default
+}
+
+/// The policy currently installed for the host of `url`
+pub fn for_url(url: &str) -> NetworkPolicy {
+    policies()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .for_url(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn with_waf_wait(secs: u64) -> NetworkPolicy {
+        let mut policy = NetworkPolicy::default();
+        policy.timeouts.waf_wait = Duration::from_secs(secs);
+        policy
+    }
+
+    #[test]
+    fn test_for_url_matches_host_and_subdomains() {
+        let policies = NetworkPolicies {
+            default: with_waf_wait(8),
+            hosts: HashMap::from([
+                ("example.com".to_string(), with_waf_wait(20)),
+                ("api.example.com".to_string(), with_waf_wait(40)),
+            ]),
+        };
+
+        let waf_wait = |url: &str| policies.for_url(url).timeouts.waf_wait.as_secs();
+        assert_eq!(waf_wait("https://example.com/login"), 20);
+        assert_eq!(waf_wait("https://www.Example.com/login"), 20);
+        assert_eq!(waf_wait("https://api.example.com/v1"), 40);
+        assert_eq!(waf_wait("https://notexample.com"), 8);
+        assert_eq!(waf_wait("not a url"), 8);
+    }
+}
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 /// Configuration for various timeout durations across the application
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct TimeoutConfig {
     /// WAF bypass wait time for page load
     pub waf_wait: Duration,
@@ -45,19 +45,13 @@ impl TimeoutConfig {
         Self::default()
     }
 
-    /// Get the global timeout configuration
-    pub fn global() -> &'static Self {
-        &GLOBAL_TIMEOUT_CONFIG
+    /// Get the timeout configuration currently installed (see `network_policy::install`)
+    pub fn global() -> Self {
+        super::network_policy::current().timeouts
     }
-}
 
-/// Global timeout configuration instance
-static GLOBAL_TIMEOUT_CONFIG: TimeoutConfig = TimeoutConfig {
-    waf_wait: Duration::from_secs(8),
-    check_in_processing: Duration::from_millis(1500),
-    browser_close: Duration::from_secs(5),
-    browser_launch: Duration::from_secs(30),
-    pool_idle: Duration::from_secs(300),
-    http_request: Duration::from_secs(30),
-    db_query: Duration::from_secs(10),
-};
+    /// Get the timeout configuration for the host of `url`, with per-site overrides
+    pub fn for_url(url: &str) -> Self {
+        super::network_policy::for_url(url).timeouts
+    }
+}
@@ -0,0 +1,192 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use log::{debug, info};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{ConnectOptions, Connection};
+use std::path::{Path, PathBuf};
+
+use super::{cookie_matches_host, host_from_domain, is_expired, CookieCollector, CookieImport};
+
+/// Microseconds between 1601-01-01 (Chromium epoch) and 1970-01-01
+const CHROMIUM_EPOCH_OFFSET_MICROS: i64 = 11_644_473_600_000_000;
+
+/// Firefox stores `expiry` in seconds, newer releases in milliseconds
+const FIREFOX_MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+/// Temporary copy of a cookie database, removed on drop
+///
+/// Browsers keep their cookie database locked while running, so it is
+/// copied (together with its WAL file) before being opened.
+struct DbSnapshot {
+    dir: PathBuf,
+    db_path: PathBuf,
+}
+
+impl DbSnapshot {
+    fn create(source: &Path) -> Result<Self> {
+        if !source.is_file() {
+            anyhow::bail!("Cookie database not found: {}", source.display());
+        }
+
+        let dir = std::env::temp_dir().join(format!("neuradock-cookies-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).context("Failed to create temporary directory")?;
+        let db_path = dir.join("cookies.db");
+        let snapshot = Self { dir, db_path };
+
+        std::fs::copy(source, &snapshot.db_path)
+            .with_context(|| format!("Failed to copy cookie database {}", source.display()))?;
+        let wal = PathBuf::from(format!("{}-wal", source.display()));
+        if wal.is_file() {
+            std::fs::copy(&wal, snapshot.dir.join("cookies.db-wal"))
+                .context("Failed to copy cookie database WAL")?;
+        }
+
+        Ok(snapshot)
+    }
+
+    async fn connect(&self) -> Result<sqlx::SqliteConnection> {
+        SqliteConnectOptions::new()
+            .filename(&self.db_path)
+            .connect()
+            .await
+            .context("Failed to open cookie database")
+    }
+}
+
+impl Drop for DbSnapshot {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_dir_all(&self.dir) {
+            debug!(
+                "Failed to remove cookie snapshot {}: {}",
+                self.dir.display(),
+                e
+            );
+        }
+    }
+}
+
+fn chromium_expiry(expires_utc: i64) -> Option<DateTime<Utc>> {
+    // 0 marks a session cookie
+    if expires_utc <= 0 {
+        return None;
+    }
+    DateTime::from_timestamp_micros(expires_utc - CHROMIUM_EPOCH_OFFSET_MICROS)
+}
+
+fn firefox_expiry(expiry: i64) -> Option<DateTime<Utc>> {
+    if expiry <= 0 {
+        return None;
+    }
+    if expiry >= FIREFOX_MILLIS_THRESHOLD {
+        DateTime::from_timestamp_millis(expiry)
+    } else {
+        DateTime::from_timestamp(expiry, 0)
+    }
+}
+
+/// Read cookies for `domain` from a Chromium-family `Cookies` database
+///
+/// Only plain-text values are imported. Values encrypted with the OS
+/// keyring (`v10`/`v11` prefixes) are counted in `skipped_encrypted`.
+pub async fn read_chromium_cookies(db_path: &Path, domain: &str) -> Result<CookieImport> {
+    let host = host_from_domain(domain);
+    let snapshot = DbSnapshot::create(db_path)?;
+    let mut conn = snapshot.connect().await?;
+
+    let rows: Vec<(String, String, String, Vec<u8>, i64)> =
+        sqlx::query_as("SELECT host_key, name, value, encrypted_value, expires_utc FROM cookies")
+            .fetch_all(&mut conn)
+            .await
+            .context("Failed to read Chromium cookies table")?;
+    conn.close().await.ok();
+
+    let now = Utc::now();
+    let mut collector = CookieCollector::default();
+    for (host_key, name, value, encrypted_value, expires_utc) in rows {
+        if !cookie_matches_host(&host_key, &host) {
+            continue;
+        }
+        if is_expired(chromium_expiry(expires_utc), now) {
+            collector.import.skipped_expired += 1;
+            continue;
+        }
+        if value.is_empty() && !encrypted_value.is_empty() {
+            collector.import.skipped_encrypted += 1;
+            continue;
+        }
+        collector.add(&host_key, &name, &value);
+    }
+
+    let import = collector.finish();
+    info!(
+        "Read {} Chromium cookie(s) for {} ({} encrypted, {} expired skipped)",
+        import.cookies.len(),
+        host,
+        import.skipped_encrypted,
+        import.skipped_expired
+    );
+    Ok(import)
+}
+
+/// Read cookies for `domain` from a Firefox `cookies.sqlite` database
+pub async fn read_firefox_cookies(db_path: &Path, domain: &str) -> Result<CookieImport> {
+    let host = host_from_domain(domain);
+    let snapshot = DbSnapshot::create(db_path)?;
+    let mut conn = snapshot.connect().await?;
+
+    let rows: Vec<(String, String, String, i64)> =
+        sqlx::query_as("SELECT host, name, value, expiry FROM moz_cookies")
+            .fetch_all(&mut conn)
+            .await
+            .context("Failed to read Firefox moz_cookies table")?;
+    conn.close().await.ok();
+
+    let now = Utc::now();
+    let mut collector = CookieCollector::default();
+    for (cookie_host, name, value, expiry) in rows {
+        if !cookie_matches_host(&cookie_host, &host) {
+            continue;
+        }
+        if is_expired(firefox_expiry(expiry), now) {
+            collector.import.skipped_expired += 1;
+            continue;
+        }
+        collector.add(&cookie_host, &name, &value);
+    }
+
+    let import = collector.finish();
+    info!(
+        "Read {} Firefox cookie(s) for {} ({} expired skipped)",
+        import.cookies.len(),
+        host,
+        import.skipped_expired
+    );
+    Ok(import)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chromium_expiry() {
+        assert_eq!(chromium_expiry(0), None);
+        // 2030-01-01T00:00:00Z
+        let expires =
+            chromium_expiry(1_893_456_000_000_000 + CHROMIUM_EPOCH_OFFSET_MICROS).unwrap();
+        assert_eq!(expires.timestamp(), 1_893_456_000);
+    }
+
+    #[test]
+    fn test_firefox_expiry_seconds_and_millis() {
+        assert_eq!(
+            firefox_expiry(1_893_456_000).unwrap().timestamp(),
+            1_893_456_000
+        );
+        assert_eq!(
+            firefox_expiry(1_893_456_000_000).unwrap().timestamp(),
+            1_893_456_000
+        );
+        assert_eq!(firefox_expiry(0), None);
+    }
+}
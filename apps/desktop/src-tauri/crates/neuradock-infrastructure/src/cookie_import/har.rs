@@ -0,0 +1,253 @@
+use anyhow::{Context, Result};
+use base64::Engine;
+use serde::Deserialize;
+
+use super::{cookie_matches_host, host_from_domain, CookieImport};
+use crate::http::api_user_from_user_info;
+
+#[derive(Deserialize)]
+struct Har {
+    log: HarLog,
+}
+
+#[derive(Deserialize)]
+struct HarLog {
+    #[serde(default)]
+    entries: Vec<HarEntry>,
+}
+
+#[derive(Deserialize)]
+struct HarEntry {
+    request: HarRequest,
+    response: Option<HarResponse>,
+}
+
+#[derive(Deserialize)]
+struct HarRequest {
+    url: String,
+    #[serde(default)]
+    headers: Vec<HarNameValue>,
+    #[serde(default)]
+    cookies: Vec<HarNameValue>,
+}
+
+#[derive(Deserialize)]
+struct HarResponse {
+    #[serde(default)]
+    status: i64,
+    #[serde(default)]
+    cookies: Vec<HarCookie>,
+    content: Option<HarContent>,
+}
+
+#[derive(Deserialize)]
+struct HarNameValue {
+    name: String,
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct HarCookie {
+    name: String,
+    value: String,
+    domain: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct HarContent {
+    text: Option<String>,
+    encoding: Option<String>,
+}
+
+impl HarContent {
+    fn decoded_text(&self) -> Option<String> {
+        let text = self.text.as_ref()?;
+        match self.encoding.as_deref() {
+            Some("base64") => base64::engine::general_purpose::STANDARD
+                .decode(text)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok()),
+            _ => Some(text.clone()),
+        }
+    }
+}
+
+fn parse_cookie_header(header: &str) -> impl Iterator<Item = (&str, &str)> {
+    header.split(';').filter_map(|pair| {
+        let (name, value) = pair.trim().split_once('=')?;
+        (!name.trim().is_empty()).then_some((name.trim(), value.trim()))
+    })
+}
+
+/// Parse a HAR archive and collect the cookies exchanged with `domain`
+///
+/// Entries are replayed in order, so cookies set by later responses win.
+/// The `api_user` is taken from the `api_user_key` request header, or
+/// failing that from a successful `user_info_path` response.
+pub fn parse_har(
+    content: &str,
+    domain: &str,
+    api_user_key: &str,
+    user_info_path: &str,
+) -> Result<CookieImport> {
+    let har: Har = serde_json::from_str(content).context("Invalid HAR file")?;
+    let host = host_from_domain(domain);
+    let mut import = CookieImport::default();
+    let mut header_api_user = None;
+    let mut response_api_user = None;
+
+    for entry in har.log.entries {
+        let Ok(url) = url::Url::parse(&entry.request.url) else {
+            continue;
+        };
+        let Some(request_host) = url.host_str().map(str::to_lowercase) else {
+            continue;
+        };
+        if !cookie_matches_host(&host, &request_host) {
+            continue;
+        }
+
+        if entry.request.cookies.is_empty() {
+            for header in entry
+                .request
+                .headers
+                .iter()
+                .filter(|h| h.name.eq_ignore_ascii_case("cookie"))
+            {
+                for (name, value) in parse_cookie_header(&header.value) {
+                    import.cookies.insert(name.to_string(), value.to_string());
+                }
+            }
+        } else {
+            for cookie in &entry.request.cookies {
+                import
+                    .cookies
+                    .insert(cookie.name.clone(), cookie.value.clone());
+            }
+        }
+
+        if let Some(header) = entry
+            .request
+            .headers
+            .iter()
+            .find(|h| h.name.eq_ignore_ascii_case(api_user_key))
+            .filter(|h| !h.value.trim().is_empty() && h.value.trim() != "-1")
+        {
+            header_api_user = Some(header.value.trim().to_string());
+        }
+
+        let Some(response) = entry.response else {
+            continue;
+        };
+        for cookie in response.cookies {
+            let applies = cookie
+                .domain
+                .as_deref()
+                .is_none_or(|cookie_domain| cookie_matches_host(cookie_domain, &host));
+            if applies {
+                import.cookies.insert(cookie.name, cookie.value);
+            }
+        }
+
+        if response.status == 200
+            && url.path().trim_end_matches('/') == user_info_path.trim_end_matches('/')
+        {
+            if let Some(json) = response
+                .content
+                .and_then(|content| content.decoded_text())
+                .and_then(|text| serde_json::from_str::<serde_json::Value>(&text).ok())
+            {
+                response_api_user = api_user_from_user_info(&json).or(response_api_user);
+            }
+        }
+    }
+
+    import.api_user = header_api_user.or(response_api_user);
+    Ok(import)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn har_fixture(api_user_header: Option<&str>) -> String {
+        let mut headers =
+            vec![serde_json::json!({ "name": "Cookie", "value": "session=old; acw_tc=waf" })];
+        if let Some(api_user) = api_user_header {
+            headers.push(serde_json::json!({ "name": "New-Api-User", "value": api_user }));
+        }
+
+        serde_json::json!({
+            "log": {
+                "entries": [
+                    {
+                        "request": {
+                            "url": "https://anyrouter.top/api/user/self",
+                            "headers": headers,
+                            "cookies": []
+                        },
+                        "response": {
+                            "status": 200,
+                            "cookies": [
+                                { "name": "session", "value": "fresh", "domain": ".anyrouter.top" }
+                            ],
+                            "content": {
+                                "mimeType": "application/json",
+                                "text": "{\"success\":true,\"data\":{\"id\":4242,\"username\":\"demo\"}}"
+                            }
+                        }
+                    },
+                    {
+                        "request": {
+                            "url": "https://cdn.example.com/app.js",
+                            "headers": [],
+                            "cookies": [ { "name": "tracker", "value": "x" } ]
+                        },
+                        "response": { "status": 200, "cookies": [] }
+                    }
+                ]
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_parse_har_collects_domain_cookies() {
+        let import = parse_har(
+            &har_fixture(None),
+            "anyrouter.top",
+            "new-api-user",
+            "/api/user/self",
+        )
+        .unwrap();
+
+        assert_eq!(import.cookies.len(), 2);
+        assert_eq!(
+            import.cookies.get("session").map(String::as_str),
+            Some("fresh")
+        );
+        assert_eq!(
+            import.cookies.get("acw_tc").map(String::as_str),
+            Some("waf")
+        );
+        assert_eq!(import.api_user.as_deref(), Some("4242"));
+    }
+
+    #[test]
+    fn test_parse_har_prefers_api_user_header() {
+        let har = har_fixture(Some("7"));
+        let import = parse_har(&har, "anyrouter.top", "new-api-user", "/api/user/self").unwrap();
+        assert_eq!(import.api_user.as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn test_parse_har_rejects_invalid_json() {
+        assert!(parse_har(
+            "not json",
+            "anyrouter.top",
+            "new-api-user",
+            "/api/user/self"
+        )
+        .is_err());
+    }
+}
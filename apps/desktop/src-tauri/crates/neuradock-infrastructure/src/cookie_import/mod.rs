@@ -0,0 +1,108 @@
+//! Read provider cookies from browser profiles and exported files
+//!
+//! Supported sources:
+//! - Chromium / Chrome / Edge `Cookies` SQLite database (unencrypted values only)
+//! - Firefox `cookies.sqlite` database
+//! - HAR archives exported from browser devtools
+//! - Netscape `cookies.txt` files
+
+mod browser_db;
+mod har;
+mod netscape;
+
+pub use browser_db::{read_chromium_cookies, read_firefox_cookies};
+pub use har::parse_har;
+pub use netscape::parse_netscape_cookies;
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Cookies extracted for one provider domain
+#[derive(Debug, Clone, Default)]
+pub struct CookieImport {
+    pub cookies: HashMap<String, String>,
+    /// `api_user` found in the source itself (HAR request headers or responses)
+    pub api_user: Option<String>,
+    /// Cookies that matched the domain but whose value is encrypted
+    pub skipped_encrypted: usize,
+    /// Cookies that matched the domain but are already expired
+    pub skipped_expired: usize,
+}
+
+/// Collects matching cookies, keeping the most specific domain on name conflicts
+#[derive(Default)]
+struct CookieCollector {
+    import: CookieImport,
+    ranks: HashMap<String, usize>,
+}
+
+impl CookieCollector {
+    fn add(&mut self, domain: &str, name: &str, value: &str) {
+        let rank = domain.trim_start_matches('.').len();
+        if self
+            .ranks
+            .get(name)
+            .is_some_and(|existing| *existing > rank)
+        {
+            return;
+        }
+        self.ranks.insert(name.to_string(), rank);
+        self.import
+            .cookies
+            .insert(name.to_string(), value.to_string());
+    }
+
+    fn finish(self) -> CookieImport {
+        self.import
+    }
+}
+
+/// Extract the lowercase host from a provider domain or URL
+pub fn host_from_domain(domain: &str) -> String {
+    let trimmed = domain.trim();
+    let with_scheme = if trimmed.contains("://") {
+        trimmed.to_string()
+    } else {
+        format!("https://{}", trimmed)
+    };
+    url::Url::parse(&with_scheme)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+        .unwrap_or_else(|| trimmed.trim_end_matches('/').to_string())
+        .to_lowercase()
+}
+
+/// Whether a cookie set for `cookie_domain` is sent to `host`
+pub(crate) fn cookie_matches_host(cookie_domain: &str, host: &str) -> bool {
+    let cookie_domain = cookie_domain.trim().trim_start_matches('.').to_lowercase();
+    if cookie_domain.is_empty() {
+        return false;
+    }
+    host == cookie_domain || host.ends_with(&format!(".{}", cookie_domain))
+}
+
+fn is_expired(expires_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    expires_at.is_some_and(|expires_at| expires_at <= now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cookie_matches_host() {
+        assert!(cookie_matches_host(".anyrouter.top", "anyrouter.top"));
+        assert!(cookie_matches_host("anyrouter.top", "anyrouter.top"));
+        assert!(cookie_matches_host(".anyrouter.top", "api.anyrouter.top"));
+        assert!(!cookie_matches_host("anyrouter.top", "notanyrouter.top"));
+        assert!(!cookie_matches_host("api.anyrouter.top", "anyrouter.top"));
+        assert!(!cookie_matches_host("", "anyrouter.top"));
+    }
+
+    #[test]
+    fn test_host_from_domain() {
+        assert_eq!(host_from_domain("https://AnyRouter.top/"), "anyrouter.top");
+        assert_eq!(host_from_domain("agentrouter.org"), "agentrouter.org");
+        assert_eq!(host_from_domain("http://localhost:3000"), "localhost");
+    }
+}
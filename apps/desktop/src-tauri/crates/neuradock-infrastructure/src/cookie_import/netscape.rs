@@ -0,0 +1,97 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use super::{cookie_matches_host, host_from_domain, is_expired, CookieCollector, CookieImport};
+
+const HTTP_ONLY_PREFIX: &str = "#HttpOnly_";
+
+/// Parse a Netscape `cookies.txt` file and keep the cookies sent to `domain`
+///
+/// Each line holds seven tab-separated fields:
+/// `domain  include_subdomains  path  secure  expiry  name  value`.
+/// Lines prefixed with `#HttpOnly_` are cookies, other `#` lines are comments.
+pub fn parse_netscape_cookies(content: &str, domain: &str) -> Result<CookieImport> {
+    let host = host_from_domain(domain);
+    let now = Utc::now();
+    let mut collector = CookieCollector::default();
+    let mut valid_lines = 0;
+
+    for line in content.lines() {
+        let line = line.trim_end_matches('\r');
+        let line = match line.strip_prefix(HTTP_ONLY_PREFIX) {
+            Some(rest) => rest,
+            None if line.starts_with('#') || line.trim().is_empty() => continue,
+            None => line,
+        };
+
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() < 7 {
+            continue;
+        }
+        valid_lines += 1;
+
+        let (cookie_domain, expiry, name) = (fields[0], fields[4], fields[5]);
+        // Values may legitimately contain tabs
+        let value = fields[6..].join("\t");
+
+        if !cookie_matches_host(cookie_domain, &host) {
+            continue;
+        }
+        let expires_at = expiry
+            .trim()
+            .parse::<i64>()
+            .ok()
+            .filter(|secs| *secs > 0)
+            .and_then(|secs| DateTime::from_timestamp(secs, 0));
+        if is_expired(expires_at, now) {
+            collector.import.skipped_expired += 1;
+            continue;
+        }
+        collector.add(cookie_domain, name, &value);
+    }
+
+    if valid_lines == 0 && !content.trim().is_empty() {
+        anyhow::bail!("Not a Netscape cookies.txt file: no tab-separated cookie lines found");
+    }
+
+    Ok(collector.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COOKIES_TXT: &str = "# Netscape HTTP Cookie File\n\
+        # This is a generated file! Do not edit.\n\
+        \n\
+        #HttpOnly_.anyrouter.top\tTRUE\t/\tTRUE\t0\tsession\tabc123\n\
+        anyrouter.top\tFALSE\t/\tFALSE\t4102444800\tacw_tc\twaf\n\
+        .anyrouter.top\tTRUE\t/\tFALSE\t1\told\tgone\n\
+        .example.com\tTRUE\t/\tFALSE\t0\tsession\tother\n";
+
+    #[test]
+    fn test_parse_netscape_cookies() {
+        let import = parse_netscape_cookies(COOKIES_TXT, "https://anyrouter.top").unwrap();
+
+        assert_eq!(import.cookies.len(), 2);
+        assert_eq!(
+            import.cookies.get("session").map(String::as_str),
+            Some("abc123")
+        );
+        assert_eq!(
+            import.cookies.get("acw_tc").map(String::as_str),
+            Some("waf")
+        );
+        assert_eq!(import.skipped_expired, 1);
+        assert_eq!(import.api_user, None);
+    }
+
+    #[test]
+    fn test_parse_netscape_rejects_other_formats() {
+        assert!(parse_netscape_cookies("{\"log\": {}}", "anyrouter.top").is_err());
+        assert!(parse_netscape_cookies("", "anyrouter.top")
+            .unwrap()
+            .cookies
+            .is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use super::redaction::redact_secrets;
+
+/// Daily log files written by `init_logger` start with this prefix
+const LOG_FILE_PREFIX: &str = "neuradock.log";
+/// Only the newest files are scanned, older errors rarely matter for a fresh bug report
+const MAX_LOG_FILES: usize = 3;
+
+fn is_error_line(line: &str) -> bool {
+    line.contains(r#""level":"ERROR""#)
+        || line.contains(r#""level":"WARN""#)
+        || line.contains(" ERROR ")
+        || line.contains(" WARN ")
+}
+
+/// Collect the last `limit` warning and error lines from the newest log files, secrets redacted
+///
+/// Lines are returned oldest first. A missing log directory yields no lines.
+pub fn recent_log_errors(log_dir: &Path, limit: usize) -> std::io::Result<Vec<String>> {
+    let entries = match fs::read_dir(log_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(LOG_FILE_PREFIX)
+        })
+        .filter_map(|entry| {
+            let modified = entry.metadata().and_then(|meta| meta.modified()).ok()?;
+            Some((modified, entry.path()))
+        })
+        .collect();
+    files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+    files.truncate(MAX_LOG_FILES);
+
+    let mut lines = VecDeque::with_capacity(limit);
+    // Oldest file first so the most recent lines survive the limit
+    for (_, path) in files.into_iter().rev() {
+        let reader = BufReader::new(fs::File::open(&path)?);
+        for line in reader.lines() {
+            let Ok(line) = line else { continue };
+            if !is_error_line(&line) {
+                continue;
+            }
+            if lines.len() == limit {
+                lines.pop_front();
+            }
+            lines.push_back(line);
+        }
+    }
+
+    Ok(lines
+        .into_iter()
+        .map(|line| redact_secrets(&line))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_log_errors_keeps_newest_redacted() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("neuradock.log.2026-10-18"),
+            concat!(
+                r#"{"level":"INFO","fields":{"message":"started"}}"#,
+                "\n",
+                r#"{"level":"ERROR","fields":{"message":"first","api_key":"sk-0123456789abcdef"}}"#,
+                "\n",
+                r#"{"level":"WARN","fields":{"message":"second"}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+        fs::write(dir.path().join("other.txt"), r#"{"level":"ERROR"}"#).unwrap();
+
+        let lines = recent_log_errors(dir.path(), 10).unwrap();
+        assert_eq!(lines.len(), 2);
+        assert!(!lines[0].contains("0123456789abcdef"));
+
+        let lines = recent_log_errors(dir.path(), 1).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("second"));
+
+        assert!(recent_log_errors(&dir.path().join("missing"), 10)
+            .unwrap()
+            .is_empty());
+    }
+}
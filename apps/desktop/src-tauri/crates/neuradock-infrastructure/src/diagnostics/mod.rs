@@ -0,0 +1,9 @@
+//! Building blocks for the self-diagnostics report attached to bug reports
+
+mod log_errors;
+mod redaction;
+mod zip_archive;
+
+pub use log_errors::recent_log_errors;
+pub use redaction::redact_secrets;
+pub use zip_archive::ZipArchive;
@@ -0,0 +1,186 @@
+use chrono::{Datelike, Local, Timelike};
+
+/// CRC-32 (IEEE) lookup table, as required by the zip format
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Flag bit 11: file names are UTF-8
+const UTF8_NAMES: u16 = 1 << 11;
+const VERSION: u16 = 20;
+
+struct CentralEntry {
+    name: String,
+    crc: u32,
+    size: u32,
+    offset: u32,
+}
+
+/// In-memory zip archive with uncompressed (stored) entries
+///
+/// Diagnostics bundles are small text files, so skipping compression keeps this free of
+/// extra dependencies while any unzip tool can still open the result.
+pub struct ZipArchive {
+    buffer: Vec<u8>,
+    entries: Vec<CentralEntry>,
+    dos_time: u16,
+    dos_date: u16,
+}
+
+impl Default for ZipArchive {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZipArchive {
+    pub fn new() -> Self {
+        let now = Local::now();
+        let year = now.year().clamp(1980, 2107) as u16;
+        Self {
+            buffer: Vec::new(),
+            entries: Vec::new(),
+            dos_time: ((now.hour() as u16) << 11)
+                | ((now.minute() as u16) << 5)
+                | (now.second() as u16 / 2),
+            dos_date: ((year - 1980) << 9) | ((now.month() as u16) << 5) | now.day() as u16,
+        }
+    }
+
+    /// Add a file, `name` may contain `/` for directories
+    pub fn add_file(&mut self, name: &str, data: &[u8]) -> std::io::Result<()> {
+        let too_large = || std::io::Error::other("zip entry exceeds 4 GiB");
+        let size = u32::try_from(data.len()).map_err(|_| too_large())?;
+        let offset = u32::try_from(self.buffer.len()).map_err(|_| too_large())?;
+        let crc = crc32(data);
+
+        let buffer = &mut self.buffer;
+        buffer.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        buffer.extend_from_slice(&VERSION.to_le_bytes());
+        buffer.extend_from_slice(&UTF8_NAMES.to_le_bytes());
+        buffer.extend_from_slice(&0u16.to_le_bytes()); // stored
+        buffer.extend_from_slice(&self.dos_time.to_le_bytes());
+        buffer.extend_from_slice(&self.dos_date.to_le_bytes());
+        buffer.extend_from_slice(&crc.to_le_bytes());
+        buffer.extend_from_slice(&size.to_le_bytes());
+        buffer.extend_from_slice(&size.to_le_bytes());
+        buffer.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        buffer.extend_from_slice(&0u16.to_le_bytes());
+        buffer.extend_from_slice(name.as_bytes());
+        buffer.extend_from_slice(data);
+
+        self.entries.push(CentralEntry {
+            name: name.to_string(),
+            crc,
+            size,
+            offset,
+        });
+        Ok(())
+    }
+
+    /// Write the central directory and return the archive bytes
+    pub fn finish(mut self) -> Vec<u8> {
+        let directory_offset = self.buffer.len() as u32;
+
+        for entry in &self.entries {
+            let buffer = &mut self.buffer;
+            buffer.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+            buffer.extend_from_slice(&VERSION.to_le_bytes()); // made by
+            buffer.extend_from_slice(&VERSION.to_le_bytes()); // needed
+            buffer.extend_from_slice(&UTF8_NAMES.to_le_bytes());
+            buffer.extend_from_slice(&0u16.to_le_bytes());
+            buffer.extend_from_slice(&self.dos_time.to_le_bytes());
+            buffer.extend_from_slice(&self.dos_date.to_le_bytes());
+            buffer.extend_from_slice(&entry.crc.to_le_bytes());
+            buffer.extend_from_slice(&entry.size.to_le_bytes());
+            buffer.extend_from_slice(&entry.size.to_le_bytes());
+            buffer.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+            buffer.extend_from_slice(&0u16.to_le_bytes()); // extra
+            buffer.extend_from_slice(&0u16.to_le_bytes()); // comment
+            buffer.extend_from_slice(&0u16.to_le_bytes()); // disk
+            buffer.extend_from_slice(&0u16.to_le_bytes()); // internal attributes
+            buffer.extend_from_slice(&0u32.to_le_bytes()); // external attributes
+            buffer.extend_from_slice(&entry.offset.to_le_bytes());
+            buffer.extend_from_slice(entry.name.as_bytes());
+        }
+
+        let directory_size = self.buffer.len() as u32 - directory_offset;
+        let count = self.entries.len() as u16;
+        let buffer = &mut self.buffer;
+        buffer.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        buffer.extend_from_slice(&0u16.to_le_bytes());
+        buffer.extend_from_slice(&0u16.to_le_bytes());
+        buffer.extend_from_slice(&count.to_le_bytes());
+        buffer.extend_from_slice(&count.to_le_bytes());
+        buffer.extend_from_slice(&directory_size.to_le_bytes());
+        buffer.extend_from_slice(&directory_offset.to_le_bytes());
+        buffer.extend_from_slice(&0u16.to_le_bytes());
+
+        self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_u16(bytes: &[u8], at: usize) -> u16 {
+        u16::from_le_bytes([bytes[at], bytes[at + 1]])
+    }
+
+    fn read_u32(bytes: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_archive_layout() {
+        let mut archive = ZipArchive::new();
+        archive.add_file("report.json", b"{}").unwrap();
+        archive.add_file("logs/errors.log", b"line\n").unwrap();
+        let bytes = archive.finish();
+
+        // End of central directory record is the last 22 bytes
+        let eocd = bytes.len() - 22;
+        assert_eq!(read_u32(&bytes, eocd), 0x0605_4b50);
+        assert_eq!(read_u16(&bytes, eocd + 10), 2);
+
+        let directory_offset = read_u32(&bytes, eocd + 16) as usize;
+        assert_eq!(read_u32(&bytes, directory_offset), 0x0201_4b50);
+        let name_len = read_u16(&bytes, directory_offset + 28) as usize;
+        assert_eq!(
+            &bytes[directory_offset + 46..directory_offset + 46 + name_len],
+            b"report.json"
+        );
+
+        // The first entry's data follows its local header
+        assert_eq!(read_u32(&bytes, 0), 0x0403_4b50);
+        assert_eq!(&bytes[30 + "report.json".len()..][..2], b"{}");
+    }
+}
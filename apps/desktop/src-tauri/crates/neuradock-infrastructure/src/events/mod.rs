@@ -1,3 +1,5 @@
 pub mod in_memory_event_bus;
+pub mod persistent_event_bus;
 
 pub use in_memory_event_bus::InMemoryEventBus;
+pub use persistent_event_bus::PersistentEventBus;
@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+use log::{error, info, warn};
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
+
+use neuradock_domain::events::event_bus::{DynamicEventHandler, EventBus};
+use neuradock_domain::events::{DomainEvent, EventStore, ReplayableEvent, StoredEvent};
+use neuradock_domain::shared::DomainError;
+
+/// Events read per batch when catching a subscriber up
+const REPLAY_BATCH_SIZE: u32 = 200;
+
+type EventDecoder = fn(&str) -> Result<Box<dyn DomainEvent>, DomainError>;
+
+struct DurableSubscription {
+    /// Checkpoint key, `<subscriber>:<event name>`
+    key: String,
+    event_name: &'static str,
+    event_type_name: &'static str,
+    decode: EventDecoder,
+    handler: Arc<dyn DynamicEventHandler>,
+    /// Held from loading the checkpoint until saving it, so concurrent publishes and
+    /// replays never deliver the same event twice or move the checkpoint backwards
+    dispatch_lock: Mutex<()>,
+}
+
+/// Event bus that appends every published event to an `EventStore` before dispatching
+///
+/// Subscribers are durable: each keeps a checkpoint of the last sequence it handled, so
+/// events published while a handler failed or the app was down are redelivered by
+/// `replay_pending`. Delivery is at least once, handlers must be idempotent.
+pub struct PersistentEventBus {
+    store: Arc<dyn EventStore>,
+    subscriptions: RwLock<Vec<Arc<DurableSubscription>>>,
+}
+
+impl PersistentEventBus {
+    pub fn new(store: Arc<dyn EventStore>) -> Self {
+        Self {
+            store,
+            subscriptions: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Subscribe a handler under a stable subscriber name
+    /// A new subscriber starts at the current end of the stream rather than replaying history
+    pub async fn subscribe<E: ReplayableEvent + 'static>(
+        &self,
+        subscriber: &str,
+        handler: Arc<dyn DynamicEventHandler>,
+    ) -> Result<(), DomainError> {
+        let key = format!("{}:{}", subscriber, E::EVENT_NAME);
+        if self.store.checkpoint(&key).await?.is_none() {
+            let head = self.store.last_sequence().await?;
+            self.store.save_checkpoint(&key, head).await?;
+        }
+
+        self.subscriptions
+            .write()
+            .await
+            .push(Arc::new(DurableSubscription {
+                key: key.clone(),
+                event_name: E::EVENT_NAME,
+                event_type_name: std::any::type_name::<E>(),
+                decode: E::decode,
+                handler,
+                dispatch_lock: Mutex::new(()),
+            }));
+
+        info!("Subscribed durable handler: {}", key);
+        Ok(())
+    }
+
+    /// Deliver events each subscriber missed since its checkpoint
+    /// Returns the number of events delivered
+    pub async fn replay_pending(&self) -> Result<usize, DomainError> {
+        let subscriptions = self.subscriptions.read().await.clone();
+        let mut delivered = 0;
+
+        for subscription in subscriptions {
+            delivered += self.catch_up(&subscription).await?;
+        }
+
+        if delivered > 0 {
+            info!("Replayed {} pending domain events", delivered);
+        }
+        Ok(delivered)
+    }
+
+    async fn catch_up(&self, subscription: &DurableSubscription) -> Result<usize, DomainError> {
+        let _dispatch = subscription.dispatch_lock.lock().await;
+        self.catch_up_locked(subscription).await
+    }
+
+    /// `catch_up` for a caller already holding the subscription's dispatch lock
+    async fn catch_up_locked(
+        &self,
+        subscription: &DurableSubscription,
+    ) -> Result<usize, DomainError> {
+        let mut checkpoint = self.store.checkpoint(&subscription.key).await?.unwrap_or(0);
+        let mut delivered = 0;
+
+        loop {
+            let batch = self.store.read_after(checkpoint, REPLAY_BATCH_SIZE).await?;
+            if batch.is_empty() {
+                break;
+            }
+
+            for stored in &batch {
+                if stored.event_name == subscription.event_name {
+                    if let Err(e) = self.redeliver(subscription, stored).await {
+                        warn!(
+                            "Replay of event {} to {} failed, retrying later: {}",
+                            stored.sequence, subscription.key, e
+                        );
+                        self.store
+                            .save_checkpoint(&subscription.key, checkpoint)
+                            .await?;
+                        return Ok(delivered);
+                    }
+                    delivered += 1;
+                }
+                checkpoint = stored.sequence;
+            }
+
+            self.store
+                .save_checkpoint(&subscription.key, checkpoint)
+                .await?;
+            if batch.len() < REPLAY_BATCH_SIZE as usize {
+                break;
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    async fn redeliver(
+        &self,
+        subscription: &DurableSubscription,
+        stored: &StoredEvent,
+    ) -> Result<(), DomainError> {
+        let event = (subscription.decode)(&stored.payload)?;
+        subscription.handler.handle_dynamic(event.as_any()).await
+    }
+}
+
+#[async_trait]
+impl EventBus for PersistentEventBus {
+    async fn publish(&self, event: Box<dyn DomainEvent>) -> Result<(), DomainError> {
+        let event_type_name = event.event_type_name();
+
+        // The operation behind the event already happened, so a store failure is logged
+        // and the event is still dispatched, only without advancing checkpoints
+        let sequence = match self.store.append(event.as_ref()).await {
+            Ok(sequence) => Some(sequence),
+            Err(e) => {
+                error!("Failed to persist event {}: {}", event.event_name(), e);
+                None
+            }
+        };
+
+        let subscriptions: Vec<_> = self
+            .subscriptions
+            .read()
+            .await
+            .iter()
+            .filter(|subscription| subscription.event_type_name == event_type_name)
+            .cloned()
+            .collect();
+
+        for subscription in subscriptions {
+            let _dispatch = subscription.dispatch_lock.lock().await;
+            let Some(sequence) = sequence else {
+                if let Err(e) = subscription.handler.handle_dynamic(event.as_any()).await {
+                    error!(
+                        "Handler {} failed to process event {}: {}",
+                        subscription.key, event_type_name, e
+                    );
+                }
+                continue;
+            };
+
+            // Only an event right after the checkpoint is delivered live. Otherwise an earlier
+            // event failed or a concurrent publish is still in flight, and moving the
+            // checkpoint here would skip it, so the subscriber catches up in order instead.
+            let checkpoint = match self.store.checkpoint(&subscription.key).await {
+                Ok(checkpoint) => checkpoint.unwrap_or(0),
+                Err(e) => {
+                    warn!("Failed to load checkpoint for {}: {}", subscription.key, e);
+                    continue;
+                }
+            };
+            if sequence <= checkpoint {
+                continue;
+            }
+            if sequence != checkpoint + 1 {
+                if let Err(e) = self.catch_up_locked(&subscription).await {
+                    warn!("Failed to catch up {}: {}", subscription.key, e);
+                }
+                continue;
+            }
+
+            match subscription.handler.handle_dynamic(event.as_any()).await {
+                Ok(()) => {
+                    if let Err(e) = self
+                        .store
+                        .save_checkpoint(&subscription.key, sequence)
+                        .await
+                    {
+                        warn!("Failed to save checkpoint for {}: {}", subscription.key, e);
+                    }
+                }
+                Err(e) => {
+                    error!(
+                        "Handler {} failed to process event {}: {}",
+                        subscription.key, event_type_name, e
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
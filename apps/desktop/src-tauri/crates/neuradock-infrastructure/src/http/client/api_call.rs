@@ -35,7 +35,11 @@ impl super::HttpClient {
         }
 
         // Build request with cookies
-        let mut request = self.client.get(url).headers(headers);
+        let mut request = self
+            .client
+            .get(url)
+            .timeout(Self::request_timeout(url))
+            .headers(headers);
 
         // Add cookies as header string
         let cookie_string = cookies
@@ -0,0 +1,99 @@
+use anyhow::{Context, Result};
+use reqwest::header;
+use std::collections::HashMap;
+
+use super::types::extract_domain;
+
+/// Extract the user id from a `/api/user/self` response body
+///
+/// Accepts both numeric and string ids (`{"data": {"id": 123}}`).
+pub fn api_user_from_user_info(json: &serde_json::Value) -> Option<String> {
+    let id = &json["data"]["id"];
+    id.as_i64()
+        .filter(|id| *id > 0)
+        .map(|id| id.to_string())
+        .or_else(|| {
+            id.as_str()
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string)
+        })
+}
+
+impl super::HttpClient {
+    /// Detect the `api_user` of a session by calling the user info endpoint
+    ///
+    /// The request is sent without the api_user header, which providers
+    /// accept for plain session cookies and answer with the user's id.
+    pub async fn detect_api_user(
+        &self,
+        url: &str,
+        cookies: &HashMap<String, String>,
+    ) -> Result<String> {
+        let retry_config = self.retry_config_for(url);
+        let url = url.to_string();
+        let cookie_string = cookies
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        self.execute_with_retry("Detect api_user", retry_config, move || {
+            let request = self
+                .client
+                .get(&url)
+                .timeout(Self::request_timeout(&url))
+                .header(header::ACCEPT, "application/json, text/plain, */*")
+                .header(header::COOKIE, cookie_string.clone());
+            let url = url.clone();
+
+            async move {
+                let response = request
+                    .header(header::REFERER, extract_domain(&url)?)
+                    .send()
+                    .await
+                    .context("Failed to send user info request")?;
+                let status = response.status();
+                let body = response
+                    .text()
+                    .await
+                    .context("Failed to read user info response")?;
+
+                if body.contains("acw_sc__v2") || body.trim_start().starts_with('<') {
+                    anyhow::bail!("WAF_CHALLENGE: user info endpoint returned an HTML challenge");
+                }
+
+                let json: serde_json::Value = serde_json::from_str(&body).with_context(|| {
+                    format!(
+                        "Invalid user info response ({}): {}",
+                        status,
+                        body.chars().take(200).collect::<String>()
+                    )
+                })?;
+
+                api_user_from_user_info(&json).ok_or_else(|| {
+                    let message = json["message"].as_str().unwrap_or("missing user id");
+                    anyhow::anyhow!("Could not detect api_user ({}): {}", status, message)
+                })
+            }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_user_from_user_info() {
+        let numeric = serde_json::json!({ "success": true, "data": { "id": 1234 } });
+        assert_eq!(api_user_from_user_info(&numeric).as_deref(), Some("1234"));
+
+        let string = serde_json::json!({ "data": { "id": " 99 " } });
+        assert_eq!(api_user_from_user_info(&string).as_deref(), Some("99"));
+
+        let failure = serde_json::json!({ "success": false, "message": "unauthorized" });
+        assert_eq!(api_user_from_user_info(&failure), None);
+    }
+}
@@ -13,21 +13,23 @@ impl super::HttpClient {
         api_user_key: &str,
         api_user_value: &str,
     ) -> Result<(CheckInResult, SetCookieResult)> {
-        const MAX_RETRIES: u32 = 3;
-        const INITIAL_DELAY_MS: u64 = 1000;
+        let retry_config = self.retry_config_for(url);
+        let max_attempts = retry_config.max_retries + 1;
+        let mut delay = retry_config.initial_backoff_ms;
 
         let mut last_error = None;
 
-        for attempt in 0..MAX_RETRIES {
+        for attempt in 0..max_attempts {
             if attempt > 0 {
-                let delay = INITIAL_DELAY_MS * 2u64.pow(attempt - 1);
                 log::info!(
                     "Retrying check-in (attempt {}/{}), waiting {}ms...",
                     attempt + 1,
-                    MAX_RETRIES,
+                    max_attempts,
                     delay
                 );
                 tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                delay = ((delay as f64 * retry_config.backoff_multiplier) as u64)
+                    .min(retry_config.max_backoff_ms);
             }
 
             match self
@@ -43,7 +45,7 @@ impl super::HttpClient {
         }
 
         Err(last_error
-            .unwrap_or_else(|| anyhow::anyhow!("Check-in failed after {} attempts", MAX_RETRIES)))
+            .unwrap_or_else(|| anyhow::anyhow!("Check-in failed after {} attempts", max_attempts)))
     }
 
     /// Execute check-in once (internal method)
@@ -86,7 +88,11 @@ impl super::HttpClient {
         }
 
         // Build request with cookies
-        let mut request = self.client.post(url).headers(headers);
+        let mut request = self
+            .client
+            .post(url)
+            .timeout(Self::request_timeout(url))
+            .headers(headers);
 
         // Add cookies as header string
         let cookie_string = cookies
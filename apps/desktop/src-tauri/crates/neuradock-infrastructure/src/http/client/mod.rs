@@ -1,9 +1,11 @@
 mod api_call;
+mod api_user;
 mod check_in;
 mod types;
 mod user_info;
 mod visit;
 
+pub use api_user::api_user_from_user_info;
 pub use types::{CheckInResult, RetryConfig, SetCookieResult, UserInfo};
 
 use anyhow::{Context, Result};
@@ -12,40 +14,50 @@ use reqwest::{Client, Proxy, StatusCode};
 use std::time::Duration;
 use tokio::time::sleep;
 
-use types::USER_AGENT;
+use crate::config::{network_policy, TimeoutConfig};
+
+pub(crate) use types::USER_AGENT;
 
 pub struct HttpClient {
     pub(super) client: Client,
-    pub(super) retry_config: RetryConfig,
+    /// Fixed retry policy, `None` follows the configured network policy per host
+    pub(super) retry_config: Option<RetryConfig>,
 }
 
 impl HttpClient {
     pub fn new() -> Result<Self> {
-        Self::with_retry_config_and_proxy(RetryConfig::default(), None)
+        Self::build(None, None)
     }
 
     pub fn with_retry_config(retry_config: RetryConfig) -> Result<Self> {
-        Self::with_retry_config_and_proxy(retry_config, None)
+        Self::build(Some(retry_config), None)
     }
 
     pub fn with_proxy(proxy_url: Option<String>) -> Result<Self> {
-        Self::with_retry_config_and_proxy(RetryConfig::default(), proxy_url)
+        Self::build(None, proxy_url)
     }
 
     pub fn with_retry_config_and_proxy(
         retry_config: RetryConfig,
         proxy_url: Option<String>,
     ) -> Result<Self> {
+        Self::build(Some(retry_config), proxy_url)
+    }
+
+    fn build(retry_config: Option<RetryConfig>, proxy_url: Option<String>) -> Result<Self> {
         let mut client_builder = Client::builder()
             .user_agent(USER_AGENT)
             .cookie_store(true)
-            .timeout(Duration::from_secs(30))
+            .timeout(TimeoutConfig::global().http_request)
             // Always ignore environment/system proxy settings; use only app config.
             .no_proxy();
 
         // Apply proxy configuration if provided.
         if let Some(url) = proxy_url {
-            debug!("🌐 Configuring HTTP client with proxy: {}", url);
+            debug!(
+                "🌐 Configuring HTTP client with proxy: {}",
+                crate::logging::log_utils::mask_url_credentials(&url)
+            );
             let proxy = Proxy::all(&url).context("Failed to create proxy")?;
             client_builder = client_builder.proxy(proxy);
         }
@@ -60,6 +72,17 @@ impl HttpClient {
         })
     }
 
+    /// Retry policy for requests to `url`
+    pub(super) fn retry_config_for(&self, url: &str) -> RetryConfig {
+        self.retry_config
+            .unwrap_or_else(|| network_policy::for_url(url).retry)
+    }
+
+    /// Request timeout for `url`, read per request so setting changes apply immediately
+    pub(super) fn request_timeout(url: &str) -> Duration {
+        TimeoutConfig::for_url(url).http_request
+    }
+
     /// Execute a request with retry logic
     ///
     /// Retries on:
@@ -73,6 +96,7 @@ impl HttpClient {
     pub(super) async fn execute_with_retry<F, Fut, T>(
         &self,
         operation_name: &str,
+        retry_config: RetryConfig,
         mut request_fn: F,
     ) -> Result<T>
     where
@@ -80,7 +104,7 @@ impl HttpClient {
         Fut: std::future::Future<Output = Result<T>>,
     {
         let mut attempt = 0;
-        let mut backoff_ms = self.retry_config.initial_backoff_ms;
+        let mut backoff_ms = retry_config.initial_backoff_ms;
 
         loop {
             attempt += 1;
@@ -94,25 +118,24 @@ impl HttpClient {
                 }
                 Err(e) => {
                     let should_retry =
-                        attempt <= self.retry_config.max_retries && self.is_retryable_error(&e);
+                        attempt <= retry_config.max_retries && self.is_retryable_error(&e);
 
                     if should_retry {
                         warn!(
                             "⚠️  {} failed (attempt {}/{}): {}. Retrying in {}ms...",
-                            operation_name, attempt, self.retry_config.max_retries, e, backoff_ms
+                            operation_name, attempt, retry_config.max_retries, e, backoff_ms
                         );
 
                         sleep(Duration::from_millis(backoff_ms)).await;
 
                         // Exponential backoff with cap
-                        backoff_ms = ((backoff_ms as f64 * self.retry_config.backoff_multiplier)
-                            as u64)
-                            .min(self.retry_config.max_backoff_ms);
+                        backoff_ms = ((backoff_ms as f64 * retry_config.backoff_multiplier) as u64)
+                            .min(retry_config.max_backoff_ms);
                     } else {
-                        if attempt > self.retry_config.max_retries {
+                        if attempt > retry_config.max_retries {
                             warn!(
                                 "❌ {} failed after {} attempts",
-                                operation_name, self.retry_config.max_retries
+                                operation_name, retry_config.max_retries
                             );
                         }
                         return Err(e);
@@ -149,7 +172,7 @@ impl Default for HttpClient {
                 warn!("⚠️  Failed to create default HTTP client: {}", e);
                 Self {
                     client: Client::new(),
-                    retry_config: RetryConfig::default(),
+                    retry_config: None,
                 }
             }
         }
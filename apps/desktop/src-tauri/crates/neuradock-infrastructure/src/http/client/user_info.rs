@@ -13,12 +13,13 @@ impl super::HttpClient {
         api_user_key: &str,
         api_user_value: &str,
     ) -> Result<(UserInfo, SetCookieResult)> {
+        let retry_config = self.retry_config_for(url);
         let url = url.to_string();
         let cookies = cookies.clone();
         let api_user_key = api_user_key.to_string();
         let api_user_value = api_user_value.to_string();
 
-        self.execute_with_retry("Get user info", move || {
+        self.execute_with_retry("Get user info", retry_config, move || {
             let url = url.clone();
             let cookies = cookies.clone();
             let api_user_key = api_user_key.clone();
@@ -65,7 +66,10 @@ impl super::HttpClient {
         }
 
         // Build request with cookies
-        let mut request = client.get(url).headers(headers);
+        let mut request = client
+            .get(url)
+            .timeout(Self::request_timeout(url))
+            .headers(headers);
 
         // Add cookies as header string
         let cookie_string = cookies
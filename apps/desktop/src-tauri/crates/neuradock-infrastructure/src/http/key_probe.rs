@@ -0,0 +1,370 @@
+use anyhow::{Context, Result};
+use chrono::{Duration as ChronoDuration, Utc};
+use reqwest::{Client, Proxy, RequestBuilder};
+use serde_json::Value;
+use std::time::{Duration, Instant};
+
+use super::client::USER_AGENT;
+
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// How the key authenticates against the models endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAuthStyle {
+    /// `Authorization: Bearer`, used by OpenAI and OpenAI-compatible relays
+    Bearer,
+    /// `x-api-key` and `anthropic-version`
+    Anthropic,
+}
+
+/// Response of a models endpoint
+#[derive(Debug, Clone)]
+pub struct KeyProbeResult {
+    /// `None` when no HTTP response arrived
+    pub status_code: Option<u16>,
+    pub latency_ms: u64,
+    pub models: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// Credit of an OpenAI-compatible relay key in USD
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelayCredit {
+    pub total_usd: f64,
+    pub used_usd: f64,
+}
+
+/// `{base_url}{path}` where `path` starts below `/v1`
+///
+/// Base URLs are stored both with and without the `/v1` suffix.
+pub fn api_url(base_url: &str, path: &str) -> String {
+    let base = base_url.trim().trim_end_matches('/');
+    if base.ends_with("/v1") {
+        format!("{}{}", base, path)
+    } else {
+        format!("{}/v1{}", base, path)
+    }
+}
+
+fn build_client(proxy_url: Option<&str>, timeout: Duration) -> Result<Client> {
+    let mut builder = Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(timeout)
+        .no_proxy();
+    if let Some(url) = proxy_url {
+        builder = builder.proxy(Proxy::all(url).context("Failed to create proxy")?);
+    }
+    builder.build().context("Failed to create HTTP client")
+}
+
+fn authorize(
+    request: RequestBuilder,
+    auth: KeyAuthStyle,
+    api_key: &str,
+    organization_id: Option<&str>,
+) -> RequestBuilder {
+    let request = match auth {
+        KeyAuthStyle::Bearer => request.bearer_auth(api_key),
+        KeyAuthStyle::Anthropic => request
+            .header("x-api-key", api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION),
+    };
+    match organization_id.filter(|org| !org.is_empty()) {
+        Some(org) if auth == KeyAuthStyle::Bearer => request.header("OpenAI-Organization", org),
+        _ => request,
+    }
+}
+
+/// List the models visible to `api_key` at `{base_url}/v1/models`
+///
+/// HTTP errors are reported in the result, only client setup fails.
+pub async fn probe_api_key(
+    base_url: &str,
+    api_key: &str,
+    auth: KeyAuthStyle,
+    organization_id: Option<&str>,
+    proxy_url: Option<&str>,
+    timeout: Duration,
+) -> Result<KeyProbeResult> {
+    let client = build_client(proxy_url, timeout)?;
+    let request = authorize(
+        client.get(api_url(base_url, "/models")),
+        auth,
+        api_key,
+        organization_id,
+    );
+
+    let started = Instant::now();
+    let response = request.send().await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            return Ok(KeyProbeResult {
+                status_code: None,
+                latency_ms,
+                models: Vec::new(),
+                error: Some(e.to_string()),
+            })
+        }
+    };
+
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        return Ok(KeyProbeResult {
+            status_code: Some(status.as_u16()),
+            latency_ms,
+            models: Vec::new(),
+            error: Some(error_message(&body)),
+        });
+    }
+
+    let (models, error) = match serde_json::from_str::<Value>(&body) {
+        Ok(value) => (model_ids(&value), None),
+        Err(e) => (
+            Vec::new(),
+            Some(format!("Unexpected models response: {}", e)),
+        ),
+    };
+    Ok(KeyProbeResult {
+        status_code: Some(status.as_u16()),
+        latency_ms,
+        models,
+        error,
+    })
+}
+
+/// Credit of a relay key via the OpenAI-style `/dashboard/billing` endpoints
+///
+/// `Ok(None)` when the endpoint does not offer them.
+pub async fn fetch_relay_credit(
+    base_url: &str,
+    api_key: &str,
+    proxy_url: Option<&str>,
+    timeout: Duration,
+) -> Result<Option<RelayCredit>> {
+    let client = build_client(proxy_url, timeout)?;
+
+    let Some(subscription) = get_billing_json(
+        &client,
+        &api_url(base_url, "/dashboard/billing/subscription"),
+        api_key,
+    )
+    .await?
+    else {
+        return Ok(None);
+    };
+    let Some(total_usd) = subscription
+        .get("hard_limit_usd")
+        .or_else(|| subscription.get("system_hard_limit_usd"))
+        .and_then(Value::as_f64)
+    else {
+        return Ok(None);
+    };
+
+    // Relays ignore the range and return the lifetime usage, OpenAI wants one
+    let today = Utc::now().date_naive();
+    let usage_url = format!(
+        "{}?start_date={}&end_date={}",
+        api_url(base_url, "/dashboard/billing/usage"),
+        (today - ChronoDuration::days(99)).format("%Y-%m-%d"),
+        (today + ChronoDuration::days(1)).format("%Y-%m-%d")
+    );
+    let used_usd = get_billing_json(&client, &usage_url, api_key)
+        .await?
+        .and_then(|usage| usage.get("total_usage").and_then(Value::as_f64))
+        // total_usage is in cents
+        .map(|cents| cents / 100.0)
+        .unwrap_or(0.0);
+
+    Ok(Some(RelayCredit {
+        total_usd,
+        used_usd,
+    }))
+}
+
+async fn get_billing_json(client: &Client, url: &str, api_key: &str) -> Result<Option<Value>> {
+    let response = client
+        .get(url)
+        .bearer_auth(api_key)
+        .send()
+        .await
+        .with_context(|| format!("Billing request to {} failed", url))?;
+    if !response.status().is_success() {
+        return Ok(None);
+    }
+    // Relays without the endpoint often answer with their HTML frontend
+    Ok(response.json::<Value>().await.ok())
+}
+
+/// `data[].id` of an OpenAI or Anthropic models list, sorted
+fn model_ids(value: &Value) -> Vec<String> {
+    let mut ids: Vec<String> = value
+        .get("data")
+        .and_then(Value::as_array)
+        .map(|models| {
+            models
+                .iter()
+                .filter_map(|model| model.get("id").and_then(Value::as_str))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+/// `error.message` of an OpenAI/Anthropic error body, else the start of the body
+fn error_message(body: &str) -> String {
+    serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|value| {
+            value
+                .pointer("/error/message")
+                .or_else(|| value.get("message"))
+                .or_else(|| value.get("error"))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| body.chars().take(200).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stand_in;
+    use serde_json::json;
+
+    /// Local endpoint answering by path prefix, returns its base URL
+    async fn stand_in(routes: Vec<(&'static str, u16, Value)>) -> String {
+        let (base_url, _requests) = stand_in::serve(move |request| {
+            let head = request.head.to_lowercase();
+            let authorized =
+                head.contains("bearer sk-good") || head.contains("x-api-key: sk-ant-good");
+            let (status, body) = routes
+                .iter()
+                .find(|(prefix, _, _)| request.path.starts_with(prefix))
+                .map(|(_, status, body)| (*status, body.clone()))
+                .unwrap_or((404, json!({ "error": { "message": "not found" } })));
+            if authorized || status != 200 {
+                (status, body)
+            } else {
+                (
+                    401,
+                    json!({ "error": { "message": "Incorrect API key provided" } }),
+                )
+            }
+        })
+        .await;
+        base_url
+    }
+
+    fn timeout() -> Duration {
+        Duration::from_secs(5)
+    }
+
+    #[test]
+    fn api_url_accepts_base_with_and_without_v1() {
+        assert_eq!(
+            api_url("https://api.openai.com/v1/", "/models"),
+            "https://api.openai.com/v1/models"
+        );
+        assert_eq!(
+            api_url("https://relay.example.com", "/models"),
+            "https://relay.example.com/v1/models"
+        );
+    }
+
+    #[tokio::test]
+    async fn probe_lists_models_and_rejects_bad_keys() {
+        let base_url = stand_in(vec![(
+            "/v1/models",
+            200,
+            json!({ "data": [{ "id": "gpt-4o" }, { "id": "gpt-4o-mini" }] }),
+        )])
+        .await;
+
+        let ok = probe_api_key(
+            &base_url,
+            "sk-good",
+            KeyAuthStyle::Bearer,
+            None,
+            None,
+            timeout(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(ok.status_code, Some(200));
+        assert_eq!(ok.models, vec!["gpt-4o", "gpt-4o-mini"]);
+
+        let bad = probe_api_key(
+            &base_url,
+            "sk-bad",
+            KeyAuthStyle::Bearer,
+            None,
+            None,
+            timeout(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(bad.status_code, Some(401));
+        assert_eq!(bad.error.as_deref(), Some("Incorrect API key provided"));
+    }
+
+    #[tokio::test]
+    async fn probe_uses_anthropic_headers() {
+        let base_url = stand_in(vec![(
+            "/v1/models",
+            200,
+            json!({ "data": [{ "id": "claude-sonnet-4-5", "type": "model" }] }),
+        )])
+        .await;
+
+        let result = probe_api_key(
+            &format!("{}/v1", base_url),
+            "sk-ant-good",
+            KeyAuthStyle::Anthropic,
+            None,
+            None,
+            timeout(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.models, vec!["claude-sonnet-4-5"]);
+    }
+
+    #[tokio::test]
+    async fn relay_credit_from_billing_endpoints() {
+        let base_url = stand_in(vec![
+            (
+                "/v1/dashboard/billing/subscription",
+                200,
+                json!({ "hard_limit_usd": 25.0 }),
+            ),
+            (
+                "/v1/dashboard/billing/usage",
+                200,
+                json!({ "total_usage": 1050.0 }),
+            ),
+        ])
+        .await;
+
+        let credit = fetch_relay_credit(&base_url, "sk-good", None, timeout())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(credit.total_usd, 25.0);
+        assert_eq!(credit.used_usd, 10.5);
+
+        let without_billing = stand_in(vec![]).await;
+        assert!(
+            fetch_relay_credit(&without_billing, "sk-good", None, timeout())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -1,8 +1,15 @@
 mod client;
+pub mod key_probe;
 pub mod openai;
+mod proxy_probe;
+#[cfg(test)]
+mod stand_in;
 pub mod token;
 pub mod waf_bypass;
 
-pub use client::{CheckInResult, HttpClient, SetCookieResult, UserInfo};
+pub use client::{
+    api_user_from_user_info, CheckInResult, HttpClient, RetryConfig, SetCookieResult, UserInfo,
+};
+pub use proxy_probe::{probe_proxy, ProxyProbeResult};
 pub use token::{TokenClient, TokenData, TokenResponse};
 pub use waf_bypass::WafBypassService;
@@ -2,3 +2,4 @@ pub mod tempmail;
 pub mod registrar;
 pub mod quota;
 pub mod payment;
+pub mod oauth;
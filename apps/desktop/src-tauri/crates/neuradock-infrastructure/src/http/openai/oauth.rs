@@ -0,0 +1,254 @@
+use base64::Engine;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// Client ID of the Codex CLI, refresh tokens are bound to it
+const OAUTH_CLIENT_ID: &str = "app_EMoamEEZ73f0CkXaXp7hrann";
+const REFRESH_SCOPE: &str = "openid profile email";
+
+/// Tokens returned by a refresh grant
+///
+/// `refresh_token` and `id_token` are `None` when the endpoint did not rotate them.
+#[derive(Debug, Clone)]
+pub struct RefreshedCodexTokens {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub id_token: Option<String>,
+    pub account_id: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CodexTokenRefreshError {
+    /// The refresh token is expired, revoked or already used, retrying will not help
+    #[error("Refresh token rejected (HTTP {status}): {message}")]
+    Rejected { status: u16, message: String },
+
+    /// Network errors, timeouts, rate limits and server errors
+    #[error("Token refresh failed: {0}")]
+    Transient(String),
+}
+
+impl CodexTokenRefreshError {
+    pub fn is_definitive(&self) -> bool {
+        matches!(self, CodexTokenRefreshError::Rejected { .. })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RefreshResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    id_token: Option<String>,
+    #[serde(default)]
+    expires_in: Option<i64>,
+}
+
+/// Exchange a Codex refresh token for new tokens at `token_url`
+pub async fn refresh_codex_tokens(
+    token_url: &str,
+    refresh_token: &str,
+    proxy_url: Option<&str>,
+) -> Result<RefreshedCodexTokens, CodexTokenRefreshError> {
+    let transient = |e: reqwest::Error| CodexTokenRefreshError::Transient(e.to_string());
+
+    let mut builder = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent("codex-cli");
+    if let Some(proxy) = proxy_url {
+        builder = builder.proxy(reqwest::Proxy::all(proxy).map_err(transient)?);
+    }
+    let client = builder.build().map_err(transient)?;
+
+    let resp = client
+        .post(token_url)
+        .header("Accept", "application/json")
+        .json(&json!({
+            "client_id": OAUTH_CLIENT_ID,
+            "grant_type": "refresh_token",
+            "refresh_token": refresh_token,
+            "scope": REFRESH_SCOPE,
+        }))
+        .send()
+        .await
+        .map_err(transient)?;
+
+    let status = resp.status();
+    let body = resp.text().await.map_err(transient)?;
+
+    if !status.is_success() {
+        let message = error_message(&body);
+        // 408 and 429 are worth retrying, other client errors mean the grant is dead
+        let definitive = status.is_client_error()
+            && status != reqwest::StatusCode::REQUEST_TIMEOUT
+            && status != reqwest::StatusCode::TOO_MANY_REQUESTS;
+        return Err(if definitive {
+            CodexTokenRefreshError::Rejected {
+                status: status.as_u16(),
+                message,
+            }
+        } else {
+            CodexTokenRefreshError::Transient(format!("HTTP {}: {}", status.as_u16(), message))
+        });
+    }
+
+    let payload: RefreshResponse = serde_json::from_str(&body).map_err(|e| {
+        CodexTokenRefreshError::Transient(format!("Decode error for token response: {}", e))
+    })?;
+
+    let expires_at = payload
+        .expires_in
+        .map(|seconds| Utc::now() + Duration::seconds(seconds))
+        .or_else(|| jwt_expiry(&parse_jwt_claims(&payload.access_token)));
+    let account_id = payload
+        .id_token
+        .as_deref()
+        .and_then(|id_token| extract_account_id(&parse_jwt_claims(id_token)));
+
+    Ok(RefreshedCodexTokens {
+        access_token: payload.access_token,
+        refresh_token: payload.refresh_token.filter(|token| !token.is_empty()),
+        id_token: payload.id_token.filter(|token| !token.is_empty()),
+        account_id,
+        expires_at,
+    })
+}
+
+/// `error_description`, `error.message` or `error` of an OAuth error body
+fn error_message(body: &str) -> String {
+    let Ok(value) = serde_json::from_str::<Value>(body) else {
+        return body.chars().take(400).collect();
+    };
+    value
+        .get("error_description")
+        .and_then(Value::as_str)
+        .or_else(|| value.pointer("/error/message").and_then(Value::as_str))
+        .or_else(|| value.pointer("/error/code").and_then(Value::as_str))
+        .or_else(|| value.get("error").and_then(Value::as_str))
+        .map(str::to_string)
+        .unwrap_or_else(|| body.chars().take(400).collect())
+}
+
+/// Expiry in the `exp` claim of an access token, e.g. one read from auth.json
+pub fn access_token_expiry(access_token: &str) -> Option<DateTime<Utc>> {
+    jwt_expiry(&parse_jwt_claims(access_token))
+}
+
+fn parse_jwt_claims(token: &str) -> Value {
+    let segment = token.split('.').nth(1).unwrap_or("");
+    base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(segment)
+        .ok()
+        .and_then(|b| serde_json::from_slice(&b).ok())
+        .unwrap_or(Value::Null)
+}
+
+fn jwt_expiry(claims: &Value) -> Option<DateTime<Utc>> {
+    claims
+        .get("exp")
+        .and_then(Value::as_i64)
+        .and_then(|exp| Utc.timestamp_opt(exp, 0).single())
+}
+
+fn extract_account_id(claims: &Value) -> Option<String> {
+    claims
+        .get("https://api.openai.com/auth")
+        .and_then(|v| v.get("chatgpt_account_id"))
+        .and_then(Value::as_str)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stand_in::{self, Captured};
+    use tokio::sync::mpsc;
+
+    fn jwt(claims: Value) -> String {
+        let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        format!(
+            "{}.{}.sig",
+            engine.encode(r#"{"alg":"none"}"#),
+            engine.encode(claims.to_string())
+        )
+    }
+
+    /// Local token endpoint, returns its URL and the requests it received
+    async fn stand_in(status: u16, body: Value) -> (String, mpsc::UnboundedReceiver<Captured>) {
+        let (base_url, requests) = stand_in::serve(move |_| (status, body.clone())).await;
+        (format!("{}/oauth/token", base_url), requests)
+    }
+
+    #[tokio::test]
+    async fn refresh_returns_rotated_tokens() {
+        let id_token = jwt(json!({
+            "email": "user@example.com",
+            "https://api.openai.com/auth": { "chatgpt_account_id": "acct-1" }
+        }));
+        let (url, mut requests) = stand_in(
+            200,
+            json!({
+                "access_token": "new-access",
+                "refresh_token": "new-refresh",
+                "id_token": id_token,
+                "expires_in": 3600
+            }),
+        )
+        .await;
+
+        let tokens = refresh_codex_tokens(&url, "old-refresh", None)
+            .await
+            .unwrap();
+        assert_eq!(tokens.access_token, "new-access");
+        assert_eq!(tokens.refresh_token.as_deref(), Some("new-refresh"));
+        assert_eq!(tokens.account_id.as_deref(), Some("acct-1"));
+        let expires_in = tokens.expires_at.unwrap() - Utc::now();
+        assert!(expires_in > Duration::minutes(59) && expires_in <= Duration::hours(1));
+
+        let request = requests.recv().await.unwrap().json();
+        assert_eq!(request["grant_type"], "refresh_token");
+        assert_eq!(request["refresh_token"], "old-refresh");
+        assert_eq!(request["client_id"], OAUTH_CLIENT_ID);
+    }
+
+    #[tokio::test]
+    async fn refresh_without_rotation_uses_access_token_expiry() {
+        let access_token = jwt(json!({ "exp": 1_900_000_000 }));
+        let (url, _requests) = stand_in(200, json!({ "access_token": access_token })).await;
+
+        let tokens = refresh_codex_tokens(&url, "refresh", None).await.unwrap();
+        assert!(tokens.refresh_token.is_none());
+        assert!(tokens.id_token.is_none());
+        assert_eq!(tokens.expires_at.unwrap().timestamp(), 1_900_000_000);
+    }
+
+    #[tokio::test]
+    async fn invalid_grant_is_definitive() {
+        let (url, _requests) = stand_in(
+            400,
+            json!({ "error": "invalid_grant", "error_description": "Refresh token expired" }),
+        )
+        .await;
+
+        let err = refresh_codex_tokens(&url, "refresh", None)
+            .await
+            .unwrap_err();
+        assert!(err.is_definitive());
+        assert!(err.to_string().contains("Refresh token expired"));
+    }
+
+    #[tokio::test]
+    async fn server_errors_and_rate_limits_are_transient() {
+        for status in [500, 429] {
+            let (url, _requests) = stand_in(status, json!({ "error": "busy" })).await;
+            let err = refresh_codex_tokens(&url, "refresh", None)
+                .await
+                .unwrap_err();
+            assert!(!err.is_definitive(), "HTTP {} should be retried", status);
+        }
+    }
+}
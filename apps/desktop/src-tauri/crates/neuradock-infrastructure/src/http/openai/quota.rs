@@ -57,11 +57,18 @@ struct WindowPayload {
     reset_at: Option<i64>,
 }
 
-pub async fn fetch_codex_usage(access_token: &str, account_id: Option<&str>) -> anyhow::Result<CodexUsageQuota> {
-    let client = reqwest::Client::builder()
+pub async fn fetch_codex_usage(
+    access_token: &str,
+    account_id: Option<&str>,
+    proxy_url: Option<&str>,
+) -> anyhow::Result<CodexUsageQuota> {
+    let mut builder = reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(20))
-        .user_agent("codex-cli")
-        .build()?;
+        .user_agent("codex-cli");
+    if let Some(proxy) = proxy_url {
+        builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+    }
+    let client = builder.build()?;
 
     let mut failures = Vec::new();
 
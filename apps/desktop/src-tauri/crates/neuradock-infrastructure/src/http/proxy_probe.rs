@@ -0,0 +1,53 @@
+use anyhow::{Context, Result};
+use reqwest::{Client, Proxy};
+use std::time::{Duration, Instant};
+
+use super::client::USER_AGENT;
+
+/// Outcome of a request sent through a proxy
+#[derive(Debug, Clone)]
+pub struct ProxyProbeResult {
+    pub success: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Send a GET to `target_url` through `proxy_url` (or directly when `None`)
+/// and report reachability and latency.
+///
+/// Any HTTP response counts as success: it proves the proxy forwarded the
+/// request.
+pub async fn probe_proxy(
+    proxy_url: Option<&str>,
+    target_url: &str,
+    timeout: Duration,
+) -> Result<ProxyProbeResult> {
+    let mut builder = Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(timeout)
+        .no_proxy();
+    if let Some(url) = proxy_url {
+        builder = builder.proxy(Proxy::all(url).context("Failed to create proxy")?);
+    }
+    let client = builder.build().context("Failed to create HTTP client")?;
+
+    let started = Instant::now();
+    let result = client.get(target_url).send().await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    Ok(match result {
+        Ok(response) => ProxyProbeResult {
+            success: true,
+            status_code: Some(response.status().as_u16()),
+            latency_ms,
+            error: None,
+        },
+        Err(e) => ProxyProbeResult {
+            success: false,
+            status_code: None,
+            latency_ms,
+            error: Some(e.to_string()),
+        },
+    })
+}
@@ -0,0 +1,90 @@
+//! Local HTTP server standing in for remote APIs in tests
+
+use serde_json::Value;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+/// Request line, headers and body of a request the stand-in received
+pub(crate) struct Captured {
+    pub method: String,
+    pub path: String,
+    pub head: String,
+    pub body: String,
+}
+
+impl Captured {
+    pub fn json(&self) -> Value {
+        serde_json::from_str(&self.body).unwrap()
+    }
+}
+
+/// Serve every request with the status and JSON body `respond` picks for it
+///
+/// Returns the base URL and the requests received, in order.
+pub(crate) async fn serve<F>(respond: F) -> (String, mpsc::UnboundedReceiver<Captured>)
+where
+    F: Fn(&Captured) -> (u16, Value) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                return;
+            };
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            let header_end = loop {
+                if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break pos + 4;
+                }
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break request.len();
+                }
+                request.extend_from_slice(&buf[..n]);
+            };
+
+            let head = String::from_utf8_lossy(&request[..header_end]).to_string();
+            let content_length = head
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().ok())?
+                })
+                .unwrap_or(0);
+            while request.len() < header_end + content_length {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            let mut request_line = head.split_whitespace();
+            let captured = Captured {
+                method: request_line.next().unwrap_or_default().to_string(),
+                path: request_line.next().unwrap_or_default().to_string(),
+                body: String::from_utf8_lossy(&request[header_end..]).to_string(),
+                head,
+            };
+
+            let (status, body) = respond(&captured);
+            let payload = body.to_string();
+            let response = format!(
+                "HTTP/1.1 {} Stand-in\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                payload.len(),
+                payload
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            let _ = tx.send(captured);
+        }
+    });
+
+    (base_url, rx)
+}
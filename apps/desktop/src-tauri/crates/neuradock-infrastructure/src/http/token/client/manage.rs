@@ -0,0 +1,213 @@
+use anyhow::Result;
+use reqwest::RequestBuilder;
+use serde_json::json;
+
+use super::types::{TokenMutationResponse, TokenPayload, TokenSession};
+
+impl super::TokenClient {
+    /// Create a token via `POST {token_api_path}`
+    pub async fn create_token(
+        &self,
+        session: &TokenSession<'_>,
+        payload: &TokenPayload,
+    ) -> Result<()> {
+        let url = Self::build_url(session.base_url, session.token_api_path);
+        log::info!("Creating token '{}' at: {}", payload.name, url);
+
+        let request = self.client.post(&url).json(payload);
+        self.send_mutation(request, session, "create token").await
+    }
+
+    /// Replace name, quota, expiry and model limits via `PUT {token_api_path}`
+    pub async fn update_token(
+        &self,
+        session: &TokenSession<'_>,
+        payload: &TokenPayload,
+    ) -> Result<()> {
+        let id = payload
+            .id
+            .ok_or_else(|| anyhow::anyhow!("Token id is required for updates"))?;
+        let url = Self::build_url(session.base_url, session.token_api_path);
+        log::info!("Updating token {} at: {}", id, url);
+
+        let request = self.client.put(&url).json(payload);
+        self.send_mutation(request, session, "update token").await
+    }
+
+    /// Enable (1) or disable (2) a token without touching its other settings
+    pub async fn set_token_status(
+        &self,
+        session: &TokenSession<'_>,
+        id: i64,
+        status: i32,
+    ) -> Result<()> {
+        let url = format!(
+            "{}?status_only=true",
+            Self::build_url(session.base_url, session.token_api_path)
+        );
+        log::info!("Setting status of token {} to {}", id, status);
+
+        let request = self
+            .client
+            .put(&url)
+            .json(&json!({ "id": id, "status": status }));
+        self.send_mutation(request, session, "update token status")
+            .await
+    }
+
+    /// Delete a token via `DELETE {token_api_path}/{id}`
+    pub async fn delete_token(&self, session: &TokenSession<'_>, id: i64) -> Result<()> {
+        let url = format!(
+            "{}/{}",
+            Self::build_url(session.base_url, session.token_api_path).trim_end_matches('/'),
+            id
+        );
+        log::info!("Deleting token {} at: {}", id, url);
+
+        let request = self.client.delete(&url);
+        self.send_mutation(request, session, "delete token").await
+    }
+
+    async fn send_mutation(
+        &self,
+        request: RequestBuilder,
+        session: &TokenSession<'_>,
+        action: &str,
+    ) -> Result<()> {
+        let normalized_base = session.base_url.trim_end_matches('/');
+        let mut request = request
+            .timeout(Self::request_timeout(session.base_url))
+            .header("Cookie", session.cookie_string)
+            .header("Accept", "application/json")
+            .header("Cache-Control", "no-store")
+            .header("Referer", format!("{}/console/token", normalized_base));
+
+        if let Some(user) = session.api_user {
+            let header_name = session.api_user_header.unwrap_or("New-API-User");
+            request = request.header(header_name, user);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let response_text = response.text().await?;
+
+        // Same WAF detection as fetch_tokens so callers can retry with fresh cookies
+        if response_text.contains("<html>") && response_text.contains("acw_sc__v2") {
+            log::warn!("Detected WAF challenge page while trying to {}", action);
+            anyhow::bail!("WAF_CHALLENGE: Session cookies expired or invalid, please re-login to refresh WAF cookies");
+        }
+
+        if !status.is_success() {
+            log::error!("Failed to {}: HTTP {}", action, status);
+            anyhow::bail!("Failed to {}: HTTP {}", action, status);
+        }
+
+        let mutation: TokenMutationResponse =
+            serde_json::from_str(&response_text).map_err(|e| {
+                log::error!("Failed to parse {} response: {}", action, e);
+                anyhow::anyhow!("Failed to parse response: {}", e)
+            })?;
+
+        if !mutation.success {
+            log::error!("API refused to {}: {}", action, mutation.message);
+            anyhow::bail!("API returned error: {}", mutation.message);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::TokenClient;
+    use super::*;
+    use crate::http::stand_in::{self, Captured};
+    use serde_json::Value;
+    use tokio::sync::mpsc;
+
+    /// Local endpoint answering every request with `reply`, returns its base URL
+    async fn stand_in(reply: Value) -> (String, mpsc::UnboundedReceiver<Captured>) {
+        stand_in::serve(move |_| (200, reply.clone())).await
+    }
+
+    fn session(base_url: &str) -> TokenSession<'_> {
+        TokenSession {
+            base_url,
+            token_api_path: "/api/token/",
+            cookie_string: "session=abc",
+            api_user_header: None,
+            api_user: Some("42"),
+        }
+    }
+
+    fn payload(id: Option<i64>) -> TokenPayload {
+        TokenPayload {
+            id,
+            name: "ci-runner".to_string(),
+            remain_quota: 500_000,
+            unlimited_quota: false,
+            expired_time: -1,
+            model_limits_enabled: true,
+            model_limits: "gpt-4o,claude-sonnet-4-5".to_string(),
+            group: "vip".to_string(),
+            allow_ips: "10.0.0.1".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_update_and_delete_hit_new_api_routes() {
+        let (base_url, mut requests) = stand_in(json!({ "success": true, "message": "" })).await;
+        let client = TokenClient::new().unwrap();
+        let session = session(&base_url);
+
+        client.create_token(&session, &payload(None)).await.unwrap();
+        let create = requests.recv().await.unwrap();
+        assert_eq!(create.method, "POST");
+        assert_eq!(create.path, "/api/token/");
+        assert!(create.head.to_lowercase().contains("new-api-user: 42"));
+        let body = create.json();
+        assert_eq!(body["name"], "ci-runner");
+        assert_eq!(body["model_limits"], "gpt-4o,claude-sonnet-4-5");
+        assert!(body.get("id").is_none());
+
+        client
+            .update_token(&session, &payload(Some(7)))
+            .await
+            .unwrap();
+        let update = requests.recv().await.unwrap();
+        assert_eq!(update.method, "PUT");
+        let body = update.json();
+        assert_eq!(body["id"], 7);
+        assert_eq!(body["group"], "vip");
+        assert_eq!(body["allow_ips"], "10.0.0.1");
+
+        client.set_token_status(&session, 7, 2).await.unwrap();
+        let status = requests.recv().await.unwrap();
+        assert_eq!(status.path, "/api/token/?status_only=true");
+        let body = status.json();
+        assert_eq!(body["status"], 2);
+
+        client.delete_token(&session, 7).await.unwrap();
+        let delete = requests.recv().await.unwrap();
+        assert_eq!(delete.method, "DELETE");
+        assert_eq!(delete.path, "/api/token/7");
+    }
+
+    #[tokio::test]
+    async fn refused_mutation_reports_api_message() {
+        let (base_url, _requests) =
+            stand_in(json!({ "success": false, "message": "令牌名称过长" })).await;
+        let client = TokenClient::new().unwrap();
+
+        let err = client
+            .create_token(&session(&base_url), &payload(None))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("令牌名称过长"));
+
+        assert!(client
+            .update_token(&session(&base_url), &payload(None))
+            .await
+            .is_err());
+    }
+}
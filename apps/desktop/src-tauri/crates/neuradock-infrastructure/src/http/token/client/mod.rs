@@ -1,3 +1,4 @@
+mod manage;
 mod models;
 mod tokens;
 mod types;
@@ -5,9 +6,14 @@ mod types;
 use anyhow::Result;
 use log::debug;
 use reqwest::{Client, Proxy};
+use std::time::Duration;
+
+use crate::config::TimeoutConfig;
 
 // Re-export types
-pub use types::{FetchTokensRequest, TokenData, TokenResponse};
+pub use types::{
+    FetchTokensRequest, TokenData, TokenMutationResponse, TokenPayload, TokenResponse, TokenSession,
+};
 
 pub struct TokenClient {
     pub(super) client: Client,
@@ -20,13 +26,16 @@ impl TokenClient {
 
     pub fn with_proxy(proxy_url: Option<String>) -> Result<Self> {
         let mut builder = Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
+            .timeout(TimeoutConfig::global().http_request)
             .gzip(true) // Enable automatic gzip decompression
             // Always ignore environment/system proxy settings; use only app config.
             .no_proxy();
 
         if let Some(url) = proxy_url {
-            debug!("🌐 Configuring TokenClient with proxy: {}", url);
+            debug!(
+                "🌐 Configuring TokenClient with proxy: {}",
+                crate::logging::log_utils::mask_url_credentials(&url)
+            );
             builder = builder.proxy(Proxy::all(&url)?);
         }
 
@@ -35,6 +44,11 @@ impl TokenClient {
         Ok(Self { client })
     }
 
+    /// Request timeout for a provider, read per request so setting changes apply immediately
+    pub(super) fn request_timeout(base_url: &str) -> Duration {
+        TimeoutConfig::for_url(base_url).http_request
+    }
+
     pub(super) fn build_url(base: &str, path: &str) -> String {
         if path.starts_with("http://") || path.starts_with("https://") {
             path.to_string()
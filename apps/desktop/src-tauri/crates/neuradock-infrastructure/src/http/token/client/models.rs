@@ -20,6 +20,7 @@ impl super::TokenClient {
         let mut request = self
             .client
             .get(&url)
+            .timeout(Self::request_timeout(base_url))
             .header("Cookie", cookie_string)
             .header("Accept", "application/json")
             .header("Accept-Encoding", "gzip, deflate, br")
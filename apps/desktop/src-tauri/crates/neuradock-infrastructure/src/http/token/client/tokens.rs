@@ -22,6 +22,7 @@ impl super::TokenClient {
         let mut http_request = self
             .client
             .get(&url)
+            .timeout(Self::request_timeout(request.base_url))
             .header("Cookie", request.cookie_string)
             .header("Accept", "application/json")
             .header("Accept-Encoding", "gzip, deflate, br")
@@ -12,6 +12,44 @@ pub struct FetchTokensRequest<'a> {
     pub size: u32,
 }
 
+/// Console session used to create, update and delete tokens
+#[derive(Debug, Clone)]
+pub struct TokenSession<'a> {
+    pub base_url: &'a str,
+    pub token_api_path: &'a str,
+    pub cookie_string: &'a str,
+    pub api_user_header: Option<&'a str>,
+    pub api_user: Option<&'a str>,
+}
+
+/// Body of new-api's token create and update requests
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenPayload {
+    /// Only sent on update
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    pub remain_quota: i64,
+    pub unlimited_quota: bool,
+    /// Unix seconds, `-1` never expires
+    pub expired_time: i64,
+    pub model_limits_enabled: bool,
+    /// Comma-separated model names
+    pub model_limits: String,
+    /// Billing group, empty for the user's own group
+    pub group: String,
+    /// Newline-separated allowed IPs, empty allows any
+    pub allow_ips: String,
+}
+
+/// Response of token create, update and delete requests
+#[derive(Debug, Deserialize)]
+pub struct TokenMutationResponse {
+    pub success: bool,
+    #[serde(default)]
+    pub message: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct TokenResponse {
     pub success: bool,
@@ -75,6 +113,11 @@ pub struct TokenData {
     pub used_quota: i64,
     pub model_limits_enabled: bool,
     pub model_limits: serde_json::Value,
+    #[serde(default)]
+    pub group: String,
+    /// `null` when no IPs were ever set
+    #[serde(default)]
+    pub allow_ips: Option<String>,
 }
 
 /// Response format for provider models API
@@ -1,3 +1,6 @@
 mod client;
 
-pub use client::{FetchTokensRequest, TokenClient, TokenData, TokenResponse};
+pub use client::{
+    FetchTokensRequest, TokenClient, TokenData, TokenMutationResponse, TokenPayload, TokenResponse,
+    TokenSession,
+};
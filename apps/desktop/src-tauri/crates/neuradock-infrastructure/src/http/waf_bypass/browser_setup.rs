@@ -8,7 +8,7 @@ use tokio::task::JoinHandle;
 use crate::config::TimeoutConfig;
 
 /// Find available Chromium-based browser on the system
-pub(super) fn find_browser() -> Option<PathBuf> {
+pub(crate) fn find_browser() -> Option<PathBuf> {
     let browser_paths = vec![
         // macOS
         "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
@@ -144,6 +144,7 @@ impl super::WafBypassService {
             .chrome_executable(&browser_path); // Use found browser
 
         // Apply proxy if configured (Chrome flag supports http(s):// and socks5://).
+        // Credentials were split off in `with_proxy` and are answered over CDP.
         if let Some(proxy_url) = self.proxy_url.as_deref() {
             info!(
                 "[{}] Launching browser with proxy: {}",
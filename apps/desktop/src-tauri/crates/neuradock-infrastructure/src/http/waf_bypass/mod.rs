@@ -1,20 +1,30 @@
 mod browser_setup;
 mod cleanup;
 mod navigation;
+mod pooled;
+mod proxy_auth;
 mod types;
 
 use anyhow::Result;
 use log::{info, warn};
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
-use browser_setup::find_browser;
+pub(crate) use browser_setup::find_browser;
 use cleanup::cleanup_browser;
+use proxy_auth::{split_proxy_credentials, ProxyCredentials};
 use types::REQUIRED_WAF_COOKIES;
 
+use crate::browser::BrowserPool;
+use crate::config::network_policy;
+use crate::monitoring::metrics;
+
 pub struct WafBypassService {
     headless: bool,
     proxy_url: Option<String>,
+    proxy_credentials: Option<ProxyCredentials>,
+    browser_pool: Option<Arc<BrowserPool>>,
 }
 
 impl WafBypassService {
@@ -22,38 +32,58 @@ impl WafBypassService {
         Self {
             headless,
             proxy_url: None,
+            proxy_credentials: None,
+            browser_pool: None,
         }
     }
 
     pub fn with_proxy(headless: bool, proxy_url: Option<String>) -> Self {
+        let (proxy_url, proxy_credentials) = match proxy_url.as_deref().map(split_proxy_credentials)
+        {
+            Some((url, credentials)) => (Some(url), credentials),
+            None => (None, None),
+        };
         Self {
             headless,
             proxy_url,
+            proxy_credentials,
+            browser_pool: None,
         }
     }
 
+    /// Take pages from a shared pool instead of launching a browser per attempt
+    ///
+    /// Only used in headless mode; a visible browser is still launched on its own.
+    pub fn with_browser_pool(mut self, pool: Arc<BrowserPool>) -> Self {
+        self.browser_pool = Some(pool);
+        self
+    }
+
     /// Get WAF cookies using chromiumoxide (pure Rust)
     pub async fn get_waf_cookies(
         &self,
         login_url: &str,
         account_name: &str,
     ) -> Result<HashMap<String, String>> {
-        const MAX_RETRIES: u32 = 2;
+        let max_attempts = network_policy::for_url(login_url).waf_max_attempts.max(1);
         let mut last_error = None;
 
-        for attempt in 0..MAX_RETRIES {
+        for attempt in 0..max_attempts {
             if attempt > 0 {
                 info!(
                     "[{}] Retrying WAF cookie fetch (attempt {}/{})",
                     account_name,
                     attempt + 1,
-                    MAX_RETRIES
+                    max_attempts
                 );
                 tokio::time::sleep(Duration::from_secs(2)).await;
             }
 
             match self.get_waf_cookies_once(login_url, account_name).await {
-                Ok(cookies) => return Ok(cookies),
+                Ok(cookies) => {
+                    record_waf_refresh(login_url, "success");
+                    return Ok(cookies);
+                }
                 Err(e) => {
                     warn!(
                         "[{}] WAF cookie fetch attempt {} failed: {}",
@@ -66,8 +96,9 @@ impl WafBypassService {
             }
         }
 
+        record_waf_refresh(login_url, "failure");
         Err(last_error.unwrap_or_else(|| {
-            anyhow::anyhow!("Failed to get WAF cookies after {} attempts", MAX_RETRIES)
+            anyhow::anyhow!("Failed to get WAF cookies after {} attempts", max_attempts)
         }))
     }
 
@@ -77,25 +108,16 @@ impl WafBypassService {
         login_url: &str,
         account_name: &str,
     ) -> Result<HashMap<String, String>> {
-        info!(
-            "[{}] Starting browser to get WAF cookies (chromiumoxide)...",
-            account_name
-        );
-
-        // 1. Launch browser with proper configuration
-        let (browser, handler_task, temp_dir) =
-            self.launch_browser_with_config(account_name).await?;
-
-        // 2. Navigate to page and extract cookies
-        let (browser, waf_cookies_result) = self
-            .navigate_and_extract_cookies(browser, login_url, account_name)
-            .await;
-
-        // 3. Clean up browser resources (always execute even if error)
-        cleanup_browser(browser, handler_task, temp_dir, account_name).await;
-
-        // 4. Return result
-        let waf_cookies = waf_cookies_result?;
+        let waf_cookies = match self.browser_pool.as_deref().filter(|_| self.headless) {
+            Some(pool) => {
+                self.get_pooled_waf_cookies(pool, login_url, account_name)
+                    .await?
+            }
+            None => {
+                self.get_launched_waf_cookies(login_url, account_name)
+                    .await?
+            }
+        };
 
         // Check if we got any cookies
         if waf_cookies.is_empty() {
@@ -115,6 +137,32 @@ impl WafBypassService {
 
         Ok(waf_cookies)
     }
+
+    /// Launch a dedicated browser, get the cookies and tear it down again
+    async fn get_launched_waf_cookies(
+        &self,
+        login_url: &str,
+        account_name: &str,
+    ) -> Result<HashMap<String, String>> {
+        info!(
+            "[{}] Starting browser to get WAF cookies (chromiumoxide)...",
+            account_name
+        );
+
+        // 1. Launch browser with proper configuration
+        let (browser, handler_task, temp_dir) =
+            self.launch_browser_with_config(account_name).await?;
+
+        // 2. Navigate to page and extract cookies
+        let (browser, waf_cookies_result) = self
+            .navigate_and_extract_cookies(browser, login_url, account_name)
+            .await;
+
+        // 3. Clean up browser resources (always execute even if error)
+        cleanup_browser(browser, handler_task, temp_dir, account_name).await;
+
+        waf_cookies_result
+    }
 }
 
 impl Default for WafBypassService {
@@ -128,6 +176,19 @@ pub fn check_available_browser() -> Option<String> {
     find_browser().map(|path| path.to_string_lossy().to_string())
 }
 
+/// Count a WAF cookie refresh, retries included, per provider host
+fn record_waf_refresh(login_url: &str, outcome: &str) {
+    let host = url::Url::parse(login_url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "unknown".to_string());
+    metrics().inc_counter(
+        "neuradock_waf_refresh_total",
+        "WAF cookie refreshes by provider host and outcome",
+        &[("host", &host), ("outcome", outcome)],
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
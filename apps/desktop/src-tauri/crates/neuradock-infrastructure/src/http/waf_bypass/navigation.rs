@@ -1,5 +1,6 @@
 use anyhow::Result;
 use chromiumoxide::browser::Browser;
+use chromiumoxide::Page;
 use log::info;
 use std::collections::HashMap;
 
@@ -28,11 +29,33 @@ impl super::WafBypassService {
 
         info!("[{}] New page created", account_name);
 
+        let result = self
+            .extract_cookies_from_page(&page, login_url, account_name)
+            .await;
+        (browser, result)
+    }
+
+    /// Load the login page in `page` and collect the WAF cookies it sets
+    pub(super) async fn extract_cookies_from_page(
+        &self,
+        page: &Page,
+        login_url: &str,
+        account_name: &str,
+    ) -> Result<HashMap<String, String>> {
+        // Answer proxy authentication challenges before any navigation
+        if let Some(credentials) = self.proxy_credentials.clone() {
+            if let Err(e) = super::proxy_auth::enable_proxy_auth(page, credentials).await {
+                let err_msg = format!("Failed to enable proxy authentication: {}", e);
+                log::error!("[{}] {}", account_name, err_msg);
+                return Err(anyhow::anyhow!(err_msg));
+            }
+        }
+
         // Set user agent
         if let Err(e) = page.set_user_agent(USER_AGENT).await {
             let err_msg = format!("Failed to set user agent: {}", e);
             log::error!("[{}] {}", account_name, err_msg);
-            return (browser, Err(anyhow::anyhow!(err_msg)));
+            return Err(anyhow::anyhow!(err_msg));
         }
 
         info!("[{}] Navigating to: {}", account_name, login_url);
@@ -41,13 +64,13 @@ impl super::WafBypassService {
         if let Err(e) = page.goto(login_url).await {
             let err_msg = format!("Failed to navigate to login page: {}", e);
             log::error!("[{}] {}", account_name, err_msg);
-            return (browser, Err(anyhow::anyhow!(err_msg)));
+            return Err(anyhow::anyhow!(err_msg));
         }
 
         info!("[{}] Page loaded, waiting for WAF cookies...", account_name);
 
-        // Wait for cookies to be set (configurable timeout)
-        let timeout_config = TimeoutConfig::global();
+        // Wait for cookies to be set (configurable timeout, per-site overrides apply)
+        let timeout_config = TimeoutConfig::for_url(login_url);
         tokio::time::sleep(timeout_config.waf_wait).await;
 
         // Get all cookies
@@ -56,7 +79,7 @@ impl super::WafBypassService {
             Err(e) => {
                 let err_msg = format!("Failed to get cookies: {}", e);
                 log::error!("[{}] {}", account_name, err_msg);
-                return (browser, Err(anyhow::anyhow!(err_msg)));
+                return Err(anyhow::anyhow!(err_msg));
             }
         };
 
@@ -92,6 +115,6 @@ impl super::WafBypassService {
             REQUIRED_WAF_COOKIES.len()
         );
 
-        (browser, Ok(waf_cookies))
+        Ok(waf_cookies)
     }
 }
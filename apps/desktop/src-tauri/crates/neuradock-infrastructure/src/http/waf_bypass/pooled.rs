@@ -0,0 +1,41 @@
+use anyhow::Result;
+use log::info;
+use std::collections::HashMap;
+
+use crate::browser::BrowserPool;
+
+impl super::WafBypassService {
+    /// Get WAF cookies in an isolated context of the shared browser pool
+    ///
+    /// The context is keyed by the login host, gets its own cookie jar and
+    /// carries the proxy, so pooled browsers are launched without one.
+    pub(super) async fn get_pooled_waf_cookies(
+        &self,
+        pool: &BrowserPool,
+        login_url: &str,
+        account_name: &str,
+    ) -> Result<HashMap<String, String>> {
+        let provider_key = url::Url::parse(login_url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_else(|| login_url.to_string());
+        info!(
+            "[{}] Acquiring pooled browser context for {}",
+            account_name, provider_key
+        );
+
+        let context = pool
+            .acquire_context(&provider_key, self.proxy_url.as_deref())
+            .await?;
+        let result = match context.new_page("about:blank").await {
+            Ok(page) => {
+                self.extract_cookies_from_page(&page, login_url, account_name)
+                    .await
+            }
+            Err(e) => Err(e.into()),
+        };
+        context.close().await;
+
+        result
+    }
+}
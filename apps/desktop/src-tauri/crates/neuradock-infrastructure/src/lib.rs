@@ -5,6 +5,8 @@ pub mod bootstrap;
 pub mod browser;
 pub mod codex_auth;
 pub mod config;
+pub mod cookie_import;
+pub mod diagnostics;
 pub mod events;
 pub mod http;
 pub mod logging;
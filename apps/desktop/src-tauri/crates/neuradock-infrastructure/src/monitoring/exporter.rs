@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// Upper bound for reading a request head, scrapers send a few hundred bytes
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Produces the body served at `/metrics`
+#[async_trait]
+pub trait MetricsSource: Send + Sync {
+    async fn render(&self) -> String;
+}
+
+/// Minimal HTTP server exposing `GET /metrics` on the loopback interface
+///
+/// Only binds 127.0.0.1 so balances and account names never leave the machine unless a
+/// local Prometheus agent forwards them. The server stops when the exporter is dropped.
+pub struct MetricsExporter {
+    local_addr: SocketAddr,
+    handle: JoinHandle<()>,
+}
+
+impl MetricsExporter {
+    /// Bind `127.0.0.1:<port>` and start serving, port 0 picks a free port
+    pub async fn start(port: u16, source: Arc<dyn MetricsSource>) -> std::io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port)).await?;
+        let local_addr = listener.local_addr()?;
+        info!(
+            "Metrics exporter listening on http://{}/metrics",
+            local_addr
+        );
+
+        let handle = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        let source = source.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = serve_connection(stream, source).await {
+                                debug!("Metrics connection error: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        warn!("Metrics exporter accept failed: {}", e);
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                    }
+                }
+            }
+        });
+
+        Ok(Self { local_addr, handle })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    pub fn stop(self) {
+        // Drop aborts the accept loop
+    }
+}
+
+impl Drop for MetricsExporter {
+    fn drop(&mut self) {
+        self.handle.abort();
+        info!("Metrics exporter on {} stopped", self.local_addr);
+    }
+}
+
+async fn serve_connection(
+    mut stream: TcpStream,
+    source: Arc<dyn MetricsSource>,
+) -> std::io::Result<()> {
+    let head = match tokio::time::timeout(READ_TIMEOUT, read_request_head(&mut stream)).await {
+        Ok(head) => head?,
+        Err(_) => return Ok(()),
+    };
+
+    let request_line = head.lines().next().unwrap_or_default();
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+    let path = path.split('?').next().unwrap_or_default();
+
+    let (status, body) = match (method, path) {
+        ("GET", "/metrics") => ("200 OK", source.render().await),
+        (_, "/metrics") => ("405 Method Not Allowed", String::new()),
+        _ => ("404 Not Found", String::new()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        CONTENT_TYPE,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+async fn read_request_head(stream: &mut TcpStream) -> std::io::Result<String> {
+    let mut buffer = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+
+    loop {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if buffer.windows(4).any(|window| window == b"\r\n\r\n") || buffer.len() >= MAX_REQUEST_HEAD
+        {
+            break;
+        }
+    }
+
+    Ok(String::from_utf8_lossy(&buffer).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StaticSource;
+
+    #[async_trait]
+    impl MetricsSource for StaticSource {
+        async fn render(&self) -> String {
+            "neuradock_up 1\n".to_string()
+        }
+    }
+
+    async fn request(addr: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_exporter_serves_metrics_on_loopback() {
+        let exporter = MetricsExporter::start(0, Arc::new(StaticSource))
+            .await
+            .unwrap();
+        let addr = exporter.local_addr();
+        assert!(addr.ip().is_loopback());
+
+        let response = request(addr, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains(CONTENT_TYPE));
+        assert!(response.ends_with("neuradock_up 1\n"));
+
+        let response = request(addr, "GET / HTTP/1.1\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 404"));
+
+        let response = request(addr, "POST /metrics HTTP/1.1\r\n\r\n").await;
+        assert!(response.starts_with("HTTP/1.1 405"));
+
+        exporter.stop();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(TcpStream::connect(addr).await.is_err());
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Mutex, OnceLock};
+
+/// Buckets in seconds for operations that include network round trips or a browser
+pub const LATENCY_BUCKETS: &[f64] = &[0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 20.0, 30.0, 60.0, 120.0];
+
+static REGISTRY: OnceLock<MetricsRegistry> = OnceLock::new();
+
+/// Process-wide metrics registry
+///
+/// Recording is always on and cheap, exposing it over HTTP is opt-in (see `MetricsExporter`).
+pub fn metrics() -> &'static MetricsRegistry {
+    REGISTRY.get_or_init(MetricsRegistry::new)
+}
+
+/// Label pairs in the order they are rendered
+pub type Labels = Vec<(String, String)>;
+
+#[derive(Debug, Clone, Copy)]
+enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram(&'static [f64]),
+}
+
+impl MetricKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MetricKind::Counter => "counter",
+            MetricKind::Gauge => "gauge",
+            MetricKind::Histogram(_) => "histogram",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Series {
+    Value(f64),
+    Histogram {
+        /// Per-bucket counts, not cumulative
+        buckets: Vec<u64>,
+        sum: f64,
+        count: u64,
+    },
+}
+
+#[derive(Debug)]
+struct MetricFamily {
+    help: &'static str,
+    kind: MetricKind,
+    series: BTreeMap<Labels, Series>,
+}
+
+/// Counters, gauges and histograms rendered in the Prometheus text format
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    families: Mutex<BTreeMap<String, MetricFamily>>,
+}
+
+fn to_labels(labels: &[(&str, &str)]) -> Labels {
+    labels
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_family(
+        &self,
+        name: &str,
+        help: &'static str,
+        kind: MetricKind,
+        update: impl FnOnce(&mut MetricFamily),
+    ) {
+        let mut families = self
+            .families
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let family = families
+            .entry(name.to_string())
+            .or_insert_with(|| MetricFamily {
+                help,
+                kind,
+                series: BTreeMap::new(),
+            });
+        update(family);
+    }
+
+    pub fn inc_counter(&self, name: &str, help: &'static str, labels: &[(&str, &str)]) {
+        self.add_counter(name, help, labels, 1.0);
+    }
+
+    pub fn add_counter(&self, name: &str, help: &'static str, labels: &[(&str, &str)], value: f64) {
+        self.with_family(name, help, MetricKind::Counter, |family| {
+            let series = family
+                .series
+                .entry(to_labels(labels))
+                .or_insert(Series::Value(0.0));
+            if let Series::Value(total) = series {
+                *total += value;
+            }
+        });
+    }
+
+    pub fn set_gauge(&self, name: &str, help: &'static str, labels: &[(&str, &str)], value: f64) {
+        self.with_family(name, help, MetricKind::Gauge, |family| {
+            family
+                .series
+                .insert(to_labels(labels), Series::Value(value));
+        });
+    }
+
+    /// Replace every series of a gauge, e.g. per-account values collected at scrape time
+    /// Series missing from `series` disappear, so deleted accounts stop being exported
+    pub fn replace_gauges(&self, name: &str, help: &'static str, series: Vec<(Labels, f64)>) {
+        self.with_family(name, help, MetricKind::Gauge, |family| {
+            family.series = series
+                .into_iter()
+                .map(|(labels, value)| (labels, Series::Value(value)))
+                .collect();
+        });
+    }
+
+    pub fn observe(
+        &self,
+        name: &str,
+        help: &'static str,
+        buckets: &'static [f64],
+        labels: &[(&str, &str)],
+        value: f64,
+    ) {
+        self.with_family(name, help, MetricKind::Histogram(buckets), |family| {
+            let bounds = match family.kind {
+                MetricKind::Histogram(bounds) => bounds,
+                _ => return,
+            };
+            let series =
+                family
+                    .series
+                    .entry(to_labels(labels))
+                    .or_insert_with(|| Series::Histogram {
+                        buckets: vec![0; bounds.len()],
+                        sum: 0.0,
+                        count: 0,
+                    });
+            if let Series::Histogram {
+                buckets,
+                sum,
+                count,
+            } = series
+            {
+                if let Some(index) = bounds.iter().position(|bound| value <= *bound) {
+                    buckets[index] += 1;
+                }
+                *sum += value;
+                *count += 1;
+            }
+        });
+    }
+
+    /// Render all metrics in the Prometheus text exposition format (0.0.4)
+    pub fn render(&self) -> String {
+        let families = self
+            .families
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut out = String::new();
+
+        for (name, family) in families.iter() {
+            let _ = writeln!(out, "# HELP {} {}", name, family.help);
+            let _ = writeln!(out, "# TYPE {} {}", name, family.kind.as_str());
+
+            for (labels, series) in &family.series {
+                match series {
+                    Series::Value(value) => {
+                        write_sample(&mut out, name, labels, None, *value);
+                    }
+                    Series::Histogram {
+                        buckets,
+                        sum,
+                        count,
+                    } => {
+                        let bounds = match family.kind {
+                            MetricKind::Histogram(bounds) => bounds,
+                            _ => continue,
+                        };
+                        let bucket_name = format!("{}_bucket", name);
+                        let mut cumulative = 0;
+                        for (bound, bucket) in bounds.iter().zip(buckets) {
+                            cumulative += bucket;
+                            write_sample(
+                                &mut out,
+                                &bucket_name,
+                                labels,
+                                Some(format_value(*bound)),
+                                cumulative as f64,
+                            );
+                        }
+                        write_sample(
+                            &mut out,
+                            &bucket_name,
+                            labels,
+                            Some("+Inf".to_string()),
+                            *count as f64,
+                        );
+                        write_sample(&mut out, &format!("{}_sum", name), labels, None, *sum);
+                        write_sample(
+                            &mut out,
+                            &format!("{}_count", name),
+                            labels,
+                            None,
+                            *count as f64,
+                        );
+                    }
+                }
+            }
+        }
+
+        out
+    }
+}
+
+fn write_sample(out: &mut String, name: &str, labels: &Labels, le: Option<String>, value: f64) {
+    out.push_str(name);
+
+    let le = le.map(|le| ("le".to_string(), le));
+    let mut pairs = labels.iter().chain(le.iter()).peekable();
+    if pairs.peek().is_some() {
+        out.push('{');
+        for (index, (label, value)) in pairs.enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{}=\"{}\"", label, escape_label_value(value));
+        }
+        out.push('}');
+    }
+
+    let _ = writeln!(out, " {}", format_value(value));
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_counter_and_gauge() {
+        let registry = MetricsRegistry::new();
+        registry.inc_counter("nd_attempts_total", "Attempts", &[("provider", "a")]);
+        registry.inc_counter("nd_attempts_total", "Attempts", &[("provider", "a")]);
+        registry.set_gauge("nd_balance", "Balance", &[("account", "Main \"1\"")], 12.5);
+
+        let text = registry.render();
+        assert!(text.contains("# TYPE nd_attempts_total counter"));
+        assert!(text.contains("nd_attempts_total{provider=\"a\"} 2"));
+        assert!(text.contains("nd_balance{account=\"Main \\\"1\\\"\"} 12.5"));
+    }
+
+    #[test]
+    fn test_render_histogram_is_cumulative() {
+        let registry = MetricsRegistry::new();
+        for value in [0.3, 0.3, 7.0, 500.0] {
+            registry.observe("nd_seconds", "Latency", LATENCY_BUCKETS, &[], value);
+        }
+
+        let text = registry.render();
+        assert!(text.contains("nd_seconds_bucket{le=\"0.25\"} 0"));
+        assert!(text.contains("nd_seconds_bucket{le=\"0.5\"} 2"));
+        assert!(text.contains("nd_seconds_bucket{le=\"10\"} 3"));
+        assert!(text.contains("nd_seconds_bucket{le=\"+Inf\"} 4"));
+        assert!(text.contains("nd_seconds_count 4"));
+    }
+
+    #[test]
+    fn test_replace_gauges_drops_missing_series() {
+        let registry = MetricsRegistry::new();
+        registry.set_gauge("nd_balance", "Balance", &[("account", "old")], 1.0);
+        registry.replace_gauges(
+            "nd_balance",
+            "Balance",
+            vec![(vec![("account".to_string(), "new".to_string())], 2.0)],
+        );
+
+        let text = registry.render();
+        assert!(!text.contains("old"));
+        assert!(text.contains("nd_balance{account=\"new\"} 2"));
+    }
+}
@@ -1,3 +1,7 @@
+pub mod exporter;
+pub mod metrics;
 pub mod performance;
 
+pub use exporter::*;
+pub use metrics::*;
 pub use performance::*;
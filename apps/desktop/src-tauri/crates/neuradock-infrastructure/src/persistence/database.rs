@@ -1,8 +1,40 @@
 use neuradock_domain::shared::DomainError;
-use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use sqlx::migrate::Migrator;
+use sqlx::{sqlite::SqlitePoolOptions, FromRow, SqlitePool};
 use std::fs::OpenOptions;
 use std::path::Path;
 
+use crate::config::TimeoutConfig;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// A migration recorded in `_sqlx_migrations`
+#[derive(Debug, Clone, FromRow)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub description: String,
+    pub success: bool,
+    pub installed_on: String,
+}
+
+/// Applied migrations compared with the ones embedded in this build
+#[derive(Debug, Clone)]
+pub struct SchemaStatus {
+    pub applied: Vec<AppliedMigration>,
+    /// Embedded migrations not yet applied, `(version, description)`
+    pub pending: Vec<(i64, String)>,
+}
+
+impl SchemaStatus {
+    pub fn latest_applied(&self) -> Option<i64> {
+        self.applied
+            .iter()
+            .filter(|migration| migration.success)
+            .map(|migration| migration.version)
+            .max()
+    }
+}
+
 pub struct Database {
     pool: SqlitePool,
 }
@@ -30,6 +62,16 @@ impl Database {
 
         let pool = SqlitePoolOptions::new()
             .max_connections(5)
+            // How long a query waits on a locked database follows the live network policy
+            .before_acquire(|conn, _meta| {
+                Box::pin(async move {
+                    let busy_timeout_ms = TimeoutConfig::global().db_query.as_millis();
+                    sqlx::query(&format!("PRAGMA busy_timeout = {}", busy_timeout_ms))
+                        .execute(conn)
+                        .await?;
+                    Ok(true)
+                })
+            })
             .connect(&format!("sqlite:{}", db_path))
             .await
             .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
@@ -38,7 +80,7 @@ impl Database {
     }
 
     pub async fn run_migrations(&self) -> Result<(), DomainError> {
-        sqlx::migrate!("./migrations")
+        MIGRATOR
             .run(&self.pool)
             .await
             .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
@@ -48,4 +90,38 @@ impl Database {
     pub fn pool(&self) -> &SqlitePool {
         &self.pool
     }
+
+    /// Read the migration history, a database without it reports every migration as pending
+    pub async fn schema_status(pool: &SqlitePool) -> Result<SchemaStatus, DomainError> {
+        let has_history: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')",
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        let applied = if has_history {
+            sqlx::query_as::<_, AppliedMigration>(
+                "SELECT version, description, success, CAST(installed_on AS TEXT) AS installed_on FROM _sqlx_migrations ORDER BY version",
+            )
+            .fetch_all(pool)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?
+        } else {
+            Vec::new()
+        };
+
+        let pending = MIGRATOR
+            .iter()
+            .filter(|migration| !migration.migration_type.is_down_migration())
+            .filter(|migration| {
+                !applied
+                    .iter()
+                    .any(|applied| applied.version == migration.version && applied.success)
+            })
+            .map(|migration| (migration.version, migration.description.to_string()))
+            .collect();
+
+        Ok(SchemaStatus { applied, pending })
+    }
 }
@@ -5,7 +5,7 @@ mod database;
 mod repository_base;
 mod result_ext;
 
-pub use database::Database;
+pub use database::{AppliedMigration, Database, SchemaStatus};
 pub use repository_base::SqliteRepositoryBase;
 pub use result_ext::ResultExt;
 pub use unit_of_work::{RepositoryErrorMapper, UnitOfWork};
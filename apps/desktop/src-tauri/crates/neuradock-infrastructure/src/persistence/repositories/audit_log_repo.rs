@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use std::sync::Arc;
+
+use neuradock_domain::audit::{
+    AuditAction, AuditActor, AuditEntry, AuditLogPage, AuditLogQuery, AuditLogRepository,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+
+/// SQLite implementation of AuditLogRepository
+pub struct SqliteAuditLogRepository {
+    pool: Arc<SqlitePool>,
+}
+
+impl SqliteAuditLogRepository {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+fn entry_from_row(row: &SqliteRow) -> Result<AuditEntry, DomainError> {
+    let action: String = row.get("action");
+    let actor: String = row.get("actor");
+    let occurred_at: String = row.get("occurred_at");
+
+    Ok(AuditEntry {
+        id: Some(row.get("id")),
+        occurred_at: occurred_at
+            .parse::<DateTime<Utc>>()
+            .map_err(|e| DomainError::Repository(format!("Invalid occurred_at: {}", e)))?,
+        actor: AuditActor::parse(&actor),
+        action: AuditAction::parse(&action)
+            .ok_or_else(|| DomainError::Repository(format!("Unknown audit action: {}", action)))?,
+        target: row.get("target"),
+        target_name: row.get("target_name"),
+        before: row.get("before_summary"),
+        after: row.get("after_summary"),
+    })
+}
+
+#[async_trait]
+impl AuditLogRepository for SqliteAuditLogRepository {
+    async fn append(&self, entry: &AuditEntry) -> Result<i64, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO audit_log (
+                occurred_at, actor, action, target, target_name, before_summary, after_summary
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(entry.occurred_at.to_rfc3339())
+        .bind(entry.actor.as_str())
+        .bind(entry.action.as_str())
+        .bind(&entry.target)
+        .bind(&entry.target_name)
+        .bind(&entry.before)
+        .bind(&entry.after)
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to append audit log entry")?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn query(&self, query: &AuditLogQuery) -> Result<AuditLogPage, DomainError> {
+        let action = query.action.map(|action| action.as_str().to_string());
+
+        let total: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM audit_log
+            WHERE (?1 IS NULL OR action = ?1) AND (?2 IS NULL OR target = ?2)
+            "#,
+        )
+        .bind(&action)
+        .bind(&query.target)
+        .fetch_one(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to count audit log entries")?;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, occurred_at, actor, action, target, target_name,
+                   before_summary, after_summary
+            FROM audit_log
+            WHERE (?1 IS NULL OR action = ?1) AND (?2 IS NULL OR target = ?2)
+            ORDER BY id DESC
+            LIMIT ?3 OFFSET ?4
+            "#,
+        )
+        .bind(&action)
+        .bind(&query.target)
+        .bind(query.page_size as i64)
+        .bind(query.offset() as i64)
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to query audit log")?;
+
+        Ok(AuditLogPage {
+            entries: rows.iter().map(entry_from_row).collect::<Result<_, _>>()?,
+            total: total.max(0) as u64,
+        })
+    }
+}
@@ -3,12 +3,60 @@ use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::{FromRow, SqlitePool};
 use std::sync::Arc;
 
-use crate::persistence::SqliteRepositoryBase;
+use crate::persistence::{ResultExt, SqliteRepositoryBase};
 use neuradock_domain::balance_history::{
-    BalanceHistoryDailySummary, BalanceHistoryRecord, BalanceHistoryRepository,
+    BalanceHistoryDailySummary, BalanceHistoryExportRow, BalanceHistoryRecord,
+    BalanceHistoryRepository, DownsampleReport,
 };
 use neuradock_domain::shared::{AccountId, DomainError};
 
+/// Per-day view over raw snapshots and downsampled aggregates.
+///
+/// Binds the account id as `?1` and exposes a `daily_summary` CTE.
+const DAILY_SUMMARY_CTE: &str = r#"
+    WITH combined AS (
+        SELECT
+            DATE(recorded_at) AS check_in_date,
+            total_quota,
+            current_balance,
+            total_consumed
+        FROM balance_history
+        WHERE account_id = ?1
+        UNION ALL
+        SELECT
+            record_date AS check_in_date,
+            last_total_quota AS total_quota,
+            max_balance AS current_balance,
+            last_total_consumed AS total_consumed
+        FROM balance_history_daily
+        WHERE account_id = ?1
+    ),
+    daily_summary AS (
+        SELECT
+            check_in_date,
+            MAX(total_quota) AS daily_total_quota,
+            MAX(current_balance) AS daily_balance,
+            MAX(total_consumed) AS daily_consumed
+        FROM combined
+        GROUP BY check_in_date
+    )
+"#;
+
+/// Raw rows eligible for downsampling: recorded before `?1` (YYYY-MM-DD) and
+/// not the latest snapshot of their account.
+const DOWNSAMPLE_ELIGIBLE_FILTER: &str = r#"
+    DATE(recorded_at) < ?1
+    AND id NOT IN (
+        SELECT id FROM (
+            SELECT
+                id,
+                ROW_NUMBER() OVER (PARTITION BY account_id ORDER BY recorded_at DESC) AS rn
+            FROM balance_history
+        )
+        WHERE rn = 1
+    )
+"#;
+
 #[derive(FromRow)]
 struct BalanceHistoryRow {
     id: String,
@@ -40,6 +88,41 @@ struct DailySummaryRow {
     daily_consumed: f64,
 }
 
+#[derive(FromRow)]
+struct ExportRow {
+    account_id: String,
+    record_date: String,
+    recorded_at: DateTime<Utc>,
+    current_balance: f64,
+    total_consumed: f64,
+    total_quota: f64,
+    min_balance: f64,
+    max_balance: f64,
+    sample_count: i64,
+    downsampled: bool,
+}
+
+impl ExportRow {
+    fn try_into_export_row(self) -> Result<BalanceHistoryExportRow, DomainError> {
+        let date = NaiveDate::parse_from_str(&self.record_date, "%Y-%m-%d").map_err(|e| {
+            DomainError::Validation(format!("Invalid record_date: {} ({})", self.record_date, e))
+        })?;
+
+        Ok(BalanceHistoryExportRow {
+            account_id: AccountId::from_string(&self.account_id),
+            date,
+            recorded_at: self.recorded_at,
+            current_balance: self.current_balance,
+            total_consumed: self.total_consumed,
+            total_quota: self.total_quota,
+            min_balance: self.min_balance,
+            max_balance: self.max_balance,
+            sample_count: self.sample_count.max(0) as u32,
+            downsampled: self.downsampled,
+        })
+    }
+}
+
 impl DailySummaryRow {
     fn try_into_summary(self) -> Result<BalanceHistoryDailySummary, DomainError> {
         let date = NaiveDate::parse_from_str(&self.check_in_date, "%Y-%m-%d").map_err(|e| {
@@ -165,22 +248,23 @@ impl BalanceHistoryRepository for SqliteBalanceHistoryRepository {
         &self,
         account_id: &AccountId,
     ) -> Result<Vec<BalanceHistoryDailySummary>, DomainError> {
-        let query = r#"
+        let query = format!(
+            r#"
+            {DAILY_SUMMARY_CTE}
             SELECT
-                DATE(recorded_at) AS check_in_date,
-                MAX(total_quota) AS daily_total_quota,
-                MAX(current_balance) AS daily_balance,
-                MAX(total_consumed) AS daily_consumed
-            FROM balance_history
-            WHERE account_id = ?1
-            GROUP BY DATE(recorded_at)
+                check_in_date,
+                daily_total_quota,
+                daily_balance,
+                daily_consumed
+            FROM daily_summary
             ORDER BY check_in_date ASC
-        "#;
+        "#
+        );
 
         let rows: Vec<DailySummaryRow> = self
             .base
             .fetch_all(
-                sqlx::query_as(query).bind(account_id.as_str()),
+                sqlx::query_as(&query).bind(account_id.as_str()),
                 "List all daily summaries",
             )
             .await?;
@@ -194,17 +278,9 @@ impl BalanceHistoryRepository for SqliteBalanceHistoryRepository {
         start_date: NaiveDate,
         end_date: NaiveDate,
     ) -> Result<Vec<BalanceHistoryDailySummary>, DomainError> {
-        let query = r#"
-            WITH daily_summary AS (
-                SELECT
-                    DATE(recorded_at) AS check_in_date,
-                    MAX(total_quota) AS daily_total_quota,
-                    MAX(current_balance) AS daily_balance,
-                    MAX(total_consumed) AS daily_consumed
-                FROM balance_history
-                WHERE account_id = ?1
-                GROUP BY DATE(recorded_at)
-            )
+        let query = format!(
+            r#"
+            {DAILY_SUMMARY_CTE}
             SELECT
                 check_in_date,
                 daily_total_quota,
@@ -214,12 +290,13 @@ impl BalanceHistoryRepository for SqliteBalanceHistoryRepository {
             WHERE check_in_date >= ?2
               AND check_in_date <= ?3
             ORDER BY check_in_date ASC
-        "#;
+        "#
+        );
 
         let rows: Vec<DailySummaryRow> = self
             .base
             .fetch_all(
-                sqlx::query_as(query)
+                sqlx::query_as(&query)
                     .bind(account_id.as_str())
                     .bind(start_date.format("%Y-%m-%d").to_string())
                     .bind(end_date.format("%Y-%m-%d").to_string()),
@@ -235,17 +312,9 @@ impl BalanceHistoryRepository for SqliteBalanceHistoryRepository {
         account_id: &AccountId,
         date: NaiveDate,
     ) -> Result<Option<BalanceHistoryDailySummary>, DomainError> {
-        let query = r#"
-            WITH daily_summary AS (
-                SELECT
-                    DATE(recorded_at) AS check_in_date,
-                    MAX(total_quota) AS daily_total_quota,
-                    MAX(current_balance) AS daily_balance,
-                    MAX(total_consumed) AS daily_consumed
-                FROM balance_history
-                WHERE account_id = ?1
-                GROUP BY DATE(recorded_at)
-            )
+        let query = format!(
+            r#"
+            {DAILY_SUMMARY_CTE}
             SELECT
                 check_in_date,
                 daily_total_quota,
@@ -254,12 +323,13 @@ impl BalanceHistoryRepository for SqliteBalanceHistoryRepository {
             FROM daily_summary
             WHERE check_in_date = ?2
             LIMIT 1
-        "#;
+        "#
+        );
 
         let row: Option<DailySummaryRow> = self
             .base
             .fetch_optional(
-                sqlx::query_as(query)
+                sqlx::query_as(&query)
                     .bind(account_id.as_str())
                     .bind(date.format("%Y-%m-%d").to_string()),
                 "Find daily summary",
@@ -270,7 +340,11 @@ impl BalanceHistoryRepository for SqliteBalanceHistoryRepository {
     }
 
     async fn list_distinct_account_ids(&self) -> Result<Vec<AccountId>, DomainError> {
-        let query = "SELECT DISTINCT account_id FROM balance_history";
+        let query = r#"
+            SELECT account_id FROM balance_history
+            UNION
+            SELECT account_id FROM balance_history_daily
+        "#;
         let ids: Vec<String> = sqlx::query_scalar(query)
             .fetch_all(self.base.pool())
             .await
@@ -281,4 +355,189 @@ impl BalanceHistoryRepository for SqliteBalanceHistoryRepository {
             .map(|id| AccountId::from_string(&id))
             .collect())
     }
+
+    async fn downsample_before(&self, cutoff: NaiveDate) -> Result<DownsampleReport, DomainError> {
+        let cutoff = cutoff.format("%Y-%m-%d").to_string();
+        let mut tx = self
+            .base
+            .pool()
+            .begin()
+            .await
+            .map_repo_error("Begin balance history downsample")?;
+
+        // Merge eligible raw rows into daily aggregates. When an aggregate for
+        // the day already exists (earlier run), widen min/max and keep the
+        // newest "last" values.
+        let aggregate_query = format!(
+            r#"
+            WITH eligible AS (
+                SELECT
+                    id,
+                    account_id,
+                    DATE(recorded_at) AS record_date,
+                    current_balance,
+                    total_consumed,
+                    total_quota,
+                    recorded_at,
+                    ROW_NUMBER() OVER (
+                        PARTITION BY account_id, DATE(recorded_at)
+                        ORDER BY recorded_at DESC
+                    ) AS rn
+                FROM balance_history
+                WHERE {DOWNSAMPLE_ELIGIBLE_FILTER}
+            ),
+            grouped AS (
+                SELECT
+                    account_id,
+                    record_date,
+                    MIN(current_balance) AS min_balance,
+                    MAX(current_balance) AS max_balance,
+                    COUNT(*) AS sample_count
+                FROM eligible
+                GROUP BY account_id, record_date
+            )
+            INSERT INTO balance_history_daily (
+                account_id,
+                record_date,
+                min_balance,
+                max_balance,
+                last_balance,
+                last_total_consumed,
+                last_total_quota,
+                sample_count,
+                last_recorded_at
+            )
+            SELECT
+                g.account_id,
+                g.record_date,
+                g.min_balance,
+                g.max_balance,
+                e.current_balance,
+                e.total_consumed,
+                e.total_quota,
+                g.sample_count,
+                e.recorded_at
+            FROM grouped g
+            JOIN eligible e
+              ON e.account_id = g.account_id
+             AND e.record_date = g.record_date
+             AND e.rn = 1
+            WHERE 1
+            ON CONFLICT(account_id, record_date) DO UPDATE SET
+                min_balance = MIN(balance_history_daily.min_balance, excluded.min_balance),
+                max_balance = MAX(balance_history_daily.max_balance, excluded.max_balance),
+                last_balance = CASE
+                    WHEN excluded.last_recorded_at >= balance_history_daily.last_recorded_at
+                    THEN excluded.last_balance ELSE balance_history_daily.last_balance END,
+                last_total_consumed = CASE
+                    WHEN excluded.last_recorded_at >= balance_history_daily.last_recorded_at
+                    THEN excluded.last_total_consumed ELSE balance_history_daily.last_total_consumed END,
+                last_total_quota = CASE
+                    WHEN excluded.last_recorded_at >= balance_history_daily.last_recorded_at
+                    THEN excluded.last_total_quota ELSE balance_history_daily.last_total_quota END,
+                sample_count = balance_history_daily.sample_count + excluded.sample_count,
+                last_recorded_at = MAX(balance_history_daily.last_recorded_at, excluded.last_recorded_at)
+        "#
+        );
+
+        let daily_rows_written = sqlx::query(&aggregate_query)
+            .bind(&cutoff)
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Aggregate balance history into daily rows")?
+            .rows_affected();
+
+        let delete_query =
+            format!("DELETE FROM balance_history WHERE {DOWNSAMPLE_ELIGIBLE_FILTER}");
+        let raw_rows_removed = sqlx::query(&delete_query)
+            .bind(&cutoff)
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Delete downsampled balance history rows")?
+            .rows_affected();
+
+        tx.commit()
+            .await
+            .map_repo_error("Commit balance history downsample")?;
+
+        Ok(DownsampleReport {
+            raw_rows_removed,
+            daily_rows_written,
+        })
+    }
+
+    async fn list_for_export(
+        &self,
+        account_ids: &[AccountId],
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<BalanceHistoryExportRow>, DomainError> {
+        let account_filter = if account_ids.is_empty() {
+            String::new()
+        } else {
+            let placeholders = (3..account_ids.len() + 3)
+                .map(|i| format!("?{}", i))
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("AND account_id IN ({})", placeholders)
+        };
+
+        let query = format!(
+            r#"
+            SELECT
+                account_id,
+                DATE(recorded_at) AS record_date,
+                recorded_at,
+                current_balance,
+                total_consumed,
+                total_quota,
+                current_balance AS min_balance,
+                current_balance AS max_balance,
+                1 AS sample_count,
+                0 AS downsampled
+            FROM balance_history
+            WHERE DATE(recorded_at) >= ?1
+              AND DATE(recorded_at) <= ?2
+              {account_filter}
+            UNION ALL
+            SELECT
+                account_id,
+                record_date,
+                last_recorded_at AS recorded_at,
+                last_balance AS current_balance,
+                last_total_consumed AS total_consumed,
+                last_total_quota AS total_quota,
+                min_balance,
+                max_balance,
+                sample_count,
+                1 AS downsampled
+            FROM balance_history_daily
+            WHERE record_date >= ?1
+              AND record_date <= ?2
+              {account_filter}
+            ORDER BY account_id ASC, record_date ASC, recorded_at ASC
+        "#
+        );
+
+        let mut query_builder = sqlx::query_as::<_, ExportRow>(&query)
+            .bind(start_date.format("%Y-%m-%d").to_string())
+            .bind(end_date.format("%Y-%m-%d").to_string());
+        for account_id in account_ids {
+            query_builder = query_builder.bind(account_id.as_str());
+        }
+
+        let rows = self
+            .base
+            .fetch_all(query_builder, "List balance history for export")
+            .await?;
+
+        rows.into_iter().map(|r| r.try_into_export_row()).collect()
+    }
+
+    async fn compact(&self) -> Result<(), DomainError> {
+        self.base
+            .execute(sqlx::query("VACUUM"), "Vacuum database")
+            .await?;
+        Ok(())
+    }
 }
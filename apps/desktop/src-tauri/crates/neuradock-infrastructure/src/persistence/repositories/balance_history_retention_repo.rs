@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool};
+use std::sync::Arc;
+
+use neuradock_domain::balance_history::{
+    BalanceHistoryRetentionPolicy, BalanceHistoryRetentionRepository,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+
+/// SQLite implementation of BalanceHistoryRetentionRepository
+pub struct SqliteBalanceHistoryRetentionRepository {
+    pool: Arc<SqlitePool>,
+}
+
+impl SqliteBalanceHistoryRetentionRepository {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BalanceHistoryRetentionRepository for SqliteBalanceHistoryRetentionRepository {
+    async fn get(&self) -> Result<BalanceHistoryRetentionPolicy, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT enabled, raw_retention_days, vacuum_after_cleanup, last_run_at
+            FROM balance_history_retention
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load balance history retention policy")?;
+
+        let Some(row) = row else {
+            return Ok(BalanceHistoryRetentionPolicy::default());
+        };
+
+        let enabled: bool = row.get("enabled");
+        let raw_retention_days: i64 = row.get("raw_retention_days");
+        let vacuum_after_cleanup: bool = row.get("vacuum_after_cleanup");
+        let last_run_at: Option<String> = row.get("last_run_at");
+
+        let last_run_at = last_run_at
+            .map(|value| {
+                value
+                    .parse::<DateTime<Utc>>()
+                    .map_err(|e| DomainError::Repository(format!("Invalid last_run_at: {}", e)))
+            })
+            .transpose()?;
+
+        Ok(BalanceHistoryRetentionPolicy::restore(
+            enabled,
+            raw_retention_days.max(0) as u32,
+            vacuum_after_cleanup,
+            last_run_at,
+        ))
+    }
+
+    async fn save(&self, policy: &BalanceHistoryRetentionPolicy) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO balance_history_retention (
+                id, enabled, raw_retention_days, vacuum_after_cleanup, last_run_at, updated_at
+            )
+            VALUES (1, ?, ?, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            ON CONFLICT(id) DO UPDATE SET
+                enabled = excluded.enabled,
+                raw_retention_days = excluded.raw_retention_days,
+                vacuum_after_cleanup = excluded.vacuum_after_cleanup,
+                last_run_at = excluded.last_run_at,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(policy.is_enabled())
+        .bind(policy.raw_retention_days() as i64)
+        .bind(policy.vacuum_after_cleanup())
+        .bind(policy.last_run_at().map(|at| at.to_rfc3339()))
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save balance history retention policy")?;
+
+        Ok(())
+    }
+
+    async fn mark_run(&self, at: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO balance_history_retention (id, last_run_at, updated_at)
+            VALUES (1, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            ON CONFLICT(id) DO UPDATE SET
+                last_run_at = excluded.last_run_at,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(at.to_rfc3339())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to record balance history maintenance run")?;
+
+        Ok(())
+    }
+}
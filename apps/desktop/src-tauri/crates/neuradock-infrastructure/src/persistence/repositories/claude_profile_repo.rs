@@ -0,0 +1,288 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use neuradock_domain::claude_profile::{
+    ActiveClaudeProfile, ClaudeModelMapping, ClaudeProfile, ClaudeProfileConfig, ClaudeProfileId,
+    ClaudeProfileRepository,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+use crate::security::EncryptionService;
+
+#[derive(FromRow)]
+struct ClaudeProfileRow {
+    id: i64,
+    name: String,
+    auth_token: String, // encrypted
+    base_url: String,
+    haiku_model: Option<String>,
+    sonnet_model: Option<String>,
+    opus_model: Option<String>,
+    extra_env: String, // encrypted JSON
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(FromRow)]
+struct ActiveProfileRow {
+    profile_id: i64,
+    applied_env: String,
+    applied_at: String,
+}
+
+fn parse_timestamp(value: &str, field: &str) -> Result<DateTime<Utc>, DomainError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| DomainError::DataIntegrity(format!("Invalid {}: {}", field, e)))
+}
+
+const SELECT_PROFILE: &str = r#"
+    SELECT id, name, auth_token, base_url, haiku_model, sonnet_model, opus_model,
+           extra_env, created_at, updated_at
+    FROM claude_profiles
+"#;
+
+/// SQLite implementation of ClaudeProfileRepository
+///
+/// The token and the extra env (which may hold secrets too) are encrypted.
+pub struct SqliteClaudeProfileRepository {
+    pool: Arc<SqlitePool>,
+    encryption: Arc<EncryptionService>,
+}
+
+impl SqliteClaudeProfileRepository {
+    pub fn new(pool: Arc<SqlitePool>, encryption: Arc<EncryptionService>) -> Self {
+        Self { pool, encryption }
+    }
+
+    fn encrypt(&self, value: &str, field: &str) -> Result<String, DomainError> {
+        self.encryption
+            .encrypt(value)
+            .map_err(|e| DomainError::DataIntegrity(format!("Failed to encrypt {}: {}", field, e)))
+    }
+
+    fn decrypt(&self, value: &str, field: &str, id: i64) -> Result<String, DomainError> {
+        self.encryption.decrypt(value).map_err(|e| {
+            DomainError::DataIntegrity(format!(
+                "Failed to decrypt {} for Claude profile {}: {}",
+                field, id, e
+            ))
+        })
+    }
+
+    fn encrypted_fields(&self, profile: &ClaudeProfile) -> Result<(String, String), DomainError> {
+        let extra_env = serde_json::to_string(profile.extra_env())
+            .map_err(|e| DomainError::Repository(format!("Failed to encode extra env: {}", e)))?;
+        Ok((
+            self.encrypt(profile.auth_token(), "profile token")?,
+            self.encrypt(&extra_env, "extra env")?,
+        ))
+    }
+
+    fn row_to_profile(&self, row: ClaudeProfileRow) -> Result<ClaudeProfile, DomainError> {
+        let auth_token = self.decrypt(&row.auth_token, "token", row.id)?;
+        let extra_env: BTreeMap<String, String> =
+            serde_json::from_str(&self.decrypt(&row.extra_env, "extra env", row.id)?).map_err(
+                |e| {
+                    DomainError::DataIntegrity(format!(
+                        "Invalid extra env for Claude profile {}: {}",
+                        row.id, e
+                    ))
+                },
+            )?;
+
+        Ok(ClaudeProfile::restore(
+            ClaudeProfileId::new(row.id),
+            ClaudeProfileConfig {
+                name: row.name,
+                auth_token,
+                base_url: row.base_url,
+                models: ClaudeModelMapping {
+                    haiku: row.haiku_model,
+                    sonnet: row.sonnet_model,
+                    opus: row.opus_model,
+                },
+                extra_env,
+            },
+            parse_timestamp(&row.created_at, "created_at")?,
+            parse_timestamp(&row.updated_at, "updated_at")?,
+        ))
+    }
+}
+
+#[async_trait]
+impl ClaudeProfileRepository for SqliteClaudeProfileRepository {
+    async fn create(&self, profile: &ClaudeProfile) -> Result<ClaudeProfileId, DomainError> {
+        let (auth_token, extra_env) = self.encrypted_fields(profile)?;
+        let models = profile.models();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO claude_profiles (
+                name, auth_token, base_url, haiku_model, sonnet_model, opus_model,
+                extra_env, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(profile.name())
+        .bind(auth_token)
+        .bind(profile.base_url())
+        .bind(models.haiku.as_deref())
+        .bind(models.sonnet.as_deref())
+        .bind(models.opus.as_deref())
+        .bind(extra_env)
+        .bind(profile.created_at().to_rfc3339())
+        .bind(profile.updated_at().to_rfc3339())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to create Claude profile")?;
+
+        Ok(ClaudeProfileId::new(result.last_insert_rowid()))
+    }
+
+    async fn update(&self, profile: &ClaudeProfile) -> Result<(), DomainError> {
+        let id = profile.id().ok_or_else(|| {
+            DomainError::NotFound("Profile ID is required for update".to_string())
+        })?;
+        let (auth_token, extra_env) = self.encrypted_fields(profile)?;
+        let models = profile.models();
+
+        sqlx::query(
+            r#"
+            UPDATE claude_profiles
+            SET name = ?, auth_token = ?, base_url = ?, haiku_model = ?, sonnet_model = ?,
+                opus_model = ?, extra_env = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(profile.name())
+        .bind(auth_token)
+        .bind(profile.base_url())
+        .bind(models.haiku.as_deref())
+        .bind(models.sonnet.as_deref())
+        .bind(models.opus.as_deref())
+        .bind(extra_env)
+        .bind(profile.updated_at().to_rfc3339())
+        .bind(id.value())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to update Claude profile")?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &ClaudeProfileId) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_repo_error("Failed to begin transaction")?;
+
+        sqlx::query("DELETE FROM claude_active_profile WHERE profile_id = ?")
+            .bind(id.value())
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Failed to clear active Claude profile")?;
+        sqlx::query("DELETE FROM claude_profiles WHERE id = ?")
+            .bind(id.value())
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Failed to delete Claude profile")?;
+
+        tx.commit()
+            .await
+            .map_repo_error("Failed to commit transaction")?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ClaudeProfileId) -> Result<Option<ClaudeProfile>, DomainError> {
+        let row =
+            sqlx::query_as::<_, ClaudeProfileRow>(&format!("{} WHERE id = ?", SELECT_PROFILE))
+                .bind(id.value())
+                .fetch_optional(self.pool.as_ref())
+                .await
+                .map_repo_error("Failed to load Claude profile")?;
+
+        row.map(|row| self.row_to_profile(row)).transpose()
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<ClaudeProfile>, DomainError> {
+        let row =
+            sqlx::query_as::<_, ClaudeProfileRow>(&format!("{} WHERE name = ?", SELECT_PROFILE))
+                .bind(name)
+                .fetch_optional(self.pool.as_ref())
+                .await
+                .map_repo_error("Failed to load Claude profile")?;
+
+        row.map(|row| self.row_to_profile(row)).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<ClaudeProfile>, DomainError> {
+        let rows =
+            sqlx::query_as::<_, ClaudeProfileRow>(&format!("{} ORDER BY name", SELECT_PROFILE))
+                .fetch_all(self.pool.as_ref())
+                .await
+                .map_repo_error("Failed to list Claude profiles")?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_profile(row))
+            .collect()
+    }
+
+    async fn get_active(&self) -> Result<Option<ActiveClaudeProfile>, DomainError> {
+        let row = sqlx::query_as::<_, ActiveProfileRow>(
+            "SELECT profile_id, applied_env, applied_at FROM claude_active_profile WHERE id = 1",
+        )
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load active Claude profile")?;
+
+        row.map(|row| {
+            Ok(ActiveClaudeProfile {
+                profile_id: ClaudeProfileId::new(row.profile_id),
+                applied_at: parse_timestamp(&row.applied_at, "applied_at")?,
+                applied_env: serde_json::from_str(&row.applied_env).map_err(|e| {
+                    DomainError::DataIntegrity(format!("Invalid applied env: {}", e))
+                })?,
+            })
+        })
+        .transpose()
+    }
+
+    async fn set_active(&self, active: &ActiveClaudeProfile) -> Result<(), DomainError> {
+        let applied_env = serde_json::to_string(&active.applied_env)
+            .map_err(|e| DomainError::Repository(format!("Failed to encode applied env: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO claude_active_profile (id, profile_id, applied_env, applied_at)
+            VALUES (1, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                profile_id = excluded.profile_id,
+                applied_env = excluded.applied_env,
+                applied_at = excluded.applied_at
+            "#,
+        )
+        .bind(active.profile_id.value())
+        .bind(applied_env)
+        .bind(active.applied_at.to_rfc3339())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save active Claude profile")?;
+
+        Ok(())
+    }
+
+    async fn clear_active(&self) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM claude_active_profile")
+            .execute(self.pool.as_ref())
+            .await
+            .map_repo_error("Failed to clear active Claude profile")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool};
+use std::sync::Arc;
+
+use neuradock_domain::codex::{
+    CodexAccountId, CodexAutoSwitchEvent, CodexAutoSwitchPolicy, CodexAutoSwitchReason,
+    CodexAutoSwitchRepository,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+
+/// SQLite implementation of CodexAutoSwitchRepository
+pub struct SqliteCodexAutoSwitchRepository {
+    pool: Arc<SqlitePool>,
+}
+
+impl SqliteCodexAutoSwitchRepository {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+fn parse_timestamp(
+    value: Option<String>,
+    column: &str,
+) -> Result<Option<DateTime<Utc>>, DomainError> {
+    value
+        .map(|value| {
+            value
+                .parse::<DateTime<Utc>>()
+                .map_err(|e| DomainError::Repository(format!("Invalid {}: {}", column, e)))
+        })
+        .transpose()
+}
+
+#[async_trait]
+impl CodexAutoSwitchRepository for SqliteCodexAutoSwitchRepository {
+    async fn get_policy(&self) -> Result<CodexAutoSwitchPolicy, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT enabled, threshold_percent, poll_interval_minutes, switch_back,
+                   home_account_id, switch_back_at, last_checked_at
+            FROM codex_auto_switch_policy
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load Codex auto-switch policy")?;
+
+        let Some(row) = row else {
+            return Ok(CodexAutoSwitchPolicy::default());
+        };
+
+        let threshold_percent: i64 = row.get("threshold_percent");
+        let poll_interval_minutes: i64 = row.get("poll_interval_minutes");
+        let home_account_id: Option<String> = row.get("home_account_id");
+
+        Ok(CodexAutoSwitchPolicy::restore(
+            row.get("enabled"),
+            threshold_percent.max(0) as u32,
+            poll_interval_minutes.max(0) as u32,
+            row.get("switch_back"),
+            home_account_id.map(|id| CodexAccountId::from_string(&id)),
+            parse_timestamp(row.get("switch_back_at"), "switch_back_at")?,
+            parse_timestamp(row.get("last_checked_at"), "last_checked_at")?,
+        ))
+    }
+
+    async fn save_policy(&self, policy: &CodexAutoSwitchPolicy) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO codex_auto_switch_policy (
+                id, enabled, threshold_percent, poll_interval_minutes, switch_back,
+                home_account_id, switch_back_at, last_checked_at, updated_at
+            )
+            VALUES (1, ?, ?, ?, ?, ?, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            ON CONFLICT(id) DO UPDATE SET
+                enabled = excluded.enabled,
+                threshold_percent = excluded.threshold_percent,
+                poll_interval_minutes = excluded.poll_interval_minutes,
+                switch_back = excluded.switch_back,
+                home_account_id = excluded.home_account_id,
+                switch_back_at = excluded.switch_back_at,
+                last_checked_at = excluded.last_checked_at,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(policy.is_enabled())
+        .bind(policy.threshold_percent() as i64)
+        .bind(policy.poll_interval_minutes() as i64)
+        .bind(policy.switch_back())
+        .bind(policy.home_account_id().map(|id| id.as_str().to_string()))
+        .bind(policy.switch_back_at().map(|at| at.to_rfc3339()))
+        .bind(policy.last_checked_at().map(|at| at.to_rfc3339()))
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save Codex auto-switch policy")?;
+
+        Ok(())
+    }
+
+    async fn record_switch(&self, event: &CodexAutoSwitchEvent) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO codex_auto_switch_log (
+                from_account_id, from_email, to_account_id, to_email, reason,
+                from_used_percent, switched_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(
+            event
+                .from_account_id
+                .as_ref()
+                .map(|id| id.as_str().to_string()),
+        )
+        .bind(&event.from_email)
+        .bind(event.to_account_id.as_str())
+        .bind(&event.to_email)
+        .bind(event.reason.as_str())
+        .bind(event.from_used_percent)
+        .bind(event.switched_at.to_rfc3339())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to record Codex auto-switch")?;
+
+        Ok(())
+    }
+
+    async fn recent_switches(&self, limit: u32) -> Result<Vec<CodexAutoSwitchEvent>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT from_account_id, from_email, to_account_id, to_email, reason,
+                   from_used_percent, switched_at
+            FROM codex_auto_switch_log
+            ORDER BY switched_at DESC, id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(limit as i64)
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load Codex auto-switch log")?;
+
+        rows.into_iter()
+            .map(|row| {
+                let from_account_id: Option<String> = row.get("from_account_id");
+                let to_account_id: String = row.get("to_account_id");
+                let reason: String = row.get("reason");
+                let switched_at = parse_timestamp(row.get("switched_at"), "switched_at")?
+                    .ok_or_else(|| DomainError::Repository("Missing switched_at".to_string()))?;
+
+                Ok(CodexAutoSwitchEvent {
+                    from_account_id: from_account_id.map(|id| CodexAccountId::from_string(&id)),
+                    from_email: row.get("from_email"),
+                    to_account_id: CodexAccountId::from_string(&to_account_id),
+                    to_email: row.get("to_email"),
+                    reason: CodexAutoSwitchReason::parse(&reason),
+                    from_used_percent: row.get("from_used_percent"),
+                    switched_at,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool};
+use std::sync::Arc;
+
+use neuradock_domain::codex::{CodexTokenRefreshPolicy, CodexTokenRefreshPolicyRepository};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+
+/// SQLite implementation of CodexTokenRefreshPolicyRepository
+pub struct SqliteCodexTokenRefreshPolicyRepository {
+    pool: Arc<SqlitePool>,
+}
+
+impl SqliteCodexTokenRefreshPolicyRepository {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CodexTokenRefreshPolicyRepository for SqliteCodexTokenRefreshPolicyRepository {
+    async fn get(&self) -> Result<CodexTokenRefreshPolicy, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT enabled, refresh_before_minutes, token_url, last_run_at
+            FROM codex_token_refresh_policy
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load Codex token refresh policy")?;
+
+        let Some(row) = row else {
+            return Ok(CodexTokenRefreshPolicy::default());
+        };
+
+        let enabled: bool = row.get("enabled");
+        let refresh_before_minutes: i64 = row.get("refresh_before_minutes");
+        let token_url: String = row.get("token_url");
+        let last_run_at: Option<String> = row.get("last_run_at");
+
+        let last_run_at = last_run_at
+            .map(|value| {
+                value
+                    .parse::<DateTime<Utc>>()
+                    .map_err(|e| DomainError::Repository(format!("Invalid last_run_at: {}", e)))
+            })
+            .transpose()?;
+
+        Ok(CodexTokenRefreshPolicy::restore(
+            enabled,
+            refresh_before_minutes.max(0) as u32,
+            token_url,
+            last_run_at,
+        ))
+    }
+
+    async fn save(&self, policy: &CodexTokenRefreshPolicy) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO codex_token_refresh_policy (
+                id, enabled, refresh_before_minutes, token_url, last_run_at, updated_at
+            )
+            VALUES (1, ?, ?, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            ON CONFLICT(id) DO UPDATE SET
+                enabled = excluded.enabled,
+                refresh_before_minutes = excluded.refresh_before_minutes,
+                token_url = excluded.token_url,
+                last_run_at = excluded.last_run_at,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(policy.is_enabled())
+        .bind(policy.refresh_before_minutes() as i64)
+        .bind(policy.token_url())
+        .bind(policy.last_run_at().map(|at| at.to_rfc3339()))
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save Codex token refresh policy")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use std::sync::Arc;
+
+use neuradock_domain::codex::{
+    CodexAccountId, CodexUsageHistoryRepository, CodexUsageReminderKind,
+    CodexUsageReminderSettings, CodexUsageSnapshot, CodexUsageWindowKind,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+
+/// SQLite implementation of CodexUsageHistoryRepository
+pub struct SqliteCodexUsageHistoryRepository {
+    pool: Arc<SqlitePool>,
+}
+
+impl SqliteCodexUsageHistoryRepository {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+fn parse_timestamp(value: &str, column: &str) -> Result<DateTime<Utc>, DomainError> {
+    value
+        .parse::<DateTime<Utc>>()
+        .map_err(|e| DomainError::Repository(format!("Invalid {}: {}", column, e)))
+}
+
+fn snapshot_from_row(row: &SqliteRow) -> Result<CodexUsageSnapshot, DomainError> {
+    let account_id: String = row.get("account_id");
+    let window_kind: String = row.get("window_kind");
+    let resets_at: Option<String> = row.get("resets_at");
+    let recorded_at: String = row.get("recorded_at");
+
+    Ok(CodexUsageSnapshot {
+        account_id: CodexAccountId::from_string(&account_id),
+        window: CodexUsageWindowKind::parse(&window_kind),
+        used_percent: row.get("used_percent"),
+        window_minutes: row.get("window_minutes"),
+        resets_at: resets_at
+            .map(|value| parse_timestamp(&value, "resets_at"))
+            .transpose()?,
+        recorded_at: parse_timestamp(&recorded_at, "recorded_at")?,
+    })
+}
+
+#[async_trait]
+impl CodexUsageHistoryRepository for SqliteCodexUsageHistoryRepository {
+    async fn record(&self, snapshots: &[CodexUsageSnapshot]) -> Result<(), DomainError> {
+        if snapshots.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_repo_error("Failed to begin transaction")?;
+
+        for snapshot in snapshots {
+            sqlx::query(
+                r#"
+                INSERT INTO codex_usage_snapshots (
+                    account_id, window_kind, used_percent, window_minutes, resets_at, recorded_at
+                )
+                VALUES (?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(snapshot.account_id.as_str())
+            .bind(snapshot.window.as_str())
+            .bind(snapshot.used_percent)
+            .bind(snapshot.window_minutes)
+            .bind(snapshot.resets_at.map(|at| at.to_rfc3339()))
+            .bind(snapshot.recorded_at.to_rfc3339())
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Failed to record Codex usage snapshot")?;
+        }
+
+        tx.commit()
+            .await
+            .map_repo_error("Failed to commit transaction")?;
+
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        account_id: &CodexAccountId,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<CodexUsageSnapshot>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT account_id, window_kind, used_percent, window_minutes, resets_at, recorded_at
+            FROM codex_usage_snapshots
+            WHERE account_id = ? AND recorded_at >= ? AND recorded_at <= ?
+            ORDER BY recorded_at ASC, id ASC
+            "#,
+        )
+        .bind(account_id.as_str())
+        .bind(since.to_rfc3339())
+        .bind(until.to_rfc3339())
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load Codex usage history")?;
+
+        rows.iter().map(snapshot_from_row).collect()
+    }
+
+    async fn latest(&self) -> Result<Vec<CodexUsageSnapshot>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT account_id, window_kind, used_percent, window_minutes, resets_at, recorded_at
+            FROM (
+                SELECT *, ROW_NUMBER() OVER (
+                    PARTITION BY account_id, window_kind
+                    ORDER BY recorded_at DESC, id DESC
+                ) AS rn
+                FROM codex_usage_snapshots
+            )
+            WHERE rn = 1
+            ORDER BY account_id, window_kind
+            "#,
+        )
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load latest Codex usage")?;
+
+        rows.iter().map(snapshot_from_row).collect()
+    }
+
+    async fn delete_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DomainError> {
+        let cutoff = cutoff.to_rfc3339();
+
+        let result = sqlx::query("DELETE FROM codex_usage_snapshots WHERE recorded_at < ?")
+            .bind(&cutoff)
+            .execute(self.pool.as_ref())
+            .await
+            .map_repo_error("Failed to prune Codex usage history")?;
+
+        sqlx::query("DELETE FROM codex_usage_reminders WHERE sent_at < ?")
+            .bind(&cutoff)
+            .execute(self.pool.as_ref())
+            .await
+            .map_repo_error("Failed to prune Codex usage reminders")?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn claim_reminder(
+        &self,
+        account_id: &CodexAccountId,
+        window: CodexUsageWindowKind,
+        kind: CodexUsageReminderKind,
+        cycle: DateTime<Utc>,
+    ) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO codex_usage_reminders (
+                account_id, window_kind, kind, cycle_resets_at, sent_at
+            )
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(account_id.as_str())
+        .bind(window.as_str())
+        .bind(kind.as_str())
+        .bind(cycle.to_rfc3339())
+        .bind(Utc::now().to_rfc3339())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to record Codex usage reminder")?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn get_settings(&self) -> Result<CodexUsageReminderSettings, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT threshold_reminders, reset_reminders
+            FROM codex_usage_reminder_settings
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load Codex usage reminder settings")?;
+
+        Ok(match row {
+            Some(row) => CodexUsageReminderSettings::restore(
+                row.get("threshold_reminders"),
+                row.get("reset_reminders"),
+            ),
+            None => CodexUsageReminderSettings::default(),
+        })
+    }
+
+    async fn save_settings(
+        &self,
+        settings: &CodexUsageReminderSettings,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO codex_usage_reminder_settings (
+                id, threshold_reminders, reset_reminders, updated_at
+            )
+            VALUES (1, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            ON CONFLICT(id) DO UPDATE SET
+                threshold_reminders = excluded.threshold_reminders,
+                reset_reminders = excluded.reset_reminders,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(settings.threshold_reminders())
+        .bind(settings.reset_reminders())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save Codex usage reminder settings")?;
+
+        Ok(())
+    }
+}
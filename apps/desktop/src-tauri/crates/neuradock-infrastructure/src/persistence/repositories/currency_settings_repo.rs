@@ -0,0 +1,131 @@
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use neuradock_domain::currency::{
+    BalanceUnit, CurrencySettings, CurrencySettingsRepository, ProviderBalanceUnit,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+
+/// SQLite implementation of CurrencySettingsRepository
+pub struct SqliteCurrencySettingsRepository {
+    pool: Arc<SqlitePool>,
+}
+
+impl SqliteCurrencySettingsRepository {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CurrencySettingsRepository for SqliteCurrencySettingsRepository {
+    async fn get(&self) -> Result<CurrencySettings, DomainError> {
+        let display_currency: Option<String> =
+            sqlx::query_scalar("SELECT display_currency FROM currency_settings WHERE id = 1")
+                .fetch_optional(self.pool.as_ref())
+                .await
+                .map_repo_error("Failed to load currency settings")?;
+
+        let Some(display_currency) = display_currency else {
+            return Ok(CurrencySettings::default());
+        };
+
+        let exchange_rates = sqlx::query("SELECT currency, units_per_usd FROM exchange_rates")
+            .fetch_all(self.pool.as_ref())
+            .await
+            .map_repo_error("Failed to load exchange rates")?
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get::<String, _>("currency"),
+                    row.get::<f64, _>("units_per_usd"),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut provider_units = HashMap::new();
+        let rows =
+            sqlx::query("SELECT provider_id, unit, conversion_factor FROM provider_balance_units")
+                .fetch_all(self.pool.as_ref())
+                .await
+                .map_repo_error("Failed to load provider balance units")?;
+        for row in rows {
+            let unit: String = row.get("unit");
+            provider_units.insert(
+                row.get::<String, _>("provider_id"),
+                ProviderBalanceUnit {
+                    unit: BalanceUnit::from_str(&unit)?,
+                    conversion_factor: row.get("conversion_factor"),
+                },
+            );
+        }
+
+        Ok(CurrencySettings::restore(
+            display_currency,
+            exchange_rates,
+            provider_units,
+        ))
+    }
+
+    async fn save(&self, settings: &CurrencySettings) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_repo_error("Failed to begin currency settings transaction")?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO currency_settings (id, display_currency, updated_at)
+            VALUES (1, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            ON CONFLICT(id) DO UPDATE SET
+                display_currency = excluded.display_currency,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(settings.display_currency())
+        .execute(&mut *tx)
+        .await
+        .map_repo_error("Failed to save currency settings")?;
+
+        sqlx::query("DELETE FROM exchange_rates")
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Failed to clear exchange rates")?;
+        for (currency, rate) in settings.exchange_rates() {
+            sqlx::query("INSERT INTO exchange_rates (currency, units_per_usd) VALUES (?, ?)")
+                .bind(currency)
+                .bind(rate)
+                .execute(&mut *tx)
+                .await
+                .map_repo_error("Failed to save exchange rate")?;
+        }
+
+        sqlx::query("DELETE FROM provider_balance_units")
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Failed to clear provider balance units")?;
+        for (provider_id, unit) in settings.provider_units() {
+            sqlx::query(
+                "INSERT INTO provider_balance_units (provider_id, unit, conversion_factor) VALUES (?, ?, ?)",
+            )
+            .bind(provider_id)
+            .bind(unit.unit.as_str())
+            .bind(unit.conversion_factor)
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Failed to save provider balance unit")?;
+        }
+
+        tx.commit()
+            .await
+            .map_repo_error("Failed to commit currency settings")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use std::sync::Arc;
+
+use neuradock_domain::events::{DomainEvent, EventStore, StoredEvent};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+
+/// SQLite implementation of EventStore
+pub struct SqliteEventStore {
+    pool: Arc<SqlitePool>,
+}
+
+impl SqliteEventStore {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+fn parse_time(value: &str, column: &str) -> Result<DateTime<Utc>, DomainError> {
+    value
+        .parse::<DateTime<Utc>>()
+        .map_err(|e| DomainError::Repository(format!("Invalid {}: {}", column, e)))
+}
+
+fn event_from_row(row: &SqliteRow) -> Result<StoredEvent, DomainError> {
+    let occurred_at: String = row.get("occurred_at");
+    let recorded_at: String = row.get("recorded_at");
+
+    Ok(StoredEvent {
+        sequence: row.get("sequence"),
+        event_name: row.get("event_name"),
+        aggregate_id: row.get("aggregate_id"),
+        payload: row.get("payload"),
+        occurred_at: parse_time(&occurred_at, "occurred_at")?,
+        recorded_at: parse_time(&recorded_at, "recorded_at")?,
+    })
+}
+
+#[async_trait]
+impl EventStore for SqliteEventStore {
+    async fn append(&self, event: &dyn DomainEvent) -> Result<i64, DomainError> {
+        let payload = event.to_payload()?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO domain_events (event_name, aggregate_id, payload, occurred_at, recorded_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(event.event_name())
+        .bind(event.aggregate_id())
+        .bind(payload)
+        .bind(event.occurred_at().to_rfc3339())
+        .bind(Utc::now().to_rfc3339())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to append domain event")?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn read_after(
+        &self,
+        after_sequence: i64,
+        limit: u32,
+    ) -> Result<Vec<StoredEvent>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT sequence, event_name, aggregate_id, payload, occurred_at, recorded_at
+            FROM domain_events
+            WHERE sequence > ?
+            ORDER BY sequence ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(after_sequence)
+        .bind(limit as i64)
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to read domain events")?;
+
+        rows.iter().map(event_from_row).collect()
+    }
+
+    async fn aggregate_timeline(
+        &self,
+        aggregate_id: &str,
+        before_sequence: Option<i64>,
+        limit: u32,
+    ) -> Result<Vec<StoredEvent>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT sequence, event_name, aggregate_id, payload, occurred_at, recorded_at
+            FROM domain_events
+            WHERE aggregate_id = ?1 AND (?2 IS NULL OR sequence < ?2)
+            ORDER BY sequence DESC
+            LIMIT ?3
+            "#,
+        )
+        .bind(aggregate_id)
+        .bind(before_sequence)
+        .bind(limit as i64)
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to read event timeline")?;
+
+        rows.iter().map(event_from_row).collect()
+    }
+
+    async fn last_sequence(&self) -> Result<i64, DomainError> {
+        let sequence: Option<i64> = sqlx::query_scalar("SELECT MAX(sequence) FROM domain_events")
+            .fetch_one(self.pool.as_ref())
+            .await
+            .map_repo_error("Failed to read last event sequence")?;
+
+        Ok(sequence.unwrap_or(0))
+    }
+
+    async fn checkpoint(&self, subscriber: &str) -> Result<Option<i64>, DomainError> {
+        sqlx::query_scalar(
+            "SELECT last_sequence FROM event_subscriber_checkpoints WHERE subscriber = ?",
+        )
+        .bind(subscriber)
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load subscriber checkpoint")
+    }
+
+    async fn save_checkpoint(&self, subscriber: &str, sequence: i64) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO event_subscriber_checkpoints (subscriber, last_sequence, updated_at)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(subscriber) DO UPDATE SET
+                last_sequence = MAX(last_sequence, excluded.last_sequence),
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(subscriber)
+        .bind(sequence)
+        .bind(Utc::now().to_rfc3339())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save subscriber checkpoint")?;
+
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ use crate::persistence::RepositoryErrorMapper;
 use crate::security::EncryptionService;
 use neuradock_domain::independent_key::{
     IndependentApiKey, IndependentApiKeyConfig, IndependentKeyId, IndependentKeyRepository,
-    KeyProviderType,
+    KeyCredit, KeyProviderType, KeyValidation, KeyValidationStatus,
 };
 use neuradock_domain::shared::DomainError;
 
@@ -23,6 +23,14 @@ struct IndependentKeyRow {
     organization_id: Option<String>,
     description: Option<String>,
     is_active: i64,
+    last_validation_status: Option<String>,
+    last_validation_status_code: Option<i64>,
+    last_validation_message: Option<String>,
+    last_validation_latency_ms: Option<i64>,
+    available_models: Option<String>,
+    credit_total: Option<f64>,
+    credit_used: Option<f64>,
+    last_validated_at: Option<String>,
     created_at: String,
     updated_at: String,
 }
@@ -55,6 +63,36 @@ impl IndependentKeyRow {
             .map_err(|e| DomainError::DataIntegrity(format!("Invalid updated_at: {}", e)))?
             .with_timezone(&Utc);
 
+        let last_validation = match (&self.last_validation_status, &self.last_validated_at) {
+            (Some(status), Some(validated_at)) => Some(KeyValidation {
+                status: KeyValidationStatus::parse(status),
+                status_code: self
+                    .last_validation_status_code
+                    .and_then(|code| u16::try_from(code).ok()),
+                message: self.last_validation_message.clone(),
+                latency_ms: self.last_validation_latency_ms.unwrap_or(0).max(0) as u64,
+                models: self
+                    .available_models
+                    .as_deref()
+                    .map(serde_json::from_str)
+                    .transpose()
+                    .map_err(|e| {
+                        DomainError::DataIntegrity(format!("Invalid available_models: {}", e))
+                    })?
+                    .unwrap_or_default(),
+                credit: match (self.credit_total, self.credit_used) {
+                    (Some(total), Some(used)) => Some(KeyCredit { total, used }),
+                    _ => None,
+                },
+                validated_at: DateTime::parse_from_rfc3339(validated_at)
+                    .map_err(|e| {
+                        DomainError::DataIntegrity(format!("Invalid last_validated_at: {}", e))
+                    })?
+                    .with_timezone(&Utc),
+            }),
+            _ => None,
+        };
+
         let config = IndependentApiKeyConfig {
             name: self.name,
             provider_type,
@@ -71,7 +109,8 @@ impl IndependentKeyRow {
             self.is_active != 0,
             created_at,
             updated_at,
-        ))
+        )
+        .with_last_validation(last_validation))
     }
 }
 
@@ -151,7 +190,28 @@ impl IndependentKeyRepository for SqliteIndependentKeyRepository {
         .await
         .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "Update independent key"))?;
 
-        Ok(())
+        match key.last_validation() {
+            Some(validation) => self.record_validation(id, validation).await,
+            None => {
+                sqlx::query(
+                    r#"
+                    UPDATE independent_api_keys
+                    SET last_validation_status = NULL, last_validation_status_code = NULL,
+                        last_validation_message = NULL, last_validation_latency_ms = NULL,
+                        available_models = NULL, credit_total = NULL, credit_used = NULL,
+                        last_validated_at = NULL
+                    WHERE id = ?
+                    "#,
+                )
+                .bind(id.value())
+                .execute(&*self.pool)
+                .await
+                .map_err(|e| {
+                    RepositoryErrorMapper::map_sqlx_error(e, "Clear independent key validation")
+                })?;
+                Ok(())
+            }
+        }
     }
 
     async fn delete(&self, id: &IndependentKeyId) -> Result<(), DomainError> {
@@ -171,7 +231,10 @@ impl IndependentKeyRepository for SqliteIndependentKeyRepository {
         let row: Option<IndependentKeyRow> = sqlx::query_as(
             r#"
             SELECT id, name, provider_type, custom_provider_name, api_key, base_url,
-                   organization_id, description, is_active, created_at, updated_at
+                   organization_id, description, is_active, last_validation_status,
+                   last_validation_status_code, last_validation_message,
+                   last_validation_latency_ms, available_models, credit_total, credit_used,
+                   last_validated_at, created_at, updated_at
             FROM independent_api_keys
             WHERE id = ?
             "#,
@@ -191,7 +254,10 @@ impl IndependentKeyRepository for SqliteIndependentKeyRepository {
         let rows: Vec<IndependentKeyRow> = sqlx::query_as(
             r#"
             SELECT id, name, provider_type, custom_provider_name, api_key, base_url,
-                   organization_id, description, is_active, created_at, updated_at
+                   organization_id, description, is_active, last_validation_status,
+                   last_validation_status_code, last_validation_message,
+                   last_validation_latency_ms, available_models, credit_total, credit_used,
+                   last_validated_at, created_at, updated_at
             FROM independent_api_keys
             ORDER BY created_at DESC
             "#,
@@ -212,7 +278,10 @@ impl IndependentKeyRepository for SqliteIndependentKeyRepository {
         let rows: Vec<IndependentKeyRow> = sqlx::query_as(
             r#"
             SELECT id, name, provider_type, custom_provider_name, api_key, base_url,
-                   organization_id, description, is_active, created_at, updated_at
+                   organization_id, description, is_active, last_validation_status,
+                   last_validation_status_code, last_validation_message,
+                   last_validation_latency_ms, available_models, credit_total, credit_used,
+                   last_validated_at, created_at, updated_at
             FROM independent_api_keys
             WHERE provider_type = ?
             ORDER BY created_at DESC
@@ -234,7 +303,10 @@ impl IndependentKeyRepository for SqliteIndependentKeyRepository {
         let rows: Vec<IndependentKeyRow> = sqlx::query_as(
             r#"
             SELECT id, name, provider_type, custom_provider_name, api_key, base_url,
-                   organization_id, description, is_active, created_at, updated_at
+                   organization_id, description, is_active, last_validation_status,
+                   last_validation_status_code, last_validation_message,
+                   last_validation_latency_ms, available_models, credit_total, credit_used,
+                   last_validated_at, created_at, updated_at
             FROM independent_api_keys
             WHERE is_active = 1
             ORDER BY created_at DESC
@@ -248,4 +320,41 @@ impl IndependentKeyRepository for SqliteIndependentKeyRepository {
             .map(|r| r.try_into_domain(&self.encryption))
             .collect()
     }
+
+    async fn record_validation(
+        &self,
+        id: &IndependentKeyId,
+        validation: &KeyValidation,
+    ) -> Result<(), DomainError> {
+        let models = serde_json::to_string(&validation.models).map_err(|e| {
+            DomainError::DataIntegrity(format!("Failed to serialize models: {}", e))
+        })?;
+
+        sqlx::query(
+            r#"
+            UPDATE independent_api_keys
+            SET last_validation_status = ?, last_validation_status_code = ?,
+                last_validation_message = ?, last_validation_latency_ms = ?,
+                available_models = ?, credit_total = ?, credit_used = ?,
+                last_validated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(validation.status.as_str())
+        .bind(validation.status_code.map(i64::from))
+        .bind(validation.message.as_deref())
+        .bind(validation.latency_ms as i64)
+        .bind(&models)
+        .bind(validation.credit.map(|credit| credit.total))
+        .bind(validation.credit.map(|credit| credit.used))
+        .bind(validation.validated_at.to_rfc3339())
+        .bind(id.value())
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| {
+            RepositoryErrorMapper::map_sqlx_error(e, "Record independent key validation")
+        })?;
+
+        Ok(())
+    }
 }
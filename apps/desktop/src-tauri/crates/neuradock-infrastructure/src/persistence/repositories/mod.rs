@@ -1,27 +1,49 @@
 pub mod account_repo;
 pub mod ai_chat_service_repo;
+pub mod audit_log_repo;
 pub mod balance_history_repo;
+pub mod balance_history_retention_repo;
 pub mod balance_repo;
+pub mod claude_profile_repo;
 pub mod codex_account_repo;
+pub mod codex_auto_switch_repo;
+pub mod codex_token_refresh_policy_repo;
+pub mod codex_usage_history_repo;
+pub mod currency_settings_repo;
 pub mod custom_node_repository;
+pub mod event_store_repo;
 pub mod independent_key_repo;
+pub mod network_settings_repo;
 pub mod provider_models_repository;
 pub mod provider_repository;
 pub mod proxy_config_repo;
+pub mod proxy_routing_repo;
 pub mod session_repo;
 pub mod token_repository;
+pub mod token_watch_repo;
 pub mod waf_cookies_repository;
 
 pub use account_repo::SqliteAccountRepository;
 pub use ai_chat_service_repo::SqliteAiChatServiceRepository;
+pub use audit_log_repo::SqliteAuditLogRepository;
 pub use balance_history_repo::SqliteBalanceHistoryRepository;
+pub use balance_history_retention_repo::SqliteBalanceHistoryRetentionRepository;
 pub use balance_repo::SqliteBalanceRepository;
+pub use claude_profile_repo::SqliteClaudeProfileRepository;
 pub use codex_account_repo::SqliteCodexAccountRepository;
+pub use codex_auto_switch_repo::SqliteCodexAutoSwitchRepository;
+pub use codex_token_refresh_policy_repo::SqliteCodexTokenRefreshPolicyRepository;
+pub use codex_usage_history_repo::SqliteCodexUsageHistoryRepository;
+pub use currency_settings_repo::SqliteCurrencySettingsRepository;
 pub use custom_node_repository::SqliteCustomProviderNodeRepository;
+pub use event_store_repo::SqliteEventStore;
 pub use independent_key_repo::SqliteIndependentKeyRepository;
+pub use network_settings_repo::SqliteNetworkSettingsRepository;
 pub use provider_models_repository::SqliteProviderModelsRepository;
 pub use provider_repository::SqliteProviderRepository;
 pub use proxy_config_repo::SqliteProxyConfigRepository;
+pub use proxy_routing_repo::SqliteProxyRoutingRepository;
 pub use session_repo::SqliteSessionRepository;
 pub use token_repository::SqliteTokenRepository;
+pub use token_watch_repo::SqliteTokenWatchRepository;
 pub use waf_cookies_repository::SqliteWafCookiesRepository;
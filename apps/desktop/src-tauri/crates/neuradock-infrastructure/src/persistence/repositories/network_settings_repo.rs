@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use neuradock_domain::network_settings::{
+    NetworkPolicySettings, NetworkSettings, NetworkSettingsRepository, ProviderNetworkOverride,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+
+/// SQLite implementation of NetworkSettingsRepository
+pub struct SqliteNetworkSettingsRepository {
+    pool: Arc<SqlitePool>,
+}
+
+impl SqliteNetworkSettingsRepository {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NetworkSettingsRepository for SqliteNetworkSettingsRepository {
+    async fn get(&self) -> Result<NetworkSettings, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT waf_wait_ms, browser_launch_ms, http_request_ms, db_query_ms, max_retries,
+                   initial_backoff_ms, max_backoff_ms, backoff_multiplier, waf_max_attempts
+            FROM network_settings WHERE id = 1
+            "#,
+        )
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load network settings")?;
+
+        let defaults = match row {
+            Some(row) => NetworkPolicySettings {
+                waf_wait_ms: row.get::<i64, _>("waf_wait_ms") as u64,
+                browser_launch_ms: row.get::<i64, _>("browser_launch_ms") as u64,
+                http_request_ms: row.get::<i64, _>("http_request_ms") as u64,
+                db_query_ms: row.get::<i64, _>("db_query_ms") as u64,
+                max_retries: row.get::<i64, _>("max_retries") as u32,
+                initial_backoff_ms: row.get::<i64, _>("initial_backoff_ms") as u64,
+                max_backoff_ms: row.get::<i64, _>("max_backoff_ms") as u64,
+                backoff_multiplier: row.get("backoff_multiplier"),
+                waf_max_attempts: row.get::<i64, _>("waf_max_attempts") as u32,
+            },
+            None => NetworkPolicySettings::default(),
+        };
+
+        let rows = sqlx::query(
+            r#"
+            SELECT provider_id, waf_wait_ms, http_request_ms, max_retries, waf_max_attempts
+            FROM provider_network_overrides
+            "#,
+        )
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load provider network overrides")?;
+
+        let provider_overrides = rows
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get::<String, _>("provider_id"),
+                    ProviderNetworkOverride {
+                        waf_wait_ms: row.get::<Option<i64>, _>("waf_wait_ms").map(|v| v as u64),
+                        http_request_ms: row
+                            .get::<Option<i64>, _>("http_request_ms")
+                            .map(|v| v as u64),
+                        max_retries: row.get::<Option<i64>, _>("max_retries").map(|v| v as u32),
+                        waf_max_attempts: row
+                            .get::<Option<i64>, _>("waf_max_attempts")
+                            .map(|v| v as u32),
+                    },
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        Ok(NetworkSettings::restore(defaults, provider_overrides))
+    }
+
+    async fn save(&self, settings: &NetworkSettings) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_repo_error("Failed to begin network settings transaction")?;
+
+        let defaults = settings.defaults();
+        sqlx::query(
+            r#"
+            INSERT INTO network_settings (
+                id, waf_wait_ms, browser_launch_ms, http_request_ms, db_query_ms, max_retries,
+                initial_backoff_ms, max_backoff_ms, backoff_multiplier, waf_max_attempts, updated_at
+            )
+            VALUES (1, ?, ?, ?, ?, ?, ?, ?, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            ON CONFLICT(id) DO UPDATE SET
+                waf_wait_ms = excluded.waf_wait_ms,
+                browser_launch_ms = excluded.browser_launch_ms,
+                http_request_ms = excluded.http_request_ms,
+                db_query_ms = excluded.db_query_ms,
+                max_retries = excluded.max_retries,
+                initial_backoff_ms = excluded.initial_backoff_ms,
+                max_backoff_ms = excluded.max_backoff_ms,
+                backoff_multiplier = excluded.backoff_multiplier,
+                waf_max_attempts = excluded.waf_max_attempts,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(defaults.waf_wait_ms as i64)
+        .bind(defaults.browser_launch_ms as i64)
+        .bind(defaults.http_request_ms as i64)
+        .bind(defaults.db_query_ms as i64)
+        .bind(defaults.max_retries as i64)
+        .bind(defaults.initial_backoff_ms as i64)
+        .bind(defaults.max_backoff_ms as i64)
+        .bind(defaults.backoff_multiplier)
+        .bind(defaults.waf_max_attempts as i64)
+        .execute(&mut *tx)
+        .await
+        .map_repo_error("Failed to save network settings")?;
+
+        sqlx::query("DELETE FROM provider_network_overrides")
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Failed to clear provider network overrides")?;
+        for (provider_id, value) in settings.provider_overrides() {
+            sqlx::query(
+                r#"
+                INSERT INTO provider_network_overrides
+                    (provider_id, waf_wait_ms, http_request_ms, max_retries, waf_max_attempts)
+                VALUES (?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(provider_id)
+            .bind(value.waf_wait_ms.map(|v| v as i64))
+            .bind(value.http_request_ms.map(|v| v as i64))
+            .bind(value.max_retries.map(|v| v as i64))
+            .bind(value.waf_max_attempts.map(|v| v as i64))
+            .execute(&mut *tx)
+            .await
+            .map_repo_error("Failed to save provider network override")?;
+        }
+
+        tx.commit()
+            .await
+            .map_repo_error("Failed to commit network settings")?;
+
+        Ok(())
+    }
+}
@@ -3,7 +3,9 @@ use chrono::{DateTime, Utc};
 use sqlx::{FromRow, SqlitePool};
 use std::sync::Arc;
 
-use neuradock_domain::provider_models::{ProviderModels, ProviderModelsRepository};
+use neuradock_domain::provider_models::{
+    ModelCatalogDiff, ProviderModels, ProviderModelsRepository, ProviderModelsSnapshot,
+};
 use neuradock_domain::shared::DomainError;
 
 use crate::persistence::unit_of_work::RepositoryErrorMapper;
@@ -18,6 +20,28 @@ struct ProviderModelsRow {
     fetched_at: String,
 }
 
+#[derive(Debug, FromRow)]
+struct ProviderModelsSnapshotRow {
+    id: i64,
+    provider_id: String,
+    models: String, // JSON array
+    fetched_at: String,
+}
+
+/// Snapshots kept per provider, older ones are pruned on save
+const MAX_SNAPSHOTS_PER_PROVIDER: i64 = 50;
+
+fn parse_models(models: &str) -> Result<Vec<String>, DomainError> {
+    serde_json::from_str(models)
+        .map_err(|e| DomainError::Validation(format!("Invalid models JSON: {}", e)))
+}
+
+fn parse_fetched_at(fetched_at: &str) -> Result<DateTime<Utc>, DomainError> {
+    Ok(DateTime::parse_from_rfc3339(fetched_at)
+        .map_err(|e| DomainError::Validation(format!("Invalid fetched_at: {}", e)))?
+        .with_timezone(&Utc))
+}
+
 pub struct SqliteProviderModelsRepository {
     base: SqliteRepositoryBase,
 }
@@ -30,29 +54,60 @@ impl SqliteProviderModelsRepository {
     }
 
     fn row_to_domain(&self, row: ProviderModelsRow) -> Result<ProviderModels, DomainError> {
-        let models: Vec<String> = serde_json::from_str(&row.models)
-            .map_err(|e| DomainError::Validation(format!("Invalid models JSON: {}", e)))?;
-
-        let fetched_at = DateTime::parse_from_rfc3339(&row.fetched_at)
-            .map_err(|e| DomainError::Validation(format!("Invalid fetched_at: {}", e)))?
-            .with_timezone(&Utc);
-
         Ok(ProviderModels {
+            models: parse_models(&row.models)?,
+            fetched_at: parse_fetched_at(&row.fetched_at)?,
+            provider_id: row.provider_id,
+        })
+    }
+
+    fn snapshot_row_to_domain(
+        &self,
+        row: ProviderModelsSnapshotRow,
+    ) -> Result<ProviderModelsSnapshot, DomainError> {
+        Ok(ProviderModelsSnapshot {
+            id: row.id,
+            models: parse_models(&row.models)?,
+            fetched_at: parse_fetched_at(&row.fetched_at)?,
             provider_id: row.provider_id,
-            models,
-            fetched_at,
         })
     }
 }
 
 #[async_trait]
 impl ProviderModelsRepository for SqliteProviderModelsRepository {
-    /// Save or update provider models
-    async fn save(&self, provider_id: &str, models: &[String]) -> Result<(), DomainError> {
+    /// Save or update provider models, recording a snapshot when the list changed
+    async fn save(
+        &self,
+        provider_id: &str,
+        models: &[String],
+    ) -> Result<Option<ModelCatalogDiff>, DomainError> {
         let models_json = serde_json::to_string(models)
             .map_err(|e| DomainError::Validation(format!("Failed to serialize models: {}", e)))?;
         let now = Utc::now().to_rfc3339();
 
+        let mut tx =
+            self.base.pool().begin().await.map_err(|e| {
+                RepositoryErrorMapper::map_sqlx_error(e, "Begin save provider models")
+            })?;
+
+        let previous: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT models FROM provider_models_snapshots
+            WHERE provider_id = ?
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(provider_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "Find latest models snapshot"))?;
+        let diff = previous
+            .map(|previous| parse_models(&previous))
+            .transpose()?
+            .map(|previous| ModelCatalogDiff::between(&previous, models));
+
         sqlx::query(
             r#"
             INSERT INTO provider_models (provider_id, models, fetched_at)
@@ -63,13 +118,48 @@ impl ProviderModelsRepository for SqliteProviderModelsRepository {
             "#,
         )
         .bind(provider_id)
-        .bind(models_json)
-        .bind(now)
-        .execute(self.base.pool())
+        .bind(&models_json)
+        .bind(&now)
+        .execute(&mut *tx)
         .await
         .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "Save provider models"))?;
 
-        Ok(())
+        if diff.as_ref().is_none_or(|diff| !diff.is_empty()) {
+            sqlx::query(
+                "INSERT INTO provider_models_snapshots (provider_id, models, fetched_at) VALUES (?, ?, ?)",
+            )
+            .bind(provider_id)
+            .bind(&models_json)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "Save models snapshot"))?;
+
+            sqlx::query(
+                r#"
+                DELETE FROM provider_models_snapshots
+                WHERE provider_id = ?
+                  AND id NOT IN (
+                      SELECT id FROM provider_models_snapshots
+                      WHERE provider_id = ?
+                      ORDER BY id DESC
+                      LIMIT ?
+                  )
+                "#,
+            )
+            .bind(provider_id)
+            .bind(provider_id)
+            .bind(MAX_SNAPSHOTS_PER_PROVIDER)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "Prune models snapshots"))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "Commit save provider models"))?;
+
+        Ok(diff)
     }
 
     /// Find models by provider ID
@@ -108,7 +198,33 @@ impl ProviderModelsRepository for SqliteProviderModelsRepository {
         }
     }
 
-    /// Delete models for a provider
+    /// Model list snapshots of a provider, newest first
+    async fn find_snapshots(
+        &self,
+        provider_id: &str,
+        limit: i64,
+    ) -> Result<Vec<ProviderModelsSnapshot>, DomainError> {
+        let rows = sqlx::query_as::<_, ProviderModelsSnapshotRow>(
+            r#"
+            SELECT id, provider_id, models, fetched_at
+            FROM provider_models_snapshots
+            WHERE provider_id = ?
+            ORDER BY id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(provider_id)
+        .bind(limit)
+        .fetch_all(self.base.pool())
+        .await
+        .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "Find models snapshots"))?;
+
+        rows.into_iter()
+            .map(|row| self.snapshot_row_to_domain(row))
+            .collect()
+    }
+
+    /// Delete models and their snapshot history for a provider
     async fn delete_by_provider(&self, provider_id: &str) -> Result<(), DomainError> {
         sqlx::query("DELETE FROM provider_models WHERE provider_id = ?")
             .bind(provider_id)
@@ -116,6 +232,12 @@ impl ProviderModelsRepository for SqliteProviderModelsRepository {
             .await
             .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "Delete provider models"))?;
 
+        sqlx::query("DELETE FROM provider_models_snapshots WHERE provider_id = ?")
+            .bind(provider_id)
+            .execute(self.base.pool())
+            .await
+            .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "Delete models snapshots"))?;
+
         Ok(())
     }
 }
@@ -8,6 +8,7 @@ use neuradock_domain::proxy_config::{ProxyConfig, ProxyConfigRepository, ProxyTy
 use neuradock_domain::shared::DomainError;
 
 use crate::persistence::result_ext::ResultExt;
+use crate::security::EncryptionService;
 
 /// Parse datetime string, supporting both old format '2025-12-20 12:34:56' and ISO 8601 format '2025-12-20T12:34:56Z'
 fn parse_datetime_flexible(datetime_str: &str) -> Result<DateTime<Utc>, DomainError> {
@@ -26,11 +27,12 @@ fn parse_datetime_flexible(datetime_str: &str) -> Result<DateTime<Utc>, DomainEr
 /// SQLite implementation of ProxyConfigRepository
 pub struct SqliteProxyConfigRepository {
     pool: Arc<SqlitePool>,
+    encryption: Arc<EncryptionService>,
 }
 
 impl SqliteProxyConfigRepository {
-    pub fn new(pool: Arc<SqlitePool>) -> Self {
-        Self { pool }
+    pub fn new(pool: Arc<SqlitePool>, encryption: Arc<EncryptionService>) -> Self {
+        Self { pool, encryption }
     }
 }
 
@@ -39,7 +41,8 @@ impl ProxyConfigRepository for SqliteProxyConfigRepository {
     async fn get(&self) -> Result<ProxyConfig, DomainError> {
         let row = sqlx::query(
             r#"
-            SELECT enabled, proxy_type, host, port, created_at, updated_at
+            SELECT enabled, proxy_type, host, port, username, password, bypass_domains,
+                   created_at, updated_at
             FROM proxy_settings
             WHERE id = 1
             "#,
@@ -56,16 +59,31 @@ impl ProxyConfigRepository for SqliteProxyConfigRepository {
         let proxy_type_str: String = row.get("proxy_type");
         let host: String = row.get("host");
         let port: i64 = row.get("port");
+        let username: Option<String> = row.get("username");
+        let password: Option<String> = row.get("password");
+        let bypass_domains: String = row.get("bypass_domains");
         let created_at: String = row.get("created_at");
         let updated_at: String = row.get("updated_at");
 
         let proxy_type = ProxyType::from_str(&proxy_type_str)?;
+        let password = password
+            .map(|encrypted| {
+                self.encryption.decrypt(&encrypted).map_err(|e| {
+                    DomainError::DataIntegrity(format!("Failed to decrypt proxy password: {}", e))
+                })
+            })
+            .transpose()?;
+        let bypass_domains: Vec<String> = serde_json::from_str(&bypass_domains)
+            .map_err(|e| DomainError::DataIntegrity(format!("Invalid bypass_domains: {}", e)))?;
 
         let config = ProxyConfig::builder()
             .enabled(enabled)
             .proxy_type(proxy_type)
             .host(host)
             .port(port as u16)
+            .username(username)
+            .password(password)
+            .bypass_domains(bypass_domains)
             .created_at(parse_datetime_flexible(&created_at)?)
             .updated_at(parse_datetime_flexible(&updated_at)?)
             .build();
@@ -74,15 +92,33 @@ impl ProxyConfigRepository for SqliteProxyConfigRepository {
     }
 
     async fn save(&self, config: &ProxyConfig) -> Result<(), DomainError> {
+        let password = config
+            .password()
+            .map(|password| {
+                self.encryption.encrypt(password).map_err(|e| {
+                    DomainError::DataIntegrity(format!("Failed to encrypt proxy password: {}", e))
+                })
+            })
+            .transpose()?;
+        let bypass_domains = serde_json::to_string(config.bypass_domains()).map_err(|e| {
+            DomainError::Repository(format!("Failed to encode bypass domains: {}", e))
+        })?;
+
         sqlx::query(
             r#"
-            INSERT INTO proxy_settings (id, enabled, proxy_type, host, port, created_at, updated_at)
+            INSERT INTO proxy_settings (
+                id, enabled, proxy_type, host, port, username, password, bypass_domains,
+                created_at, updated_at
+            )
             VALUES (
                 1,
                 ?,
                 ?,
                 ?,
                 ?,
+                ?,
+                ?,
+                ?,
                 strftime('%Y-%m-%dT%H:%M:%SZ', 'now'),
                 strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
             )
@@ -91,6 +127,9 @@ impl ProxyConfigRepository for SqliteProxyConfigRepository {
                 proxy_type = excluded.proxy_type,
                 host = excluded.host,
                 port = excluded.port,
+                username = excluded.username,
+                password = excluded.password,
+                bypass_domains = excluded.bypass_domains,
                 updated_at = excluded.updated_at
             "#,
         )
@@ -98,6 +137,9 @@ impl ProxyConfigRepository for SqliteProxyConfigRepository {
         .bind(config.proxy_type().as_str())
         .bind(config.host())
         .bind(config.port() as i64)
+        .bind(config.username())
+        .bind(password)
+        .bind(bypass_domains)
         .execute(self.pool.as_ref())
         .await
         .map_repo_error("Failed to save proxy configuration")?;
@@ -126,6 +168,9 @@ mod tests {
                 proxy_type TEXT NOT NULL DEFAULT 'http' CHECK(proxy_type IN ('http', 'socks5')),
                 host TEXT NOT NULL DEFAULT '',
                 port INTEGER NOT NULL DEFAULT 0 CHECK(port >= 0 AND port <= 65535),
+                username TEXT,
+                password TEXT,
+                bypass_domains TEXT NOT NULL DEFAULT '[]',
                 created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
                 updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
             );
@@ -141,10 +186,15 @@ mod tests {
         pool
     }
 
+    fn test_repo(pool: SqlitePool) -> SqliteProxyConfigRepository {
+        let encryption = EncryptionService::from_password("test_password", &[7u8; 32]).unwrap();
+        SqliteProxyConfigRepository::new(Arc::new(pool), Arc::new(encryption))
+    }
+
     #[tokio::test]
     async fn test_get_default_config() {
         let pool = setup_test_db().await;
-        let repo = SqliteProxyConfigRepository::new(Arc::new(pool));
+        let repo = test_repo(pool);
 
         let config = repo.get().await.unwrap();
         assert!(!config.is_enabled());
@@ -156,7 +206,7 @@ mod tests {
     #[tokio::test]
     async fn test_save_and_get() {
         let pool = setup_test_db().await;
-        let repo = SqliteProxyConfigRepository::new(Arc::new(pool));
+        let repo = test_repo(pool);
 
         let mut config =
             ProxyConfig::new(ProxyType::Socks5, "127.0.0.1".to_string(), 1080).unwrap();
@@ -175,4 +225,28 @@ mod tests {
         let loaded = repo.get().await.unwrap();
         assert!(!loaded.is_enabled());
     }
+
+    #[tokio::test]
+    async fn test_auth_and_bypass_roundtrip() {
+        let pool = setup_test_db().await;
+        let repo = test_repo(pool.clone());
+
+        let mut config = ProxyConfig::new(ProxyType::Http, "proxy".to_string(), 3128).unwrap();
+        config.set_auth(Some("user".to_string()), Some("secret".to_string()));
+        config
+            .set_bypass_domains(vec!["localhost".to_string()])
+            .unwrap();
+        repo.save(&config).await.unwrap();
+
+        let stored: String = sqlx::query_scalar("SELECT password FROM proxy_settings WHERE id = 1")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_ne!(stored, "secret");
+
+        let loaded = repo.get().await.unwrap();
+        assert_eq!(loaded.username(), Some("user"));
+        assert_eq!(loaded.password(), Some("secret"));
+        assert_eq!(loaded.bypass_domains(), ["localhost"]);
+    }
 }
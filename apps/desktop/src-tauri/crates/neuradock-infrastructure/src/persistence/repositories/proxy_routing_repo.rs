@@ -0,0 +1,281 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use neuradock_domain::proxy_config::{
+    PoolStrategy, ProxyEndpoint, ProxyOverride, ProxyPool, ProxyRoute, ProxyRouting,
+    ProxyRoutingRepository, ProxyScope,
+};
+use neuradock_domain::shared::DomainError;
+
+use crate::persistence::result_ext::ResultExt;
+use crate::security::EncryptionService;
+
+#[derive(FromRow)]
+struct ProxyPoolRow {
+    id: String,
+    name: String,
+    strategy: String,
+    endpoints: String,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(FromRow)]
+struct ProxyOverrideRow {
+    scope_type: String,
+    scope_id: String,
+    route_kind: String,
+    pool_id: Option<String>,
+    endpoint: Option<String>,
+}
+
+fn parse_timestamp(value: &str, field: &str) -> Result<DateTime<Utc>, DomainError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| DomainError::DataIntegrity(format!("Invalid {}: {}", field, e)))
+}
+
+/// SQLite implementation of ProxyRoutingRepository
+///
+/// Endpoint passwords are encrypted inside the stored JSON.
+pub struct SqliteProxyRoutingRepository {
+    pool: Arc<SqlitePool>,
+    encryption: Arc<EncryptionService>,
+}
+
+impl SqliteProxyRoutingRepository {
+    pub fn new(pool: Arc<SqlitePool>, encryption: Arc<EncryptionService>) -> Self {
+        Self { pool, encryption }
+    }
+
+    fn encode_endpoint(&self, endpoint: &ProxyEndpoint) -> Result<ProxyEndpoint, DomainError> {
+        let mut stored = endpoint.clone();
+        stored.password = endpoint
+            .password
+            .as_deref()
+            .map(|password| {
+                self.encryption.encrypt(password).map_err(|e| {
+                    DomainError::DataIntegrity(format!("Failed to encrypt proxy password: {}", e))
+                })
+            })
+            .transpose()?;
+        Ok(stored)
+    }
+
+    fn decode_endpoint(&self, mut stored: ProxyEndpoint) -> Result<ProxyEndpoint, DomainError> {
+        stored.password = stored
+            .password
+            .as_deref()
+            .map(|encrypted| {
+                self.encryption.decrypt(encrypted).map_err(|e| {
+                    DomainError::DataIntegrity(format!("Failed to decrypt proxy password: {}", e))
+                })
+            })
+            .transpose()?;
+        Ok(stored)
+    }
+
+    fn row_to_pool(&self, row: ProxyPoolRow) -> Result<ProxyPool, DomainError> {
+        let stored: Vec<ProxyEndpoint> = serde_json::from_str(&row.endpoints).map_err(|e| {
+            DomainError::DataIntegrity(format!("Invalid endpoints for pool {}: {}", row.id, e))
+        })?;
+        let endpoints = stored
+            .into_iter()
+            .map(|endpoint| self.decode_endpoint(endpoint))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ProxyPool::restore(
+            row.id,
+            row.name,
+            PoolStrategy::from_str(&row.strategy)?,
+            endpoints,
+            parse_timestamp(&row.created_at, "created_at")?,
+            parse_timestamp(&row.updated_at, "updated_at")?,
+        ))
+    }
+
+    fn row_to_override(&self, row: ProxyOverrideRow) -> Result<ProxyOverride, DomainError> {
+        let route = match row.route_kind.as_str() {
+            "global" => ProxyRoute::Global,
+            "direct" => ProxyRoute::Direct,
+            "pool" => ProxyRoute::Pool {
+                pool_id: row.pool_id.ok_or_else(|| {
+                    DomainError::DataIntegrity(format!(
+                        "Proxy override for {} is missing its pool",
+                        row.scope_id
+                    ))
+                })?,
+            },
+            "endpoint" => {
+                let json = row.endpoint.ok_or_else(|| {
+                    DomainError::DataIntegrity(format!(
+                        "Proxy override for {} is missing its endpoint",
+                        row.scope_id
+                    ))
+                })?;
+                let stored: ProxyEndpoint = serde_json::from_str(&json).map_err(|e| {
+                    DomainError::DataIntegrity(format!("Invalid proxy endpoint: {}", e))
+                })?;
+                ProxyRoute::Endpoint {
+                    endpoint: self.decode_endpoint(stored)?,
+                }
+            }
+            other => {
+                return Err(DomainError::DataIntegrity(format!(
+                    "Invalid proxy route kind: {}",
+                    other
+                )))
+            }
+        };
+
+        Ok(ProxyOverride {
+            scope: ProxyScope::from_str(&row.scope_type)?,
+            scope_id: row.scope_id,
+            route,
+        })
+    }
+}
+
+#[async_trait]
+impl ProxyRoutingRepository for SqliteProxyRoutingRepository {
+    async fn load(&self) -> Result<ProxyRouting, DomainError> {
+        Ok(ProxyRouting::new(
+            self.list_pools().await?,
+            self.list_overrides().await?,
+        ))
+    }
+
+    async fn list_pools(&self) -> Result<Vec<ProxyPool>, DomainError> {
+        let rows = sqlx::query_as::<_, ProxyPoolRow>(
+            "SELECT id, name, strategy, endpoints, created_at, updated_at FROM proxy_pools ORDER BY name",
+        )
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to list proxy pools")?;
+
+        rows.into_iter().map(|row| self.row_to_pool(row)).collect()
+    }
+
+    async fn find_pool(&self, id: &str) -> Result<Option<ProxyPool>, DomainError> {
+        let row = sqlx::query_as::<_, ProxyPoolRow>(
+            "SELECT id, name, strategy, endpoints, created_at, updated_at FROM proxy_pools WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load proxy pool")?;
+
+        row.map(|row| self.row_to_pool(row)).transpose()
+    }
+
+    async fn save_pool(&self, pool: &ProxyPool) -> Result<(), DomainError> {
+        let endpoints = pool
+            .endpoints()
+            .iter()
+            .map(|endpoint| self.encode_endpoint(endpoint))
+            .collect::<Result<Vec<_>, _>>()?;
+        let endpoints = serde_json::to_string(&endpoints)
+            .map_err(|e| DomainError::Repository(format!("Failed to encode endpoints: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO proxy_pools (id, name, strategy, endpoints, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                strategy = excluded.strategy,
+                endpoints = excluded.endpoints,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(pool.id())
+        .bind(pool.name())
+        .bind(pool.strategy().as_str())
+        .bind(endpoints)
+        .bind(pool.created_at().to_rfc3339())
+        .bind(pool.updated_at().to_rfc3339())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save proxy pool")?;
+
+        Ok(())
+    }
+
+    async fn delete_pool(&self, id: &str) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM proxy_pools WHERE id = ?")
+            .bind(id)
+            .execute(self.pool.as_ref())
+            .await
+            .map_repo_error("Failed to delete proxy pool")?;
+
+        Ok(())
+    }
+
+    async fn list_overrides(&self) -> Result<Vec<ProxyOverride>, DomainError> {
+        let rows = sqlx::query_as::<_, ProxyOverrideRow>(
+            r#"
+            SELECT scope_type, scope_id, route_kind, pool_id, endpoint
+            FROM proxy_overrides
+            ORDER BY scope_type, scope_id
+            "#,
+        )
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to list proxy overrides")?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_override(row))
+            .collect()
+    }
+
+    async fn save_override(&self, proxy_override: &ProxyOverride) -> Result<(), DomainError> {
+        let (route_kind, pool_id, endpoint) = match &proxy_override.route {
+            ProxyRoute::Global => ("global", None, None),
+            ProxyRoute::Direct => ("direct", None, None),
+            ProxyRoute::Pool { pool_id } => ("pool", Some(pool_id.clone()), None),
+            ProxyRoute::Endpoint { endpoint } => {
+                let stored = self.encode_endpoint(endpoint)?;
+                let json = serde_json::to_string(&stored).map_err(|e| {
+                    DomainError::Repository(format!("Failed to encode endpoint: {}", e))
+                })?;
+                ("endpoint", None, Some(json))
+            }
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO proxy_overrides (scope_type, scope_id, route_kind, pool_id, endpoint, updated_at)
+            VALUES (?, ?, ?, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            ON CONFLICT(scope_type, scope_id) DO UPDATE SET
+                route_kind = excluded.route_kind,
+                pool_id = excluded.pool_id,
+                endpoint = excluded.endpoint,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(proxy_override.scope.as_str())
+        .bind(&proxy_override.scope_id)
+        .bind(route_kind)
+        .bind(pool_id)
+        .bind(endpoint)
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save proxy override")?;
+
+        Ok(())
+    }
+
+    async fn delete_override(&self, scope: ProxyScope, scope_id: &str) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM proxy_overrides WHERE scope_type = ? AND scope_id = ?")
+            .bind(scope.as_str())
+            .bind(scope_id)
+            .execute(self.pool.as_ref())
+            .await
+            .map_repo_error("Failed to delete proxy override")?;
+
+        Ok(())
+    }
+}
@@ -27,6 +27,8 @@ struct TokenRow {
     model_limits_enabled: i32,
     model_limits_allowed: Option<String>,
     model_limits_denied: Option<String>,
+    token_group: String,
+    allow_ips: String,
     fetched_at: String,
 }
 
@@ -81,6 +83,8 @@ impl SqliteTokenRepository {
                 expired_time,
                 model_limits_enabled: row.model_limits_enabled != 0,
                 model_limits,
+                group: row.token_group,
+                allow_ips: row.allow_ips,
             },
         ))
     }
@@ -103,9 +107,10 @@ impl TokenRepository for SqliteTokenRepository {
             INSERT INTO api_tokens (
                 account_id, token_id, token_name, token_key, status,
                 used_quota, remain_quota, unlimited_quota, expired_time,
-                model_limits_enabled, model_limits_allowed, model_limits_denied, fetched_at
+                model_limits_enabled, model_limits_allowed, model_limits_denied,
+                token_group, allow_ips, fetched_at
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(account_id, token_id) DO UPDATE SET
                 token_name = excluded.token_name,
                 token_key = excluded.token_key,
@@ -117,6 +122,8 @@ impl TokenRepository for SqliteTokenRepository {
                 model_limits_enabled = excluded.model_limits_enabled,
                 model_limits_allowed = excluded.model_limits_allowed,
                 model_limits_denied = excluded.model_limits_denied,
+                token_group = excluded.token_group,
+                allow_ips = excluded.allow_ips,
                 fetched_at = excluded.fetched_at
             "#,
         )
@@ -132,6 +139,8 @@ impl TokenRepository for SqliteTokenRepository {
         .bind(if token.model_limits_enabled() { 1 } else { 0 })
         .bind(model_limits_allowed)
         .bind(model_limits_denied)
+        .bind(token.group())
+        .bind(token.allow_ips())
         .bind(token.fetched_at().to_rfc3339())
         .execute(self.base.pool())
         .await
@@ -163,9 +172,10 @@ impl TokenRepository for SqliteTokenRepository {
                 INSERT INTO api_tokens (
                     account_id, token_id, token_name, token_key, status,
                     used_quota, remain_quota, unlimited_quota, expired_time,
-                    model_limits_enabled, model_limits_allowed, model_limits_denied, fetched_at
+                    model_limits_enabled, model_limits_allowed, model_limits_denied,
+                    token_group, allow_ips, fetched_at
                 )
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 ON CONFLICT(account_id, token_id) DO UPDATE SET
                     token_name = excluded.token_name,
                     token_key = excluded.token_key,
@@ -177,6 +187,8 @@ impl TokenRepository for SqliteTokenRepository {
                     model_limits_enabled = excluded.model_limits_enabled,
                     model_limits_allowed = excluded.model_limits_allowed,
                     model_limits_denied = excluded.model_limits_denied,
+                    token_group = excluded.token_group,
+                    allow_ips = excluded.allow_ips,
                     fetched_at = excluded.fetched_at
                 "#,
             )
@@ -192,6 +204,8 @@ impl TokenRepository for SqliteTokenRepository {
             .bind(if token.model_limits_enabled() { 1 } else { 0 })
             .bind(model_limits_allowed)
             .bind(model_limits_denied)
+            .bind(token.group())
+            .bind(token.allow_ips())
             .bind(token.fetched_at().to_rfc3339())
             .execute(&mut *tx)
             .await
@@ -210,7 +224,8 @@ impl TokenRepository for SqliteTokenRepository {
             r#"
             SELECT id, account_id, token_id, token_name, token_key, status,
                    used_quota, remain_quota, unlimited_quota, expired_time,
-                   model_limits_enabled, model_limits_allowed, model_limits_denied, fetched_at
+                   model_limits_enabled, model_limits_allowed, model_limits_denied,
+                   token_group, allow_ips, fetched_at
             FROM api_tokens
             WHERE token_id = ?
             "#,
@@ -231,7 +246,8 @@ impl TokenRepository for SqliteTokenRepository {
             r#"
             SELECT id, account_id, token_id, token_name, token_key, status,
                    used_quota, remain_quota, unlimited_quota, expired_time,
-                   model_limits_enabled, model_limits_allowed, model_limits_denied, fetched_at
+                   model_limits_enabled, model_limits_allowed, model_limits_denied,
+                   token_group, allow_ips, fetched_at
             FROM api_tokens
             WHERE account_id = ?
             ORDER BY token_id
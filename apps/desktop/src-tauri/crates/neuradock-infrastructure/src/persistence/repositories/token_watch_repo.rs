@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use std::sync::Arc;
+
+use neuradock_domain::shared::{AccountId, DomainError};
+use neuradock_domain::token::{
+    TokenId, TokenWatchAlert, TokenWatchRepository, TokenWatchSettings, TokenWatchTarget,
+    TokenWatchTool,
+};
+
+use crate::persistence::result_ext::ResultExt;
+
+/// SQLite implementation of TokenWatchRepository
+pub struct SqliteTokenWatchRepository {
+    pool: Arc<SqlitePool>,
+}
+
+impl SqliteTokenWatchRepository {
+    pub fn new(pool: Arc<SqlitePool>) -> Self {
+        Self { pool }
+    }
+}
+
+fn target_from_row(row: &SqliteRow) -> Result<TokenWatchTarget, DomainError> {
+    let tool: String = row.get("tool");
+    let account_id: String = row.get("account_id");
+    let alerts: String = row.get("alerts");
+    let checked_at: Option<String> = row.get("checked_at");
+
+    Ok(TokenWatchTarget {
+        tool: TokenWatchTool::parse(&tool),
+        account_id: AccountId::from_string(&account_id),
+        token_id: TokenId::new(row.get("token_id")),
+        key_digest: row.get("key_digest"),
+        alerts: alerts
+            .split(',')
+            .filter_map(TokenWatchAlert::parse)
+            .collect(),
+        checked_at: checked_at
+            .map(|value| {
+                value
+                    .parse::<DateTime<Utc>>()
+                    .map_err(|e| DomainError::Repository(format!("Invalid checked_at: {}", e)))
+            })
+            .transpose()?,
+        last_error: row.get("last_error"),
+    })
+}
+
+#[async_trait]
+impl TokenWatchRepository for SqliteTokenWatchRepository {
+    async fn list_targets(&self) -> Result<Vec<TokenWatchTarget>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT tool, account_id, token_id, key_digest, alerts, checked_at, last_error
+            FROM token_watch_targets
+            ORDER BY tool
+            "#,
+        )
+        .fetch_all(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to list token watch targets")?;
+
+        rows.iter().map(target_from_row).collect()
+    }
+
+    async fn find_target(
+        &self,
+        tool: TokenWatchTool,
+    ) -> Result<Option<TokenWatchTarget>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT tool, account_id, token_id, key_digest, alerts, checked_at, last_error
+            FROM token_watch_targets
+            WHERE tool = ?
+            "#,
+        )
+        .bind(tool.as_str())
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load token watch target")?;
+
+        row.as_ref().map(target_from_row).transpose()
+    }
+
+    async fn save_target(&self, target: &TokenWatchTarget) -> Result<(), DomainError> {
+        let alerts = target
+            .alerts
+            .iter()
+            .map(|alert| alert.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        sqlx::query(
+            r#"
+            INSERT INTO token_watch_targets (
+                tool, account_id, token_id, key_digest, alerts, checked_at, last_error
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(tool) DO UPDATE SET
+                account_id = excluded.account_id,
+                token_id = excluded.token_id,
+                key_digest = excluded.key_digest,
+                alerts = excluded.alerts,
+                checked_at = excluded.checked_at,
+                last_error = excluded.last_error
+            "#,
+        )
+        .bind(target.tool.as_str())
+        .bind(target.account_id.as_str())
+        .bind(target.token_id.value())
+        .bind(&target.key_digest)
+        .bind(alerts)
+        .bind(target.checked_at.map(|at| at.to_rfc3339()))
+        .bind(&target.last_error)
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save token watch target")?;
+
+        Ok(())
+    }
+
+    async fn delete_target(&self, tool: TokenWatchTool) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM token_watch_targets WHERE tool = ?")
+            .bind(tool.as_str())
+            .execute(self.pool.as_ref())
+            .await
+            .map_repo_error("Failed to delete token watch target")?;
+
+        Ok(())
+    }
+
+    async fn get_settings(&self) -> Result<TokenWatchSettings, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT enabled, expiry_warning_days, low_quota_threshold
+            FROM token_watch_settings
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to load token watch settings")?;
+
+        Ok(match row {
+            Some(row) => TokenWatchSettings::restore(
+                row.get("enabled"),
+                row.get::<i64, _>("expiry_warning_days").max(0) as u32,
+                row.get("low_quota_threshold"),
+            ),
+            None => TokenWatchSettings::default(),
+        })
+    }
+
+    async fn save_settings(&self, settings: &TokenWatchSettings) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO token_watch_settings (
+                id, enabled, expiry_warning_days, low_quota_threshold, updated_at
+            )
+            VALUES (1, ?, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            ON CONFLICT(id) DO UPDATE SET
+                enabled = excluded.enabled,
+                expiry_warning_days = excluded.expiry_warning_days,
+                low_quota_threshold = excluded.low_quota_threshold,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(settings.enabled())
+        .bind(settings.expiry_warning_days() as i64)
+        .bind(settings.low_quota_threshold())
+        .execute(self.pool.as_ref())
+        .await
+        .map_repo_error("Failed to save token watch settings")?;
+
+        Ok(())
+    }
+}
@@ -132,6 +132,24 @@ impl WafCookiesRepository for SqliteWafCookiesRepository {
         }
     }
 
+    /// List cached WAF cookies of all providers, expired entries included
+    async fn find_all(&self) -> Result<Vec<WafCookies>, DomainError> {
+        let rows = sqlx::query_as::<_, WafCookiesRow>(
+            r#"
+            SELECT id, provider_id, cookies, fetched_at, expires_at
+            FROM waf_cookies
+            ORDER BY provider_id
+            "#,
+        )
+        .fetch_all(self.base.pool())
+        .await
+        .map_err(|e| RepositoryErrorMapper::map_sqlx_error(e, "List WAF cookies"))?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_domain(row))
+            .collect()
+    }
+
     /// Delete WAF cookies for a provider
     async fn delete(&self, provider_id: &str) -> Result<(), DomainError> {
         sqlx::query("DELETE FROM waf_cookies WHERE provider_id = ?")
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use neuradock_domain::audit::{
+    AuditAction, AuditActor, AuditEntry, AuditLogQuery, AuditLogRepository,
+};
+use neuradock_infrastructure::persistence::repositories::SqliteAuditLogRepository;
+
+mod test_helpers;
+
+#[tokio::test]
+async fn audit_log_append_and_page_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let repo = SqliteAuditLogRepository::new(Arc::new(pool));
+
+    for i in 0..5 {
+        let entry = AuditEntry::new(
+            AuditActor::User,
+            AuditAction::AccountUpdated,
+            format!("acc-{}", i % 2),
+        )
+        .with_target_name("Main")
+        .with_after(r#"{"name":"Main"}"#);
+        let id = repo.append(&entry).await.expect("append entry");
+        assert_eq!(id, i + 1);
+    }
+    repo.append(
+        &AuditEntry::new(AuditActor::System, AuditAction::CodexAuthSwitched, "codex")
+            .with_before(r#"{"email":"a@example.com"}"#),
+    )
+    .await
+    .expect("append switch");
+
+    // Newest first, total spans all pages
+    let page = repo
+        .query(&AuditLogQuery::new(1, 4).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(page.total, 6);
+    assert_eq!(page.entries.len(), 4);
+    assert_eq!(page.entries[0].id, Some(6));
+    assert_eq!(page.entries[0].actor, AuditActor::System);
+    assert_eq!(page.entries[0].action, AuditAction::CodexAuthSwitched);
+    assert!(page.entries[0].after.is_none());
+
+    let page = repo
+        .query(&AuditLogQuery::new(2, 4).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(page.entries.len(), 2);
+    assert_eq!(page.entries[1].id, Some(1));
+    assert_eq!(page.entries[1].target_name.as_deref(), Some("Main"));
+
+    let mut query = AuditLogQuery::new(1, 50).unwrap();
+    query.action = Some(AuditAction::AccountUpdated);
+    query.target = Some("acc-0".to_string());
+    let page = repo.query(&query).await.unwrap();
+    assert_eq!(page.total, 3);
+    assert!(page.entries.iter().all(|entry| entry.target == "acc-0"));
+}
@@ -1,9 +1,14 @@
-use chrono::{Duration, Utc};
+use chrono::{Duration, NaiveDate, Utc};
 use std::sync::Arc;
 
-use neuradock_domain::balance_history::{BalanceHistoryRecord, BalanceHistoryRepository};
+use neuradock_domain::balance_history::{
+    BalanceHistoryRecord, BalanceHistoryRepository, BalanceHistoryRetentionPolicy,
+    BalanceHistoryRetentionRepository,
+};
 use neuradock_domain::shared::AccountId;
-use neuradock_infrastructure::persistence::repositories::SqliteBalanceHistoryRepository;
+use neuradock_infrastructure::persistence::repositories::{
+    SqliteBalanceHistoryRepository, SqliteBalanceHistoryRetentionRepository,
+};
 
 mod test_helpers;
 
@@ -78,3 +83,133 @@ async fn balance_history_repo_save_and_find_latest_integration() {
     assert_eq!(latest.id(), "newer");
     assert_eq!(latest.current_balance(), 25.0);
 }
+
+#[tokio::test]
+async fn balance_history_repo_downsample_and_export_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+
+    let repo = SqliteBalanceHistoryRepository::new(Arc::new(pool.clone()));
+
+    let account_id = AccountId::new();
+    sqlx::query("INSERT OR IGNORE INTO accounts (id, name, provider_id, cookies, api_user, enabled, created_at) VALUES (?1, ?2, ?3, ?4, ?5, 1, datetime('now'))")
+        .bind(account_id.as_str())
+        .bind("Test Account")
+        .bind("test-provider")
+        .bind("{}")
+        .bind("api_user")
+        .execute(&pool)
+        .await
+        .expect("insert account");
+
+    let day_one = "2026-01-01T08:00:00Z".parse().unwrap();
+    let day_one_later = "2026-01-01T20:00:00Z".parse().unwrap();
+    let day_two = "2026-01-02T08:00:00Z".parse().unwrap();
+    let recent = Utc::now();
+
+    for (id, balance, consumed, recorded_at) in [
+        ("d1-a", 100.0, 0.0, day_one),
+        ("d1-b", 80.0, 20.0, day_one_later),
+        ("d2", 70.0, 30.0, day_two),
+        ("recent", 60.0, 40.0, recent),
+    ] {
+        let record = BalanceHistoryRecord::new(
+            id.to_string(),
+            account_id.clone(),
+            balance,
+            consumed,
+            100.0,
+            recorded_at,
+        )
+        .expect("create record");
+        repo.save(&record).await.expect("save record");
+    }
+
+    let cutoff = NaiveDate::from_ymd_opt(2026, 1, 3).unwrap();
+    let report = repo.downsample_before(cutoff).await.expect("downsample");
+    assert_eq!(report.raw_rows_removed, 3);
+    assert_eq!(report.daily_rows_written, 2);
+
+    // Running again is a no-op
+    let report = repo
+        .downsample_before(cutoff)
+        .await
+        .expect("downsample again");
+    assert_eq!(report.raw_rows_removed, 0);
+
+    // Latest raw snapshot survives
+    let latest = repo
+        .find_latest_by_account_id(&account_id)
+        .await
+        .expect("find latest")
+        .expect("latest should exist");
+    assert_eq!(latest.id(), "recent");
+
+    // Daily summaries still cover the downsampled days
+    let summaries = repo
+        .list_all_daily_summaries(&account_id)
+        .await
+        .expect("list summaries");
+    assert_eq!(summaries.len(), 3);
+    assert_eq!(summaries[0].daily_consumed(), 20.0);
+
+    let rows = repo
+        .list_for_export(
+            &[],
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            recent.date_naive(),
+        )
+        .await
+        .expect("export");
+    assert_eq!(rows.len(), 3);
+    assert!(rows[0].downsampled);
+    assert_eq!(rows[0].sample_count, 2);
+    assert_eq!(rows[0].min_balance, 80.0);
+    assert_eq!(rows[0].max_balance, 100.0);
+    assert_eq!(rows[0].current_balance, 80.0);
+    assert!(!rows[2].downsampled);
+
+    let rows = repo
+        .list_for_export(
+            &[AccountId::new()],
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            recent.date_naive(),
+        )
+        .await
+        .expect("export filtered");
+    assert!(rows.is_empty());
+
+    repo.compact().await.expect("vacuum");
+}
+
+#[tokio::test]
+async fn balance_history_retention_repo_roundtrip_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+
+    let repo = SqliteBalanceHistoryRetentionRepository::new(Arc::new(pool));
+
+    let mut policy = repo.get().await.expect("get default");
+    assert!(policy.is_enabled());
+    assert_eq!(policy.raw_retention_days(), 90);
+    assert!(policy.last_run_at().is_none());
+
+    policy.update(true, 30, false).expect("update policy");
+    policy.mark_run(Utc::now());
+    repo.save(&policy).await.expect("save policy");
+
+    let loaded: BalanceHistoryRetentionPolicy = repo.get().await.expect("get saved");
+    assert_eq!(loaded.raw_retention_days(), 30);
+    assert!(!loaded.vacuum_after_cleanup());
+    assert!(loaded.last_run_at().is_some());
+
+    // Recording a run leaves settings saved in the meantime alone
+    let mut updated = loaded.clone();
+    updated.update(false, 60, true).expect("update policy");
+    repo.save(&updated).await.expect("save policy");
+    let ran_at = Utc::now();
+    repo.mark_run(ran_at).await.expect("mark run");
+
+    let loaded = repo.get().await.expect("get after run");
+    assert!(!loaded.is_enabled());
+    assert_eq!(loaded.raw_retention_days(), 60);
+    assert_eq!(loaded.last_run_at(), Some(ran_at));
+}
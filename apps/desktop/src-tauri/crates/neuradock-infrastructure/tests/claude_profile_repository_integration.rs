@@ -0,0 +1,90 @@
+use chrono::Utc;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use neuradock_domain::claude_profile::{
+    ActiveClaudeProfile, ClaudeModelMapping, ClaudeProfile, ClaudeProfileConfig,
+    ClaudeProfileRepository,
+};
+use neuradock_infrastructure::persistence::repositories::SqliteClaudeProfileRepository;
+
+mod test_helpers;
+
+fn config(name: &str) -> ClaudeProfileConfig {
+    ClaudeProfileConfig {
+        name: name.to_string(),
+        auth_token: format!("sk-{}-secret", name),
+        base_url: "https://relay.example.com".to_string(),
+        models: ClaudeModelMapping {
+            haiku: Some("glm-4.5-air".to_string()),
+            sonnet: Some("glm-4.6".to_string()),
+            opus: None,
+        },
+        extra_env: BTreeMap::from([("HTTPS_PROXY".to_string(), "http://user:pw@p:1".to_string())]),
+    }
+}
+
+#[tokio::test]
+async fn claude_profile_repo_roundtrip_integration() {
+    let (pool, encryption) = test_helpers::setup_in_memory_db().await;
+    let pool = Arc::new(pool);
+    let repo = SqliteClaudeProfileRepository::new(pool.clone(), encryption);
+
+    let work_id = repo
+        .create(&ClaudeProfile::create(config("work")).unwrap())
+        .await
+        .unwrap();
+    repo.create(&ClaudeProfile::create(config("home")).unwrap())
+        .await
+        .unwrap();
+
+    // Secrets are not stored in plain text
+    let (stored_token, stored_env): (String, String) =
+        sqlx::query_as("SELECT auth_token, extra_env FROM claude_profiles WHERE id = ?")
+            .bind(work_id.value())
+            .fetch_one(pool.as_ref())
+            .await
+            .unwrap();
+    assert!(!stored_token.contains("sk-work-secret"));
+    assert!(!stored_env.contains("user:pw"));
+
+    let mut work = repo.find_by_name("work").await.unwrap().unwrap();
+    assert_eq!(work.auth_token(), "sk-work-secret");
+    assert_eq!(work.models().sonnet.as_deref(), Some("glm-4.6"));
+    assert_eq!(
+        work.extra_env().get("HTTPS_PROXY").map(String::as_str),
+        Some("http://user:pw@p:1")
+    );
+
+    let mut updated = config("work");
+    updated.models.opus = Some("glm-4.6".to_string());
+    work.update(updated).unwrap();
+    repo.update(&work).await.unwrap();
+    let work = repo.find_by_id(&work_id).await.unwrap().unwrap();
+    assert_eq!(work.models().opus.as_deref(), Some("glm-4.6"));
+
+    let names: Vec<_> = repo
+        .find_all()
+        .await
+        .unwrap()
+        .iter()
+        .map(|p| p.name().to_string())
+        .collect();
+    assert_eq!(names, vec!["home".to_string(), "work".to_string()]);
+
+    // Active record round trip, cleared when the profile is deleted
+    assert!(repo.get_active().await.unwrap().is_none());
+    let active = ActiveClaudeProfile {
+        profile_id: work_id,
+        applied_at: Utc::now(),
+        applied_env: BTreeMap::from([("ANTHROPIC_BASE_URL".to_string(), "digest".to_string())]),
+    };
+    repo.set_active(&active).await.unwrap();
+    let loaded = repo.get_active().await.unwrap().unwrap();
+    assert_eq!(loaded.profile_id, work_id);
+    assert_eq!(loaded.applied_env, active.applied_env);
+
+    repo.delete(&work_id).await.unwrap();
+    assert!(repo.find_by_id(&work_id).await.unwrap().is_none());
+    assert!(repo.get_active().await.unwrap().is_none());
+}
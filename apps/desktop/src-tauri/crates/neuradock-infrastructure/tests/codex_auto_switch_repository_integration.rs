@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use neuradock_domain::codex::{
+    CodexAccountId, CodexAutoSwitchEvent, CodexAutoSwitchReason, CodexAutoSwitchRepository,
+};
+use neuradock_infrastructure::persistence::repositories::SqliteCodexAutoSwitchRepository;
+
+mod test_helpers;
+
+#[tokio::test]
+async fn codex_auto_switch_repo_roundtrip_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let repo = SqliteCodexAutoSwitchRepository::new(Arc::new(pool));
+
+    let mut policy = repo.get_policy().await.expect("get defaults");
+    assert!(!policy.is_enabled());
+    assert_eq!(policy.threshold_percent(), 90);
+    assert!(policy.home_account_id().is_none());
+
+    let now = Utc::now();
+    let home = CodexAccountId::from_string("home-account");
+    policy.update(true, 85, 5, true).expect("update policy");
+    policy.record_switch_away(&home, Some(now + Duration::hours(3)));
+    policy.mark_checked(now);
+    repo.save_policy(&policy).await.expect("save policy");
+
+    let loaded = repo.get_policy().await.expect("get saved");
+    assert!(loaded.is_enabled());
+    assert_eq!(loaded.threshold_percent(), 85);
+    assert_eq!(loaded.poll_interval_minutes(), 5);
+    assert_eq!(loaded.home_account_id(), Some(&home));
+    assert_eq!(
+        loaded.switch_back_at().map(|at| at.timestamp()),
+        Some((now + Duration::hours(3)).timestamp())
+    );
+
+    for (offset, reason) in [
+        (0, CodexAutoSwitchReason::ThresholdReached),
+        (1, CodexAutoSwitchReason::SwitchBack),
+    ] {
+        repo.record_switch(&CodexAutoSwitchEvent {
+            from_account_id: Some(home.clone()),
+            from_email: "home@example.com".to_string(),
+            to_account_id: CodexAccountId::from_string("spare-account"),
+            to_email: "spare@example.com".to_string(),
+            reason,
+            from_used_percent: Some(92.5),
+            switched_at: now + Duration::minutes(offset),
+        })
+        .await
+        .expect("record switch");
+    }
+
+    let switches = repo.recent_switches(10).await.expect("list switches");
+    assert_eq!(switches.len(), 2);
+    assert_eq!(switches[0].reason, CodexAutoSwitchReason::SwitchBack);
+    assert_eq!(switches[1].from_used_percent, Some(92.5));
+    assert_eq!(repo.recent_switches(1).await.unwrap().len(), 1);
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use neuradock_domain::codex::{CodexTokenRefreshPolicyRepository, DEFAULT_CODEX_TOKEN_URL};
+use neuradock_infrastructure::persistence::repositories::SqliteCodexTokenRefreshPolicyRepository;
+
+mod test_helpers;
+
+#[tokio::test]
+async fn codex_token_refresh_policy_repo_roundtrip_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let repo = SqliteCodexTokenRefreshPolicyRepository::new(Arc::new(pool));
+
+    let mut policy = repo.get().await.expect("get defaults");
+    assert!(policy.is_enabled());
+    assert_eq!(policy.refresh_before_minutes(), 1440);
+    assert_eq!(policy.token_url(), DEFAULT_CODEX_TOKEN_URL);
+    assert!(policy.last_run_at().is_none());
+
+    policy
+        .update(false, 30, Some("http://127.0.0.1:4010/oauth/token"))
+        .expect("update policy");
+    let now = Utc::now();
+    policy.mark_run(now);
+    repo.save(&policy).await.expect("save policy");
+
+    let loaded = repo.get().await.expect("get saved");
+    assert!(!loaded.is_enabled());
+    assert_eq!(loaded.refresh_before_minutes(), 30);
+    assert_eq!(loaded.token_url(), "http://127.0.0.1:4010/oauth/token");
+    assert_eq!(
+        loaded.last_run_at().map(|at| at.timestamp()),
+        Some(now.timestamp())
+    );
+}
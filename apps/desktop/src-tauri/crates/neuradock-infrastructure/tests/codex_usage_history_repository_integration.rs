@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use neuradock_domain::codex::{
+    CodexAccount, CodexAccountRepository, CodexAccountSource, CodexUsageHistoryRepository,
+    CodexUsageReminderKind, CodexUsageSnapshot, CodexUsageWindowKind,
+};
+use neuradock_infrastructure::persistence::repositories::{
+    SqliteCodexAccountRepository, SqliteCodexUsageHistoryRepository,
+};
+
+mod test_helpers;
+
+#[tokio::test]
+async fn codex_usage_history_repo_roundtrip_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let pool = Arc::new(pool);
+    let accounts = SqliteCodexAccountRepository::new(pool.clone());
+    let repo = SqliteCodexUsageHistoryRepository::new(pool);
+
+    let account = CodexAccount::new(
+        "usage@example.com".to_string(),
+        None,
+        CodexAccountSource::Import,
+    )
+    .expect("new account");
+    accounts.save(&account).await.expect("save account");
+
+    let now = Utc::now();
+    let resets_at = now + Duration::hours(2);
+    let snapshot = |window, used_percent, minutes_ago| CodexUsageSnapshot {
+        account_id: account.id().clone(),
+        window,
+        used_percent,
+        window_minutes: Some(300),
+        resets_at: Some(resets_at),
+        recorded_at: now - Duration::minutes(minutes_ago),
+    };
+    repo.record(&[
+        snapshot(CodexUsageWindowKind::Primary, 10.0, 120),
+        snapshot(CodexUsageWindowKind::Primary, 40.0, 60),
+        snapshot(CodexUsageWindowKind::Secondary, 5.0, 60),
+        snapshot(CodexUsageWindowKind::Primary, 85.0, 0),
+    ])
+    .await
+    .expect("record snapshots");
+
+    let history = repo
+        .list(account.id(), now - Duration::minutes(90), now)
+        .await
+        .expect("list history");
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0].used_percent, 40.0);
+    assert_eq!(history[2].used_percent, 85.0);
+    assert_eq!(
+        history[2].resets_at.map(|at| at.timestamp()),
+        Some(resets_at.timestamp())
+    );
+
+    let latest = repo.latest().await.expect("latest snapshots");
+    assert_eq!(latest.len(), 2);
+    assert_eq!(latest[0].window, CodexUsageWindowKind::Primary);
+    assert_eq!(latest[0].used_percent, 85.0);
+
+    // A reminder is sent once per window cycle
+    let cycle = history[2].cycle();
+    let claim =
+        |kind| repo.claim_reminder(account.id(), CodexUsageWindowKind::Primary, kind, cycle);
+    assert!(claim(CodexUsageReminderKind::Warning).await.unwrap());
+    assert!(!claim(CodexUsageReminderKind::Warning).await.unwrap());
+    assert!(claim(CodexUsageReminderKind::Reset).await.unwrap());
+
+    let deleted = repo
+        .delete_before(now - Duration::minutes(90))
+        .await
+        .expect("prune history");
+    assert_eq!(deleted, 1);
+
+    let mut settings = repo.get_settings().await.expect("default settings");
+    assert!(settings.threshold_reminders() && settings.reset_reminders());
+    settings.update(true, false);
+    repo.save_settings(&settings).await.expect("save settings");
+    let loaded = repo.get_settings().await.expect("saved settings");
+    assert!(loaded.threshold_reminders());
+    assert!(!loaded.reset_reminders());
+}
@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use neuradock_infrastructure::cookie_import::{read_chromium_cookies, read_firefox_cookies};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{ConnectOptions, Connection};
+
+/// Microseconds between 1601-01-01 and 1970-01-01
+const CHROMIUM_EPOCH_OFFSET_MICROS: i64 = 11_644_473_600_000_000;
+const FAR_FUTURE_SECS: i64 = 4_102_444_800;
+
+async fn create_db(path: &Path, statements: &[&str]) {
+    let mut conn = SqliteConnectOptions::new()
+        .filename(path)
+        .create_if_missing(true)
+        .connect()
+        .await
+        .unwrap();
+    for statement in statements {
+        sqlx::query(statement).execute(&mut conn).await.unwrap();
+    }
+    conn.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn chromium_cookie_import_integration() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("Cookies");
+    let future = FAR_FUTURE_SECS * 1_000_000 + CHROMIUM_EPOCH_OFFSET_MICROS;
+    let past = 1_000_000 + CHROMIUM_EPOCH_OFFSET_MICROS;
+
+    create_db(
+        &db_path,
+        &[
+            "CREATE TABLE cookies (host_key TEXT, name TEXT, value TEXT, encrypted_value BLOB, path TEXT, expires_utc INTEGER)",
+            &format!("INSERT INTO cookies VALUES ('.anyrouter.top', 'session', 'abc', x'', '/', {future})"),
+            "INSERT INTO cookies VALUES ('anyrouter.top', 'acw_tc', 'waf', x'', '/', 0)",
+            &format!("INSERT INTO cookies VALUES ('.anyrouter.top', 'old', 'gone', x'', '/', {past})"),
+            &format!("INSERT INTO cookies VALUES ('.anyrouter.top', 'secret', '', x'76313001', '/', {future})"),
+            "INSERT INTO cookies VALUES ('.example.com', 'session', 'other', x'', '/', 0)",
+        ],
+    )
+    .await;
+
+    let import = read_chromium_cookies(&db_path, "https://anyrouter.top")
+        .await
+        .unwrap();
+
+    assert_eq!(import.cookies.len(), 2);
+    assert_eq!(
+        import.cookies.get("session").map(String::as_str),
+        Some("abc")
+    );
+    assert_eq!(
+        import.cookies.get("acw_tc").map(String::as_str),
+        Some("waf")
+    );
+    assert_eq!(import.skipped_encrypted, 1);
+    assert_eq!(import.skipped_expired, 1);
+
+    // The source database is left untouched and no snapshot remains open
+    assert!(db_path.is_file());
+}
+
+#[tokio::test]
+async fn firefox_cookie_import_integration() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("cookies.sqlite");
+
+    create_db(
+        &db_path,
+        &[
+            "CREATE TABLE moz_cookies (id INTEGER PRIMARY KEY, host TEXT, name TEXT, value TEXT, path TEXT, expiry INTEGER)",
+            &format!("INSERT INTO moz_cookies (host, name, value, path, expiry) VALUES ('.anyrouter.top', 'session', 'parent', '/', {FAR_FUTURE_SECS})"),
+            &format!("INSERT INTO moz_cookies (host, name, value, path, expiry) VALUES ('api.anyrouter.top', 'session', 'child', '/', {})", FAR_FUTURE_SECS * 1000),
+            "INSERT INTO moz_cookies (host, name, value, path, expiry) VALUES ('.anyrouter.top', 'old', 'gone', '/', 1)",
+        ],
+    )
+    .await;
+
+    let import = read_firefox_cookies(&db_path, "api.anyrouter.top")
+        .await
+        .unwrap();
+
+    // The more specific domain wins when names collide
+    assert_eq!(import.cookies.len(), 1);
+    assert_eq!(
+        import.cookies.get("session").map(String::as_str),
+        Some("child")
+    );
+    assert_eq!(import.skipped_expired, 1);
+
+    assert!(
+        read_firefox_cookies(&dir.path().join("missing.sqlite"), "anyrouter.top")
+            .await
+            .is_err()
+    );
+}
@@ -0,0 +1,62 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use neuradock_domain::check_in::{Provider, ProviderConfig, ProviderRepository};
+use neuradock_domain::currency::{BalanceUnit, CurrencySettingsRepository, ProviderBalanceUnit};
+use neuradock_infrastructure::persistence::repositories::{
+    SqliteCurrencySettingsRepository, SqliteProviderRepository,
+};
+
+mod test_helpers;
+
+#[tokio::test]
+async fn currency_settings_repo_roundtrip_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let pool = Arc::new(pool);
+
+    let provider_repo = SqliteProviderRepository::new(pool.clone());
+    let provider = Provider::new(ProviderConfig {
+        name: "Quota Provider".to_string(),
+        domain: "https://quota.example.com".to_string(),
+        login_path: "/login".to_string(),
+        sign_in_path: None,
+        user_info_path: "/api/user/self".to_string(),
+        token_api_path: None,
+        models_path: None,
+        api_user_key: "new-api-user".to_string(),
+        bypass_method: None,
+        supports_check_in: true,
+        check_in_bugged: false,
+    });
+    provider_repo.save(&provider).await.expect("save provider");
+
+    let repo = SqliteCurrencySettingsRepository::new(pool);
+
+    let mut settings = repo.get().await.expect("get defaults");
+    assert_eq!(settings.display_currency(), "USD");
+    assert_eq!(settings.exchange_rates().get("CNY"), Some(&7.2));
+    assert!(settings.provider_units().is_empty());
+
+    let provider_id = provider.id().as_str().to_string();
+    settings
+        .update(
+            "CNY",
+            BTreeMap::from([("CNY".to_string(), 7.0), ("EUR".to_string(), 0.9)]),
+            HashMap::from([(
+                provider_id.clone(),
+                ProviderBalanceUnit {
+                    unit: BalanceUnit::QuotaUnits,
+                    conversion_factor: 0.000002,
+                },
+            )]),
+        )
+        .expect("update settings");
+    repo.save(&settings).await.expect("save settings");
+
+    let loaded = repo.get().await.expect("get saved");
+    assert_eq!(loaded.display_currency(), "CNY");
+    assert_eq!(loaded.exchange_rates().len(), 3);
+    assert_eq!(loaded.exchange_rates().get("EUR"), Some(&0.9));
+    assert_eq!(loaded.unit_for(&provider_id).unit, BalanceUnit::QuotaUnits);
+    assert!((loaded.normalize(&provider_id, 500_000.0) - 7.0).abs() < 1e-9);
+}
@@ -0,0 +1,41 @@
+//! Integration tests for the persistence reads behind the diagnostics report
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use neuradock_domain::waf_cookies::WafCookiesRepository;
+use neuradock_infrastructure::persistence::repositories::SqliteWafCookiesRepository;
+use neuradock_infrastructure::persistence::Database;
+
+mod test_helpers;
+
+#[tokio::test]
+async fn waf_cookies_find_all_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let repo = SqliteWafCookiesRepository::new(Arc::new(pool));
+
+    assert!(repo.find_all().await.unwrap().is_empty());
+
+    let cookies = HashMap::from([("acw_tc".to_string(), "value".to_string())]);
+    repo.save("provider-b", &cookies).await.unwrap();
+    repo.save("provider-a", &cookies).await.unwrap();
+
+    let all = repo.find_all().await.unwrap();
+    let providers: Vec<&str> = all.iter().map(|c| c.provider_id.as_str()).collect();
+    assert_eq!(providers, vec!["provider-a", "provider-b"]);
+    assert!(all
+        .iter()
+        .all(|c| c.is_valid() && c.fetched_at < c.expires_at));
+}
+
+#[tokio::test]
+async fn schema_status_without_migration_history_integration() {
+    // The test helper applies migrations by hand, so no sqlx history table exists
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+
+    let status = Database::schema_status(&pool).await.unwrap();
+    assert!(status.applied.is_empty());
+    assert_eq!(status.latest_applied(), None);
+    assert!(!status.pending.is_empty());
+    assert!(status.pending.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
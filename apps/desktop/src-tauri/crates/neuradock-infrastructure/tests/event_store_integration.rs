@@ -0,0 +1,253 @@
+//! Integration tests for the SQLite event store and the persistent event bus
+//! Covers sequencing, per-account timelines, checkpoints and replay after failures
+
+use async_trait::async_trait;
+use chrono::Utc;
+use std::any::Any;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use neuradock_domain::events::account_events::{AccountCreated, AccountToggled};
+use neuradock_domain::events::{DynamicEventHandler, EventBus, EventStore};
+use neuradock_domain::shared::{AccountId, DomainError, ProviderId};
+use neuradock_infrastructure::events::PersistentEventBus;
+use neuradock_infrastructure::persistence::repositories::SqliteEventStore;
+
+mod test_helpers;
+
+/// Counts toggles and fails while `failing` is set
+struct ToggleHandler {
+    handled: AtomicUsize,
+    failing: AtomicBool,
+}
+
+#[async_trait]
+impl DynamicEventHandler for ToggleHandler {
+    async fn handle_dynamic(&self, event: &(dyn Any + Send + Sync)) -> Result<(), DomainError> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(DomainError::Infrastructure("handler down".to_string()));
+        }
+        if event.downcast_ref::<AccountToggled>().is_none() {
+            return Err(DomainError::Infrastructure("Wrong event type".to_string()));
+        }
+        self.handled.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn event_type_name(&self) -> &'static str {
+        std::any::type_name::<AccountToggled>()
+    }
+}
+
+/// Counts toggles, taking long enough for concurrent publishes to interleave
+struct SlowToggleHandler {
+    handled: AtomicUsize,
+}
+
+#[async_trait]
+impl DynamicEventHandler for SlowToggleHandler {
+    async fn handle_dynamic(&self, _event: &(dyn Any + Send + Sync)) -> Result<(), DomainError> {
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        self.handled.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn event_type_name(&self) -> &'static str {
+        std::any::type_name::<AccountToggled>()
+    }
+}
+
+fn toggled(account_id: &str, enabled: bool) -> Box<AccountToggled> {
+    Box::new(AccountToggled {
+        account_id: AccountId::from_string(account_id),
+        enabled,
+        occurred_at: Utc::now(),
+    })
+}
+
+#[tokio::test]
+async fn event_store_sequences_and_timeline_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let store = SqliteEventStore::new(Arc::new(pool));
+
+    assert_eq!(store.last_sequence().await.unwrap(), 0);
+
+    let created = AccountCreated {
+        account_id: AccountId::from_string("acc-1"),
+        name: "Main".to_string(),
+        provider_id: ProviderId::from_string("anyrouter"),
+        auto_checkin_enabled: true,
+        occurred_at: Utc::now(),
+    };
+    assert_eq!(store.append(&created).await.unwrap(), 1);
+    assert_eq!(
+        store
+            .append(toggled("acc-2", false).as_ref())
+            .await
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        store
+            .append(toggled("acc-1", false).as_ref())
+            .await
+            .unwrap(),
+        3
+    );
+
+    let timeline = store.aggregate_timeline("acc-1", None, 10).await.unwrap();
+    let sequences: Vec<i64> = timeline.iter().map(|e| e.sequence).collect();
+    assert_eq!(sequences, vec![3, 1]);
+    assert_eq!(timeline[1].event_name, "account_created");
+    assert!(timeline[1].payload.contains("\"Main\""));
+
+    let older = store
+        .aggregate_timeline("acc-1", Some(3), 10)
+        .await
+        .unwrap();
+    assert_eq!(older.len(), 1);
+
+    let after = store.read_after(1, 10).await.unwrap();
+    assert_eq!(after.len(), 2);
+
+    assert_eq!(store.checkpoint("sub").await.unwrap(), None);
+    store.save_checkpoint("sub", 3).await.unwrap();
+    store.save_checkpoint("sub", 2).await.unwrap();
+    assert_eq!(store.checkpoint("sub").await.unwrap(), Some(3));
+}
+
+#[tokio::test]
+async fn persistent_event_bus_replays_after_failure_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let store = Arc::new(SqliteEventStore::new(Arc::new(pool)));
+
+    // Events from before the subscriber existed are not replayed to it
+    store.append(toggled("acc-1", true).as_ref()).await.unwrap();
+
+    let handler = Arc::new(ToggleHandler {
+        handled: AtomicUsize::new(0),
+        failing: AtomicBool::new(false),
+    });
+    let bus = PersistentEventBus::new(store.clone());
+    bus.subscribe::<AccountToggled>("test", handler.clone())
+        .await
+        .unwrap();
+
+    bus.publish(toggled("acc-1", false)).await.unwrap();
+    assert_eq!(handler.handled.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        store.checkpoint("test:account_toggled").await.unwrap(),
+        Some(2)
+    );
+
+    // A failed delivery keeps the checkpoint behind
+    handler.failing.store(true, Ordering::SeqCst);
+    bus.publish(toggled("acc-1", true)).await.unwrap();
+    assert_eq!(
+        store.checkpoint("test:account_toggled").await.unwrap(),
+        Some(2)
+    );
+
+    // A fresh bus over the same store, as after a restart, redelivers the missed event
+    handler.failing.store(false, Ordering::SeqCst);
+    let restarted = PersistentEventBus::new(store.clone());
+    restarted
+        .subscribe::<AccountToggled>("test", handler.clone())
+        .await
+        .unwrap();
+    assert_eq!(restarted.replay_pending().await.unwrap(), 1);
+    assert_eq!(handler.handled.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        store.checkpoint("test:account_toggled").await.unwrap(),
+        Some(3)
+    );
+    assert_eq!(restarted.replay_pending().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn persistent_event_bus_catches_up_lagging_subscriber_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let store = Arc::new(SqliteEventStore::new(Arc::new(pool)));
+
+    let handler = Arc::new(ToggleHandler {
+        handled: AtomicUsize::new(0),
+        failing: AtomicBool::new(true),
+    });
+    let bus = PersistentEventBus::new(store.clone());
+    bus.subscribe::<AccountToggled>("test", handler.clone())
+        .await
+        .unwrap();
+
+    bus.publish(toggled("acc-1", false)).await.unwrap();
+    assert_eq!(handler.handled.load(Ordering::SeqCst), 0);
+
+    // The next publish delivers the missed event along with the new one
+    handler.failing.store(false, Ordering::SeqCst);
+    bus.publish(toggled("acc-1", true)).await.unwrap();
+    assert_eq!(handler.handled.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        store.checkpoint("test:account_toggled").await.unwrap(),
+        Some(2)
+    );
+}
+
+#[tokio::test]
+async fn persistent_event_bus_does_not_skip_in_flight_event_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let store = Arc::new(SqliteEventStore::new(Arc::new(pool)));
+
+    let handler = Arc::new(ToggleHandler {
+        handled: AtomicUsize::new(0),
+        failing: AtomicBool::new(false),
+    });
+    let bus = PersistentEventBus::new(store.clone());
+    bus.subscribe::<AccountToggled>("test", handler.clone())
+        .await
+        .unwrap();
+
+    // Appended by a concurrent publish that has not dispatched it yet
+    store
+        .append(toggled("acc-1", false).as_ref())
+        .await
+        .unwrap();
+
+    // A later event catches up through the store instead of jumping the checkpoint
+    bus.publish(toggled("acc-1", true)).await.unwrap();
+    assert_eq!(handler.handled.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        store.checkpoint("test:account_toggled").await.unwrap(),
+        Some(2)
+    );
+    assert_eq!(bus.replay_pending().await.unwrap(), 0);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn persistent_event_bus_delivers_concurrent_publishes_once_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let store = Arc::new(SqliteEventStore::new(Arc::new(pool)));
+
+    let handler = Arc::new(SlowToggleHandler {
+        handled: AtomicUsize::new(0),
+    });
+    let bus = Arc::new(PersistentEventBus::new(store.clone()));
+    bus.subscribe::<AccountToggled>("test", handler.clone())
+        .await
+        .unwrap();
+
+    let publishes: Vec<_> = (0..20)
+        .map(|i| {
+            let bus = bus.clone();
+            tokio::spawn(async move { bus.publish(toggled("acc-1", i % 2 == 0)).await })
+        })
+        .collect();
+    for publish in publishes {
+        publish.await.unwrap().unwrap();
+    }
+
+    assert_eq!(handler.handled.load(Ordering::SeqCst), 20);
+    assert_eq!(
+        store.checkpoint("test:account_toggled").await.unwrap(),
+        Some(20)
+    );
+    assert_eq!(bus.replay_pending().await.unwrap(), 0);
+}
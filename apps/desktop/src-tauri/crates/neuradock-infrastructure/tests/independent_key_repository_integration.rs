@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use neuradock_domain::independent_key::{
+    IndependentApiKey, IndependentApiKeyConfig, IndependentKeyRepository, KeyCredit,
+    KeyProviderType, KeyValidation, KeyValidationStatus,
+};
+use neuradock_infrastructure::persistence::repositories::SqliteIndependentKeyRepository;
+
+mod test_helpers;
+
+#[tokio::test]
+async fn independent_key_validation_roundtrip_integration() {
+    let (pool, encryption) = test_helpers::setup_in_memory_db().await;
+    let repo = SqliteIndependentKeyRepository::new(Arc::new(pool), encryption);
+
+    let key = IndependentApiKey::create(IndependentApiKeyConfig {
+        name: "Relay".to_string(),
+        provider_type: KeyProviderType::Custom,
+        custom_provider_name: Some("Relay".to_string()),
+        api_key: "sk-relay-1234567890".to_string(),
+        base_url: Some("https://relay.example.com/v1".to_string()),
+        organization_id: None,
+        description: None,
+    });
+    let id = repo.create(&key).await.expect("create key");
+    let loaded = repo.find_by_id(&id).await.unwrap().unwrap();
+    assert!(loaded.last_validation().is_none());
+
+    let validation = KeyValidation {
+        status: KeyValidationStatus::Valid,
+        status_code: Some(200),
+        message: None,
+        latency_ms: 230,
+        models: vec!["gpt-4o".to_string(), "gpt-4o-mini".to_string()],
+        credit: Some(KeyCredit {
+            total: 25.0,
+            used: 10.5,
+        }),
+        validated_at: Utc::now(),
+    };
+    repo.record_validation(&id, &validation)
+        .await
+        .expect("record validation");
+
+    let mut loaded = repo.find_by_id(&id).await.unwrap().unwrap();
+    let stored = loaded.last_validation().expect("validation stored");
+    assert!(stored.is_valid());
+    assert_eq!(stored.models, validation.models);
+    assert_eq!(stored.credit.map(|credit| credit.remaining()), Some(14.5));
+    assert_eq!(loaded.updated_at(), key.updated_at());
+
+    // A new key invalidates the stored result
+    loaded.update(
+        None,
+        Some("sk-relay-rotated-000".to_string()),
+        None,
+        None,
+        None,
+    );
+    repo.update(&loaded).await.expect("update key");
+    let reloaded = repo.find_by_id(&id).await.unwrap().unwrap();
+    assert!(reloaded.last_validation().is_none());
+    assert_eq!(reloaded.api_key(), "sk-relay-rotated-000");
+}
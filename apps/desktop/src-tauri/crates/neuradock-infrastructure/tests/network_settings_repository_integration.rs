@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use neuradock_domain::check_in::{Provider, ProviderConfig, ProviderRepository};
+use neuradock_domain::network_settings::{
+    NetworkPolicySettings, NetworkSettingsRepository, ProviderNetworkOverride,
+};
+use neuradock_infrastructure::persistence::repositories::{
+    SqliteNetworkSettingsRepository, SqliteProviderRepository,
+};
+
+mod test_helpers;
+
+#[tokio::test]
+async fn network_settings_repo_roundtrip_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let pool = Arc::new(pool);
+
+    let provider_repo = SqliteProviderRepository::new(pool.clone());
+    let provider = Provider::new(ProviderConfig {
+        name: "Slow Provider".to_string(),
+        domain: "https://slow.example.com".to_string(),
+        login_path: "/login".to_string(),
+        sign_in_path: None,
+        user_info_path: "/api/user/self".to_string(),
+        token_api_path: None,
+        models_path: None,
+        api_user_key: "new-api-user".to_string(),
+        bypass_method: Some("waf_cookies".to_string()),
+        supports_check_in: true,
+        check_in_bugged: false,
+    });
+    provider_repo.save(&provider).await.expect("save provider");
+
+    let repo = SqliteNetworkSettingsRepository::new(pool);
+
+    let mut settings = repo.get().await.expect("get defaults");
+    assert_eq!(settings.defaults(), &NetworkPolicySettings::default());
+    assert!(settings.provider_overrides().is_empty());
+
+    let provider_id = provider.id().as_str().to_string();
+    settings
+        .update(
+            NetworkPolicySettings {
+                http_request_ms: 45_000,
+                backoff_multiplier: 1.5,
+                ..Default::default()
+            },
+            HashMap::from([(
+                provider_id.clone(),
+                ProviderNetworkOverride {
+                    waf_wait_ms: Some(25_000),
+                    waf_max_attempts: Some(3),
+                    ..Default::default()
+                },
+            )]),
+        )
+        .expect("update settings");
+    repo.save(&settings).await.expect("save settings");
+
+    let loaded = repo.get().await.expect("get saved");
+    assert_eq!(loaded.defaults().http_request_ms, 45_000);
+    assert_eq!(loaded.defaults().backoff_multiplier, 1.5);
+    let effective = loaded.for_provider(&provider_id);
+    assert_eq!(effective.waf_wait_ms, 25_000);
+    assert_eq!(effective.waf_max_attempts, 3);
+    assert_eq!(effective.max_retries, 3);
+    assert_eq!(effective.http_request_ms, 45_000);
+
+    // Saving again replaces the overrides instead of merging them
+    settings
+        .update(NetworkPolicySettings::default(), HashMap::new())
+        .expect("clear overrides");
+    repo.save(&settings).await.expect("save cleared");
+    assert!(repo
+        .get()
+        .await
+        .expect("get cleared")
+        .provider_overrides()
+        .is_empty());
+}
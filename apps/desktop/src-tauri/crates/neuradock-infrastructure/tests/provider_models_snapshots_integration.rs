@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use neuradock_domain::provider_models::ProviderModelsRepository;
+use neuradock_infrastructure::persistence::repositories::SqliteProviderModelsRepository;
+
+mod test_helpers;
+
+fn models(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[tokio::test]
+async fn provider_models_snapshots_record_changes_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let repo = SqliteProviderModelsRepository::new(Arc::new(pool));
+
+    let first = repo
+        .save("provider-a", &models(&["gpt-4o", "claude-sonnet-4"]))
+        .await
+        .expect("save first list");
+    assert!(first.is_none(), "nothing to compare the first list with");
+
+    // Same models in another order do not count as a change
+    let unchanged = repo
+        .save("provider-a", &models(&["claude-sonnet-4", "gpt-4o"]))
+        .await
+        .expect("save unchanged list")
+        .expect("diff against first snapshot");
+    assert!(unchanged.is_empty());
+
+    let changed = repo
+        .save("provider-a", &models(&["gpt-4o", "o3"]))
+        .await
+        .expect("save changed list")
+        .expect("diff against previous snapshot");
+    assert_eq!(changed.added, models(&["o3"]));
+    assert_eq!(changed.removed, models(&["claude-sonnet-4"]));
+
+    let snapshots = repo
+        .find_snapshots("provider-a", 10)
+        .await
+        .expect("find snapshots");
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(snapshots[0].models, models(&["gpt-4o", "o3"]));
+    assert_eq!(snapshots[1].models, models(&["gpt-4o", "claude-sonnet-4"]));
+
+    let current = repo
+        .find_by_provider("provider-a")
+        .await
+        .expect("find current")
+        .expect("current list exists");
+    assert_eq!(current.models, models(&["gpt-4o", "o3"]));
+
+    repo.delete_by_provider("provider-a")
+        .await
+        .expect("delete provider models");
+    assert!(repo
+        .find_snapshots("provider-a", 10)
+        .await
+        .expect("find snapshots after delete")
+        .is_empty());
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use neuradock_domain::proxy_config::{
+    PoolStrategy, ProxyConfig, ProxyEndpoint, ProxyOverride, ProxyPool, ProxyRoute,
+    ProxyRoutingRepository, ProxyScope, ProxyTarget, ProxyType,
+};
+use neuradock_infrastructure::persistence::repositories::SqliteProxyRoutingRepository;
+
+mod test_helpers;
+
+fn endpoint(host: &str, password: Option<&str>) -> ProxyEndpoint {
+    ProxyEndpoint::new(
+        ProxyType::Http,
+        host.to_string(),
+        3128,
+        password.map(|_| "user".to_string()),
+        password.map(str::to_string),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn proxy_routing_repo_roundtrip_integration() {
+    let (pool, encryption) = test_helpers::setup_in_memory_db().await;
+    let pool = Arc::new(pool);
+    let repo = SqliteProxyRoutingRepository::new(pool.clone(), encryption);
+
+    let proxy_pool = ProxyPool::new(
+        "residential".to_string(),
+        "Residential".to_string(),
+        PoolStrategy::Sticky,
+        vec![
+            endpoint("a.proxy", Some("s3cret")),
+            endpoint("b.proxy", None),
+        ],
+    )
+    .unwrap();
+    repo.save_pool(&proxy_pool).await.unwrap();
+
+    repo.save_override(&ProxyOverride {
+        scope: ProxyScope::Provider,
+        scope_id: "anyrouter".to_string(),
+        route: ProxyRoute::Pool {
+            pool_id: "residential".to_string(),
+        },
+    })
+    .await
+    .unwrap();
+    repo.save_override(&ProxyOverride {
+        scope: ProxyScope::Account,
+        scope_id: "acc-1".to_string(),
+        route: ProxyRoute::Endpoint {
+            endpoint: endpoint("dedicated.proxy", Some("pw")),
+        },
+    })
+    .await
+    .unwrap();
+
+    // Passwords never hit the database in plain text
+    let stored: String = sqlx::query_scalar("SELECT endpoints FROM proxy_pools WHERE id = ?")
+        .bind("residential")
+        .fetch_one(pool.as_ref())
+        .await
+        .unwrap();
+    assert!(!stored.contains("s3cret"));
+
+    let loaded = repo.find_pool("residential").await.unwrap().unwrap();
+    assert_eq!(loaded.strategy(), PoolStrategy::Sticky);
+    assert_eq!(loaded.endpoints()[0].password.as_deref(), Some("s3cret"));
+
+    let routing = repo.load().await.unwrap();
+    let global = ProxyConfig::new_disabled();
+    let account_target = ProxyTarget {
+        account_id: Some("acc-1"),
+        provider_id: Some("anyrouter"),
+        host: Some("anyrouter.top"),
+    };
+    assert_eq!(
+        routing.resolve(&global, &account_target, |_| 0),
+        Some("http://user:pw@dedicated.proxy:3128".to_string())
+    );
+
+    let provider_target = ProxyTarget {
+        account_id: Some("acc-2"),
+        provider_id: Some("anyrouter"),
+        host: Some("anyrouter.top"),
+    };
+    assert!(routing
+        .resolve(&global, &provider_target, |_| 0)
+        .is_some_and(|url| url.ends_with(".proxy:3128")));
+
+    // Pools still referenced by an override cannot be deleted
+    assert!(repo.delete_pool("residential").await.is_err());
+
+    repo.delete_override(ProxyScope::Provider, "anyrouter")
+        .await
+        .unwrap();
+    repo.delete_pool("residential").await.unwrap();
+    assert!(repo.list_pools().await.unwrap().is_empty());
+    assert_eq!(repo.list_overrides().await.unwrap().len(), 1);
+}
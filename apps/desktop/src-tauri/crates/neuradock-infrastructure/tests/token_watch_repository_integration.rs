@@ -0,0 +1,69 @@
+use chrono::Utc;
+use std::sync::Arc;
+
+use neuradock_domain::shared::AccountId;
+use neuradock_domain::token::{
+    TokenId, TokenWatchAlert, TokenWatchRepository, TokenWatchTarget, TokenWatchTool,
+};
+use neuradock_infrastructure::persistence::repositories::SqliteTokenWatchRepository;
+
+mod test_helpers;
+
+#[tokio::test]
+async fn token_watch_targets_and_settings_integration() {
+    let (pool, _encryption) = test_helpers::setup_in_memory_db().await;
+    let repo = SqliteTokenWatchRepository::new(Arc::new(pool.clone()));
+
+    let account_id = AccountId::new();
+    sqlx::query("INSERT OR IGNORE INTO accounts (id, name, provider_id, cookies, api_user, enabled, created_at) VALUES (?1, ?2, ?3, ?4, ?5, 1, datetime('now'))")
+        .bind(account_id.as_str())
+        .bind("Test Account")
+        .bind("test-provider")
+        .bind("{}")
+        .bind("api_user")
+        .execute(&pool)
+        .await
+        .expect("insert account");
+
+    // Defaults are seeded by the migration
+    let mut settings = repo.get_settings().await.expect("load settings");
+    assert!(settings.enabled());
+    assert_eq!(settings.expiry_warning_days(), 3);
+    settings.update(true, 7, 1_000_000).unwrap();
+    repo.save_settings(&settings).await.expect("save settings");
+    let settings = repo.get_settings().await.unwrap();
+    assert_eq!(settings.expiry_warning_days(), 7);
+    assert_eq!(settings.low_quota_threshold(), 1_000_000);
+
+    let mut target = TokenWatchTarget::new(
+        TokenWatchTool::Claude,
+        account_id.clone(),
+        TokenId::new(12),
+        "digest-a".to_string(),
+    );
+    repo.save_target(&target).await.expect("save target");
+    assert!(repo
+        .find_target(TokenWatchTool::Codex)
+        .await
+        .unwrap()
+        .is_none());
+
+    target.record_check(
+        vec![TokenWatchAlert::ExpiringSoon, TokenWatchAlert::LowQuota],
+        Utc::now(),
+    );
+    target.token_id = TokenId::new(13);
+    repo.save_target(&target).await.expect("update target");
+
+    let targets = repo.list_targets().await.unwrap();
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].token_id, TokenId::new(13));
+    assert_eq!(
+        targets[0].alerts,
+        vec![TokenWatchAlert::ExpiringSoon, TokenWatchAlert::LowQuota]
+    );
+    assert!(targets[0].checked_at.is_some());
+
+    repo.delete_target(TokenWatchTool::Claude).await.unwrap();
+    assert!(repo.list_targets().await.unwrap().is_empty());
+}
@@ -7,6 +7,7 @@ import {
   DropdownMenuTrigger,
 } from '@/components/ui/dropdown-menu';
 import { Button } from '@/components/ui/button';
+import { saveBackendLocale } from '@/i18n';
 
 const languages = [
   { code: 'zh-CN', label: '中文' },
@@ -23,6 +24,7 @@ export function LanguageSwitcher({ onOpenChange }: LanguageSwitcherProps) {
   const changeLanguage = (lng: string) => {
     i18n.changeLanguage(lng);
     localStorage.setItem('language', lng);
+    void saveBackendLocale(lng);
   };
 
   return (
@@ -1,3 +1,4 @@
+import { invoke } from '@tauri-apps/api/core';
 import i18n from 'i18next';
 import { initReactI18next } from 'react-i18next';
 import zhCN from './locales/zh-CN.json';
@@ -38,4 +39,39 @@ const applyDocumentLanguage = (lng: string) => {
 applyDocumentLanguage(i18n.language);
 i18n.on('languageChanged', applyDocumentLanguage);
 
+interface LocaleSettings {
+  locale: string;
+  persisted: boolean;
+  available_locales: string[];
+}
+
+// Tell the backend which language to send notifications in
+export const saveBackendLocale = async (lng: string) => {
+  try {
+    await invoke<LocaleSettings>('set_locale', { locale: lng });
+  } catch (error) {
+    console.error('Failed to save backend locale:', error);
+  }
+};
+
+// The backend setting wins once saved, otherwise it adopts the stored UI language
+const syncBackendLocale = async () => {
+  try {
+    const settings = await invoke<LocaleSettings>('get_locale');
+    if (!settings.persisted) {
+      await saveBackendLocale(i18n.language);
+    } else if (
+      settings.locale !== i18n.language &&
+      i18n.hasResourceBundle(settings.locale, 'translation')
+    ) {
+      await i18n.changeLanguage(settings.locale);
+      localStorage.setItem('language', settings.locale);
+    }
+  } catch (error) {
+    console.error('Failed to sync backend locale:', error);
+  }
+};
+
+void syncBackendLocale();
+
 export default i18n;